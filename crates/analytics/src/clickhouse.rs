@@ -157,7 +157,8 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::ApiEventsAnalytics
             | AnalyticsCollection::OutgoingWebhookEvent
             | AnalyticsCollection::OutgoingWebhookPayoutEvent
-            | AnalyticsCollection::ActivePaymentsAnalytics => TableEngine::BasicTree,
+            | AnalyticsCollection::ActivePaymentsAnalytics
+            | AnalyticsCollection::SettlementReconLine => TableEngine::BasicTree,
         }
     }
 }
@@ -493,6 +494,8 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
             Self::Authentications => Ok("authentications".to_string()),
             Self::RoutingEvents => Ok("routing_events_audit".to_string()),
+            Self::SettlementReconLine => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("SettlementReconLine table is not implemented for Clickhouse"))?,
         }
     }
 }
@@ -51,6 +51,11 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_payment_dimensions(),
         },
+        AnalyticsDomain::SettlementRecon => GetInfoResponse {
+            metrics: utils::get_settlement_recon_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_settlement_recon_dimensions(),
+        },
     };
     Ok(info)
 }
@@ -19,6 +19,7 @@ pub mod refunds;
 pub mod routing_events;
 pub mod sdk_events;
 pub mod search;
+pub mod settlement_recon;
 mod sqlx;
 mod types;
 use api_event::metrics::{ApiEventMetric, ApiEventMetricRow};
@@ -31,6 +32,7 @@ use hyperswitch_interfaces::secrets_interface::{
     SecretManagementInterface, SecretsManagementError,
 };
 use refunds::distribution::{RefundDistribution, RefundDistributionRow};
+use settlement_recon::metrics::{SettlementReconMetric, SettlementReconMetricRow};
 pub use types::AnalyticsDomain;
 pub mod lambda_utils;
 pub mod utils;
@@ -56,6 +58,10 @@ use api_models::analytics::{
     sdk_events::{
         SdkEventDimensions, SdkEventFilters, SdkEventMetrics, SdkEventMetricsBucketIdentifier,
     },
+    settlement_recon::{
+        SettlementReconDimensions, SettlementReconFilters, SettlementReconMetrics,
+        SettlementReconMetricsBucketIdentifier,
+    },
     Granularity, PaymentDistributionBody, RefundDistributionBody, TimeRange,
 };
 use clickhouse::ClickhouseClient;
@@ -842,6 +848,40 @@ impl AnalyticsProvider {
         .await
     }
 
+    /// Settlement reports are only stored in postgres, so the metrics are always loaded from the
+    /// sqlx pool.
+    pub async fn get_settlement_recon_metrics(
+        &self,
+        metric: &SettlementReconMetrics,
+        dimensions: &[SettlementReconDimensions],
+        auth: &AuthInfo,
+        filters: &SettlementReconFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<
+        HashSet<(
+            SettlementReconMetricsBucketIdentifier,
+            SettlementReconMetricRow,
+        )>,
+    > {
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) | Self::CombinedCkh(pool, _) | Self::CombinedSqlx(pool, _) => {
+                        metric
+                            .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                            .await
+                    }
+                    Self::Clickhouse(_) => Err(report!(MetricsError::NotImplemented)),
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            metric,
+            self,
+        )
+        .await
+    }
+
     pub async fn get_sdk_event_metrics(
         &self,
         metric: &SdkEventMetrics,
@@ -1157,6 +1197,8 @@ pub enum AnalyticsFlow {
     GetSearchResults,
     GetDisputeFilters,
     GetDisputeMetrics,
+    GetSettlementReconFilters,
+    GetSettlementReconMetrics,
     GetSankey,
     GetRoutingEvents,
     GetPaymentListFromOpenSearch,
@@ -11,11 +11,13 @@ use api_models::{
         payments::{PaymentDimensions, PaymentDistributions},
         refunds::{RefundDimensions, RefundDistributions, RefundType},
         sdk_events::{SdkEventDimensions, SdkEventNames},
+        settlement_recon::SettlementReconDimensions,
         Granularity,
     },
     enums::{
         AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, IntentStatus,
-        PaymentMethod, PaymentMethodType, RoutingApproach,
+        PaymentMethod, PaymentMethodType, ReconLineStatus, RoutingApproach,
+        SettlementTransactionType,
     },
     refunds::RefundStatus,
 };
@@ -535,7 +537,11 @@ impl_to_sql_for_to_string!(
     DisputeDimensions,
     DisputeStage,
     AuthEventDimensions,
-    &AuthEventDimensions
+    &AuthEventDimensions,
+    &SettlementReconDimensions,
+    SettlementReconDimensions,
+    ReconLineStatus,
+    SettlementTransactionType
 );
 
 #[derive(Debug, Clone, Copy)]
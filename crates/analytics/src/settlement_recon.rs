@@ -0,0 +1,9 @@
+pub mod accumulators;
+mod core;
+pub mod filters;
+pub mod metrics;
+pub mod types;
+pub use accumulators::{SettlementReconMetricAccumulator, SettlementReconMetricsAccumulator};
+
+pub trait SettlementReconAnalytics: metrics::SettlementReconMetricAnalytics {}
+pub use self::core::{get_filters, get_metrics};
@@ -0,0 +1,116 @@
+use api_models::analytics::settlement_recon::SettlementReconMetricsBucketValue;
+use diesel_models::enums as storage_enums;
+
+use super::metrics::SettlementReconMetricRow;
+#[derive(Debug, Default)]
+pub struct SettlementReconMetricsAccumulator {
+    pub recon_line_status: StatusCountAccumulator,
+    pub settled_amount: SettledAmountAccumulator,
+    pub settlement_fees: SettledAmountAccumulator,
+}
+#[derive(Debug, Default)]
+pub struct StatusCountAccumulator {
+    pub matched_count: i64,
+    pub amount_mismatch_count: i64,
+    pub missing_in_hyperswitch_count: i64,
+    pub missing_at_psp_count: i64,
+    pub total: i64,
+}
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct SettledAmountAccumulator {
+    pub total: Option<i64>,
+}
+
+pub trait SettlementReconMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &SettlementReconMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl SettlementReconMetricAccumulator for SettledAmountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &SettlementReconMetricRow) {
+        self.total = match (
+            self.total,
+            metrics
+                .total
+                .as_ref()
+                .and_then(bigdecimal::ToPrimitive::to_i64),
+        ) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.total.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl SettlementReconMetricAccumulator for StatusCountAccumulator {
+    type MetricOutput = (
+        Option<u64>,
+        Option<u64>,
+        Option<u64>,
+        Option<u64>,
+        Option<u64>,
+    );
+
+    fn add_metrics_bucket(&mut self, metrics: &SettlementReconMetricRow) {
+        let count = metrics.count.unwrap_or_default();
+        if let Some(ref status) = metrics.status {
+            match status.as_ref() {
+                storage_enums::ReconLineStatus::Matched => self.matched_count += count,
+                storage_enums::ReconLineStatus::AmountMismatch => {
+                    self.amount_mismatch_count += count
+                }
+                storage_enums::ReconLineStatus::MissingInHyperswitch => {
+                    self.missing_in_hyperswitch_count += count
+                }
+                storage_enums::ReconLineStatus::MissingAtPsp => self.missing_at_psp_count += count,
+            }
+        };
+
+        self.total += count;
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            (None, None, None, None, None)
+        } else {
+            (
+                u64::try_from(self.matched_count).ok(),
+                u64::try_from(self.amount_mismatch_count).ok(),
+                u64::try_from(self.missing_in_hyperswitch_count).ok(),
+                u64::try_from(self.missing_at_psp_count).ok(),
+                u64::try_from(self.total).ok(),
+            )
+        }
+    }
+}
+
+impl SettlementReconMetricsAccumulator {
+    pub fn collect(self) -> SettlementReconMetricsBucketValue {
+        let (
+            matched_count,
+            amount_mismatch_count,
+            missing_in_hyperswitch_count,
+            missing_at_psp_count,
+            total_count,
+        ) = self.recon_line_status.collect();
+        SettlementReconMetricsBucketValue {
+            matched_count,
+            amount_mismatch_count,
+            missing_in_hyperswitch_count,
+            missing_at_psp_count,
+            total_count,
+            settled_amount: self.settled_amount.collect(),
+            settlement_fees: self.settlement_fees.collect(),
+        }
+    }
+}
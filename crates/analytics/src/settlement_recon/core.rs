@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    settlement_recon::{
+        SettlementReconDimensions, SettlementReconMetrics, SettlementReconMetricsBucketIdentifier,
+        SettlementReconMetricsBucketResponse,
+    },
+    GetSettlementReconFilterRequest, GetSettlementReconMetricRequest,
+    SettlementReconAnalyticsMetadata, SettlementReconFilterValue, SettlementReconFiltersResponse,
+    SettlementReconMetricsResponse,
+};
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    tracing::{self, Instrument},
+};
+
+use super::{
+    filters::{get_settlement_recon_filter_for_dimension, SettlementReconFilterRow},
+    SettlementReconMetricAccumulator, SettlementReconMetricsAccumulator,
+};
+use crate::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics, AnalyticsProvider,
+};
+
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetSettlementReconMetricRequest,
+) -> AnalyticsResult<SettlementReconMetricsResponse<SettlementReconMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        SettlementReconMetricsBucketIdentifier,
+        SettlementReconMetricsAccumulator,
+    > = HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_settlement_recon_query",
+            settlement_recon_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let auth_scoped = auth.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_settlement_recon_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &auth_scoped,
+                        &req.filters,
+                        req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = router_env::metric_attributes!(
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        );
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                SettlementReconMetrics::ReconLineStatusMetric => {
+                    metrics_builder.recon_line_status.add_metrics_bucket(&value)
+                }
+                SettlementReconMetrics::TotalSettledAmount => {
+                    metrics_builder.settled_amount.add_metrics_bucket(&value)
+                }
+                SettlementReconMetrics::TotalSettlementFees => {
+                    metrics_builder.settlement_fees.add_metrics_bucket(&value)
+                }
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+    let mut total_settled_amount = 0;
+    let mut total_settlement_fees = 0;
+    let query_data: Vec<SettlementReconMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| {
+            let collected_values = val.collect();
+            if let Some(amount) = collected_values.settled_amount {
+                total_settled_amount += amount;
+            }
+            if let Some(amount) = collected_values.settlement_fees {
+                total_settlement_fees += amount;
+            }
+
+            SettlementReconMetricsBucketResponse {
+                values: collected_values,
+                dimensions: id,
+            }
+        })
+        .collect();
+
+    Ok(SettlementReconMetricsResponse {
+        query_data,
+        meta_data: [SettlementReconAnalyticsMetadata {
+            total_settled_amount: Some(total_settled_amount),
+            total_settlement_fees: Some(total_settlement_fees),
+        }],
+    })
+}
+
+/// Settlement reports are only stored in postgres, so the filters are always loaded from the
+/// sqlx pool.
+pub async fn get_filters(
+    pool: &AnalyticsProvider,
+    req: GetSettlementReconFilterRequest,
+    auth: &AuthInfo,
+) -> AnalyticsResult<SettlementReconFiltersResponse> {
+    let mut res = SettlementReconFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool {
+            AnalyticsProvider::Sqlx(sqlx_pool)
+            | AnalyticsProvider::CombinedCkh(sqlx_pool, _)
+            | AnalyticsProvider::CombinedSqlx(sqlx_pool, _) => {
+                get_settlement_recon_filter_for_dimension(dim, auth, &req.time_range, sqlx_pool)
+                    .await
+                    .change_context(AnalyticsError::UnknownError)?
+            }
+            AnalyticsProvider::Clickhouse(_) => Err(AnalyticsError::NotImplemented(
+                "Settlement reconciliation analytics is not supported on clickhouse",
+            ))?,
+        }
+        .into_iter()
+        .filter_map(|fil: SettlementReconFilterRow| match dim {
+            SettlementReconDimensions::Connector => fil.connector,
+            SettlementReconDimensions::SettledCurrency => {
+                fil.settled_currency.map(|i| i.as_ref().to_string())
+            }
+            SettlementReconDimensions::Status => fil.status.map(|i| i.as_ref().to_string()),
+            SettlementReconDimensions::TransactionType => {
+                fil.transaction_type.map(|i| i.as_ref().to_string())
+            }
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(SettlementReconFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(res)
+}
@@ -0,0 +1,111 @@
+mod recon_line_status_metric;
+mod total_settled_amount;
+mod total_settlement_fees;
+
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    settlement_recon::{
+        SettlementReconDimensions, SettlementReconFilters, SettlementReconMetrics,
+        SettlementReconMetricsBucketIdentifier,
+    },
+    Granularity,
+};
+use common_utils::types::TimeRange;
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+
+use self::{
+    recon_line_status_metric::ReconLineStatusMetric, total_settled_amount::TotalSettledAmount,
+    total_settlement_fees::TotalSettlementFees,
+};
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct SettlementReconMetricRow {
+    pub connector: Option<String>,
+    pub settled_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub status: Option<DBEnumWrapper<storage_enums::ReconLineStatus>>,
+    pub transaction_type: Option<DBEnumWrapper<storage_enums::SettlementTransactionType>>,
+    pub total: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait SettlementReconMetricAnalytics: LoadRow<SettlementReconMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait SettlementReconMetric<T>
+where
+    T: AnalyticsDataSource + SettlementReconMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[SettlementReconDimensions],
+        auth: &AuthInfo,
+        filters: &SettlementReconFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            SettlementReconMetricsBucketIdentifier,
+            SettlementReconMetricRow,
+        )>,
+    >;
+}
+
+#[async_trait::async_trait]
+impl<T> SettlementReconMetric<T> for SettlementReconMetrics
+where
+    T: AnalyticsDataSource + SettlementReconMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[SettlementReconDimensions],
+        auth: &AuthInfo,
+        filters: &SettlementReconFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            SettlementReconMetricsBucketIdentifier,
+            SettlementReconMetricRow,
+        )>,
+    > {
+        match self {
+            Self::ReconLineStatusMetric => {
+                ReconLineStatusMetric::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::TotalSettledAmount => {
+                TotalSettledAmount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::TotalSettlementFees => {
+                TotalSettlementFees::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use api_models::analytics::settlement_recon::{SettlementReconDimensions, SettlementReconFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for SettlementReconFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(SettlementReconDimensions::Connector, &self.connector)
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.settled_currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    SettlementReconDimensions::SettledCurrency,
+                    &self.settled_currency,
+                )
+                .attach_printable("Error adding settled currency filter")?;
+        }
+
+        if !self.status.is_empty() {
+            builder
+                .add_filter_in_range_clause(SettlementReconDimensions::Status, &self.status)
+                .attach_printable("Error adding status filter")?;
+        }
+
+        if !self.transaction_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    SettlementReconDimensions::TransactionType,
+                    &self.transaction_type,
+                )
+                .attach_printable("Error adding transaction type filter")?;
+        }
+
+        Ok(())
+    }
+}
@@ -5,7 +5,8 @@ use api_models::{
     enums::{DisputeStage, DisputeStatus},
 };
 use common_enums::{
-    AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, TransactionStatus,
+    AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, ReconLineStatus,
+    SettlementTransactionType, TransactionStatus,
 };
 use common_utils::{
     errors::{CustomResult, ParsingError},
@@ -104,6 +105,8 @@ db_type!(TransactionStatus);
 db_type!(AuthenticationConnectors);
 db_type!(DecoupledAuthenticationType);
 db_type!(RoutingApproach);
+db_type!(ReconLineStatus, TEXT);
+db_type!(SettlementTransactionType, TEXT);
 
 impl<'q, Type> Encode<'q, Postgres> for DBEnumWrapper<Type>
 where
@@ -169,6 +172,8 @@ impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
 impl super::auth_events::filters::AuthEventFilterAnalytics for SqlxClient {}
+impl super::settlement_recon::filters::SettlementReconFilterAnalytics for SqlxClient {}
+impl super::settlement_recon::metrics::SettlementReconMetricAnalytics for SqlxClient {}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
@@ -1434,6 +1439,84 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::settlement_recon::filters::SettlementReconFilterRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let settled_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("settled_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<ReconLineStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let transaction_type: Option<DBEnumWrapper<SettlementTransactionType>> =
+            row.try_get("transaction_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        Ok(Self {
+            connector,
+            settled_currency,
+            status,
+            transaction_type,
+        })
+    }
+}
+impl<'a> FromRow<'a, PgRow> for super::settlement_recon::metrics::SettlementReconMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let settled_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("settled_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<ReconLineStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let transaction_type: Option<DBEnumWrapper<SettlementTransactionType>> =
+            row.try_get("transaction_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector,
+            settled_currency,
+            status,
+            transaction_type,
+            total,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
@@ -1484,6 +1567,7 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
                 .attach_printable("Authentications table is not implemented for Sqlx"))?,
             Self::RoutingEvents => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("RoutingEvents table is not implemented for Sqlx"))?,
+            Self::SettlementReconLine => Ok("settlement_recon_line".to_string()),
         }
     }
 }
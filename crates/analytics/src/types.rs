@@ -22,6 +22,7 @@ pub enum AnalyticsDomain {
     ApiEvents,
     Dispute,
     Routing,
+    SettlementRecon,
 }
 
 #[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
@@ -47,6 +48,7 @@ pub enum AnalyticsCollection {
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
     RoutingEvents,
+    SettlementReconLine,
 }
 
 #[allow(dead_code)]
@@ -7,6 +7,7 @@ use api_models::analytics::{
     payments::{PaymentDimensions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
+    settlement_recon::{SettlementReconDimensions, SettlementReconMetrics},
     NameDescription,
 };
 use strum::IntoEnumIterator;
@@ -131,3 +132,11 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+pub fn get_settlement_recon_metrics_info() -> Vec<NameDescription> {
+    SettlementReconMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_settlement_recon_dimensions() -> Vec<NameDescription> {
+    SettlementReconDimensions::iter().map(Into::into).collect()
+}
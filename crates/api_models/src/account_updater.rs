@@ -0,0 +1,134 @@
+use common_enums::{
+    AccountUpdaterBatchStatus, AccountUpdaterConnector, AccountUpdaterReason, AccountUpdaterResult,
+};
+use common_utils::id_type;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountUpdaterEnableRequest {
+    /// The service the saved cards are submitted to. Defaults to `file`
+    #[schema(value_type = Option<AccountUpdaterConnector>, example = "file")]
+    #[serde(default)]
+    pub connector: AccountUpdaterConnector,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AccountUpdaterEnrollmentResponse {
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// Whether the saved cards of the merchant are periodically submitted to the account updater
+    pub enabled: bool,
+
+    /// The service the saved cards are submitted to
+    #[schema(value_type = Option<AccountUpdaterConnector>)]
+    pub connector: Option<AccountUpdaterConnector>,
+
+    /// Time of the next account updater run
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountUpdaterBatchListConstraints {
+    /// Limit on the number of batches to return
+    pub limit: Option<u32>,
+
+    /// The starting point within the list of batches
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccountUpdaterBatchId {
+    /// The identifier for the batch
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AccountUpdaterBatchResponse {
+    /// The identifier for the batch
+    #[schema(example = "aub_2b3ahVcG4K4sG5xQ9nGdI0")]
+    pub batch_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The service the batch was submitted to
+    #[schema(value_type = AccountUpdaterConnector)]
+    pub connector: AccountUpdaterConnector,
+
+    /// The status of the batch
+    #[schema(value_type = AccountUpdaterBatchStatus)]
+    pub status: AccountUpdaterBatchStatus,
+
+    /// The number of cards submitted in the batch
+    pub card_count: i32,
+
+    /// The number of cards which were reissued with a new account number or expiry date
+    pub updated_count: i32,
+
+    /// The number of cards whose account was closed
+    pub closed_count: i32,
+
+    /// The number of cards for which no update was received
+    pub unchanged_count: i32,
+
+    /// Time when the batch was submitted
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time when the batch was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+
+    /// Time when the results of the batch were applied
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+
+    /// The cards submitted in the batch, only included when a single batch is retrieved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cards: Option<Vec<AccountUpdaterCardResponse>>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AccountUpdaterCardResponse {
+    /// The identifier for the payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIg")]
+    pub payment_method_id: String,
+
+    /// The identifier for the customer the payment method belongs to
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+
+    /// Why the card was submitted
+    #[schema(value_type = AccountUpdaterReason)]
+    pub reason: AccountUpdaterReason,
+
+    /// The outcome of the inquiry, absent until the results of the batch are received
+    #[schema(value_type = Option<AccountUpdaterResult>)]
+    pub result: Option<AccountUpdaterResult>,
+
+    /// Time when the card was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AccountUpdaterBatchListResponse {
+    /// The number of batches included in the list
+    pub count: usize,
+
+    /// The batches, latest first
+    pub data: Vec<AccountUpdaterBatchResponse>,
+}
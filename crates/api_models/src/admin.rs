@@ -2108,6 +2108,72 @@ pub struct ToggleAllKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StorageSchemeMigrationRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Status of KV for the specific merchant once the migration completes
+    #[schema(example = false)]
+    pub kv_enabled: bool,
+    /// Number of recently modified payments to compare between redis and postgres before
+    /// disabling KV, defaults to 50
+    #[schema(example = 50)]
+    pub verification_sample_size: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StorageSchemeMigrationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Outcome of the migration
+    pub status: StorageSchemeMigrationStatus,
+    /// Storage scheme of the merchant before the migration
+    #[schema(value_type = String, example = "redis_kv")]
+    pub previous_storage_scheme: api_enums::MerchantStorageScheme,
+    /// Storage scheme of the merchant after the migration
+    #[schema(value_type = String, example = "postgres_only")]
+    pub storage_scheme: api_enums::MerchantStorageScheme,
+    /// Number of entries of the merchant in the drainer streams yet to be written to postgres
+    #[schema(example = 0)]
+    pub pending_drainer_entries: usize,
+    /// Number of payments, attempts and refunds compared between redis and postgres
+    #[schema(example = 120)]
+    pub verified_records: usize,
+    /// Records whose redis and postgres copies differ
+    pub mismatches: Vec<StorageSchemeMismatch>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageSchemeMigrationStatus {
+    /// The entries of the merchant are being drained and verified, the storage scheme is switched
+    /// once they are
+    InProgress,
+    /// Redis and postgres agree, and the storage scheme was switched
+    Completed,
+    /// The drainer has not caught up with the merchant, the storage scheme was not switched
+    DrainPending,
+    /// Redis and postgres do not agree, the storage scheme was not switched
+    VerificationFailed,
+    /// The storage scheme was switched back to the one before the last migration
+    RolledBack,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StorageSchemeMismatch {
+    /// Table of the record
+    #[schema(example = "payment_attempt")]
+    pub table: String,
+    /// Identifier of the record
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub record_id: String,
+    /// Fields whose redis and postgres values differ, or `missing_in_postgres`
+    #[schema(example = json!(["status", "modified_at"]))]
+    pub differences: Vec<String>,
+}
+
 /// Merchant connector details used to make payments.
 #[derive(
     Debug,
@@ -13,6 +13,7 @@ use self::{
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
     refunds::{RefundDimensions, RefundDistributions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
+    settlement_recon::{SettlementReconDimensions, SettlementReconMetrics},
 };
 pub mod active_payments;
 pub mod api_event;
@@ -27,6 +28,7 @@ pub mod refunds;
 pub mod routing_events;
 pub mod sdk_events;
 pub mod search;
+pub mod settlement_recon;
 
 #[derive(Debug, serde::Serialize)]
 pub struct NameDescription {
@@ -437,6 +439,17 @@ pub struct DisputesMetricsResponse<T> {
     pub query_data: Vec<T>,
     pub meta_data: [DisputesAnalyticsMetadata; 1],
 }
+#[derive(Debug, serde::Serialize)]
+pub struct SettlementReconAnalyticsMetadata {
+    pub total_settled_amount: Option<u64>,
+    pub total_settlement_fees: Option<u64>,
+}
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementReconMetricsResponse<T> {
+    pub query_data: Vec<T>,
+    pub meta_data: [SettlementReconAnalyticsMetadata; 1],
+}
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetApiEventFiltersRequest {
@@ -507,6 +520,41 @@ pub struct GetDisputeMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSettlementReconFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<SettlementReconDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementReconFiltersResponse {
+    pub query_data: Vec<SettlementReconFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementReconFilterValue {
+    pub dimension: SettlementReconDimensions,
+    pub values: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSettlementReconMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<SettlementReconDimensions>,
+    #[serde(default)]
+    pub filters: settlement_recon::SettlementReconFilters,
+    pub metrics: HashSet<SettlementReconMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Clone, Debug, Default, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct SankeyResponse {
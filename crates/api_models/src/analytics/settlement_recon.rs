@@ -0,0 +1,151 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+use crate::enums::{Currency, ReconLineStatus, SettlementTransactionType};
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementReconMetrics {
+    ReconLineStatusMetric,
+    TotalSettledAmount,
+    TotalSettlementFees,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SettlementReconDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    Connector,
+    SettledCurrency,
+    Status,
+    TransactionType,
+}
+
+impl From<SettlementReconDimensions> for NameDescription {
+    fn from(value: SettlementReconDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<SettlementReconMetrics> for NameDescription {
+    fn from(value: SettlementReconMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct SettlementReconFilters {
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub settled_currency: Vec<Currency>,
+    #[serde(default)]
+    pub status: Vec<ReconLineStatus>,
+    #[serde(default)]
+    pub transaction_type: Vec<SettlementTransactionType>,
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct SettlementReconMetricsBucketIdentifier {
+    pub connector: Option<String>,
+    pub settled_currency: Option<Currency>,
+    pub status: Option<ReconLineStatus>,
+    pub transaction_type: Option<SettlementTransactionType>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for SettlementReconMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector.hash(state);
+        self.settled_currency.hash(state);
+        self.status.hash(state);
+        self.transaction_type.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+impl PartialEq for SettlementReconMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl SettlementReconMetricsBucketIdentifier {
+    pub fn new(
+        connector: Option<String>,
+        settled_currency: Option<Currency>,
+        status: Option<ReconLineStatus>,
+        transaction_type: Option<SettlementTransactionType>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector,
+            settled_currency,
+            status,
+            transaction_type,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SettlementReconMetricsBucketValue {
+    pub matched_count: Option<u64>,
+    pub amount_mismatch_count: Option<u64>,
+    pub missing_in_hyperswitch_count: Option<u64>,
+    pub missing_at_psp_count: Option<u64>,
+    pub total_count: Option<u64>,
+    pub settled_amount: Option<u64>,
+    pub settlement_fees: Option<u64>,
+}
+#[derive(Debug, serde::Serialize)]
+pub struct SettlementReconMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: SettlementReconMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: SettlementReconMetricsBucketIdentifier,
+}
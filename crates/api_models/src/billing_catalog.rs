@@ -0,0 +1,512 @@
+use common_enums::{
+    BillingInterval, BillingPricingModel, CouponDiscountType, CouponDuration, Currency,
+};
+use common_types::subscriptions::PriceTiers;
+use common_utils::{
+    errors::ValidationError, events::ApiEventMetric, fp_utils, id_type::ProfileId, pii,
+    types::MinorUnit,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Request payload for creating a product of the native billing catalog.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BillingProductCreateRequest {
+    /// Name of the product.
+    pub name: String,
+
+    /// Description of the product.
+    pub description: Option<String>,
+
+    /// Metadata attached to the product.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Request payload for updating a product of the native billing catalog.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BillingProductUpdateRequest {
+    /// Name of the product.
+    pub name: Option<String>,
+
+    /// Description of the product.
+    pub description: Option<String>,
+
+    /// Whether new subscriptions can be created for the plans of the product.
+    pub is_active: Option<bool>,
+
+    /// Metadata attached to the product.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BillingProductResponse {
+    /// Unique identifier for the product.
+    pub id: String,
+
+    /// Profile the product belongs to.
+    pub profile_id: ProfileId,
+
+    /// Name of the product.
+    pub name: String,
+
+    /// Description of the product.
+    pub description: Option<String>,
+
+    /// Whether new subscriptions can be created for the plans of the product.
+    pub is_active: bool,
+
+    /// Metadata attached to the product.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// Request payload for creating a plan of the native billing catalog.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BillingPlanCreateRequest {
+    /// Product the plan belongs to.
+    pub product_id: String,
+
+    /// Name of the plan.
+    pub name: String,
+
+    /// Description of the plan.
+    pub description: Option<String>,
+
+    /// Number of days new subscriptions to the plan are in trial before the first charge.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u32>,
+
+    /// Metadata attached to the plan.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Request payload for updating a plan of the native billing catalog.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BillingPlanUpdateRequest {
+    /// Name of the plan.
+    pub name: Option<String>,
+
+    /// Description of the plan.
+    pub description: Option<String>,
+
+    /// Number of days new subscriptions to the plan are in trial before the first charge.
+    pub trial_period_days: Option<u32>,
+
+    /// Whether new subscriptions can be created for the plan.
+    pub is_active: Option<bool>,
+
+    /// Metadata attached to the plan.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BillingPlanResponse {
+    /// Unique identifier for the plan.
+    pub id: String,
+
+    /// Profile the plan belongs to.
+    pub profile_id: ProfileId,
+
+    /// Product the plan belongs to.
+    pub product_id: String,
+
+    /// Name of the plan.
+    pub name: String,
+
+    /// Description of the plan.
+    pub description: Option<String>,
+
+    /// Number of days new subscriptions to the plan are in trial before the first charge.
+    pub trial_period_days: Option<u32>,
+
+    /// Whether new subscriptions can be created for the plan.
+    pub is_active: bool,
+
+    /// Metadata attached to the plan.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// Request payload for creating a price of a plan.
+///
+/// `flat` and `per_unit` prices need `unit_amount`, `tiered` prices need `tiers`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BillingPriceCreateRequest {
+    /// Plan the price belongs to.
+    pub plan_id: String,
+
+    /// Currency the price is charged in.
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+
+    /// How the amount of an invoice is computed from the quantity of the subscription.
+    #[schema(value_type = BillingPricingModel)]
+    pub pricing_model: BillingPricingModel,
+
+    /// Amount charged per billing cycle for a `flat` price, or per unit for a `per_unit` price.
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub unit_amount: Option<MinorUnit>,
+
+    /// Tiers of a `tiered` price.
+    #[schema(value_type = Option<Vec<PriceTier>>)]
+    pub tiers: Option<PriceTiers>,
+
+    /// Unit of the billing cycle.
+    #[schema(value_type = BillingInterval)]
+    pub billing_interval: BillingInterval,
+
+    /// Number of intervals in a billing cycle. Defaults to 1.
+    #[schema(example = 1, minimum = 1)]
+    pub interval_count: Option<u32>,
+
+    /// Metadata attached to the price.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl BillingPriceCreateRequest {
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        fp_utils::when(self.interval_count == Some(0), || {
+            Err(ValidationError::InvalidValue {
+                message: "interval_count must be greater than 0".to_string(),
+            }
+            .into())
+        })?;
+        match self.pricing_model {
+            BillingPricingModel::Flat | BillingPricingModel::PerUnit => {
+                fp_utils::when(self.tiers.is_some(), || {
+                    Err(ValidationError::InvalidValue {
+                        message: "tiers are only allowed for tiered prices".to_string(),
+                    }
+                    .into())
+                })?;
+                match self.unit_amount {
+                    Some(unit_amount) if unit_amount.is_greater_than(-1) => Ok(()),
+                    Some(_) => Err(ValidationError::InvalidValue {
+                        message: "unit_amount must not be negative".to_string(),
+                    }
+                    .into()),
+                    None => Err(ValidationError::MissingRequiredField {
+                        field_name: "unit_amount".to_string(),
+                    }
+                    .into()),
+                }
+            }
+            BillingPricingModel::Tiered => {
+                fp_utils::when(self.unit_amount.is_some(), || {
+                    Err(ValidationError::InvalidValue {
+                        message: "unit_amount is not allowed for tiered prices".to_string(),
+                    }
+                    .into())
+                })?;
+                let tiers = self
+                    .tiers
+                    .as_ref()
+                    .ok_or(ValidationError::MissingRequiredField {
+                        field_name: "tiers".to_string(),
+                    })?;
+                tiers.validate().map_err(|message| {
+                    ValidationError::InvalidValue {
+                        message: message.to_string(),
+                    }
+                    .into()
+                })
+            }
+        }
+    }
+}
+
+/// Request payload for updating a price of a plan. The amounts of a price can not be changed.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BillingPriceUpdateRequest {
+    /// Whether new subscriptions can be created with the price.
+    pub is_active: Option<bool>,
+
+    /// Metadata attached to the price.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BillingPriceResponse {
+    /// Unique identifier for the price.
+    pub id: String,
+
+    /// Profile the price belongs to.
+    pub profile_id: ProfileId,
+
+    /// Plan the price belongs to.
+    pub plan_id: String,
+
+    /// Currency the price is charged in.
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+
+    /// How the amount of an invoice is computed from the quantity of the subscription.
+    #[schema(value_type = BillingPricingModel)]
+    pub pricing_model: BillingPricingModel,
+
+    /// Amount charged per billing cycle for a `flat` price, or per unit for a `per_unit` price.
+    #[schema(value_type = Option<i64>)]
+    pub unit_amount: Option<MinorUnit>,
+
+    /// Tiers of a `tiered` price.
+    #[schema(value_type = Option<Vec<PriceTier>>)]
+    pub tiers: Option<PriceTiers>,
+
+    /// Unit of the billing cycle.
+    #[schema(value_type = BillingInterval)]
+    pub billing_interval: BillingInterval,
+
+    /// Number of intervals in a billing cycle.
+    pub interval_count: u32,
+
+    /// Whether new subscriptions can be created with the price.
+    pub is_active: bool,
+
+    /// Metadata attached to the price.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Request payload for creating a coupon.
+///
+/// `percentage` coupons need `percent_off`, `fixed_amount` coupons need `amount_off` and `currency`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BillingCouponCreateRequest {
+    /// Code customers apply the coupon with, unique within the profile.
+    pub code: String,
+
+    /// Name of the coupon.
+    pub name: String,
+
+    /// Whether the coupon takes off a percentage or a fixed amount.
+    #[schema(value_type = CouponDiscountType)]
+    pub discount_type: CouponDiscountType,
+
+    /// Percentage taken off the invoice amount.
+    #[schema(example = 20, maximum = 100)]
+    pub percent_off: Option<u8>,
+
+    /// Amount taken off the invoice amount.
+    #[schema(value_type = Option<i64>)]
+    pub amount_off: Option<MinorUnit>,
+
+    /// Currency of `amount_off`, the coupon only applies to prices in this currency.
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<Currency>,
+
+    /// How many invoices of a subscription the coupon applies to.
+    #[schema(value_type = CouponDuration)]
+    pub duration: CouponDuration,
+
+    /// Number of billing cycles a `repeating` coupon applies to.
+    pub duration_in_cycles: Option<u32>,
+
+    /// Number of times the coupon can be redeemed across all subscriptions.
+    pub max_redemptions: Option<u32>,
+
+    /// Time after which the coupon can no longer be redeemed.
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub redeem_by: Option<PrimitiveDateTime>,
+
+    /// Metadata attached to the coupon.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl BillingCouponCreateRequest {
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        match self.discount_type {
+            CouponDiscountType::Percentage => match self.percent_off {
+                Some(percent_off) if (1..=100).contains(&percent_off) => Ok(()),
+                Some(_) => Err(ValidationError::InvalidValue {
+                    message: "percent_off must be between 1 and 100".to_string(),
+                }
+                .into()),
+                None => Err(ValidationError::MissingRequiredField {
+                    field_name: "percent_off".to_string(),
+                }
+                .into()),
+            },
+            CouponDiscountType::FixedAmount => {
+                fp_utils::when(self.currency.is_none(), || {
+                    Err(ValidationError::MissingRequiredField {
+                        field_name: "currency".to_string(),
+                    }
+                    .into())
+                })?;
+                match self.amount_off {
+                    Some(amount_off) if amount_off.is_greater_than(0) => Ok(()),
+                    Some(_) => Err(ValidationError::InvalidValue {
+                        message: "amount_off must be greater than 0".to_string(),
+                    }
+                    .into()),
+                    None => Err(ValidationError::MissingRequiredField {
+                        field_name: "amount_off".to_string(),
+                    }
+                    .into()),
+                }
+            }
+        }?;
+        match (self.duration, self.duration_in_cycles) {
+            (CouponDuration::Repeating, Some(cycles)) if cycles > 0 => Ok(()),
+            (CouponDuration::Repeating, _) => Err(ValidationError::InvalidValue {
+                message: "duration_in_cycles must be greater than 0 for repeating coupons"
+                    .to_string(),
+            }
+            .into()),
+            (CouponDuration::Once | CouponDuration::Forever, Some(_)) => {
+                Err(ValidationError::InvalidValue {
+                    message: "duration_in_cycles is only allowed for repeating coupons".to_string(),
+                }
+                .into())
+            }
+            (CouponDuration::Once | CouponDuration::Forever, None) => Ok(()),
+        }
+    }
+}
+
+/// Request payload for updating a coupon. The discount of a coupon can not be changed.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BillingCouponUpdateRequest {
+    /// Name of the coupon.
+    pub name: Option<String>,
+
+    /// Number of times the coupon can be redeemed across all subscriptions.
+    pub max_redemptions: Option<u32>,
+
+    /// Time after which the coupon can no longer be redeemed.
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub redeem_by: Option<PrimitiveDateTime>,
+
+    /// Whether the coupon can be redeemed.
+    pub is_active: Option<bool>,
+
+    /// Metadata attached to the coupon.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BillingCouponResponse {
+    /// Unique identifier for the coupon.
+    pub id: String,
+
+    /// Profile the coupon belongs to.
+    pub profile_id: ProfileId,
+
+    /// Code customers apply the coupon with.
+    pub code: String,
+
+    /// Name of the coupon.
+    pub name: String,
+
+    /// Whether the coupon takes off a percentage or a fixed amount.
+    #[schema(value_type = CouponDiscountType)]
+    pub discount_type: CouponDiscountType,
+
+    /// Percentage taken off the invoice amount.
+    pub percent_off: Option<u8>,
+
+    /// Amount taken off the invoice amount.
+    #[schema(value_type = Option<i64>)]
+    pub amount_off: Option<MinorUnit>,
+
+    /// Currency of `amount_off`.
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<Currency>,
+
+    /// How many invoices of a subscription the coupon applies to.
+    #[schema(value_type = CouponDuration)]
+    pub duration: CouponDuration,
+
+    /// Number of billing cycles a `repeating` coupon applies to.
+    pub duration_in_cycles: Option<u32>,
+
+    /// Number of times the coupon can be redeemed across all subscriptions.
+    pub max_redemptions: Option<u32>,
+
+    /// Number of times the coupon has been redeemed.
+    pub times_redeemed: u32,
+
+    /// Time after which the coupon can no longer be redeemed.
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub redeem_by: Option<PrimitiveDateTime>,
+
+    /// Whether the coupon can be redeemed.
+    pub is_active: bool,
+
+    /// Metadata attached to the coupon.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BillingCatalogListQuery {
+    /// Number of records to return.
+    pub limit: Option<i64>,
+
+    /// Offset for pagination.
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BillingPriceListQuery {
+    /// Plan whose prices are listed.
+    pub plan_id: String,
+}
+
+impl ApiEventMetric for BillingProductCreateRequest {}
+impl ApiEventMetric for BillingProductUpdateRequest {}
+impl ApiEventMetric for BillingProductResponse {}
+impl ApiEventMetric for BillingPlanCreateRequest {}
+impl ApiEventMetric for BillingPlanUpdateRequest {}
+impl ApiEventMetric for BillingPlanResponse {}
+impl ApiEventMetric for BillingPriceCreateRequest {}
+impl ApiEventMetric for BillingPriceUpdateRequest {}
+impl ApiEventMetric for BillingPriceResponse {}
+impl ApiEventMetric for BillingCouponCreateRequest {}
+impl ApiEventMetric for BillingCouponUpdateRequest {}
+impl ApiEventMetric for BillingCouponResponse {}
+impl ApiEventMetric for BillingCatalogListQuery {}
+impl ApiEventMetric for BillingPriceListQuery {}
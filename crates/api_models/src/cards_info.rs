@@ -126,3 +126,89 @@ impl From<CardInfoMigrationResponseType> for CardInfoMigrationResponse {
         }
     }
 }
+
+/// Layout of the BIN file uploaded to load the BIN ranges
+#[derive(Debug, Default, Clone, Copy, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BinFileFormat {
+    /// CSV with the `range_low`, `range_high`, `card_issuer`, `card_network`, `card_type`,
+    /// `card_subtype`, `card_issuing_country`, `country_code` and `bank_code` columns
+    #[default]
+    Generic,
+    /// CSV in the binlist layout, with a single `bin` per line in place of a range
+    Binlist,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BinRangeRecord {
+    #[schema(example = "41111100")]
+    pub range_low: String,
+    #[schema(example = "41111199")]
+    pub range_high: String,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<String>,
+    #[schema(example = "CREDIT")]
+    pub card_type: Option<String>,
+    #[schema(example = "CLASSIC")]
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub country_code: Option<String>,
+    pub bank_code: Option<String>,
+    #[serde(default)]
+    pub line_number: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct BinlistRecord {
+    pub bin: String,
+    pub brand: Option<String>,
+    #[serde(rename = "type")]
+    pub card_type: Option<String>,
+    pub category: Option<String>,
+    pub issuer: Option<String>,
+    pub alpha_2: Option<String>,
+    pub country: Option<String>,
+}
+
+impl From<BinlistRecord> for BinRangeRecord {
+    fn from(record: BinlistRecord) -> Self {
+        Self {
+            range_low: record.bin.clone(),
+            range_high: record.bin,
+            card_issuer: record.issuer,
+            card_network: record.brand,
+            card_type: record.card_type,
+            card_subtype: record.category,
+            card_issuing_country: record.country,
+            country_code: record.alpha_2,
+            bank_code: None,
+            line_number: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BinRangeUploadRequest {
+    pub source: String,
+    pub records: Vec<BinRangeRecord>,
+}
+
+impl ApiEventMetric for BinRangeUploadRequest {}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct BinRangeRejectedRecord {
+    pub line_number: Option<i64>,
+    pub range_low: String,
+    pub range_high: String,
+    pub error: String,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct BinRangeUploadResponse {
+    /// The provider of the BIN file, whose previously loaded ranges were replaced
+    pub source: String,
+    pub loaded_count: usize,
+    pub rejected: Vec<BinRangeRejectedRecord>,
+}
+
+impl ApiEventMetric for BinRangeUploadResponse {}
@@ -521,3 +521,186 @@ impl CustomerDocumentDetails {
         self.document_type.validate(self.document_number.peek())
     }
 }
+
+/// A data export or erasure requested on behalf of a customer
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDataRequestResponse {
+    /// The identifier of the request
+    #[schema(example = "cdr_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub request_id: String,
+    /// The identifier of the customer the request was made on behalf of
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// Whether the data of the customer is exported or erased
+    #[schema(value_type = CustomerDataRequestType)]
+    pub request_type: common_enums::CustomerDataRequestType,
+    /// The status of the request
+    #[schema(value_type = CustomerDataRequestStatus)]
+    pub status: common_enums::CustomerDataRequestStatus,
+    /// Whether the archive of an export is available for download
+    pub archive_available: bool,
+    /// The reason the request failed
+    pub error_message: Option<String>,
+    /// A timestamp (ISO 8601 code) that determines when the request was made
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// A timestamp (ISO 8601 code) that determines when the request was completed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+/// The data subject requests made on behalf of a customer
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDataRequestListResponse {
+    /// The requests, most recent first
+    pub data: Vec<CustomerDataRequestResponse>,
+}
+
+/// Identifies a data subject request of a customer
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerDataRequestId {
+    pub customer_id: id_type::CustomerId,
+    pub request_id: String,
+}
+
+/// Everything held about a customer, as exported in the archive of a data export request
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerDataArchive {
+    pub customer: CustomerResponse,
+    pub payment_methods: Vec<CustomerDataArchivePaymentMethod>,
+    pub mandates: Vec<CustomerDataArchiveMandate>,
+    pub payments: Vec<CustomerDataArchivePayment>,
+    pub disputes: Vec<crate::disputes::DisputeResponse>,
+    pub events: Vec<CustomerDataArchiveEvent>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub exported_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerDataArchivePaymentMethod {
+    pub payment_method_id: String,
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub payment_method_data: Option<pii::SecretSerdeValue>,
+    pub billing_address: Option<pii::SecretSerdeValue>,
+    pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub last_used_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerDataArchiveMandate {
+    pub mandate_id: String,
+    pub payment_method_id: String,
+    pub status: common_enums::MandateStatus,
+    pub mandate_type: common_enums::MandateType,
+    pub customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    pub customer_user_agent: Option<String>,
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub customer_accepted_at: Option<time::PrimitiveDateTime>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerDataArchivePayment {
+    pub payment_id: id_type::PaymentId,
+    pub status: common_enums::IntentStatus,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: Option<common_enums::Currency>,
+    pub description: Option<String>,
+    pub billing: Option<payments::AddressDetails>,
+    pub shipping: Option<payments::AddressDetails>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerDataArchiveEvent {
+    pub event_id: String,
+    pub event_type: common_enums::EventType,
+    pub primary_object_id: String,
+    pub primary_object_type: common_enums::EventObjectType,
+    pub is_webhook_notified: bool,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// Merges the source customers into the customer identified in the path
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerMergeRequest {
+    /// The customers to merge, their payment methods, mandates, addresses and payments are moved
+    /// to the target customer and their identifiers redirect to it afterwards
+    #[schema(value_type = Vec<String>, example = json!(["cus_y3oqhf46pyzuxjbcn2giaqnb44"]))]
+    pub source_customer_ids: Vec<id_type::CustomerId>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerMergeRequestInternal {
+    pub customer_id: id_type::CustomerId,
+    pub request: CustomerMergeRequest,
+}
+
+/// The result of merging customers, also sent as the content of the `customer_merged` webhook
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerMergeResponse {
+    /// The identifier of the merge
+    #[schema(example = "cmg_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merge_id: String,
+    /// The customer the source customers were merged into
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The customers merged into the target customer, which now redirect to it
+    #[schema(value_type = Vec<String>)]
+    pub merged_customer_ids: Vec<id_type::CustomerId>,
+    /// A timestamp (ISO 8601 code) that determines when the customers were merged
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub merged_at: time::PrimitiveDateTime,
+}
+
+/// Parameters of the search for duplicate customers
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerDuplicatesRequest {
+    /// The maximum number of groups of duplicates to return
+    #[schema(example = 20)]
+    pub limit: Option<u16>,
+}
+
+/// Customers sharing the same email address or phone number
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDuplicateGroup {
+    /// The attribute shared by the customers
+    #[schema(value_type = CustomerMatchKeyType)]
+    pub match_type: common_enums::CustomerMatchKeyType,
+    /// The customers sharing the attribute
+    #[schema(value_type = Vec<String>)]
+    pub customer_ids: Vec<id_type::CustomerId>,
+}
+
+/// Groups of customers that are likely to be the same person
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDuplicatesResponse {
+    /// The groups of duplicate customers
+    pub data: Vec<CustomerDuplicateGroup>,
+}
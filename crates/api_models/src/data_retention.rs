@@ -0,0 +1,139 @@
+use common_enums::{ArchiveFileFormat, DataPurgeMode, DataRetentionEntity};
+use common_utils::id_type;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataRetentionPolicyRequest {
+    /// The number of days the records are kept in the database before being archived
+    #[schema(example = 365, minimum = 1)]
+    pub retention_days: u32,
+
+    /// The format of the archive files. Defaults to `ndjson`
+    #[schema(value_type = Option<ArchiveFileFormat>)]
+    pub file_format: Option<ArchiveFileFormat>,
+
+    /// How the archived records are removed from the database. Defaults to `delete`
+    #[schema(value_type = Option<DataPurgeMode>)]
+    pub purge_mode: Option<DataPurgeMode>,
+
+    /// The number of records exported to a single archive file. Defaults to 1000
+    #[schema(example = 1000, minimum = 1)]
+    pub batch_size: Option<u32>,
+
+    /// Whether the records are archived. Defaults to `true`
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct DataRetentionEntityPath {
+    /// The table the policy applies to
+    #[schema(value_type = DataRetentionEntity)]
+    pub entity: DataRetentionEntity,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DataRetentionPolicyUpsertRequest {
+    /// The table the policy applies to
+    #[schema(value_type = DataRetentionEntity)]
+    pub entity: DataRetentionEntity,
+
+    #[serde(flatten)]
+    pub policy: DataRetentionPolicyRequest,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DataRetentionPolicyResponse {
+    /// The table the policy applies to
+    #[schema(value_type = DataRetentionEntity)]
+    pub entity: DataRetentionEntity,
+
+    /// The number of days the records are kept in the database before being archived
+    pub retention_days: u32,
+
+    /// The format of the archive files
+    #[schema(value_type = ArchiveFileFormat)]
+    pub file_format: ArchiveFileFormat,
+
+    /// How the archived records are removed from the database
+    #[schema(value_type = DataPurgeMode)]
+    pub purge_mode: DataPurgeMode,
+
+    /// The number of records exported to a single archive file
+    pub batch_size: u32,
+
+    /// Whether the records are archived
+    pub is_enabled: bool,
+
+    /// The number of records archived under the policy so far
+    pub archived_count: i64,
+
+    /// The time at which the last archival run of the policy completed
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_archived_at: Option<PrimitiveDateTime>,
+
+    /// The time at which the policy was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the policy was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DataRetentionPolicyListResponse {
+    /// The retention policies of the tenant
+    pub policies: Vec<DataRetentionPolicyResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DataRetentionPolicyDeleteResponse {
+    /// The table the deleted policy applied to
+    #[schema(value_type = DataRetentionEntity)]
+    pub entity: DataRetentionEntity,
+
+    /// Whether the policy was deleted. The records archived under the policy remain retrievable
+    pub deleted: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ArchivedPaymentRetrieveRequest {
+    /// The identifier for the archived payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ArchivedPaymentResponse {
+    /// The identifier for the archived payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+
+    /// The archived records of the payment, oldest first
+    pub records: Vec<ArchivedRecordResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ArchivedRecordResponse {
+    /// The table the record was archived from
+    #[schema(value_type = DataRetentionEntity)]
+    pub entity: DataRetentionEntity,
+
+    /// The identifier for the record in its table
+    pub record_id: String,
+
+    /// The time at which the record was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the record was archived
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub archived_at: PrimitiveDateTime,
+
+    /// The record, in the layout of its table at the time it was archived
+    #[schema(value_type = Object)]
+    pub data: serde_json::Value,
+}
@@ -1,13 +1,20 @@
 use std::collections::HashMap;
 
 use common_utils::types::{StringMinorUnit, TimeRange};
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
 use hyperswitch_masking::{Deserialize, Serialize};
 use serde::de::Error;
 use smithy::SmithyModel;
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::enums::{Currency, DisputeStage, DisputeStatus};
+use super::enums::{
+    Currency, DisputeAutomatedActionStatus, DisputeEvidenceSource, DisputeRuleAction, DisputeStage,
+    DisputeStatus,
+};
 use crate::{admin::MerchantConnectorInfo, files};
 
 #[derive(Clone, Debug, Serialize, ToSchema)]
@@ -262,6 +269,186 @@ pub struct DisputeRetrieveBody {
     pub force_sync: Option<bool>,
 }
 
+/// Static evidence which is included as is in the evidence assembled from a template
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidenceTemplateText {
+    /// An explanation of how and when the customer was shown the cancellation policy
+    pub cancellation_policy_disclosure: Option<String>,
+    /// A justification for why the customer's subscription was not canceled
+    pub cancellation_rebuttal: Option<String>,
+    /// A description of the product or service which was sold
+    pub product_description: Option<String>,
+    /// Documentation demonstrating that the customer was shown the refund policy prior to purchase
+    pub refund_policy_disclosure: Option<String>,
+    /// A justification for why the customer is not entitled to a refund
+    pub refund_refusal_explanation: Option<String>,
+    /// Any additional evidence or statements
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidenceTemplateCreateRequest {
+    /// A name for the template
+    #[schema(max_length = 255, example = "Fraudulent transactions")]
+    pub name: String,
+
+    /// The connector for which the template is applicable. The template is applicable to disputes of all connectors if not provided
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The connector reason codes of the disputes for which the template is applicable. The template is used as the default template if no reason codes are provided
+    #[schema(example = json!(["fraudulent", "10.4"]))]
+    #[serde(default)]
+    pub reason_codes: Vec<String>,
+
+    /// The sources from which evidence is gathered when the evidence of a dispute is assembled
+    #[schema(value_type = Vec<DisputeEvidenceSource>)]
+    pub evidence_sources: Vec<DisputeEvidenceSource>,
+
+    /// Static evidence which is included as is in the assembled evidence
+    pub text_evidence: Option<DisputeEvidenceTemplateText>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeEvidenceTemplateResponse {
+    /// The identifier for the template
+    #[schema(example = "det_2b3ahVcG4K4sG5xQ9nGdI0")]
+    pub template_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the profile to which the template is restricted
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The name of the template
+    pub name: String,
+
+    /// The connector for which the template is applicable
+    pub connector: Option<String>,
+
+    /// The connector reason codes of the disputes for which the template is applicable
+    pub reason_codes: Vec<String>,
+
+    /// The sources from which evidence is gathered
+    #[schema(value_type = Vec<DisputeEvidenceSource>)]
+    pub evidence_sources: Vec<DisputeEvidenceSource>,
+
+    /// Static evidence which is included as is in the assembled evidence
+    pub text_evidence: Option<DisputeEvidenceTemplateText>,
+
+    /// Time when the template was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DisputeEvidenceTemplateId {
+    /// The identifier for the template
+    pub template_id: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidenceAssembleRequest {
+    /// The identifier for the dispute
+    #[serde(skip_deserializing)]
+    pub dispute_id: String,
+
+    /// The template to be used for assembling the evidence. The template matching the connector and the reason code of the dispute is used if not provided
+    pub template_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeEvidenceAssembleResponse {
+    /// The identifier for the dispute
+    pub dispute_id: String,
+
+    /// The template which was used for assembling the evidence
+    pub template_id: String,
+
+    /// The sources from which evidence was found
+    #[schema(value_type = Vec<DisputeEvidenceSource>)]
+    pub evidence_sources: Vec<DisputeEvidenceSource>,
+
+    /// The assembled evidence, which can be reviewed and submitted with the submit evidence API
+    pub evidence: SubmitEvidenceRequest,
+
+    /// The deadline by which the evidence has to be submitted
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+}
+
+/// The decision of the dispute rules of a profile
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisputeRuleOutput {
+    /// The action to be taken on the dispute
+    pub action: DisputeRuleAction,
+}
+
+/// Dispute rules are evaluated against the amount and the currency of the dispute, the
+/// authentication type of the disputed payment and the following metadata keys: `connector`,
+/// `reason_code`, `dispute_stage` and `three_ds_authenticated`.
+impl EuclidDirFilter for DisputeRuleOutput {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::AuthenticationType,
+        DirKeyKind::MetaData,
+    ];
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeRulesRequest {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: Option<String>,
+    pub program: Program<DisputeRuleOutput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisputeRulesRecord {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub program: Program<DisputeRuleOutput>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeAutomatedActionResponse {
+    /// The identifier for the automated action
+    pub action_id: String,
+
+    /// The identifier for the dispute
+    pub dispute_id: String,
+
+    /// The name of the dispute rule which decided the action
+    pub rule_name: Option<String>,
+
+    /// The action taken on the dispute
+    #[schema(value_type = DisputeRuleAction)]
+    pub action: DisputeRuleAction,
+
+    /// The outcome of the action
+    #[schema(value_type = DisputeAutomatedActionStatus)]
+    pub status: DisputeAutomatedActionStatus,
+
+    /// The reason for the failure of the action
+    pub error_message: Option<String>,
+
+    /// The time at which the action was taken
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
 fn parse_comma_separated<'de, D, T>(v: D) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -1,10 +1,13 @@
+pub mod account_updater;
 pub mod apple_pay_certificates_migration;
 pub mod chat;
 pub mod connector_onboarding;
 pub mod customer;
+pub mod data_retention;
 pub mod dispute;
 pub mod external_service_auth;
 pub mod gsm;
+pub mod ledger;
 mod locker_migration;
 pub mod payment;
 #[cfg(feature = "payouts")]
@@ -15,6 +18,8 @@ pub mod refund;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
 pub mod routing;
+pub mod settlement_recon;
+pub mod tenant_registry;
 pub mod user;
 pub mod user_role;
 use common_utils::{
@@ -98,6 +103,8 @@ impl_api_event_type!(
         ToggleKVRequest,
         ToggleAllKVRequest,
         ToggleAllKVResponse,
+        StorageSchemeMigrationRequest,
+        StorageSchemeMigrationResponse,
         MerchantAccountDeleteResponse,
         MerchantAccountUpdate,
         CardInfoResponse,
@@ -146,6 +153,9 @@ impl_api_event_type!(
         GetDisputeFilterRequest,
         DisputeFiltersResponse,
         GetDisputeMetricRequest,
+        GetSettlementReconFilterRequest,
+        SettlementReconFiltersResponse,
+        GetSettlementReconMetricRequest,
         SankeyResponse,
         OrganizationResponse,
         OrganizationCreateRequest,
@@ -196,6 +206,12 @@ impl<T> ApiEventMetric for DisputesMetricsResponse<T> {
     }
 }
 
+impl<T> ApiEventMetric for SettlementReconMetricsResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl<T> ApiEventMetric for AuthEventMetricsResponse<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Miscellaneous)
@@ -0,0 +1,42 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::account_updater::{
+    AccountUpdaterBatchId, AccountUpdaterBatchListConstraints, AccountUpdaterBatchListResponse,
+    AccountUpdaterBatchResponse, AccountUpdaterEnableRequest, AccountUpdaterEnrollmentResponse,
+};
+
+impl ApiEventMetric for AccountUpdaterEnableRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::AccountUpdater)
+    }
+}
+
+impl ApiEventMetric for AccountUpdaterEnrollmentResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::AccountUpdater)
+    }
+}
+
+impl ApiEventMetric for AccountUpdaterBatchListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::AccountUpdater)
+    }
+}
+
+impl ApiEventMetric for AccountUpdaterBatchId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::AccountUpdater)
+    }
+}
+
+impl ApiEventMetric for AccountUpdaterBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::AccountUpdater)
+    }
+}
+
+impl ApiEventMetric for AccountUpdaterBatchListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::AccountUpdater)
+    }
+}
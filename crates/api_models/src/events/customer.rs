@@ -1,5 +1,11 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
+#[cfg(feature = "v1")]
+use crate::customers::{
+    CustomerDataRequestId, CustomerDataRequestListResponse, CustomerDataRequestResponse,
+    CustomerDuplicatesRequest, CustomerDuplicatesResponse, CustomerMergeRequestInternal,
+    CustomerMergeResponse,
+};
 use crate::customers::{
     CustomerDeleteResponse, CustomerListRequestWithConstraints, CustomerListResponse,
     CustomerRequest, CustomerResponse, CustomerUpdateRequestInternal,
@@ -104,3 +110,60 @@ impl ApiEventMetric for CustomerListResponse {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDataRequestResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDataRequestListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDataRequestId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDuplicatesRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDuplicatesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
@@ -0,0 +1,56 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+#[cfg(feature = "v1")]
+use crate::data_retention::{ArchivedPaymentResponse, ArchivedPaymentRetrieveRequest};
+use crate::data_retention::{
+    DataRetentionEntityPath, DataRetentionPolicyDeleteResponse, DataRetentionPolicyListResponse,
+    DataRetentionPolicyResponse, DataRetentionPolicyUpsertRequest,
+};
+
+impl ApiEventMetric for DataRetentionPolicyUpsertRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for DataRetentionEntityPath {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for DataRetentionPolicyResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for DataRetentionPolicyListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for DataRetentionPolicyDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for ArchivedPaymentRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for ArchivedPaymentResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
@@ -1,8 +1,11 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
-    DisputeRetrieveRequest, DisputesAggregateResponse, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeEvidenceAssembleRequest, DisputeEvidenceAssembleResponse,
+    DisputeEvidenceTemplateCreateRequest, DisputeEvidenceTemplateId,
+    DisputeEvidenceTemplateResponse, DisputeResponse, DisputeResponsePaymentsRetrieve,
+    DisputeRetrieveRequest, DisputeRulesRecord, DisputeRulesRequest, DisputesAggregateResponse,
+    SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -48,3 +51,57 @@ impl ApiEventMetric for DisputesAggregateResponse {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl ApiEventMetric for DisputeEvidenceAssembleRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for DisputeEvidenceAssembleResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for DisputeEvidenceTemplateCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DisputeEvidenceTemplate { template_id: None })
+    }
+}
+
+impl ApiEventMetric for DisputeEvidenceTemplateId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DisputeEvidenceTemplate {
+            template_id: Some(self.template_id.clone()),
+        })
+    }
+}
+
+impl ApiEventMetric for DisputeEvidenceTemplateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DisputeEvidenceTemplate {
+            template_id: Some(self.template_id.clone()),
+        })
+    }
+}
+
+impl ApiEventMetric for DisputeRulesRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for DisputeRulesRecord {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::ledger::{
+    LedgerBalanceRequest, LedgerBalanceResponse, LedgerFeeScheduleCreateRequest,
+    LedgerFeeScheduleId, LedgerFeeScheduleResponse, LedgerStatementRequest,
+    LedgerStatementResponse,
+};
+
+impl ApiEventMetric for LedgerFeeScheduleCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Ledger)
+    }
+}
+
+impl ApiEventMetric for LedgerFeeScheduleId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Ledger)
+    }
+}
+
+impl ApiEventMetric for LedgerFeeScheduleResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Ledger)
+    }
+}
+
+impl ApiEventMetric for LedgerBalanceRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Ledger)
+    }
+}
+
+impl ApiEventMetric for LedgerBalanceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Ledger)
+    }
+}
+
+impl ApiEventMetric for LedgerStatementRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Ledger)
+    }
+}
+
+impl ApiEventMetric for LedgerStatementResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Ledger)
+    }
+}
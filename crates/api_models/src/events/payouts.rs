@@ -1,7 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
+    PayoutActionRequest, PayoutBatchCreateRequest, PayoutBatchResponse, PayoutBatchRetrieveQuery,
+    PayoutBatchReviewRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
     PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListFiltersV2,
     PayoutListResponse, PayoutRetrieveRequest, PayoutsAggregateResponse,
     PayoutsManualUpdateRequest, PayoutsManualUpdateResponse,
@@ -98,3 +99,35 @@ impl ApiEventMetric for PayoutsManualUpdateResponse {
         })
     }
 }
+
+impl ApiEventMetric for PayoutBatchCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            payout_batch_id: None,
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchReviewRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            payout_batch_id: None,
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchRetrieveQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            payout_batch_id: None,
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            payout_batch_id: Some(self.payout_batch_id.clone()),
+        })
+    }
+}
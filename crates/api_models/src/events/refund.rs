@@ -6,7 +6,8 @@ use crate::refunds::{
 };
 #[cfg(feature = "v1")]
 use crate::refunds::{
-    RefundManualUpdateRequest, RefundRequest, RefundUpdateRequest, RefundsRetrieveRequest,
+    BulkRefundRequest, BulkRefundResponse, RefundManualUpdateRequest, RefundRequest,
+    RefundUpdateRequest, RefundsRetrieveRequest,
 };
 
 #[cfg(feature = "v1")]
@@ -111,3 +112,21 @@ impl ApiEventMetric for RefundListFilters {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for BulkRefundRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BulkRefund {
+            bulk_refund_id: None,
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for BulkRefundResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BulkRefund {
+            bulk_refund_id: Some(self.bulk_refund_id.clone()),
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::settlement_recon::{
+    SettlementExceptionResolveRequest, SettlementReconLineId, SettlementReconLineListConstraints,
+    SettlementReconLineListResponse, SettlementReconLineResponse, SettlementReportCreateRequest,
+    SettlementReportId, SettlementReportListConstraints, SettlementReportListResponse,
+    SettlementReportResponse,
+};
+
+impl ApiEventMetric for SettlementReportCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementReportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementReportId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementReportListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementReportListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementReconLineListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementReconLineResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementReconLineListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementReconLineId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
+
+impl ApiEventMetric for SettlementExceptionResolveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SettlementReconciliation)
+    }
+}
@@ -0,0 +1,43 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::tenant_registry::{
+    TenantCreateRequest, TenantDeleteResponse, TenantIdPath, TenantListResponse, TenantResponse,
+};
+
+impl ApiEventMetric for TenantCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Tenant {
+            tenant_id: self.tenant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for TenantIdPath {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Tenant {
+            tenant_id: self.tenant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for TenantResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Tenant {
+            tenant_id: self.tenant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for TenantDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Tenant {
+            tenant_id: self.tenant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for TenantListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,160 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::enums::FrmPreferredFlowTypes;
+
+/// The decision of the native fraud rules of a profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmRuleDecision {
+    #[default]
+    Accept,
+    Review,
+    Decline,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrmRuleOutput {
+    /// The decision to be taken on the payment
+    pub decision: FrmRuleDecision,
+}
+
+/// Fraud rules are evaluated against the amount, the currency, the payment method and the card
+/// of the payment, along with the following metadata keys: `risk_level` (`low`, `medium` or
+/// `high`) and one key per triggered signal (eg. `velocity`) with the value `true`.
+impl EuclidDirFilter for FrmRuleOutput {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::PaymentMethod,
+        DirKeyKind::PaymentMethodType,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::CardBin,
+        DirKeyKind::BillingCountry,
+        DirKeyKind::IssuerCountry,
+        DirKeyKind::MetaData,
+    ];
+}
+
+/// A signal which adds its weight to the risk score of the payment when it is triggered
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmSignalWeight {
+    pub weight: u8,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmEmailDomainSignal {
+    pub weight: u8,
+    /// Recently registered or disposable email domains
+    pub domains: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmVelocitySignal {
+    pub weight: u8,
+    /// Number of payments allowed from the same customer, email, IP address or card in the window
+    pub max_attempts: u32,
+    pub window_in_secs: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmAmountOutlierSignal {
+    pub weight: u8,
+    /// Payments above this multiple of the average amount of the currency are outliers
+    pub multiplier: u32,
+    /// Number of payments in the currency required before amounts are compared to the average
+    pub min_samples: u32,
+}
+
+/// Risk scores at which a payment moves to the `medium` and `high` risk levels
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmRiskLevelThresholds {
+    pub medium: u8,
+    pub high: u8,
+}
+
+impl Default for FrmRiskLevelThresholds {
+    fn default() -> Self {
+        Self {
+            medium: 30,
+            high: 60,
+        }
+    }
+}
+
+/// The signals scored by the native fraud engine. Signals which are not configured are skipped.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmSignalsConfig {
+    /// The issuing country of the card differs from the country of the customer's IP address,
+    /// which is read from the `ip_country` key of the `frm_metadata` of the payment
+    pub bin_ip_country_mismatch: Option<FrmSignalWeight>,
+    /// The domain of the customer's email is in the configured list
+    pub email_domain: Option<FrmEmailDomainSignal>,
+    pub velocity: Option<FrmVelocitySignal>,
+    pub amount_outlier: Option<FrmAmountOutlierSignal>,
+    /// The BIN or the extended BIN of the card is in the blocklist of the merchant
+    pub blocklist_proximity: Option<FrmSignalWeight>,
+    #[serde(default)]
+    pub risk_level_thresholds: FrmRiskLevelThresholds,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmRulesRequest {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub signals: FrmSignalsConfig,
+    pub program: Program<FrmRuleOutput>,
+    /// Whether the rules are evaluated before (`pre`) or after (`post`) the authorization
+    pub preferred_flow_type: Option<FrmPreferredFlowTypes>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrmRulesRecord {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub signals: FrmSignalsConfig,
+    pub program: Program<FrmRuleOutput>,
+    pub preferred_flow_type: FrmPreferredFlowTypes,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl ApiEventMetric for FrmRulesRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for FrmRulesRecord {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -0,0 +1,198 @@
+use common_enums::{Currency, LedgerAccount, LedgerPostingDirection, PaymentMethod};
+use common_utils::types::{MinorUnit, TimeRange};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LedgerFeeScheduleCreateRequest {
+    /// The connector to which the fee schedule applies. The fee schedule applies to all connectors if not provided
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The payment method to which the fee schedule applies. The fee schedule applies to all payment methods if not provided
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<PaymentMethod>,
+
+    /// The currency to which the fee schedule applies. The fee schedule applies to all currencies if not provided
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<Currency>,
+
+    /// The fixed fee charged per captured payment, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 30)]
+    #[serde(default)]
+    pub fixed_fee: MinorUnit,
+
+    /// The percentage fee charged on the captured amount, in basis points
+    #[schema(example = 290, maximum = 10000)]
+    #[serde(default)]
+    pub percentage_fee_basis_points: u16,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LedgerFeeScheduleResponse {
+    /// The identifier for the fee schedule
+    #[schema(example = "lfs_2b3ahVcG4K4sG5xQ9nGdI0")]
+    pub fee_schedule_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The connector to which the fee schedule applies
+    pub connector: Option<String>,
+
+    /// The payment method to which the fee schedule applies
+    #[schema(value_type = Option<PaymentMethod>)]
+    pub payment_method: Option<PaymentMethod>,
+
+    /// The currency to which the fee schedule applies
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<Currency>,
+
+    /// The fixed fee charged per captured payment
+    #[schema(value_type = i64)]
+    pub fixed_fee: MinorUnit,
+
+    /// The percentage fee charged on the captured amount, in basis points
+    pub percentage_fee_basis_points: i32,
+
+    /// Time when the fee schedule was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LedgerFeeScheduleId {
+    /// The identifier for the fee schedule
+    pub fee_schedule_id: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LedgerBalanceRequest {
+    /// The identifier for the business profile. Balances of all the profiles are returned if not provided
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The currency for which the balances are needed. Balances of all the currencies are returned if not provided
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<Currency>,
+
+    /// The time range of the postings which are included in the balances
+    #[serde(flatten)]
+    pub time_range: Option<TimeRange>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LedgerAccountBalance {
+    /// The ledger account
+    #[schema(value_type = LedgerAccount)]
+    pub account: LedgerAccount,
+
+    /// The balance of the account, positive on the normal side of the account
+    #[schema(value_type = i64)]
+    pub balance: MinorUnit,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LedgerCurrencyBalance {
+    /// The currency of the balances
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+
+    /// The balances of the individual ledger accounts
+    pub accounts: Vec<LedgerAccountBalance>,
+
+    /// The amount which is yet to be settled by the connectors, net of fees, refunds, chargebacks and payouts
+    #[schema(value_type = i64)]
+    pub net_settlement: MinorUnit,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LedgerBalanceResponse {
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the business profile, if the balances are restricted to a profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The balances per currency
+    pub balances: Vec<LedgerCurrencyBalance>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LedgerStatementRequest {
+    /// The identifier for the business profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The currency of the postings
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<Currency>,
+
+    /// The ledger account of the postings
+    #[schema(value_type = Option<LedgerAccount>)]
+    pub account: Option<LedgerAccount>,
+
+    /// Limit on the number of postings to return
+    pub limit: Option<u32>,
+
+    /// The starting point within the list of postings
+    pub offset: Option<u32>,
+
+    /// The time range of the postings
+    #[serde(flatten)]
+    pub time_range: Option<TimeRange>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LedgerStatementLine {
+    /// The identifier for the posting
+    pub posting_id: String,
+
+    /// The identifier for the journal entry to which the posting belongs
+    pub entry_id: String,
+
+    /// The identifier for the business profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The connector through which the transaction was processed
+    pub connector: Option<String>,
+
+    /// The ledger account
+    #[schema(value_type = LedgerAccount)]
+    pub account: LedgerAccount,
+
+    /// Whether the account was debited or credited
+    #[schema(value_type = LedgerPostingDirection)]
+    pub direction: LedgerPostingDirection,
+
+    /// The amount of the posting
+    #[schema(value_type = i64)]
+    pub amount: MinorUnit,
+
+    /// The currency of the posting
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+
+    /// Time when the posting was made
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LedgerStatementResponse {
+    /// The number of postings included in the statement
+    pub count: usize,
+
+    /// The postings, ordered by the time at which they were made
+    pub data: Vec<LedgerStatementLine>,
+}
@@ -1,8 +1,10 @@
+pub mod account_updater;
 pub mod admin;
 pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
 pub mod authentication;
+pub mod billing_catalog;
 pub mod blocklist;
 pub mod card_issuer;
 pub mod cards_info;
@@ -13,6 +15,7 @@ pub mod connector_onboarding;
 pub mod consts;
 pub mod currency;
 pub mod customers;
+pub mod data_retention;
 pub mod disputes;
 pub mod enums;
 pub mod ephemeral_key;
@@ -22,8 +25,10 @@ pub mod events;
 pub mod external_service_auth;
 pub mod feature_matrix;
 pub mod files;
+pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod ledger;
 pub mod locker_migration;
 pub mod mandates;
 pub mod merchant_connector_webhook_management;
@@ -48,9 +53,11 @@ pub mod relay;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery_data_backfill;
 pub mod routing;
+pub mod settlement_recon;
 pub mod subscription;
 pub mod superposition_sdk_config;
 pub mod surcharge_decision_configs;
+pub mod tenant_registry;
 pub mod three_ds_decision_rule;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
@@ -1117,6 +1117,48 @@ pub struct PaymentMethodResponse {
     pub client_secret: Option<String>,
 }
 
+/// The payment method sent as the content of the payment method webhooks
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodWebhookResponse {
+    /// The unique identifier of the payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIg")]
+    pub payment_method_id: String,
+
+    /// Unique identifier for a merchant
+    #[schema(example = "merchant_1671528864", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The unique identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The type of payment method
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+
+    /// This is a sub-category of payment method
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The status of the payment method
+    #[schema(value_type = PaymentMethodStatus, example = "active")]
+    pub status: api_enums::PaymentMethodStatus,
+
+    /// The card details of the payment method, after the update
+    pub card: Option<CardDetailFromLocker>,
+
+    /// The result of the account updater inquiry which updated the payment method, if any
+    #[schema(value_type = Option<AccountUpdaterResult>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_updater_result: Option<api_enums::AccountUpdaterResult>,
+
+    /// The time at which the payment method was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2024-02-24T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified: time::PrimitiveDateTime,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct CustomerPaymentMethodUpdateResponse {
@@ -3155,6 +3197,63 @@ pub struct DefaultPaymentMethod {
     pub payment_method_id: String,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct CustomerExpiringPaymentMethodsRequest {
+    /// The unique identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    #[serde(skip_deserializing)]
+    pub customer_id: id_type::CustomerId,
+
+    /// Lists the cards expiring within these many days, defaults to the earliest pre-expiry
+    /// notification configured
+    #[schema(example = 30)]
+    pub within_days: Option<u16>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CustomerExpiringPaymentMethodsResponse {
+    /// The unique identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The window the payment methods were listed for, in days
+    #[schema(example = 30)]
+    pub within_days: u16,
+
+    /// The active cards of the customer expiring within the window, the earliest expiry first
+    pub data: Vec<ExpiringPaymentMethod>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct ExpiringPaymentMethod {
+    /// The unique identifier of the payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIg")]
+    pub payment_method_id: String,
+
+    /// This is a sub-category of payment method
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The card details of the payment method
+    pub card: CardDetailFromLocker,
+
+    /// The time at which the card stops being valid, the end of its expiry month
+    #[schema(value_type = PrimitiveDateTime, example = "2024-03-01T00:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+
+    /// Whole days left until the card expires, zero for a card which has expired but is yet to be
+    /// marked inactive
+    #[schema(example = 6)]
+    pub expires_in_days: i64,
+
+    /// Whether the payment method is the default payment method of the customer
+    pub is_default: bool,
+}
+
 //------------------------------------------------TokenizeService------------------------------------------------
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct TokenizePayloadEncrypted {
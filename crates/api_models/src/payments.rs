@@ -11949,7 +11949,7 @@ pub struct RetrievePaymentLinkRequest {
     pub client_secret: Option<String>,
 }
 
-#[derive(Clone, Debug, serde::Serialize, PartialEq, ToSchema)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
 pub struct PaymentLinkResponse {
     /// URL for rendering the open payment link
     pub link: String,
@@ -1224,3 +1224,214 @@ pub struct PayoutsManualUpdateResponse {
     /// A unique identifier for a payout provided by the connector
     pub connector_payout_id: Option<String>,
 }
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The identifier of a file uploaded through the files API with purpose `payout_batch`. The file must be a CSV or a JSON array of payout batch items. Exactly one of `file_id` or `items` must be provided.
+    #[schema(example = "file_2b3ahVcG4K4sG5xQ9nGdI0")]
+    pub file_id: Option<String>,
+
+    /// The list of payouts to be created as part of this batch. Exactly one of `file_id` or `items` must be provided.
+    pub items: Option<Vec<PayoutBatchItemRequest>>,
+
+    /// The business profile to use for all payouts in this batch
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// A description of the batch
+    #[schema(example = "Seller payouts for 2024-01-31")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchItemRequest {
+    /// Unique identifier for the payout that will be created for this item. It is generated if not provided.
+    #[schema(value_type = Option<String>, min_length = 30, max_length = 30)]
+    pub payout_id: Option<id_type::PayoutId>,
+
+    /// The payout amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1000)]
+    pub amount: common_utils::types::MinorUnit,
+
+    /// The currency of the payout
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The identifier of the customer receiving the payout
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier of the saved payout method of the customer to which the payout is sent
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payout_method_id: String,
+
+    /// The payout type
+    #[schema(value_type = Option<PayoutType>, example = "bank")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// The list of connectors to be used for routing this payout
+    #[schema(value_type = Option<Vec<PayoutConnectors>>, example = json!(["wise", "adyen"]))]
+    pub connector: Option<Vec<api_enums::PayoutConnectors>>,
+
+    /// The send method for processing the payout
+    #[schema(value_type = Option<PayoutSendPriority>, example = "instant")]
+    pub priority: Option<api_enums::PayoutSendPriority>,
+
+    /// Set to true to fulfill the payout immediately after creation
+    #[schema(value_type = Option<bool>, example = true)]
+    pub auto_fulfill: Option<bool>,
+
+    /// A description of the payout
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl PayoutBatchItemRequest {
+    /// Builds the request used for creating and confirming the payout of this item
+    pub fn to_payout_create_request(
+        &self,
+        payout_id: id_type::PayoutId,
+        profile_id: id_type::ProfileId,
+    ) -> PayoutCreateRequest {
+        PayoutCreateRequest {
+            payout_id: Some(payout_id),
+            amount: Some(self.amount.into()),
+            currency: Some(self.currency),
+            customer_id: Some(self.customer_id.clone()),
+            payout_method_id: Some(self.payout_method_id.clone()),
+            payout_type: self.payout_type,
+            connector: self.connector.clone(),
+            priority: self.priority,
+            auto_fulfill: self.auto_fulfill,
+            description: self.description.clone(),
+            metadata: self.metadata.clone(),
+            profile_id: Some(profile_id),
+            confirm: Some(true),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchReviewAction {
+    /// Approve the batch and start executing its payouts
+    Approve,
+    /// Reject the batch, no payouts will be created
+    Reject,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchReviewRequest {
+    /// The review decision for the batch
+    pub action: PayoutBatchReviewAction,
+
+    /// The reason for rejecting the batch
+    #[schema(example = "Amounts do not match the settlement report")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct PayoutBatchRetrieveQuery {
+    /// Set to true to include the individual items of the batch in the response
+    #[serde(default)]
+    pub expand_items: bool,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// The identifier of the payout batch
+    #[schema(example = "payout_batch_2b3ahVcG4K4sG5xQ9nGdI0")]
+    pub payout_batch_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The business profile used for the payouts in this batch
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The current status of the batch
+    #[schema(value_type = PayoutBatchStatus, example = "pending_approval")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// A description of the batch
+    pub description: Option<String>,
+
+    /// The identifier of the file the batch was created from
+    pub file_id: Option<String>,
+
+    /// The total number of payouts in the batch
+    pub total_count: i32,
+
+    /// The number of payouts that were submitted successfully
+    pub submitted_count: i32,
+
+    /// The number of payouts that failed
+    pub failed_count: i32,
+
+    /// The entity that created the batch
+    pub created_by: Option<String>,
+
+    /// The entity that approved or rejected the batch
+    pub reviewed_by: Option<String>,
+
+    /// The reason provided when the batch was rejected
+    pub rejection_reason: Option<String>,
+
+    /// Time when the batch was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time when the batch was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+
+    /// The items of the batch, only populated when `expand_items` is set
+    pub items: Option<Vec<PayoutBatchItemResponse>>,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// The position of the item in the batch
+    pub item_index: i32,
+
+    /// The identifier of the payout created for this item
+    #[schema(value_type = String)]
+    pub payout_id: id_type::PayoutId,
+
+    /// The status of the item
+    #[schema(value_type = PayoutBatchItemStatus, example = "submitted")]
+    pub status: api_enums::PayoutBatchItemStatus,
+
+    /// The status of the payout created for this item
+    #[schema(value_type = Option<PayoutStatus>, example = "success")]
+    pub payout_status: Option<api_enums::PayoutStatus>,
+
+    /// The payout amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1000)]
+    pub amount: common_utils::types::MinorUnit,
+
+    /// The currency of the payout
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The identifier of the customer receiving the payout
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+
+    /// The error code if the payout could not be created
+    pub error_code: Option<String>,
+
+    /// The error message if the payout could not be created
+    pub error_message: Option<String>,
+}
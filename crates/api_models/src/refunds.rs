@@ -594,3 +594,116 @@ impl From<RefundStatus> for enums::RefundStatus {
         }
     }
 }
+
+#[cfg(feature = "v1")]
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BulkRefundRequest {
+    /// The list of refunds to be created as part of this job
+    pub items: Vec<BulkRefundItemRequest>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BulkRefundItemRequest {
+    /// The payment id against which the refund is to be initiated
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "pay_mbabizu24mvu3mela5njyhpit4",
+        value_type = String,
+    )]
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// Unique Identifier for the Refund. It is generated if not provided.
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "ref_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub refund_id: Option<String>,
+
+    /// Total amount for which the refund is to be initiated. If not provided, this will default to the full payment amount
+    #[schema(value_type = Option<i64>, minimum = 100, example = 6540)]
+    pub amount: Option<MinorUnit>,
+
+    /// Reason for the refund
+    #[schema(max_length = 255, example = "Customer returned the product")]
+    pub reason: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[cfg(feature = "v1")]
+impl BulkRefundItemRequest {
+    /// Builds the request used for creating the refund of this item
+    pub fn to_refund_request(&self, refund_id: String) -> RefundRequest {
+        RefundRequest {
+            payment_id: self.payment_id.clone(),
+            refund_id: Some(refund_id),
+            amount: self.amount,
+            reason: self.reason.clone(),
+            metadata: self.metadata.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct BulkRefundResponse {
+    /// The identifier of the bulk refund job
+    #[schema(example = "bulk_refund_2b3ahVcG4K4sG5xQ9nGdI0")]
+    pub bulk_refund_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The current status of the job
+    #[schema(value_type = BulkRefundStatus, example = "processing")]
+    pub status: enums::BulkRefundStatus,
+
+    /// The total number of refunds in the job
+    pub total_count: i32,
+
+    /// The number of refunds that were submitted successfully
+    pub submitted_count: i32,
+
+    /// The number of refunds that failed
+    pub failed_count: i32,
+
+    /// The number of refunds that are yet to be executed
+    pub pending_count: i32,
+
+    /// The entity that created the job
+    pub created_by: Option<String>,
+
+    /// Time when the job was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time when the job was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// A single row of the report of a bulk refund job
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkRefundReportRecord {
+    pub item_index: i32,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub refund_id: String,
+    pub amount: Option<MinorUnit>,
+    pub status: enums::BulkRefundItemStatus,
+    pub refund_status: Option<RefundStatus>,
+    pub connector: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
@@ -0,0 +1,252 @@
+use common_enums::{
+    Currency, ReconLineStatus, SettlementReportFormat, SettlementReportStatus,
+    SettlementTransactionType,
+};
+use common_utils::types::MinorUnit;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementReportCreateRequest {
+    /// The identifier of the settlement report file, uploaded through the files API with the purpose `settlement_report`
+    #[schema(example = "file_Mz8IKvpXC2CQ1z9uk0AF")]
+    pub file_id: String,
+
+    /// The connector which issued the settlement report
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    /// The format of the settlement report file
+    #[schema(value_type = Option<SettlementReportFormat>, example = "generic")]
+    #[serde(default)]
+    pub format: SettlementReportFormat,
+
+    /// Start of the capture period covered by the report. When the period is not declared, it is
+    /// derived from the settlement times of the lines of the report and the settlement lag
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub period_start: Option<PrimitiveDateTime>,
+
+    /// End of the capture period covered by the report, required along with `period_start`
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-11T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub period_end: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SettlementReportResponse {
+    /// The identifier for the settlement report
+    #[schema(example = "sr_2b3ahVcG4K4sG5xQ9nGdI0")]
+    pub report_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the business profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The connector which issued the settlement report
+    pub connector: String,
+
+    /// The identifier of the settlement report file
+    pub file_id: String,
+
+    /// The format of the settlement report file
+    #[schema(value_type = SettlementReportFormat)]
+    pub format: SettlementReportFormat,
+
+    /// The status of the reconciliation of the settlement report
+    #[schema(value_type = SettlementReportStatus)]
+    pub status: SettlementReportStatus,
+
+    /// Start of the capture period reconciled, either declared with the report or derived from
+    /// the settlement times of its lines
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub period_start: Option<PrimitiveDateTime>,
+
+    /// End of the capture period reconciled
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub period_end: Option<PrimitiveDateTime>,
+
+    /// The number of reconciled lines
+    pub total_count: i32,
+
+    /// The number of lines which matched a transaction of Hyperswitch
+    pub matched_count: i32,
+
+    /// The number of lines whose amount or currency differs from the transaction of Hyperswitch
+    pub amount_mismatch_count: i32,
+
+    /// The number of lines which settled a transaction unknown to Hyperswitch
+    pub missing_in_hyperswitch_count: i32,
+
+    /// The number of transactions of Hyperswitch which were not settled by the connector
+    pub missing_at_psp_count: i32,
+
+    /// The reason for which the reconciliation failed
+    pub error_message: Option<String>,
+
+    /// Time when the settlement report was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time when the settlement report was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SettlementReportId {
+    /// The identifier for the settlement report
+    pub report_id: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementReportListConstraints {
+    /// Limit on the number of settlement reports to return
+    pub limit: Option<u32>,
+
+    /// The starting point within the list of settlement reports
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SettlementReportListResponse {
+    /// The number of settlement reports included in the list
+    pub count: usize,
+
+    /// The settlement reports, latest first
+    pub data: Vec<SettlementReportResponse>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementReconLineListConstraints {
+    /// The identifier for the settlement report of the lines
+    pub report_id: Option<String>,
+
+    /// The connector of the lines
+    pub connector: Option<String>,
+
+    /// The reconciliation status of the lines
+    #[schema(value_type = Option<ReconLineStatus>)]
+    pub status: Option<ReconLineStatus>,
+
+    /// Whether the lines have been resolved
+    pub is_resolved: Option<bool>,
+
+    /// Limit on the number of lines to return
+    pub limit: Option<u32>,
+
+    /// The starting point within the list of lines
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SettlementReconLineResponse {
+    /// The identifier for the recon line
+    #[schema(example = "srl_2b3ahVcG4K4sG5xQ9nGdI0")]
+    pub line_id: String,
+
+    /// The identifier for the settlement report
+    pub report_id: String,
+
+    /// The identifier for the business profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The connector which issued the settlement report
+    pub connector: String,
+
+    /// The line of the settlement report file, absent for transactions which are missing at the connector
+    pub line_number: Option<i32>,
+
+    /// The type of the settled transaction
+    #[schema(value_type = SettlementTransactionType)]
+    pub transaction_type: SettlementTransactionType,
+
+    /// The identifier of the transaction at the connector
+    pub connector_transaction_id: Option<String>,
+
+    /// The reconciliation status of the line
+    #[schema(value_type = ReconLineStatus)]
+    pub status: ReconLineStatus,
+
+    /// The amount settled by the connector
+    #[schema(value_type = Option<i64>)]
+    pub settled_amount: Option<MinorUnit>,
+
+    /// The currency settled by the connector
+    #[schema(value_type = Option<Currency>)]
+    pub settled_currency: Option<Currency>,
+
+    /// The fee deducted by the connector
+    #[schema(value_type = Option<i64>)]
+    pub fee: Option<MinorUnit>,
+
+    /// The amount of the transaction in Hyperswitch
+    #[schema(value_type = Option<i64>)]
+    pub hyperswitch_amount: Option<MinorUnit>,
+
+    /// The currency of the transaction in Hyperswitch
+    #[schema(value_type = Option<Currency>)]
+    pub hyperswitch_currency: Option<Currency>,
+
+    /// The identifier for the payment
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+
+    /// The identifier for the payment attempt
+    pub attempt_id: Option<String>,
+
+    /// The identifier for the refund
+    pub refund_id: Option<String>,
+
+    /// Time when the transaction was settled by the connector
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<PrimitiveDateTime>,
+
+    /// Whether the exception has been resolved
+    pub is_resolved: bool,
+
+    /// The note recorded while resolving the exception
+    pub resolution_note: Option<String>,
+
+    /// Time when the exception was resolved
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub resolved_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SettlementReconLineListResponse {
+    /// The number of lines included in the list
+    pub count: usize,
+
+    /// The recon lines
+    pub data: Vec<SettlementReconLineResponse>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SettlementReconLineId {
+    /// The identifier for the recon line
+    pub line_id: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementExceptionResolveRequest {
+    /// A note describing how the exception was resolved
+    #[schema(example = "Fee adjustment confirmed with the connector")]
+    pub resolution_note: Option<String>,
+}
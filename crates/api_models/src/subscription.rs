@@ -19,7 +19,7 @@ use crate::{
         PaymentMethod, PaymentMethodType, PaymentType,
     },
     mandates::RecurringDetails,
-    payments::{Address, NextActionData, PaymentMethodDataRequest},
+    payments::{Address, NextActionData, PaymentLinkResponse, PaymentMethodDataRequest},
 };
 
 /// Request payload for creating a subscription.
@@ -40,6 +40,10 @@ pub struct CreateSubscriptionRequest {
     /// Optional coupon code applied to the subscription.
     pub coupon_code: Option<String>,
 
+    /// Number of units subscribed to, used by per unit and tiered prices. Defaults to 1.
+    #[schema(example = 1, minimum = 1)]
+    pub quantity: Option<u32>,
+
     /// customer ID associated with this subscription.
     pub customer_id: CustomerId,
 
@@ -337,6 +341,19 @@ pub struct CreateAndConfirmPaymentsRequestData {
     pub off_session: Option<bool>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CreatePaymentLinkRequestData {
+    pub amount: MinorUnit,
+    pub currency: Currency,
+    pub customer_id: Option<CustomerId>,
+    pub profile_id: Option<ProfileId>,
+    pub setup_future_usage: Option<FutureUsage>,
+    pub payment_link: bool,
+    /// Number of seconds after which the payment link expires
+    pub session_expiry: Option<u32>,
+    pub description: Option<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct PaymentResponseData {
     pub payment_id: PaymentId,
@@ -364,6 +381,7 @@ pub struct PaymentResponseData {
     pub payment_type: Option<PaymentType>,
     #[schema(value_type = Option<String>, example = "token_sxJdmpUnpNsJk5VWzcjl")]
     pub payment_token: Option<Secret<String>>,
+    pub payment_link: Option<PaymentLinkResponse>,
 }
 
 impl PaymentResponseData {
@@ -421,6 +439,10 @@ pub struct CreateAndConfirmSubscriptionRequest {
     /// Identifier for the coupon code for the subscription.
     pub coupon_code: Option<String>,
 
+    /// Number of units subscribed to, used by per unit and tiered prices. Defaults to 1.
+    #[schema(example = 1, minimum = 1)]
+    pub quantity: Option<u32>,
+
     /// Identifier for customer.
     pub customer_id: CustomerId,
 
@@ -515,8 +537,8 @@ pub struct Invoice {
     /// Identifier for the profile.
     pub profile_id: ProfileId,
 
-    /// Identifier for the merchant connector account.
-    pub merchant_connector_id: MerchantConnectorAccountId,
+    /// Identifier for the merchant connector account, absent for invoices raised by the native billing engine.
+    pub merchant_connector_id: Option<MerchantConnectorAccountId>,
 
     /// Identifier for the Payment.
     pub payment_intent_id: Option<PaymentId>,
@@ -549,6 +571,8 @@ pub struct UpdateSubscriptionRequest {
     pub plan_id: String,
     /// Identifier for the associated item_price_id for the subscription.
     pub item_price_id: String,
+    /// Number of units subscribed to, used by per unit and tiered prices.
+    pub quantity: Option<u32>,
 }
 
 impl ApiEventMetric for UpdateSubscriptionRequest {}
@@ -563,6 +587,9 @@ pub struct EstimateSubscriptionQuery {
 
     /// Identifier for the coupon code for the subscription.
     pub coupon_code: Option<String>,
+
+    /// Number of units subscribed to, used by per unit and tiered prices. Defaults to 1.
+    pub quantity: Option<u32>,
 }
 
 impl ApiEventMetric for EstimateSubscriptionQuery {}
@@ -795,3 +822,113 @@ impl ApiEventMetric for ResumeSubscriptionRequest {}
 impl ApiEventMetric for ResumeSubscriptionResponse {}
 impl ApiEventMetric for CancelSubscriptionRequest {}
 impl ApiEventMetric for CancelSubscriptionResponse {}
+
+/// Maximum number of payment retries a dunning policy can schedule
+pub const MAX_DUNNING_RETRIES: usize = 10;
+
+/// Request payload for configuring the dunning policy of a profile.
+///
+/// The policy decides how failed subscription invoice payments are retried, whether the customer
+/// is reminded to update their payment method, and what happens once every retry has failed.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct DunningPolicyRequest {
+    /// Days after the initial payment failure on which the payment is retried, in increasing order
+    #[schema(example = json!([1, 3, 7]))]
+    pub retry_schedule_days: Vec<u16>,
+    /// Whether the customer is emailed a link to update their payment method after each failure
+    #[serde(default = "default_send_reminders")]
+    pub send_reminders: bool,
+    /// Action applied to the subscription once every retry has failed
+    pub final_action: DunningFinalAction,
+}
+
+fn default_send_reminders() -> bool {
+    true
+}
+
+impl DunningPolicyRequest {
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        fp_utils::when(self.retry_schedule_days.len() > MAX_DUNNING_RETRIES, || {
+            Err(ValidationError::InvalidValue {
+                message: format!(
+                    "retry_schedule_days cannot have more than {MAX_DUNNING_RETRIES} entries"
+                ),
+            }
+            .into())
+        })?;
+
+        fp_utils::when(self.retry_schedule_days.contains(&0), || {
+            Err(ValidationError::InvalidValue {
+                message: "retry_schedule_days must be greater than zero".to_string(),
+            }
+            .into())
+        })?;
+
+        fp_utils::when(
+            self.retry_schedule_days
+                .windows(2)
+                .any(|days| matches!(days, [previous, next] if next <= previous)),
+            || {
+                Err(ValidationError::InvalidValue {
+                    message: "retry_schedule_days must be in strictly increasing order".to_string(),
+                }
+                .into())
+            },
+        )
+    }
+}
+
+/// Action applied to a subscription once dunning has exhausted all payment retries
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DunningFinalAction {
+    /// Pause the subscription
+    Pause,
+    /// Cancel the subscription
+    Cancel,
+    /// Keep the subscription and mark it as unpaid
+    MarkUnpaid,
+}
+
+/// Dunning policy configured for a profile.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct DunningPolicyRecord {
+    /// Identifier of the profile the policy belongs to
+    #[schema(value_type = String)]
+    pub profile_id: ProfileId,
+    /// Days after the initial payment failure on which the payment is retried
+    pub retry_schedule_days: Vec<u16>,
+    /// Whether the customer is emailed a link to update their payment method after each failure
+    pub send_reminders: bool,
+    /// Action applied to the subscription once every retry has failed
+    pub final_action: DunningFinalAction,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl ApiEventMetric for DunningPolicyRequest {}
+impl ApiEventMetric for DunningPolicyRecord {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dunning_policy(retry_schedule_days: Vec<u16>) -> DunningPolicyRequest {
+        DunningPolicyRequest {
+            retry_schedule_days,
+            send_reminders: true,
+            final_action: DunningFinalAction::Cancel,
+        }
+    }
+
+    #[test]
+    fn test_dunning_policy_validation() {
+        assert!(dunning_policy(Vec::new()).validate().is_ok());
+        assert!(dunning_policy(vec![1, 3, 7]).validate().is_ok());
+        assert!(dunning_policy((1..=10).collect()).validate().is_ok());
+        assert!(dunning_policy((1..=11).collect()).validate().is_err());
+        assert!(dunning_policy(vec![0, 3]).validate().is_err());
+        assert!(dunning_policy(vec![3, 3]).validate().is_err());
+        assert!(dunning_policy(vec![7, 3]).validate().is_err());
+    }
+}
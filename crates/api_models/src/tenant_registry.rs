@@ -0,0 +1,99 @@
+use common_enums::TenantStatus;
+use common_utils::id_type;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TenantCreateRequest {
+    /// The identifier for the tenant, sent in the `x-tenant-id` header of its requests
+    #[schema(value_type = String, example = "acme")]
+    pub tenant_id: id_type::TenantId,
+
+    /// The base URL of the tenant, used in the links sent to its customers
+    #[schema(example = "https://acme.hyperswitch.io")]
+    pub base_url: String,
+
+    /// The database schema holding the data of the tenant. The schema must already be migrated
+    #[schema(example = "acme")]
+    pub schema: String,
+
+    /// The database schema holding the accounts of the tenant. Defaults to `schema`
+    #[schema(example = "acme")]
+    pub accounts_schema: Option<String>,
+
+    /// The prefix of the Redis keys of the tenant, which must not be shared with another tenant
+    #[schema(example = "acme")]
+    pub redis_key_prefix: String,
+
+    /// The ClickHouse database to which the Kafka events of the tenant are routed
+    #[schema(example = "acme")]
+    pub clickhouse_database: String,
+
+    /// The URL of the control center used by the users of the tenant
+    #[schema(example = "https://app.acme.hyperswitch.io")]
+    pub control_center_url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct TenantIdPath {
+    /// The identifier for the tenant
+    #[schema(value_type = String)]
+    pub tenant_id: id_type::TenantId,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct TenantResponse {
+    /// The identifier for the tenant
+    #[schema(value_type = String, example = "acme")]
+    pub tenant_id: id_type::TenantId,
+
+    /// The base URL of the tenant
+    pub base_url: String,
+
+    /// The database schema holding the data of the tenant
+    pub schema: String,
+
+    /// The database schema holding the accounts of the tenant
+    pub accounts_schema: String,
+
+    /// The prefix of the Redis keys of the tenant
+    pub redis_key_prefix: String,
+
+    /// The ClickHouse database to which the Kafka events of the tenant are routed
+    pub clickhouse_database: String,
+
+    /// The URL of the control center used by the users of the tenant
+    pub control_center_url: String,
+
+    /// Whether the tenant serves requests
+    #[schema(value_type = TenantStatus)]
+    pub status: TenantStatus,
+
+    /// The time at which the tenant was provisioned
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the tenant was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct TenantListResponse {
+    /// The tenants provisioned through the tenant registry. The tenants of the static
+    /// configuration are not listed
+    pub tenants: Vec<TenantResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct TenantDeleteResponse {
+    /// The identifier for the deleted tenant
+    #[schema(value_type = String)]
+    pub tenant_id: id_type::TenantId,
+
+    /// Whether the tenant was removed from the registry. The schema and the Redis keys of the
+    /// tenant are left in place
+    pub deleted: bool,
+}
@@ -83,6 +83,43 @@ pub struct ThreeDsDecisionRuleExecuteResponse {
     pub decision: common_types::three_ds_decision_rule_engine::ThreeDSDecision,
 }
 
+/// Represents the request to configure the SCA exemption engine of a profile.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScaExemptionEngineRequest {
+    /// The ID of the profile.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Whether transaction risk analysis exemptions are requested when the fraud rate allows it.
+    pub tra_enabled: Option<bool>,
+    /// Whether low value exemptions are requested when the counters of the card allow it.
+    pub low_value_enabled: Option<bool>,
+    /// The number of transactions required in the reference period before the fraud rate of an
+    /// acquirer or a connector is used for transaction risk analysis.
+    pub min_transactions_for_tra: Option<u32>,
+}
+
+/// Represents the SCA exemption engine configuration of a profile. Payments for which the 3DS
+/// decision rule decides on no 3DS in the PSD2 region are given the optimal exemption before
+/// falling back to a challenge.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ScaExemptionEngineRecord {
+    /// The ID of the profile.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Whether transaction risk analysis exemptions are requested when the fraud rate allows it.
+    pub tra_enabled: bool,
+    /// Whether low value exemptions are requested when the counters of the card allow it.
+    pub low_value_enabled: bool,
+    /// The number of transactions required in the reference period before the fraud rate of an
+    /// acquirer or a connector is used for transaction risk analysis.
+    pub min_transactions_for_tra: u32,
+    /// The time at which the configuration was created, as a unix timestamp.
+    pub created_at: i64,
+    /// The time at which the configuration was last modified, as a unix timestamp.
+    pub modified_at: i64,
+}
+
 impl common_utils::events::ApiEventMetric for ThreeDsDecisionRuleExecuteRequest {
     fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
         Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
@@ -94,3 +131,15 @@ impl common_utils::events::ApiEventMetric for ThreeDsDecisionRuleExecuteResponse
         Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
     }
 }
+
+impl common_utils::events::ApiEventMetric for ScaExemptionEngineRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
+    }
+}
+
+impl common_utils::events::ApiEventMetric for ScaExemptionEngineRecord {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
+    }
+}
@@ -5,7 +5,10 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds, subscription};
+use crate::{
+    customers, disputes, enums as api_enums, mandates, payment_methods, payments, refunds,
+    subscription,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -468,8 +471,15 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = PayoutBatchResponse, title = "PayoutBatchResponse")]
+    PayoutBatchDetails(Box<payouts::PayoutBatchResponse>),
     #[schema(value_type = ConfirmSubscriptionResponse, title = "ConfirmSubscriptionResponse")]
     SubscriptionDetails(Box<subscription::ConfirmSubscriptionResponse>),
+    #[schema(value_type = CustomerMergeResponse, title = "CustomerMergeResponse")]
+    CustomerDetails(Box<customers::CustomerMergeResponse>),
+    #[schema(value_type = PaymentMethodWebhookResponse, title = "PaymentMethodWebhookResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodWebhookResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -1,3 +1,4 @@
+pub mod networks;
 pub mod validate;
 use std::ops::Deref;
 
@@ -7,7 +8,13 @@ use hyperswitch_masking::{PeekInterface, StrongSecret};
 use serde::{de, Deserialize, Serialize};
 use time::{Date, Duration, PrimitiveDateTime, Time};
 
-pub use crate::validate::{CardNumber, CardNumberStrategy, CardNumberValidationErr, NetworkToken};
+pub use crate::{
+    networks::{
+        get_card_network_rule, get_card_networks, get_card_networks_by_iin,
+        validate_card_number_for_network, validate_card_security_code_for_network, CardNetworkRule,
+    },
+    validate::{CardNumber, CardNumberStrategy, CardNumberValidationErr, NetworkToken},
+};
 
 #[derive(Serialize)]
 pub struct CardSecurityCode(StrongSecret<u16>);
@@ -0,0 +1,510 @@
+//! Offline detection of the card networks of a card number, along with the card number and
+//! card security code rules of each network
+
+use std::ops::RangeInclusive;
+
+use common_enums::CardNetwork;
+
+use crate::validate::CardNumberValidationErr;
+
+/// Number of leading digits of a card number that identify its issuer
+pub const IIN_LENGTH: usize = 6;
+
+/// Card number and card security code rules of a card network
+#[derive(Debug)]
+pub struct CardNetworkRule {
+    pub network: CardNetwork,
+    /// Issuer identification number ranges of the network, as six digit numbers
+    pub iin_ranges: &'static [RangeInclusive<u32>],
+    /// Valid card number lengths of the network
+    pub pan_lengths: &'static [usize],
+    /// Valid card security code lengths of the network
+    pub cvc_lengths: &'static [usize],
+}
+
+impl CardNetworkRule {
+    fn matches_iin(&self, iin: u32) -> bool {
+        self.iin_ranges.iter().any(|range| range.contains(&iin))
+    }
+}
+
+/// Rules of every card network, ordered by precedence. When a card number is co-badged, the
+/// first matching network is considered to be its primary network.
+///
+/// Cartes Bancaires is co-badged with Visa and Mastercard, and RuPay with JCB and Discover, on the
+/// ranges they share with these networks. The US debit networks and Interac share the issuer identification numbers of the global
+/// networks they are co-badged with, and cannot be identified from the card number alone.
+pub static CARD_NETWORK_RULES: &[CardNetworkRule] = &[
+    CardNetworkRule {
+        network: CardNetwork::Visa,
+        iin_ranges: &[400000..=499999],
+        pan_lengths: &[13, 16, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::Mastercard,
+        iin_ranges: &[222100..=272099, 510000..=559999],
+        pan_lengths: &[16],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::AmericanExpress,
+        iin_ranges: &[340000..=349999, 370000..=379999],
+        pan_lengths: &[15],
+        cvc_lengths: &[4],
+    },
+    CardNetworkRule {
+        network: CardNetwork::Discover,
+        iin_ranges: &[601100..=601199, 622126..=622925, 644000..=659999],
+        pan_lengths: &[16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::JCB,
+        iin_ranges: &[
+            308800..=308899,
+            309600..=309699,
+            311200..=311299,
+            315800..=315899,
+            333700..=333799,
+            352800..=358999,
+        ],
+        pan_lengths: &[16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::DinersClub,
+        iin_ranges: &[
+            300000..=305999,
+            309500..=309599,
+            360000..=369999,
+            380000..=399999,
+        ],
+        pan_lengths: &[14, 15, 16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    // The UnionPay ranges exclude the ranges of Discover and Maestro within the 62 prefix
+    CardNetworkRule {
+        network: CardNetwork::UnionPay,
+        iin_ranges: &[
+            620000..=621999,
+            622100..=622125,
+            622926..=627740,
+            627742..=629999,
+            810000..=817199,
+        ],
+        pan_lengths: &[16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::Maestro,
+        iin_ranges: &[
+            501800..=501899,
+            502000..=502099,
+            503800..=503899,
+            504400..=504499,
+            504645..=504645,
+            504681..=504681,
+            504775..=504775,
+            504817..=504817,
+            504834..=504834,
+            504993..=504993,
+            508100..=508199,
+            589300..=589399,
+            600206..=600206,
+            603123..=603123,
+            603845..=603845,
+            622000..=622099,
+            627741..=627741,
+            630400..=630499,
+            675900..=675999,
+            676100..=676399,
+        ],
+        pan_lengths: &[12, 13, 14, 15, 16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::RuPay,
+        iin_ranges: &[
+            353800..=353899,
+            508227..=508227,
+            508500..=508999,
+            603741..=603741,
+            606985..=606999,
+            607000..=607984,
+            608001..=608500,
+            652150..=653149,
+            817290..=817290,
+            817368..=817368,
+            817378..=817378,
+            820000..=829999,
+        ],
+        pan_lengths: &[16],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::CartesBancaires,
+        iin_ranges: &[
+            401005..=401006,
+            401581..=401581,
+            402101..=402102,
+            403550..=403550,
+            405936..=405936,
+            406572..=406572,
+            413849..=413849,
+            414819..=414819,
+            415056..=415056,
+            415059..=415059,
+            415062..=415062,
+            415071..=415071,
+            415074..=415074,
+            416286..=416286,
+            416537..=416537,
+            416579..=416579,
+            417170..=417179,
+            420110..=420110,
+            423460..=423460,
+            434721..=434722,
+            435048..=435052,
+            437875..=437875,
+            439509..=439509,
+            439511..=439511,
+            439515..=439515,
+            439539..=439539,
+            439598..=439598,
+            439603..=439603,
+            439618..=439620,
+            439622..=439622,
+            439672..=439672,
+            442448..=442452,
+            442457..=442457,
+            448412..=448412,
+            450519..=450519,
+            450560..=450560,
+            453300..=453399,
+            455660..=455689,
+            456100..=456229,
+            456240..=456299,
+            456955..=456955,
+            457452..=457452,
+            457717..=457717,
+            459300..=459309,
+            459320..=459339,
+            459370..=459379,
+            459390..=459399,
+            460990..=460999,
+            465476..=465477,
+            466258..=466258,
+            466575..=466575,
+            469800..=469809,
+            469820..=469839,
+            474107..=474107,
+            477173..=477174,
+            477186..=477186,
+            477265..=477265,
+            477293..=477293,
+            479619..=479619,
+            481091..=481091,
+            483622..=483622,
+            486519..=486519,
+            497000..=497999,
+            498350..=498399,
+            499001..=499006,
+            499010..=499016,
+            499020..=499023,
+            499030..=499033,
+            499040..=499043,
+            499050..=499052,
+            499068..=499068,
+            499092..=499092,
+            499095..=499099,
+            507589..=507590,
+            507593..=507594,
+            507597..=507597,
+            510726..=510726,
+            513000..=513799,
+            513850..=513869,
+            513900..=513900,
+            513938..=513938,
+            515214..=515214,
+            516207..=516207,
+            516236..=516236,
+            517222..=517222,
+            517243..=517243,
+            517365..=517366,
+            517502..=517502,
+            517647..=517647,
+            518101..=518101,
+            519920..=519920,
+            520993..=520993,
+            521662..=521662,
+            523718..=523718,
+            527429..=527429,
+            529227..=529227,
+            529313..=529314,
+            529331..=529331,
+            529414..=529414,
+            529421..=529421,
+            529430..=529430,
+            529440..=529440,
+            529447..=529447,
+            529455..=529456,
+            529460..=529499,
+            529542..=529542,
+            530901..=530901,
+            531028..=531028,
+            531030..=531030,
+            531195..=531195,
+            532344..=532347,
+            532459..=532459,
+            532509..=532509,
+            532534..=532534,
+            532554..=532554,
+            532556..=532556,
+            533801..=533801,
+            534102..=534102,
+            534105..=534105,
+            534111..=534111,
+            535029..=535029,
+            535066..=535066,
+            535324..=535324,
+            536107..=536107,
+            536115..=536115,
+            537106..=537106,
+            537112..=537112,
+            538011..=538011,
+            542848..=542848,
+            545157..=545157,
+            549538..=549538,
+            549858..=549859,
+            553979..=553979,
+            553993..=553993,
+            554205..=554205,
+            554260..=554260,
+            554965..=554965,
+            557008..=557008,
+            558867..=558867,
+            558882..=558882,
+            558929..=558929,
+            558942..=558943,
+            559618..=559618,
+            559809..=559810,
+            560408..=560408,
+            561202..=561206,
+            561241..=561241,
+            561243..=561244,
+            561250..=561250,
+            561254..=561254,
+            561256..=561258,
+            581700..=581707,
+            581715..=581715,
+            581721..=581721,
+            581724..=581724,
+            581731..=581731,
+            581736..=581751,
+            581756..=581756,
+            581762..=581762,
+            581766..=581766,
+            581769..=581769,
+            581773..=581773,
+            581777..=581787,
+            581790..=581791,
+            581797..=581797,
+            585502..=585505,
+            585577..=585582,
+        ],
+        pan_lengths: &[16],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::Interac,
+        iin_ranges: &[],
+        pan_lengths: &[16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::Star,
+        iin_ranges: &[],
+        pan_lengths: &[16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::Pulse,
+        iin_ranges: &[],
+        pan_lengths: &[16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::Accel,
+        iin_ranges: &[],
+        pan_lengths: &[16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+    CardNetworkRule {
+        network: CardNetwork::Nyce,
+        iin_ranges: &[],
+        pan_lengths: &[16, 17, 18, 19],
+        cvc_lengths: &[3],
+    },
+];
+
+/// Returns the rules of the given card network
+pub fn get_card_network_rule(network: &CardNetwork) -> Option<&'static CardNetworkRule> {
+    CARD_NETWORK_RULES
+        .iter()
+        .find(|rule| &rule.network == network)
+}
+
+fn get_iin(card_number: &str) -> Option<u32> {
+    card_number
+        .get(..IIN_LENGTH)
+        .filter(|iin| iin.bytes().all(|digit| digit.is_ascii_digit()))
+        .and_then(|iin| iin.parse().ok())
+}
+
+/// Returns the card networks whose issuer identification number ranges contain the leading
+/// digits of the card number or the card BIN, in order of precedence
+pub fn get_card_networks_by_iin(card_number: &str) -> Vec<CardNetwork> {
+    get_iin(card_number)
+        .map(|iin| {
+            CARD_NETWORK_RULES
+                .iter()
+                .filter(|rule| rule.matches_iin(iin))
+                .map(|rule| rule.network.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the card networks of the card number, in order of precedence. A card number is
+/// co-badged when more than one network is returned.
+pub fn get_card_networks(card_number: &str) -> Vec<CardNetwork> {
+    get_iin(card_number)
+        .map(|iin| {
+            CARD_NETWORK_RULES
+                .iter()
+                .filter(|rule| {
+                    rule.matches_iin(iin) && rule.pan_lengths.contains(&card_number.len())
+                })
+                .map(|rule| rule.network.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Validates the length of the card number against the rules of the card network
+pub fn validate_card_number_for_network(
+    card_number: &str,
+    network: &CardNetwork,
+) -> Result<(), CardNumberValidationErr> {
+    match get_card_network_rule(network) {
+        Some(rule) if !rule.pan_lengths.contains(&card_number.len()) => Err(
+            CardNumberValidationErr("card number length is invalid for the card network"),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Validates the length of the card security code against the rules of the card network
+pub fn validate_card_security_code_for_network(
+    card_security_code: &str,
+    network: &CardNetwork,
+) -> Result<(), CardNumberValidationErr> {
+    if !card_security_code
+        .bytes()
+        .all(|digit| digit.is_ascii_digit())
+    {
+        return Err(CardNumberValidationErr(
+            "card security code contains invalid characters",
+        ));
+    }
+    match get_card_network_rule(network) {
+        Some(rule) if !rule.cvc_lengths.contains(&card_security_code.len()) => Err(
+            CardNumberValidationErr("card security code length is invalid for the card network"),
+        ),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Card numbers of the prefixes supported by the previous regular expression based detection
+    const PREFIX_CARD_NUMBERS: &[(&str, CardNetwork)] = &[
+        ("4111111111111111", CardNetwork::Visa),
+        ("4222222222222", CardNetwork::Visa),
+        ("5105105105105100", CardNetwork::Mastercard),
+        ("5555555555554444", CardNetwork::Mastercard),
+        ("2221000000000009", CardNetwork::Mastercard),
+        ("2720990000000007", CardNetwork::Mastercard),
+        ("340000000000009", CardNetwork::AmericanExpress),
+        ("378282246310005", CardNetwork::AmericanExpress),
+        ("6011111111111117", CardNetwork::Discover),
+        ("6441111111111117", CardNetwork::Discover),
+        ("6500000000000002", CardNetwork::Discover),
+        ("6221260000000000", CardNetwork::Discover),
+        ("6229250000000000", CardNetwork::Discover),
+        ("3088000000000000", CardNetwork::JCB),
+        ("3096000000000000", CardNetwork::JCB),
+        ("3112000000000000", CardNetwork::JCB),
+        ("3158000000000000", CardNetwork::JCB),
+        ("3337000000000000", CardNetwork::JCB),
+        ("3528000000000000", CardNetwork::JCB),
+        ("3589000000000000", CardNetwork::JCB),
+        ("30000000000004", CardNetwork::DinersClub),
+        ("30500000000003", CardNetwork::DinersClub),
+        ("36000000000008", CardNetwork::DinersClub),
+        ("38000000000006", CardNetwork::DinersClub),
+        ("39000000000004", CardNetwork::DinersClub),
+        ("6200000000000005", CardNetwork::UnionPay),
+        ("6221000000000000", CardNetwork::UnionPay),
+        ("6229260000000000", CardNetwork::UnionPay),
+        ("6250941006528599", CardNetwork::UnionPay),
+        ("5018000000000000", CardNetwork::Maestro),
+        ("5020000000000000", CardNetwork::Maestro),
+        ("5038000000000000", CardNetwork::Maestro),
+        ("5893000000000000", CardNetwork::Maestro),
+        ("6304000000000000", CardNetwork::Maestro),
+        ("6759000000000000", CardNetwork::Maestro),
+        ("6761000000000000", CardNetwork::Maestro),
+        ("6763000000000000", CardNetwork::Maestro),
+        ("6220000000000000", CardNetwork::Maestro),
+        ("6277410000000000", CardNetwork::Maestro),
+        ("5085000000000000", CardNetwork::RuPay),
+        ("6070000000000000", CardNetwork::RuPay),
+        ("8200000000000000", CardNetwork::RuPay),
+    ];
+
+    #[test]
+    fn card_networks_of_supported_prefixes() {
+        for (card_number, network) in PREFIX_CARD_NUMBERS {
+            assert_eq!(
+                get_card_networks(card_number).first(),
+                Some(network),
+                "card number {card_number}"
+            );
+        }
+    }
+
+    #[test]
+    fn union_pay_ranges_are_disjoint_from_discover_and_maestro() {
+        for card_number in ["6221260000000000", "6229250000000000"] {
+            assert_eq!(get_card_networks(card_number), vec![CardNetwork::Discover]);
+        }
+        for card_number in ["6220000000000000", "6277410000000000"] {
+            assert_eq!(get_card_networks(card_number), vec![CardNetwork::Maestro]);
+        }
+    }
+
+    #[test]
+    fn card_number_of_invalid_length_is_identified_by_iin() {
+        let card_number = "41111111111111";
+        assert!(get_card_networks(card_number).is_empty());
+        assert_eq!(
+            get_card_networks_by_iin(card_number),
+            vec![CardNetwork::Visa]
+        );
+        assert!(validate_card_number_for_network(card_number, &CardNetwork::Visa).is_err());
+    }
+}
@@ -1,14 +1,15 @@
-use std::{collections::HashMap, fmt, ops::Deref, str::FromStr, sync::LazyLock};
+use std::{fmt, ops::Deref, str::FromStr};
 
+use common_enums::CardNetwork;
 use common_utils::errors::ValidationError;
-use error_stack::report;
 use hyperswitch_masking::{PeekInterface, Strategy, StrongSecret, WithType};
-use regex::Regex;
 #[cfg(not(target_arch = "wasm32"))]
 use router_env::{logger, which as router_env_which, Env};
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
+use crate::networks;
+
 /// Minimum limit of a card number will not be less than 8 by ISO standards
 pub const MIN_CARD_NUMBER_LENGTH: usize = 8;
 
@@ -49,58 +50,18 @@ impl CardNumber {
             .rev()
             .collect::<String>()
     }
+    /// Returns the card networks of the card number, in order of precedence
+    pub fn get_card_networks(&self) -> Vec<CardNetwork> {
+        networks::get_card_networks(self.0.peek())
+    }
+
+    /// Returns the primary card network of the card number
+    pub fn get_card_network(&self) -> Option<CardNetwork> {
+        self.get_card_networks().into_iter().next()
+    }
+
     pub fn is_cobadged_card(&self) -> Result<bool, error_stack::Report<ValidationError>> {
-        /// Regex to identify card networks
-        static CARD_NETWORK_REGEX: LazyLock<HashMap<&str, Result<Regex, regex::Error>>> =
-            LazyLock::new(|| {
-                let mut map = HashMap::new();
-                map.insert(
-                    "Mastercard",
-                    Regex::new(r"^(222[1-9]|22[3-9][0-9]|2[3-6][0-9]{2}|27[0-1][0-9]|2720|5[1-5])"),
-                );
-                map.insert("American Express", Regex::new(r"^3[47]"));
-                map.insert("Visa", Regex::new(r"^4"));
-                map.insert(
-                    "Discover",
-                    Regex::new(
-                        r"^(6011|64[4-9]|65|622126|622[1-9][0-9][0-9]|6229[0-1][0-9]|622925)",
-                    ),
-                );
-                map.insert(
-        "Maestro",
-        Regex::new(r"^(5018|5081|5044|504681|504993|5020|502260|5038|5893|603845|603123|6304|6759|676[1-3]|6220|504834|504817|504645|504775|600206|627741)"),
-    );
-                map.insert(
-        "RuPay",
-        Regex::new(r"^(508227|508[5-9]|603741|60698[5-9]|60699|607[0-8]|6079[0-7]|60798[0-4]|60800[1-9]|6080[1-9]|608[1-4]|608500|6521[5-9]|652[2-9]|6530|6531[0-4]|817290|817368|817378|353800|82)"),
-    );
-                map.insert("Diners Club", Regex::new(r"^(36|38|39|30[0-5])"));
-                map.insert("JCB", Regex::new(r"^35(2[89]|[3-8][0-9])"));
-                map.insert("CarteBlanche", Regex::new(r"^389[0-9]{11}$"));
-                map.insert("Sodex", Regex::new(r"^(637513)"));
-                map.insert("BAJAJ", Regex::new(r"^(203040)"));
-                map.insert("CartesBancaires", Regex::new(r"^(401(005|006|581)|4021(01|02)|403550|405936|406572|41(3849|4819|50(56|59|62|71|74)|6286|65(37|79)|71[7])|420110|423460|43(47(21|22)|50(48|49|50|51|52)|7875|95(09|11|15|39|98)|96(03|18|19|20|22|72))|4424(48|49|50|51|52|57)|448412|4505(19|60)|45(33|56[6-8]|61|62[^3]|6955|7452|7717|93[02379])|46(099|54(76|77)|6258|6575|98[023])|47(4107|71(73|74|86)|72(65|93)|9619)|48(1091|3622|6519)|49(7|83[5-9]|90(0[1-6]|1[0-6]|2[0-3]|3[0-3]|4[0-3]|5[0-2]|68|9[256789]))|5075(89|90|93|94|97)|51(0726|3([0-7]|8[56]|9(00|38))|5214|62(07|36)|72(22|43)|73(65|66)|7502|7647|8101|9920)|52(0993|1662|3718|7429|9227|93(13|14|31)|94(14|21|30|40|47|55|56|[6-9])|9542)|53(0901|10(28|30)|1195|23(4[4-7])|2459|25(09|34|54|56)|3801|41(02|05|11)|50(29|66)|5324|61(07|15)|71(06|12)|8011)|54(2848|5157|9538|98(5[89]))|55(39(79|93)|42(05|60)|4965|7008|88(67|82)|89(29|4[23])|9618|98(09|10))|56(0408|12(0[2-6]|4[134]|5[04678]))|58(17(0[0-7]|15|2[14]|3[16789]|4[0-9]|5[016]|6[269]|7[3789]|8[0-7]|9[017])|55(0[2-5]|7[7-9]|8[0-2])))"));
-                map
-            });
-        let mut no_of_supported_card_networks = 0;
-
-        let card_number_str = self.get_card_no();
-        for (_, regex) in CARD_NETWORK_REGEX.iter() {
-            let card_regex = match regex.as_ref() {
-                Ok(regex) => Ok(regex),
-                Err(_) => Err(report!(ValidationError::InvalidValue {
-                    message: "Invalid regex expression".into(),
-                })),
-            }?;
-
-            if card_regex.is_match(&card_number_str) {
-                no_of_supported_card_networks += 1;
-                if no_of_supported_card_networks > 1 {
-                    break;
-                }
-            }
-        }
-        Ok(no_of_supported_card_networks > 1)
+        Ok(self.get_card_networks().len() > 1)
     }
 
     pub fn to_network_token(&self) -> NetworkToken {
@@ -413,4 +374,64 @@ mod tests {
         let error_msg = card_number.unwrap_err().to_string();
         assert_eq!(error_msg, "card number invalid".to_string());
     }
+
+    #[test]
+    fn card_number_networks() {
+        let visa = CardNumber::from_str("4242424242424242").unwrap();
+        assert_eq!(visa.get_card_network(), Some(CardNetwork::Visa));
+        assert!(!visa.is_cobadged_card().unwrap());
+
+        let mastercard = CardNumber::from_str("2223003122003222").unwrap();
+        assert_eq!(mastercard.get_card_network(), Some(CardNetwork::Mastercard));
+
+        let amex = CardNumber::from_str("378282246310005").unwrap();
+        assert_eq!(amex.get_card_networks(), vec![CardNetwork::AmericanExpress]);
+
+        let unknown = CardNumber::from_str("9000000000000001").unwrap();
+        assert_eq!(unknown.get_card_network(), None);
+    }
+
+    #[test]
+    fn cobadged_card_number_networks() {
+        let cartes_bancaires = CardNumber::from_str("4035501000000008").unwrap();
+        assert_eq!(
+            cartes_bancaires.get_card_networks(),
+            vec![CardNetwork::Visa, CardNetwork::CartesBancaires]
+        );
+        assert!(cartes_bancaires.is_cobadged_card().unwrap());
+    }
+
+    #[test]
+    fn card_number_length_for_network() {
+        assert!(networks::validate_card_number_for_network(
+            "378282246310005",
+            &CardNetwork::AmericanExpress
+        )
+        .is_ok());
+        assert!(networks::validate_card_number_for_network(
+            "4242424242424242",
+            &CardNetwork::AmericanExpress
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn card_security_code_for_network() {
+        assert!(networks::validate_card_security_code_for_network(
+            "1234",
+            &CardNetwork::AmericanExpress
+        )
+        .is_ok());
+        assert!(networks::validate_card_security_code_for_network(
+            "123",
+            &CardNetwork::AmericanExpress
+        )
+        .is_err());
+        assert!(
+            networks::validate_card_security_code_for_network("123", &CardNetwork::Visa).is_ok()
+        );
+        assert!(
+            networks::validate_card_security_code_for_network("12a", &CardNetwork::Visa).is_err()
+        );
+    }
 }
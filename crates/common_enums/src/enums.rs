@@ -1702,7 +1702,10 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    PayoutBatchDetails,
     SubscriptionDetails,
+    CustomerDetails,
+    PaymentMethodDetails,
 }
 
 #[derive(
@@ -1729,6 +1732,8 @@ pub enum EventClass {
     #[cfg(feature = "payouts")]
     Payouts,
     Subscriptions,
+    Customers,
+    PaymentMethods,
 }
 
 impl EventClass {
@@ -1755,6 +1760,7 @@ impl EventClass {
                 EventType::DisputeChallenged,
                 EventType::DisputeWon,
                 EventType::DisputeLost,
+                EventType::DisputeEvidenceDue,
             ]),
             Self::Mandates => HashSet::from([EventType::MandateActive, EventType::MandateRevoked]),
             #[cfg(feature = "payouts")]
@@ -1766,8 +1772,15 @@ impl EventClass {
                 EventType::PayoutCancelled,
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
+                EventType::PayoutBatchCompleted,
             ]),
             Self::Subscriptions => HashSet::from([EventType::InvoicePaid]),
+            Self::Customers => HashSet::from([EventType::CustomerMerged]),
+            Self::PaymentMethods => HashSet::from([
+                EventType::PaymentMethodUpdated,
+                EventType::PaymentMethodExpiring,
+                EventType::PaymentMethodExpired,
+            ]),
         }
     }
 }
@@ -1811,6 +1824,7 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeEvidenceDue,
     MandateActive,
     MandateRevoked,
     #[cfg(feature = "payouts")]
@@ -1827,7 +1841,13 @@ pub enum EventType {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    #[cfg(feature = "payouts")]
+    PayoutBatchCompleted,
     InvoicePaid,
+    CustomerMerged,
+    PaymentMethodUpdated,
+    PaymentMethodExpiring,
+    PaymentMethodExpired,
 }
 
 #[derive(
@@ -2932,6 +2952,64 @@ impl RefundStatus {
     }
 }
 
+/// The status of a bulk refund job
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BulkRefundStatus {
+    /// The refunds of the job are being created
+    #[default]
+    Processing,
+    /// All refunds of the job were created successfully
+    Completed,
+    /// Some refunds of the job could not be created
+    PartiallyCompleted,
+    /// None of the refunds of the job could be created
+    Failed,
+}
+
+/// The status of a single item of a bulk refund job
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BulkRefundItemStatus {
+    /// The refund for this item has not been created yet
+    #[default]
+    Pending,
+    /// The refund for this item was created and submitted to the connector
+    Submitted,
+    /// The refund for this item could not be created or was declined
+    Failed,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -3681,6 +3759,91 @@ pub enum DisputeStatus {
     DisputeLost,
 }
 
+/// The sources from which evidence is gathered when assembling the evidence of a dispute
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeEvidenceSource {
+    /// The description and the date of the disputed payment
+    PaymentDetails,
+    /// The name and the email address of the customer
+    CustomerDetails,
+    /// The billing address of the payment
+    BillingAddress,
+    /// The shipping address of the payment
+    ShippingAddress,
+    /// The 3DS authentication result and the IP address of the customer
+    AuthenticationResult,
+    /// The refunds initiated against the payment
+    RefundHistory,
+    /// The files already attached as evidence to the dispute, such as receipts
+    UploadedEvidence,
+}
+
+/// The action taken automatically on a dispute by the dispute rules of a profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeRuleAction {
+    /// Leave the dispute for the merchant to act upon
+    #[default]
+    ManualReview,
+    /// Accept the dispute
+    AcceptDispute,
+    /// Assemble the evidence from the evidence templates and submit it
+    SubmitEvidence,
+}
+
+/// The outcome of an action taken automatically on a dispute
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeAutomatedActionStatus {
+    /// The dispute is claimed by the evaluation of its rules, the action is being taken
+    Pending,
+    Succeeded,
+    Failed,
+}
+
 #[derive(Debug, Clone, AsExpression, PartialEq, ToSchema, Eq)]
 #[schema(
     value_type = String,
@@ -8970,133 +9133,151 @@ pub enum PayoutEntityType {
     Personal,
 }
 
-/// The send method which will be required for processing payouts, check options for better understanding.
+/// The status of a payout batch
 #[derive(
     Clone,
     Copy,
     Debug,
+    Default,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
     strum::EnumString,
     ToSchema,
-    Hash,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum PayoutSendPriority {
-    Instant,
-    Fast,
-    Regular,
-    Wire,
-    CrossBorder,
-    Internal,
+pub enum PayoutBatchStatus {
+    /// The batch was validated and is waiting for a second user to approve it
+    #[default]
+    PendingApproval,
+    /// The batch was rejected by the approver and will not be executed
+    Rejected,
+    /// The batch was approved and its items are being executed
+    Processing,
+    /// All items in the batch were executed successfully
+    Completed,
+    /// Some items in the batch failed while the rest were executed successfully
+    PartiallyCompleted,
+    /// All items in the batch failed
+    Failed,
+}
+
+impl PayoutBatchStatus {
+    pub fn is_terminal_status(self) -> bool {
+        match self {
+            Self::Rejected | Self::Completed | Self::PartiallyCompleted | Self::Failed => true,
+            Self::PendingApproval | Self::Processing => false,
+        }
+    }
 }
 
+/// The accounts of the ledger of a merchant
 #[derive(
     Clone,
     Copy,
     Debug,
-    Default,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
     strum::EnumString,
     ToSchema,
-    Hash,
 )]
-#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum PaymentSource {
-    #[default]
-    MerchantServer,
-    Postman,
-    Dashboard,
-    Sdk,
-    Webhook,
-    ExternalAuthenticator,
-}
-
-#[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
-pub enum BrowserName {
-    #[default]
-    Safari,
-    #[serde(other)]
-    Unknown,
-}
-
-#[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-pub enum ClientPlatform {
-    #[default]
-    Web,
-    Ios,
-    Android,
-    #[serde(other)]
-    Unknown,
+pub enum LedgerAccount {
+    /// The funds held by the connector on behalf of the merchant, which are yet to be settled
+    ConnectorReceivable,
+    /// The amount captured from customers
+    Sales,
+    /// The fees charged by the connector for processing payments
+    ProcessingFees,
+    /// The amount refunded to customers
+    Refunds,
+    /// The amount lost to disputes
+    Chargebacks,
+    /// The amount paid out to recipients
+    Payouts,
 }
 
-impl PaymentSource {
-    pub fn is_for_internal_use_only(self) -> bool {
+impl LedgerAccount {
+    /// Returns whether the balance of the account increases with debits
+    pub fn is_debit_normal(self) -> bool {
         match self {
-            Self::Dashboard | Self::Sdk | Self::MerchantServer | Self::Postman => false,
-            Self::Webhook | Self::ExternalAuthenticator => true,
+            Self::ConnectorReceivable
+            | Self::ProcessingFees
+            | Self::Refunds
+            | Self::Chargebacks
+            | Self::Payouts => true,
+            Self::Sales => false,
         }
     }
 }
 
+/// The state transitions which are posted to the ledger
 #[derive(
     Clone,
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
-    serde::Serialize,
     serde::Deserialize,
+    serde::Serialize,
     strum::Display,
     strum::EnumString,
+    ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum MerchantDecision {
-    Approved,
-    Rejected,
-    AutoRefunded,
+pub enum LedgerEntryType {
+    /// A payment attempt was captured
+    PaymentCaptured,
+    /// A refund succeeded
+    RefundSucceeded,
+    /// A dispute was lost
+    DisputeLost,
+    /// A payout succeeded
+    PayoutSucceeded,
 }
+
+/// The side of an account on which a ledger posting is recorded
 #[derive(
     Clone,
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
-    serde::Serialize,
     serde::Deserialize,
-    SmithyModel,
+    serde::Serialize,
     strum::Display,
     strum::EnumString,
-    strum::EnumIter,
     ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-#[smithy(namespace = "com.hyperswitch.smithy.types")]
-pub enum TaxStatus {
-    Taxable,
-    Exempt,
+pub enum LedgerPostingDirection {
+    Debit,
+    Credit,
 }
 
+/// The layout of a settlement report uploaded for reconciliation
 #[derive(
     Clone,
     Copy,
-    Default,
     Debug,
+    Default,
     Eq,
     Hash,
     PartialEq,
@@ -9106,18 +9287,26 @@ pub enum TaxStatus {
     strum::EnumString,
     ToSchema,
 )]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum FrmSuggestion {
+pub enum SettlementReportFormat {
+    /// A CSV file with the `connector_transaction_id`, `transaction_type`, `amount`, `currency`
+    /// and optionally the `fee` and `settled_at` columns, amounts being in minor units
     #[default]
-    FrmCancelTransaction,
-    FrmManualReview,
-    FrmAuthorizeTransaction, // When manual capture payment which was marked fraud and held, when approved needs to be authorized.
+    Generic,
+    /// The itemized payout reconciliation report of Stripe
+    Stripe,
+    /// The settlement details report of Adyen
+    Adyen,
 }
 
+/// The status of the reconciliation of a settlement report
 #[derive(
     Clone,
+    Copy,
     Debug,
+    Default,
     Eq,
     Hash,
     PartialEq,
@@ -9125,19 +9314,20 @@ pub enum FrmSuggestion {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
-    utoipa::ToSchema,
-    Copy,
+    ToSchema,
 )]
-#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum ReconStatus {
-    NotRequested,
-    Requested,
-    Active,
-    Disabled,
+pub enum SettlementReportStatus {
+    #[default]
+    Pending,
+    Processing,
+    Completed,
+    Failed,
 }
 
+/// The kind of transaction of a settlement report line
 #[derive(
     Clone,
     Copy,
@@ -9145,40 +9335,286 @@ pub enum ReconStatus {
     Eq,
     Hash,
     PartialEq,
-    serde::Serialize,
     serde::Deserialize,
+    serde::Serialize,
     strum::Display,
     strum::EnumString,
     ToSchema,
 )]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum AuthenticationConnectors {
-    Threedsecureio,
-    Netcetera,
-    Gpayments,
-    CtpMastercard,
-    UnifiedAuthenticationService,
-    Juspaythreedsserver,
-    CtpVisa,
-    Cardinal,
+pub enum SettlementTransactionType {
+    Payment,
+    Refund,
 }
 
-impl AuthenticationConnectors {
-    pub fn is_separate_version_call_required(self) -> bool {
-        match self {
-            Self::Threedsecureio
-            | Self::Netcetera
-            | Self::CtpMastercard
-            | Self::UnifiedAuthenticationService
-            | Self::Juspaythreedsserver
-            | Self::CtpVisa
-            | Self::Cardinal => false,
-            Self::Gpayments => true,
-        }
-    }
+/// The outcome of reconciling a transaction against a settlement report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconLineStatus {
+    /// The transaction was found in both Hyperswitch and the settlement report with the same amount
+    Matched,
+    /// The transaction was found in both, but the amounts or the currencies differ
+    AmountMismatch,
+    /// The transaction was found in the settlement report but not in Hyperswitch
+    MissingInHyperswitch,
+    /// The transaction succeeded in Hyperswitch during the period of the settlement report, but
+    /// was not found in the settlement report
+    MissingAtPsp,
+}
 
-    pub fn is_jwt_flow(&self) -> bool {
+/// The status of a single item in a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The payout for this item has not been created yet
+    #[default]
+    Pending,
+    /// The payout for this item was created and submitted to the connector
+    Submitted,
+    /// The payout for this item could not be created or was declined
+    Failed,
+}
+
+/// The send method which will be required for processing payouts, check options for better understanding.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutSendPriority {
+    Instant,
+    Fast,
+    Regular,
+    Wire,
+    CrossBorder,
+    Internal,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentSource {
+    #[default]
+    MerchantServer,
+    Postman,
+    Dashboard,
+    Sdk,
+    Webhook,
+    ExternalAuthenticator,
+}
+
+#[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
+pub enum BrowserName {
+    #[default]
+    Safari,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum ClientPlatform {
+    #[default]
+    Web,
+    Ios,
+    Android,
+    #[serde(other)]
+    Unknown,
+}
+
+impl PaymentSource {
+    pub fn is_for_internal_use_only(self) -> bool {
+        match self {
+            Self::Dashboard | Self::Sdk | Self::MerchantServer | Self::Postman => false,
+            Self::Webhook | Self::ExternalAuthenticator => true,
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantDecision {
+    Approved,
+    Rejected,
+    AutoRefunded,
+}
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    SmithyModel,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[smithy(namespace = "com.hyperswitch.smithy.types")]
+pub enum TaxStatus {
+    Taxable,
+    Exempt,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmSuggestion {
+    #[default]
+    FrmCancelTransaction,
+    FrmManualReview,
+    FrmAuthorizeTransaction, // When manual capture payment which was marked fraud and held, when approved needs to be authorized.
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+    Copy,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconStatus {
+    NotRequested,
+    Requested,
+    Active,
+    Disabled,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthenticationConnectors {
+    Threedsecureio,
+    Netcetera,
+    Gpayments,
+    CtpMastercard,
+    UnifiedAuthenticationService,
+    Juspaythreedsserver,
+    CtpVisa,
+    Cardinal,
+}
+
+impl AuthenticationConnectors {
+    pub fn is_separate_version_call_required(self) -> bool {
+        match self {
+            Self::Threedsecureio
+            | Self::Netcetera
+            | Self::CtpMastercard
+            | Self::UnifiedAuthenticationService
+            | Self::Juspaythreedsserver
+            | Self::CtpVisa
+            | Self::Cardinal => false,
+            Self::Gpayments => true,
+        }
+    }
+
+    pub fn is_jwt_flow(&self) -> bool {
         match self {
             Self::Threedsecureio
             | Self::Netcetera
@@ -10643,6 +11079,17 @@ pub enum ProcessTrackerRunner {
     DisputeListWorkflow,
     InvoiceSyncflow,
     PayoutSyncWorkFlow,
+    PayoutBatchWorkflow,
+    BulkRefundWorkflow,
+    DisputeEvidenceReminderWorkflow,
+    SettlementReconWorkflow,
+    CustomerErasureWorkflow,
+    AccountUpdaterWorkflow,
+    PaymentMethodExpiryWorkflow,
+    SubscriptionBillingWorkflow,
+    DunningWorkflow,
+    StorageSchemeMigrationWorkflow,
+    LedgerPostingWorkflow,
 }
 
 #[derive(
@@ -11248,3 +11695,510 @@ impl PostCaptureVoidStatus {
         }
     }
 }
+
+/// The status of a tenant provisioned through the tenant registry
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TenantStatus {
+    /// The tenant serves requests, and its process tracker tasks and drainer streams are processed
+    #[default]
+    Active,
+    /// The tenant is known to the registry, but its stores are not built by any instance
+    Suspended,
+}
+
+/// The kind of record whose state changes, or outgoing webhook, are written to the transactional
+/// outbox
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutboxAggregateType {
+    PaymentIntent,
+    PaymentAttempt,
+    Refund,
+    OutgoingWebhook,
+}
+
+/// The database operation recorded by an outbox entry
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutboxOperation {
+    Insert,
+    Update,
+}
+
+/// The publishing status of an outbox entry
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutboxStatus {
+    /// The entry is waiting to be published by the relay
+    #[default]
+    Pending,
+    /// The entry was published at least once
+    Published,
+    /// The relay gave up on the entry after exhausting its attempts
+    Failed,
+}
+
+/// The kind of a data subject request made on behalf of a customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerDataRequestType {
+    /// Export of all the data held about the customer
+    Export,
+    /// Erasure of all the data held about the customer
+    Erasure,
+}
+
+/// The status of a data subject request
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerDataRequestStatus {
+    /// The request is waiting to be processed
+    #[default]
+    Pending,
+    /// The request is being processed
+    Processing,
+    /// The request was processed successfully
+    Completed,
+    /// The request could not be processed
+    Failed,
+}
+
+/// A step of the erasure of a customer's data, executed in declaration order
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerErasureStep {
+    /// Deletion of the payment methods, from the locker and the database
+    PaymentMethods,
+    /// Redaction of the addresses of the customer and of their payments
+    Addresses,
+    /// Redaction of the customer record
+    Customer,
+    /// Publication of the tombstone consumed by the events pipeline and analytics
+    Tombstone,
+}
+
+/// The customer attribute a match key was derived from
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerMatchKeyType {
+    /// The email address of the customer
+    Email,
+    /// The phone number of the customer, including the country code
+    Phone,
+}
+
+/// A table whose records are archived by the data retention policies
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DataRetentionEntity {
+    /// The `payment_intent` table
+    PaymentIntent,
+    /// The `payment_attempt` table
+    PaymentAttempt,
+    /// The `refund` table
+    Refund,
+    /// The `events` table, holding the outgoing webhooks
+    Event,
+    /// The `process_tracker` table, only the finished tasks are archived
+    ProcessTracker,
+}
+
+/// The format of the files the archived records are exported to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ArchiveFileFormat {
+    /// Gzip compressed file holding one JSON document per line
+    #[default]
+    Ndjson,
+    /// Snappy compressed columnar file
+    Parquet,
+}
+
+/// How the archived records are removed from the database
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DataPurgeMode {
+    /// The archived records are deleted in batches
+    #[default]
+    Delete,
+    /// The partitions of the table whose range ends before the retention period are detached
+    /// once all their records are archived, the table must be range partitioned on `created_at`.
+    /// Only supported for events, as the other tables keep the records that are still in use
+    DetachPartition,
+}
+
+/// The service the saved cards are submitted to for account updates
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountUpdaterConnector {
+    /// Request and response files exchanged through the file storage
+    #[default]
+    File,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountUpdaterBatchStatus {
+    /// The batch was submitted and its results are awaited
+    Submitted,
+    /// The results of the batch were applied to the payment methods
+    Completed,
+    /// No results were received for the batch before it timed out
+    Failed,
+}
+
+/// Why a saved card was submitted to the account updater
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountUpdaterReason {
+    /// The card expires soon
+    Expiring,
+    /// A recurring payment with the card was declined
+    Declined,
+}
+
+/// The outcome of an account update inquiry for a saved card
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountUpdaterResult {
+    /// The card was reissued with a new account number
+    NewAccountNumber,
+    /// The card was reissued with a new expiry date
+    NewExpiry,
+    /// The account of the card was closed
+    AccountClosed,
+    /// The card is still valid as saved
+    NoChange,
+    /// The issuer asks for the cardholder to be contacted
+    ContactCardholder,
+    /// The issuer does not participate in the account updater or the card is unknown
+    NoMatch,
+}
+
+impl AccountUpdaterResult {
+    /// Whether the result changes the saved payment method
+    pub fn is_update(self) -> bool {
+        matches!(
+            self,
+            Self::NewAccountNumber | Self::NewExpiry | Self::AccountClosed
+        )
+    }
+}
+
+/// How the amount of a subscription price is computed from the subscribed quantity
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BillingPricingModel {
+    /// A fixed amount per billing cycle, irrespective of the quantity
+    Flat,
+    /// The unit amount multiplied by the quantity
+    PerUnit,
+    /// Graduated tiers, the units falling in each tier are charged at the price of that tier
+    Tiered,
+}
+
+/// The unit of the billing cycle of a subscription price
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BillingInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// How a subscription coupon reduces the invoice amount
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CouponDiscountType {
+    /// A percentage of the invoice amount
+    Percentage,
+    /// A fixed amount in the currency of the coupon
+    FixedAmount,
+}
+
+/// For how many billing cycles a subscription coupon applies
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CouponDuration {
+    /// Only the first invoice of the subscription
+    Once,
+    /// The invoices of a fixed number of billing cycles
+    Repeating,
+    /// Every invoice of the subscription
+    Forever,
+}
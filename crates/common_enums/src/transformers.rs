@@ -2,12 +2,12 @@ use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "payouts")]
-use crate::enums::PayoutStatus;
 use crate::enums::{
     AttemptStatus, Country, CountryAlpha2, CountryAlpha3, DisputeStatus, EventType, IntentStatus,
     MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus, SubscriptionStatus,
 };
+#[cfg(feature = "payouts")]
+use crate::enums::{PayoutBatchStatus, PayoutStatus};
 
 impl Display for NumericCountryCodeParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -2196,6 +2196,20 @@ impl From<PayoutStatus> for Option<EventType> {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl From<PayoutBatchStatus> for Option<EventType> {
+    fn from(value: PayoutBatchStatus) -> Self {
+        match value {
+            PayoutBatchStatus::Completed
+            | PayoutBatchStatus::PartiallyCompleted
+            | PayoutBatchStatus::Failed => Some(EventType::PayoutBatchCompleted),
+            PayoutBatchStatus::PendingApproval
+            | PayoutBatchStatus::Rejected
+            | PayoutBatchStatus::Processing => None,
+        }
+    }
+}
+
 impl From<DisputeStatus> for EventType {
     fn from(value: DisputeStatus) -> Self {
         match value {
@@ -0,0 +1,72 @@
+//! Subscription related types
+
+use common_utils::{impl_to_sql_from_sql_json, types::MinorUnit};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The tiers of a tiered subscription price, ordered by their upper bound
+#[derive(
+    Clone, Debug, Eq, PartialEq, Serialize, Deserialize, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(transparent)]
+pub struct PriceTiers(pub Vec<PriceTier>);
+
+impl_to_sql_from_sql_json!(PriceTiers);
+
+/// A tier of a tiered subscription price
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PriceTier {
+    /// The last unit, inclusive, charged at the price of this tier. The last tier has no upper
+    /// bound
+    pub up_to: Option<u32>,
+    /// The amount charged for every unit in this tier
+    #[schema(value_type = i64)]
+    pub unit_amount: MinorUnit,
+    /// The amount charged once when any unit falls in this tier
+    #[schema(value_type = Option<i64>)]
+    pub flat_amount: Option<MinorUnit>,
+}
+
+impl PriceTiers {
+    /// Validates that the tiers are in increasing order and that only the last tier is unbounded
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let Some((last, bounded)) = self.0.split_last() else {
+            return Err("tiers must contain at least one tier");
+        };
+        if last.up_to.is_some() {
+            return Err("the last tier must not have an upper bound");
+        }
+        bounded
+            .iter()
+            .try_fold(0, |previous, tier| match tier.up_to {
+                Some(up_to) if up_to > previous => Ok(up_to),
+                Some(_) => Err("the upper bounds of the tiers must be increasing"),
+                None => Err("only the last tier may be unbounded"),
+            })
+            .map(|_| ())
+    }
+
+    /// The amount charged for the quantity, every tier charging the units that fall in it
+    pub fn amount_for_quantity(&self, quantity: u32) -> MinorUnit {
+        let mut amount = MinorUnit::new(0);
+        let mut lower_bound = 0;
+        for tier in &self.0 {
+            if quantity <= lower_bound {
+                break;
+            }
+            let upper_bound = tier.up_to.map_or(quantity, |up_to| up_to.min(quantity));
+            let units = upper_bound.saturating_sub(lower_bound);
+            amount = amount
+                + MinorUnit::new(
+                    tier.unit_amount
+                        .get_amount_as_i64()
+                        .saturating_mul(i64::from(units)),
+                )
+                + tier.flat_amount.unwrap_or(MinorUnit::new(0));
+            lower_bound = upper_bound;
+        }
+        amount
+    }
+}
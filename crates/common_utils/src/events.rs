@@ -14,6 +14,9 @@ pub enum ApiEventsType {
     Payout {
         payout_id: id_type::PayoutId,
     },
+    PayoutBatch {
+        payout_batch_id: Option<String>,
+    },
     #[cfg(feature = "v1")]
     Payment {
         payment_id: id_type::PaymentId,
@@ -32,6 +35,9 @@ pub enum ApiEventsType {
         payment_id: Option<id_type::GlobalPaymentId>,
         refund_id: id_type::GlobalRefundId,
     },
+    BulkRefund {
+        bulk_refund_id: Option<String>,
+    },
     #[cfg(feature = "v1")]
     PaymentMethod {
         payment_method_id: String,
@@ -111,6 +117,15 @@ pub enum ApiEventsType {
     Dispute {
         dispute_id: String,
     },
+    DisputeEvidenceTemplate {
+        template_id: Option<String>,
+    },
+    Ledger,
+    SettlementReconciliation,
+    AccountUpdater,
+    Tenant {
+        tenant_id: id_type::TenantId,
+    },
     Events {
         merchant_id: id_type::MerchantId,
     },
@@ -22,6 +22,28 @@ impl crate::events::ApiEventMetric for ProfileId {
     }
 }
 
+impl ProfileId {
+    /// get_dispute_rules_key
+    pub fn get_dispute_rules_key(&self) -> String {
+        format!("dispute_rules_{}", self.get_string_repr())
+    }
+
+    /// get_frm_rules_key
+    pub fn get_frm_rules_key(&self) -> String {
+        format!("frm_rules_{}", self.get_string_repr())
+    }
+
+    /// get_sca_exemption_engine_key
+    pub fn get_sca_exemption_engine_key(&self) -> String {
+        format!("sca_exemption_engine_{}", self.get_string_repr())
+    }
+
+    /// get_dunning_policy_key
+    pub fn get_dunning_policy_key(&self) -> String {
+        format!("dunning_policy_{}", self.get_string_repr())
+    }
+}
+
 impl FromStr for ProfileId {
     type Err = error_stack::Report<crate::errors::ValidationError>;
 
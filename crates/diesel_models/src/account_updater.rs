@@ -0,0 +1,159 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{account_updater_batch, account_updater_card},
+};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = account_updater_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct AccountUpdaterBatch {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub connector: storage_enums::AccountUpdaterConnector,
+    pub status: storage_enums::AccountUpdaterBatchStatus,
+    pub card_count: i32,
+    pub updated_count: i32,
+    pub closed_count: i32,
+    pub unchanged_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = account_updater_batch)]
+pub struct AccountUpdaterBatchNew {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub connector: storage_enums::AccountUpdaterConnector,
+    pub status: storage_enums::AccountUpdaterBatchStatus,
+    pub card_count: i32,
+    pub updated_count: i32,
+    pub closed_count: i32,
+    pub unchanged_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum AccountUpdaterBatchUpdate {
+    ResultsApplied {
+        updated_count: i32,
+        closed_count: i32,
+        unchanged_count: i32,
+    },
+    TimedOut,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = account_updater_batch)]
+pub struct AccountUpdaterBatchUpdateInternal {
+    pub status: Option<storage_enums::AccountUpdaterBatchStatus>,
+    pub updated_count: Option<i32>,
+    pub closed_count: Option<i32>,
+    pub unchanged_count: Option<i32>,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+impl From<AccountUpdaterBatchUpdate> for AccountUpdaterBatchUpdateInternal {
+    fn from(update: AccountUpdaterBatchUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match update {
+            AccountUpdaterBatchUpdate::ResultsApplied {
+                updated_count,
+                closed_count,
+                unchanged_count,
+            } => Self {
+                status: Some(storage_enums::AccountUpdaterBatchStatus::Completed),
+                updated_count: Some(updated_count),
+                closed_count: Some(closed_count),
+                unchanged_count: Some(unchanged_count),
+                modified_at: now,
+                completed_at: Some(now),
+            },
+            AccountUpdaterBatchUpdate::TimedOut => Self {
+                status: Some(storage_enums::AccountUpdaterBatchStatus::Failed),
+                updated_count: None,
+                closed_count: None,
+                unchanged_count: None,
+                modified_at: now,
+                completed_at: None,
+            },
+        }
+    }
+}
+
+impl AccountUpdaterBatchUpdateInternal {
+    pub fn apply_changeset(self, source: AccountUpdaterBatch) -> AccountUpdaterBatch {
+        AccountUpdaterBatch {
+            status: self.status.unwrap_or(source.status),
+            updated_count: self.updated_count.unwrap_or(source.updated_count),
+            closed_count: self.closed_count.unwrap_or(source.closed_count),
+            unchanged_count: self.unchanged_count.unwrap_or(source.unchanged_count),
+            modified_at: self.modified_at,
+            completed_at: self.completed_at.or(source.completed_at),
+            ..source
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = account_updater_card, primary_key(batch_id, payment_method_id), check_for_backend(diesel::pg::Pg))]
+pub struct AccountUpdaterCard {
+    pub batch_id: String,
+    pub payment_method_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub reason: storage_enums::AccountUpdaterReason,
+    pub result: Option<storage_enums::AccountUpdaterResult>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = account_updater_card)]
+pub struct AccountUpdaterCardNew {
+    pub batch_id: String,
+    pub payment_method_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub reason: storage_enums::AccountUpdaterReason,
+    pub result: Option<storage_enums::AccountUpdaterResult>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = account_updater_card)]
+pub struct AccountUpdaterCardUpdate {
+    pub result: Option<storage_enums::AccountUpdaterResult>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// Selection of the saved cards which are due for an account updater inquiry.
+#[derive(Clone, Debug)]
+pub struct AccountUpdaterCandidate {
+    pub payment_method_id: String,
+    pub reason: storage_enums::AccountUpdaterReason,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountUpdaterTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub connector: storage_enums::AccountUpdaterConnector,
+}
@@ -0,0 +1,304 @@
+use common_types::subscriptions::PriceTiers;
+use common_utils::{id_type, pii::SecretSerdeValue, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{billing_coupon, billing_plan, billing_price, billing_product},
+};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = billing_product, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct BillingProduct {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_active: bool,
+    pub metadata: Option<SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = billing_product)]
+pub struct BillingProductNew {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_active: bool,
+    pub metadata: Option<SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, AsChangeset, router_derive::DebugAsDisplay, Deserialize)]
+#[diesel(table_name = billing_product)]
+pub struct BillingProductUpdate {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub is_active: Option<bool>,
+    pub metadata: Option<SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl BillingProductUpdate {
+    pub fn apply_changeset(self, source: BillingProduct) -> BillingProduct {
+        BillingProduct {
+            name: self.name.unwrap_or(source.name),
+            description: self.description.or(source.description),
+            is_active: self.is_active.unwrap_or(source.is_active),
+            metadata: self.metadata.or(source.metadata),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = billing_plan, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct BillingPlan {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub product_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = billing_plan)]
+pub struct BillingPlanNew {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub product_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub metadata: Option<SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, AsChangeset, router_derive::DebugAsDisplay, Deserialize)]
+#[diesel(table_name = billing_plan)]
+pub struct BillingPlanUpdate {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub trial_period_days: Option<i32>,
+    pub is_active: Option<bool>,
+    pub metadata: Option<SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl BillingPlanUpdate {
+    pub fn apply_changeset(self, source: BillingPlan) -> BillingPlan {
+        BillingPlan {
+            name: self.name.unwrap_or(source.name),
+            description: self.description.or(source.description),
+            trial_period_days: self.trial_period_days.or(source.trial_period_days),
+            is_active: self.is_active.unwrap_or(source.is_active),
+            metadata: self.metadata.or(source.metadata),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = billing_price, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct BillingPrice {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub plan_id: String,
+    pub currency: storage_enums::Currency,
+    pub pricing_model: storage_enums::BillingPricingModel,
+    pub unit_amount: Option<MinorUnit>,
+    pub tiers: Option<PriceTiers>,
+    pub billing_interval: storage_enums::BillingInterval,
+    pub interval_count: i32,
+    pub is_active: bool,
+    pub metadata: Option<SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = billing_price)]
+pub struct BillingPriceNew {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub plan_id: String,
+    pub currency: storage_enums::Currency,
+    pub pricing_model: storage_enums::BillingPricingModel,
+    pub unit_amount: Option<MinorUnit>,
+    pub tiers: Option<PriceTiers>,
+    pub billing_interval: storage_enums::BillingInterval,
+    pub interval_count: i32,
+    pub is_active: bool,
+    pub metadata: Option<SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The amounts and the billing cycle of a price are fixed once it is created, a price is
+/// replaced by archiving it and creating a new one
+#[derive(Clone, Debug, Eq, PartialEq, AsChangeset, router_derive::DebugAsDisplay, Deserialize)]
+#[diesel(table_name = billing_price)]
+pub struct BillingPriceUpdate {
+    pub is_active: Option<bool>,
+    pub metadata: Option<SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl BillingPriceUpdate {
+    pub fn apply_changeset(self, source: BillingPrice) -> BillingPrice {
+        BillingPrice {
+            is_active: self.is_active.unwrap_or(source.is_active),
+            metadata: self.metadata.or(source.metadata),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = billing_coupon, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct BillingCoupon {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub code: String,
+    pub name: String,
+    pub discount_type: storage_enums::CouponDiscountType,
+    pub percent_off: Option<i32>,
+    pub amount_off: Option<MinorUnit>,
+    pub currency: Option<storage_enums::Currency>,
+    pub duration: storage_enums::CouponDuration,
+    pub duration_in_cycles: Option<i32>,
+    pub max_redemptions: Option<i32>,
+    pub times_redeemed: i32,
+    pub redeem_by: Option<PrimitiveDateTime>,
+    pub is_active: bool,
+    pub metadata: Option<SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = billing_coupon)]
+pub struct BillingCouponNew {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub code: String,
+    pub name: String,
+    pub discount_type: storage_enums::CouponDiscountType,
+    pub percent_off: Option<i32>,
+    pub amount_off: Option<MinorUnit>,
+    pub currency: Option<storage_enums::Currency>,
+    pub duration: storage_enums::CouponDuration,
+    pub duration_in_cycles: Option<i32>,
+    pub max_redemptions: Option<i32>,
+    pub times_redeemed: i32,
+    pub redeem_by: Option<PrimitiveDateTime>,
+    pub is_active: bool,
+    pub metadata: Option<SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum BillingCouponUpdate {
+    Update {
+        name: Option<String>,
+        max_redemptions: Option<i32>,
+        redeem_by: Option<PrimitiveDateTime>,
+        is_active: Option<bool>,
+        metadata: Option<SecretSerdeValue>,
+    },
+    Redeemed {
+        times_redeemed: i32,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = billing_coupon)]
+pub struct BillingCouponUpdateInternal {
+    pub name: Option<String>,
+    pub max_redemptions: Option<i32>,
+    pub times_redeemed: Option<i32>,
+    pub redeem_by: Option<PrimitiveDateTime>,
+    pub is_active: Option<bool>,
+    pub metadata: Option<SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<BillingCouponUpdate> for BillingCouponUpdateInternal {
+    fn from(update: BillingCouponUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match update {
+            BillingCouponUpdate::Update {
+                name,
+                max_redemptions,
+                redeem_by,
+                is_active,
+                metadata,
+            } => Self {
+                name,
+                max_redemptions,
+                times_redeemed: None,
+                redeem_by,
+                is_active,
+                metadata,
+                modified_at,
+            },
+            BillingCouponUpdate::Redeemed { times_redeemed } => Self {
+                name: None,
+                max_redemptions: None,
+                times_redeemed: Some(times_redeemed),
+                redeem_by: None,
+                is_active: None,
+                metadata: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+impl BillingCouponUpdateInternal {
+    pub fn apply_changeset(self, source: BillingCoupon) -> BillingCoupon {
+        BillingCoupon {
+            name: self.name.unwrap_or(source.name),
+            max_redemptions: self.max_redemptions.or(source.max_redemptions),
+            times_redeemed: self.times_redeemed.unwrap_or(source.times_redeemed),
+            redeem_by: self.redeem_by.or(source.redeem_by),
+            is_active: self.is_active.unwrap_or(source.is_active),
+            metadata: self.metadata.or(source.metadata),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
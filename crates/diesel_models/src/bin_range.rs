@@ -0,0 +1,65 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{cards_info::CardInfo, enums as storage_enums, schema::bin_range};
+
+/// A range of card numbers sharing the same issuer details. The bounds are BIN prefixes of equal
+/// length between 6 and 11 digits, both inclusive.
+#[derive(
+    Clone, Debug, Queryable, Identifiable, Selectable, serde::Deserialize, serde::Serialize,
+)]
+#[diesel(table_name = bin_range, primary_key(bin_range_id), check_for_backend(diesel::pg::Pg))]
+pub struct BinRange {
+    pub bin_range_id: String,
+    pub range_low: String,
+    pub range_high: String,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<storage_enums::CardNetwork>,
+    /// The funding type of the card, such as credit, debit or prepaid
+    pub card_type: Option<String>,
+    /// The product type of the card, such as classic, gold or business
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub country_code: Option<String>,
+    pub bank_code: Option<String>,
+    /// The provider of the BIN file the range was loaded from
+    pub source: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = bin_range)]
+pub struct BinRangeNew {
+    pub bin_range_id: String,
+    pub range_low: String,
+    pub range_high: String,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<storage_enums::CardNetwork>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub country_code: Option<String>,
+    pub bank_code: Option<String>,
+    pub source: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+impl BinRange {
+    /// Describes the card with the given IIN using the issuer details of the range.
+    pub fn into_card_info(self, card_iin: String) -> CardInfo {
+        CardInfo {
+            card_iin,
+            card_issuer: self.card_issuer,
+            card_network: self.card_network,
+            card_type: self.card_type,
+            card_subtype: self.card_subtype,
+            card_issuing_country: self.card_issuing_country,
+            bank_code_id: None,
+            bank_code: self.bank_code,
+            country_code: self.country_code,
+            date_created: self.created_at,
+            last_updated: None,
+            last_updated_provider: Some(self.source),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{bulk_refund, bulk_refund_item},
+};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = bulk_refund, primary_key(bulk_refund_id), check_for_backend(diesel::pg::Pg))]
+pub struct BulkRefund {
+    pub bulk_refund_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub status: storage_enums::BulkRefundStatus,
+    pub total_count: i32,
+    pub submitted_count: i32,
+    pub failed_count: i32,
+    pub created_by: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = bulk_refund)]
+pub struct BulkRefundNew {
+    pub bulk_refund_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub status: storage_enums::BulkRefundStatus,
+    pub total_count: i32,
+    pub submitted_count: i32,
+    pub failed_count: i32,
+    pub created_by: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum BulkRefundUpdate {
+    ProgressUpdate {
+        submitted_count: i32,
+        failed_count: i32,
+    },
+    StatusUpdate {
+        status: storage_enums::BulkRefundStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = bulk_refund)]
+pub struct BulkRefundUpdateInternal {
+    pub status: Option<storage_enums::BulkRefundStatus>,
+    pub submitted_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<BulkRefundUpdate> for BulkRefundUpdateInternal {
+    fn from(update: BulkRefundUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match update {
+            BulkRefundUpdate::ProgressUpdate {
+                submitted_count,
+                failed_count,
+            } => Self {
+                status: None,
+                submitted_count: Some(submitted_count),
+                failed_count: Some(failed_count),
+                modified_at,
+            },
+            BulkRefundUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                submitted_count: None,
+                failed_count: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = bulk_refund_item, primary_key(bulk_refund_id, item_index), check_for_backend(diesel::pg::Pg))]
+pub struct BulkRefundItem {
+    pub bulk_refund_id: String,
+    pub item_index: i32,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub refund_id: String,
+    pub amount: Option<MinorUnit>,
+    pub request_data: pii::SecretSerdeValue,
+    pub connector: Option<String>,
+    pub status: storage_enums::BulkRefundItemStatus,
+    pub refund_status: Option<storage_enums::RefundStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = bulk_refund_item)]
+pub struct BulkRefundItemNew {
+    pub bulk_refund_id: String,
+    pub item_index: i32,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub refund_id: String,
+    pub amount: Option<MinorUnit>,
+    pub request_data: pii::SecretSerdeValue,
+    pub connector: Option<String>,
+    pub status: storage_enums::BulkRefundItemStatus,
+    pub refund_status: Option<storage_enums::RefundStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = bulk_refund_item)]
+pub struct BulkRefundItemUpdate {
+    pub connector: Option<String>,
+    pub status: storage_enums::BulkRefundItemStatus,
+    pub refund_status: Option<storage_enums::RefundStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BulkRefundTrackingData {
+    pub bulk_refund_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
@@ -0,0 +1,101 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::customer_data_request};
+
+/// Audit record of a data export or erasure requested on behalf of a customer
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = customer_data_request, primary_key(request_id), check_for_backend(diesel::pg::Pg))]
+pub struct CustomerDataRequest {
+    pub request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub request_type: storage_enums::CustomerDataRequestType,
+    pub status: storage_enums::CustomerDataRequestStatus,
+    pub file_id: Option<String>,
+    pub requested_by: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = customer_data_request)]
+pub struct CustomerDataRequestNew {
+    pub request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub request_type: storage_enums::CustomerDataRequestType,
+    pub status: storage_enums::CustomerDataRequestStatus,
+    pub file_id: Option<String>,
+    pub requested_by: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum CustomerDataRequestUpdate {
+    StatusUpdate {
+        status: storage_enums::CustomerDataRequestStatus,
+        error_message: Option<String>,
+    },
+    Completed {
+        file_id: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_data_request)]
+pub struct CustomerDataRequestUpdateInternal {
+    pub status: Option<storage_enums::CustomerDataRequestStatus>,
+    pub file_id: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+impl From<CustomerDataRequestUpdate> for CustomerDataRequestUpdateInternal {
+    fn from(update: CustomerDataRequestUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match update {
+            CustomerDataRequestUpdate::StatusUpdate {
+                status,
+                error_message,
+            } => Self {
+                status: Some(status),
+                file_id: None,
+                error_message,
+                modified_at,
+                completed_at: None,
+            },
+            CustomerDataRequestUpdate::Completed { file_id } => Self {
+                status: Some(storage_enums::CustomerDataRequestStatus::Completed),
+                file_id,
+                error_message: None,
+                modified_at,
+                completed_at: Some(modified_at),
+            },
+        }
+    }
+}
+
+/// Tracking data of the erasure workflow, the completed steps are persisted after each step so
+/// that a retried task resumes where the previous attempt stopped
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomerErasureTrackingData {
+    pub request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    #[serde(default)]
+    pub completed_steps: Vec<storage_enums::CustomerErasureStep>,
+}
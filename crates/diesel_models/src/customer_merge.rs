@@ -0,0 +1,60 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{customer_match_key, customer_redirect},
+};
+
+/// Redirect left behind by a customer merged into another customer
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = customer_redirect, primary_key(merchant_id, customer_id), check_for_backend(diesel::pg::Pg))]
+pub struct CustomerRedirect {
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub target_customer_id: id_type::CustomerId,
+    pub merge_id: String,
+    pub merged_by: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = customer_redirect)]
+pub struct CustomerRedirectNew {
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub target_customer_id: id_type::CustomerId,
+    pub merge_id: String,
+    pub merged_by: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Keyed hash of a customer attribute, used to find customers that are likely to be the same
+/// person without comparing the decrypted attributes
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = customer_match_key, primary_key(merchant_id, customer_id, key_type), check_for_backend(diesel::pg::Pg))]
+pub struct CustomerMatchKey {
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub key_type: storage_enums::CustomerMatchKeyType,
+    pub key_hash: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = customer_match_key)]
+pub struct CustomerMatchKeyNew {
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub key_type: storage_enums::CustomerMatchKeyType,
+    pub key_hash: String,
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,306 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums, errors,
+    events::Event,
+    outbox::OutboxAggregate,
+    process_tracker::ProcessTracker,
+    schema::{archived_record, data_retention_policy},
+    PaymentAttempt, PaymentIntent, Refund, StorageResult,
+};
+
+/// Retention policy of a table, the records older than the retention period are exported to the
+/// file storage and removed from the database by the data archival job
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = data_retention_policy, primary_key(entity), check_for_backend(diesel::pg::Pg))]
+pub struct DataRetentionPolicy {
+    pub entity: storage_enums::DataRetentionEntity,
+    pub retention_days: i32,
+    pub file_format: storage_enums::ArchiveFileFormat,
+    pub purge_mode: storage_enums::DataPurgeMode,
+    pub batch_size: i32,
+    pub is_enabled: bool,
+    pub archived_count: i64,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_archived_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = data_retention_policy)]
+pub struct DataRetentionPolicyNew {
+    pub entity: storage_enums::DataRetentionEntity,
+    pub retention_days: i32,
+    pub file_format: storage_enums::ArchiveFileFormat,
+    pub purge_mode: storage_enums::DataPurgeMode,
+    pub batch_size: i32,
+    pub is_enabled: bool,
+    pub archived_count: i64,
+    pub last_archived_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum DataRetentionPolicyUpdate {
+    Update {
+        retention_days: i32,
+        file_format: storage_enums::ArchiveFileFormat,
+        purge_mode: storage_enums::DataPurgeMode,
+        batch_size: i32,
+        is_enabled: bool,
+    },
+    ArchivalCompleted {
+        archived_count: i64,
+        last_archived_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_retention_policy)]
+pub struct DataRetentionPolicyUpdateInternal {
+    pub retention_days: Option<i32>,
+    pub file_format: Option<storage_enums::ArchiveFileFormat>,
+    pub purge_mode: Option<storage_enums::DataPurgeMode>,
+    pub batch_size: Option<i32>,
+    pub is_enabled: Option<bool>,
+    pub archived_count: Option<i64>,
+    pub last_archived_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<DataRetentionPolicyUpdate> for DataRetentionPolicyUpdateInternal {
+    fn from(update: DataRetentionPolicyUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match update {
+            DataRetentionPolicyUpdate::Update {
+                retention_days,
+                file_format,
+                purge_mode,
+                batch_size,
+                is_enabled,
+            } => Self {
+                retention_days: Some(retention_days),
+                file_format: Some(file_format),
+                purge_mode: Some(purge_mode),
+                batch_size: Some(batch_size),
+                is_enabled: Some(is_enabled),
+                archived_count: None,
+                last_archived_at: None,
+                modified_at,
+            },
+            DataRetentionPolicyUpdate::ArchivalCompleted {
+                archived_count,
+                last_archived_at,
+            } => Self {
+                retention_days: None,
+                file_format: None,
+                purge_mode: None,
+                batch_size: None,
+                is_enabled: None,
+                archived_count: Some(archived_count),
+                last_archived_at: Some(last_archived_at),
+                modified_at,
+            },
+        }
+    }
+}
+
+impl DataRetentionPolicyUpdate {
+    pub fn apply_changeset(self, source: DataRetentionPolicy) -> DataRetentionPolicy {
+        let DataRetentionPolicyUpdateInternal {
+            retention_days,
+            file_format,
+            purge_mode,
+            batch_size,
+            is_enabled,
+            archived_count,
+            last_archived_at,
+            modified_at,
+        } = self.into();
+        DataRetentionPolicy {
+            retention_days: retention_days.unwrap_or(source.retention_days),
+            file_format: file_format.unwrap_or(source.file_format),
+            purge_mode: purge_mode.unwrap_or(source.purge_mode),
+            batch_size: batch_size.unwrap_or(source.batch_size),
+            is_enabled: is_enabled.unwrap_or(source.is_enabled),
+            archived_count: archived_count.unwrap_or(source.archived_count),
+            last_archived_at: last_archived_at.or(source.last_archived_at),
+            modified_at,
+            ..source
+        }
+    }
+}
+
+/// Index entry of an archived record, pointing to the file the record was exported to so that
+/// the record can still be retrieved once removed from the database
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = archived_record, primary_key(merchant_id, entity, record_id), check_for_backend(diesel::pg::Pg))]
+pub struct ArchivedRecord {
+    pub merchant_id: id_type::MerchantId,
+    pub entity: storage_enums::DataRetentionEntity,
+    pub record_id: String,
+    pub payment_id: Option<String>,
+    pub file_key: String,
+    pub file_format: storage_enums::ArchiveFileFormat,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub record_created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub archived_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = archived_record)]
+pub struct ArchivedRecordNew {
+    pub merchant_id: id_type::MerchantId,
+    pub entity: storage_enums::DataRetentionEntity,
+    pub record_id: String,
+    pub payment_id: Option<String>,
+    pub file_key: String,
+    pub file_format: storage_enums::ArchiveFileFormat,
+    pub record_created_at: PrimitiveDateTime,
+    pub archived_at: PrimitiveDateTime,
+}
+
+/// A record selected for archival, serialized in the layout of its table, along with the
+/// identifiers it is indexed and deleted by
+#[derive(Clone, Debug)]
+pub struct ArchivableRecord {
+    pub record_id: String,
+    /// The merchant of the record, records without a merchant are archived without being indexed
+    pub merchant_id: Option<id_type::MerchantId>,
+    /// The payment the record belongs to, if any
+    pub payment_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub data: serde_json::Value,
+}
+
+impl ArchivableRecord {
+    pub fn new<T: Serialize>(
+        record_id: String,
+        merchant_id: Option<id_type::MerchantId>,
+        payment_id: Option<String>,
+        created_at: PrimitiveDateTime,
+        record: &T,
+    ) -> StorageResult<Self> {
+        let data = serde_json::to_value(record)
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable_lazy(|| format!("Failed to serialize record {record_id}"))?;
+        Ok(Self {
+            record_id,
+            merchant_id,
+            payment_id,
+            created_at,
+            data,
+        })
+    }
+
+    fn from_aggregate<T: OutboxAggregate>(
+        record: &T,
+        payment_id: &str,
+        created_at: PrimitiveDateTime,
+    ) -> StorageResult<Self> {
+        Self::new(
+            record.get_aggregate_id().to_owned(),
+            Some(record.get_merchant_id().to_owned()),
+            Some(payment_id.to_owned()),
+            created_at,
+            record,
+        )
+    }
+}
+
+impl TryFrom<&PaymentIntent> for ArchivableRecord {
+    type Error = error_stack::Report<errors::DatabaseError>;
+
+    fn try_from(payment_intent: &PaymentIntent) -> Result<Self, Self::Error> {
+        #[cfg(feature = "v1")]
+        let payment_id = &payment_intent.payment_id;
+        #[cfg(feature = "v2")]
+        let payment_id = &payment_intent.id;
+        Self::from_aggregate(
+            payment_intent,
+            payment_id.get_string_repr(),
+            payment_intent.created_at,
+        )
+    }
+}
+
+impl TryFrom<&PaymentAttempt> for ArchivableRecord {
+    type Error = error_stack::Report<errors::DatabaseError>;
+
+    fn try_from(payment_attempt: &PaymentAttempt) -> Result<Self, Self::Error> {
+        Self::from_aggregate(
+            payment_attempt,
+            payment_attempt.payment_id.get_string_repr(),
+            payment_attempt.created_at,
+        )
+    }
+}
+
+impl TryFrom<&Refund> for ArchivableRecord {
+    type Error = error_stack::Report<errors::DatabaseError>;
+
+    fn try_from(refund: &Refund) -> Result<Self, Self::Error> {
+        Self::from_aggregate(
+            refund,
+            refund.payment_id.get_string_repr(),
+            refund.created_at,
+        )
+    }
+}
+
+impl TryFrom<&Event> for ArchivableRecord {
+    type Error = error_stack::Report<errors::DatabaseError>;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        let payment_id = (event.primary_object_type
+            == storage_enums::EventObjectType::PaymentDetails)
+            .then(|| event.primary_object_id.clone());
+        Self::new(
+            event.event_id.clone(),
+            event.merchant_id.clone(),
+            payment_id,
+            event.created_at,
+            event,
+        )
+    }
+}
+
+impl TryFrom<&ProcessTracker> for ArchivableRecord {
+    type Error = error_stack::Report<errors::DatabaseError>;
+
+    fn try_from(process: &ProcessTracker) -> Result<Self, Self::Error> {
+        Self::new(process.id.clone(), None, None, process.created_at, process)
+    }
+}
+
+/// A partition of a table range partitioned on `created_at`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TablePartition {
+    pub name: String,
+    /// Inclusive lower bound of the partition, `None` when the partition starts at `MINVALUE`
+    pub range_start: Option<PrimitiveDateTime>,
+    /// Exclusive upper bound of the partition, `None` when the partition ends at `MAXVALUE`
+    pub range_end: Option<PrimitiveDateTime>,
+}
+
+/// Creation time range of the records selected for archival
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ArchivalWindow {
+    /// Inclusive lower bound, `None` to select every record created before `created_before`
+    pub created_from: Option<PrimitiveDateTime>,
+    /// Exclusive upper bound
+    pub created_before: PrimitiveDateTime,
+}
@@ -0,0 +1,66 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::dispute_automated_action};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = dispute_automated_action)]
+pub struct DisputeAutomatedActionNew {
+    pub action_id: String,
+    pub dispute_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub rule_name: Option<String>,
+    pub action: storage_enums::DisputeRuleAction,
+    pub status: storage_enums::DisputeAutomatedActionStatus,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = dispute_automated_action, primary_key(action_id), check_for_backend(diesel::pg::Pg))]
+pub struct DisputeAutomatedAction {
+    pub action_id: String,
+    pub dispute_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub rule_name: Option<String>,
+    pub action: storage_enums::DisputeRuleAction,
+    pub status: storage_enums::DisputeAutomatedActionStatus,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum DisputeAutomatedActionUpdate {
+    StatusUpdate {
+        status: storage_enums::DisputeAutomatedActionStatus,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = dispute_automated_action)]
+pub struct DisputeAutomatedActionUpdateInternal {
+    pub status: storage_enums::DisputeAutomatedActionStatus,
+    pub error_message: Option<String>,
+}
+
+impl From<DisputeAutomatedActionUpdate> for DisputeAutomatedActionUpdateInternal {
+    fn from(update: DisputeAutomatedActionUpdate) -> Self {
+        match update {
+            DisputeAutomatedActionUpdate::StatusUpdate {
+                status,
+                error_message,
+            } => Self {
+                status,
+                error_message,
+            },
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::dispute_evidence_template;
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = dispute_evidence_template)]
+pub struct DisputeEvidenceTemplateNew {
+    pub template_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub name: String,
+    pub connector: Option<String>,
+    pub reason_codes: Vec<Option<String>>,
+    pub evidence_sources: serde_json::Value,
+    pub text_evidence: Option<serde_json::Value>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = dispute_evidence_template, primary_key(template_id), check_for_backend(diesel::pg::Pg))]
+pub struct DisputeEvidenceTemplate {
+    pub template_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub name: String,
+    pub connector: Option<String>,
+    pub reason_codes: Vec<Option<String>>,
+    pub evidence_sources: serde_json::Value,
+    pub text_evidence: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// Tracking data of the reminder which is sent when the evidence of a dispute is due
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DisputeEvidenceReminderTrackingData {
+    pub dispute_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
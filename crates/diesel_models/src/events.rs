@@ -76,6 +76,9 @@ pub enum EventMetadata {
     Payout {
         payout_id: common_utils::id_type::PayoutId,
     },
+    PayoutBatch {
+        payout_batch_id: String,
+    },
     #[cfg(feature = "v1")]
     Refund {
         payment_id: common_utils::id_type::PaymentId,
@@ -107,6 +110,14 @@ pub enum EventMetadata {
         invoice_id: Option<common_utils::id_type::InvoiceId>,
         payment_id: Option<common_utils::id_type::PaymentId>,
     },
+    Customer {
+        customer_id: common_utils::id_type::CustomerId,
+        merge_id: String,
+    },
+    PaymentMethod {
+        customer_id: common_utils::id_type::CustomerId,
+        payment_method_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
@@ -116,3 +116,29 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
         }
     }
 }
+
+impl FraudCheckUpdate {
+    pub fn apply_changeset(self, source: FraudCheck) -> FraudCheck {
+        let FraudCheckUpdateInternal {
+            frm_status,
+            frm_transaction_id,
+            frm_reason,
+            frm_score,
+            frm_error,
+            metadata,
+            last_step,
+            payment_capture_method,
+        } = self.into();
+        FraudCheck {
+            frm_status: frm_status.unwrap_or(source.frm_status),
+            frm_transaction_id: frm_transaction_id.or(source.frm_transaction_id),
+            frm_reason: frm_reason.or(source.frm_reason),
+            frm_score: frm_score.or(source.frm_score),
+            frm_error: frm_error.unwrap_or(source.frm_error),
+            metadata: metadata.or(source.metadata),
+            last_step,
+            payment_capture_method: payment_capture_method.or(source.payment_capture_method),
+            ..source
+        }
+    }
+}
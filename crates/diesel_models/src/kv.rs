@@ -85,16 +85,32 @@ pub struct TypedSql {
 }
 
 impl DBOperation {
-    pub async fn execute(self, conn: &PgPooledConn) -> crate::StorageResult<DBResult> {
+    /// Executes the operation, inserting the outbox entries of the payment intents, payment
+    /// attempts and refunds written when `with_outbox` is set
+    pub async fn execute(
+        self,
+        conn: &PgPooledConn,
+        with_outbox: bool,
+    ) -> crate::StorageResult<DBResult> {
         Ok(match self {
             Self::Insert { insertable } => match *insertable {
-                Insertable::PaymentIntent(a) => {
-                    DBResult::PaymentIntent(Box::new(a.insert(conn).await?))
-                }
+                Insertable::PaymentIntent(a) => DBResult::PaymentIntent(Box::new(if with_outbox {
+                    a.insert_with_outbox(conn).await?
+                } else {
+                    a.insert(conn).await?
+                })),
                 Insertable::PaymentAttempt(a) => {
-                    DBResult::PaymentAttempt(Box::new(a.insert(conn).await?))
+                    DBResult::PaymentAttempt(Box::new(if with_outbox {
+                        a.insert_with_outbox(conn).await?
+                    } else {
+                        a.insert(conn).await?
+                    }))
                 }
-                Insertable::Refund(a) => DBResult::Refund(Box::new(a.insert(conn).await?)),
+                Insertable::Refund(a) => DBResult::Refund(Box::new(if with_outbox {
+                    a.insert_with_outbox(conn).await?
+                } else {
+                    a.insert(conn).await?
+                })),
                 Insertable::Address(addr) => DBResult::Address(Box::new(addr.insert(conn).await?)),
                 Insertable::Customer(cust) => {
                     DBResult::Customer(Box::new(cust.insert(conn).await?))
@@ -114,28 +130,54 @@ impl DBOperation {
             Self::Update { updatable } => match *updatable {
                 #[cfg(feature = "v1")]
                 Updateable::PaymentIntentUpdate(a) => {
-                    DBResult::PaymentIntent(Box::new(a.orig.update(conn, a.update_data).await?))
+                    DBResult::PaymentIntent(Box::new(if with_outbox {
+                        a.orig.update_with_outbox(conn, a.update_data).await?
+                    } else {
+                        a.orig.update(conn, a.update_data).await?
+                    }))
                 }
                 #[cfg(feature = "v2")]
                 Updateable::PaymentIntentUpdate(a) => {
-                    DBResult::PaymentIntent(Box::new(a.orig.update(conn, a.update_data).await?))
+                    DBResult::PaymentIntent(Box::new(if with_outbox {
+                        a.orig.update_with_outbox(conn, a.update_data).await?
+                    } else {
+                        a.orig.update(conn, a.update_data).await?
+                    }))
                 }
                 #[cfg(feature = "v1")]
-                Updateable::PaymentAttemptUpdate(a) => DBResult::PaymentAttempt(Box::new(
-                    a.orig.update_with_attempt_id(conn, a.update_data).await?,
-                )),
-                #[cfg(feature = "v2")]
-                Updateable::PaymentAttemptUpdate(a) => DBResult::PaymentAttempt(Box::new(
-                    a.orig.update_with_attempt_id(conn, a.update_data).await?,
-                )),
-                #[cfg(feature = "v1")]
-                Updateable::RefundUpdate(a) => {
-                    DBResult::Refund(Box::new(a.orig.update(conn, a.update_data).await?))
+                Updateable::PaymentAttemptUpdate(a) => {
+                    DBResult::PaymentAttempt(Box::new(if with_outbox {
+                        a.orig
+                            .update_with_attempt_id_and_outbox(conn, a.update_data)
+                            .await?
+                    } else {
+                        a.orig.update_with_attempt_id(conn, a.update_data).await?
+                    }))
                 }
                 #[cfg(feature = "v2")]
-                Updateable::RefundUpdate(a) => {
-                    DBResult::Refund(Box::new(a.orig.update_with_id(conn, a.update_data).await?))
+                Updateable::PaymentAttemptUpdate(a) => {
+                    DBResult::PaymentAttempt(Box::new(if with_outbox {
+                        a.orig
+                            .update_with_attempt_id_and_outbox(conn, a.update_data)
+                            .await?
+                    } else {
+                        a.orig.update_with_attempt_id(conn, a.update_data).await?
+                    }))
                 }
+                #[cfg(feature = "v1")]
+                Updateable::RefundUpdate(a) => DBResult::Refund(Box::new(if with_outbox {
+                    a.orig.update_with_outbox(conn, a.update_data).await?
+                } else {
+                    a.orig.update(conn, a.update_data).await?
+                })),
+                #[cfg(feature = "v2")]
+                Updateable::RefundUpdate(a) => DBResult::Refund(Box::new(if with_outbox {
+                    a.orig
+                        .update_with_id_and_outbox(conn, a.update_data)
+                        .await?
+                } else {
+                    a.orig.update_with_id(conn, a.update_data).await?
+                })),
                 Updateable::AddressUpdate(a) => {
                     DBResult::Address(Box::new(a.orig.update(conn, a.update_data).await?))
                 }
@@ -0,0 +1,105 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{ledger_fee_schedule, ledger_journal_entry, ledger_posting},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = ledger_fee_schedule)]
+pub struct LedgerFeeScheduleNew {
+    pub fee_schedule_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub connector: Option<String>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub currency: Option<storage_enums::Currency>,
+    pub fixed_fee: MinorUnit,
+    pub percentage_fee_basis_points: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = ledger_fee_schedule, primary_key(fee_schedule_id), check_for_backend(diesel::pg::Pg))]
+pub struct LedgerFeeSchedule {
+    pub fee_schedule_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub connector: Option<String>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub currency: Option<storage_enums::Currency>,
+    pub fixed_fee: MinorUnit,
+    pub percentage_fee_basis_points: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = ledger_journal_entry)]
+pub struct LedgerJournalEntryNew {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub entry_type: storage_enums::LedgerEntryType,
+    pub reference_id: String,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub currency: storage_enums::Currency,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = ledger_journal_entry, primary_key(entry_id), check_for_backend(diesel::pg::Pg))]
+pub struct LedgerJournalEntry {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub entry_type: storage_enums::LedgerEntryType,
+    pub reference_id: String,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub currency: storage_enums::Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = ledger_posting)]
+pub struct LedgerPostingNew {
+    pub posting_id: String,
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub account: storage_enums::LedgerAccount,
+    pub direction: storage_enums::LedgerPostingDirection,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = ledger_posting, primary_key(posting_id), check_for_backend(diesel::pg::Pg))]
+pub struct LedgerPosting {
+    pub posting_id: String,
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub account: storage_enums::LedgerAccount,
+    pub direction: storage_enums::LedgerPostingDirection,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
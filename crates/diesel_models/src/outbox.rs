@@ -0,0 +1,302 @@
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    id_type,
+};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums, errors, events::Event, schema::outbox, PaymentAttempt, PaymentIntent,
+    Refund, StorageResult,
+};
+
+/// A state change of a payment intent, payment attempt or refund, or an outgoing webhook to
+/// deliver, written in the same database transaction as the change itself. The outbox relay
+/// publishes the state changes to the events pipeline and delivers the webhooks.
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = outbox, primary_key(outbox_id), check_for_backend(diesel::pg::Pg))]
+pub struct Outbox {
+    pub outbox_id: String,
+    pub dedupe_key: String,
+    pub aggregate_type: storage_enums::OutboxAggregateType,
+    pub aggregate_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub operation: storage_enums::OutboxOperation,
+    pub payload: serde_json::Value,
+    pub status: storage_enums::OutboxStatus,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub published_at: Option<PrimitiveDateTime>,
+    /// Time before which a pending entry that failed to be published is not published again
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_attempt_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = outbox)]
+pub struct OutboxNew {
+    pub outbox_id: String,
+    pub dedupe_key: String,
+    pub aggregate_type: storage_enums::OutboxAggregateType,
+    pub aggregate_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub operation: storage_enums::OutboxOperation,
+    pub payload: serde_json::Value,
+    pub status: storage_enums::OutboxStatus,
+    pub attempts: i32,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum OutboxUpdate {
+    Published {
+        published_at: PrimitiveDateTime,
+    },
+    PublishFailed {
+        attempts: i32,
+        last_error: String,
+        status: storage_enums::OutboxStatus,
+        next_attempt_at: Option<PrimitiveDateTime>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = outbox)]
+pub struct OutboxUpdateInternal {
+    pub status: Option<storage_enums::OutboxStatus>,
+    pub attempts: Option<i32>,
+    pub last_error: Option<String>,
+    pub published_at: Option<PrimitiveDateTime>,
+    pub next_attempt_at: Option<PrimitiveDateTime>,
+}
+
+impl From<OutboxUpdate> for OutboxUpdateInternal {
+    fn from(update: OutboxUpdate) -> Self {
+        match update {
+            OutboxUpdate::Published { published_at } => Self {
+                status: Some(storage_enums::OutboxStatus::Published),
+                attempts: None,
+                last_error: None,
+                published_at: Some(published_at),
+                next_attempt_at: None,
+            },
+            OutboxUpdate::PublishFailed {
+                attempts,
+                last_error,
+                status,
+                next_attempt_at,
+            } => Self {
+                status: Some(status),
+                attempts: Some(attempts),
+                last_error: Some(last_error),
+                published_at: None,
+                next_attempt_at,
+            },
+        }
+    }
+}
+
+/// A record whose state changes are mirrored into the outbox
+pub trait OutboxAggregate: Serialize {
+    const AGGREGATE_TYPE: storage_enums::OutboxAggregateType;
+
+    fn get_aggregate_id(&self) -> &str;
+
+    fn get_merchant_id(&self) -> Option<&id_type::MerchantId>;
+
+    fn get_payload(&self) -> StorageResult<serde_json::Value> {
+        hyperswitch_masking::masked_serialize(self)
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Failed to serialize the outbox payload")
+    }
+}
+
+impl OutboxAggregate for PaymentIntent {
+    const AGGREGATE_TYPE: storage_enums::OutboxAggregateType =
+        storage_enums::OutboxAggregateType::PaymentIntent;
+
+    #[cfg(feature = "v1")]
+    fn get_aggregate_id(&self) -> &str {
+        self.payment_id.get_string_repr()
+    }
+
+    #[cfg(feature = "v2")]
+    fn get_aggregate_id(&self) -> &str {
+        self.id.get_string_repr()
+    }
+
+    fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
+        Some(&self.merchant_id)
+    }
+}
+
+impl OutboxAggregate for PaymentAttempt {
+    const AGGREGATE_TYPE: storage_enums::OutboxAggregateType =
+        storage_enums::OutboxAggregateType::PaymentAttempt;
+
+    #[cfg(feature = "v1")]
+    fn get_aggregate_id(&self) -> &str {
+        &self.attempt_id
+    }
+
+    #[cfg(feature = "v2")]
+    fn get_aggregate_id(&self) -> &str {
+        self.id.get_string_repr()
+    }
+
+    fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
+        Some(&self.merchant_id)
+    }
+}
+
+impl OutboxAggregate for Refund {
+    const AGGREGATE_TYPE: storage_enums::OutboxAggregateType =
+        storage_enums::OutboxAggregateType::Refund;
+
+    #[cfg(feature = "v1")]
+    fn get_aggregate_id(&self) -> &str {
+        &self.refund_id
+    }
+
+    #[cfg(feature = "v2")]
+    fn get_aggregate_id(&self) -> &str {
+        self.id.get_string_repr()
+    }
+
+    fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
+        Some(&self.merchant_id)
+    }
+}
+
+impl OutboxAggregate for Event {
+    const AGGREGATE_TYPE: storage_enums::OutboxAggregateType =
+        storage_enums::OutboxAggregateType::OutgoingWebhook;
+
+    fn get_aggregate_id(&self) -> &str {
+        &self.event_id
+    }
+
+    fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
+        self.merchant_id.as_ref()
+    }
+
+    /// The entry only references the event, the relay reads the webhook request from the event
+    fn get_payload(&self) -> StorageResult<serde_json::Value> {
+        Ok(serde_json::json!({
+            "event_id": self.event_id,
+            "business_profile_id": self.business_profile_id,
+        }))
+    }
+}
+
+impl OutboxNew {
+    /// Builds the outbox entry of the written record. The dedupe key is derived from the digest of
+    /// the written record, so that replaying the same write, as the drainer may do, does not
+    /// produce a second entry, while two writes of different states always do, even when they
+    /// share the modification timestamp.
+    pub fn for_aggregate<T: OutboxAggregate>(
+        aggregate: &T,
+        operation: storage_enums::OutboxOperation,
+    ) -> StorageResult<Self> {
+        let payload = aggregate.get_payload()?;
+        let merchant_id = aggregate
+            .get_merchant_id()
+            .cloned()
+            .ok_or_else(|| error_stack::report!(errors::DatabaseError::Others))
+            .attach_printable("The outbox aggregate has no merchant")?;
+
+        Ok(Self {
+            outbox_id: common_utils::generate_time_ordered_id("outbox"),
+            dedupe_key: get_dedupe_key::<T>(aggregate.get_aggregate_id(), operation, &payload)?,
+            aggregate_type: T::AGGREGATE_TYPE,
+            aggregate_id: aggregate.get_aggregate_id().to_owned(),
+            merchant_id,
+            operation,
+            payload,
+            status: storage_enums::OutboxStatus::Pending,
+            attempts: 0,
+            created_at: common_utils::date_time::now(),
+        })
+    }
+}
+
+/// `{aggregate_type}_{aggregate_id}_{operation}_{payload digest}`
+fn get_dedupe_key<T: OutboxAggregate>(
+    aggregate_id: &str,
+    operation: storage_enums::OutboxOperation,
+    payload: &serde_json::Value,
+) -> StorageResult<String> {
+    let payload_digest = crypto::Sha256
+        .generate_digest(payload.to_string().as_bytes())
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to compute the digest of the outbox payload")?;
+
+    Ok(format!(
+        "{}_{aggregate_id}_{operation}_{}",
+        T::AGGREGATE_TYPE,
+        payload_digest
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct TestRefund {
+        refund_id: String,
+        merchant_id: id_type::MerchantId,
+        status: storage_enums::RefundStatus,
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        modified_at: PrimitiveDateTime,
+    }
+
+    impl OutboxAggregate for TestRefund {
+        const AGGREGATE_TYPE: storage_enums::OutboxAggregateType =
+            storage_enums::OutboxAggregateType::Refund;
+
+        fn get_aggregate_id(&self) -> &str {
+            &self.refund_id
+        }
+
+        fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
+            Some(&self.merchant_id)
+        }
+    }
+
+    #[test]
+    fn test_dedupe_key_is_unique_per_written_state() {
+        let modified_at = common_utils::date_time::now();
+        let refund = |status| TestRefund {
+            refund_id: "ref_1".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            status,
+            modified_at,
+        };
+        let dedupe_key = |refund: &TestRefund| {
+            OutboxNew::for_aggregate(refund, storage_enums::OutboxOperation::Update)
+                .unwrap()
+                .dedupe_key
+        };
+        let pending = refund(storage_enums::RefundStatus::Pending);
+        let succeeded = refund(storage_enums::RefundStatus::Success);
+
+        // Two state changes sharing the modification timestamp get their own entries
+        assert_ne!(dedupe_key(&pending), dedupe_key(&succeeded));
+        // Replaying the same write is deduplicated
+        assert_eq!(dedupe_key(&succeeded), dedupe_key(&succeeded));
+        assert!(dedupe_key(&succeeded).starts_with("refund_ref_1_update_"));
+    }
+}
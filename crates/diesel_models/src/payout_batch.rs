@@ -0,0 +1,175 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = payout_batch, primary_key(payout_batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub payout_batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub description: Option<String>,
+    pub file_id: Option<String>,
+    pub total_count: i32,
+    pub submitted_count: i32,
+    pub failed_count: i32,
+    pub created_by: Option<String>,
+    pub reviewed_by: Option<String>,
+    pub rejection_reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub payout_batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub description: Option<String>,
+    pub file_id: Option<String>,
+    pub total_count: i32,
+    pub submitted_count: i32,
+    pub failed_count: i32,
+    pub created_by: Option<String>,
+    pub reviewed_by: Option<String>,
+    pub rejection_reason: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    ReviewUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        reviewed_by: Option<String>,
+        rejection_reason: Option<String>,
+    },
+    ProgressUpdate {
+        submitted_count: i32,
+        failed_count: i32,
+    },
+    StatusUpdate {
+        status: storage_enums::PayoutBatchStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    pub status: Option<storage_enums::PayoutBatchStatus>,
+    pub submitted_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub reviewed_by: Option<String>,
+    pub rejection_reason: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(update: PayoutBatchUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match update {
+            PayoutBatchUpdate::ReviewUpdate {
+                status,
+                reviewed_by,
+                rejection_reason,
+            } => Self {
+                status: Some(status),
+                submitted_count: None,
+                failed_count: None,
+                reviewed_by,
+                rejection_reason,
+                modified_at,
+            },
+            PayoutBatchUpdate::ProgressUpdate {
+                submitted_count,
+                failed_count,
+            } => Self {
+                status: None,
+                submitted_count: Some(submitted_count),
+                failed_count: Some(failed_count),
+                reviewed_by: None,
+                rejection_reason: None,
+                modified_at,
+            },
+            PayoutBatchUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                submitted_count: None,
+                failed_count: None,
+                reviewed_by: None,
+                rejection_reason: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = payout_batch_item, primary_key(payout_batch_id, item_index), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatchItem {
+    pub payout_batch_id: String,
+    pub item_index: i32,
+    pub merchant_id: id_type::MerchantId,
+    pub payout_id: id_type::PayoutId,
+    pub customer_id: id_type::CustomerId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub request_data: pii::SecretSerdeValue,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemNew {
+    pub payout_batch_id: String,
+    pub item_index: i32,
+    pub merchant_id: id_type::MerchantId,
+    pub payout_id: id_type::PayoutId,
+    pub customer_id: id_type::CustomerId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub request_data: pii::SecretSerdeValue,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdate {
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PayoutBatchTrackingData {
+    pub payout_batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
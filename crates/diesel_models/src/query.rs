@@ -1,3 +1,4 @@
+pub mod account_updater;
 pub mod address;
 pub mod api_keys;
 pub mod blocklist_lookup;
@@ -9,12 +10,20 @@ pub mod configs;
 
 pub mod authentication;
 pub mod authorization;
+pub mod billing_catalog;
+pub mod bin_range;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
+pub mod bulk_refund;
 pub mod callback_mapper;
+pub mod customer_data_request;
+pub mod customer_merge;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
+pub mod dispute_automated_action;
+pub mod dispute_evidence_template;
 pub mod dynamic_routing_stats;
 pub mod events;
 pub mod file;
@@ -24,17 +33,20 @@ pub mod generics;
 pub mod gsm;
 pub mod hyperswitch_ai_interaction;
 pub mod invoice;
+pub mod ledger;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
+pub mod outbox;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
@@ -42,7 +54,9 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_report;
 pub mod subscription;
+pub mod tenant_registry;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod unified_translations;
@@ -0,0 +1,264 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+#[cfg(feature = "v1")]
+use diesel::{debug_query, pg::Pg, QueryDsl};
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::generics;
+#[cfg(feature = "v1")]
+use super::generics::db_metrics;
+#[cfg(feature = "v1")]
+use crate::{
+    account_updater::AccountUpdaterCandidate,
+    schema::{payment_attempt::dsl as attempt_dsl, payment_methods::dsl as pm_dsl},
+    PaymentAttempt, PaymentMethod,
+};
+use crate::{
+    account_updater::{
+        AccountUpdaterBatch, AccountUpdaterBatchNew, AccountUpdaterBatchUpdate,
+        AccountUpdaterBatchUpdateInternal, AccountUpdaterCard, AccountUpdaterCardNew,
+        AccountUpdaterCardUpdate,
+    },
+    enums as storage_enums, errors,
+    schema::{account_updater_batch::dsl, account_updater_card::dsl as card_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl AccountUpdaterBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AccountUpdaterBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AccountUpdaterBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        status: storage_enums::AccountUpdaterBatchStatus,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::status.eq(status)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        batch_update: AccountUpdaterBatchUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::batch_id.eq(self.batch_id.to_owned())),
+            AccountUpdaterBatchUpdateInternal::from(batch_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl AccountUpdaterCardNew {
+    pub async fn batch_insert(cards: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, AccountUpdaterCard>(conn, cards).await?;
+        Ok(())
+    }
+}
+
+impl AccountUpdaterCard {
+    pub async fn list_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            card_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(card_dsl::batch_id.eq(batch_id.to_owned())),
+            None,
+            None,
+            Some(card_dsl::payment_method_id.asc()),
+        )
+        .await
+    }
+
+    /// Lists the submissions of the payment methods made since the given time, used to avoid
+    /// inquiring about the same card in every run.
+    pub async fn find_by_payment_method_ids_submitted_since(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+        submitted_since: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            card_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(card_dsl::payment_method_id.eq_any(payment_method_ids))
+                .and(card_dsl::created_at.ge(submitted_since)),
+            None,
+            None,
+            Some(card_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_result(
+        self,
+        conn: &PgPooledConn,
+        result: storage_enums::AccountUpdaterResult,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            card_dsl::batch_id
+                .eq(self.batch_id.to_owned())
+                .and(card_dsl::payment_method_id.eq(self.payment_method_id.to_owned())),
+            AccountUpdaterCardUpdate {
+                result: Some(result),
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "v1")]
+impl AccountUpdaterCandidate {
+    /// Finds the saved cards used in recurring payments which failed since the given time,
+    /// optionally restricted to the given error codes of the connector or the issuer.
+    pub async fn find_declined(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        failed_since: PrimitiveDateTime,
+        error_codes: &[String],
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = attempt_dsl::payment_attempt
+            .select(attempt_dsl::payment_method_id)
+            .distinct()
+            .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(attempt_dsl::status.eq(storage_enums::AttemptStatus::Failure))
+            .filter(attempt_dsl::payment_method_id.is_not_null())
+            .filter(attempt_dsl::modified_at.ge(failed_since))
+            .filter(
+                attempt_dsl::mandate_id
+                    .is_not_null()
+                    .or(attempt_dsl::is_stored_credential.eq(true)),
+            )
+            .limit(limit)
+            .into_boxed();
+        if !error_codes.is_empty() {
+            query = query.filter(
+                attempt_dsl::error_code
+                    .eq_any(error_codes.to_vec())
+                    .or(attempt_dsl::issuer_error_code.eq_any(error_codes.to_vec())),
+            );
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        let payment_method_ids: Vec<Option<String>> =
+            db_metrics::track_database_call::<<PaymentAttempt as HasTable>::Table, _, _>(
+                async_bb8_diesel::AsyncRunQueryDsl::get_results_async(query, conn),
+                db_metrics::DatabaseOperation::Filter,
+            )
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while finding declined payment methods")?;
+
+        Ok(payment_method_ids
+            .into_iter()
+            .flatten()
+            .map(|payment_method_id| Self {
+                payment_method_id,
+                reason: storage_enums::AccountUpdaterReason::Declined,
+            })
+            .collect())
+    }
+
+    /// Finds the active card payment methods of the merchant in the order of their identifiers,
+    /// starting after the given identifier. The expiry of the cards is encrypted, so the callers
+    /// filter the expiring cards after decrypting them.
+    pub async fn find_active_cards(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<String>> {
+        let mut query = pm_dsl::payment_methods
+            .select(pm_dsl::payment_method_id)
+            .filter(pm_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(pm_dsl::status.eq(storage_enums::PaymentMethodStatus::Active))
+            .filter(pm_dsl::payment_method.eq(storage_enums::PaymentMethod::Card))
+            .order(pm_dsl::payment_method_id.asc())
+            .limit(limit)
+            .into_boxed();
+        if let Some(starting_after) = starting_after {
+            query = query.filter(pm_dsl::payment_method_id.gt(starting_after));
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<PaymentMethod as HasTable>::Table, _, _>(
+            async_bb8_diesel::AsyncRunQueryDsl::get_results_async(query, conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding card payment methods")
+    }
+}
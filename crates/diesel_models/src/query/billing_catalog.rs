@@ -0,0 +1,301 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
+
+use super::generics;
+use crate::{
+    billing_catalog::{
+        BillingCoupon, BillingCouponNew, BillingCouponUpdate, BillingCouponUpdateInternal,
+        BillingPlan, BillingPlanNew, BillingPlanUpdate, BillingPrice, BillingPriceNew,
+        BillingPriceUpdate, BillingProduct, BillingProductNew, BillingProductUpdate,
+    },
+    errors,
+    schema::{
+        billing_coupon::dsl as coupon_dsl, billing_plan::dsl as plan_dsl,
+        billing_price::dsl as price_dsl, billing_product::dsl as product_dsl,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl BillingProductNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BillingProduct> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BillingProduct {
+    pub async fn find_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            product_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(product_dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            product_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(product_dsl::profile_id.eq(profile_id.to_owned())),
+            limit,
+            offset,
+            Some(product_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+        product_update: BillingProductUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            product_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(product_dsl::id.eq(id.to_owned())),
+            product_update,
+        )
+        .await
+    }
+}
+
+impl BillingPlanNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BillingPlan> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BillingPlan {
+    pub async fn find_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::profile_id.eq(profile_id.to_owned())),
+            limit,
+            offset,
+            Some(plan_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+        plan_update: BillingPlanUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::id.eq(id.to_owned())),
+            plan_update,
+        )
+        .await
+    }
+}
+
+impl BillingPriceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BillingPrice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BillingPrice {
+    pub async fn find_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            price_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(price_dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            price_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(price_dsl::plan_id.eq(plan_id.to_owned())),
+            None,
+            None,
+            Some(price_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+        price_update: BillingPriceUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            price_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(price_dsl::id.eq(id.to_owned())),
+            price_update,
+        )
+        .await
+    }
+}
+
+impl BillingCouponNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BillingCoupon> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BillingCoupon {
+    pub async fn find_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            coupon_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(coupon_dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_profile_id_code(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        code: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            coupon_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(coupon_dsl::profile_id.eq(profile_id.to_owned()))
+                .and(coupon_dsl::code.eq(code.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            coupon_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(coupon_dsl::profile_id.eq(profile_id.to_owned())),
+            limit,
+            offset,
+            Some(coupon_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+        coupon_update: BillingCouponUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            coupon_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(coupon_dsl::id.eq(id.to_owned())),
+            BillingCouponUpdateInternal::from(coupon_update),
+        )
+        .await
+    }
+
+    /// Counts a redemption of the coupon, provided no other redemption was counted since the
+    /// coupon was read
+    pub async fn redeem(self, conn: &PgPooledConn) -> StorageResult<Self> {
+        let times_redeemed = self.times_redeemed.saturating_add(1);
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            coupon_dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(coupon_dsl::id.eq(self.id.to_owned()))
+                .and(coupon_dsl::times_redeemed.eq(self.times_redeemed)),
+            BillingCouponUpdateInternal::from(BillingCouponUpdate::Redeemed { times_redeemed }),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("The coupon was redeemed concurrently")
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    bin_range::{BinRange, BinRangeNew},
+    schema::bin_range::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl BinRangeNew {
+    pub async fn batch_insert(ranges: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, BinRange>(conn, ranges).await?;
+        Ok(())
+    }
+}
+
+impl BinRange {
+    pub async fn list_all(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::bin_range_id.is_not_null(),
+            None,
+            None,
+            Some(dsl::range_low.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_source(conn: &PgPooledConn, source: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::source.eq(source.to_owned()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,140 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    bulk_refund::{
+        BulkRefund, BulkRefundItem, BulkRefundItemNew, BulkRefundItemUpdate, BulkRefundNew,
+        BulkRefundUpdate, BulkRefundUpdateInternal,
+    },
+    enums as storage_enums, errors,
+    schema::{bulk_refund::dsl, bulk_refund_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl BulkRefundNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BulkRefund> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BulkRefund {
+    pub async fn find_by_merchant_id_bulk_refund_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        bulk_refund_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::bulk_refund_id.eq(bulk_refund_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        bulk_refund_update: BulkRefundUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::bulk_refund_id.eq(self.bulk_refund_id.to_owned())),
+            BulkRefundUpdateInternal::from(bulk_refund_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl BulkRefundItemNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BulkRefundItem> {
+        generics::generic_insert(conn, self).await
+    }
+
+    pub async fn batch_insert(items: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, BulkRefundItem>(conn, items).await?;
+        Ok(())
+    }
+}
+
+impl BulkRefundItem {
+    pub async fn list_by_bulk_refund_id(
+        conn: &PgPooledConn,
+        bulk_refund_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::bulk_refund_id.eq(bulk_refund_id.to_owned()),
+            None,
+            None,
+            Some(item_dsl::item_index.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_bulk_refund_id_status(
+        conn: &PgPooledConn,
+        bulk_refund_id: &str,
+        status: storage_enums::BulkRefundItemStatus,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::bulk_refund_id
+                .eq(bulk_refund_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+            Some(limit),
+            None,
+            Some(item_dsl::item_index.asc()),
+        )
+        .await
+    }
+
+    pub async fn get_count_by_bulk_refund_id_status(
+        conn: &PgPooledConn,
+        bulk_refund_id: &str,
+        status: storage_enums::BulkRefundItemStatus,
+    ) -> StorageResult<usize> {
+        generics::generic_count::<<Self as HasTable>::Table, _>(
+            conn,
+            item_dsl::bulk_refund_id
+                .eq(bulk_refund_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        item_update: BulkRefundItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::bulk_refund_id
+                .eq(self.bulk_refund_id.to_owned())
+                .and(item_dsl::item_index.eq(self.item_index)),
+            item_update,
+        )
+        .await
+    }
+}
@@ -0,0 +1,78 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    customer_data_request::{
+        CustomerDataRequest, CustomerDataRequestNew, CustomerDataRequestUpdate,
+        CustomerDataRequestUpdateInternal,
+    },
+    errors,
+    schema::customer_data_request::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerDataRequestNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerDataRequest> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerDataRequest {
+    pub async fn find_by_merchant_id_request_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::request_id.eq(request_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        customer_data_request_update: CustomerDataRequestUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::request_id.eq(self.request_id.to_owned())),
+            CustomerDataRequestUpdateInternal::from(customer_data_request_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, dsl::count_star, pg::Pg, BoolExpressionMethods,
+    ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use super::generics::{self, db_metrics};
+use crate::{
+    customer_merge::{
+        CustomerMatchKey, CustomerMatchKeyNew, CustomerRedirect, CustomerRedirectNew,
+    },
+    enums as storage_enums, errors,
+    schema::{
+        address::{self, dsl as address_dsl},
+        customer_match_key::dsl as match_key_dsl,
+        customer_redirect::dsl,
+        customers::{self, dsl as customer_dsl},
+        mandate::{self, dsl as mandate_dsl},
+        payment_intent::{self, dsl as payment_intent_dsl},
+        payment_methods::{self, dsl as payment_method_dsl},
+        payout_attempt::{self, dsl as payout_attempt_dsl},
+        payouts::{self, dsl as payout_dsl},
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerRedirectNew {
+    pub async fn batch_insert(redirects: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, CustomerRedirect>(conn, redirects).await?;
+        Ok(())
+    }
+}
+
+impl CustomerRedirect {
+    pub async fn find_optional_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_merge_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merge_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merge_id.eq(merge_id.to_owned())),
+            None,
+            None,
+            Some(dsl::customer_id.asc()),
+        )
+        .await
+    }
+
+    /// Points the redirects to any of the given customers at the new target, so that redirects
+    /// are never chained.
+    pub async fn update_target_by_merchant_id_target_customer_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        target_customer_ids: Vec<common_utils::id_type::CustomerId>,
+        new_target_customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::target_customer_id.eq_any(target_customer_ids)),
+            dsl::target_customer_id.eq(new_target_customer_id.to_owned()),
+        )
+        .await
+    }
+
+    /// Moves the payouts, payment methods, mandates, addresses and payments of the source
+    /// customers to the target customer and deletes the source customers. The payouts made to a
+    /// payment method of a source customer are moved along with the payment method.
+    pub async fn reassign_customer_records(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        source_customer_ids: Vec<common_utils::id_type::CustomerId>,
+        target_customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<()> {
+        Self::reassign_payouts(
+            conn,
+            merchant_id,
+            source_customer_ids.clone(),
+            target_customer_id,
+        )
+        .await?;
+
+        generics::generic_update::<payment_methods::table, _, _>(
+            conn,
+            payment_method_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_method_dsl::customer_id.eq_any(source_customer_ids.clone())),
+            payment_method_dsl::customer_id.eq(target_customer_id.to_owned()),
+        )
+        .await?;
+
+        generics::generic_update::<mandate::table, _, _>(
+            conn,
+            mandate_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(mandate_dsl::customer_id.eq_any(source_customer_ids.clone())),
+            mandate_dsl::customer_id.eq(target_customer_id.to_owned()),
+        )
+        .await?;
+
+        generics::generic_update::<address::table, _, _>(
+            conn,
+            address_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(address_dsl::customer_id.eq_any(source_customer_ids.clone())),
+            address_dsl::customer_id.eq(target_customer_id.to_owned()),
+        )
+        .await?;
+
+        generics::generic_update::<payment_intent::table, _, _>(
+            conn,
+            payment_intent_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_intent_dsl::customer_id.eq_any(source_customer_ids.clone())),
+            payment_intent_dsl::customer_id.eq(target_customer_id.to_owned()),
+        )
+        .await?;
+
+        let query = diesel::delete(customers::table).filter(
+            customer_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(customer_dsl::customer_id.eq_any(source_customer_ids)),
+        );
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<customers::table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting merged customers")?;
+
+        Ok(())
+    }
+
+    /// Moves the payouts of the source customers, and the payouts made to their payment methods,
+    /// to the target customer along with the attempts of the payouts. Runs before the payment
+    /// methods are moved, as the payouts are found through the customer of their payment method.
+    async fn reassign_payouts(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        source_customer_ids: Vec<common_utils::id_type::CustomerId>,
+        target_customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<()> {
+        let query = payment_methods::table
+            .filter(
+                payment_method_dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(payment_method_dsl::customer_id.eq_any(source_customer_ids.clone())),
+            )
+            .select(payment_method_dsl::payment_method_id);
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        let payment_method_ids: Vec<String> =
+            db_metrics::track_database_call::<payment_methods::table, _, _>(
+                query.get_results_async(conn),
+                db_metrics::DatabaseOperation::Filter,
+            )
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while finding the payment methods of merged customers")?;
+
+        let query = payouts::table
+            .filter(
+                payout_dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                    payout_dsl::customer_id
+                        .eq_any(source_customer_ids.clone())
+                        .or(payout_dsl::payout_method_id.eq_any(payment_method_ids)),
+                ),
+            )
+            .select(payout_dsl::payout_id);
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        let payout_ids: Vec<common_utils::id_type::PayoutId> =
+            db_metrics::track_database_call::<payouts::table, _, _>(
+                query.get_results_async(conn),
+                db_metrics::DatabaseOperation::Filter,
+            )
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while finding the payouts of merged customers")?;
+
+        generics::generic_update::<payout_attempt::table, _, _>(
+            conn,
+            payout_attempt_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(
+                    payout_attempt_dsl::customer_id
+                        .eq_any(source_customer_ids)
+                        .or(payout_attempt_dsl::payout_id.eq_any(payout_ids.clone())),
+                ),
+            payout_attempt_dsl::customer_id.eq(target_customer_id.to_owned()),
+        )
+        .await?;
+
+        generics::generic_update::<payouts::table, _, _>(
+            conn,
+            payout_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payout_dsl::payout_id.eq_any(payout_ids)),
+            payout_dsl::customer_id.eq(target_customer_id.to_owned()),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl CustomerMatchKeyNew {
+    pub async fn batch_insert(match_keys: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, CustomerMatchKey>(conn, match_keys).await?;
+        Ok(())
+    }
+}
+
+impl CustomerMatchKey {
+    /// Deletes the match keys of the given customers, returning the number of deleted keys.
+    pub async fn delete_by_merchant_id_customer_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_ids: Vec<common_utils::id_type::CustomerId>,
+    ) -> StorageResult<usize> {
+        let query = diesel::delete(<Self as HasTable>::table()).filter(
+            match_key_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(match_key_dsl::customer_id.eq_any(customer_ids)),
+        );
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting customer match keys")
+    }
+
+    /// Returns the key hashes shared by more than one customer of the merchant.
+    pub async fn find_duplicated_key_hashes(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> StorageResult<Vec<(storage_enums::CustomerMatchKeyType, String)>> {
+        let query = <Self as HasTable>::table()
+            .filter(match_key_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .group_by((match_key_dsl::key_type, match_key_dsl::key_hash))
+            .having(count_star().gt(1))
+            .select((match_key_dsl::key_type, match_key_dsl::key_hash))
+            .order(match_key_dsl::key_hash.asc())
+            .limit(limit);
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding duplicated customer match keys")
+    }
+
+    pub async fn list_by_merchant_id_key_hashes(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_hashes: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            match_key_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(match_key_dsl::key_hash.eq_any(key_hashes)),
+            None,
+            None,
+            Some(match_key_dsl::created_at.asc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,622 @@
+use std::collections::HashMap;
+
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use diesel::{
+    associations::HasTable,
+    debug_query, dsl,
+    pg::Pg,
+    query_builder::QueryFragment,
+    query_dsl::{methods::ExecuteDsl, LoadQuery},
+    sql_types, BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods, PgConnection,
+    QueryDsl,
+};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::generics::{self, db_metrics};
+#[cfg(feature = "v1")]
+use crate::schema::{
+    dispute, mandate, merchant_account, payment_attempt::dsl as attempt_dsl,
+    payment_intent::dsl as intent_dsl, refund::dsl as refund_dsl,
+};
+#[cfg(feature = "v2")]
+use crate::schema_v2::{
+    dispute, mandate, merchant_account, payment_attempt::dsl as attempt_dsl,
+    payment_intent::dsl as intent_dsl, refund::dsl as refund_dsl,
+};
+use crate::{
+    data_retention::{
+        ArchivableRecord, ArchivalWindow, ArchivedRecord, ArchivedRecordNew, DataRetentionPolicy,
+        DataRetentionPolicyNew, DataRetentionPolicyUpdate, DataRetentionPolicyUpdateInternal,
+        TablePartition,
+    },
+    enums as storage_enums, errors,
+    events::Event,
+    process_tracker::ProcessTracker,
+    schema::{
+        archived_record::dsl as archived_dsl, data_retention_policy::dsl as policy_dsl,
+        events::dsl as events_dsl, process_tracker::dsl as process_dsl,
+    },
+    PaymentAttempt, PaymentIntent, PgPooledConn, Refund, StorageResult,
+};
+
+impl DataRetentionPolicyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DataRetentionPolicy> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DataRetentionPolicy {
+    pub async fn find_by_entity(
+        conn: &PgPooledConn,
+        entity: storage_enums::DataRetentionEntity,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            policy_dsl::entity.eq(entity),
+        )
+        .await
+    }
+
+    pub async fn list(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            policy_dsl::entity.is_not_null(),
+            None,
+            None,
+            Some(policy_dsl::entity.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_entity(
+        conn: &PgPooledConn,
+        entity: storage_enums::DataRetentionEntity,
+        policy_update: DataRetentionPolicyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            policy_dsl::entity.eq(entity),
+            DataRetentionPolicyUpdateInternal::from(policy_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_entity(
+        conn: &PgPooledConn,
+        entity: storage_enums::DataRetentionEntity,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            policy_dsl::entity.eq(entity),
+        )
+        .await
+    }
+}
+
+impl ArchivedRecordNew {
+    /// Inserts the index entries of the archived records, repointing the entries of records that
+    /// were archived before to the new file.
+    pub async fn batch_upsert(
+        conn: &PgPooledConn,
+        records: Vec<Self>,
+    ) -> StorageResult<Vec<ArchivedRecord>> {
+        let query = diesel::insert_into(<ArchivedRecord as HasTable>::table())
+            .values(records)
+            .on_conflict((
+                archived_dsl::merchant_id,
+                archived_dsl::entity,
+                archived_dsl::record_id,
+            ))
+            .do_update()
+            .set((
+                archived_dsl::file_key.eq(diesel::upsert::excluded(archived_dsl::file_key)),
+                archived_dsl::file_format.eq(diesel::upsert::excluded(archived_dsl::file_format)),
+                archived_dsl::archived_at.eq(diesel::upsert::excluded(archived_dsl::archived_at)),
+            ));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<ArchivedRecord as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while inserting archived records")
+    }
+}
+
+impl ArchivedRecord {
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            archived_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(archived_dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(archived_dsl::record_created_at.asc()),
+        )
+        .await
+    }
+}
+
+impl ArchivableRecord {
+    /// Finds the records of the entity created in the window, oldest first.
+    ///
+    /// Payments and refunds are only archived once they can no longer change: they must be in a
+    /// terminal state and last written to Postgres directly, so that no newer copy is waiting in
+    /// Redis to be drained, by a merchant not on the KV storage scheme. Payments backing an open
+    /// dispute or a live mandate are kept as well.
+    pub async fn find_by_entity(
+        conn: &PgPooledConn,
+        entity: storage_enums::DataRetentionEntity,
+        window: ArchivalWindow,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match entity {
+            storage_enums::DataRetentionEntity::PaymentIntent => {
+                #[cfg(feature = "v1")]
+                let payment_id_column = intent_dsl::payment_id;
+                #[cfg(feature = "v2")]
+                let payment_id_column = intent_dsl::id;
+                let mut query = <PaymentIntent as HasTable>::table()
+                    .filter(intent_dsl::created_at.lt(window.created_before))
+                    .filter(intent_dsl::status.eq_any(TERMINAL_INTENT_STATUSES.to_vec()))
+                    .filter(intent_dsl::updated_by.eq(postgres_only()))
+                    .filter(intent_dsl::merchant_id.ne_all(kv_merchant_ids()))
+                    .filter(payment_id_column.ne_all(disputed_payment_ids()))
+                    .filter(payment_id_column.ne_all(mandated_payment_ids()))
+                    .into_boxed();
+                if let Some(created_from) = window.created_from {
+                    query = query.filter(intent_dsl::created_at.ge(created_from));
+                }
+                #[cfg(feature = "v1")]
+                let query = query.order((
+                    intent_dsl::created_at.asc(),
+                    intent_dsl::merchant_id.asc(),
+                    intent_dsl::payment_id.asc(),
+                ));
+                #[cfg(feature = "v2")]
+                let query = query.order((intent_dsl::created_at.asc(), intent_dsl::id.asc()));
+
+                load_records::<<PaymentIntent as HasTable>::Table, _, PaymentIntent>(
+                    conn,
+                    query.limit(limit).offset(offset),
+                )
+                .await?
+                .iter()
+                .map(Self::try_from)
+                .collect()
+            }
+            storage_enums::DataRetentionEntity::PaymentAttempt => {
+                let mut query = <PaymentAttempt as HasTable>::table()
+                    .filter(attempt_dsl::created_at.lt(window.created_before))
+                    .filter(attempt_dsl::status.eq_any(TERMINAL_ATTEMPT_STATUSES.to_vec()))
+                    .filter(attempt_dsl::updated_by.eq(postgres_only()))
+                    .filter(attempt_dsl::merchant_id.ne_all(kv_merchant_ids()))
+                    .filter(attempt_dsl::payment_id.ne_all(disputed_payment_ids()))
+                    .filter(attempt_dsl::payment_id.ne_all(mandated_payment_ids()))
+                    .into_boxed();
+                if let Some(created_from) = window.created_from {
+                    query = query.filter(attempt_dsl::created_at.ge(created_from));
+                }
+                #[cfg(feature = "v1")]
+                let query = query.order((
+                    attempt_dsl::created_at.asc(),
+                    attempt_dsl::merchant_id.asc(),
+                    attempt_dsl::attempt_id.asc(),
+                ));
+                #[cfg(feature = "v2")]
+                let query = query.order((attempt_dsl::created_at.asc(), attempt_dsl::id.asc()));
+
+                load_records::<<PaymentAttempt as HasTable>::Table, _, PaymentAttempt>(
+                    conn,
+                    query.limit(limit).offset(offset),
+                )
+                .await?
+                .iter()
+                .map(Self::try_from)
+                .collect()
+            }
+            storage_enums::DataRetentionEntity::Refund => {
+                let mut query = <Refund as HasTable>::table()
+                    .filter(refund_dsl::created_at.lt(window.created_before))
+                    .filter(refund_dsl::refund_status.eq_any(TERMINAL_REFUND_STATUSES.to_vec()))
+                    .filter(refund_dsl::updated_by.eq(postgres_only()))
+                    .filter(refund_dsl::merchant_id.ne_all(kv_merchant_ids()))
+                    .filter(refund_dsl::payment_id.ne_all(disputed_payment_ids()))
+                    .into_boxed();
+                if let Some(created_from) = window.created_from {
+                    query = query.filter(refund_dsl::created_at.ge(created_from));
+                }
+                #[cfg(feature = "v1")]
+                let query = query.order((
+                    refund_dsl::created_at.asc(),
+                    refund_dsl::merchant_id.asc(),
+                    refund_dsl::refund_id.asc(),
+                ));
+                #[cfg(feature = "v2")]
+                let query = query.order((refund_dsl::created_at.asc(), refund_dsl::id.asc()));
+
+                load_records::<<Refund as HasTable>::Table, _, Refund>(
+                    conn,
+                    query.limit(limit).offset(offset),
+                )
+                .await?
+                .iter()
+                .map(Self::try_from)
+                .collect()
+            }
+            storage_enums::DataRetentionEntity::Event => {
+                let mut query = <Event as HasTable>::table()
+                    .filter(events_dsl::created_at.lt(window.created_before))
+                    .into_boxed();
+                if let Some(created_from) = window.created_from {
+                    query = query.filter(events_dsl::created_at.ge(created_from));
+                }
+                let query = query
+                    .order((events_dsl::created_at.asc(), events_dsl::event_id.asc()))
+                    .limit(limit)
+                    .offset(offset);
+
+                load_records::<<Event as HasTable>::Table, _, Event>(conn, query)
+                    .await?
+                    .iter()
+                    .map(Self::try_from)
+                    .collect()
+            }
+            storage_enums::DataRetentionEntity::ProcessTracker => {
+                // Only the finished tasks are archived, the pending ones are still needed by the
+                // scheduler however old they are
+                let mut query = <ProcessTracker as HasTable>::table()
+                    .filter(
+                        process_dsl::status
+                            .eq(storage_enums::ProcessTrackerStatus::Finish)
+                            .and(process_dsl::created_at.lt(window.created_before)),
+                    )
+                    .into_boxed();
+                if let Some(created_from) = window.created_from {
+                    query = query.filter(process_dsl::created_at.ge(created_from));
+                }
+                let query = query
+                    .order((process_dsl::created_at.asc(), process_dsl::id.asc()))
+                    .limit(limit)
+                    .offset(offset);
+
+                load_records::<<ProcessTracker as HasTable>::Table, _, ProcessTracker>(conn, query)
+                    .await?
+                    .iter()
+                    .map(Self::try_from)
+                    .collect()
+            }
+        }
+    }
+
+    /// Deletes the archived records from their table, returning the number of deleted records.
+    pub async fn delete_by_entity(
+        conn: &PgPooledConn,
+        entity: storage_enums::DataRetentionEntity,
+        records: &[Self],
+    ) -> StorageResult<usize> {
+        let record_ids = || {
+            records
+                .iter()
+                .map(|record| record.record_id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        match entity {
+            storage_enums::DataRetentionEntity::PaymentIntent => {
+                let mut deleted = 0;
+                for (merchant_id, record_ids) in Self::group_by_merchant(records) {
+                    #[cfg(feature = "v1")]
+                    let id_column = intent_dsl::payment_id;
+                    #[cfg(feature = "v2")]
+                    let id_column = intent_dsl::id;
+                    let query = diesel::delete(<PaymentIntent as HasTable>::table()).filter(
+                        intent_dsl::merchant_id
+                            .eq(merchant_id)
+                            .and(id_column.eq_any(record_ids)),
+                    );
+                    deleted += execute_delete::<<PaymentIntent as HasTable>::Table, _>(conn, query)
+                        .await?;
+                }
+                Ok(deleted)
+            }
+            storage_enums::DataRetentionEntity::PaymentAttempt => {
+                let mut deleted = 0;
+                for (merchant_id, record_ids) in Self::group_by_merchant(records) {
+                    #[cfg(feature = "v1")]
+                    let id_column = attempt_dsl::attempt_id;
+                    #[cfg(feature = "v2")]
+                    let id_column = attempt_dsl::id;
+                    let query = diesel::delete(<PaymentAttempt as HasTable>::table()).filter(
+                        attempt_dsl::merchant_id
+                            .eq(merchant_id)
+                            .and(id_column.eq_any(record_ids)),
+                    );
+                    deleted +=
+                        execute_delete::<<PaymentAttempt as HasTable>::Table, _>(conn, query)
+                            .await?;
+                }
+                Ok(deleted)
+            }
+            storage_enums::DataRetentionEntity::Refund => {
+                let mut deleted = 0;
+                for (merchant_id, record_ids) in Self::group_by_merchant(records) {
+                    #[cfg(feature = "v1")]
+                    let id_column = refund_dsl::refund_id;
+                    #[cfg(feature = "v2")]
+                    let id_column = refund_dsl::id;
+                    let query = diesel::delete(<Refund as HasTable>::table()).filter(
+                        refund_dsl::merchant_id
+                            .eq(merchant_id)
+                            .and(id_column.eq_any(record_ids)),
+                    );
+                    deleted +=
+                        execute_delete::<<Refund as HasTable>::Table, _>(conn, query).await?;
+                }
+                Ok(deleted)
+            }
+            storage_enums::DataRetentionEntity::Event => {
+                let query = diesel::delete(<Event as HasTable>::table())
+                    .filter(events_dsl::event_id.eq_any(record_ids()));
+                execute_delete::<<Event as HasTable>::Table, _>(conn, query).await
+            }
+            storage_enums::DataRetentionEntity::ProcessTracker => {
+                let query = diesel::delete(<ProcessTracker as HasTable>::table())
+                    .filter(process_dsl::id.eq_any(record_ids()));
+                execute_delete::<<ProcessTracker as HasTable>::Table, _>(conn, query).await
+            }
+        }
+    }
+
+    fn group_by_merchant(records: &[Self]) -> HashMap<id_type::MerchantId, Vec<String>> {
+        records
+            .iter()
+            .filter_map(|record| {
+                record
+                    .merchant_id
+                    .clone()
+                    .map(|merchant_id| (merchant_id, record.record_id.clone()))
+            })
+            .fold(HashMap::new(), |mut groups, (merchant_id, record_id)| {
+                groups
+                    .entry(merchant_id)
+                    .or_insert_with(Vec::new)
+                    .push(record_id);
+                groups
+            })
+    }
+}
+
+#[derive(diesel::QueryableByName)]
+struct PartitionBound {
+    #[diesel(sql_type = sql_types::Text)]
+    name: String,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Timestamp>)]
+    range_start: Option<PrimitiveDateTime>,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Timestamp>)]
+    range_end: Option<PrimitiveDateTime>,
+}
+
+impl TablePartition {
+    /// Lists the partitions of the table of the entity, when the table is range partitioned on
+    /// `created_at`. The default partition is left out, as it has no bounds.
+    pub async fn list_by_entity(
+        conn: &PgPooledConn,
+        entity: storage_enums::DataRetentionEntity,
+    ) -> StorageResult<Vec<Self>> {
+        let query = diesel::sql_query(
+            "SELECT child.relname::text AS name, \
+                (regexp_match(pg_get_expr(child.relpartbound, child.oid), 'FROM \\(''([^'']+)''\\)'))[1]::timestamp AS range_start, \
+                (regexp_match(pg_get_expr(child.relpartbound, child.oid), 'TO \\(''([^'']+)''\\)'))[1]::timestamp AS range_end \
+            FROM pg_inherits \
+            JOIN pg_class child ON child.oid = pg_inherits.inhrelid \
+            WHERE pg_inherits.inhparent = to_regclass($1) \
+                AND pg_get_partkeydef(pg_inherits.inhparent) = 'RANGE (created_at)' \
+                AND pg_get_expr(child.relpartbound, child.oid) <> 'DEFAULT' \
+            ORDER BY range_start NULLS FIRST",
+        )
+        .bind::<sql_types::Text, _>(entity_table_name(entity));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        let partitions = query
+            .load_async::<PartitionBound>(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while listing table partitions")?;
+
+        Ok(partitions
+            .into_iter()
+            .map(|partition| Self {
+                name: partition.name,
+                range_start: partition.range_start,
+                range_end: partition.range_end,
+            })
+            .collect())
+    }
+
+    /// Detaches the partition from the table of the entity. The detached table is left in place,
+    /// to be dropped once its archive has been verified.
+    pub async fn detach(
+        &self,
+        conn: &PgPooledConn,
+        entity: storage_enums::DataRetentionEntity,
+    ) -> StorageResult<()> {
+        let query = diesel::sql_query(format!(
+            "ALTER TABLE {} DETACH PARTITION {}",
+            quote_identifier(entity_table_name(entity)),
+            quote_identifier(&self.name),
+        ));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        query
+            .execute_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable_lazy(|| format!("Error while detaching partition {}", self.name))?;
+
+        Ok(())
+    }
+}
+
+const TERMINAL_INTENT_STATUSES: [storage_enums::IntentStatus; 6] = [
+    storage_enums::IntentStatus::Succeeded,
+    storage_enums::IntentStatus::Failed,
+    storage_enums::IntentStatus::Cancelled,
+    storage_enums::IntentStatus::CancelledPostCapture,
+    storage_enums::IntentStatus::PartiallyCaptured,
+    storage_enums::IntentStatus::Expired,
+];
+
+const TERMINAL_ATTEMPT_STATUSES: [storage_enums::AttemptStatus; 9] = [
+    storage_enums::AttemptStatus::RouterDeclined,
+    storage_enums::AttemptStatus::Charged,
+    storage_enums::AttemptStatus::AutoRefunded,
+    storage_enums::AttemptStatus::Voided,
+    storage_enums::AttemptStatus::VoidFailed,
+    storage_enums::AttemptStatus::CaptureFailed,
+    storage_enums::AttemptStatus::Failure,
+    storage_enums::AttemptStatus::PartialCharged,
+    storage_enums::AttemptStatus::Expired,
+];
+
+const TERMINAL_REFUND_STATUSES: [storage_enums::RefundStatus; 3] = [
+    storage_enums::RefundStatus::Success,
+    storage_enums::RefundStatus::Failure,
+    storage_enums::RefundStatus::TransactionFailure,
+];
+
+const OPEN_DISPUTE_STATUSES: [storage_enums::DisputeStatus; 2] = [
+    storage_enums::DisputeStatus::DisputeOpened,
+    storage_enums::DisputeStatus::DisputeChallenged,
+];
+
+const LIVE_MANDATE_STATUSES: [storage_enums::MandateStatus; 2] = [
+    storage_enums::MandateStatus::Active,
+    storage_enums::MandateStatus::Pending,
+];
+
+/// The `updated_by` of the records last written to Postgres directly
+fn postgres_only() -> String {
+    storage_enums::MerchantStorageScheme::PostgresOnly.to_string()
+}
+
+#[cfg(feature = "v1")]
+type MerchantIdColumn = merchant_account::merchant_id;
+#[cfg(feature = "v2")]
+type MerchantIdColumn = merchant_account::id;
+
+type KvMerchantIds = dsl::Select<
+    dsl::Filter<
+        merchant_account::table,
+        dsl::Eq<merchant_account::storage_scheme, storage_enums::MerchantStorageScheme>,
+    >,
+    MerchantIdColumn,
+>;
+
+fn kv_merchant_ids() -> KvMerchantIds {
+    #[cfg(feature = "v1")]
+    let merchant_id_column = merchant_account::merchant_id;
+    #[cfg(feature = "v2")]
+    let merchant_id_column = merchant_account::id;
+
+    merchant_account::table
+        .filter(merchant_account::storage_scheme.eq(storage_enums::MerchantStorageScheme::RedisKv))
+        .select(merchant_id_column)
+}
+
+type DisputedPaymentIds = dsl::Select<
+    dsl::Filter<
+        dispute::table,
+        dsl::EqAny<dispute::dispute_status, Vec<storage_enums::DisputeStatus>>,
+    >,
+    dispute::payment_id,
+>;
+
+fn disputed_payment_ids() -> DisputedPaymentIds {
+    dispute::table
+        .filter(dispute::dispute_status.eq_any(OPEN_DISPUTE_STATUSES.to_vec()))
+        .select(dispute::payment_id)
+}
+
+type MandatedPaymentIds = dsl::Select<
+    dsl::Filter<
+        mandate::table,
+        dsl::And<
+            dsl::EqAny<mandate::mandate_status, Vec<storage_enums::MandateStatus>>,
+            dsl::IsNotNull<mandate::original_payment_id>,
+        >,
+    >,
+    dsl::AssumeNotNull<mandate::original_payment_id>,
+>;
+
+// The mandates without an original payment are left out, as a null in the subquery would make
+// `NOT IN` exclude every payment
+fn mandated_payment_ids() -> MandatedPaymentIds {
+    mandate::table
+        .filter(
+            mandate::mandate_status
+                .eq_any(LIVE_MANDATE_STATUSES.to_vec())
+                .and(mandate::original_payment_id.is_not_null()),
+        )
+        .select(mandate::original_payment_id.assume_not_null())
+}
+
+fn entity_table_name(entity: storage_enums::DataRetentionEntity) -> &'static str {
+    match entity {
+        storage_enums::DataRetentionEntity::PaymentIntent => "payment_intent",
+        storage_enums::DataRetentionEntity::PaymentAttempt => "payment_attempt",
+        storage_enums::DataRetentionEntity::Refund => "refund",
+        storage_enums::DataRetentionEntity::Event => "events",
+        storage_enums::DataRetentionEntity::ProcessTracker => "process_tracker",
+    }
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+async fn load_records<T, Q, R>(conn: &PgPooledConn, query: Q) -> StorageResult<Vec<R>>
+where
+    Q: LoadQuery<'static, PgConnection, R> + QueryFragment<Pg> + Send + 'static,
+    R: Send + 'static,
+{
+    router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+    db_metrics::track_database_call::<T, _, _>(
+        query.get_results_async(conn),
+        db_metrics::DatabaseOperation::Filter,
+    )
+    .await
+    .change_context(errors::DatabaseError::Others)
+    .attach_printable("Error while finding records to archive")
+}
+
+async fn execute_delete<T, Q>(conn: &PgPooledConn, query: Q) -> StorageResult<usize>
+where
+    Q: ExecuteDsl<PgConnection> + QueryFragment<Pg> + Send + 'static,
+{
+    router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+    db_metrics::track_database_call::<T, _, _>(
+        query.execute_async(conn),
+        db_metrics::DatabaseOperation::Delete,
+    )
+    .await
+    .change_context(errors::DatabaseError::Others)
+    .attach_printable("Error while deleting archived records")
+}
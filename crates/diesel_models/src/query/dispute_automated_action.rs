@@ -0,0 +1,54 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    dispute_automated_action::{
+        DisputeAutomatedAction, DisputeAutomatedActionNew, DisputeAutomatedActionUpdate,
+        DisputeAutomatedActionUpdateInternal,
+    },
+    schema::dispute_automated_action::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl DisputeAutomatedActionNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DisputeAutomatedAction> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DisputeAutomatedAction {
+    pub async fn update_by_action_id(
+        conn: &PgPooledConn,
+        action_id: &str,
+        action_update: DisputeAutomatedActionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::action_id.eq(action_id.to_owned()),
+            DisputeAutomatedActionUpdateInternal::from(action_update),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_dispute_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::dispute_id.eq(dispute_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,58 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    dispute_evidence_template::{DisputeEvidenceTemplate, DisputeEvidenceTemplateNew},
+    schema::dispute_evidence_template::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl DisputeEvidenceTemplateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DisputeEvidenceTemplate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DisputeEvidenceTemplate {
+    pub async fn find_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::template_id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::template_id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+}
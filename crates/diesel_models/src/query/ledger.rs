@@ -0,0 +1,79 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    ledger::{
+        LedgerFeeSchedule, LedgerFeeScheduleNew, LedgerJournalEntry, LedgerJournalEntryNew,
+        LedgerPosting, LedgerPostingNew,
+    },
+    schema::{ledger_fee_schedule::dsl as fee_schedule_dsl, ledger_posting::dsl as posting_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl LedgerFeeScheduleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<LedgerFeeSchedule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl LedgerFeeSchedule {
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            fee_schedule_dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(fee_schedule_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_fee_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fee_schedule_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            fee_schedule_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(fee_schedule_dsl::fee_schedule_id.eq(fee_schedule_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl LedgerJournalEntryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<LedgerJournalEntry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl LedgerPostingNew {
+    pub async fn batch_insert(postings: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, LedgerPosting>(conn, postings).await?;
+        Ok(())
+    }
+}
+
+impl LedgerPosting {
+    pub async fn list_by_merchant_id_entry_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            posting_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(posting_dsl::entry_id.eq(entry_id.to_owned())),
+            None,
+            None,
+            Some(posting_dsl::created_at.asc()),
+        )
+        .await
+    }
+}
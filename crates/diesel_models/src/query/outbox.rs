@@ -0,0 +1,328 @@
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+};
+use error_stack::{report, ResultExt};
+use time::PrimitiveDateTime;
+
+use super::generics::{self, db_metrics};
+#[cfg(feature = "v2")]
+use crate::payment_attempt::PaymentAttemptUpdateInternal;
+use crate::{
+    enums as storage_enums, errors,
+    events::{Event, EventNew},
+    outbox::{Outbox, OutboxAggregate, OutboxNew, OutboxUpdate, OutboxUpdateInternal},
+    payment_attempt::{PaymentAttempt, PaymentAttemptNew},
+    payment_intent::{PaymentIntent, PaymentIntentNew},
+    refund::{Refund, RefundNew, RefundUpdate},
+    schema::outbox::dsl,
+    PgPooledConn, StorageResult,
+};
+#[cfg(feature = "v1")]
+use crate::{payment_attempt::PaymentAttemptUpdate, payment_intent::PaymentIntentUpdate};
+
+/// Error of the transactions writing a record along with its outbox entry. The transactions need
+/// an error type convertible from [`diesel::result::Error`], which the reports returned by the
+/// queries are not.
+#[derive(Debug)]
+pub struct OutboxTransactionError(pub error_stack::Report<errors::DatabaseError>);
+
+impl From<diesel::result::Error> for OutboxTransactionError {
+    fn from(error: diesel::result::Error) -> Self {
+        Self(report!(errors::DatabaseError::from(error)))
+    }
+}
+
+impl From<error_stack::Report<errors::DatabaseError>> for OutboxTransactionError {
+    fn from(error: error_stack::Report<errors::DatabaseError>) -> Self {
+        Self(error)
+    }
+}
+
+impl OutboxNew {
+    /// Inserts the entry, ignoring it if an entry with the same dedupe key already exists.
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<()> {
+        let query = diesel::insert_into(<Outbox as HasTable>::table())
+            .values(self)
+            .on_conflict(dsl::dedupe_key)
+            .do_nothing();
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Outbox as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while inserting outbox entry")?;
+
+        Ok(())
+    }
+
+    /// Inserts the outbox entry of a record written on the same connection.
+    pub async fn insert_for_aggregate<T: OutboxAggregate>(
+        conn: &PgPooledConn,
+        aggregate: &T,
+        operation: storage_enums::OutboxOperation,
+    ) -> StorageResult<()> {
+        Self::for_aggregate(aggregate, operation)?
+            .insert(conn)
+            .await
+    }
+}
+
+impl PaymentIntentNew {
+    pub async fn insert_with_outbox(self, conn: &PgPooledConn) -> StorageResult<PaymentIntent> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.insert(&conn).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Insert,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+}
+
+impl PaymentIntent {
+    #[cfg(feature = "v1")]
+    pub async fn update_with_outbox(
+        self,
+        conn: &PgPooledConn,
+        payment_intent: PaymentIntentUpdate,
+    ) -> StorageResult<Self> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.update(&conn, payment_intent).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Update,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+
+    #[cfg(feature = "v2")]
+    pub async fn update_with_outbox(
+        self,
+        conn: &PgPooledConn,
+        payment_intent_update: crate::payment_intent::PaymentIntentUpdateInternal,
+    ) -> StorageResult<Self> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.update(&conn, payment_intent_update).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Update,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+}
+
+impl PaymentAttemptNew {
+    pub async fn insert_with_outbox(self, conn: &PgPooledConn) -> StorageResult<PaymentAttempt> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.insert(&conn).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Insert,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+}
+
+impl PaymentAttempt {
+    #[cfg(feature = "v1")]
+    pub async fn update_with_attempt_id_and_outbox(
+        self,
+        conn: &PgPooledConn,
+        payment_attempt: PaymentAttemptUpdate,
+    ) -> StorageResult<Self> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.update_with_attempt_id(&conn, payment_attempt).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Update,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+
+    #[cfg(feature = "v2")]
+    pub async fn update_with_attempt_id_and_outbox(
+        self,
+        conn: &PgPooledConn,
+        payment_attempt: PaymentAttemptUpdateInternal,
+    ) -> StorageResult<Self> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.update_with_attempt_id(&conn, payment_attempt).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Update,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+}
+
+impl RefundNew {
+    pub async fn insert_with_outbox(self, conn: &PgPooledConn) -> StorageResult<Refund> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.insert(&conn).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Insert,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+}
+
+impl Refund {
+    #[cfg(feature = "v1")]
+    pub async fn update_with_outbox(
+        self,
+        conn: &PgPooledConn,
+        refund: RefundUpdate,
+    ) -> StorageResult<Self> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.update(&conn, refund).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Update,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+
+    #[cfg(feature = "v2")]
+    pub async fn update_with_id_and_outbox(
+        self,
+        conn: &PgPooledConn,
+        refund: RefundUpdate,
+    ) -> StorageResult<Self> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.update_with_id(&conn, refund).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Update,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+}
+
+impl EventNew {
+    /// Inserts the event of an outgoing webhook along with the outbox entry the relay delivers
+    /// the webhook from.
+    pub async fn insert_with_outbox(self, conn: &PgPooledConn) -> StorageResult<Event> {
+        conn.transaction_async(|conn| async move {
+            let aggregate = self.insert(&conn).await?;
+            OutboxNew::insert_for_aggregate(
+                &conn,
+                &aggregate,
+                storage_enums::OutboxOperation::Insert,
+            )
+            .await?;
+            Ok::<_, OutboxTransactionError>(aggregate)
+        })
+        .await
+        .map_err(|OutboxTransactionError(error)| error)
+    }
+}
+
+impl Outbox {
+    /// Finds the oldest pending entries, skipping the entries whose next publish attempt is not
+    /// due yet.
+    pub async fn find_pending(conn: &PgPooledConn, limit: i64) -> StorageResult<Vec<Self>> {
+        let now = common_utils::date_time::now();
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::status.eq(storage_enums::OutboxStatus::Pending).and(
+                dsl::next_attempt_at
+                    .is_null()
+                    .or(dsl::next_attempt_at.le(now)),
+            ),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_outbox_id(
+        conn: &PgPooledConn,
+        outbox_id: &str,
+        outbox_update: OutboxUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::outbox_id.eq(outbox_id.to_owned()),
+            OutboxUpdateInternal::from(outbox_update),
+        )
+        .await
+    }
+
+    /// Deletes the published entries created before the given time, returning the number of
+    /// deleted entries.
+    pub async fn delete_published_before(
+        conn: &PgPooledConn,
+        created_before: PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        let query = diesel::delete(<Self as HasTable>::table()).filter(
+            dsl::status
+                .eq(storage_enums::OutboxStatus::Published)
+                .and(dsl::created_at.lt(created_before)),
+        );
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting published outbox entries")
+    }
+}
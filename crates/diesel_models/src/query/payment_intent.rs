@@ -104,6 +104,22 @@ impl PaymentIntent {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    pub async fn list_recently_modified_by_processor_merchant_id(
+        conn: &PgPooledConn,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::processor_merchant_id.eq(processor_merchant_id.to_owned()),
+            Some(limit),
+            None,
+            Some(dsl::modified_at.desc()),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn find_by_payment_id_processor_merchant_id(
         conn: &PgPooledConn,
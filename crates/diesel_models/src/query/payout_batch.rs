@@ -0,0 +1,180 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate, PayoutBatchNew,
+        PayoutBatchUpdate, PayoutBatchUpdateInternal,
+    },
+    schema::{payout_batch::dsl, payout_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_payout_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_batch_id.eq(payout_batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::payout_batch_id.eq(self.payout_batch_id.to_owned())),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    /// Updates the batch only if it is still in `current_status`, returning
+    /// [`errors::DatabaseError::NotFound`] if it was moved to another status concurrently.
+    pub async fn update_by_status(
+        self,
+        conn: &PgPooledConn,
+        current_status: storage_enums::PayoutBatchStatus,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::payout_batch_id.eq(self.payout_batch_id.to_owned()))
+                .and(dsl::status.eq(current_status)),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+    }
+}
+
+impl PayoutBatchItemNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatchItem> {
+        generics::generic_insert(conn, self).await
+    }
+
+    pub async fn batch_insert(items: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PayoutBatchItem>(conn, items).await?;
+        Ok(())
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn list_by_payout_batch_id(
+        conn: &PgPooledConn,
+        payout_batch_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::payout_batch_id.eq(payout_batch_id.to_owned()),
+            None,
+            None,
+            Some(item_dsl::item_index.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_payout_batch_id_status(
+        conn: &PgPooledConn,
+        payout_batch_id: &str,
+        status: storage_enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::payout_batch_id
+                .eq(payout_batch_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+            Some(limit),
+            None,
+            Some(item_dsl::item_index.asc()),
+        )
+        .await
+    }
+
+    pub async fn get_count_by_payout_batch_id_status(
+        conn: &PgPooledConn,
+        payout_batch_id: &str,
+        status: storage_enums::PayoutBatchItemStatus,
+    ) -> StorageResult<usize> {
+        generics::generic_count::<<Self as HasTable>::Table, _>(
+            conn,
+            item_dsl::payout_batch_id
+                .eq(payout_batch_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::payout_batch_id
+                .eq(self.payout_batch_id.to_owned())
+                .and(item_dsl::item_index.eq(self.item_index)),
+            item_update,
+        )
+        .await
+    }
+}
@@ -0,0 +1,414 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+#[cfg(feature = "v1")]
+use common_utils::types::{ConnectorTransactionId, MinorUnit, TimeRange};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use time::PrimitiveDateTime;
+
+use super::generics::{self, db_metrics};
+use crate::{
+    errors,
+    schema::{settlement_recon_line::dsl as line_dsl, settlement_report::dsl},
+    settlement_report::{
+        SettlementCandidate, SettlementReconLine, SettlementReconLineConstraints,
+        SettlementReconLineNew, SettlementReconLineUpdate, SettlementReport, SettlementReportNew,
+        SettlementReportUpdate, SettlementReportUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+#[cfg(feature = "v1")]
+type PaymentAttemptSettlementRow = (
+    String,
+    common_utils::id_type::PaymentId,
+    common_utils::id_type::ProfileId,
+    Option<ConnectorTransactionId>,
+    MinorUnit,
+    Option<MinorUnit>,
+    Option<MinorUnit>,
+    Option<common_enums::Currency>,
+    PrimitiveDateTime,
+);
+
+#[cfg(feature = "v1")]
+type RefundSettlementRow = (
+    String,
+    common_utils::id_type::PaymentId,
+    Option<common_utils::id_type::ProfileId>,
+    Option<ConnectorTransactionId>,
+    MinorUnit,
+    common_enums::Currency,
+    PrimitiveDateTime,
+);
+
+impl SettlementReportNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SettlementReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SettlementReport {
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_id.eq(report_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        settlement_report_update: SettlementReportUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::report_id.eq(self.report_id.to_owned())),
+            SettlementReportUpdateInternal::from(settlement_report_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl SettlementReconLineNew {
+    pub async fn batch_insert(lines: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, SettlementReconLine>(conn, lines).await?;
+        Ok(())
+    }
+}
+
+impl SettlementReconLine {
+    pub async fn find_by_merchant_id_line_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        line_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            line_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(line_dsl::line_id.eq(line_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &SettlementReconLineConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(line_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order((line_dsl::created_at.desc(), line_dsl::line_id.asc()))
+            .into_boxed();
+
+        if let Some(report_id) = &constraints.report_id {
+            filter = filter.filter(line_dsl::report_id.eq(report_id.clone()));
+        }
+        if let Some(connector) = &constraints.connector {
+            filter = filter.filter(line_dsl::connector.eq(connector.clone()));
+        }
+        if let Some(status) = &constraints.status {
+            filter = filter.filter(line_dsl::status.eq_any(status.clone()));
+        }
+        if let Some(is_resolved) = constraints.is_resolved {
+            filter = filter.filter(line_dsl::is_resolved.eq(is_resolved));
+        }
+        if let Some(profile_id) = &constraints.profile_id {
+            filter = filter.filter(line_dsl::profile_id.eq(profile_id.clone()));
+        }
+        if let Some(limit) = constraints.limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = constraints.offset {
+            filter = filter.offset(offset);
+        }
+
+        router_env::logger::debug!(
+            query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string()
+        );
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable("Error filtering settlement recon lines by predicate")
+    }
+
+    pub async fn delete_by_report_id(conn: &PgPooledConn, report_id: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            line_dsl::report_id.eq(report_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        line_update: SettlementReconLineUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            line_dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(line_dsl::line_id.eq(self.line_id.to_owned())),
+            line_update,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "v1")]
+impl SettlementCandidate {
+    /// Finds the captured payment attempts of a connector having one of the given connector
+    /// transaction ids.
+    pub async fn find_captured_payment_attempts_by_connector_transaction_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        connector_transaction_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        use crate::schema::payment_attempt::dsl as attempt_dsl;
+
+        let query = crate::PaymentAttempt::table()
+            .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(attempt_dsl::connector.eq(connector.to_owned()))
+            .filter(attempt_dsl::connector_transaction_id.eq_any(connector_transaction_ids))
+            .filter(attempt_dsl::status.eq_any(Self::get_captured_attempt_statuses()));
+
+        Self::load_payment_attempts(conn, query.into_boxed()).await
+    }
+
+    /// Finds the payment attempts of a connector which were captured within the given time range.
+    pub async fn find_captured_payment_attempts_by_time_range(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        time_range: &TimeRange,
+    ) -> StorageResult<Vec<Self>> {
+        use crate::schema::payment_attempt::dsl as attempt_dsl;
+
+        let mut query = crate::PaymentAttempt::table()
+            .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(attempt_dsl::connector.eq(connector.to_owned()))
+            .filter(attempt_dsl::status.eq_any(Self::get_captured_attempt_statuses()))
+            .filter(attempt_dsl::modified_at.ge(time_range.start_time))
+            .into_boxed();
+
+        if let Some(end_time) = time_range.end_time {
+            query = query.filter(attempt_dsl::modified_at.le(end_time));
+        }
+
+        Self::load_payment_attempts(conn, query).await
+    }
+
+    /// Finds the succeeded refunds of a connector having one of the given connector refund ids.
+    pub async fn find_succeeded_refunds_by_connector_refund_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        connector_refund_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        use crate::schema::refund::dsl as refund_dsl;
+
+        let query = crate::refund::Refund::table()
+            .filter(refund_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(refund_dsl::connector.eq(connector.to_owned()))
+            .filter(refund_dsl::connector_refund_id.eq_any(connector_refund_ids))
+            .filter(refund_dsl::refund_status.eq(common_enums::RefundStatus::Success));
+
+        Self::load_refunds(conn, query.into_boxed()).await
+    }
+
+    /// Finds the refunds of a connector which succeeded within the given time range.
+    pub async fn find_succeeded_refunds_by_time_range(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        time_range: &TimeRange,
+    ) -> StorageResult<Vec<Self>> {
+        use crate::schema::refund::dsl as refund_dsl;
+
+        let mut query = crate::refund::Refund::table()
+            .filter(
+                refund_dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(refund_dsl::connector.eq(connector.to_owned())),
+            )
+            .filter(refund_dsl::refund_status.eq(common_enums::RefundStatus::Success))
+            .filter(refund_dsl::modified_at.ge(time_range.start_time))
+            .into_boxed();
+
+        if let Some(end_time) = time_range.end_time {
+            query = query.filter(refund_dsl::modified_at.le(end_time));
+        }
+
+        Self::load_refunds(conn, query).await
+    }
+
+    fn get_captured_attempt_statuses() -> [common_enums::AttemptStatus; 2] {
+        [
+            common_enums::AttemptStatus::Charged,
+            common_enums::AttemptStatus::PartialCharged,
+        ]
+    }
+
+    async fn load_payment_attempts(
+        conn: &PgPooledConn,
+        query: crate::schema::payment_attempt::BoxedQuery<'static, diesel::pg::Pg>,
+    ) -> StorageResult<Vec<Self>> {
+        use crate::schema::payment_attempt::dsl as attempt_dsl;
+
+        let query = query.select((
+            attempt_dsl::attempt_id,
+            attempt_dsl::payment_id,
+            attempt_dsl::profile_id,
+            attempt_dsl::connector_transaction_id,
+            attempt_dsl::amount,
+            attempt_dsl::net_amount,
+            attempt_dsl::amount_to_capture,
+            attempt_dsl::currency,
+            attempt_dsl::modified_at,
+        ));
+
+        router_env::logger::debug!(
+            query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string()
+        );
+
+        let rows = db_metrics::track_database_call::<crate::schema::payment_attempt::table, _, _>(
+            query.get_results_async::<PaymentAttemptSettlementRow>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable("Error filtering payment attempts for settlement reconciliation")?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(
+                    attempt_id,
+                    payment_id,
+                    profile_id,
+                    connector_transaction_id,
+                    amount,
+                    net_amount,
+                    amount_to_capture,
+                    currency,
+                    modified_at,
+                )| Self {
+                    transaction_type: common_enums::SettlementTransactionType::Payment,
+                    connector_transaction_id: connector_transaction_id
+                        .map(|connector_transaction_id| connector_transaction_id.get_id().clone()),
+                    amount: amount_to_capture.or(net_amount).unwrap_or(amount),
+                    currency,
+                    payment_id,
+                    attempt_id: Some(attempt_id),
+                    refund_id: None,
+                    profile_id: Some(profile_id),
+                    modified_at,
+                },
+            )
+            .collect())
+    }
+
+    async fn load_refunds(
+        conn: &PgPooledConn,
+        query: crate::schema::refund::BoxedQuery<'static, diesel::pg::Pg>,
+    ) -> StorageResult<Vec<Self>> {
+        use crate::schema::refund::dsl as refund_dsl;
+
+        let query = query.select((
+            refund_dsl::refund_id,
+            refund_dsl::payment_id,
+            refund_dsl::profile_id,
+            refund_dsl::connector_refund_id,
+            refund_dsl::refund_amount,
+            refund_dsl::currency,
+            refund_dsl::modified_at,
+        ));
+
+        router_env::logger::debug!(
+            query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string()
+        );
+
+        let rows = db_metrics::track_database_call::<crate::schema::refund::table, _, _>(
+            query.get_results_async::<RefundSettlementRow>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable("Error filtering refunds for settlement reconciliation")?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(
+                    refund_id,
+                    payment_id,
+                    profile_id,
+                    connector_refund_id,
+                    amount,
+                    currency,
+                    modified_at,
+                )| Self {
+                    transaction_type: common_enums::SettlementTransactionType::Refund,
+                    connector_transaction_id: connector_refund_id
+                        .map(|connector_refund_id| connector_refund_id.get_id().clone()),
+                    amount,
+                    currency: Some(currency),
+                    payment_id,
+                    attempt_id: None,
+                    refund_id: Some(refund_id),
+                    profile_id,
+                    modified_at,
+                },
+            )
+            .collect())
+    }
+}
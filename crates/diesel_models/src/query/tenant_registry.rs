@@ -0,0 +1,84 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::tenant_registry::dsl,
+    tenant_registry::{
+        TenantRegistry, TenantRegistryNew, TenantRegistryUpdate, TenantRegistryUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl TenantRegistryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<TenantRegistry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl TenantRegistry {
+    pub async fn find_by_tenant_id(
+        conn: &PgPooledConn,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::tenant_id.eq(tenant_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn list_all(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::tenant_id.is_not_null(),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_status(
+        conn: &PgPooledConn,
+        status: storage_enums::TenantStatus,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::status.eq(status),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_tenant_id(
+        conn: &PgPooledConn,
+        tenant_id: &common_utils::id_type::TenantId,
+        tenant_registry_update: TenantRegistryUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::tenant_id.eq(tenant_id.to_owned()),
+            TenantRegistryUpdateInternal::from(tenant_registry_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_tenant_id(
+        conn: &PgPooledConn,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::tenant_id.eq(tenant_id.to_owned()),
+        )
+        .await
+    }
+}
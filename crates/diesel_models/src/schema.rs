@@ -1,5 +1,50 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    account_updater_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        connector -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        card_count -> Int4,
+        updated_count -> Int4,
+        closed_count -> Int4,
+        unchanged_count -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    account_updater_card (batch_id, payment_method_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 32]
+        reason -> Varchar,
+        #[max_length = 32]
+        result -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -58,6 +103,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    archived_record (merchant_id, entity, record_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        entity -> Varchar,
+        #[max_length = 255]
+        record_id -> Varchar,
+        #[max_length = 255]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        file_key -> Varchar,
+        #[max_length = 32]
+        file_format -> Varchar,
+        record_created_at -> Timestamp,
+        archived_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -184,6 +251,138 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    billing_coupon (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        code -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 32]
+        discount_type -> Varchar,
+        percent_off -> Nullable<Int4>,
+        amount_off -> Nullable<Int8>,
+        currency -> Nullable<Currency>,
+        #[max_length = 32]
+        duration -> Varchar,
+        duration_in_cycles -> Nullable<Int4>,
+        max_redemptions -> Nullable<Int4>,
+        times_redeemed -> Int4,
+        redeem_by -> Nullable<Timestamp>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    billing_plan (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        product_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        trial_period_days -> Nullable<Int4>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    billing_price (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        currency -> Currency,
+        #[max_length = 32]
+        pricing_model -> Varchar,
+        unit_amount -> Nullable<Int8>,
+        tiers -> Nullable<Jsonb>,
+        #[max_length = 32]
+        billing_interval -> Varchar,
+        interval_count -> Int4,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    billing_product (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    bin_range (bin_range_id) {
+        #[max_length = 64]
+        bin_range_id -> Varchar,
+        #[max_length = 11]
+        range_low -> Varchar,
+        #[max_length = 11]
+        range_high -> Varchar,
+        card_issuer -> Nullable<Text>,
+        card_network -> Nullable<Text>,
+        card_type -> Nullable<Text>,
+        card_subtype -> Nullable<Text>,
+        card_issuing_country -> Nullable<Text>,
+        #[max_length = 32]
+        country_code -> Nullable<Varchar>,
+        #[max_length = 32]
+        bank_code -> Nullable<Varchar>,
+        #[max_length = 64]
+        source -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -225,6 +424,58 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    bulk_refund (bulk_refund_id) {
+        #[max_length = 64]
+        bulk_refund_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        total_count -> Int4,
+        submitted_count -> Int4,
+        failed_count -> Int4,
+        #[max_length = 255]
+        created_by -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    bulk_refund_item (bulk_refund_id, item_index) {
+        #[max_length = 64]
+        bulk_refund_id -> Varchar,
+        item_index -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        refund_id -> Varchar,
+        amount -> Nullable<Int8>,
+        request_data -> Jsonb,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        refund_status -> Nullable<RefundStatus>,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -406,6 +657,68 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_data_request (request_id) {
+        #[max_length = 64]
+        request_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 32]
+        request_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        file_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        requested_by -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_match_key (merchant_id, customer_id, key_type) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 16]
+        key_type -> Varchar,
+        #[max_length = 128]
+        key_hash -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_redirect (merchant_id, customer_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        target_customer_id -> Varchar,
+        #[max_length = 64]
+        merge_id -> Varchar,
+        #[max_length = 255]
+        merged_by -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -465,6 +778,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    data_retention_policy (entity) {
+        #[max_length = 32]
+        entity -> Varchar,
+        retention_days -> Int4,
+        #[max_length = 32]
+        file_format -> Varchar,
+        #[max_length = 32]
+        purge_mode -> Varchar,
+        batch_size -> Int4,
+        is_enabled -> Bool,
+        archived_count -> Int8,
+        last_archived_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -515,6 +849,53 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute_automated_action (action_id) {
+        #[max_length = 64]
+        action_id -> Varchar,
+        #[max_length = 64]
+        dispute_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        rule_name -> Nullable<Varchar>,
+        #[max_length = 32]
+        action -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute_evidence_template (template_id) {
+        #[max_length = 64]
+        template_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        reason_codes -> Array<Nullable<Text>>,
+        evidence_sources -> Jsonb,
+        text_evidence -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -803,7 +1184,7 @@ diesel::table! {
         #[max_length = 64]
         profile_id -> Varchar,
         #[max_length = 128]
-        merchant_connector_id -> Varchar,
+        merchant_connector_id -> Nullable<Varchar>,
         #[max_length = 64]
         payment_intent_id -> Nullable<Varchar>,
         #[max_length = 64]
@@ -816,7 +1197,7 @@ diesel::table! {
         #[max_length = 64]
         status -> Varchar,
         #[max_length = 128]
-        provider_name -> Varchar,
+        provider_name -> Nullable<Varchar>,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
         modified_at -> Timestamp,
@@ -825,6 +1206,76 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_fee_schedule (fee_schedule_id) {
+        #[max_length = 64]
+        fee_schedule_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method -> Nullable<Varchar>,
+        currency -> Nullable<Currency>,
+        fixed_fee -> Int8,
+        percentage_fee_basis_points -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_journal_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        #[max_length = 32]
+        entry_type -> Varchar,
+        #[max_length = 128]
+        reference_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        currency -> Currency,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_posting (posting_id) {
+        #[max_length = 64]
+        posting_id -> Varchar,
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        #[max_length = 32]
+        account -> Varchar,
+        #[max_length = 16]
+        direction -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1038,6 +1489,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    outbox (outbox_id) {
+        #[max_length = 64]
+        outbox_id -> Varchar,
+        #[max_length = 255]
+        dedupe_key -> Varchar,
+        #[max_length = 32]
+        aggregate_type -> Varchar,
+        #[max_length = 64]
+        aggregate_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 16]
+        operation -> Varchar,
+        payload -> Jsonb,
+        #[max_length = 32]
+        status -> Varchar,
+        attempts -> Int4,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamp,
+        published_at -> Nullable<Timestamp>,
+        next_attempt_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1436,6 +1915,64 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (payout_batch_id) {
+        #[max_length = 64]
+        payout_batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 64]
+        file_id -> Nullable<Varchar>,
+        total_count -> Int4,
+        submitted_count -> Int4,
+        failed_count -> Int4,
+        #[max_length = 255]
+        created_by -> Nullable<Varchar>,
+        #[max_length = 255]
+        reviewed_by -> Nullable<Varchar>,
+        rejection_reason -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch_item (payout_batch_id, item_index) {
+        #[max_length = 64]
+        payout_batch_id -> Varchar,
+        item_index -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payout_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        request_data -> Jsonb,
+        #[max_length = 32]
+        status -> Varchar,
+        payout_status -> Nullable<PayoutStatus>,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1687,6 +2224,84 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_recon_line (line_id) {
+        #[max_length = 64]
+        line_id -> Varchar,
+        #[max_length = 64]
+        report_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector -> Varchar,
+        line_number -> Nullable<Int4>,
+        #[max_length = 16]
+        transaction_type -> Varchar,
+        #[max_length = 128]
+        connector_transaction_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        settled_amount -> Nullable<Int8>,
+        settled_currency -> Nullable<Currency>,
+        fee -> Nullable<Int8>,
+        hyperswitch_amount -> Nullable<Int8>,
+        hyperswitch_currency -> Nullable<Currency>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        settled_at -> Nullable<Timestamp>,
+        is_resolved -> Bool,
+        resolution_note -> Nullable<Text>,
+        resolved_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_report (report_id) {
+        #[max_length = 64]
+        report_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        file_id -> Varchar,
+        #[max_length = 32]
+        format -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        period_start -> Nullable<Timestamp>,
+        period_end -> Nullable<Timestamp>,
+        total_count -> Int4,
+        matched_count -> Int4,
+        amount_mismatch_count -> Int4,
+        missing_in_hyperswitch_count -> Int4,
+        missing_at_psp_count -> Int4,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1721,6 +2336,31 @@ diesel::table! {
         plan_id -> Nullable<Varchar>,
         #[max_length = 128]
         item_price_id -> Nullable<Varchar>,
+        billing_details -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    tenant_registry (tenant_id) {
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        base_url -> Text,
+        #[max_length = 64]
+        schema_name -> Varchar,
+        #[max_length = 64]
+        accounts_schema_name -> Varchar,
+        #[max_length = 64]
+        redis_key_prefix -> Varchar,
+        #[max_length = 64]
+        clickhouse_database -> Varchar,
+        control_center_url -> Text,
+        #[max_length = 32]
+        status -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1873,21 +2513,37 @@ diesel::table! {
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
+    account_updater_batch,
+    account_updater_card,
     address,
     api_keys,
+    archived_record,
     authentication,
+    billing_coupon,
+    billing_plan,
+    billing_price,
+    billing_product,
+    bin_range,
     blocklist,
     blocklist_fingerprint,
     blocklist_lookup,
+    bulk_refund,
+    bulk_refund_item,
     business_profile,
     callback_mapper,
     captures,
     card_issuers,
     cards_info,
     configs,
+    customer_data_request,
+    customer_match_key,
+    customer_redirect,
     customers,
     dashboard_metadata,
+    data_retention_policy,
     dispute,
+    dispute_automated_action,
+    dispute_evidence_template,
     dynamic_routing_stats,
     events,
     file_metadata,
@@ -1898,17 +2554,23 @@ diesel::allow_tables_to_appear_in_same_query!(
     hyperswitch_ai_interaction_default,
     incremental_authorization,
     invoice,
+    ledger_fee_schedule,
+    ledger_journal_entry,
+    ledger_posting,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
     merchant_key_store,
     organization,
+    outbox,
     payment_attempt,
     payment_intent,
     payment_link,
     payment_methods,
     payout_attempt,
+    payout_batch,
+    payout_batch_item,
     payouts,
     process_tracker,
     refund,
@@ -1916,7 +2578,10 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    settlement_recon_line,
+    settlement_report,
     subscription,
+    tenant_registry,
     themes,
     unified_translations,
     user_authentication_methods,
@@ -0,0 +1,242 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{settlement_recon_line, settlement_report},
+};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = settlement_report, primary_key(report_id), check_for_backend(diesel::pg::Pg))]
+pub struct SettlementReport {
+    pub report_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub organization_id: id_type::OrganizationId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: String,
+    pub file_id: String,
+    pub format: storage_enums::SettlementReportFormat,
+    pub status: storage_enums::SettlementReportStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub period_start: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub period_end: Option<PrimitiveDateTime>,
+    pub total_count: i32,
+    pub matched_count: i32,
+    pub amount_mismatch_count: i32,
+    pub missing_in_hyperswitch_count: i32,
+    pub missing_at_psp_count: i32,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = settlement_report)]
+pub struct SettlementReportNew {
+    pub report_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub organization_id: id_type::OrganizationId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: String,
+    pub file_id: String,
+    pub format: storage_enums::SettlementReportFormat,
+    pub status: storage_enums::SettlementReportStatus,
+    pub period_start: Option<PrimitiveDateTime>,
+    pub period_end: Option<PrimitiveDateTime>,
+    pub total_count: i32,
+    pub matched_count: i32,
+    pub amount_mismatch_count: i32,
+    pub missing_in_hyperswitch_count: i32,
+    pub missing_at_psp_count: i32,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SettlementReportUpdate {
+    StatusUpdate {
+        status: storage_enums::SettlementReportStatus,
+        error_message: Option<String>,
+    },
+    ResultUpdate {
+        period_start: Option<PrimitiveDateTime>,
+        period_end: Option<PrimitiveDateTime>,
+        total_count: i32,
+        matched_count: i32,
+        amount_mismatch_count: i32,
+        missing_in_hyperswitch_count: i32,
+        missing_at_psp_count: i32,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_report)]
+pub struct SettlementReportUpdateInternal {
+    pub status: Option<storage_enums::SettlementReportStatus>,
+    pub period_start: Option<PrimitiveDateTime>,
+    pub period_end: Option<PrimitiveDateTime>,
+    pub total_count: Option<i32>,
+    pub matched_count: Option<i32>,
+    pub amount_mismatch_count: Option<i32>,
+    pub missing_in_hyperswitch_count: Option<i32>,
+    pub missing_at_psp_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<SettlementReportUpdate> for SettlementReportUpdateInternal {
+    fn from(update: SettlementReportUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match update {
+            SettlementReportUpdate::StatusUpdate {
+                status,
+                error_message,
+            } => Self {
+                status: Some(status),
+                period_start: None,
+                period_end: None,
+                total_count: None,
+                matched_count: None,
+                amount_mismatch_count: None,
+                missing_in_hyperswitch_count: None,
+                missing_at_psp_count: None,
+                error_message,
+                modified_at,
+            },
+            SettlementReportUpdate::ResultUpdate {
+                period_start,
+                period_end,
+                total_count,
+                matched_count,
+                amount_mismatch_count,
+                missing_in_hyperswitch_count,
+                missing_at_psp_count,
+            } => Self {
+                status: Some(storage_enums::SettlementReportStatus::Completed),
+                period_start,
+                period_end,
+                total_count: Some(total_count),
+                matched_count: Some(matched_count),
+                amount_mismatch_count: Some(amount_mismatch_count),
+                missing_in_hyperswitch_count: Some(missing_in_hyperswitch_count),
+                missing_at_psp_count: Some(missing_at_psp_count),
+                error_message: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = settlement_recon_line, primary_key(line_id), check_for_backend(diesel::pg::Pg))]
+pub struct SettlementReconLine {
+    pub line_id: String,
+    pub report_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub organization_id: id_type::OrganizationId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: String,
+    pub line_number: Option<i32>,
+    pub transaction_type: storage_enums::SettlementTransactionType,
+    pub connector_transaction_id: Option<String>,
+    pub status: storage_enums::ReconLineStatus,
+    pub settled_amount: Option<MinorUnit>,
+    pub settled_currency: Option<storage_enums::Currency>,
+    pub fee: Option<MinorUnit>,
+    pub hyperswitch_amount: Option<MinorUnit>,
+    pub hyperswitch_currency: Option<storage_enums::Currency>,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<PrimitiveDateTime>,
+    pub is_resolved: bool,
+    pub resolution_note: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub resolved_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = settlement_recon_line)]
+pub struct SettlementReconLineNew {
+    pub line_id: String,
+    pub report_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub organization_id: id_type::OrganizationId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: String,
+    pub line_number: Option<i32>,
+    pub transaction_type: storage_enums::SettlementTransactionType,
+    pub connector_transaction_id: Option<String>,
+    pub status: storage_enums::ReconLineStatus,
+    pub settled_amount: Option<MinorUnit>,
+    pub settled_currency: Option<storage_enums::Currency>,
+    pub fee: Option<MinorUnit>,
+    pub hyperswitch_amount: Option<MinorUnit>,
+    pub hyperswitch_currency: Option<storage_enums::Currency>,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub settled_at: Option<PrimitiveDateTime>,
+    pub is_resolved: bool,
+    pub resolution_note: Option<String>,
+    pub resolved_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_recon_line)]
+pub struct SettlementReconLineUpdate {
+    pub is_resolved: bool,
+    pub resolution_note: Option<String>,
+    pub resolved_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SettlementReportTrackingData {
+    pub report_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SettlementReconLineConstraints {
+    pub report_id: Option<String>,
+    pub connector: Option<String>,
+    pub status: Option<Vec<storage_enums::ReconLineStatus>>,
+    pub is_resolved: Option<bool>,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// A succeeded payment attempt or refund of Hyperswitch, which is expected to be present in the
+/// settlement report of the connector.
+#[derive(Clone, Debug)]
+pub struct SettlementCandidate {
+    pub transaction_type: storage_enums::SettlementTransactionType,
+    pub connector_transaction_id: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: Option<storage_enums::Currency>,
+    pub payment_id: id_type::PaymentId,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub profile_id: Option<id_type::ProfileId>,
+    /// Time of the last update of the attempt or refund, at which it was captured or succeeded
+    pub modified_at: PrimitiveDateTime,
+}
@@ -1,7 +1,8 @@
-use common_utils::{generate_id_with_default_len, pii::SecretSerdeValue};
-use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use common_utils::{generate_id_with_default_len, pii::SecretSerdeValue, types::MinorUnit};
+use diesel::{AsChangeset, AsExpression, Identifiable, Insertable, Queryable, Selectable};
 use hyperswitch_masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 
 use crate::schema::subscription;
 
@@ -24,6 +25,7 @@ pub struct SubscriptionNew {
     merchant_reference_id: Option<String>,
     plan_id: Option<String>,
     item_price_id: Option<String>,
+    billing_details: Option<SubscriptionBillingDetails>,
 }
 
 #[derive(
@@ -47,6 +49,7 @@ pub struct Subscription {
     pub merchant_reference_id: Option<String>,
     pub plan_id: Option<String>,
     pub item_price_id: Option<String>,
+    pub billing_details: Option<SubscriptionBillingDetails>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, AsChangeset, router_derive::DebugAsDisplay, Deserialize)]
@@ -58,8 +61,36 @@ pub struct SubscriptionUpdate {
     pub modified_at: time::PrimitiveDateTime,
     pub plan_id: Option<String>,
     pub item_price_id: Option<String>,
+    pub billing_details: Option<SubscriptionBillingDetails>,
 }
 
+/// Billing state of a subscription whose invoices are generated by the native billing engine
+/// instead of an external billing processor
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct SubscriptionBillingDetails {
+    pub quantity: u32,
+    pub coupon_id: Option<String>,
+    /// Number of billing cycles the coupon still applies to, `None` when it applies forever
+    pub coupon_cycles_remaining: Option<u32>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+    /// Proration credit that is deducted from the next invoice
+    pub credit_balance: MinorUnit,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub pause_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub resume_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub cancel_at: Option<PrimitiveDateTime>,
+}
+
+common_utils::impl_to_sql_from_sql_json!(SubscriptionBillingDetails);
+
 impl SubscriptionNew {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -77,6 +108,7 @@ impl SubscriptionNew {
         merchant_reference_id: Option<String>,
         plan_id: Option<String>,
         item_price_id: Option<String>,
+        billing_details: Option<SubscriptionBillingDetails>,
     ) -> Self {
         let now = common_utils::date_time::now();
         Self {
@@ -96,6 +128,7 @@ impl SubscriptionNew {
             merchant_reference_id,
             plan_id,
             item_price_id,
+            billing_details,
         }
     }
 
@@ -122,6 +155,30 @@ impl SubscriptionUpdate {
             modified_at: common_utils::date_time::now(),
             plan_id,
             item_price_id,
+            billing_details: None,
+        }
+    }
+
+    pub fn apply_changeset(self, source: Subscription) -> Subscription {
+        let Self {
+            connector_subscription_id,
+            payment_method_id,
+            status,
+            modified_at,
+            plan_id,
+            item_price_id,
+            billing_details,
+        } = self;
+        Subscription {
+            connector_subscription_id: connector_subscription_id
+                .or(source.connector_subscription_id),
+            payment_method_id: payment_method_id.or(source.payment_method_id),
+            status: status.unwrap_or(source.status),
+            modified_at,
+            plan_id: plan_id.or(source.plan_id),
+            item_price_id: item_price_id.or(source.item_price_id),
+            billing_details: billing_details.or(source.billing_details),
+            ..source
         }
     }
 }
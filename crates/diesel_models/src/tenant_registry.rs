@@ -0,0 +1,64 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::tenant_registry};
+
+/// A tenant provisioned at runtime, in addition to the tenants of the static configuration
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = tenant_registry, primary_key(tenant_id), check_for_backend(diesel::pg::Pg))]
+pub struct TenantRegistry {
+    pub tenant_id: id_type::TenantId,
+    pub base_url: String,
+    pub schema_name: String,
+    pub accounts_schema_name: String,
+    pub redis_key_prefix: String,
+    pub clickhouse_database: String,
+    pub control_center_url: String,
+    pub status: storage_enums::TenantStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = tenant_registry)]
+pub struct TenantRegistryNew {
+    pub tenant_id: id_type::TenantId,
+    pub base_url: String,
+    pub schema_name: String,
+    pub accounts_schema_name: String,
+    pub redis_key_prefix: String,
+    pub clickhouse_database: String,
+    pub control_center_url: String,
+    pub status: storage_enums::TenantStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum TenantRegistryUpdate {
+    StatusUpdate { status: storage_enums::TenantStatus },
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = tenant_registry)]
+pub struct TenantRegistryUpdateInternal {
+    pub status: Option<storage_enums::TenantStatus>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<TenantRegistryUpdate> for TenantRegistryUpdateInternal {
+    fn from(update: TenantRegistryUpdate) -> Self {
+        match update {
+            TenantRegistryUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -18,7 +18,7 @@ use time::PrimitiveDateTime;
 use crate::{
     enums::{MandateDataType, MandateDetails},
     schema::payment_attempt,
-    ConnectorMandateReferenceId, NetworkDetails, PaymentAttemptNew,
+    ConnectorMandateReferenceId, NetworkDetails, PaymentAttempt, PaymentAttemptNew,
 };
 
 // #[cfg(feature = "v2")]
@@ -263,15 +263,7 @@ impl PaymentAttemptBatchNew {
             connector_metadata: self.connector_metadata,
             payment_experience: self.payment_experience,
             payment_method_type: self.payment_method_type,
-            card_network: self
-                .payment_method_data
-                .as_ref()
-                .and_then(|data| data.as_object())
-                .and_then(|card| card.get("card"))
-                .and_then(|v| v.as_object())
-                .and_then(|v| v.get("card_network"))
-                .and_then(|network| network.as_str())
-                .map(|network| network.to_string()),
+            card_network: self.get_card_network(),
             payment_method_data: self.payment_method_data,
             encrypted_payment_method_data: self.encrypted_payment_method_data,
             business_sub_label: self.business_sub_label,
@@ -324,4 +316,110 @@ impl PaymentAttemptBatchNew {
             installment_data: None,
         }
     }
+
+    /// Builds the row the batch insert of the sample data would store in the payment attempt table
+    pub fn convert_into_payment_attempt(self) -> PaymentAttempt {
+        PaymentAttempt {
+            payment_id: self.payment_id,
+            merchant_id: self.merchant_id,
+            attempt_id: self.attempt_id,
+            status: self.status,
+            amount: self.amount,
+            currency: self.currency,
+            save_to_locker: self.save_to_locker,
+            connector: self.connector,
+            error_message: self.error_message,
+            offer_amount: self.offer_amount,
+            surcharge_amount: self.surcharge_amount,
+            tax_amount: self.tax_amount,
+            payment_method_id: self.payment_method_id,
+            payment_method: self.payment_method,
+            connector_transaction_id: self.connector_transaction_id,
+            capture_method: self.capture_method,
+            capture_on: self.capture_on,
+            confirm: self.confirm,
+            authentication_type: self.authentication_type,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            last_synced: self.last_synced,
+            cancellation_reason: self.cancellation_reason,
+            amount_to_capture: self.amount_to_capture,
+            mandate_id: self.mandate_id,
+            browser_info: self.browser_info,
+            error_code: self.error_code,
+            payment_token: self.payment_token,
+            connector_metadata: self.connector_metadata,
+            payment_experience: self.payment_experience,
+            payment_method_type: self.payment_method_type,
+            payment_method_data: self.payment_method_data,
+            business_sub_label: self.business_sub_label,
+            straight_through_algorithm: self.straight_through_algorithm,
+            preprocessing_step_id: self.preprocessing_step_id,
+            mandate_details: self.mandate_details,
+            error_reason: self.error_reason,
+            multiple_capture_count: self.multiple_capture_count,
+            connector_response_reference_id: self.connector_response_reference_id,
+            amount_capturable: self.amount_capturable,
+            updated_by: self.updated_by,
+            merchant_connector_id: self.merchant_connector_id,
+            authentication_data: self.authentication_data,
+            encoded_data: self.encoded_data,
+            unified_code: self.unified_code,
+            unified_message: self.unified_message,
+            net_amount: self.net_amount,
+            external_three_ds_authentication_attempted: self
+                .external_three_ds_authentication_attempted,
+            authentication_connector: self.authentication_connector,
+            authentication_id: self.authentication_id,
+            mandate_data: self.mandate_data,
+            fingerprint_id: self.fingerprint_id,
+            payment_method_billing_address_id: self.payment_method_billing_address_id,
+            charge_id: self.charge_id,
+            client_source: self.client_source,
+            client_version: self.client_version,
+            customer_acceptance: self.customer_acceptance,
+            profile_id: self.profile_id,
+            organization_id: self.organization_id,
+            card_network: self.get_card_network(),
+            shipping_cost: self.shipping_cost,
+            order_tax_amount: self.order_tax_amount,
+            connector_transaction_data: None,
+            connector_mandate_detail: self.connector_mandate_detail,
+            request_extended_authorization: self.request_extended_authorization,
+            extended_authorization_applied: self.extended_authorization_applied,
+            capture_before: self.capture_before,
+            processor_transaction_data: self.processor_transaction_data,
+            card_discovery: self.card_discovery,
+            charges: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+            processor_merchant_id: self.processor_merchant_id,
+            created_by: self.created_by,
+            setup_future_usage_applied: self.setup_future_usage_applied,
+            routing_approach: self.routing_approach,
+            connector_request_reference_id: self.connector_request_reference_id,
+            network_transaction_id: self.network_transaction_id,
+            is_overcapture_enabled: None,
+            network_details: self.network_details,
+            is_stored_credential: self.is_stored_credential,
+            authorized_amount: self.authorized_amount,
+            extended_authorization_last_applied_at: self.extended_authorization_last_applied_at,
+            tokenization: self.tokenization,
+            encrypted_payment_method_data: self.encrypted_payment_method_data,
+            error_details: None,
+            retry_type: None,
+            installment_data: None,
+        }
+    }
+
+    fn get_card_network(&self) -> Option<String> {
+        self.payment_method_data
+            .as_ref()
+            .and_then(|data| data.as_object())
+            .and_then(|card| card.get("card"))
+            .and_then(|v| v.as_object())
+            .and_then(|v| v.get("card_network"))
+            .and_then(|network| network.as_str())
+            .map(|network| network.to_string())
+    }
 }
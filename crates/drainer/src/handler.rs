@@ -1,9 +1,5 @@
-use std::{
-    collections::HashMap,
-    sync::{atomic, Arc},
-};
+use std::sync::{atomic, Arc};
 
-use common_utils::id_type;
 use router_env::tracing::Instrument;
 use tokio::{
     sync::{mpsc, oneshot},
@@ -11,8 +7,8 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    errors, instrument, logger, metrics, query::ExecuteQuery, tenant_registry::TenantStores,
+    tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -35,15 +31,12 @@ pub struct HandlerInner {
     loop_interval: Duration,
     active_tasks: Arc<atomic::AtomicU64>,
     conf: DrainerSettings,
-    stores: HashMap<id_type::TenantId, Arc<Store>>,
+    stores: TenantStores,
     running: Arc<atomic::AtomicBool>,
 }
 
 impl Handler {
-    pub fn from_conf(
-        conf: DrainerSettings,
-        stores: HashMap<id_type::TenantId, Arc<Store>>,
-    ) -> Self {
+    pub fn from_conf(conf: DrainerSettings, stores: TenantStores) -> Self {
         let shutdown_interval = Duration::from_millis(conf.shutdown_interval.into());
         let loop_interval = Duration::from_millis(conf.loop_interval.into());
 
@@ -75,7 +68,14 @@ impl Handler {
 
         while self.running.load(atomic::Ordering::SeqCst) {
             metrics::DRAINER_HEALTH.add(1, &[]);
-            for store in self.stores.values() {
+            let stores = self
+                .stores
+                .read()
+                .await
+                .values()
+                .cloned()
+                .collect::<Vec<_>>();
+            for store in stores {
                 if store.is_stream_available(stream_index).await {
                     let _task_handle = tokio::spawn(
                         drainer_handler(
@@ -123,10 +123,12 @@ impl Handler {
         )
     }
 
-    pub fn spawn_error_handlers(&self, tx: mpsc::Sender<()>) -> errors::DrainerResult<()> {
+    pub async fn spawn_error_handlers(&self, tx: mpsc::Sender<()>) -> errors::DrainerResult<()> {
         let (redis_error_tx, redis_error_rx) = oneshot::channel();
         let redis_conn_clone = self
             .stores
+            .read()
+            .await
             .values()
             .next()
             .map(|store| store.redis_conn.clone());
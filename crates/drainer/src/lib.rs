@@ -8,6 +8,7 @@ mod query;
 pub mod services;
 pub mod settings;
 mod stream;
+pub mod tenant_registry;
 mod types;
 mod utils;
 use std::{collections::HashMap, sync::Arc};
@@ -31,7 +32,7 @@ use crate::{
 };
 
 pub async fn start_drainer(
-    stores: HashMap<id_type::TenantId, Arc<Store>>,
+    stores: tenant_registry::TenantStores,
     conf: DrainerSettings,
 ) -> errors::DrainerResult<()> {
     let drainer_handler = handler::Handler::from_conf(conf, stores);
@@ -49,7 +50,7 @@ pub async fn start_drainer(
 
     tokio::task::spawn(async move { handler_clone.shutdown_listener(rx).await });
 
-    drainer_handler.spawn_error_handlers(tx)?;
+    drainer_handler.spawn_error_handlers(tx).await?;
     drainer_handler.spawn().await?;
 
     handle.close();
@@ -1,6 +1,9 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
-use drainer::{errors::DrainerResult, logger, services, settings, start_drainer, start_web_server};
+use drainer::{
+    errors::DrainerResult, logger, services, settings, start_drainer, start_web_server,
+    tenant_registry::TenantRegistryListener,
+};
 use router_env::tracing::Instrument;
 
 #[tokio::main]
@@ -19,7 +22,7 @@ async fn main() -> DrainerResult<()> {
 
     let mut stores = HashMap::new();
     for (tenant_name, tenant) in conf.multitenancy.get_tenants() {
-        let store = std::sync::Arc::new(services::Store::new(&state.conf, false, tenant).await);
+        let store = Arc::new(services::Store::new(&state.conf, false, tenant).await);
         stores.insert(tenant_name.clone(), store);
     }
 
@@ -54,7 +57,22 @@ async fn main() -> DrainerResult<()> {
     logger::debug!(startup_config=?conf);
     logger::info!("Drainer started [{:?}] [{:?}]", conf.drainer, conf.log);
 
-    start_drainer(stores.clone(), conf.drainer).await?;
+    let stores = Arc::new(tokio::sync::RwLock::new(stores));
+    if conf.multitenancy.enabled {
+        let listener = TenantRegistryListener::new(state.conf.clone(), stores.clone()).await;
+        listener.refresh().await?;
+        tokio::spawn(
+            async move {
+                if let Err(error) = listener.listen().await {
+                    logger::error!(tenant_registry_error=?error);
+                }
+                logger::error!("The tenant registry listener stopped working!");
+            }
+            .in_current_span(),
+        );
+    }
+
+    start_drainer(stores, conf.drainer).await?;
 
     Ok(())
 }
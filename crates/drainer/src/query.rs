@@ -27,8 +27,10 @@ impl ExecuteQuery for kv::DBOperation {
 
         let tags = router_env::metric_attributes!(("operation", operation), ("table", table));
 
-        let (result, execution_time) =
-            Box::pin(common_utils::date_time::time_it(|| self.execute(&conn))).await;
+        let (result, execution_time) = Box::pin(common_utils::date_time::time_it(|| {
+            self.execute(&conn, store.config.outbox_enabled)
+        }))
+        .await;
 
         push_drainer_delay(pushed_at, operation, table, tags);
         metrics::QUERY_EXECUTION_TIME.record(execution_time, tags);
@@ -25,6 +25,27 @@ impl SecretsHandler for Database {
     }
 }
 
+/// Decrypts the Redis ACL and Sentinel passwords, which are optional unlike the other secrets
+async fn convert_redis_secrets(
+    redis: redis_interface::RedisSettings,
+    secret_management_client: &dyn SecretManagementInterface,
+) -> CustomResult<redis_interface::RedisSettings, SecretsManagementError> {
+    let password = match redis.password {
+        Some(password) => Some(secret_management_client.get_secret(password).await?),
+        None => None,
+    };
+    let sentinel_password = match redis.sentinel_password {
+        Some(password) => Some(secret_management_client.get_secret(password).await?),
+        None => None,
+    };
+
+    Ok(redis_interface::RedisSettings {
+        password,
+        sentinel_password,
+        ..redis
+    })
+}
+
 /// # Panics
 ///
 /// Will panic even if fetching raw secret fails for at least one config value
@@ -37,10 +58,15 @@ pub async fn fetch_raw_secrets(
         .await
         .expect("Failed to decrypt database password");
 
+    #[allow(clippy::expect_used)]
+    let redis = convert_redis_secrets(conf.redis, secret_management_client)
+        .await
+        .expect("Failed to decrypt redis configs");
+
     Settings {
         server: conf.server,
         master_database: database,
-        redis: conf.redis,
+        redis,
         log: conf.log,
         drainer: conf.drainer,
         encryption_management: conf.encryption_management,
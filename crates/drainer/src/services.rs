@@ -23,6 +23,7 @@ pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
     pub use_legacy_version: bool,
+    pub outbox_enabled: bool,
 }
 
 impl Store {
@@ -47,6 +48,7 @@ impl Store {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
                 use_legacy_version: config.redis.use_legacy_version,
+                outbox_enabled: config.drainer.outbox_enabled,
             },
             request_id: None,
         }
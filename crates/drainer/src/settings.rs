@@ -1,11 +1,12 @@
 use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
-use common_utils::{ext_traits::ConfigExt, id_type, DbConnectionParams};
+use common_utils::{ext_traits::ConfigExt, id_type, DbConnectionParams, DbTlsSettings};
 use config::{Environment, File};
 use external_services::managers::{
     encryption_management::EncryptionManagementConfig, secrets_management::SecretsManagementConfig,
 };
 use hyperswitch_interfaces::{
+    configs::GlobalTenant,
     encryption_interface::EncryptionManagementInterface,
     secrets_interface::secret_state::{
         RawSecret, SecretState, SecretStateContainer, SecuredSecret,
@@ -86,6 +87,7 @@ pub struct Database {
     pub dbname: String,
     pub pool_size: u32,
     pub connection_timeout: u64,
+    pub tls: Option<DbTlsSettings>,
 }
 
 impl DbConnectionParams for Database {
@@ -104,6 +106,9 @@ impl DbConnectionParams for Database {
     fn get_dbname(&self) -> &str {
         &self.dbname
     }
+    fn get_tls_settings(&self) -> Option<&DbTlsSettings> {
+        self.tls.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -114,12 +119,17 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    /// Whether the payment intents, payment attempts and refunds are written along with their
+    /// outbox entries, to be set along with the `outbox_relay.enabled` setting of the router
+    pub outbox_enabled: bool,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Multitenancy {
     pub enabled: bool,
     pub tenants: TenantConfig,
+    #[serde(default)]
+    pub global_tenant: GlobalTenant,
 }
 impl Multitenancy {
     pub fn get_tenants(&self) -> &HashMap<id_type::TenantId, Tenant> {
@@ -212,6 +222,7 @@ impl Default for Database {
             dbname: String::new(),
             pool_size: 5,
             connection_timeout: 10,
+            tls: None,
         }
     }
 }
@@ -224,6 +235,7 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            outbox_enabled: false,
         }
     }
 }
@@ -307,7 +319,8 @@ impl Settings<SecuredSecret> {
                     .try_parsing(true)
                     .separator("__")
                     .list_separator(",")
-                    .with_list_parse_key("redis.cluster_urls"),
+                    .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls"),
             )
             .build()?;
 
@@ -0,0 +1,150 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use common_utils::id_type;
+use diesel_models::{enums::TenantStatus, tenant_registry::TenantRegistry};
+use error_stack::ResultExt;
+use redis_interface::{EventInterface, PubsubInterface, RedisValue};
+use router_env::{instrument, tracing};
+use tokio::sync::RwLock;
+
+use crate::{
+    connection::{diesel_make_pg_pool, pg_connection, redis_connection, PgPool},
+    errors, logger,
+    services::Store,
+    settings::Tenant,
+    Settings,
+};
+
+/// Channel on which the router publishes the cache invalidations, including the updates of the
+/// tenant registry
+const INVALIDATION_CHANNEL: &str = "hyperswitch_invalidate";
+
+/// Stores of the tenants drained by this instance, shared between the drainer handler and the
+/// tenant registry listener
+pub type TenantStores = Arc<RwLock<HashMap<id_type::TenantId, Arc<Store>>>>;
+
+#[derive(serde::Deserialize)]
+struct InvalidationMessage {
+    kind: serde_json::Value,
+}
+
+impl InvalidationMessage {
+    fn is_tenant_registry_update(&self) -> bool {
+        ["TenantRegistry", "All"]
+            .into_iter()
+            .any(|kind| self.kind.get(kind).is_some())
+    }
+}
+
+impl From<&TenantRegistry> for Tenant {
+    fn from(tenant: &TenantRegistry) -> Self {
+        Self {
+            tenant_id: tenant.tenant_id.clone(),
+            base_url: tenant.base_url.clone(),
+            schema: tenant.schema_name.clone(),
+            accounts_schema: tenant.accounts_schema_name.clone(),
+            redis_key_prefix: tenant.redis_key_prefix.clone(),
+            clickhouse_database: tenant.clickhouse_database.clone(),
+        }
+    }
+}
+
+/// Keeps the stores of the drainer in sync with the tenant registry stored in the global schema.
+pub struct TenantRegistryListener {
+    conf: Arc<Settings>,
+    global_pool: PgPool,
+    stores: TenantStores,
+}
+
+impl TenantRegistryListener {
+    pub async fn new(conf: Arc<Settings>, stores: TenantStores) -> Self {
+        let global_pool = diesel_make_pg_pool(
+            conf.master_database.get_inner(),
+            false,
+            &conf.multitenancy.global_tenant.schema,
+        )
+        .await;
+
+        Self {
+            conf,
+            global_pool,
+            stores,
+        }
+    }
+
+    /// Builds the stores of the active tenants of the registry which are not drained yet, and
+    /// drops the stores of the suspended or deleted ones. The tenants of the static configuration
+    /// are left untouched.
+    #[instrument(skip_all)]
+    pub async fn refresh(&self) -> errors::DrainerResult<()> {
+        let conn = pg_connection(&self.global_pool).await;
+        let tenants = TenantRegistry::list_by_status(&conn, TenantStatus::Active)
+            .await
+            .change_context(errors::DrainerError::UnexpectedError(
+                "Failed to list the active tenants of the registry".to_string(),
+            ))?;
+
+        let active_tenant_ids = tenants
+            .iter()
+            .map(|tenant| tenant.tenant_id.clone())
+            .collect::<HashSet<_>>();
+
+        self.stores.write().await.retain(|tenant_id, _| {
+            let is_retained = self.conf.multitenancy.get_tenant(tenant_id).is_some()
+                || active_tenant_ids.contains(tenant_id);
+            if !is_retained {
+                logger::info!(?tenant_id, "Stopped draining registered tenant");
+            }
+            is_retained
+        });
+
+        for tenant in tenants.iter().map(Tenant::from) {
+            if self.stores.read().await.contains_key(&tenant.tenant_id) {
+                continue;
+            }
+
+            let store = Arc::new(Store::new(&self.conf, false, &tenant).await);
+            self.stores
+                .write()
+                .await
+                .insert(tenant.tenant_id.clone(), store);
+            logger::info!(tenant_id = ?tenant.tenant_id, "Started draining registered tenant");
+        }
+
+        Ok(())
+    }
+
+    /// Refreshes the stores every time the router publishes an update of the tenant registry.
+    pub async fn listen(self) -> errors::DrainerResult<()> {
+        let redis_conn = redis_connection(&self.conf).await;
+        redis_conn.subscriber.manage_subscriptions();
+        redis_conn
+            .subscriber
+            .subscribe::<_>(INVALIDATION_CHANNEL)
+            .await
+            .map_err(|error| {
+                errors::DrainerError::UnexpectedError(format!(
+                    "Failed to subscribe to {INVALIDATION_CHANNEL}: {error}"
+                ))
+            })?;
+
+        let mut rx = redis_conn.subscriber.message_rx();
+        while let Ok(message) = rx.recv().await {
+            let is_tenant_registry_update = RedisValue::new(message.value)
+                .as_bytes()
+                .and_then(|bytes| serde_json::from_slice::<InvalidationMessage>(bytes).ok())
+                .is_some_and(|message| message.is_tenant_registry_update());
+
+            if is_tenant_registry_update {
+                if let Err(error) = self.refresh().await {
+                    logger::error!(tenant_registry_error=?error);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
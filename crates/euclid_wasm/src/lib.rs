@@ -566,5 +566,10 @@ pub fn get_valid_webhook_status(key: &str) -> JsResult {
                 .collect();
             Ok(serde_wasm_bindgen::to_value(&statuses)?)
         }
+        // Customer and payment method events are not derived from a status
+        EventClass::Customers | EventClass::PaymentMethods => {
+            let event_types: Vec<EventType> = event_class.event_types().into_iter().collect();
+            Ok(serde_wasm_bindgen::to_value(&event_types)?)
+        }
     }
 }
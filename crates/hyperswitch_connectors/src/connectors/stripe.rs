@@ -1876,6 +1876,13 @@ impl FileUpload for Stripe {
                     })?
                 }
             }
+            FilePurpose::PayoutBatch => Err(ConnectorError::FileValidationFailed {
+                reason: "file purpose payout_batch is not supported by the connector".to_owned(),
+            })?,
+            FilePurpose::SettlementReport => Err(ConnectorError::FileValidationFailed {
+                reason: "file purpose settlement_report is not supported by the connector"
+                    .to_owned(),
+            })?,
         }
         Ok(())
     }
@@ -1605,6 +1605,13 @@ impl FileUpload for Worldpayvantiv {
                     })?
                 }
             }
+            FilePurpose::PayoutBatch => Err(errors::ConnectorError::FileValidationFailed {
+                reason: "file purpose payout_batch is not supported by the connector".to_owned(),
+            })?,
+            FilePurpose::SettlementReport => Err(errors::ConnectorError::FileValidationFailed {
+                reason: "file purpose settlement_report is not supported by the connector"
+                    .to_owned(),
+            })?,
         }
         Ok(())
     }
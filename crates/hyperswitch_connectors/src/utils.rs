@@ -1189,6 +1189,34 @@ pub enum CardIssuer {
     UnionPay,
 }
 
+impl CardIssuer {
+    fn from_card_network(card_network: enums::CardNetwork, card_number: &str) -> Option<Self> {
+        match card_network {
+            enums::CardNetwork::Visa => Some(Self::Visa),
+            enums::CardNetwork::Mastercard => Some(Self::Master),
+            enums::CardNetwork::AmericanExpress => Some(Self::AmericanExpress),
+            enums::CardNetwork::Discover => Some(Self::Discover),
+            enums::CardNetwork::JCB => Some(Self::JCB),
+            // Carte Blanche cards are Diners Club cards with their own 14 digit range
+            enums::CardNetwork::DinersClub
+                if card_number.starts_with("389") && card_number.len() == 14 =>
+            {
+                Some(Self::CarteBlanche)
+            }
+            enums::CardNetwork::DinersClub => Some(Self::DinersClub),
+            enums::CardNetwork::CartesBancaires => Some(Self::CartesBancaires),
+            enums::CardNetwork::UnionPay => Some(Self::UnionPay),
+            enums::CardNetwork::Maestro => Some(Self::Maestro),
+            enums::CardNetwork::Interac
+            | enums::CardNetwork::RuPay
+            | enums::CardNetwork::Star
+            | enums::CardNetwork::Pulse
+            | enums::CardNetwork::Accel
+            | enums::CardNetwork::Nyce => None,
+        }
+    }
+}
+
 pub trait CardData {
     fn get_card_expiry_year_2_digit(&self) -> Result<Secret<String>, errors::ConnectorError>;
     fn get_card_expiry_month_2_digit(&self) -> Result<Secret<String>, errors::ConnectorError>;
@@ -1638,47 +1666,17 @@ impl CardData for api_models::payouts::ApplePayDecrypt {
     }
 }
 
+/// Identifies the card issuer by the issuer identification number alone, as the card numbers and
+/// network tokens passed to the connectors are not always of a length their network issues.
 #[track_caller]
 fn get_card_issuer(card_number: &str) -> Result<CardIssuer, Error> {
-    for (k, v) in CARD_REGEX.iter() {
-        let regex: Regex = v
-            .clone()
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-        if regex.is_match(card_number) {
-            return Ok(*k);
-        }
-    }
-    Err(error_stack::Report::new(
-        errors::ConnectorError::NotImplemented("Card Type".into()),
-    ))
-}
-
-static CARD_REGEX: LazyLock<HashMap<CardIssuer, Result<Regex, regex::Error>>> = LazyLock::new(
-    || {
-        let mut map = HashMap::new();
-        // Reference: https://gist.github.com/michaelkeevildown/9096cd3aac9029c4e6e05588448a8841
-        // [#379]: Determine card issuer from card BIN number
-        map.insert(CardIssuer::Master, Regex::new(r"^5[1-5][0-9]{14}$"));
-        map.insert(CardIssuer::AmericanExpress, Regex::new(r"^3[47][0-9]{13}$"));
-        map.insert(CardIssuer::Visa, Regex::new(r"^4[0-9]{12}(?:[0-9]{3})?$"));
-        map.insert(CardIssuer::Discover, Regex::new(r"^65[0-9]{14}|64[4-9][0-9]{13}|6011[0-9]{12}|(622(?:12[6-9]|1[3-9][0-9]|[2-8][0-9][0-9]|9[01][0-9]|92[0-5])[0-9]{10})$"));
-        map.insert(
-            CardIssuer::Maestro,
-            Regex::new(r"^(5018|5020|5038|5893|6304|6759|6761|6762|6763)[0-9]{8,15}$"),
-        );
-        map.insert(
-            CardIssuer::DinersClub,
-            Regex::new(r"^3(?:0[0-5][0-9]{11}|[68][0-9][0-9]{11,13})$"),
-        );
-        map.insert(
-            CardIssuer::JCB,
-            Regex::new(r"^(3(?:088|096|112|158|337|5(?:2[89]|[3-8][0-9]))\d{12})$"),
-        );
-        map.insert(CardIssuer::CarteBlanche, Regex::new(r"^389[0-9]{11}$"));
-        map.insert(CardIssuer::UnionPay, Regex::new(r"^(62[0-9]{14,17})$"));
-        map
-    },
-);
+    ::cards::get_card_networks_by_iin(card_number)
+        .into_iter()
+        .find_map(|card_network| CardIssuer::from_card_network(card_network, card_number))
+        .ok_or_else(|| {
+            error_stack::Report::new(errors::ConnectorError::NotImplemented("Card Type".into()))
+        })
+}
 
 pub trait CardWithLimitedData {
     fn get_card_expiry_year_2_digit(
@@ -6536,12 +6534,47 @@ impl QrImage {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils;
+    use crate::utils::{self, get_card_issuer, CardIssuer};
     #[test]
     fn test_image_data_source_url() {
         let qr_image_data_source_url = utils::QrImage::new_from_data("Hyperswitch".to_string());
         assert!(qr_image_data_source_url.is_ok());
     }
+
+    #[test]
+    fn test_get_card_issuer() {
+        for (card_number, card_issuer) in [
+            ("4111111111111111", CardIssuer::Visa),
+            ("5555555555554444", CardIssuer::Master),
+            ("2223003122003222", CardIssuer::Master),
+            ("378282246310005", CardIssuer::AmericanExpress),
+            ("6011111111111117", CardIssuer::Discover),
+            ("3530111333300000", CardIssuer::JCB),
+            ("36227206271667", CardIssuer::DinersClub),
+            ("38900000000000", CardIssuer::CarteBlanche),
+            ("6200000000000005", CardIssuer::UnionPay),
+            ("6759649826438453", CardIssuer::Maestro),
+        ] {
+            assert_eq!(get_card_issuer(card_number).ok(), Some(card_issuer));
+        }
+    }
+
+    #[test]
+    fn test_get_card_issuer_of_card_number_with_unissued_length() {
+        // Network tokens and test cards are not always of a length the network issues
+        for (card_number, card_issuer) in [
+            ("411111111111111111", CardIssuer::Visa),
+            ("5555555555554444000", CardIssuer::Master),
+            ("37828224631000", CardIssuer::AmericanExpress),
+        ] {
+            assert_eq!(get_card_issuer(card_number).ok(), Some(card_issuer));
+        }
+    }
+
+    #[test]
+    fn test_get_card_issuer_of_unknown_iin() {
+        assert!(get_card_issuer("9999999999999995").is_err());
+    }
 }
 
 pub fn is_mandate_supported(
@@ -0,0 +1,126 @@
+use common_utils::{errors::CustomResult, id_type};
+use diesel_models::billing_catalog as storage;
+pub use diesel_models::billing_catalog::{
+    BillingCoupon, BillingCouponNew, BillingCouponUpdate, BillingPlan, BillingPlanNew,
+    BillingPlanUpdate, BillingPrice, BillingPriceNew, BillingPriceUpdate, BillingProduct,
+    BillingProductNew, BillingProductUpdate,
+};
+
+#[async_trait::async_trait]
+pub trait BillingCatalogInterface {
+    type Error;
+    async fn insert_billing_product(
+        &self,
+        product: storage::BillingProductNew,
+    ) -> CustomResult<storage::BillingProduct, Self::Error>;
+
+    async fn find_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+    ) -> CustomResult<storage::BillingProduct, Self::Error>;
+
+    async fn list_billing_products_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingProduct>, Self::Error>;
+
+    async fn update_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+        product_update: storage::BillingProductUpdate,
+    ) -> CustomResult<storage::BillingProduct, Self::Error>;
+
+    async fn insert_billing_plan(
+        &self,
+        plan: storage::BillingPlanNew,
+    ) -> CustomResult<storage::BillingPlan, Self::Error>;
+
+    async fn find_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::BillingPlan, Self::Error>;
+
+    async fn list_billing_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingPlan>, Self::Error>;
+
+    async fn update_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+        plan_update: storage::BillingPlanUpdate,
+    ) -> CustomResult<storage::BillingPlan, Self::Error>;
+
+    async fn insert_billing_price(
+        &self,
+        price: storage::BillingPriceNew,
+    ) -> CustomResult<storage::BillingPrice, Self::Error>;
+
+    async fn find_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::BillingPrice, Self::Error>;
+
+    async fn list_billing_prices_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<Vec<storage::BillingPrice>, Self::Error>;
+
+    async fn update_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+        price_update: storage::BillingPriceUpdate,
+    ) -> CustomResult<storage::BillingPrice, Self::Error>;
+
+    async fn insert_billing_coupon(
+        &self,
+        coupon: storage::BillingCouponNew,
+    ) -> CustomResult<storage::BillingCoupon, Self::Error>;
+
+    async fn find_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+    ) -> CustomResult<storage::BillingCoupon, Self::Error>;
+
+    async fn find_billing_coupon_by_merchant_id_profile_id_code(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        code: &str,
+    ) -> CustomResult<storage::BillingCoupon, Self::Error>;
+
+    async fn list_billing_coupons_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingCoupon>, Self::Error>;
+
+    async fn update_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+        coupon_update: storage::BillingCouponUpdate,
+    ) -> CustomResult<storage::BillingCoupon, Self::Error>;
+
+    /// Counts a redemption of the coupon, fails when the coupon was redeemed concurrently
+    async fn redeem_billing_coupon(
+        &self,
+        coupon: storage::BillingCoupon,
+    ) -> CustomResult<storage::BillingCoupon, Self::Error>;
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeSet;
+
+use common_utils::errors;
+use diesel_models::bin_range;
+
+/// Minimum number of digits in the bounds of a BIN range
+pub const MIN_BIN_RANGE_LENGTH: usize = 6;
+
+/// Maximum number of digits in the bounds of a BIN range
+pub const MAX_BIN_RANGE_LENGTH: usize = 11;
+
+#[async_trait::async_trait]
+pub trait BinRangeInterface {
+    type Error;
+
+    /// Returns the most specific BIN range containing the card number.
+    async fn find_bin_range_by_card_number(
+        &self,
+        card_number: &str,
+    ) -> errors::CustomResult<Option<bin_range::BinRange>, Self::Error>;
+
+    /// Replaces all the BIN ranges loaded from the source and refreshes the in-memory index.
+    async fn replace_bin_ranges_by_source(
+        &self,
+        source: &str,
+        bin_ranges: Vec<bin_range::BinRangeNew>,
+    ) -> errors::CustomResult<usize, Self::Error>;
+}
+
+/// Interval index over the BIN ranges, resolving a card number to the narrowest range containing
+/// it.
+///
+/// The bounds of every range are widened to [`MAX_BIN_RANGE_LENGTH`] digits, and the overlapping
+/// ranges are flattened into disjoint segments when the index is built, so that a lookup is a
+/// single binary search.
+#[derive(Clone, Debug, Default)]
+pub struct BinRangeIndex {
+    bin_ranges: Vec<bin_range::BinRange>,
+    /// Disjoint segments sorted by their start, along with the position of the narrowest range
+    /// covering the segment
+    segments: Vec<(u64, u64, usize)>,
+}
+
+impl BinRangeIndex {
+    pub fn new(bin_ranges: Vec<bin_range::BinRange>) -> Self {
+        let bin_ranges = bin_ranges
+            .into_iter()
+            .filter(|range| {
+                normalize_bounds(&range.range_low, &range.range_high)
+                    .is_some_and(|(low, high)| low <= high)
+            })
+            .collect::<Vec<_>>();
+
+        // Ranges start covering the card numbers at their lower bound, and stop covering them
+        // after their upper bound
+        let mut events = bin_ranges
+            .iter()
+            .enumerate()
+            .filter_map(|(position, range)| {
+                normalize_bounds(&range.range_low, &range.range_high)
+                    .map(|(low, high)| (position, low, high))
+            })
+            .flat_map(|(position, low, high)| {
+                [
+                    (low, true, high - low, position),
+                    (high + 1, false, high - low, position),
+                ]
+            })
+            .collect::<Vec<_>>();
+        events.sort_unstable_by_key(|(point, ..)| *point);
+
+        // Active ranges ordered by their width, and by their position for the ranges of equal
+        // width
+        let mut active_ranges = BTreeSet::new();
+        let mut segments: Vec<(u64, u64, usize)> = Vec::new();
+        let mut events = events.into_iter().peekable();
+        while let Some((point, ..)) = events.peek().copied() {
+            while let Some((_, is_start, width, position)) =
+                events.next_if(|(event_point, ..)| *event_point == point)
+            {
+                let key = (width, position);
+                if is_start {
+                    active_ranges.insert(key);
+                } else {
+                    active_ranges.remove(&key);
+                }
+            }
+
+            let Some(next_point) = events.peek().map(|(next_point, ..)| *next_point) else {
+                break;
+            };
+            if let Some((_, position)) = active_ranges.first().copied() {
+                match segments.last_mut() {
+                    Some((_, end, last_position))
+                        if *last_position == position && *end + 1 == point =>
+                    {
+                        *end = next_point - 1
+                    }
+                    _ => segments.push((point, next_point - 1, position)),
+                }
+            }
+        }
+
+        Self {
+            bin_ranges,
+            segments,
+        }
+    }
+
+    /// Returns the narrowest range containing the card number, which needs at least
+    /// [`MIN_BIN_RANGE_LENGTH`] digits.
+    pub fn find(&self, card_number: &str) -> Option<&bin_range::BinRange> {
+        let prefix = card_number
+            .chars()
+            .take(MAX_BIN_RANGE_LENGTH)
+            .collect::<String>();
+        if prefix.len() < MIN_BIN_RANGE_LENGTH {
+            return None;
+        }
+        let key = pad_bound(&prefix, '0')?;
+
+        let segment_position = self
+            .segments
+            .partition_point(|(start, ..)| *start <= key)
+            .checked_sub(1)?;
+        self.segments
+            .get(segment_position)
+            .filter(|(_, end, _)| key <= *end)
+            .and_then(|(_, _, position)| self.bin_ranges.get(*position))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bin_ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bin_ranges.is_empty()
+    }
+}
+
+/// Validates the bounds of a BIN range, returning them widened to [`MAX_BIN_RANGE_LENGTH`]
+/// digits.
+pub fn normalize_bounds(range_low: &str, range_high: &str) -> Option<(u64, u64)> {
+    let is_valid_bound = |bound: &str| {
+        (MIN_BIN_RANGE_LENGTH..=MAX_BIN_RANGE_LENGTH).contains(&bound.len())
+            && bound.chars().all(|digit| digit.is_ascii_digit())
+    };
+    if !is_valid_bound(range_low)
+        || !is_valid_bound(range_high)
+        || range_low.len() != range_high.len()
+    {
+        return None;
+    }
+
+    Some((pad_bound(range_low, '0')?, pad_bound(range_high, '9')?))
+}
+
+fn pad_bound(bound: &str, padding: char) -> Option<u64> {
+    let padding_length = MAX_BIN_RANGE_LENGTH.saturating_sub(bound.len());
+    format!("{bound}{}", padding.to_string().repeat(padding_length))
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bin_range(bin_range_id: &str, range_low: &str, range_high: &str) -> bin_range::BinRange {
+        bin_range::BinRange {
+            bin_range_id: bin_range_id.to_string(),
+            range_low: range_low.to_string(),
+            range_high: range_high.to_string(),
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            card_subtype: None,
+            card_issuing_country: None,
+            country_code: None,
+            bank_code: None,
+            source: "test".to_string(),
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn resolves_the_narrowest_range() {
+        let index = BinRangeIndex::new(vec![
+            bin_range("wide", "400000", "499999"),
+            bin_range("narrow", "41111111", "41111199"),
+            bin_range("nested", "411100", "411199"),
+        ]);
+
+        let find = |card_number| {
+            index
+                .find(card_number)
+                .map(|range| range.bin_range_id.as_str())
+        };
+        assert_eq!(find("4111111111111111"), Some("narrow"));
+        assert_eq!(find("4111991111111111"), Some("nested"));
+        assert_eq!(find("4200001111111111"), Some("wide"));
+        assert_eq!(find("5100001111111111"), None);
+        assert_eq!(find("41111"), None);
+    }
+
+    #[test]
+    fn skips_invalid_ranges() {
+        let index = BinRangeIndex::new(vec![
+            bin_range("short", "4111", "4111"),
+            bin_range("mismatched", "411111", "4111119"),
+            bin_range("inverted", "499999", "400000"),
+        ]);
+
+        assert!(index.is_empty());
+        assert!(index.find("4111111111111111").is_none());
+    }
+}
@@ -1,5 +1,6 @@
 use common_utils::errors::CustomResult;
 use diesel_models::configs as storage;
+pub use diesel_models::configs::{ConfigNew, ConfigUpdate};
 
 #[async_trait::async_trait]
 pub trait ConfigInterface {
@@ -2,6 +2,8 @@ pub mod address;
 pub mod api;
 pub mod authentication;
 pub mod behaviour;
+pub mod billing_catalog;
+pub mod bin_range;
 pub mod bulk_tokenization;
 pub mod business_profile;
 pub mod callback_mapper;
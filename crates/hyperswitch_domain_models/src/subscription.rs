@@ -5,6 +5,7 @@ use common_utils::{
     pii::SecretSerdeValue,
     types::keymanager::{self, KeyManagerState},
 };
+pub use diesel_models::subscription::SubscriptionBillingDetails;
 use error_stack::ResultExt;
 use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret};
 use time::PrimitiveDateTime;
@@ -73,6 +74,7 @@ pub struct Subscription {
     pub merchant_reference_id: Option<String>,
     pub plan_id: Option<String>,
     pub item_price_id: Option<String>,
+    pub billing_details: Option<SubscriptionBillingDetails>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -113,6 +115,11 @@ impl Subscription {
         self.client_secret = Some(client_secret.clone());
         Secret::new(client_secret)
     }
+
+    /// Subscriptions created without a billing processor are billed by the native billing engine
+    pub fn is_natively_billed(&self) -> bool {
+        self.billing_processor.is_none()
+    }
 }
 
 #[async_trait::async_trait]
@@ -139,6 +146,7 @@ impl super::behaviour::Conversion for Subscription {
             merchant_reference_id: self.merchant_reference_id,
             plan_id: self.plan_id,
             item_price_id: self.item_price_id,
+            billing_details: self.billing_details,
         })
     }
 
@@ -168,6 +176,7 @@ impl super::behaviour::Conversion for Subscription {
             merchant_reference_id: item.merchant_reference_id,
             plan_id: item.plan_id,
             item_price_id: item.item_price_id,
+            billing_details: item.billing_details,
         })
     }
 
@@ -187,6 +196,7 @@ impl super::behaviour::Conversion for Subscription {
             self.merchant_reference_id,
             self.plan_id,
             self.item_price_id,
+            self.billing_details,
         ))
     }
 }
@@ -232,6 +242,7 @@ pub struct SubscriptionUpdate {
     pub modified_at: PrimitiveDateTime,
     pub plan_id: Option<String>,
     pub item_price_id: Option<String>,
+    pub billing_details: Option<SubscriptionBillingDetails>,
 }
 
 impl SubscriptionUpdate {
@@ -249,12 +260,23 @@ impl SubscriptionUpdate {
             modified_at: common_utils::date_time::now(),
             plan_id,
             item_price_id,
+            billing_details: None,
         }
     }
 
     pub fn update_status(status: String) -> Self {
         Self::new(None, None, Some(status), None, None)
     }
+
+    pub fn update_billing_details(
+        status: Option<String>,
+        billing_details: SubscriptionBillingDetails,
+    ) -> Self {
+        Self {
+            billing_details: Some(billing_details),
+            ..Self::new(None, None, status, None, None)
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -270,6 +292,7 @@ impl super::behaviour::Conversion for SubscriptionUpdate {
             modified_at: self.modified_at,
             plan_id: self.plan_id,
             item_price_id: self.item_price_id,
+            billing_details: self.billing_details,
         })
     }
 
@@ -289,6 +312,7 @@ impl super::behaviour::Conversion for SubscriptionUpdate {
             modified_at: item.modified_at,
             plan_id: item.plan_id,
             item_price_id: item.item_price_id,
+            billing_details: item.billing_details,
         })
     }
 
@@ -300,6 +324,7 @@ impl super::behaviour::Conversion for SubscriptionUpdate {
             modified_at: self.modified_at,
             plan_id: self.plan_id,
             item_price_id: self.item_price_id,
+            billing_details: self.billing_details,
         })
     }
 }
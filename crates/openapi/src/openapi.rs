@@ -99,6 +99,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_create,
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_update,
+        routes::refunds::bulk_refunds_create,
+        routes::refunds::bulk_refunds_retrieve,
+        routes::refunds::bulk_refunds_report,
         routes::refunds::refunds_list,
 
         // Routes for Organization
@@ -200,6 +203,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payout_batch_create,
+        routes::payouts::payout_batch_retrieve,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -339,6 +344,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
+        api_models::refunds::BulkRefundRequest,
+        api_models::refunds::BulkRefundItemRequest,
+        api_models::refunds::BulkRefundResponse,
+        api_models::enums::BulkRefundStatus,
         api_models::organization::OrganizationCreateRequest,
         api_models::organization::OrganizationUpdateRequest,
         api_models::organization::OrganizationResponse,
@@ -817,9 +826,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::Bank,
         api_models::payouts::ApplePayDecrypt,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchItemRequest,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchItemResponse,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchItemStatus,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
@@ -190,3 +190,38 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payout Batch - Create
+///
+/// Creates a batch of payouts either from a file uploaded through the files API with purpose `payout_batch`, or from a list of items. The batch has to be approved from the dashboard before the payouts are created.
+#[utoipa::path(
+    post,
+    path = "/payouts/batches",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid payout batch items")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_create() {}
+
+/// Payout Batch - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/batches/{payout_batch_id}",
+    params(
+        ("payout_batch_id" = String, Path, description = "The identifier for the payout batch"),
+        ("expand_items" = Option<bool>, Query, description = "Include the status of the individual items of the batch (defaults to false)")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_retrieve() {}
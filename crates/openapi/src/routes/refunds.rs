@@ -115,6 +115,64 @@ pub async fn refunds_retrieve_with_body() {}
 #[cfg(feature = "v1")]
 pub async fn refunds_update() {}
 
+/// Refunds - Bulk Create
+///
+/// Creates refunds against multiple payments asynchronously. The refunds are executed in the background, and the progress of the job can be tracked with the bulk refund retrieve API.
+#[utoipa::path(
+    post,
+    path = "/refunds/bulk",
+    request_body=BulkRefundRequest,
+    responses(
+        (status = 200, description = "Bulk refund created", body = BulkRefundResponse),
+        (status = 400, description = "Invalid bulk refund items", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Refunds",
+    operation_id = "Create a Bulk Refund",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn bulk_refunds_create() {}
+
+/// Refunds - Bulk Retrieve
+///
+/// Retrieves the progress of a bulk refund job.
+#[utoipa::path(
+    get,
+    path = "/refunds/bulk/{bulk_refund_id}",
+    params(
+        ("bulk_refund_id" = String, Path, description = "The identifier for the bulk refund")
+    ),
+    responses(
+        (status = 200, description = "Bulk refund retrieved", body = BulkRefundResponse),
+        (status = 404, description = "Bulk refund does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve a Bulk Refund",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn bulk_refunds_retrieve() {}
+
+/// Refunds - Bulk Report
+///
+/// Downloads a CSV report containing the outcome of every refund of a bulk refund job.
+#[utoipa::path(
+    get,
+    path = "/refunds/bulk/{bulk_refund_id}/report",
+    params(
+        ("bulk_refund_id" = String, Path, description = "The identifier for the bulk refund")
+    ),
+    responses(
+        (status = 200, description = "Bulk refund report", content_type = "text/csv", body = String),
+        (status = 404, description = "Bulk refund does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Download a Bulk Refund Report",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn bulk_refunds_report() {}
+
 /// Refunds - List
 ///
 /// Lists all the refunds associated with the merchant, or for a specific payment if payment_id is provided
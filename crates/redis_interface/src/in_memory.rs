@@ -0,0 +1,1119 @@
+//! In-memory stand-in for a Redis server
+//!
+//! Plugged into `fred` as a mock, so that the connection pool, the publisher and the subscriber
+//! can be created without a Redis server while the commands issued through them keep working.
+//! Only the commands used by the application are implemented, on strings, hashes, sets, lists and
+//! streams, with key expiry.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use fred::{
+    error::{RedisError, RedisErrorKind},
+    mocks::{MockCommand, Mocks},
+    types::RedisValue,
+};
+
+#[derive(Debug)]
+enum Entry {
+    String(RedisValue),
+    Hash(HashMap<String, RedisValue>),
+    Set(HashSet<String>),
+    List(VecDeque<RedisValue>),
+    Stream(Stream),
+}
+
+impl Entry {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::String(_) => "string",
+            Self::Hash(_) => "hash",
+            Self::Set(_) => "set",
+            Self::List(_) => "list",
+            Self::Stream(_) => "stream",
+        }
+    }
+}
+
+/// Entries of a stream along with the consumer groups reading it
+#[derive(Debug, Default)]
+struct Stream {
+    entries: Vec<(String, Vec<RedisValue>)>,
+    groups: HashMap<String, ConsumerGroup>,
+}
+
+/// Position of a consumer group in the stream and the entries delivered to it but not yet
+/// acknowledged
+#[derive(Debug, Default)]
+struct ConsumerGroup {
+    last_delivered_id: StreamId,
+    pending: HashSet<String>,
+}
+
+type StreamId = (u128, u64);
+
+#[derive(Debug)]
+struct Record {
+    entry: Entry,
+    expires_at: Option<Instant>,
+}
+
+impl Record {
+    fn new(entry: Entry) -> Self {
+        Self {
+            entry,
+            expires_at: None,
+        }
+    }
+
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Keyspace {
+    records: HashMap<String, Record>,
+    last_stream_id: (u128, u64),
+}
+
+impl Keyspace {
+    fn purge_expired(&mut self) {
+        let now = Instant::now();
+        self.records.retain(|_, record| !record.is_expired(now));
+    }
+
+    fn get(&self, key: &str) -> Option<&Record> {
+        self.records.get(key)
+    }
+
+    fn get_mut(&mut self, key: &str) -> Option<&mut Record> {
+        self.records.get_mut(key)
+    }
+
+    fn next_stream_id(&mut self) -> String {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        self.last_stream_id = if millis > self.last_stream_id.0 {
+            (millis, 0)
+        } else {
+            (
+                self.last_stream_id.0,
+                self.last_stream_id.1.saturating_add(1),
+            )
+        };
+        format!("{}-{}", self.last_stream_id.0, self.last_stream_id.1)
+    }
+}
+
+/// Keyspace shared by every client of the process, the way a single Redis server would be
+#[derive(Debug, Default)]
+pub struct InMemoryRedis {
+    keyspace: Mutex<Keyspace>,
+}
+
+impl InMemoryRedis {
+    /// Returns the keyspace shared by all the connection pools created in this process
+    pub fn shared() -> Arc<Self> {
+        static SHARED: OnceLock<Arc<InMemoryRedis>> = OnceLock::new();
+        Arc::clone(SHARED.get_or_init(|| Arc::new(Self::default())))
+    }
+
+    fn execute(&self, command: &str, args: Vec<RedisValue>) -> Result<RedisValue, RedisError> {
+        let mut keyspace = self
+            .keyspace
+            .lock()
+            .map_err(|_| RedisError::new(RedisErrorKind::Unknown, "In-memory Redis poisoned"))?;
+        keyspace.purge_expired();
+
+        match command {
+            "PING" => Ok(RedisValue::from("PONG")),
+            "PUBLISH" => Ok(RedisValue::Integer(0)),
+            "GET" => Ok(match keyspace.get(&key_at(&args, 0)?) {
+                Some(Record {
+                    entry: Entry::String(value),
+                    ..
+                }) => value.clone(),
+                Some(record) => Err(wrong_type(&record.entry))?,
+                None => RedisValue::Null,
+            }),
+            "SET" => set(&mut keyspace, &args),
+            "MGET" => Ok(RedisValue::Array(
+                args.iter()
+                    .map(|key| {
+                        value_as_string(key)
+                            .and_then(|key| match keyspace.get(&key) {
+                                Some(Record {
+                                    entry: Entry::String(value),
+                                    ..
+                                }) => Some(value.clone()),
+                                _ => None,
+                            })
+                            .unwrap_or(RedisValue::Null)
+                    })
+                    .collect(),
+            )),
+            "MSETNX" => {
+                let pairs = pairs(&args)?;
+                if pairs
+                    .iter()
+                    .any(|(key, _)| keyspace.records.contains_key(key))
+                {
+                    return Ok(RedisValue::Integer(0));
+                }
+                for (key, value) in pairs {
+                    keyspace
+                        .records
+                        .insert(key, Record::new(Entry::String(value)));
+                }
+                Ok(RedisValue::Integer(1))
+            }
+            "DEL" => Ok(count(args.iter().filter(|key| {
+                value_as_string(key).is_some_and(|key| keyspace.records.remove(&key).is_some())
+            }))),
+            "EXISTS" => Ok(count(args.iter().filter(|key| {
+                value_as_string(key).is_some_and(|key| keyspace.records.contains_key(&key))
+            }))),
+            "EXPIRE" | "EXPIREAT" => {
+                let key = key_at(&args, 0)?;
+                let value = integer_at(&args, 1)?;
+                let expires_at = if command == "EXPIRE" {
+                    instant_after(Duration::from_secs(u64::try_from(value).unwrap_or(0)))
+                } else {
+                    instant_at(Duration::from_secs(u64::try_from(value).unwrap_or(0)))
+                };
+                Ok(match keyspace.get_mut(&key) {
+                    Some(record) => {
+                        record.expires_at = Some(expires_at);
+                        RedisValue::Integer(1)
+                    }
+                    None => RedisValue::Integer(0),
+                })
+            }
+            "TTL" => Ok(RedisValue::Integer(
+                match keyspace.get(&key_at(&args, 0)?) {
+                    Some(Record {
+                        expires_at: Some(expires_at),
+                        ..
+                    }) => i64::try_from(
+                        expires_at
+                            .saturating_duration_since(Instant::now())
+                            .as_secs(),
+                    )
+                    .unwrap_or(i64::MAX),
+                    Some(_) => -1,
+                    None => -2,
+                },
+            )),
+            "SCAN" => {
+                let options = ScanOptions::parse(args.get(1..).unwrap_or_default());
+                let keys = keyspace
+                    .records
+                    .iter()
+                    .filter(|(key, record)| {
+                        options.matches(key)
+                            && options.key_type.as_deref().is_none_or(|key_type| {
+                                key_type.eq_ignore_ascii_case(record.entry.type_name())
+                            })
+                    })
+                    .map(|(key, _)| RedisValue::from(key.as_str()))
+                    .collect();
+                Ok(scan_reply(keys))
+            }
+            "HSET" | "HSETNX" => {
+                let key = key_at(&args, 0)?;
+                let pairs = pairs(args.get(1..).unwrap_or_default())?;
+                let hash = hash_entry(&mut keyspace, key)?;
+                let mut added = 0;
+                for (field, value) in pairs {
+                    if command == "HSETNX" && hash.contains_key(&field) {
+                        continue;
+                    }
+                    if hash.insert(field, value).is_none() {
+                        added += 1;
+                    }
+                }
+                Ok(RedisValue::Integer(added))
+            }
+            "HGET" => {
+                let field = key_at(&args, 1)?;
+                Ok(match keyspace.get(&key_at(&args, 0)?) {
+                    Some(Record {
+                        entry: Entry::Hash(hash),
+                        ..
+                    }) => hash.get(&field).cloned().unwrap_or(RedisValue::Null),
+                    Some(record) => Err(wrong_type(&record.entry))?,
+                    None => RedisValue::Null,
+                })
+            }
+            "HGETALL" => Ok(RedisValue::Array(match keyspace.get(&key_at(&args, 0)?) {
+                Some(Record {
+                    entry: Entry::Hash(hash),
+                    ..
+                }) => hash
+                    .iter()
+                    .flat_map(|(field, value)| [RedisValue::from(field.as_str()), value.clone()])
+                    .collect(),
+                Some(record) => Err(wrong_type(&record.entry))?,
+                None => Vec::new(),
+            })),
+            "HDEL" => {
+                let key = key_at(&args, 0)?;
+                Ok(match keyspace.get_mut(&key) {
+                    Some(Record {
+                        entry: Entry::Hash(hash),
+                        ..
+                    }) => count(args.iter().skip(1).filter(|field| {
+                        value_as_string(field).is_some_and(|field| hash.remove(&field).is_some())
+                    })),
+                    Some(record) => Err(wrong_type(&record.entry))?,
+                    None => RedisValue::Integer(0),
+                })
+            }
+            "HINCRBY" => {
+                let key = key_at(&args, 0)?;
+                let field = key_at(&args, 1)?;
+                let increment = integer_at(&args, 2)?;
+                let hash = hash_entry(&mut keyspace, key)?;
+                let current = hash
+                    .get(&field)
+                    .map(|value| {
+                        value.as_i64().ok_or_else(|| {
+                            RedisError::new(
+                                RedisErrorKind::InvalidArgument,
+                                "hash value is not an integer",
+                            )
+                        })
+                    })
+                    .transpose()?
+                    .unwrap_or(0);
+                let updated = current.checked_add(increment).ok_or_else(|| {
+                    RedisError::new(
+                        RedisErrorKind::InvalidArgument,
+                        "increment or decrement would overflow",
+                    )
+                })?;
+                hash.insert(field, RedisValue::from(updated.to_string()));
+                Ok(RedisValue::Integer(updated))
+            }
+            "HSCAN" => {
+                let options = ScanOptions::parse(args.get(2..).unwrap_or_default());
+                let fields = match keyspace.get(&key_at(&args, 0)?) {
+                    Some(Record {
+                        entry: Entry::Hash(hash),
+                        ..
+                    }) => hash
+                        .iter()
+                        .filter(|(field, _)| options.matches(field))
+                        .flat_map(|(field, value)| {
+                            [RedisValue::from(field.as_str()), value.clone()]
+                        })
+                        .collect(),
+                    Some(record) => Err(wrong_type(&record.entry))?,
+                    None => Vec::new(),
+                };
+                Ok(scan_reply(fields))
+            }
+            "SADD" => {
+                let key = key_at(&args, 0)?;
+                let record = keyspace
+                    .records
+                    .entry(key)
+                    .or_insert_with(|| Record::new(Entry::Set(HashSet::new())));
+                match &mut record.entry {
+                    Entry::Set(set) => Ok(count(args.iter().skip(1).filter(|member| {
+                        value_as_string(member).is_some_and(|member| set.insert(member))
+                    }))),
+                    entry => Err(wrong_type(entry)),
+                }
+            }
+            "SMEMBERS" => Ok(RedisValue::Array(match keyspace.get(&key_at(&args, 0)?) {
+                Some(Record {
+                    entry: Entry::Set(set),
+                    ..
+                }) => set
+                    .iter()
+                    .map(|member| RedisValue::from(member.as_str()))
+                    .collect(),
+                Some(record) => Err(wrong_type(&record.entry))?,
+                None => Vec::new(),
+            })),
+            "RPUSH" => {
+                let key = key_at(&args, 0)?;
+                let record = keyspace
+                    .records
+                    .entry(key)
+                    .or_insert_with(|| Record::new(Entry::List(VecDeque::new())));
+                match &mut record.entry {
+                    Entry::List(list) => {
+                        list.extend(args.into_iter().skip(1));
+                        Ok(length(list.len()))
+                    }
+                    entry => Err(wrong_type(entry)),
+                }
+            }
+            "LPOP" => {
+                let key = key_at(&args, 0)?;
+                let requested = args
+                    .get(1)
+                    .and_then(|value| value.as_u64())
+                    .map(|value| usize::try_from(value).unwrap_or(usize::MAX));
+                let popped = match keyspace.get_mut(&key) {
+                    Some(Record {
+                        entry: Entry::List(list),
+                        ..
+                    }) => {
+                        let popped: Vec<_> = (0..requested.unwrap_or(1))
+                            .map_while(|_| list.pop_front())
+                            .collect();
+                        if list.is_empty() {
+                            keyspace.records.remove(&key);
+                        }
+                        popped
+                    }
+                    Some(record) => Err(wrong_type(&record.entry))?,
+                    None => return Ok(RedisValue::Null),
+                };
+                Ok(match requested {
+                    Some(_) => RedisValue::Array(popped),
+                    None => popped.into_iter().next().unwrap_or(RedisValue::Null),
+                })
+            }
+            "LRANGE" => {
+                let start = integer_at(&args, 1)?;
+                let stop = integer_at(&args, 2)?;
+                Ok(RedisValue::Array(match keyspace.get(&key_at(&args, 0)?) {
+                    Some(Record {
+                        entry: Entry::List(list),
+                        ..
+                    }) => {
+                        let (start, stop) = list_range(list.len(), start, stop);
+                        list.iter()
+                            .skip(start)
+                            .take(stop.saturating_sub(start))
+                            .cloned()
+                            .collect()
+                    }
+                    Some(record) => Err(wrong_type(&record.entry))?,
+                    None => Vec::new(),
+                }))
+            }
+            "LLEN" => Ok(match keyspace.get(&key_at(&args, 0)?) {
+                Some(Record {
+                    entry: Entry::List(list),
+                    ..
+                }) => length(list.len()),
+                Some(record) => Err(wrong_type(&record.entry))?,
+                None => RedisValue::Integer(0),
+            }),
+            "XADD" => xadd(&mut keyspace, args),
+            "XLEN" => Ok(match keyspace.get(&key_at(&args, 0)?) {
+                Some(Record {
+                    entry: Entry::Stream(stream),
+                    ..
+                }) => length(stream.entries.len()),
+                Some(record) => Err(wrong_type(&record.entry))?,
+                None => RedisValue::Integer(0),
+            }),
+            "XDEL" => {
+                let key = key_at(&args, 0)?;
+                let ids: HashSet<String> =
+                    args.iter().skip(1).filter_map(value_as_string).collect();
+                Ok(match keyspace.get_mut(&key) {
+                    Some(Record {
+                        entry: Entry::Stream(stream),
+                        ..
+                    }) => {
+                        let before = stream.entries.len();
+                        stream.entries.retain(|(id, _)| !ids.contains(id));
+                        length(before.saturating_sub(stream.entries.len()))
+                    }
+                    Some(record) => Err(wrong_type(&record.entry))?,
+                    None => RedisValue::Integer(0),
+                })
+            }
+            "XGROUP" => xgroup(&mut keyspace, &args),
+            "XREADGROUP" => xreadgroup(&mut keyspace, &args),
+            "XACK" => {
+                let key = key_at(&args, 0)?;
+                let group = key_at(&args, 1)?;
+                Ok(match keyspace.get_mut(&key) {
+                    Some(Record {
+                        entry: Entry::Stream(stream),
+                        ..
+                    }) => match stream.groups.get_mut(&group) {
+                        Some(group) => count(
+                            args.iter()
+                                .skip(2)
+                                .filter_map(value_as_string)
+                                .filter(|id| group.pending.remove(id)),
+                        ),
+                        None => RedisValue::Integer(0),
+                    },
+                    Some(record) => Err(wrong_type(&record.entry))?,
+                    None => RedisValue::Integer(0),
+                })
+            }
+            command => Err(RedisError::new(
+                RedisErrorKind::InvalidCommand,
+                format!("`{command}` is not supported by the in-memory Redis"),
+            )),
+        }
+    }
+}
+
+impl Mocks for InMemoryRedis {
+    fn process_command(&self, command: MockCommand) -> Result<RedisValue, RedisError> {
+        let mut args = command.args;
+        if let Some(subcommand) = command.subcommand {
+            args.insert(0, RedisValue::from(subcommand.to_string()));
+        }
+        self.execute(&command.cmd.to_ascii_uppercase(), args)
+    }
+}
+
+/// `SET key value [NX | XX] [GET] [EX seconds | PX milliseconds | EXAT | PXAT | KEEPTTL]`
+fn set(keyspace: &mut Keyspace, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+    let key = key_at(args, 0)?;
+    let value = args
+        .get(1)
+        .cloned()
+        .ok_or_else(|| missing_argument("value"))?;
+
+    let (mut only_if_absent, mut only_if_present, mut get, mut keep_ttl) =
+        (false, false, false, false);
+    let mut expires_at = None;
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        let option = value_as_string(option)
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        let mut amount = || {
+            options
+                .next()
+                .and_then(|value| value.as_u64())
+                .ok_or_else(|| missing_argument("expiry"))
+        };
+        match option.as_str() {
+            "NX" => only_if_absent = true,
+            "XX" => only_if_present = true,
+            "GET" => get = true,
+            "KEEPTTL" => keep_ttl = true,
+            "EX" => expires_at = Some(instant_after(Duration::from_secs(amount()?))),
+            "PX" => expires_at = Some(instant_after(Duration::from_millis(amount()?))),
+            "EXAT" => expires_at = Some(instant_at(Duration::from_secs(amount()?))),
+            "PXAT" => expires_at = Some(instant_at(Duration::from_millis(amount()?))),
+            _ => Err(RedisError::new(
+                RedisErrorKind::InvalidArgument,
+                format!("Unsupported SET option `{option}`"),
+            ))?,
+        }
+    }
+
+    let existing = keyspace.get(&key);
+    let previous = match existing {
+        Some(Record {
+            entry: Entry::String(value),
+            ..
+        }) => value.clone(),
+        Some(record) if get => Err(wrong_type(&record.entry))?,
+        _ => RedisValue::Null,
+    };
+    let previous_expiry = existing.and_then(|record| record.expires_at);
+
+    if (only_if_absent && existing.is_some()) || (only_if_present && existing.is_none()) {
+        return Ok(if get { previous } else { RedisValue::Null });
+    }
+
+    keyspace.records.insert(
+        key,
+        Record {
+            entry: Entry::String(value),
+            expires_at: if keep_ttl {
+                previous_expiry
+            } else {
+                expires_at
+            },
+        },
+    );
+    Ok(if get {
+        previous
+    } else {
+        RedisValue::from("OK")
+    })
+}
+
+/// `XADD key [NOMKSTREAM] [MAXLEN | MINID [= | ~] threshold [LIMIT count]] * | id field value ...`
+fn xadd(keyspace: &mut Keyspace, args: Vec<RedisValue>) -> Result<RedisValue, RedisError> {
+    let key = key_at(&args, 0)?;
+    let mut args = args.into_iter().skip(1).peekable();
+
+    let mut no_mkstream = false;
+    let mut max_len = None;
+    loop {
+        let option = args
+            .peek()
+            .and_then(value_as_string)
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        match option.as_str() {
+            "NOMKSTREAM" => no_mkstream = true,
+            "MAXLEN" | "MINID" => {
+                args.next();
+                if args
+                    .peek()
+                    .and_then(value_as_string)
+                    .is_some_and(|value| value == "=" || value == "~")
+                {
+                    args.next();
+                }
+                let threshold = args.next();
+                if option == "MAXLEN" {
+                    max_len = threshold
+                        .and_then(|value| value.as_u64())
+                        .map(|value| usize::try_from(value).unwrap_or(usize::MAX));
+                }
+                if args
+                    .peek()
+                    .and_then(value_as_string)
+                    .is_some_and(|value| value.eq_ignore_ascii_case("LIMIT"))
+                {
+                    args.next();
+                    args.next();
+                }
+                continue;
+            }
+            _ => break,
+        }
+        args.next();
+    }
+
+    let id = args
+        .next()
+        .and_then(|value| value_as_string(&value))
+        .ok_or_else(|| missing_argument("id"))?;
+    let id = if id == "*" {
+        keyspace.next_stream_id()
+    } else {
+        id
+    };
+    let fields: Vec<_> = args.collect();
+
+    if no_mkstream && keyspace.get(&key).is_none() {
+        return Ok(RedisValue::Null);
+    }
+    let record = keyspace
+        .records
+        .entry(key)
+        .or_insert_with(|| Record::new(Entry::Stream(Stream::default())));
+    match &mut record.entry {
+        Entry::Stream(stream) => {
+            stream.entries.push((id.clone(), fields));
+            if let Some(max_len) = max_len {
+                let excess = stream.entries.len().saturating_sub(max_len);
+                stream.entries.drain(..excess);
+            }
+            Ok(RedisValue::from(id))
+        }
+        entry => Err(wrong_type(entry)),
+    }
+}
+
+/// `XGROUP CREATE key group id | $ [MKSTREAM]` and `XGROUP DESTROY key group`
+fn xgroup(keyspace: &mut Keyspace, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+    let subcommand = key_at(args, 0)?.to_ascii_uppercase();
+    let key = key_at(args, 1)?;
+    let group = key_at(args, 2)?;
+
+    match subcommand.as_str() {
+        "CREATE" => {
+            let id = key_at(args, 3)?;
+            let make_stream = args
+                .get(4)
+                .and_then(value_as_string)
+                .is_some_and(|option| option.eq_ignore_ascii_case("MKSTREAM"));
+
+            if !make_stream && keyspace.get(&key).is_none() {
+                return Err(RedisError::new(
+                    RedisErrorKind::NotFound,
+                    "The XGROUP subcommand requires the key to exist",
+                ));
+            }
+            let record = keyspace
+                .records
+                .entry(key)
+                .or_insert_with(|| Record::new(Entry::Stream(Stream::default())));
+            let Entry::Stream(stream) = &mut record.entry else {
+                return Err(wrong_type(&record.entry));
+            };
+            if stream.groups.contains_key(&group) {
+                return Err(RedisError::new(
+                    RedisErrorKind::InvalidArgument,
+                    "BUSYGROUP Consumer Group name already exists",
+                ));
+            }
+
+            let last_delivered_id = if id == "$" {
+                stream
+                    .entries
+                    .last()
+                    .and_then(|(id, _)| parse_stream_id(id))
+                    .unwrap_or_default()
+            } else {
+                parse_stream_id(&id).ok_or_else(|| missing_argument("id"))?
+            };
+            stream.groups.insert(
+                group,
+                ConsumerGroup {
+                    last_delivered_id,
+                    pending: HashSet::new(),
+                },
+            );
+            Ok(RedisValue::from("OK"))
+        }
+        "DESTROY" => Ok(match keyspace.get_mut(&key) {
+            Some(Record {
+                entry: Entry::Stream(stream),
+                ..
+            }) => RedisValue::Integer(stream.groups.remove(&group).map_or(0, |_| 1)),
+            Some(record) => Err(wrong_type(&record.entry))?,
+            None => RedisValue::Integer(0),
+        }),
+        subcommand => Err(RedisError::new(
+            RedisErrorKind::InvalidCommand,
+            format!("`XGROUP {subcommand}` is not supported by the in-memory Redis"),
+        )),
+    }
+}
+
+/// `XREADGROUP GROUP group consumer [COUNT count] [BLOCK milliseconds] [NOACK] STREAMS key ... id
+/// ...`, the block timeout is ignored since no other client can append while the command runs.
+/// Reading from `>` delivers the entries the group has not seen yet, any other id returns the
+/// entries pending for the group after that id.
+fn xreadgroup(keyspace: &mut Keyspace, args: &[RedisValue]) -> Result<RedisValue, RedisError> {
+    let group = key_at(args, 1)?;
+
+    let mut max_count = usize::MAX;
+    let mut no_ack = false;
+    let mut options = args.iter().skip(3).enumerate();
+    let streams_at = loop {
+        let (index, option) = options.next().ok_or_else(|| missing_argument("STREAMS"))?;
+        match value_as_string(option)
+            .unwrap_or_default()
+            .to_ascii_uppercase()
+            .as_str()
+        {
+            "COUNT" => {
+                max_count = options
+                    .next()
+                    .and_then(|(_, value)| value.as_u64())
+                    .map(|value| usize::try_from(value).unwrap_or(usize::MAX))
+                    .ok_or_else(|| missing_argument("count"))?;
+            }
+            "BLOCK" => {
+                options.next();
+            }
+            "NOACK" => no_ack = true,
+            "STREAMS" => break index.saturating_add(4),
+            option => Err(RedisError::new(
+                RedisErrorKind::InvalidArgument,
+                format!("Unsupported XREADGROUP option `{option}`"),
+            ))?,
+        }
+    };
+
+    let streams = args.get(streams_at..).unwrap_or_default();
+    let (keys, ids) = streams.split_at(streams.len() / 2);
+    let mut reply = Vec::new();
+    for (key, id) in keys.iter().zip(ids) {
+        let key = value_as_string(key).ok_or_else(|| missing_argument("key"))?;
+        let id = value_as_string(id).ok_or_else(|| missing_argument("id"))?;
+
+        let no_group = || {
+            RedisError::new(
+                RedisErrorKind::NotFound,
+                format!("NOGROUP No such key `{key}` or consumer group `{group}`"),
+            )
+        };
+        let stream = match keyspace.get_mut(&key) {
+            Some(Record {
+                entry: Entry::Stream(stream),
+                ..
+            }) => stream,
+            Some(record) => Err(wrong_type(&record.entry))?,
+            None => Err(no_group())?,
+        };
+        let consumer_group = stream.groups.get_mut(&group).ok_or_else(no_group)?;
+
+        let entries: Vec<_> = if id == ">" {
+            let entries: Vec<_> = stream
+                .entries
+                .iter()
+                .filter(|(id, _)| {
+                    parse_stream_id(id).is_some_and(|id| id > consumer_group.last_delivered_id)
+                })
+                .take(max_count)
+                .collect();
+            if let Some(last_delivered_id) = entries.last().and_then(|(id, _)| parse_stream_id(id))
+            {
+                consumer_group.last_delivered_id = last_delivered_id;
+            }
+            if !no_ack {
+                consumer_group
+                    .pending
+                    .extend(entries.iter().map(|(id, _)| id.clone()));
+            }
+            entries
+        } else {
+            let after = parse_stream_id(&id).ok_or_else(|| missing_argument("id"))?;
+            stream
+                .entries
+                .iter()
+                .filter(|(id, _)| {
+                    consumer_group.pending.contains(id)
+                        && parse_stream_id(id).is_some_and(|id| id > after)
+                })
+                .take(max_count)
+                .collect()
+        };
+
+        if !entries.is_empty() {
+            reply.push(RedisValue::Array(vec![
+                RedisValue::from(key),
+                RedisValue::Array(
+                    entries
+                        .into_iter()
+                        .map(|(id, fields)| {
+                            RedisValue::Array(vec![
+                                RedisValue::from(id.as_str()),
+                                RedisValue::Array(fields.clone()),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ]));
+        }
+    }
+
+    Ok(if reply.is_empty() {
+        RedisValue::Null
+    } else {
+        RedisValue::Array(reply)
+    })
+}
+
+/// Parses a complete (`<milliseconds>-<sequence>`) or partial (`<milliseconds>`) stream entry id
+fn parse_stream_id(id: &str) -> Option<StreamId> {
+    match id.split_once('-') {
+        Some((millis, sequence)) => Some((millis.parse().ok()?, sequence.parse().ok()?)),
+        None => Some((id.parse().ok()?, 0)),
+    }
+}
+
+/// `MATCH pattern`, `COUNT count` and `TYPE type` options of the `SCAN` family, the count is
+/// ignored since the whole keyspace is returned in a single iteration
+struct ScanOptions {
+    pattern: Option<String>,
+    key_type: Option<String>,
+}
+
+impl ScanOptions {
+    fn parse(args: &[RedisValue]) -> Self {
+        let mut options = Self {
+            pattern: None,
+            key_type: None,
+        };
+        let mut args = args.iter();
+        while let Some(option) = args.next().and_then(value_as_string) {
+            let value = args.next().and_then(value_as_string);
+            match option.to_ascii_uppercase().as_str() {
+                "MATCH" => options.pattern = value,
+                "TYPE" => options.key_type = value,
+                _ => {}
+            }
+        }
+        options
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        self.pattern
+            .as_deref()
+            .is_none_or(|pattern| glob_match(pattern.as_bytes(), value.as_bytes()))
+    }
+}
+
+/// Matches the `*`, `?` and `\` wildcards supported by Redis patterns
+fn glob_match(pattern: &[u8], value: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => value.is_empty(),
+        Some((b'*', rest)) => (0..=value.len()).any(|skip| {
+            value
+                .get(skip..)
+                .is_some_and(|value| glob_match(rest, value))
+        }),
+        Some((b'?', rest)) => value
+            .split_first()
+            .is_some_and(|(_, value)| glob_match(rest, value)),
+        Some((b'\\', rest)) if !rest.is_empty() => {
+            match (rest.split_first(), value.split_first()) {
+                (Some((expected, rest)), Some((actual, value))) => {
+                    expected == actual && glob_match(rest, value)
+                }
+                _ => false,
+            }
+        }
+        Some((expected, rest)) => value
+            .split_first()
+            .is_some_and(|(actual, value)| expected == actual && glob_match(rest, value)),
+    }
+}
+
+fn hash_entry(
+    keyspace: &mut Keyspace,
+    key: String,
+) -> Result<&mut HashMap<String, RedisValue>, RedisError> {
+    let record = keyspace
+        .records
+        .entry(key)
+        .or_insert_with(|| Record::new(Entry::Hash(HashMap::new())));
+    match &mut record.entry {
+        Entry::Hash(hash) => Ok(hash),
+        entry => Err(wrong_type(entry)),
+    }
+}
+
+/// Converts the inclusive, possibly negative, `LRANGE` bounds to an exclusive range
+fn list_range(len: usize, start: i64, stop: i64) -> (usize, usize) {
+    let len = i64::try_from(len).unwrap_or(i64::MAX);
+    let resolve = |index: i64| {
+        if index < 0 {
+            len.saturating_add(index)
+        } else {
+            index
+        }
+    };
+    let start = resolve(start).clamp(0, len);
+    let stop = resolve(stop).saturating_add(1).clamp(0, len);
+    (
+        usize::try_from(start).unwrap_or(0),
+        usize::try_from(stop).unwrap_or(0),
+    )
+}
+
+fn scan_reply(results: Vec<RedisValue>) -> RedisValue {
+    RedisValue::Array(vec![RedisValue::from("0"), RedisValue::Array(results)])
+}
+
+fn pairs(args: &[RedisValue]) -> Result<Vec<(String, RedisValue)>, RedisError> {
+    args.chunks(2)
+        .map(|pair| match pair {
+            [key, value] => value_as_string(key)
+                .map(|key| (key, value.clone()))
+                .ok_or_else(|| missing_argument("key")),
+            _ => Err(missing_argument("value")),
+        })
+        .collect()
+}
+
+fn value_as_string(value: &RedisValue) -> Option<String> {
+    value.as_string()
+}
+
+fn key_at(args: &[RedisValue], index: usize) -> Result<String, RedisError> {
+    args.get(index)
+        .and_then(value_as_string)
+        .ok_or_else(|| missing_argument("key"))
+}
+
+fn integer_at(args: &[RedisValue], index: usize) -> Result<i64, RedisError> {
+    args.get(index)
+        .and_then(|value| value.as_i64())
+        .ok_or_else(|| missing_argument("integer"))
+}
+
+fn count<I: Iterator>(iter: I) -> RedisValue {
+    length(iter.count())
+}
+
+fn length(len: usize) -> RedisValue {
+    RedisValue::Integer(i64::try_from(len).unwrap_or(i64::MAX))
+}
+
+fn instant_after(duration: Duration) -> Instant {
+    let now = Instant::now();
+    now.checked_add(duration).unwrap_or(now)
+}
+
+/// Converts a UNIX timestamp into an instant of the monotonic clock used for the expiry
+fn instant_at(timestamp: Duration) -> Instant {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    match timestamp.checked_sub(since_epoch) {
+        Some(remaining) => instant_after(remaining),
+        None => Instant::now(),
+    }
+}
+
+fn missing_argument(argument: &str) -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        format!("Missing or invalid {argument} argument"),
+    )
+}
+
+fn wrong_type(entry: &Entry) -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        format!(
+            "WRONGTYPE Operation against a key holding the wrong kind of value ({})",
+            entry.type_name()
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn run(redis: &InMemoryRedis, command: &str, args: &[&str]) -> RedisValue {
+        redis
+            .execute(
+                command,
+                args.iter().map(|arg| RedisValue::from(*arg)).collect(),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_set_if_not_exists() {
+        let redis = InMemoryRedis::default();
+
+        assert_eq!(
+            run(&redis, "SET", &["key", "one", "EX", "60", "NX"]),
+            "OK".into()
+        );
+        assert_eq!(run(&redis, "SET", &["key", "two", "NX"]), RedisValue::Null);
+        assert_eq!(run(&redis, "GET", &["key"]), "one".into());
+        assert!(run(&redis, "TTL", &["key"]).as_i64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_hash_scan_with_pattern() {
+        let redis = InMemoryRedis::default();
+
+        run(
+            &redis,
+            "HSET",
+            &["hash", "pa_1", "a", "pa_2", "b", "ref_1", "c"],
+        );
+        assert_eq!(
+            run(&redis, "HINCRBY", &["hash", "count", "2"]),
+            RedisValue::Integer(2)
+        );
+
+        let reply = run(&redis, "HSCAN", &["hash", "0", "MATCH", "pa_*"]);
+        let fields = reply
+            .into_array()
+            .into_iter()
+            .nth(1)
+            .map(RedisValue::into_array)
+            .unwrap();
+        assert_eq!(fields.len(), 4);
+    }
+
+    #[test]
+    fn test_consumer_group_delivers_each_entry_once() {
+        let redis = InMemoryRedis::default();
+
+        run(
+            &redis,
+            "XGROUP",
+            &["CREATE", "stream", "group", "0", "MKSTREAM"],
+        );
+        run(&redis, "XADD", &["stream", "1-0", "batch", "one"]);
+        run(&redis, "XADD", &["stream", "2-0", "batch", "two"]);
+
+        let read = |redis: &InMemoryRedis| {
+            run(
+                redis,
+                "XREADGROUP",
+                &[
+                    "GROUP", "group", "consumer", "COUNT", "1", "STREAMS", "stream", ">",
+                ],
+            )
+        };
+        let first = read(&redis);
+        assert_eq!(
+            first,
+            RedisValue::Array(vec![RedisValue::Array(vec![
+                "stream".into(),
+                RedisValue::Array(vec![RedisValue::Array(vec![
+                    "1-0".into(),
+                    RedisValue::Array(vec!["batch".into(), "one".into()]),
+                ])]),
+            ])])
+        );
+        assert_ne!(read(&redis), first);
+        assert_eq!(read(&redis), RedisValue::Null);
+
+        assert_eq!(
+            run(&redis, "XACK", &["stream", "group", "1-0", "3-0"]),
+            RedisValue::Integer(1)
+        );
+        let pending = run(
+            &redis,
+            "XREADGROUP",
+            &["GROUP", "group", "consumer", "STREAMS", "stream", "0"],
+        );
+        assert_eq!(pending.into_array().len(), 1);
+    }
+
+    #[test]
+    fn test_consumer_group_create() {
+        let redis = InMemoryRedis::default();
+
+        assert!(redis
+            .execute(
+                "XGROUP",
+                ["CREATE", "stream", "group", "$"]
+                    .map(RedisValue::from)
+                    .to_vec(),
+            )
+            .is_err());
+
+        run(&redis, "XADD", &["stream", "1-0", "batch", "one"]);
+        run(&redis, "XGROUP", &["CREATE", "stream", "group", "$"]);
+        assert!(redis
+            .execute(
+                "XGROUP",
+                ["CREATE", "stream", "group", "0"]
+                    .map(RedisValue::from)
+                    .to_vec(),
+            )
+            .is_err());
+        assert_eq!(
+            run(
+                &redis,
+                "XREADGROUP",
+                &["GROUP", "group", "consumer", "STREAMS", "stream", ">"],
+            ),
+            RedisValue::Null
+        );
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match(b"pa_*", b"pa_123"));
+        assert!(glob_match(b"?a*", b"pa_123"));
+        assert!(!glob_match(b"ref_*", b"pa_123"));
+        assert!(glob_match(b"a\\*", b"a*"));
+    }
+}
@@ -3,6 +3,7 @@
 
 use common_utils::errors::CustomResult;
 use fred::types::RedisValue as FredRedisValue;
+use hyperswitch_masking::Secret;
 
 use crate::{errors, RedisConnectionPool};
 
@@ -51,6 +52,22 @@ pub struct RedisSettings {
     pub port: u16,
     pub cluster_enabled: bool,
     pub cluster_urls: Vec<String>,
+    /// Serve the commands from an in-memory keyspace instead of a Redis server, for local
+    /// development and tests, the connection settings are ignored when enabled
+    pub in_memory: bool,
+    /// Discover the primary through Redis Sentinel, `host` and `port` are ignored when enabled
+    pub sentinel_enabled: bool,
+    /// Addresses of the sentinels, in the `host:port` format
+    pub sentinel_urls: Vec<String>,
+    /// Name of the primary monitored by the sentinels
+    pub sentinel_service_name: String,
+    pub sentinel_username: Option<String>,
+    pub sentinel_password: Option<Secret<String>>,
+    /// ACL username, the `default` user is used when only a password is provided
+    pub username: Option<String>,
+    pub password: Option<Secret<String>>,
+    /// Connect over TLS when provided
+    pub tls: Option<RedisTlsSettings>,
     pub use_legacy_version: bool,
     pub pool_size: usize,
     pub reconnect_max_attempts: u32,
@@ -71,23 +88,85 @@ pub struct RedisSettings {
     pub broadcast_channel_capacity: usize,
 }
 
+#[derive(Debug, serde::Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RedisTlsSettings {
+    /// PEM file of the CA certificates to trust, the system certificates are trusted otherwise
+    pub root_ca_path: Option<String>,
+    /// PEM files of the client certificate chain and its private key, for mutual TLS
+    pub client_cert_path: Option<String>,
+    pub client_key_path: Option<String>,
+}
+
 impl RedisSettings {
     /// Validates the Redis configuration provided.
     pub fn validate(&self) -> CustomResult<(), errors::RedisError> {
         use common_utils::{ext_traits::ConfigExt, fp_utils::when};
 
-        when(self.host.is_default_or_empty(), || {
+        if self.in_memory {
+            return Ok(());
+        }
+
+        when(
+            self.host.is_default_or_empty() && !self.sentinel_enabled,
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `host` must be specified".into(),
+                ))
+            },
+        )?;
+
+        when(self.cluster_enabled && self.cluster_urls.is_empty(), || {
             Err(errors::RedisError::InvalidConfiguration(
-                "Redis `host` must be specified".into(),
+                "Redis `cluster_urls` must be specified if `cluster_enabled` is `true`".into(),
             ))
         })?;
 
-        when(self.cluster_enabled && self.cluster_urls.is_empty(), || {
+        when(self.cluster_enabled && self.sentinel_enabled, || {
             Err(errors::RedisError::InvalidConfiguration(
-                "Redis `cluster_urls` must be specified if `cluster_enabled` is `true`".into(),
+                "Redis `cluster_enabled` and `sentinel_enabled` cannot both be `true`".into(),
             ))
         })?;
 
+        when(
+            self.sentinel_enabled
+                && (self.sentinel_urls.is_empty()
+                    || self.sentinel_service_name.is_default_or_empty()),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_urls` and `sentinel_service_name` must be specified if `sentinel_enabled` is `true`".into(),
+                ))
+            },
+        )?;
+
+        when(self.username.is_some() && self.password.is_none(), || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `password` must be specified if `username` is specified".into(),
+            ))
+        })?;
+
+        when(
+            self.sentinel_username.is_some() && self.sentinel_password.is_none(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_password` must be specified if `sentinel_username` is specified"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.tls
+                .as_ref()
+                .is_some_and(|tls| tls.client_cert_path.is_some() != tls.client_key_path.is_some()),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis TLS `client_cert_path` and `client_key_path` must be specified together"
+                        .into(),
+                ))
+            },
+        )?;
+
         when(
             self.default_command_timeout < self.unresponsive_timeout,
             || {
@@ -107,6 +186,15 @@ impl Default for RedisSettings {
             port: 6379,
             cluster_enabled: false,
             cluster_urls: vec![],
+            in_memory: false,
+            sentinel_enabled: false,
+            sentinel_urls: vec![],
+            sentinel_service_name: String::default(),
+            sentinel_username: None,
+            sentinel_password: None,
+            username: None,
+            password: None,
+            tls: None,
             use_legacy_version: false,
             pool_size: 5,
             reconnect_max_attempts: 5,
@@ -2,6 +2,7 @@ use error_stack::ResultExt;
 use router::{
     configs::settings::{CmdLineConf, Settings},
     core::errors::{ApplicationError, ApplicationResult},
+    db::StorageImpl,
     logger,
     routes::metrics,
 };
@@ -14,6 +15,11 @@ async fn main() -> ApplicationResult<()> {
     #[allow(clippy::expect_used)]
     let conf = Settings::with_config_path(cmd_line.config_path)
         .expect("Unable to construct application configuration");
+    let (conf, storage_impl) = if cmd_line.in_memory {
+        (conf.with_in_memory_storage(), StorageImpl::Mock)
+    } else {
+        (conf, StorageImpl::Postgresql)
+    };
     #[allow(clippy::expect_used)]
     conf.validate()
         .expect("Failed to validate router configuration");
@@ -32,6 +38,9 @@ async fn main() -> ApplicationResult<()> {
     .change_context(ApplicationError::ConfigurationError)?;
 
     logger::info!("Application started [{:?}] [{:?}]", conf.server, conf.log);
+    if cmd_line.in_memory {
+        logger::warn!("Running with in-memory storage, data will be lost on shutdown");
+    }
 
     // Spawn a thread for collecting metrics at fixed intervals
     metrics::bg_metrics_collector::spawn_metrics_collector(
@@ -39,7 +48,7 @@ async fn main() -> ApplicationResult<()> {
     );
 
     #[allow(clippy::expect_used)]
-    let server = Box::pin(router::start_server(conf))
+    let server = Box::pin(router::start_server_with_storage(conf, storage_impl))
         .await
         .expect("Failed to create the server");
     let _ = server.await;
@@ -8,8 +8,10 @@ use error_stack::ResultExt;
 use router::{
     configs::settings::{CmdLineConf, Settings},
     core::{
+        data_retention,
         errors::{self, CustomResult},
         health_check::HealthCheckInterface,
+        outbox,
     },
     logger, routes,
     services::{self, api},
@@ -92,6 +94,25 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 
     logger::debug!(startup_config=?state.conf);
 
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Consumer)
+        && state.conf.outbox_relay.enabled
+    {
+        let _task_handle =
+            tokio::spawn(outbox::start_outbox_relay(state.clone()).in_current_span());
+    }
+
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Consumer) {
+        let _task_handle =
+            tokio::spawn(outbox::start_outbox_cleanup(state.clone()).in_current_span());
+    }
+
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Consumer)
+        && state.conf.data_retention.enabled
+    {
+        let _task_handle =
+            tokio::spawn(data_retention::start_data_archival(state.clone()).in_current_span());
+    }
+
     start_scheduler(&state, scheduler_flow, (tx, rx)).await?;
 
     logger::error!("Scheduler shut down");
@@ -281,15 +302,45 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::RefundWorkflowRouter => {
                     Ok(Box::new(workflows::refund_router::RefundWorkflowRouter))
                 }
+                storage::ProcessTrackerRunner::BulkRefundWorkflow => {
+                    Ok(Box::new(workflows::bulk_refund::BulkRefundWorkflow))
+                }
                 storage::ProcessTrackerRunner::ProcessDisputeWorkflow => {
                     Ok(Box::new(workflows::process_dispute::ProcessDisputeWorkflow))
                 }
                 storage::ProcessTrackerRunner::DisputeListWorkflow => {
                     Ok(Box::new(workflows::dispute_list::DisputeListWorkflow))
                 }
+                storage::ProcessTrackerRunner::DisputeEvidenceReminderWorkflow => Ok(Box::new(
+                    workflows::dispute_evidence_reminder::DisputeEvidenceReminderWorkflow,
+                )),
+                storage::ProcessTrackerRunner::SettlementReconWorkflow => Ok(Box::new(
+                    workflows::settlement_recon::SettlementReconWorkflow,
+                )),
+                storage::ProcessTrackerRunner::CustomerErasureWorkflow => Ok(Box::new(
+                    workflows::customer_erasure::CustomerErasureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AccountUpdaterWorkflow => {
+                    Ok(Box::new(workflows::account_updater::AccountUpdaterWorkflow))
+                }
+                storage::ProcessTrackerRunner::PaymentMethodExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_method_expiry::PaymentMethodExpiryWorkflow,
+                )),
                 storage::ProcessTrackerRunner::InvoiceSyncflow => {
                     Ok(Box::new(workflows::invoice_sync::InvoiceSyncWorkflow))
                 }
+                storage::ProcessTrackerRunner::SubscriptionBillingWorkflow => Ok(Box::new(
+                    workflows::subscription_billing::SubscriptionBillingWorkflow,
+                )),
+                storage::ProcessTrackerRunner::DunningWorkflow => {
+                    Ok(Box::new(workflows::dunning::DunningWorkflow))
+                }
+                storage::ProcessTrackerRunner::StorageSchemeMigrationWorkflow => Ok(Box::new(
+                    workflows::storage_scheme_migration::StorageSchemeMigrationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::LedgerPostingWorkflow => {
+                    Ok(Box::new(workflows::ledger_posting::LedgerPostingWorkflow))
+                }
                 storage::ProcessTrackerRunner::DeleteTokenizeDataWorkflow => Ok(Box::new(
                     workflows::tokenized_data::DeleteTokenizeDataWorkflow,
                 )),
@@ -336,6 +387,19 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PayoutSyncWorkFlow => {
                     Ok(Box::new(workflows::payout_sync::PayoutSyncWorkFlow))
                 }
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
@@ -90,7 +90,11 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    #[cfg(feature = "payouts")]
+    PayoutBatch,
     Subscriptions,
+    Customer,
+    PaymentMethod,
 }
 
 #[derive(Serialize, Debug)]
@@ -287,6 +291,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeEvidenceDue => "dispute.evidence_due",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
 
@@ -303,7 +308,12 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::PayoutBatchCompleted => "payout_batch.completed",
         api_models::enums::EventType::InvoicePaid => "invoice.paid",
+        api_models::enums::EventType::CustomerMerged => "customer.merged",
+        api_models::enums::EventType::PaymentMethodUpdated
+        | api_models::enums::EventType::PaymentMethodExpired => "payment_method.updated",
+        api_models::enums::EventType::PaymentMethodExpiring => "customer.source.expiring",
     }
 }
 
@@ -346,9 +356,13 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            #[cfg(feature = "payouts")]
+            api::OutgoingWebhookContent::PayoutBatchDetails(_) => Self::PayoutBatch,
             api_models::webhooks::OutgoingWebhookContent::SubscriptionDetails(_) => {
                 Self::Subscriptions
             }
+            api::OutgoingWebhookContent::CustomerDetails(_) => Self::Customer,
+            api::OutgoingWebhookContent::PaymentMethodDetails(_) => Self::PaymentMethod,
         }
     }
 }
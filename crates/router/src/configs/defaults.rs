@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "payouts")]
 pub mod payout_required_fields;
@@ -47,6 +47,18 @@ impl Default for super::settings::Database {
             queue_strategy: Default::default(),
             min_idle: None,
             max_lifetime: None,
+            tls: None,
+        }
+    }
+}
+impl Default for super::settings::ReplicaRouting {
+    fn default() -> Self {
+        Self {
+            replica_weight: 1,
+            additional_replicas: Vec::new(),
+            max_replication_lag: 5000,
+            health_check_interval: 5,
+            read_your_writes: false,
         }
     }
 }
@@ -90,6 +102,73 @@ impl Default for super::settings::Refund {
         Self {
             max_attempts: 10,
             max_age: 365,
+            bulk_refund_concurrency: super::settings::BulkRefundConcurrency::default(),
+        }
+    }
+}
+
+impl Default for super::settings::BulkRefundConcurrency {
+    fn default() -> Self {
+        Self {
+            default: 5,
+            connectors: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::OutboxRelaySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            batch_size: 100,
+            poll_interval: 1000,
+            max_attempts: 10,
+            retention_days: 7,
+        }
+    }
+}
+
+impl Default for super::settings::DataRetentionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path_prefix: "archives".to_string(),
+            interval: 3600,
+            max_batches_per_run: 100,
+        }
+    }
+}
+
+impl Default for super::settings::AccountUpdaterSettings {
+    fn default() -> Self {
+        Self {
+            path_prefix: "account_updater".to_string(),
+            interval: 86400,
+            max_cards_per_batch: 1000,
+            expiring_within_days: 30,
+            decline_lookback_days: 7,
+            resubmission_cooldown_days: 30,
+            result_timeout_days: 7,
+            decline_error_codes: Vec::new(),
+        }
+    }
+}
+
+impl Default for super::settings::PaymentMethodExpirySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            expiry_reminder_days: vec![30, 7],
+            webhook_notifications: true,
+            email_notifications: false,
+        }
+    }
+}
+
+impl Default for super::settings::SettlementReconSettings {
+    fn default() -> Self {
+        Self {
+            settlement_lag_days: 2,
         }
     }
 }
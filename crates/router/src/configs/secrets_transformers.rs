@@ -388,6 +388,55 @@ impl SecretsHandler for settings::OidcSettings {
     }
 }
 
+/// Decrypts the Redis ACL and Sentinel passwords, which are optional unlike the other secrets
+async fn convert_redis_secrets(
+    redis: redis_interface::RedisSettings,
+    secret_management_client: &dyn SecretManagementInterface,
+) -> CustomResult<redis_interface::RedisSettings, SecretsManagementError> {
+    let password = match redis.password {
+        Some(password) => Some(secret_management_client.get_secret(password).await?),
+        None => None,
+    };
+    let sentinel_password = match redis.sentinel_password {
+        Some(password) => Some(secret_management_client.get_secret(password).await?),
+        None => None,
+    };
+
+    Ok(redis_interface::RedisSettings {
+        password,
+        sentinel_password,
+        ..redis
+    })
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ReplicaRouting {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let replica_routing = value.get_inner();
+        let mut additional_replicas = Vec::with_capacity(replica_routing.additional_replicas.len());
+        for replica in &replica_routing.additional_replicas {
+            let password = secret_management_client
+                .get_secret(replica.database.password.clone())
+                .await?;
+            additional_replicas.push(settings::WeightedDatabase {
+                database: settings::Database {
+                    password,
+                    ..replica.database.clone()
+                },
+                weight: replica.weight,
+            });
+        }
+
+        Ok(value.transition_state(|replica_routing| Self {
+            additional_replicas,
+            ..replica_routing
+        }))
+    }
+}
+
 /// # Panics
 ///
 /// Will panic even if kms decryption fails for at least one field
@@ -415,6 +464,15 @@ pub(crate) async fn fetch_raw_secrets(
             .await
             .expect("Failed to decrypt replica database configuration");
 
+    #[cfg(feature = "olap")]
+    #[allow(clippy::expect_used)]
+    let replica_routing = settings::ReplicaRouting::convert_to_raw_secret(
+        conf.replica_routing,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt replica routing configuration");
+
     #[allow(clippy::expect_used)]
     let secrets = settings::Secrets::convert_to_raw_secret(conf.secrets, secret_management_client)
         .await
@@ -524,6 +582,11 @@ pub(crate) async fn fetch_raw_secrets(
         .await
         .expect("Failed to decrypt superposition config");
 
+    #[allow(clippy::expect_used)]
+    let redis = convert_redis_secrets(conf.redis, secret_management_client)
+        .await
+        .expect("Failed to decrypt redis configs");
+
     #[allow(clippy::expect_used)]
     let oidc = settings::OidcSettings::convert_to_raw_secret(conf.oidc, secret_management_client)
         .await
@@ -534,7 +597,7 @@ pub(crate) async fn fetch_raw_secrets(
         application_source: conf.application_source,
         chat,
         master_database,
-        redis: conf.redis,
+        redis,
         log: conf.log,
         #[cfg(feature = "kv_store")]
         drainer: conf.drainer,
@@ -545,6 +608,8 @@ pub(crate) async fn fetch_raw_secrets(
         key_manager,
         #[cfg(feature = "olap")]
         replica_database,
+        #[cfg(feature = "olap")]
+        replica_routing,
         secrets,
         fallback_merchant_ids_api_key_auth: conf.fallback_merchant_ids_api_key_auth,
         locker: conf.locker,
@@ -644,5 +709,10 @@ pub(crate) async fn fetch_raw_secrets(
         comparison_service: conf.comparison_service,
         authentication_service_enabled_connectors: conf.authentication_service_enabled_connectors,
         save_payment_method_on_session: conf.save_payment_method_on_session,
+        outbox_relay: conf.outbox_relay,
+        data_retention: conf.data_retention,
+        account_updater: conf.account_updater,
+        payment_method_expiry: conf.payment_method_expiry,
+        settlement_recon: conf.settlement_recon,
     }
 }
@@ -8,7 +8,7 @@ use std::{
 use analytics::{opensearch::OpenSearchConfig, ReportConfig};
 use api_models::enums;
 use common_enums;
-use common_utils::{ext_traits::ConfigExt, id_type, types::user::EmailThemeConfig};
+use common_utils::{ext_traits::ConfigExt, id_type, types::user::EmailThemeConfig, DbTlsSettings};
 use config::{Environment, File};
 use error_stack::ResultExt;
 #[cfg(feature = "email")]
@@ -71,6 +71,10 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+    /// Run without a database, Redis or a locker, keeping all the data in memory.
+    /// Meant for local development and tests, the data is lost when the application stops.
+    #[arg(long)]
+    pub in_memory: bool,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -84,6 +88,8 @@ pub struct Settings<S: SecretState> {
     pub master_database: SecretStateContainer<Database, S>,
     #[cfg(feature = "olap")]
     pub replica_database: SecretStateContainer<Database, S>,
+    #[cfg(feature = "olap")]
+    pub replica_routing: SecretStateContainer<ReplicaRouting, S>,
     pub redis: RedisSettings,
     pub log: Log,
     pub secrets: SecretStateContainer<Secrets, S>,
@@ -191,6 +197,11 @@ pub struct Settings<S: SecretState> {
     pub comparison_service: Option<ComparisonServiceConfig>,
     pub authentication_service_enabled_connectors: AuthenticationServiceEnabledConnectors,
     pub save_payment_method_on_session: OnSessionConfig,
+    pub outbox_relay: OutboxRelaySettings,
+    pub data_retention: DataRetentionSettings,
+    pub account_updater: AccountUpdaterSettings,
+    pub payment_method_expiry: PaymentMethodExpirySettings,
+    pub settlement_recon: SettlementReconSettings,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -801,6 +812,26 @@ pub enum DecryptionScheme {
 pub struct Refund {
     pub max_attempts: usize,
     pub max_age: i64,
+    pub bulk_refund_concurrency: BulkRefundConcurrency,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BulkRefundConcurrency {
+    /// Number of refunds of a bulk refund job executed concurrently against a single connector
+    pub default: usize,
+    /// Connector specific overrides of the concurrency limit
+    pub connectors: HashMap<String, usize>,
+}
+
+impl BulkRefundConcurrency {
+    pub fn get_limit_for_connector(&self, connector: &str) -> usize {
+        self.connectors
+            .get(connector)
+            .copied()
+            .unwrap_or(self.default)
+            .max(1)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -845,6 +876,7 @@ pub struct Database {
     pub queue_strategy: QueueStrategy,
     pub min_idle: Option<u32>,
     pub max_lifetime: Option<u64>,
+    pub tls: Option<DbTlsSettings>,
 }
 
 impl From<Database> for storage_impl::config::Database {
@@ -860,6 +892,55 @@ impl From<Database> for storage_impl::config::Database {
             queue_strategy: val.queue_strategy,
             min_idle: val.min_idle,
             max_lifetime: val.max_lifetime,
+            tls: val.tls,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ReplicaRouting {
+    /// Weight of `replica_database` in the read rotation
+    pub replica_weight: u32,
+    /// Replicas sharing the reads with `replica_database`
+    pub additional_replicas: Vec<WeightedDatabase>,
+    /// Replication lag, in milliseconds, above which a replica stops receiving reads
+    pub max_replication_lag: u64,
+    /// Interval between the replication lag checks of each replica, in seconds
+    pub health_check_interval: u64,
+    /// Whether reads issued after a write within the same request are served by the master
+    pub read_your_writes: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WeightedDatabase {
+    #[serde(flatten)]
+    pub database: Database,
+    pub weight: u32,
+}
+
+impl ReplicaRouting {
+    pub fn get_replica_settings(
+        &self,
+        replica_database: Database,
+    ) -> storage_impl::config::ReplicaSettings {
+        let replicas = std::iter::once(storage_impl::config::WeightedReplica {
+            database: replica_database.into(),
+            weight: self.replica_weight,
+        })
+        .chain(self.additional_replicas.iter().map(|replica| {
+            storage_impl::config::WeightedReplica {
+                database: replica.database.clone().into(),
+                weight: replica.weight,
+            }
+        }))
+        .collect();
+
+        storage_impl::config::ReplicaSettings {
+            replicas,
+            max_replication_lag: self.max_replication_lag,
+            health_check_interval: self.health_check_interval,
+            read_your_writes: self.read_your_writes,
         }
     }
 }
@@ -870,6 +951,75 @@ pub struct SupportedConnectors {
     pub wallets: Vec<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct OutboxRelaySettings {
+    /// Whether the payment intents, payment attempts, refunds and outgoing webhooks are written
+    /// along with their outbox entries, and the scheduler consumer relays the entries
+    pub enabled: bool,
+    /// Number of pending entries published per iteration
+    pub batch_size: i64,
+    pub poll_interval: u64, // in milliseconds
+    /// Number of failed publish attempts after which an entry is no longer retried
+    pub max_attempts: i32,
+    /// Number of days the published entries are retained for
+    pub retention_days: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DataRetentionSettings {
+    /// Whether the scheduler consumer archives the records past the retention period of their policy
+    pub enabled: bool,
+    /// Prefix of the keys the archive files are uploaded to in the file storage
+    pub path_prefix: String,
+    pub interval: u64, // in seconds
+    /// Number of batches archived per policy and tenant in a single run, so that a large backlog is
+    /// archived over several runs
+    pub max_batches_per_run: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AccountUpdaterSettings {
+    /// Prefix of the keys the batch files of the file based account updater are exchanged under,
+    /// the file storage holds card numbers and must be in PCI scope
+    pub path_prefix: String,
+    pub interval: u64, // in seconds
+    pub max_cards_per_batch: u32,
+    /// Cards expiring within these many days are submitted for an update
+    pub expiring_within_days: i64,
+    /// Cards declined in recurring payments within these many days are submitted for an update
+    pub decline_lookback_days: i64,
+    /// A card is not submitted again within these many days of its last submission
+    pub resubmission_cooldown_days: i64,
+    /// Batches without results after these many days are marked as failed
+    pub result_timeout_days: i64,
+    /// Error codes of the declines which lead to a submission, all the declines are considered
+    /// when empty
+    pub decline_error_codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PaymentMethodExpirySettings {
+    /// Schedules the expiry of the saved cards, which marks the cards inactive once they expire
+    pub enabled: bool,
+    /// Days before the expiry of a card on which the pre-expiry notifications are sent
+    pub expiry_reminder_days: Vec<u8>,
+    pub webhook_notifications: bool,
+    /// Emails the customers, requires the `email` feature
+    pub email_notifications: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SettlementReconSettings {
+    /// Number of days between the capture of a transaction and its settlement by the connector,
+    /// used to derive the capture period of a settlement report which does not declare its period
+    pub settlement_lag_days: i64,
+}
+
 #[cfg(feature = "kv_store")]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
@@ -1147,6 +1297,7 @@ impl Settings<SecuredSecret> {
                     .list_separator(",")
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
@@ -1165,11 +1316,21 @@ impl Settings<SecuredSecret> {
         Ok(settings)
     }
 
+    /// Switches the services backing the application to their in-memory counterparts, the
+    /// storage itself is selected when creating the application state
+    pub fn with_in_memory_storage(mut self) -> Self {
+        self.redis.in_memory = true;
+        self.locker.mock_locker = true;
+        self
+    }
+
     pub fn validate(&self) -> ApplicationResult<()> {
         self.server.validate()?;
         self.master_database.get_inner().validate()?;
         #[cfg(feature = "olap")]
         self.replica_database.get_inner().validate()?;
+        #[cfg(feature = "olap")]
+        self.replica_routing.get_inner().validate()?;
 
         // The logger may not yet be initialized when validating the application configuration
         #[allow(clippy::print_stderr)]
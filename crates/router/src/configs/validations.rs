@@ -86,6 +86,29 @@ impl super::settings::Database {
     }
 }
 
+impl super::settings::ReplicaRouting {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.replica_weight == 0
+                && self
+                    .additional_replicas
+                    .iter()
+                    .all(|replica| replica.weight == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "at least one replica must have a non-zero weight".into(),
+                ))
+            },
+        )?;
+
+        self.additional_replicas
+            .iter()
+            .try_for_each(|replica| replica.database.validate())
+    }
+}
+
 impl super::settings::SupportedConnectors {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.wallets.is_empty(), || {
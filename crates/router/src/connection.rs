@@ -82,6 +82,7 @@ pub async fn pg_connection_write<T: storage_impl::DatabaseStore>(
 > {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
+    storage_impl::database::store::record_write();
 
     pool.get()
         .await
@@ -96,6 +97,7 @@ pub async fn pg_accounts_connection_write<T: storage_impl::DatabaseStore>(
 > {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_accounts_master_pool();
+    storage_impl::database::store::record_write();
 
     pool.get()
         .await
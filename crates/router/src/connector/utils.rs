@@ -1,9 +1,4 @@
-use std::{
-    collections::{HashMap, HashSet},
-    ops::Deref,
-    str::FromStr,
-    sync::LazyLock,
-};
+use std::{collections::HashSet, ops::Deref, str::FromStr};
 
 #[cfg(feature = "payouts")]
 use api_models::payouts::{self, PayoutVendorAccountDetails};
@@ -25,7 +20,6 @@ use diesel_models::{enums, types::OrderDetailsWithAmount};
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
 use hyperswitch_masking::{Deserialize, ExposeInterface, Secret};
-use regex::Regex;
 
 #[cfg(feature = "frm")]
 use crate::types::fraud_check;
@@ -1456,32 +1450,6 @@ impl PayoutsData for types::PayoutsData {
     }
 }
 
-static CARD_REGEX: LazyLock<HashMap<CardIssuer, Result<Regex, regex::Error>>> = LazyLock::new(
-    || {
-        let mut map = HashMap::new();
-        // Reference: https://gist.github.com/michaelkeevildown/9096cd3aac9029c4e6e05588448a8841
-        // [#379]: Determine card issuer from card BIN number
-        map.insert(CardIssuer::Master, Regex::new(r"^5[1-5][0-9]{14}$"));
-        map.insert(CardIssuer::AmericanExpress, Regex::new(r"^3[47][0-9]{13}$"));
-        map.insert(CardIssuer::Visa, Regex::new(r"^4[0-9]{12}(?:[0-9]{3})?$"));
-        map.insert(CardIssuer::Discover, Regex::new(r"^65[4-9][0-9]{13}|64[4-9][0-9]{13}|6011[0-9]{12}|(622(?:12[6-9]|1[3-9][0-9]|[2-8][0-9][0-9]|9[01][0-9]|92[0-5])[0-9]{10})$"));
-        map.insert(
-            CardIssuer::Maestro,
-            Regex::new(r"^(5018|5020|5038|5893|6304|6759|6761|6762|6763)[0-9]{8,15}$"),
-        );
-        map.insert(
-            CardIssuer::DinersClub,
-            Regex::new(r"^3(?:0[0-5]|[68][0-9])[0-9]{11}$"),
-        );
-        map.insert(
-            CardIssuer::JCB,
-            Regex::new(r"^(3(?:088|096|112|158|337|5(?:2[89]|[3-8][0-9]))\d{12})$"),
-        );
-        map.insert(CardIssuer::CarteBlanche, Regex::new(r"^389[0-9]{11}$"));
-        map
-    },
-);
-
 #[derive(Debug, Copy, Clone, strum::Display, Eq, Hash, PartialEq)]
 pub enum CardIssuer {
     AmericanExpress,
@@ -1494,6 +1462,34 @@ pub enum CardIssuer {
     CarteBlanche,
 }
 
+impl CardIssuer {
+    fn from_card_network(card_network: enums::CardNetwork, card_number: &str) -> Option<Self> {
+        match card_network {
+            enums::CardNetwork::Visa => Some(Self::Visa),
+            enums::CardNetwork::Mastercard => Some(Self::Master),
+            enums::CardNetwork::AmericanExpress => Some(Self::AmericanExpress),
+            enums::CardNetwork::Discover => Some(Self::Discover),
+            enums::CardNetwork::JCB => Some(Self::JCB),
+            // Carte Blanche cards are Diners Club cards with their own 14 digit range
+            enums::CardNetwork::DinersClub
+                if card_number.starts_with("389") && card_number.len() == 14 =>
+            {
+                Some(Self::CarteBlanche)
+            }
+            enums::CardNetwork::DinersClub => Some(Self::DinersClub),
+            enums::CardNetwork::Maestro => Some(Self::Maestro),
+            enums::CardNetwork::CartesBancaires
+            | enums::CardNetwork::UnionPay
+            | enums::CardNetwork::Interac
+            | enums::CardNetwork::RuPay
+            | enums::CardNetwork::Star
+            | enums::CardNetwork::Pulse
+            | enums::CardNetwork::Accel
+            | enums::CardNetwork::Nyce => None,
+        }
+    }
+}
+
 pub trait CardData {
     fn get_card_expiry_year_2_digit(&self) -> Result<Secret<String>, errors::ConnectorError>;
     fn get_card_issuer(&self) -> Result<CardIssuer, Error>;
@@ -1731,19 +1727,16 @@ impl CardData for domain::Card {
     }
 }
 
+/// Identifies the card issuer by the issuer identification number alone, as the card numbers and
+/// network tokens passed to the connectors are not always of a length their network issues.
 #[track_caller]
 fn get_card_issuer(card_number: &str) -> Result<CardIssuer, Error> {
-    for (k, v) in CARD_REGEX.iter() {
-        let regex: Regex = v
-            .clone()
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-        if regex.is_match(card_number) {
-            return Ok(*k);
-        }
-    }
-    Err(error_stack::Report::new(
-        errors::ConnectorError::NotImplemented("Card Type".into()),
-    ))
+    ::cards::get_card_networks_by_iin(card_number)
+        .into_iter()
+        .find_map(|card_network| CardIssuer::from_card_network(card_network, card_number))
+        .ok_or_else(|| {
+            error_stack::Report::new(errors::ConnectorError::NotImplemented("Card Type".into()))
+        })
 }
 pub trait WalletData {
     fn get_wallet_token(&self) -> Result<Secret<String>, Error>;
@@ -2504,6 +2497,46 @@ mod error_code_error_message_tests {
     }
 }
 
+#[cfg(test)]
+mod card_issuer_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_card_issuer() {
+        for (card_number, card_issuer) in [
+            ("4111111111111111", CardIssuer::Visa),
+            ("5555555555554444", CardIssuer::Master),
+            ("2223003122003222", CardIssuer::Master),
+            ("378282246310005", CardIssuer::AmericanExpress),
+            ("6011111111111117", CardIssuer::Discover),
+            ("3530111333300000", CardIssuer::JCB),
+            ("36227206271667", CardIssuer::DinersClub),
+            ("38900000000000", CardIssuer::CarteBlanche),
+            ("6200000000000005", CardIssuer::UnionPay),
+            ("6759649826438453", CardIssuer::Maestro),
+        ] {
+            assert_eq!(get_card_issuer(card_number).ok(), Some(card_issuer));
+        }
+    }
+
+    #[test]
+    fn test_get_card_issuer_of_card_number_with_unissued_length() {
+        // Network tokens and test cards are not always of a length the network issues
+        for (card_number, card_issuer) in [
+            ("411111111111111111", CardIssuer::Visa),
+            ("5555555555554444000", CardIssuer::Master),
+            ("37828224631000", CardIssuer::AmericanExpress),
+        ] {
+            assert_eq!(get_card_issuer(card_number).ok(), Some(card_issuer));
+        }
+    }
+
+    #[test]
+    fn test_get_card_issuer_of_unknown_iin() {
+        assert!(get_card_issuer("9999999999999995").is_err());
+    }
+}
+
 pub fn is_mandate_supported(
     selected_pmd: domain::payments::PaymentMethodData,
     payment_method_type: Option<types::storage::enums::PaymentMethodType>,
@@ -79,6 +79,8 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_PAYMENT_METHOD_EXPIRY: &str = "Your saved card is about to expire";
+pub const EMAIL_SUBJECT_SUBSCRIPTION_PAYMENT_FAILED: &str = "Your subscription payment failed";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -348,6 +350,27 @@ pub const UCS_AUTH_CURRENCY_AUTH_KEY: &str = "currency-auth-key";
 /// Form field name for challenge request during creq submission
 pub const CREQ_CHALLENGE_REQUEST_KEY: &str = "creq";
 
+/// Maximum number of items allowed in a single payout batch
+pub const MAX_PAYOUT_BATCH_ITEMS: usize = 5000;
+
+/// Number of payout batch items executed in a single run of the payout batch workflow
+pub const PAYOUT_BATCH_ITEMS_PER_RUN: i64 = 100;
+
+/// Maximum number of items allowed in a single bulk refund job
+pub const MAX_BULK_REFUND_ITEMS: usize = 10000;
+
+/// Number of bulk refund items executed in a single run of the bulk refund workflow
+pub const BULK_REFUND_ITEMS_PER_RUN: i64 = 100;
+
+/// Maximum number of transactions allowed in a single settlement report
+pub const MAX_SETTLEMENT_REPORT_LINES: usize = 100000;
+
+/// Number of connector transaction IDs looked up in a single query while reconciling a settlement report
+pub const SETTLEMENT_RECON_LOOKUP_BATCH_SIZE: usize = 500;
+
+/// Number of hours before the evidence submission deadline of a dispute at which the evidence reminder is sent
+pub const DISPUTE_EVIDENCE_REMINDER_LEAD_TIME_IN_HOURS: i64 = 48;
+
 /// Superposition configuration keys
 pub mod superposition {
     /// CVV requirement configuration key
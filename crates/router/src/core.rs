@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod account_updater;
 pub mod admin;
 pub mod api_keys;
 pub mod api_locking;
@@ -18,7 +20,12 @@ pub mod connector_onboarding;
 pub mod connector_validation;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
+#[cfg(feature = "v1")]
+pub mod customer_data_requests;
+#[cfg(feature = "v1")]
+pub mod customer_merge;
 pub mod customers;
+pub mod data_retention;
 #[cfg(feature = "v1")]
 pub mod debit_routing;
 pub mod disputes;
@@ -30,11 +37,13 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod ledger;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
 pub mod merchant_connector_webhook_management;
 pub mod metrics;
+pub mod outbox;
 pub mod payment_link;
 #[cfg(feature = "v2")]
 pub mod payment_method_balance;
@@ -65,8 +74,12 @@ pub mod revenue_recovery;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery_data_backfill;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod settlement_recon;
 pub mod superposition_sdk_config;
 pub mod surcharge_decision_config;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod tenant_registry;
 pub mod three_ds_decision_rule;
 pub mod tokenization;
 pub mod unified_authentication_service;
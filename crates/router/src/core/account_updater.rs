@@ -0,0 +1,1203 @@
+pub mod file;
+
+use std::collections::{HashMap, HashSet};
+
+use ::payment_methods::controller::PaymentMethodsController;
+use api_models::{account_updater as updater_models, payment_methods as pm_api};
+use common_utils::{ext_traits::AsyncExt, id_type};
+use error_stack::ResultExt;
+use hyperswitch_masking::Secret;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    configs::settings::AccountUpdaterSettings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, webhooks as pm_webhooks},
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const ACCOUNT_UPDATER_WORKFLOW_TAG: &str = "ACCOUNT_UPDATER";
+const ACCOUNT_UPDATER_WORKFLOW_TASK: &str = "ACCOUNT_UPDATER_EXECUTE";
+
+/// A saved card submitted to the account updater, identified by its payment method.
+#[derive(Debug)]
+pub struct AccountUpdaterRequestCard {
+    pub payment_method_id: String,
+    pub card_number: ::cards::CardNumber,
+    pub expiry_month: Secret<String>,
+    pub expiry_year: Secret<String>,
+    pub reason: storage_enums::AccountUpdaterReason,
+}
+
+/// The outcome of the inquiry of a saved card. The card number and expiry are only present when
+/// the card was reissued with them.
+#[derive(Debug)]
+pub struct AccountUpdaterResponseCard {
+    pub payment_method_id: String,
+    pub result: storage_enums::AccountUpdaterResult,
+    pub card_number: Option<::cards::CardNumber>,
+    pub expiry_month: Option<Secret<String>>,
+    pub expiry_year: Option<Secret<String>>,
+}
+
+/// A service which reports the reissued and closed cards among the submitted saved cards.
+/// The inquiries are asynchronous, the results of a batch are fetched in later runs until they
+/// are available.
+#[async_trait::async_trait]
+pub trait AccountUpdater: Send + Sync {
+    async fn submit_batch(
+        &self,
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        cards: Vec<AccountUpdaterRequestCard>,
+    ) -> RouterResult<()>;
+
+    /// Returns `None` while the results of the batch are not available.
+    async fn fetch_results(
+        &self,
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> RouterResult<Option<Vec<AccountUpdaterResponseCard>>>;
+
+    /// Removes the data held for the batch once its results have been applied.
+    async fn cleanup_batch(
+        &self,
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    );
+}
+
+fn get_account_updater(
+    connector: storage_enums::AccountUpdaterConnector,
+) -> Box<dyn AccountUpdater> {
+    match connector {
+        storage_enums::AccountUpdaterConnector::File => Box::new(file::FileAccountUpdater),
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn enable_account_updater(
+    state: SessionState,
+    platform: domain::Platform,
+    req: updater_models::AccountUpdaterEnableRequest,
+) -> RouterResponse<updater_models::AccountUpdaterEnrollmentResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let tracking_data = storage::AccountUpdaterTrackingData {
+        merchant_id: merchant_id.clone(),
+        connector: req.connector,
+    };
+
+    let process = match find_account_updater_task(&state, merchant_id).await? {
+        Some(process) => {
+            let tracking_data = serde_json::to_value(&tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize account updater tracking data")?;
+            // A disabled enrollment runs right away, an active one keeps its schedule
+            let is_finished = process.status == storage_enums::ProcessTrackerStatus::Finish;
+            let update = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: is_finished.then_some(0),
+                schedule_time: is_finished.then(common_utils::date_time::now),
+                tracking_data: Some(tracking_data),
+                business_status: is_finished.then(|| storage::business_status::PENDING.to_string()),
+                status: is_finished.then_some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(common_utils::date_time::now()),
+            };
+            state
+                .store
+                .as_scheduler()
+                .update_process(process, update)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update account updater task")?
+        }
+        None => add_account_updater_task(&state, tracking_data)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to schedule account updater task")?,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        get_enrollment_response(merchant_id, Some(&process)),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn disable_account_updater(
+    state: SessionState,
+    platform: domain::Platform,
+) -> RouterResponse<updater_models::AccountUpdaterEnrollmentResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let process = find_account_updater_task(&state, merchant_id)
+        .await?
+        .filter(|process| process.status != storage_enums::ProcessTrackerStatus::Finish)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "account updater is not enabled for the merchant".to_string(),
+        })?;
+
+    state
+        .store
+        .as_scheduler()
+        .finish_process_with_business_status(process, storage::business_status::REVOKED)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to revoke account updater task")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_enrollment_response(merchant_id, None),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_account_updater_enrollment(
+    state: SessionState,
+    platform: domain::Platform,
+) -> RouterResponse<updater_models::AccountUpdaterEnrollmentResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let process = find_account_updater_task(&state, merchant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_enrollment_response(merchant_id, process.as_ref()),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_account_updater_batches(
+    state: SessionState,
+    platform: domain::Platform,
+    constraints: updater_models::AccountUpdaterBatchListConstraints,
+) -> RouterResponse<updater_models::AccountUpdaterBatchListResponse> {
+    let batches = state
+        .store
+        .list_account_updater_batches_by_merchant_id(
+            platform.get_processor().get_account().get_id(),
+            i64::from(
+                constraints
+                    .limit
+                    .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into()),
+            ),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list account updater batches")?;
+
+    let data = batches
+        .into_iter()
+        .map(|batch| updater_models::AccountUpdaterBatchResponse::foreign_from((batch, None)))
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        updater_models::AccountUpdaterBatchListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_account_updater_batch(
+    state: SessionState,
+    platform: domain::Platform,
+    req: updater_models::AccountUpdaterBatchId,
+) -> RouterResponse<updater_models::AccountUpdaterBatchResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let batch = state
+        .store
+        .find_account_updater_batch_by_merchant_id_batch_id(merchant_id, &req.batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("account updater batch {} not found", req.batch_id),
+        })?;
+
+    let cards = state
+        .store
+        .list_account_updater_cards_by_merchant_id_batch_id(merchant_id, &req.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list account updater cards")?;
+
+    Ok(services::ApplicationResponse::Json(
+        updater_models::AccountUpdaterBatchResponse::foreign_from((batch, Some(cards))),
+    ))
+}
+
+/// Applies the results of the submitted batches of the merchant and submits the saved cards which
+/// are due for an inquiry in a new batch.
+#[instrument(skip_all)]
+pub async fn run_account_updater(
+    state: &SessionState,
+    platform: &domain::Platform,
+    connector: storage_enums::AccountUpdaterConnector,
+) -> RouterResult<()> {
+    let settings = &state.conf.account_updater;
+    let account_updater = get_account_updater(connector);
+
+    process_submitted_batches(state, platform, settings, account_updater.as_ref()).await?;
+
+    let request_cards = collect_request_cards(state, platform, settings).await?;
+    if request_cards.is_empty() {
+        logger::info!("No saved cards are due for an account updater inquiry");
+        return Ok(());
+    }
+
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let batch_id = common_utils::generate_id(consts::ID_LENGTH, "aub");
+    let now = common_utils::date_time::now();
+    let cards = request_cards
+        .iter()
+        .map(
+            |(request_card, customer_id)| storage::AccountUpdaterCardNew {
+                batch_id: batch_id.clone(),
+                payment_method_id: request_card.payment_method_id.clone(),
+                merchant_id: merchant_id.clone(),
+                customer_id: customer_id.clone(),
+                reason: request_card.reason,
+                result: None,
+                created_at: now,
+                modified_at: now,
+            },
+        )
+        .collect::<Vec<_>>();
+    let card_count = i32::try_from(cards.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Too many cards in the account updater batch")?;
+
+    // The batch is recorded only once it has been submitted, a failed submission is retried with
+    // the same cards in the next run
+    account_updater
+        .submit_batch(
+            state,
+            merchant_id,
+            &batch_id,
+            request_cards
+                .into_iter()
+                .map(|(request_card, _)| request_card)
+                .collect(),
+        )
+        .await?;
+
+    state
+        .store
+        .insert_account_updater_batch(storage::AccountUpdaterBatchNew {
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_id.clone(),
+            connector,
+            status: storage_enums::AccountUpdaterBatchStatus::Submitted,
+            card_count,
+            updated_count: 0,
+            closed_count: 0,
+            unchanged_count: 0,
+            created_at: now,
+            modified_at: now,
+            completed_at: None,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert account updater batch")?;
+
+    state
+        .store
+        .insert_account_updater_cards(cards)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert account updater cards")?;
+
+    logger::info!(%batch_id, card_count, "Submitted account updater batch");
+    Ok(())
+}
+
+async fn process_submitted_batches(
+    state: &SessionState,
+    platform: &domain::Platform,
+    settings: &AccountUpdaterSettings,
+    account_updater: &dyn AccountUpdater,
+) -> RouterResult<()> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let batches = state
+        .store
+        .list_account_updater_batches_by_merchant_id_status(
+            merchant_id,
+            storage_enums::AccountUpdaterBatchStatus::Submitted,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list submitted account updater batches")?;
+
+    let timed_out_before = common_utils::date_time::now()
+        .saturating_sub(time::Duration::days(settings.result_timeout_days));
+
+    for batch in batches {
+        match account_updater
+            .fetch_results(state, merchant_id, &batch.batch_id)
+            .await?
+        {
+            Some(results) => {
+                let batch_id = batch.batch_id.clone();
+                apply_batch_results(state, platform, batch, results).await?;
+                account_updater
+                    .cleanup_batch(state, merchant_id, &batch_id)
+                    .await;
+            }
+            None if batch.created_at < timed_out_before => {
+                logger::warn!(
+                    batch_id = %batch.batch_id,
+                    "No results were received for the account updater batch"
+                );
+                state
+                    .store
+                    .update_account_updater_batch(
+                        batch,
+                        storage::AccountUpdaterBatchUpdate::TimedOut,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update account updater batch")?;
+            }
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies the results to the saved cards of the batch. The results are recorded card by card, so
+/// that a run interrupted midway does not apply a result twice.
+async fn apply_batch_results(
+    state: &SessionState,
+    platform: &domain::Platform,
+    batch: storage::AccountUpdaterBatch,
+    results: Vec<AccountUpdaterResponseCard>,
+) -> RouterResult<()> {
+    let mut results = results
+        .into_iter()
+        .map(|result| (result.payment_method_id.clone(), result))
+        .collect::<HashMap<_, _>>();
+    let cards = state
+        .store
+        .list_account_updater_cards_by_merchant_id_batch_id(&batch.merchant_id, &batch.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list account updater cards")?;
+
+    let mut applied_results = Vec::with_capacity(cards.len());
+    for card in cards {
+        let result = match card.result {
+            Some(result) => result,
+            None => {
+                let result = match results.remove(&card.payment_method_id) {
+                    Some(response_card) => {
+                        apply_card_result(state, platform, &card, response_card).await?
+                    }
+                    None => storage_enums::AccountUpdaterResult::NoMatch,
+                };
+                state
+                    .store
+                    .update_account_updater_card_result(card, result)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update account updater card")?;
+                result
+            }
+        };
+        applied_results.push(result);
+    }
+
+    if !results.is_empty() {
+        logger::warn!(
+            batch_id = %batch.batch_id,
+            count = results.len(),
+            "Ignoring account updater results for cards which were not submitted in the batch"
+        );
+    }
+
+    let (updated_count, closed_count, unchanged_count) = get_result_counts(&applied_results);
+    state
+        .store
+        .update_account_updater_batch(
+            batch,
+            storage::AccountUpdaterBatchUpdate::ResultsApplied {
+                updated_count,
+                closed_count,
+                unchanged_count,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update account updater batch")?;
+
+    Ok(())
+}
+
+/// Returns the number of updated, closed and unchanged cards among the results of a batch
+fn get_result_counts(results: &[storage_enums::AccountUpdaterResult]) -> (i32, i32, i32) {
+    results.iter().fold(
+        (0, 0, 0),
+        |(updated_count, closed_count, unchanged_count), result| match result {
+            storage_enums::AccountUpdaterResult::NewAccountNumber
+            | storage_enums::AccountUpdaterResult::NewExpiry => {
+                (updated_count + 1, closed_count, unchanged_count)
+            }
+            storage_enums::AccountUpdaterResult::AccountClosed => {
+                (updated_count, closed_count + 1, unchanged_count)
+            }
+            storage_enums::AccountUpdaterResult::NoChange
+            | storage_enums::AccountUpdaterResult::ContactCardholder
+            | storage_enums::AccountUpdaterResult::NoMatch => {
+                (updated_count, closed_count, unchanged_count + 1)
+            }
+        },
+    )
+}
+
+/// Applies the result of a saved card to the vault and the payment method, and returns the result
+/// to record for the card.
+async fn apply_card_result(
+    state: &SessionState,
+    platform: &domain::Platform,
+    card: &storage::AccountUpdaterCard,
+    response_card: AccountUpdaterResponseCard,
+) -> RouterResult<storage_enums::AccountUpdaterResult> {
+    let result = response_card.result;
+    if !result.is_update() {
+        return Ok(result);
+    }
+
+    let provider = platform.get_provider();
+    let payment_method = match state
+        .store
+        .find_payment_method(
+            provider.get_key_store(),
+            &card.payment_method_id,
+            provider.get_account().storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_method) => payment_method,
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::info!(
+                payment_method_id = %card.payment_method_id,
+                "Payment method was deleted after its submission, skipping the account updater result"
+            );
+            return Ok(result);
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment method")?,
+    };
+
+    let updated_payment_method = match result {
+        storage_enums::AccountUpdaterResult::NewAccountNumber
+        | storage_enums::AccountUpdaterResult::NewExpiry => {
+            let Some(payment_method) =
+                update_card_in_vault(state, platform, payment_method, response_card).await?
+            else {
+                return Ok(storage_enums::AccountUpdaterResult::NoChange);
+            };
+            payment_method
+        }
+        storage_enums::AccountUpdaterResult::AccountClosed => state
+            .store
+            .update_payment_method(
+                provider.get_key_store(),
+                payment_method,
+                storage::PaymentMethodUpdate::StatusUpdate {
+                    status: Some(storage_enums::PaymentMethodStatus::Inactive),
+                    last_modified_by: None,
+                },
+                provider.get_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deactivate payment method")?,
+        storage_enums::AccountUpdaterResult::NoChange
+        | storage_enums::AccountUpdaterResult::ContactCardholder
+        | storage_enums::AccountUpdaterResult::NoMatch => return Ok(result),
+    };
+
+    let response =
+        pm_webhooks::get_payment_method_webhook_response(&updated_payment_method, Some(result));
+    pm_webhooks::trigger_payment_method_webhook(
+        state,
+        platform,
+        storage_enums::EventType::PaymentMethodUpdated,
+        response,
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(?error, "Failed to trigger payment method updated webhook");
+    })
+    .ok();
+
+    Ok(result)
+}
+
+/// Replaces the card in the vault with the reissued card under the same reference, and updates
+/// the card details stored with the payment method. Returns `None` when the result does not carry
+/// the reissued card details.
+async fn update_card_in_vault(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_method: domain::PaymentMethod,
+    response_card: AccountUpdaterResponseCard,
+) -> RouterResult<Option<domain::PaymentMethod>> {
+    if !has_reissued_card_details(&response_card) {
+        logger::warn!(
+            payment_method_id = %payment_method.get_id(),
+            result = %response_card.result,
+            "Account updater result does not carry the reissued card details"
+        );
+        return Ok(None);
+    }
+
+    let provider = platform.get_provider();
+    let card_reference = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or(payment_method.get_id().clone());
+    let locker_card = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        &card_reference,
+    )
+    .await
+    .attach_printable("Error getting card from locker")?
+    .get_card();
+
+    let mut card_detail = api::CardDetail::from((locker_card.clone(), None));
+    if let Some(card_number) = response_card.card_number {
+        card_detail.card_number = card_number;
+    }
+    if let Some(expiry_month) = response_card.expiry_month {
+        card_detail.card_exp_month = expiry_month;
+    }
+    if let Some(expiry_year) = response_card.expiry_year {
+        card_detail.card_exp_year = expiry_year;
+    }
+
+    let new_pm = api::PaymentMethodCreate {
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(card_detail.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: payment_method.client_secret.clone(),
+        payment_method_data: None,
+        card_network: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+    let pm_cards = cards::PmCards { state, provider };
+    pm_cards
+        .delete_card_from_locker(
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &card_reference,
+        )
+        .await?;
+    Box::pin(pm_cards.add_card_to_locker(
+        new_pm,
+        &card_detail,
+        &payment_method.customer_id,
+        Some(&card_reference),
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add reissued card to locker")?;
+
+    let existing_card = cards::get_saved_card_details(&payment_method);
+    let updated_card = api::CardDetailFromLocker {
+        last4_digits: Some(card_detail.card_number.get_last4()),
+        card_isin: Some(card_detail.card_number.get_card_isin()),
+        expiry_month: Some(card_detail.card_exp_month.clone()),
+        expiry_year: Some(card_detail.card_exp_year.clone()),
+        saved_to_locker: true,
+        ..existing_card.unwrap_or(api::CardDetailFromLocker {
+            scheme: payment_method.scheme.clone(),
+            issuer_country: None,
+            issuer_country_code: None,
+            last4_digits: None,
+            card_number: None,
+            expiry_month: None,
+            expiry_year: None,
+            card_token: None,
+            card_holder_name: locker_card.name_on_card,
+            card_fingerprint: None,
+            nick_name: locker_card.nick_name.map(Secret::new),
+            card_network: None,
+            card_isin: None,
+            card_issuer: None,
+            card_type: None,
+            saved_to_locker: true,
+        })
+    };
+    let payment_method_data = pm_api::PaymentMethodsData::Card(
+        pm_api::CardDetailsPaymentMethod::from((updated_card, None)),
+    );
+    let key_manager_state = state.into();
+    let payment_method_data = Some(payment_method_data)
+        .async_map(|payment_method_data| {
+            cards::create_encrypted_data(
+                &key_manager_state,
+                provider.get_key_store(),
+                payment_method_data,
+            )
+        })
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt payment method data")?;
+
+    state
+        .store
+        .update_payment_method(
+            provider.get_key_store(),
+            payment_method,
+            storage::PaymentMethodUpdate::PaymentMethodDataUpdate {
+                payment_method_data: payment_method_data.map(Into::into),
+                last_modified_by: None,
+            },
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")
+        .map(Some)
+}
+
+/// Whether the result carries the details the card was reissued with, the card number for a new
+/// account number and the expiry otherwise
+fn has_reissued_card_details(response_card: &AccountUpdaterResponseCard) -> bool {
+    match response_card.result {
+        storage_enums::AccountUpdaterResult::NewAccountNumber => {
+            response_card.card_number.is_some()
+        }
+        _ => response_card.expiry_month.is_some() && response_card.expiry_year.is_some(),
+    }
+}
+
+/// Collects the saved cards which are due for an inquiry, the cards declined in recurring payments
+/// first and then the expiring cards, along with the customers they belong to.
+async fn collect_request_cards(
+    state: &SessionState,
+    platform: &domain::Platform,
+    settings: &AccountUpdaterSettings,
+) -> RouterResult<Vec<(AccountUpdaterRequestCard, id_type::CustomerId)>> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let max_cards = usize::try_from(settings.max_cards_per_batch).unwrap_or(usize::MAX);
+    let page_size = i64::from(settings.max_cards_per_batch.max(1));
+    let now = common_utils::date_time::now();
+    let mut request_cards = Vec::new();
+
+    let declined = state
+        .store
+        .find_declined_account_updater_candidates(
+            merchant_id,
+            now.saturating_sub(time::Duration::days(settings.decline_lookback_days)),
+            &settings.decline_error_codes,
+            page_size,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find declined saved cards")?;
+    let declined_ids = declined
+        .into_iter()
+        .map(|candidate| candidate.payment_method_id)
+        .collect::<Vec<_>>();
+    for payment_method in find_due_payment_methods(state, platform, settings, &declined_ids).await?
+    {
+        if let Some(request_card) = get_request_card(
+            state,
+            payment_method,
+            storage_enums::AccountUpdaterReason::Declined,
+        )
+        .await
+        {
+            request_cards.push(request_card);
+        }
+    }
+
+    // The expiry is encrypted, the active cards are scanned for the expiring ones
+    let expiring_before = now.saturating_add(time::Duration::days(settings.expiring_within_days));
+    let mut submitted_ids = request_cards
+        .iter()
+        .map(|(request_card, _)| request_card.payment_method_id.clone())
+        .collect::<HashSet<_>>();
+    let mut starting_after = None;
+    while request_cards.len() < max_cards {
+        let payment_method_ids = state
+            .store
+            .find_active_card_payment_method_ids(merchant_id, starting_after, page_size)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find saved cards")?;
+        let Some(last_id) = payment_method_ids.last().cloned() else {
+            break;
+        };
+        starting_after = Some(last_id);
+
+        let payment_method_ids = payment_method_ids
+            .into_iter()
+            .filter(|payment_method_id| !submitted_ids.contains(payment_method_id))
+            .collect::<Vec<_>>();
+        for payment_method in
+            find_due_payment_methods(state, platform, settings, &payment_method_ids).await?
+        {
+            let is_expiring = is_expiring_card(
+                cards::get_saved_card_details(&payment_method),
+                expiring_before,
+            );
+            if !is_expiring || request_cards.len() >= max_cards {
+                continue;
+            }
+            if let Some(request_card) = get_request_card(
+                state,
+                payment_method,
+                storage_enums::AccountUpdaterReason::Expiring,
+            )
+            .await
+            {
+                submitted_ids.insert(request_card.0.payment_method_id.clone());
+                request_cards.push(request_card);
+            }
+        }
+    }
+
+    request_cards.truncate(max_cards);
+    Ok(request_cards)
+}
+
+/// Whether the saved card expires before the given time. Cards without a valid expiry are never
+/// expiring.
+fn is_expiring_card(
+    card: Option<api::CardDetailFromLocker>,
+    expiring_before: time::PrimitiveDateTime,
+) -> bool {
+    card.and_then(|card| {
+        card.expiry_month
+            .zip(card.expiry_year)
+            .and_then(|(month, year)| cards::get_card_expiry_time(&month, &year))
+    })
+    .is_some_and(|expiry_time| expiry_time <= expiring_before)
+}
+
+/// Finds the active card payment methods among the given ones which were not submitted within
+/// the resubmission cooldown.
+async fn find_due_payment_methods(
+    state: &SessionState,
+    platform: &domain::Platform,
+    settings: &AccountUpdaterSettings,
+    payment_method_ids: &[String],
+) -> RouterResult<Vec<domain::PaymentMethod>> {
+    if payment_method_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let provider = platform.get_provider();
+    let merchant_id = provider.get_account().get_id();
+    let submitted_since = common_utils::date_time::now()
+        .saturating_sub(time::Duration::days(settings.resubmission_cooldown_days));
+    let recently_submitted = state
+        .store
+        .find_account_updater_cards_submitted_since(
+            merchant_id,
+            payment_method_ids.to_vec(),
+            submitted_since,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find recently submitted saved cards")?
+        .into_iter()
+        .map(|card| card.payment_method_id)
+        .collect::<HashSet<_>>();
+
+    let payment_method_ids = payment_method_ids
+        .iter()
+        .filter(|payment_method_id| !recently_submitted.contains(*payment_method_id))
+        .cloned()
+        .collect::<Vec<_>>();
+    if payment_method_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let payment_methods = state
+        .store
+        .find_payment_methods_by_merchant_id_payment_method_ids(
+            provider.get_key_store(),
+            merchant_id,
+            &payment_method_ids,
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payment methods")?;
+
+    Ok(payment_methods
+        .into_iter()
+        .filter(|payment_method| {
+            payment_method.status == storage_enums::PaymentMethodStatus::Active
+                && payment_method.get_payment_method_type()
+                    == Some(storage_enums::PaymentMethod::Card)
+        })
+        .collect())
+}
+
+/// Retrieves the card of the payment method from the vault. Cards which cannot be retrieved are
+/// left out of the batch.
+async fn get_request_card(
+    state: &SessionState,
+    payment_method: domain::PaymentMethod,
+    reason: storage_enums::AccountUpdaterReason,
+) -> Option<(AccountUpdaterRequestCard, id_type::CustomerId)> {
+    let card = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(payment_method.get_id()),
+    )
+    .await
+    .map_err(|error| {
+        logger::warn!(
+            ?error,
+            payment_method_id = %payment_method.get_id(),
+            "Failed to retrieve card from locker, skipping the card"
+        );
+    })
+    .ok()?
+    .get_card();
+
+    Some((
+        AccountUpdaterRequestCard {
+            payment_method_id: payment_method.get_id().clone(),
+            card_number: card.card_number,
+            expiry_month: card.card_exp_month,
+            expiry_year: card.card_exp_year,
+            reason,
+        },
+        payment_method.customer_id,
+    ))
+}
+
+async fn find_account_updater_task(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<storage::ProcessTracker>> {
+    state
+        .store
+        .find_process_by_id(&get_account_updater_task_id(merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find account updater task")
+}
+
+fn get_account_updater_task_id(merchant_id: &id_type::MerchantId) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::AccountUpdaterWorkflow,
+        ACCOUNT_UPDATER_WORKFLOW_TASK,
+        merchant_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+async fn add_account_updater_task(
+    state: &SessionState,
+    tracking_data: storage::AccountUpdaterTrackingData,
+) -> errors::CustomResult<storage::ProcessTracker, errors::StorageError> {
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_account_updater_task_id(&tracking_data.merchant_id),
+        ACCOUNT_UPDATER_WORKFLOW_TASK,
+        storage::ProcessTrackerRunner::AccountUpdaterWorkflow,
+        [ACCOUNT_UPDATER_WORKFLOW_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await
+}
+
+fn get_enrollment_response(
+    merchant_id: &id_type::MerchantId,
+    process: Option<&storage::ProcessTracker>,
+) -> updater_models::AccountUpdaterEnrollmentResponse {
+    let process =
+        process.filter(|process| process.status != storage_enums::ProcessTrackerStatus::Finish);
+    let connector = process.and_then(|process| {
+        serde_json::from_value::<storage::AccountUpdaterTrackingData>(process.tracking_data.clone())
+            .map_err(|error| {
+                logger::error!(?error, "Failed to parse account updater tracking data");
+            })
+            .ok()
+            .map(|tracking_data| tracking_data.connector)
+    });
+
+    updater_models::AccountUpdaterEnrollmentResponse {
+        merchant_id: merchant_id.clone(),
+        enabled: process.is_some(),
+        connector,
+        next_run_at: process.and_then(|process| process.schedule_time),
+    }
+}
+
+impl
+    ForeignFrom<(
+        storage::AccountUpdaterBatch,
+        Option<Vec<storage::AccountUpdaterCard>>,
+    )> for updater_models::AccountUpdaterBatchResponse
+{
+    fn foreign_from(
+        (batch, cards): (
+            storage::AccountUpdaterBatch,
+            Option<Vec<storage::AccountUpdaterCard>>,
+        ),
+    ) -> Self {
+        Self {
+            batch_id: batch.batch_id,
+            merchant_id: batch.merchant_id,
+            connector: batch.connector,
+            status: batch.status,
+            card_count: batch.card_count,
+            updated_count: batch.updated_count,
+            closed_count: batch.closed_count,
+            unchanged_count: batch.unchanged_count,
+            created_at: batch.created_at,
+            modified_at: batch.modified_at,
+            completed_at: batch.completed_at,
+            cards: cards.map(|cards| {
+                cards
+                    .into_iter()
+                    .map(|card| updater_models::AccountUpdaterCardResponse {
+                        payment_method_id: card.payment_method_id,
+                        customer_id: card.customer_id,
+                        reason: card.reason,
+                        result: card.result,
+                        modified_at: card.modified_at,
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::str::FromStr;
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn saved_card(
+        expiry_month: Option<&str>,
+        expiry_year: Option<&str>,
+    ) -> api::CardDetailFromLocker {
+        api::CardDetailFromLocker {
+            scheme: None,
+            issuer_country: None,
+            issuer_country_code: None,
+            last4_digits: Some("4242".to_string()),
+            card_number: None,
+            expiry_month: expiry_month.map(|month| Secret::new(month.to_string())),
+            expiry_year: expiry_year.map(|year| Secret::new(year.to_string())),
+            card_token: None,
+            card_holder_name: None,
+            card_fingerprint: None,
+            nick_name: None,
+            card_network: None,
+            card_isin: None,
+            card_issuer: None,
+            card_type: None,
+            saved_to_locker: true,
+        }
+    }
+
+    fn response_card(
+        result: storage_enums::AccountUpdaterResult,
+        card_number: Option<&str>,
+        expiry: Option<(&str, &str)>,
+    ) -> AccountUpdaterResponseCard {
+        AccountUpdaterResponseCard {
+            payment_method_id: "pm_1".to_string(),
+            result,
+            card_number: card_number
+                .map(|card_number| ::cards::CardNumber::from_str(card_number).unwrap()),
+            expiry_month: expiry.map(|(month, _)| Secret::new(month.to_string())),
+            expiry_year: expiry.map(|(_, year)| Secret::new(year.to_string())),
+        }
+    }
+
+    fn account_updater_task(
+        status: storage_enums::ProcessTrackerStatus,
+        tracking_data: serde_json::Value,
+    ) -> storage::ProcessTracker {
+        let now = datetime!(2026-10-01 00:00:00);
+        storage::ProcessTracker {
+            id: get_account_updater_task_id(&id_type::MerchantId::default()),
+            name: Some(ACCOUNT_UPDATER_WORKFLOW_TASK.to_string()),
+            tag: vec![ACCOUNT_UPDATER_WORKFLOW_TAG.to_string()],
+            runner: Some("ACCOUNT_UPDATER_WORKFLOW".to_string()),
+            retry_count: 0,
+            schedule_time: Some(datetime!(2026-10-02 00:00:00)),
+            rule: String::new(),
+            tracking_data,
+            business_status: "Pending".to_string(),
+            status,
+            event: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            version: common_types::consts::API_VERSION,
+            application_source: None,
+        }
+    }
+
+    #[test]
+    fn test_result_counts() {
+        let results = [
+            storage_enums::AccountUpdaterResult::NewAccountNumber,
+            storage_enums::AccountUpdaterResult::NewExpiry,
+            storage_enums::AccountUpdaterResult::AccountClosed,
+            storage_enums::AccountUpdaterResult::NoChange,
+            storage_enums::AccountUpdaterResult::ContactCardholder,
+            storage_enums::AccountUpdaterResult::NoMatch,
+            storage_enums::AccountUpdaterResult::NewExpiry,
+        ];
+
+        assert_eq!(get_result_counts(&results), (3, 1, 3));
+        assert_eq!(get_result_counts(&[]), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_reissued_card_details() {
+        assert!(has_reissued_card_details(&response_card(
+            storage_enums::AccountUpdaterResult::NewAccountNumber,
+            Some("4111111111111111"),
+            None,
+        )));
+        assert!(!has_reissued_card_details(&response_card(
+            storage_enums::AccountUpdaterResult::NewAccountNumber,
+            None,
+            Some(("12", "2030")),
+        )));
+        assert!(has_reissued_card_details(&response_card(
+            storage_enums::AccountUpdaterResult::NewExpiry,
+            None,
+            Some(("12", "2030")),
+        )));
+        assert!(!has_reissued_card_details(&AccountUpdaterResponseCard {
+            expiry_year: None,
+            ..response_card(
+                storage_enums::AccountUpdaterResult::NewExpiry,
+                None,
+                Some(("12", "2030")),
+            )
+        }));
+    }
+
+    #[test]
+    fn test_card_expiry_time() {
+        // A card is valid until the end of its expiry month
+        assert_eq!(
+            cards::get_card_expiry_time(
+                &Secret::new("02".to_string()),
+                &Secret::new("2028".to_string())
+            ),
+            Some(datetime!(2028-03-01 00:00:00))
+        );
+        assert_eq!(
+            cards::get_card_expiry_time(
+                &Secret::new("12".to_string()),
+                &Secret::new("27".to_string())
+            ),
+            Some(datetime!(2028-01-01 00:00:00))
+        );
+        assert!(cards::get_card_expiry_time(
+            &Secret::new("13".to_string()),
+            &Secret::new("2028".to_string())
+        )
+        .is_none());
+        assert!(cards::get_card_expiry_time(
+            &Secret::new("12".to_string()),
+            &Secret::new("year".to_string())
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_expiring_card() {
+        let expiring_before = datetime!(2026-12-01 00:00:00);
+
+        assert!(is_expiring_card(
+            Some(saved_card(Some("11"), Some("2026"))),
+            expiring_before
+        ));
+        assert!(!is_expiring_card(
+            Some(saved_card(Some("12"), Some("2026"))),
+            expiring_before
+        ));
+        assert!(!is_expiring_card(
+            Some(saved_card(None, Some("2026"))),
+            expiring_before
+        ));
+        assert!(!is_expiring_card(None, expiring_before));
+    }
+
+    #[test]
+    fn test_enrollment_response() {
+        let merchant_id = id_type::MerchantId::default();
+        let tracking_data = serde_json::to_value(storage::AccountUpdaterTrackingData {
+            merchant_id: merchant_id.clone(),
+            connector: storage_enums::AccountUpdaterConnector::File,
+        })
+        .unwrap();
+
+        let task = account_updater_task(storage_enums::ProcessTrackerStatus::New, tracking_data);
+        let response = get_enrollment_response(&merchant_id, Some(&task));
+        assert!(response.enabled);
+        assert_eq!(
+            response.connector,
+            Some(storage_enums::AccountUpdaterConnector::File)
+        );
+        assert_eq!(response.next_run_at, Some(datetime!(2026-10-02 00:00:00)));
+
+        // A finished task is a disabled account updater
+        let task = account_updater_task(
+            storage_enums::ProcessTrackerStatus::Finish,
+            task.tracking_data,
+        );
+        let response = get_enrollment_response(&merchant_id, Some(&task));
+        assert!(!response.enabled);
+        assert_eq!(response.connector, None);
+        assert_eq!(response.next_run_at, None);
+
+        assert!(!get_enrollment_response(&merchant_id, None).enabled);
+    }
+}
@@ -0,0 +1,244 @@
+//! Reference account updater which exchanges the batches as files in the file storage.
+//!
+//! A batch is uploaded as `request.ndjson.gz` and an external process, which relays the batch to
+//! the card networks, is expected to upload the results as `response.ndjson.gz` next to it. The
+//! request files hold card numbers, so the file storage must be in PCI scope.
+
+use common_utils::id_type;
+use error_stack::ResultExt;
+use hyperswitch_masking::{PeekInterface, Secret};
+use router_env::logger;
+use serde::{Deserialize, Serialize};
+
+use super::{AccountUpdater, AccountUpdaterRequestCard, AccountUpdaterResponseCard};
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::storage::enums as storage_enums,
+    utils::json_files,
+};
+
+const REQUEST_FILE_NAME: &str = "request.ndjson.gz";
+const RESPONSE_FILE_NAME: &str = "response.ndjson.gz";
+
+pub struct FileAccountUpdater;
+
+#[derive(Serialize)]
+struct RequestLine {
+    reference: String,
+    card_number: String,
+    expiry_month: String,
+    expiry_year: String,
+    reason: storage_enums::AccountUpdaterReason,
+}
+
+#[derive(Deserialize)]
+struct ResponseLine {
+    reference: String,
+    result: storage_enums::AccountUpdaterResult,
+    card_number: Option<cards::CardNumber>,
+    expiry_month: Option<Secret<String>>,
+    expiry_year: Option<Secret<String>>,
+}
+
+#[async_trait::async_trait]
+impl AccountUpdater for FileAccountUpdater {
+    async fn submit_batch(
+        &self,
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        cards: Vec<AccountUpdaterRequestCard>,
+    ) -> RouterResult<()> {
+        let lines = get_request_lines(cards)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize account updater request")?;
+        let file = json_files::encode_ndjson_gzip(&lines)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode account updater request file")?;
+
+        let file_key = get_file_key(state, merchant_id, batch_id, REQUEST_FILE_NAME);
+        state
+            .file_storage_client
+            .upload_file(&file_key, file)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to upload the request file {file_key}"))
+    }
+
+    async fn fetch_results(
+        &self,
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> RouterResult<Option<Vec<AccountUpdaterResponseCard>>> {
+        let file_key = get_file_key(state, merchant_id, batch_id, RESPONSE_FILE_NAME);
+        // The file storage does not tell a missing file apart from other failures, a response
+        // file which cannot be retrieved is looked up again in the next run
+        let file = match state.file_storage_client.retrieve_file(&file_key).await {
+            Ok(file) => file,
+            Err(error) => {
+                logger::debug!(?error, %file_key, "Account updater response file is not available");
+                return Ok(None);
+            }
+        };
+        let lines = json_files::decode_ndjson_gzip(&file)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to decode the response file {file_key}"))?;
+
+        Ok(Some(get_response_cards(lines, &file_key)))
+    }
+
+    async fn cleanup_batch(
+        &self,
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) {
+        for file_name in [REQUEST_FILE_NAME, RESPONSE_FILE_NAME] {
+            let file_key = get_file_key(state, merchant_id, batch_id, file_name);
+            if let Err(error) = state.file_storage_client.delete_file(&file_key).await {
+                logger::error!(?error, %file_key, "Failed to delete account updater file");
+            }
+        }
+    }
+}
+
+fn get_request_lines(
+    cards: Vec<AccountUpdaterRequestCard>,
+) -> Result<Vec<serde_json::Value>, serde_json::Error> {
+    cards
+        .into_iter()
+        .map(|card| {
+            serde_json::to_value(RequestLine {
+                reference: card.payment_method_id,
+                card_number: card.card_number.get_card_no(),
+                expiry_month: card.expiry_month.peek().clone(),
+                expiry_year: card.expiry_year.peek().clone(),
+                reason: card.reason,
+            })
+        })
+        .collect()
+}
+
+/// Parses the results of the response file, skipping the lines which are not valid results
+fn get_response_cards(
+    lines: Vec<serde_json::Value>,
+    file_key: &str,
+) -> Vec<AccountUpdaterResponseCard> {
+    lines
+        .into_iter()
+        .filter_map(|line| {
+            serde_json::from_value::<ResponseLine>(line)
+                .map_err(|error| {
+                    logger::warn!(?error, %file_key, "Skipping invalid account updater result");
+                })
+                .ok()
+        })
+        .map(|line| AccountUpdaterResponseCard {
+            payment_method_id: line.reference,
+            result: line.result,
+            card_number: line.card_number,
+            expiry_month: line.expiry_month,
+            expiry_year: line.expiry_year,
+        })
+        .collect()
+}
+
+fn get_file_key(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    file_name: &str,
+) -> String {
+    format!(
+        "{}/{}/{}/{}/{}",
+        state.conf.account_updater.path_prefix,
+        state.tenant.tenant_id.get_string_repr(),
+        merchant_id.get_string_repr(),
+        batch_id,
+        file_name,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_request_lines() {
+        let lines = get_request_lines(vec![AccountUpdaterRequestCard {
+            payment_method_id: "pm_1".to_string(),
+            card_number: cards::CardNumber::from_str("4242424242424242").unwrap(),
+            expiry_month: Secret::new("11".to_string()),
+            expiry_year: Secret::new("2026".to_string()),
+            reason: storage_enums::AccountUpdaterReason::Expiring,
+        }])
+        .unwrap();
+
+        assert_eq!(
+            lines,
+            vec![serde_json::json!({
+                "reference": "pm_1",
+                "card_number": "4242424242424242",
+                "expiry_month": "11",
+                "expiry_year": "2026",
+                "reason": "expiring",
+            })]
+        );
+    }
+
+    #[test]
+    fn test_response_cards() {
+        let lines = vec![
+            serde_json::json!({
+                "reference": "pm_1",
+                "result": "new_account_number",
+                "card_number": "4111111111111111",
+                "expiry_month": "01",
+                "expiry_year": "2030",
+            }),
+            serde_json::json!({"reference": "pm_2", "result": "account_closed"}),
+            serde_json::json!({"reference": "pm_3", "result": "unknown_result"}),
+            serde_json::json!({"result": "no_change"}),
+        ];
+
+        let results = get_response_cards(lines, "account_updater/response.ndjson.gz");
+
+        // Invalid results are skipped
+        assert_eq!(results.len(), 2);
+        let reissued = results.first().unwrap();
+        assert_eq!(reissued.payment_method_id, "pm_1");
+        assert_eq!(
+            reissued.result,
+            storage_enums::AccountUpdaterResult::NewAccountNumber
+        );
+        assert_eq!(
+            reissued
+                .card_number
+                .as_ref()
+                .map(|card_number| card_number.get_card_no()),
+            Some("4111111111111111".to_string())
+        );
+        assert_eq!(
+            reissued
+                .expiry_year
+                .as_ref()
+                .map(|expiry_year| expiry_year.peek().as_str()),
+            Some("2030")
+        );
+
+        let closed = results.get(1).unwrap();
+        assert_eq!(closed.payment_method_id, "pm_2");
+        assert_eq!(
+            closed.result,
+            storage_enums::AccountUpdaterResult::AccountClosed
+        );
+        assert!(closed.card_number.is_none());
+        assert!(closed.expiry_month.is_none());
+    }
+}
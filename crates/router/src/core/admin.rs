@@ -7,7 +7,7 @@ use api_models::{
 use common_enums::{MerchantAccountType, OrganizationType};
 use common_utils::{
     date_time,
-    ext_traits::{AsyncExt, Encode, OptionExt, ValueExt},
+    ext_traits::{AsyncExt, Encode, OptionExt, StringExt, ValueExt},
     fp_utils, id_type, pii, type_name,
     types::keymanager::{self as km_types, KeyManagerState, ToEncryptable},
 };
@@ -3196,6 +3196,19 @@ pub async fn kv_for_merchant(
             .await
         }
         (false, MerchantStorageScheme::RedisKv) => {
+            let pending_drainer_entries = db
+                .count_pending_drainer_entries_by_merchant_id(&merchant_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to count the pending drainer entries of the merchant")?;
+            if pending_drainer_entries > 0 {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "Kv cannot be disabled while {pending_drainer_entries} entries of the merchant are yet to be drained"
+                    ),
+                })?
+            }
+
             db.update_merchant(
                 merchant_account,
                 storage::MerchantAccountUpdate::StorageSchemeUpdate {
@@ -3283,6 +3296,575 @@ pub async fn check_merchant_account_kv_status(
     ))
 }
 
+/// Number of recently modified payments compared between redis and postgres by default
+#[cfg(feature = "v1")]
+const DEFAULT_STORAGE_SCHEME_VERIFICATION_SAMPLE_SIZE: u16 = 50;
+
+/// Number of times the drainer streams are checked for pending entries of the merchant
+#[cfg(feature = "v1")]
+pub const STORAGE_SCHEME_DRAIN_CHECK_ATTEMPTS: i32 = 60;
+
+/// Interval between the checks for pending drainer entries, in seconds
+#[cfg(feature = "v1")]
+pub const STORAGE_SCHEME_DRAIN_CHECK_INTERVAL: i64 = 5;
+
+#[cfg(feature = "v1")]
+const STORAGE_SCHEME_MIGRATION_WORKFLOW_TAG: &str = "STORAGE_SCHEME_MIGRATION";
+
+#[cfg(feature = "v1")]
+const STORAGE_SCHEME_MIGRATION_WORKFLOW_TASK: &str = "STORAGE_SCHEME_MIGRATION_VERIFY";
+
+/// The last storage scheme migration of a merchant, kept to report its progress and to allow
+/// rolling it back
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct StorageSchemeMigrationRecord {
+    migration_id: String,
+    status: admin_types::StorageSchemeMigrationStatus,
+    previous_storage_scheme: MerchantStorageScheme,
+    storage_scheme: MerchantStorageScheme,
+    /// Whether the migration switches the merchant back to its scheme before the last migration
+    is_rollback: bool,
+    verification_sample_size: u16,
+    pending_drainer_entries: usize,
+    verified_records: usize,
+    mismatches: Vec<admin_types::StorageSchemeMismatch>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    migrated_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct StorageSchemeMigrationTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub migration_id: String,
+}
+
+#[cfg(feature = "v1")]
+fn get_storage_scheme_migration_config_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("storage_scheme_migration_{}", merchant_id.get_string_repr())
+}
+
+#[cfg(feature = "v1")]
+fn get_storage_scheme_migration_response(
+    merchant_id: id_type::MerchantId,
+    record: StorageSchemeMigrationRecord,
+) -> admin_types::StorageSchemeMigrationResponse {
+    admin_types::StorageSchemeMigrationResponse {
+        merchant_id,
+        status: record.status,
+        previous_storage_scheme: record.previous_storage_scheme,
+        storage_scheme: record.storage_scheme,
+        pending_drainer_entries: record.pending_drainer_entries,
+        verified_records: record.verified_records,
+        mismatches: record.mismatches,
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn find_storage_scheme_migration_record(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<StorageSchemeMigrationRecord>> {
+    match db
+        .find_config_by_key(&get_storage_scheme_migration_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct::<StorageSchemeMigrationRecord>("StorageSchemeMigrationRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the storage scheme migration"),
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn save_storage_scheme_migration_record(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    record: &StorageSchemeMigrationRecord,
+) -> RouterResult<()> {
+    let config_key = get_storage_scheme_migration_config_key(merchant_id);
+    match db.delete_config_by_key(&config_key).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to delete the previous storage scheme migration")?;
+    db.insert_config(configs::ConfigNew {
+        key: config_key,
+        config: record
+            .encode_to_string_of_json()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to record the storage scheme migration")?;
+
+    Ok(())
+}
+
+/// Schedules the switch of the storage scheme of the merchant, made once the drainer caught up
+/// with the merchant and, when disabling KV, the redis and postgres copies of the recent payments
+/// agree
+#[cfg(feature = "v1")]
+pub async fn migrate_merchant_storage_scheme(
+    state: SessionState,
+    request: admin_types::StorageSchemeMigrationRequest,
+) -> RouterResponse<admin_types::StorageSchemeMigrationResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &request.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&request.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let storage_scheme = if request.kv_enabled {
+        MerchantStorageScheme::RedisKv
+    } else {
+        MerchantStorageScheme::PostgresOnly
+    };
+    if merchant_account.storage_scheme == storage_scheme {
+        return Ok(service_api::ApplicationResponse::Json(
+            admin_types::StorageSchemeMigrationResponse {
+                merchant_id: request.merchant_id,
+                status: admin_types::StorageSchemeMigrationStatus::Completed,
+                previous_storage_scheme: storage_scheme,
+                storage_scheme,
+                pending_drainer_entries: 0,
+                verified_records: 0,
+                mismatches: Vec::new(),
+            },
+        ));
+    }
+
+    let response = start_storage_scheme_migration(
+        &state,
+        &merchant_account,
+        storage_scheme,
+        request
+            .verification_sample_size
+            .unwrap_or(DEFAULT_STORAGE_SCHEME_VERIFICATION_SAMPLE_SIZE),
+        false,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+/// Retrieves the progress of the last storage scheme migration of the merchant
+#[cfg(feature = "v1")]
+pub async fn retrieve_merchant_storage_scheme_migration(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_types::StorageSchemeMigrationResponse> {
+    let record = find_storage_scheme_migration_record(state.store.as_ref(), &merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No storage scheme migration found for the merchant".to_string(),
+        })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_storage_scheme_migration_response(merchant_id, record),
+    ))
+}
+
+/// Switches the merchant back to the storage scheme it had before its last migration
+#[cfg(feature = "v1")]
+pub async fn rollback_merchant_storage_scheme_migration(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_types::StorageSchemeMigrationResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let record = find_storage_scheme_migration_record(db, &merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No storage scheme migration found for the merchant".to_string(),
+        })?;
+
+    // Only a completed migration switched the storage scheme of the merchant
+    if record.is_rollback || record.status != admin_types::StorageSchemeMigrationStatus::Completed
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The last storage scheme migration of the merchant cannot be rolled back, its status is {:?}",
+                record.status
+            ),
+        }));
+    }
+    if merchant_account.storage_scheme != record.storage_scheme {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The storage scheme of the merchant was changed after its last migration"
+                .to_string(),
+        }));
+    }
+
+    let response = start_storage_scheme_migration(
+        &state,
+        &merchant_account,
+        record.previous_storage_scheme,
+        DEFAULT_STORAGE_SCHEME_VERIFICATION_SAMPLE_SIZE,
+        true,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+/// Records the migration and schedules the task which drains and verifies the entries of the
+/// merchant, the storage scheme of the merchant is left unchanged until they are
+#[cfg(feature = "v1")]
+async fn start_storage_scheme_migration(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    storage_scheme: MerchantStorageScheme,
+    verification_sample_size: u16,
+    is_rollback: bool,
+) -> RouterResult<admin_types::StorageSchemeMigrationResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id().to_owned();
+
+    if find_storage_scheme_migration_record(db, &merchant_id)
+        .await?
+        .is_some_and(|record| {
+            record.status == admin_types::StorageSchemeMigrationStatus::InProgress
+        })
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A storage scheme migration of the merchant is in progress".to_string(),
+        }));
+    }
+    if storage_scheme == MerchantStorageScheme::RedisKv
+        && state.conf.as_ref().is_kv_soft_kill_mode()
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Kv cannot be enabled when application is in soft_kill_mode".to_owned(),
+        })?
+    }
+
+    let record = StorageSchemeMigrationRecord {
+        migration_id: common_utils::generate_id(consts::ID_LENGTH, "ssm"),
+        status: admin_types::StorageSchemeMigrationStatus::InProgress,
+        previous_storage_scheme: merchant_account.storage_scheme,
+        storage_scheme,
+        is_rollback,
+        verification_sample_size,
+        pending_drainer_entries: 0,
+        verified_records: 0,
+        mismatches: Vec::new(),
+        migrated_at: date_time::now(),
+    };
+    save_storage_scheme_migration_record(db, &merchant_id, &record).await?;
+    add_storage_scheme_migration_task(state, &merchant_id, &record.migration_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the storage scheme migration verification")?;
+
+    Ok(get_storage_scheme_migration_response(merchant_id, record))
+}
+
+#[cfg(feature = "v1")]
+async fn add_storage_scheme_migration_task(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    migration_id: &str,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::StorageSchemeMigrationWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        STORAGE_SCHEME_MIGRATION_WORKFLOW_TASK,
+        migration_id,
+        merchant_id,
+    );
+    let tracking_data = StorageSchemeMigrationTrackingData {
+        merchant_id: merchant_id.clone(),
+        migration_id: migration_id.to_owned(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        STORAGE_SCHEME_MIGRATION_WORKFLOW_TASK,
+        runner,
+        [STORAGE_SCHEME_MIGRATION_WORKFLOW_TAG],
+        tracking_data,
+        None,
+        date_time::now(),
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Verifies a storage scheme migration once the drainer has caught up with the merchant, and only
+/// then switches the storage scheme of the merchant. When KV is disabled, the redis and postgres
+/// copies of the recent payments are compared first, and the merchant is left on KV if they do
+/// not agree.
+///
+/// Returns the status of the migration, which remains in progress while entries of the merchant
+/// are pending and the last attempt is not reached, or `None` if the migration was superseded.
+#[cfg(feature = "v1")]
+pub async fn verify_storage_scheme_migration(
+    state: &SessionState,
+    tracking_data: &StorageSchemeMigrationTrackingData,
+    is_last_attempt: bool,
+) -> RouterResult<Option<admin_types::StorageSchemeMigrationStatus>> {
+    let db = state.store.as_ref();
+    let merchant_id = &tracking_data.merchant_id;
+    let Some(mut record) = find_storage_scheme_migration_record(db, merchant_id)
+        .await?
+        .filter(|record| {
+            record.migration_id == tracking_data.migration_id
+                && record.status == admin_types::StorageSchemeMigrationStatus::InProgress
+        })
+    else {
+        return Ok(None);
+    };
+
+    record.pending_drainer_entries = db
+        .count_pending_drainer_entries_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the pending drainer entries of the merchant")?;
+
+    // Only the redis copies can drift from postgres, which matters once reads move to postgres
+    if record.pending_drainer_entries == 0
+        && record.storage_scheme == MerchantStorageScheme::PostgresOnly
+    {
+        let (verified_records, mismatches) =
+            compare_recent_payment_records(db, merchant_id, record.verification_sample_size)
+                .await?;
+        record.verified_records = verified_records;
+        record.mismatches = mismatches;
+    }
+
+    record.status = get_storage_scheme_migration_status(
+        record.pending_drainer_entries,
+        !record.mismatches.is_empty(),
+        record.is_rollback,
+        is_last_attempt,
+    );
+    if is_storage_scheme_switched(record.status) {
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        db.update_merchant(
+            merchant_account,
+            storage::MerchantAccountUpdate::StorageSchemeUpdate {
+                storage_scheme: record.storage_scheme,
+            },
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to switch merchant_storage_scheme")?;
+    }
+
+    save_storage_scheme_migration_record(db, merchant_id, &record).await?;
+
+    Ok(Some(record.status))
+}
+
+/// Status of a storage scheme migration once the pending drainer entries of the merchant are
+/// counted and, if none are pending, its redis and postgres copies are compared. The migration
+/// stays in progress while entries are pending and the last attempt is not reached.
+#[cfg(feature = "v1")]
+fn get_storage_scheme_migration_status(
+    pending_drainer_entries: usize,
+    has_mismatches: bool,
+    is_rollback: bool,
+    is_last_attempt: bool,
+) -> admin_types::StorageSchemeMigrationStatus {
+    if pending_drainer_entries > 0 {
+        if is_last_attempt {
+            admin_types::StorageSchemeMigrationStatus::DrainPending
+        } else {
+            admin_types::StorageSchemeMigrationStatus::InProgress
+        }
+    } else if has_mismatches {
+        admin_types::StorageSchemeMigrationStatus::VerificationFailed
+    } else if is_rollback {
+        admin_types::StorageSchemeMigrationStatus::RolledBack
+    } else {
+        admin_types::StorageSchemeMigrationStatus::Completed
+    }
+}
+
+/// Whether the storage scheme of the merchant is switched when a migration reaches the status
+#[cfg(feature = "v1")]
+fn is_storage_scheme_switched(status: admin_types::StorageSchemeMigrationStatus) -> bool {
+    matches!(
+        status,
+        admin_types::StorageSchemeMigrationStatus::Completed
+            | admin_types::StorageSchemeMigrationStatus::RolledBack
+    )
+}
+
+/// Compares the redis and postgres copies of the recently modified payments of the merchant,
+/// returning the number of records compared and the records which differ
+#[cfg(feature = "v1")]
+async fn compare_recent_payment_records(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    verification_sample_size: u16,
+) -> RouterResult<(usize, Vec<admin_types::StorageSchemeMismatch>)> {
+    let mut verified_records = 0usize;
+    let mut mismatches = Vec::new();
+    let payment_intents = db
+        .list_recently_modified_payment_intents(merchant_id, i64::from(verification_sample_size))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the recently modified payments of the merchant")?;
+
+    for payment_intent in payment_intents {
+        let kv_records = db
+            .find_kv_payment_records(merchant_id, &payment_intent.payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the redis copies of the payment")?;
+        let database_records = db
+            .find_database_payment_records(merchant_id, &payment_intent.payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the postgres copies of the payment")?;
+
+        // Records missing in redis have expired there, and only postgres holds them
+        if let Some(kv_payment_intent) = &kv_records.payment_intent {
+            verified_records = verified_records.saturating_add(1);
+            mismatches.extend(get_storage_scheme_mismatch(
+                "payment_intent",
+                kv_payment_intent.payment_id.get_string_repr(),
+                kv_payment_intent,
+                database_records.payment_intent.as_ref(),
+            ));
+        }
+        for kv_payment_attempt in &kv_records.payment_attempts {
+            verified_records = verified_records.saturating_add(1);
+            mismatches.extend(get_storage_scheme_mismatch(
+                "payment_attempt",
+                &kv_payment_attempt.attempt_id,
+                kv_payment_attempt,
+                database_records
+                    .payment_attempts
+                    .iter()
+                    .find(|payment_attempt| {
+                        payment_attempt.attempt_id == kv_payment_attempt.attempt_id
+                    }),
+            ));
+        }
+        for kv_refund in &kv_records.refunds {
+            verified_records = verified_records.saturating_add(1);
+            mismatches.extend(get_storage_scheme_mismatch(
+                "refund",
+                &kv_refund.refund_id,
+                kv_refund,
+                database_records
+                    .refunds
+                    .iter()
+                    .find(|refund| refund.refund_id == kv_refund.refund_id),
+            ));
+        }
+    }
+
+    Ok((verified_records, mismatches))
+}
+
+/// Compares the redis copy of a record with its postgres copy, field by field
+#[cfg(feature = "v1")]
+fn get_storage_scheme_mismatch<T: serde::Serialize>(
+    table: &str,
+    record_id: &str,
+    kv_record: &T,
+    database_record: Option<&T>,
+) -> Option<admin_types::StorageSchemeMismatch> {
+    let differences = match database_record {
+        None => vec!["missing_in_postgres".to_string()],
+        Some(database_record) => match (
+            serde_json::to_value(kv_record),
+            serde_json::to_value(database_record),
+        ) {
+            (
+                Ok(serde_json::Value::Object(kv_fields)),
+                Ok(serde_json::Value::Object(database_fields)),
+            ) => kv_fields
+                .iter()
+                .filter(|(field, kv_value)| {
+                    !database_fields.get(*field).is_some_and(|database_value| {
+                        is_same_stored_value(kv_value, database_value)
+                    })
+                })
+                .map(|(field, _)| field.clone())
+                .collect(),
+            _ => vec!["unserializable".to_string()],
+        },
+    };
+
+    (!differences.is_empty()).then(|| admin_types::StorageSchemeMismatch {
+        table: table.to_string(),
+        record_id: record_id.to_string(),
+        differences,
+    })
+}
+
+/// Postgres stores timestamps with microsecond precision, while the redis copies keep the
+/// nanoseconds of the timestamps
+#[cfg(feature = "v1")]
+fn is_same_stored_value(kv_value: &serde_json::Value, database_value: &serde_json::Value) -> bool {
+    let parse_timestamp = |value: &str| {
+        time::OffsetDateTime::parse(
+            value,
+            &time::format_description::well_known::Iso8601::DEFAULT,
+        )
+        .ok()
+        .and_then(|timestamp| {
+            timestamp
+                .replace_nanosecond(timestamp.nanosecond() / 1000 * 1000)
+                .ok()
+        })
+    };
+
+    kv_value == database_value
+        || match (kv_value, database_value) {
+            (serde_json::Value::String(kv_value), serde_json::Value::String(database_value)) => {
+                parse_timestamp(kv_value)
+                    .zip(parse_timestamp(database_value))
+                    .is_some_and(|(kv_timestamp, database_timestamp)| {
+                        kv_timestamp == database_timestamp
+                    })
+            }
+            _ => false,
+        }
+}
+
 pub fn get_frm_config_as_secret(
     frm_configs: Option<Vec<api_models::admin::FrmConfigs>>,
 ) -> Option<Vec<Secret<serde_json::Value>>> {
@@ -4844,3 +5426,34 @@ impl From<&types::MerchantAccountData> for pm_auth_types::RecipientCreateRequest
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_scheme_is_switched_after_drain_and_verification() {
+        use admin_types::StorageSchemeMigrationStatus as Status;
+
+        // Entries of the merchant are pending in the drainer streams
+        let status = get_storage_scheme_migration_status(3, false, false, false);
+        assert_eq!(status, Status::InProgress);
+        assert!(!is_storage_scheme_switched(status));
+        let status = get_storage_scheme_migration_status(3, false, false, true);
+        assert_eq!(status, Status::DrainPending);
+        assert!(!is_storage_scheme_switched(status));
+
+        // Drained, but redis and postgres do not agree
+        let status = get_storage_scheme_migration_status(0, true, false, false);
+        assert_eq!(status, Status::VerificationFailed);
+        assert!(!is_storage_scheme_switched(status));
+
+        // Drained and verified
+        let status = get_storage_scheme_migration_status(0, false, false, false);
+        assert_eq!(status, Status::Completed);
+        assert!(is_storage_scheme_switched(status));
+        let status = get_storage_scheme_migration_status(0, false, true, true);
+        assert_eq!(status, Status::RolledBack);
+        assert!(is_storage_scheme_switched(status));
+    }
+}
@@ -1,14 +1,15 @@
-use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
 use api_models::cards_info as cards_info_api_types;
-use common_utils::fp_utils::when;
+use common_utils::{ext_traits::ValueExt, fp_utils::when};
 use csv::Reader;
-use diesel_models::cards_info as card_info_models;
+use diesel_models::{bin_range as bin_range_models, cards_info as card_info_models};
 use error_stack::{report, ResultExt};
-use hyperswitch_domain_models::cards_info;
+use hyperswitch_domain_models::{bin_range, cards_info};
 use rdkafka::message::ToBytes;
 use router_env::{instrument, tracing};
 
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payments::helpers,
@@ -19,6 +20,7 @@ use crate::{
         domain,
         transformers::{ForeignFrom, ForeignInto},
     },
+    utils,
 };
 
 fn verify_iin_length(card_iin: &str) -> Result<(), errors::ApiErrorResponse> {
@@ -148,6 +150,154 @@ pub async fn migrate_cards_info(
     Ok(ApplicationResponse::Json(result))
 }
 
+#[derive(Debug, MultipartForm)]
+pub struct BinRangeUploadForm {
+    #[multipart(limit = "20MB")]
+    pub file: Bytes,
+    pub source: Text<String>,
+    pub format: Option<Text<cards_info_api_types::BinFileFormat>>,
+}
+
+fn parse_bin_file(
+    data: &[u8],
+    format: cards_info_api_types::BinFileFormat,
+) -> csv::Result<Vec<cards_info_api_types::BinRangeRecord>> {
+    let mut csv_reader = Reader::from_reader(data);
+    let records = match format {
+        cards_info_api_types::BinFileFormat::Generic => csv_reader
+            .deserialize::<cards_info_api_types::BinRangeRecord>()
+            .collect::<csv::Result<Vec<_>>>()?,
+        cards_info_api_types::BinFileFormat::Binlist => csv_reader
+            .deserialize::<cards_info_api_types::BinlistRecord>()
+            .map(|result| result.map(cards_info_api_types::BinRangeRecord::from))
+            .collect::<csv::Result<Vec<_>>>()?,
+    };
+
+    Ok(records
+        .into_iter()
+        .zip(1..)
+        .map(
+            |(record, line_number)| cards_info_api_types::BinRangeRecord {
+                line_number: Some(line_number),
+                ..record
+            },
+        )
+        .collect())
+}
+
+pub fn get_bin_range_upload_request(
+    form: BinRangeUploadForm,
+) -> Result<cards_info_api_types::BinRangeUploadRequest, errors::ApiErrorResponse> {
+    let source = form.source.into_inner();
+    when(source.trim().is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "source must not be empty".to_string(),
+        })
+    })?;
+    let format = form.format.map(Text::into_inner).unwrap_or_default();
+
+    match parse_bin_file(form.file.data.to_bytes(), format) {
+        Ok(records) => Ok(cards_info_api_types::BinRangeUploadRequest { source, records }),
+        Err(e) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Normalizes the network names used by the BIN file providers, such as `American Express`, to
+/// the names accepted by the card network enum.
+fn parse_bin_range_card_network(
+    card_network: Option<String>,
+) -> Result<Option<common_enums::CardNetwork>, String> {
+    card_network
+        .filter(|card_network| !card_network.trim().is_empty())
+        .map(|card_network| {
+            let normalized_card_network = card_network
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .collect::<String>()
+                .to_uppercase();
+            serde_json::Value::String(normalized_card_network)
+                .parse_value("CardNetwork")
+                .map_err(|_| format!("Unsupported card network {card_network}"))
+        })
+        .transpose()
+}
+
+fn build_bin_range(
+    source: &str,
+    record: cards_info_api_types::BinRangeRecord,
+) -> Result<bin_range_models::BinRangeNew, String> {
+    let (range_low, range_high) =
+        bin_range::normalize_bounds(&record.range_low, &record.range_high).ok_or_else(|| {
+            format!(
+                "Range bounds must be numeric with the same length between {} and {} digits",
+                bin_range::MIN_BIN_RANGE_LENGTH,
+                bin_range::MAX_BIN_RANGE_LENGTH
+            )
+        })?;
+    when(range_low > range_high, || {
+        Err("Lower bound of the range is greater than its upper bound".to_string())
+    })?;
+    let card_network = parse_bin_range_card_network(record.card_network)?;
+
+    Ok(bin_range_models::BinRangeNew {
+        bin_range_id: utils::generate_id(consts::ID_LENGTH, "bin"),
+        range_low: record.range_low,
+        range_high: record.range_high,
+        card_issuer: record.card_issuer,
+        card_network,
+        card_type: record.card_type.map(|card_type| card_type.to_uppercase()),
+        card_subtype: record.card_subtype,
+        card_issuing_country: record.card_issuing_country,
+        country_code: record.country_code,
+        bank_code: record.bank_code,
+        source: source.to_string(),
+        created_at: common_utils::date_time::now(),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn upload_bin_ranges(
+    state: routes::SessionState,
+    request: cards_info_api_types::BinRangeUploadRequest,
+) -> RouterResponse<cards_info_api_types::BinRangeUploadResponse> {
+    let db = state.store.as_ref();
+
+    let mut bin_ranges = Vec::new();
+    let mut rejected = Vec::new();
+    for record in request.records {
+        let (line_number, range_low, range_high) = (
+            record.line_number,
+            record.range_low.clone(),
+            record.range_high.clone(),
+        );
+        match build_bin_range(&request.source, record) {
+            Ok(bin_range) => bin_ranges.push(bin_range),
+            Err(error) => rejected.push(cards_info_api_types::BinRangeRejectedRecord {
+                line_number,
+                range_low,
+                range_high,
+                error,
+            }),
+        }
+    }
+
+    let loaded_count = db
+        .replace_bin_ranges_by_source(&request.source, bin_ranges)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to replace the BIN ranges of the source")?;
+
+    Ok(ApplicationResponse::Json(
+        cards_info_api_types::BinRangeUploadResponse {
+            source: request.source,
+            loaded_count,
+            rejected,
+        },
+    ))
+}
+
 pub trait State {}
 pub trait TransitionTo<S: State> {}
 // Available states for card info migration
@@ -0,0 +1,801 @@
+use api_models::customers as customer_models;
+use common_utils::{ext_traits::Encode, id_type};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use strum::IntoEnumIterator;
+
+use crate::{
+    consts,
+    core::{
+        customers,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::kafka_store::TenantID,
+    routes::SessionState,
+    services::{self, kafka::customer_erasure::KafkaCustomerErasureEvent},
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const CUSTOMER_ERASURE_WORKFLOW_TAG: &str = "CUSTOMER_ERASURE";
+const CUSTOMER_ERASURE_WORKFLOW_TASK: &str = "CUSTOMER_ERASURE_EXECUTE";
+
+/// Exports everything held about the customer into a decrypted archive, stored through the file
+/// storage backend and downloadable until the archive is deleted from the storage.
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn export_customer_data(
+    state: SessionState,
+    platform: domain::Platform,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<customer_models::CustomerDataRequestResponse> {
+    let provider = platform.get_provider();
+    let customer = state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &customer_id,
+            provider.get_account().get_id(),
+            provider.get_key_store(),
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let customer_data_request = insert_customer_data_request(
+        &state,
+        &platform,
+        customer_id,
+        storage_enums::CustomerDataRequestType::Export,
+        storage_enums::CustomerDataRequestStatus::Processing,
+    )
+    .await?;
+
+    let file_key = get_customer_data_export_file_key(
+        provider.get_account().get_id(),
+        &customer_data_request.request_id,
+    );
+    let export_result = async {
+        let archive = build_customer_data_archive(&state, provider, customer).await?;
+        let archive = archive
+            .encode_to_vec()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the customer data archive")?;
+        state
+            .file_storage_client
+            .upload_file(&file_key, archive)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to upload the customer data archive")
+    }
+    .await;
+
+    let customer_data_request_update = match &export_result {
+        Ok(()) => storage::CustomerDataRequestUpdate::Completed {
+            file_id: Some(file_key),
+        },
+        Err(error) => storage::CustomerDataRequestUpdate::StatusUpdate {
+            status: storage_enums::CustomerDataRequestStatus::Failed,
+            error_message: Some(error.current_context().to_string()),
+        },
+    };
+    let customer_data_request = state
+        .store
+        .update_customer_data_request(customer_data_request, customer_data_request_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the customer data request")?;
+    export_result?;
+
+    Ok(services::ApplicationResponse::Json(
+        customer_models::CustomerDataRequestResponse::foreign_from(customer_data_request),
+    ))
+}
+
+/// Returns the archive of a completed export
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn retrieve_customer_data_archive(
+    state: SessionState,
+    platform: domain::Platform,
+    request: customer_models::CustomerDataRequestId,
+) -> RouterResponse<serde_json::Value> {
+    let customer_data_request = find_customer_data_request(&state, &platform, &request).await?;
+    let file_key = get_customer_data_archive_file_key(customer_data_request).ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "The archive is only available for completed exports".to_string(),
+        },
+    )?;
+
+    let archive = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the customer data archive")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        archive,
+        mime::APPLICATION_JSON,
+    )))
+}
+
+/// Schedules the erasure of everything held about the customer. The erasure is carried out by the
+/// scheduler, its progress can be followed through the returned request.
+#[instrument(skip(state))]
+pub async fn erase_customer_data(
+    state: SessionState,
+    platform: domain::Platform,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<customer_models::CustomerDataRequestResponse> {
+    let provider = platform.get_provider();
+    state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &customer_id,
+            provider.get_account().get_id(),
+            provider.get_key_store(),
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    // The erasure would break the recurring payments of the active mandates
+    let has_active_mandate = state
+        .store
+        .find_mandate_by_merchant_id_customer_id(provider.get_account().get_id(), &customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the customer")?
+        .iter()
+        .any(|mandate| mandate.mandate_status == storage_enums::MandateStatus::Active);
+    if has_active_mandate {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Customer has active mandate/subsciption".to_string(),
+        }));
+    }
+
+    let customer_data_request = insert_customer_data_request(
+        &state,
+        &platform,
+        customer_id,
+        storage_enums::CustomerDataRequestType::Erasure,
+        storage_enums::CustomerDataRequestStatus::Pending,
+    )
+    .await?;
+
+    add_customer_erasure_task(&state, &customer_data_request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the customer erasure")?;
+
+    Ok(services::ApplicationResponse::Json(
+        customer_models::CustomerDataRequestResponse::foreign_from(customer_data_request),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_customer_data_requests(
+    state: SessionState,
+    platform: domain::Platform,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<customer_models::CustomerDataRequestListResponse> {
+    let customer_data_requests = state
+        .store
+        .list_customer_data_requests_by_merchant_id_customer_id(
+            platform.get_provider().get_account().get_id(),
+            &customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the customer data requests")?;
+
+    Ok(services::ApplicationResponse::Json(
+        customer_models::CustomerDataRequestListResponse {
+            data: customer_data_requests
+                .into_iter()
+                .map(customer_models::CustomerDataRequestResponse::foreign_from)
+                .collect(),
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_customer_data_request(
+    state: SessionState,
+    platform: domain::Platform,
+    request: customer_models::CustomerDataRequestId,
+) -> RouterResponse<customer_models::CustomerDataRequestResponse> {
+    let customer_data_request = find_customer_data_request(&state, &platform, &request).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        customer_models::CustomerDataRequestResponse::foreign_from(customer_data_request),
+    ))
+}
+
+/// Executes the steps of an erasure that have not been completed yet. The completed steps are
+/// recorded in the tracking data of the process after each step, so that a task picked up again
+/// after an interruption does not repeat them.
+#[instrument(skip_all, fields(request_id = %tracking_data.request_id))]
+pub async fn execute_customer_erasure(
+    state: &SessionState,
+    platform: &domain::Platform,
+    customer_data_request: storage::CustomerDataRequest,
+    mut tracking_data: storage::CustomerErasureTrackingData,
+    mut process: storage::ProcessTracker,
+) -> RouterResult<storage::ProcessTracker> {
+    let customer_data_request = state
+        .store
+        .update_customer_data_request(
+            customer_data_request,
+            storage::CustomerDataRequestUpdate::StatusUpdate {
+                status: storage_enums::CustomerDataRequestStatus::Processing,
+                error_message: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the customer data request")?;
+
+    let erasure_result = async {
+        for step in storage_enums::CustomerErasureStep::iter() {
+            if tracking_data.completed_steps.contains(&step) {
+                continue;
+            }
+
+            execute_customer_erasure_step(state, platform, &tracking_data, step).await?;
+            logger::info!(%step, "Completed customer erasure step");
+
+            tracking_data.completed_steps.push(step);
+            process = state
+                .store
+                .as_scheduler()
+                .update_process(
+                    process.clone(),
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(
+                            tracking_data
+                                .encode_to_value()
+                                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                        ),
+                        business_status: None,
+                        status: None,
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to record the customer erasure progress")?;
+        }
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(())
+    }
+    .await;
+
+    let customer_data_request_update = match &erasure_result {
+        Ok(()) => storage::CustomerDataRequestUpdate::Completed { file_id: None },
+        Err(error) => storage::CustomerDataRequestUpdate::StatusUpdate {
+            status: storage_enums::CustomerDataRequestStatus::Failed,
+            error_message: Some(error.current_context().to_string()),
+        },
+    };
+    state
+        .store
+        .update_customer_data_request(customer_data_request, customer_data_request_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the customer data request")?;
+    erasure_result?;
+
+    Ok(process)
+}
+
+async fn execute_customer_erasure_step(
+    state: &SessionState,
+    platform: &domain::Platform,
+    tracking_data: &storage::CustomerErasureTrackingData,
+    step: storage_enums::CustomerErasureStep,
+) -> RouterResult<()> {
+    let provider = platform.get_provider();
+    let key_manager_state = &state.into();
+
+    match step {
+        storage_enums::CustomerErasureStep::PaymentMethods => {
+            customers::delete_customer_payment_methods(state, provider, &tracking_data.customer_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to delete the payment methods of the customer")
+        }
+        storage_enums::CustomerErasureStep::Addresses => customers::redact_customer_addresses(
+            &*state.store,
+            provider,
+            key_manager_state,
+            &tracking_data.customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to redact the addresses of the customer"),
+        storage_enums::CustomerErasureStep::Customer => {
+            let customer = state
+                .store
+                .find_customer_by_customer_id_merchant_id(
+                    &tracking_data.customer_id,
+                    provider.get_account().get_id(),
+                    provider.get_key_store(),
+                    provider.get_account().storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the customer")?;
+
+            customers::redact_customer_record(
+                &*state.store,
+                provider,
+                None,
+                key_manager_state,
+                customer,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to redact the customer")
+        }
+        storage_enums::CustomerErasureStep::Tombstone => state
+            .event_handler
+            .publish_event(&KafkaCustomerErasureEvent {
+                request_id: &tracking_data.request_id,
+                merchant_id: &tracking_data.merchant_id,
+                customer_id: &tracking_data.customer_id,
+                erased_at: common_utils::date_time::now().assume_utc(),
+                tenant_id: TenantID(state.tenant.tenant_id.get_string_repr().to_owned()),
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to publish the customer erasure tombstone"),
+    }
+}
+
+#[cfg(feature = "olap")]
+async fn build_customer_data_archive(
+    state: &SessionState,
+    provider: &domain::Provider,
+    customer: domain::Customer,
+) -> RouterResult<customer_models::CustomerDataArchive> {
+    let db = &*state.store;
+    let merchant_id = provider.get_account().get_id();
+    let key_store = provider.get_key_store();
+    let storage_scheme = provider.get_account().storage_scheme;
+    let customer_id = customer.customer_id.clone();
+
+    let address = match &customer.address_id {
+        Some(address_id) => Some(api_models::payments::AddressDetails::from(
+            db.find_address_by_address_id(address_id, key_store)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the address of the customer")?,
+        )),
+        None => None,
+    };
+    let customer = api::customers::CustomerResponse::try_from((customer, address))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert domain customer to CustomerResponse")?
+        .into_inner();
+
+    let payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_store,
+            &customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment methods of the customer")?,
+    };
+
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the customer")?;
+
+    let payment_intents = find_customer_payment_intents(state, provider, &customer_id).await?;
+
+    let mut payments = Vec::with_capacity(payment_intents.len());
+    let mut disputes = Vec::new();
+    let mut events = db
+        .list_initial_events_by_merchant_id_primary_object_id(
+            merchant_id,
+            customer_id.get_string_repr(),
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the events of the customer")?;
+
+    for payment_intent in payment_intents {
+        let find_address = |address_id: Option<String>| async {
+            match address_id {
+                Some(address_id) => db
+                    .find_address_by_merchant_id_payment_id_address_id(
+                        merchant_id,
+                        &payment_intent.payment_id,
+                        &address_id,
+                        key_store,
+                        storage_scheme,
+                    )
+                    .await
+                    .map(|address| Some(address.address.into()))
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the address of the payment"),
+                None => Ok(None),
+            }
+        };
+        let billing = find_address(payment_intent.billing_address_id.clone()).await?;
+        let shipping = find_address(payment_intent.shipping_address_id.clone()).await?;
+
+        disputes.extend(
+            db.find_disputes_by_merchant_id_payment_id(merchant_id, &payment_intent.payment_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the disputes of the payment")?
+                .into_iter()
+                .map(api_models::disputes::DisputeResponse::foreign_from),
+        );
+        events.extend(
+            db.list_initial_events_by_merchant_id_primary_object_id(
+                merchant_id,
+                payment_intent.payment_id.get_string_repr(),
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the events of the payment")?,
+        );
+
+        payments.push(customer_models::CustomerDataArchivePayment {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            amount: payment_intent.amount,
+            currency: payment_intent.currency,
+            description: payment_intent.description,
+            billing,
+            shipping,
+            created_at: payment_intent.created_at,
+        });
+    }
+
+    Ok(customer_models::CustomerDataArchive {
+        customer,
+        payment_methods: payment_methods
+            .into_iter()
+            .map(
+                |payment_method| customer_models::CustomerDataArchivePaymentMethod {
+                    payment_method_id: payment_method.payment_method_id,
+                    payment_method: payment_method.payment_method,
+                    payment_method_type: payment_method.payment_method_type,
+                    payment_method_data: payment_method
+                        .payment_method_data
+                        .map(|data| data.into_inner()),
+                    billing_address: payment_method
+                        .payment_method_billing_address
+                        .map(|address| address.into_inner()),
+                    customer_acceptance: payment_method.customer_acceptance,
+                    created_at: payment_method.created_at,
+                    last_used_at: payment_method.last_used_at,
+                },
+            )
+            .collect(),
+        mandates: mandates
+            .into_iter()
+            .map(|mandate| customer_models::CustomerDataArchiveMandate {
+                mandate_id: mandate.mandate_id,
+                payment_method_id: mandate.payment_method_id,
+                status: mandate.mandate_status,
+                mandate_type: mandate.mandate_type,
+                customer_ip_address: mandate.customer_ip_address,
+                customer_user_agent: mandate.customer_user_agent,
+                customer_accepted_at: mandate.customer_accepted_at,
+                created_at: mandate.created_at,
+            })
+            .collect(),
+        payments,
+        disputes,
+        events: events
+            .into_iter()
+            .map(|event| customer_models::CustomerDataArchiveEvent {
+                event_id: event.event_id,
+                event_type: event.event_type,
+                primary_object_id: event.primary_object_id,
+                primary_object_type: event.primary_object_type,
+                is_webhook_notified: event.is_webhook_notified,
+                created_at: event.created_at,
+            })
+            .collect(),
+        exported_at: common_utils::date_time::now(),
+    })
+}
+
+/// Fetches every payment of the customer, a page at a time
+#[cfg(feature = "olap")]
+async fn find_customer_payment_intents(
+    state: &SessionState,
+    provider: &domain::Provider,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<Vec<storage::PaymentIntent>> {
+    let mut payment_intents = Vec::new();
+    loop {
+        let constraints = hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints::List(
+            Box::new(hyperswitch_domain_models::payments::payment_intent::PaymentIntentListParams {
+                offset: u32::try_from(payment_intents.len())
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                starting_at: None,
+                ending_at: None,
+                amount_filter: None,
+                connector: None,
+                currency: None,
+                status: None,
+                payment_method: None,
+                payment_method_type: None,
+                authentication_type: None,
+                merchant_connector_id: None,
+                profile_id: None,
+                customer_id: Some(customer_id.clone()),
+                starting_after_id: None,
+                ending_before_id: None,
+                limit: Some(common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1),
+                order: Default::default(),
+                card_network: None,
+                card_discovery: None,
+                merchant_order_reference_id: None,
+                customer_email: None,
+            }),
+        );
+        let page = state
+            .store
+            .filter_payment_intent_by_constraints(
+                provider.get_account().get_id(),
+                &constraints,
+                provider.get_key_store(),
+                provider.get_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payments of the customer")?;
+
+        let is_last_page = is_last_payment_intents_page(page.len());
+        payment_intents.extend(page);
+        if is_last_page {
+            return Ok(payment_intents);
+        }
+    }
+}
+
+/// A page shorter than the page size is the last page of the payments of the customer
+#[cfg(feature = "olap")]
+fn is_last_payment_intents_page(page_len: usize) -> bool {
+    u32::try_from(page_len)
+        .is_ok_and(|page_len| page_len < common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1)
+}
+
+/// The archive of an export is stored under the merchant, keyed by the request
+#[cfg(feature = "olap")]
+fn get_customer_data_export_file_key(
+    merchant_id: &id_type::MerchantId,
+    request_id: &str,
+) -> String {
+    format!(
+        "{}/customer_data_exports/{}",
+        merchant_id.get_string_repr(),
+        request_id
+    )
+}
+
+/// Only completed exports have an archive that can be downloaded
+#[cfg(feature = "olap")]
+fn get_customer_data_archive_file_key(
+    customer_data_request: storage::CustomerDataRequest,
+) -> Option<String> {
+    customer_data_request.file_id.filter(|_| {
+        customer_data_request.request_type == storage_enums::CustomerDataRequestType::Export
+            && customer_data_request.status == storage_enums::CustomerDataRequestStatus::Completed
+    })
+}
+
+async fn insert_customer_data_request(
+    state: &SessionState,
+    platform: &domain::Platform,
+    customer_id: id_type::CustomerId,
+    request_type: storage_enums::CustomerDataRequestType,
+    status: storage_enums::CustomerDataRequestStatus,
+) -> RouterResult<storage::CustomerDataRequest> {
+    let now = common_utils::date_time::now();
+    state
+        .store
+        .insert_customer_data_request(storage::CustomerDataRequestNew {
+            request_id: common_utils::generate_id(consts::ID_LENGTH, "cdr"),
+            merchant_id: platform.get_provider().get_account().get_id().clone(),
+            customer_id,
+            request_type,
+            status,
+            file_id: None,
+            requested_by: platform
+                .get_initiator()
+                .and_then(|initiator| initiator.to_created_by())
+                .map(|requested_by| requested_by.to_string()),
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+            completed_at: None,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the customer data request")
+}
+
+async fn find_customer_data_request(
+    state: &SessionState,
+    platform: &domain::Platform,
+    request: &customer_models::CustomerDataRequestId,
+) -> RouterResult<storage::CustomerDataRequest> {
+    state
+        .store
+        .find_customer_data_request_by_merchant_id_request_id(
+            platform.get_provider().get_account().get_id(),
+            &request.request_id,
+        )
+        .await
+        .ok()
+        .filter(|customer_data_request| customer_data_request.customer_id == request.customer_id)
+        .ok_or(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("customer data request {} not found", request.request_id),
+        }))
+}
+
+async fn add_customer_erasure_task(
+    state: &SessionState,
+    customer_data_request: &storage::CustomerDataRequest,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::CustomerErasureWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        CUSTOMER_ERASURE_WORKFLOW_TASK,
+        &customer_data_request.request_id,
+        &customer_data_request.merchant_id,
+    );
+    let tracking_data = storage::CustomerErasureTrackingData {
+        request_id: customer_data_request.request_id.clone(),
+        merchant_id: customer_data_request.merchant_id.clone(),
+        customer_id: customer_data_request.customer_id.clone(),
+        completed_steps: Vec::new(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CUSTOMER_ERASURE_WORKFLOW_TASK,
+        runner,
+        [CUSTOMER_ERASURE_WORKFLOW_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+impl ForeignFrom<storage::CustomerDataRequest> for customer_models::CustomerDataRequestResponse {
+    fn foreign_from(customer_data_request: storage::CustomerDataRequest) -> Self {
+        Self {
+            request_id: customer_data_request.request_id,
+            customer_id: customer_data_request.customer_id,
+            request_type: customer_data_request.request_type,
+            status: customer_data_request.status,
+            archive_available: customer_data_request.request_type
+                == storage_enums::CustomerDataRequestType::Export
+                && customer_data_request.status
+                    == storage_enums::CustomerDataRequestStatus::Completed,
+            error_message: customer_data_request.error_message,
+            created_at: customer_data_request.created_at,
+            completed_at: customer_data_request.completed_at,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "olap"))]
+mod tests {
+    use common_utils::id_type::GenerateId;
+
+    use super::*;
+
+    fn get_customer_data_request(
+        request_type: storage_enums::CustomerDataRequestType,
+        status: storage_enums::CustomerDataRequestStatus,
+    ) -> storage::CustomerDataRequest {
+        let merchant_id = id_type::MerchantId::default();
+        let now = common_utils::date_time::now();
+        storage::CustomerDataRequest {
+            request_id: "cdr_123".to_string(),
+            file_id: Some(get_customer_data_export_file_key(&merchant_id, "cdr_123")),
+            merchant_id,
+            customer_id: id_type::CustomerId::generate(),
+            request_type,
+            status,
+            requested_by: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_export_file_key_is_scoped_to_the_merchant() {
+        let merchant_id = id_type::MerchantId::default();
+
+        assert_eq!(
+            get_customer_data_export_file_key(&merchant_id, "cdr_123"),
+            format!(
+                "{}/customer_data_exports/cdr_123",
+                merchant_id.get_string_repr()
+            )
+        );
+    }
+
+    #[test]
+    fn test_archive_is_available_for_completed_exports() {
+        let customer_data_request = get_customer_data_request(
+            storage_enums::CustomerDataRequestType::Export,
+            storage_enums::CustomerDataRequestStatus::Completed,
+        );
+        let file_id = customer_data_request.file_id.clone();
+
+        assert!(file_id.is_some());
+        assert_eq!(
+            get_customer_data_archive_file_key(customer_data_request),
+            file_id
+        );
+    }
+
+    #[test]
+    fn test_archive_is_unavailable_for_unfinished_exports_and_erasures() {
+        for (request_type, status) in [
+            (
+                storage_enums::CustomerDataRequestType::Export,
+                storage_enums::CustomerDataRequestStatus::Processing,
+            ),
+            (
+                storage_enums::CustomerDataRequestType::Export,
+                storage_enums::CustomerDataRequestStatus::Failed,
+            ),
+            (
+                storage_enums::CustomerDataRequestType::Erasure,
+                storage_enums::CustomerDataRequestStatus::Completed,
+            ),
+        ] {
+            assert_eq!(
+                get_customer_data_archive_file_key(get_customer_data_request(request_type, status)),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_payment_intents_are_paged_until_a_short_page() {
+        let page_size =
+            usize::try_from(common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1).unwrap_or(usize::MAX);
+
+        assert!(is_last_payment_intents_page(0));
+        assert!(is_last_payment_intents_page(page_size.saturating_sub(1)));
+        assert!(!is_last_payment_intents_page(page_size));
+    }
+}
@@ -0,0 +1,487 @@
+use std::collections::{BTreeMap, HashSet};
+
+use api_models::{customers as customer_models, webhooks};
+use common_utils::{
+    crypto::{self, SignMessage},
+    id_type, pii,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_masking::PeekInterface;
+use router_env::{instrument, logger, tracing, Instrument};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const DEFAULT_DUPLICATE_GROUPS_LIMIT: u16 = 20;
+const MAX_DUPLICATE_GROUPS_LIMIT: u16 = 100;
+
+/// Merges the source customers into the target customer. The payment methods, mandates,
+/// addresses and payments of the source customers are moved to the target customer and the
+/// source customers are replaced by redirects to it.
+#[instrument(skip(state))]
+pub async fn merge_customers(
+    state: SessionState,
+    platform: domain::Platform,
+    request: customer_models::CustomerMergeRequestInternal,
+) -> RouterResponse<customer_models::CustomerMergeResponse> {
+    let db = &*state.store;
+    let provider = platform.get_provider();
+    let merchant_id = provider.get_account().get_id();
+    let target_customer_id = request.customer_id;
+
+    // The records are moved in a single database transaction, which does not cover the records
+    // that are only present in redis
+    if provider.get_account().storage_scheme != storage_enums::MerchantStorageScheme::PostgresOnly {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message:
+                "Customers can only be merged for merchants using the postgres_only storage scheme"
+                    .to_string(),
+        }));
+    }
+
+    let mut seen_customer_ids = HashSet::new();
+    let source_customer_ids: Vec<_> = request
+        .request
+        .source_customer_ids
+        .into_iter()
+        .filter(|customer_id| seen_customer_ids.insert(customer_id.clone()))
+        .collect();
+    if source_customer_ids.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "source_customer_ids"
+        }));
+    }
+    if source_customer_ids.contains(&target_customer_id) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "A customer cannot be merged into itself".to_string(),
+        }));
+    }
+
+    db.find_customer_by_customer_id_merchant_id(
+        &target_customer_id,
+        merchant_id,
+        provider.get_key_store(),
+        provider.get_account().storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    for source_customer_id in &source_customer_ids {
+        db.find_customer_optional_by_customer_id_merchant_id(
+            source_customer_id,
+            merchant_id,
+            provider.get_key_store(),
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the source customer")?
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "customer {} does not exist",
+                source_customer_id.get_string_repr()
+            ),
+        })?;
+    }
+
+    let merge_id = common_utils::generate_id(crate::consts::ID_LENGTH, "cmg");
+    let merged_by = platform
+        .get_initiator()
+        .and_then(|initiator| initiator.to_created_by())
+        .map(|merged_by| merged_by.to_string());
+    let merged_at = common_utils::date_time::now();
+    let redirects = source_customer_ids
+        .iter()
+        .map(|source_customer_id| storage::CustomerRedirectNew {
+            merchant_id: merchant_id.clone(),
+            customer_id: source_customer_id.clone(),
+            target_customer_id: target_customer_id.clone(),
+            merge_id: merge_id.clone(),
+            merged_by: merged_by.clone(),
+            created_at: merged_at,
+        })
+        .collect();
+
+    db.merge_customers(merchant_id, &target_customer_id, redirects)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to merge the customers")?;
+
+    let response = customer_models::CustomerMergeResponse {
+        merge_id,
+        customer_id: target_customer_id,
+        merged_customer_ids: source_customer_ids,
+        merged_at,
+    };
+
+    trigger_customer_merged_webhook(&state, &platform, response.clone()).await?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Groups the customers of the merchant sharing an email address or a phone number
+#[instrument(skip(state))]
+pub async fn find_duplicate_customers(
+    state: SessionState,
+    platform: domain::Platform,
+    request: customer_models::CustomerDuplicatesRequest,
+) -> RouterResponse<customer_models::CustomerDuplicatesResponse> {
+    let limit = request
+        .limit
+        .unwrap_or(DEFAULT_DUPLICATE_GROUPS_LIMIT)
+        .min(MAX_DUPLICATE_GROUPS_LIMIT);
+
+    let match_keys = state
+        .store
+        .find_duplicated_customer_match_keys(
+            platform.get_provider().get_account().get_id(),
+            i64::from(limit),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the duplicated customer match keys")?;
+
+    Ok(services::ApplicationResponse::Json(
+        customer_models::CustomerDuplicatesResponse {
+            data: group_duplicate_customers(match_keys),
+        },
+    ))
+}
+
+/// Groups the customers by the match key they share
+fn group_duplicate_customers(
+    match_keys: Vec<storage::CustomerMatchKey>,
+) -> Vec<customer_models::CustomerDuplicateGroup> {
+    let mut groups = BTreeMap::<_, Vec<_>>::new();
+    for match_key in match_keys {
+        groups
+            .entry((match_key.key_type.to_string(), match_key.key_hash))
+            .or_default()
+            .push((match_key.key_type, match_key.customer_id));
+    }
+
+    groups
+        .into_values()
+        .filter_map(|group| {
+            let match_type = group.first()?.0;
+            Some(customer_models::CustomerDuplicateGroup {
+                match_type,
+                customer_ids: group
+                    .into_iter()
+                    .map(|(_, customer_id)| customer_id)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the customer the given customer was merged into, if it was merged
+pub async fn resolve_merged_customer_id(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> errors::CustomResult<Option<id_type::CustomerId>, errors::StorageError> {
+    Ok(db
+        .find_customer_redirect_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await?
+        .map(|redirect| redirect.target_customer_id))
+}
+
+/// Records the keyed hashes of the email address and phone number of the customer, used to find
+/// duplicate customers. Failures are logged, as they must not fail the customer operation.
+pub async fn record_customer_match_keys(
+    state: &SessionState,
+    provider: &domain::Provider,
+    customer: &domain::Customer,
+) {
+    let key = provider.get_key_store().key.get_inner().peek();
+    let match_keys = get_customer_match_values(
+        customer
+            .email
+            .as_ref()
+            .map(|email| email.get_inner().peek().as_str()),
+        customer.phone_country_code.as_deref(),
+        customer
+            .phone
+            .as_ref()
+            .map(|phone| phone.get_inner().peek().as_str()),
+    )
+    .into_iter()
+    .map(|(key_type, value)| {
+        get_customer_match_key_hash(key, key_type, &value).map(|key_hash| {
+            storage::CustomerMatchKeyNew {
+                merchant_id: customer.merchant_id.clone(),
+                customer_id: customer.customer_id.clone(),
+                key_type,
+                key_hash,
+                created_at: common_utils::date_time::now(),
+            }
+        })
+    })
+    .collect::<Result<Vec<_>, _>>();
+
+    let result = match match_keys {
+        Ok(match_keys) => state
+            .store
+            .replace_customer_match_keys(&customer.merchant_id, &customer.customer_id, match_keys)
+            .await
+            .map_err(|error| format!("{error:?}")),
+        Err(error) => Err(format!("{error:?}")),
+    };
+    if let Err(error) = result {
+        logger::error!(%error, "Failed to record the customer match keys");
+    }
+}
+
+/// Normalizes the email address and phone number of the customer, so that the same attribute
+/// written differently yields the same match key. Missing and redacted attributes are skipped.
+fn get_customer_match_values(
+    email: Option<&str>,
+    phone_country_code: Option<&str>,
+    phone: Option<&str>,
+) -> Vec<(storage_enums::CustomerMatchKeyType, String)> {
+    let digits = |value: &str| {
+        value
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+    };
+    [
+        (
+            storage_enums::CustomerMatchKeyType::Email,
+            email.map(|email| email.trim().to_lowercase()),
+        ),
+        (
+            storage_enums::CustomerMatchKeyType::Phone,
+            phone.map(|phone| {
+                format!(
+                    "{}{}",
+                    phone_country_code.map(digits).unwrap_or_default(),
+                    digits(phone)
+                )
+            }),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(key_type, value)| {
+        value
+            .filter(|value| !value.is_empty() && !value.eq_ignore_ascii_case(pii::REDACTED))
+            .map(|value| (key_type, value))
+    })
+    .collect()
+}
+
+/// Hashes the match value with the key of the merchant, so that the attributes are not stored in
+/// the clear and cannot be compared across merchants
+fn get_customer_match_key_hash(
+    key: &[u8],
+    key_type: storage_enums::CustomerMatchKeyType,
+    value: &str,
+) -> errors::CustomResult<String, common_utils::errors::CryptoError> {
+    crypto::HmacSha512::sign_message(
+        &crypto::HmacSha512,
+        key,
+        format!("{key_type}:{value}").as_bytes(),
+    )
+    .map(hex::encode)
+}
+
+/// Rebuilds the content of the `customer_merged` webhook of a merge
+pub async fn get_customer_merge_response(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merge_id: &str,
+) -> RouterResult<customer_models::CustomerMergeResponse> {
+    let redirects = state
+        .store
+        .list_customer_redirects_by_merchant_id_merge_id(merchant_id, merge_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the customer redirects of the merge")?;
+
+    let first_redirect =
+        redirects
+            .first()
+            .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("customer merge {merge_id} not found"),
+            })?;
+
+    Ok(customer_models::CustomerMergeResponse {
+        merge_id: merge_id.to_owned(),
+        customer_id: first_redirect.target_customer_id.clone(),
+        merged_at: first_redirect.created_at,
+        merged_customer_ids: redirects
+            .into_iter()
+            .map(|redirect| redirect.customer_id)
+            .collect(),
+    })
+}
+
+async fn trigger_customer_merged_webhook(
+    state: &SessionState,
+    platform: &domain::Platform,
+    response: customer_models::CustomerMergeResponse,
+) -> RouterResult<()> {
+    // Customers are not scoped to a profile, the webhook is sent with the default profile
+    let Some(profile_id) = platform
+        .get_processor()
+        .get_account()
+        .get_default_profile()
+        .clone()
+    else {
+        logger::info!("Merchant has no default profile, skipping the customer merged webhook");
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(platform.get_processor().get_key_store(), &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let cloned_state = state.clone();
+    let processor = platform.get_processor().clone();
+
+    tokio::spawn(
+        async move {
+            let primary_object_created_at = Some(response.merged_at);
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                processor,
+                business_profile,
+                storage_enums::EventType::CustomerMerged,
+                storage_enums::EventClass::Customers,
+                response.merge_id.clone(),
+                storage_enums::EventObjectType::CustomerDetails,
+                webhooks::OutgoingWebhookContent::CustomerDetails(Box::new(response)),
+                primary_object_created_at,
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::id_type::GenerateId;
+
+    use super::*;
+
+    fn match_key(
+        customer_id: &id_type::CustomerId,
+        key_type: storage_enums::CustomerMatchKeyType,
+        key_hash: &str,
+    ) -> storage::CustomerMatchKey {
+        storage::CustomerMatchKey {
+            merchant_id: id_type::MerchantId::default(),
+            customer_id: customer_id.clone(),
+            key_type,
+            key_hash: key_hash.to_string(),
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_match_values_are_normalized() {
+        assert_eq!(
+            get_customer_match_values(
+                Some("  John.Doe@Example.COM "),
+                Some("+1"),
+                Some("(555) 010-0000"),
+            ),
+            vec![
+                (
+                    storage_enums::CustomerMatchKeyType::Email,
+                    "john.doe@example.com".to_string()
+                ),
+                (
+                    storage_enums::CustomerMatchKeyType::Phone,
+                    "15550100000".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_and_redacted_match_values_are_skipped() {
+        assert!(get_customer_match_values(None, Some("+1"), None).is_empty());
+        assert!(get_customer_match_values(Some("   "), None, None).is_empty());
+    }
+
+    #[test]
+    fn test_redacted_email_is_skipped_regardless_of_case() {
+        // The email address is lowercased before the redacted placeholder is checked
+        for email in [pii::REDACTED, "REDACTED", "redacted", " Redacted "] {
+            assert!(get_customer_match_values(Some(email), None, None).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_match_key_hash_is_keyed_by_merchant_and_type() {
+        let email = storage_enums::CustomerMatchKeyType::Email;
+        let phone = storage_enums::CustomerMatchKeyType::Phone;
+        let key_hash = get_customer_match_key_hash(b"merchant_key", email, "a@b.com").unwrap();
+
+        assert_eq!(key_hash.len(), 128);
+        assert_eq!(
+            get_customer_match_key_hash(b"merchant_key", email, "a@b.com").unwrap(),
+            key_hash
+        );
+        assert_ne!(
+            get_customer_match_key_hash(b"other_merchant_key", email, "a@b.com").unwrap(),
+            key_hash
+        );
+        assert_ne!(
+            get_customer_match_key_hash(b"merchant_key", phone, "a@b.com").unwrap(),
+            key_hash
+        );
+    }
+
+    #[test]
+    fn test_duplicate_customers_are_grouped_by_type_and_hash() {
+        let first = id_type::CustomerId::generate();
+        let second = id_type::CustomerId::generate();
+        let third = id_type::CustomerId::generate();
+        let email = storage_enums::CustomerMatchKeyType::Email;
+        let phone = storage_enums::CustomerMatchKeyType::Phone;
+
+        let groups = group_duplicate_customers(vec![
+            match_key(&first, phone, "hash_b"),
+            match_key(&first, email, "hash_a"),
+            match_key(&second, email, "hash_a"),
+            match_key(&third, phone, "hash_b"),
+            match_key(&second, phone, "hash_a"),
+        ]);
+
+        let groups = groups
+            .into_iter()
+            .map(|group| (group.match_type, group.customer_ids))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            vec![
+                (email, vec![first.clone(), second.clone()]),
+                (phone, vec![second]),
+                (phone, vec![first, third]),
+            ]
+        );
+    }
+}
@@ -20,6 +20,8 @@ use hyperswitch_masking::{ExposeInterface, Secret, SwitchStrategy};
 use payment_methods::controller::PaymentMethodsController;
 use router_env::{instrument, tracing};
 
+#[cfg(feature = "v1")]
+use crate::core::customer_merge;
 #[cfg(feature = "v2")]
 use crate::core::payment_methods::cards::create_encrypted_data;
 #[cfg(feature = "v2")]
@@ -109,6 +111,9 @@ pub async fn create_customer(
         .await
         .to_duplicate_response(errors::CustomersErrorResponse::CustomerAlreadyExists)?;
 
+    #[cfg(feature = "v1")]
+    customer_merge::record_customer_match_keys(&state, &provider, &customer).await;
+
     customer_data.generate_response(&customer)
 }
 
@@ -552,17 +557,38 @@ pub async fn retrieve_customer(
     customer_id: id_type::CustomerId,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
     let db = state.store.as_ref();
+    let merchant_id = provider.get_account().get_id();
 
-    let response = db
+    let customer = db
         .find_customer_optional_with_redacted_customer_details_by_customer_id_merchant_id(
             &customer_id,
-            provider.get_account().get_id(),
+            merchant_id,
             provider.get_key_store(),
             provider.get_account().storage_scheme,
         )
         .await
-        .switch()?
-        .ok_or(errors::CustomersErrorResponse::CustomerNotFound)?;
+        .switch()?;
+
+    // A customer merged into another customer is resolved to the customer it was merged into
+    let response = match customer {
+        Some(customer) => customer,
+        None => {
+            let target_customer_id =
+                customer_merge::resolve_merged_customer_id(db, merchant_id, &customer_id)
+                    .await
+                    .switch()?
+                    .ok_or(errors::CustomersErrorResponse::CustomerNotFound)?;
+            db.find_customer_optional_with_redacted_customer_details_by_customer_id_merchant_id(
+                &target_customer_id,
+                merchant_id,
+                provider.get_key_store(),
+                provider.get_account().storage_scheme,
+            )
+            .await
+            .switch()?
+            .ok_or(errors::CustomersErrorResponse::CustomerNotFound)?
+        }
+    };
 
     let address = match &response.address_id {
         Some(address_id) => Some(api_models::payments::AddressDetails::from(
@@ -921,33 +947,55 @@ impl CustomerDeleteBridge for id_type::CustomerId {
             }
         }
 
-        match db
-            .find_payment_method_by_customer_id_merchant_id_list(
-                provider.get_key_store(),
-                self,
-                provider.get_account().get_id(),
-                None,
-            )
-            .await
-        {
-            // check this in review
-            Ok(customer_payment_methods) => {
-                for pm in customer_payment_methods.into_iter() {
-                    if pm.get_payment_method_type() == Some(enums::PaymentMethod::Card) {
-                        cards::PmCards { state, provider }
-                            .delete_card_from_locker(
-                                self,
-                                provider.get_account().get_id(),
-                                pm.locker_id.as_ref().unwrap_or(&pm.payment_method_id),
-                            )
-                            .await
-                            .switch()?;
+        delete_customer_payment_methods(state, provider, self).await?;
+        redact_customer_addresses(db, provider, key_manager_state, self).await?;
+        redact_customer_record(db, provider, initiator, key_manager_state, customer_orig).await?;
+
+        let response = customers::CustomerDeleteResponse {
+            customer_id: self.clone(),
+            customer_deleted: true,
+            address_deleted: true,
+            payment_methods_deleted: true,
+        };
+        metrics::CUSTOMER_REDACTED.add(1, &[]);
+        Ok(services::ApplicationResponse::Json(response))
+    }
+}
 
-                        if let Some(network_token_ref_id) = pm.network_token_requestor_reference_id
-                        {
-                            network_tokenization::delete_network_token_from_locker_and_token_service(
+/// Deletes the payment methods of the customer, from the locker and from the database
+#[cfg(feature = "v1")]
+pub(crate) async fn delete_customer_payment_methods(
+    state: &SessionState,
+    provider: &domain::Provider,
+    customer_id: &id_type::CustomerId,
+) -> errors::CustomResult<(), errors::CustomersErrorResponse> {
+    let db = &*state.store;
+    match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            provider.get_key_store(),
+            customer_id,
+            provider.get_account().get_id(),
+            None,
+        )
+        .await
+    {
+        // check this in review
+        Ok(customer_payment_methods) => {
+            for pm in customer_payment_methods.into_iter() {
+                if pm.get_payment_method_type() == Some(enums::PaymentMethod::Card) {
+                    cards::PmCards { state, provider }
+                        .delete_card_from_locker(
+                            customer_id,
+                            provider.get_account().get_id(),
+                            pm.locker_id.as_ref().unwrap_or(&pm.payment_method_id),
+                        )
+                        .await
+                        .switch()?;
+
+                    if let Some(network_token_ref_id) = pm.network_token_requestor_reference_id {
+                        network_tokenization::delete_network_token_from_locker_and_token_service(
                             state,
-                            self,
+                            customer_id,
                             provider.get_account().get_id(),
                             pm.payment_method_id.clone(),
                             pm.network_token_locker_id,
@@ -956,140 +1004,168 @@ impl CustomerDeleteBridge for id_type::CustomerId {
                         )
                         .await
                         .switch()?;
-                        }
                     }
-
-                    db.delete_payment_method_by_merchant_id_payment_method_id(
-                        provider.get_key_store(),
-                        provider.get_account().get_id(),
-                        &pm.payment_method_id,
-                    )
-                    .await
-                    .change_context(errors::CustomersErrorResponse::InternalServerError)
-                    .attach_printable(
-                        "failed to delete payment method while redacting customer details",
-                    )?;
                 }
+
+                db.delete_payment_method_by_merchant_id_payment_method_id(
+                    provider.get_key_store(),
+                    provider.get_account().get_id(),
+                    &pm.payment_method_id,
+                )
+                .await
+                .change_context(errors::CustomersErrorResponse::InternalServerError)
+                .attach_printable(
+                    "failed to delete payment method while redacting customer details",
+                )?;
             }
-            Err(error) => {
-                if error.current_context().is_db_not_found() {
-                    Ok(())
-                } else {
-                    Err(error)
-                        .change_context(errors::CustomersErrorResponse::InternalServerError)
-                        .attach_printable(
-                            "failed find_payment_method_by_customer_id_merchant_id_list",
-                        )
-                }?
+            Ok(())
+        }
+        Err(error) => {
+            if error.current_context().is_db_not_found() {
+                Ok(())
+            } else {
+                Err(error)
+                    .change_context(errors::CustomersErrorResponse::InternalServerError)
+                    .attach_printable("failed find_payment_method_by_customer_id_merchant_id_list")
             }
-        };
+        }
+    }
+}
 
-        let key = provider.get_key_store().key.get_inner().peek();
-        let identifier = Identifier::Merchant(provider.get_key_store().merchant_id.clone());
-        let redacted_encrypted_value: Encryptable<Secret<_>> = types::crypto_operation(
-            key_manager_state,
-            type_name!(storage::Address),
-            CryptoOperation::Encrypt(REDACTED.to_string().into()),
-            identifier.clone(),
-            key,
+/// Redacts the addresses of the customer, including the addresses of their payments
+#[cfg(feature = "v1")]
+pub(crate) async fn redact_customer_addresses(
+    db: &dyn StorageInterface,
+    provider: &domain::Provider,
+    key_manager_state: &KeyManagerState,
+    customer_id: &id_type::CustomerId,
+) -> errors::CustomResult<(), errors::CustomersErrorResponse> {
+    let redacted_encrypted_value = encrypt_redacted_value(provider, key_manager_state).await?;
+    let redacted_encrypted_email = Encryptable::new(
+        redacted_encrypted_value
+            .clone()
+            .into_inner()
+            .switch_strategy(),
+        redacted_encrypted_value.clone().into_encrypted(),
+    );
+
+    let update_address = storage::AddressUpdate::Update {
+        city: Some(REDACTED.to_string()),
+        country: None,
+        line1: Some(redacted_encrypted_value.clone()),
+        line2: Some(redacted_encrypted_value.clone()),
+        line3: Some(redacted_encrypted_value.clone()),
+        state: Some(redacted_encrypted_value.clone()),
+        zip: Some(redacted_encrypted_value.clone()),
+        first_name: Some(redacted_encrypted_value.clone()),
+        last_name: Some(redacted_encrypted_value.clone()),
+        phone_number: Some(redacted_encrypted_value.clone()),
+        country_code: Some(REDACTED.to_string()),
+        updated_by: provider.get_account().storage_scheme.to_string(),
+        email: Some(redacted_encrypted_email),
+        origin_zip: Some(redacted_encrypted_value.clone()),
+    };
+
+    match db
+        .update_address_by_merchant_id_customer_id(
+            customer_id,
+            provider.get_account().get_id(),
+            update_address,
+            provider.get_key_store(),
         )
         .await
-        .and_then(|val| val.try_into_operation())
-        .switch()?;
-
-        let redacted_encrypted_email = Encryptable::new(
-            redacted_encrypted_value
-                .clone()
-                .into_inner()
-                .switch_strategy(),
-            redacted_encrypted_value.clone().into_encrypted(),
-        );
-
-        let update_address = storage::AddressUpdate::Update {
-            city: Some(REDACTED.to_string()),
-            country: None,
-            line1: Some(redacted_encrypted_value.clone()),
-            line2: Some(redacted_encrypted_value.clone()),
-            line3: Some(redacted_encrypted_value.clone()),
-            state: Some(redacted_encrypted_value.clone()),
-            zip: Some(redacted_encrypted_value.clone()),
-            first_name: Some(redacted_encrypted_value.clone()),
-            last_name: Some(redacted_encrypted_value.clone()),
-            phone_number: Some(redacted_encrypted_value.clone()),
-            country_code: Some(REDACTED.to_string()),
-            updated_by: provider.get_account().storage_scheme.to_string(),
-            email: Some(redacted_encrypted_email),
-            origin_zip: Some(redacted_encrypted_value.clone()),
-        };
+    {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            if error.current_context().is_db_not_found() {
+                Ok(())
+            } else {
+                Err(error)
+                    .change_context(errors::CustomersErrorResponse::InternalServerError)
+                    .attach_printable("failed update_address_by_merchant_id_customer_id")
+            }
+        }
+    }
+}
 
-        match db
-            .update_address_by_merchant_id_customer_id(
-                self,
-                provider.get_account().get_id(),
-                update_address,
-                provider.get_key_store(),
+/// Redacts the personal details of the customer record
+#[cfg(feature = "v1")]
+pub(crate) async fn redact_customer_record(
+    db: &dyn StorageInterface,
+    provider: &domain::Provider,
+    initiator: Option<&domain::Initiator>,
+    key_manager_state: &KeyManagerState,
+    customer: domain::Customer,
+) -> errors::CustomResult<(), errors::CustomersErrorResponse> {
+    let key = provider.get_key_store().key.get_inner().peek();
+    let identifier = Identifier::Merchant(provider.get_key_store().merchant_id.clone());
+    let redacted_encrypted_value = encrypt_redacted_value(provider, key_manager_state).await?;
+
+    let updated_customer = storage::CustomerUpdate::Update {
+        name: Some(redacted_encrypted_value.clone()),
+        email: Some(
+            types::crypto_operation(
+                key_manager_state,
+                type_name!(storage::Customer),
+                CryptoOperation::Encrypt(REDACTED.to_string().into()),
+                identifier,
+                key,
             )
             .await
-        {
-            Ok(_) => Ok(()),
-            Err(error) => {
-                if error.current_context().is_db_not_found() {
-                    Ok(())
-                } else {
-                    Err(error)
-                        .change_context(errors::CustomersErrorResponse::InternalServerError)
-                        .attach_printable("failed update_address_by_merchant_id_customer_id")
-                }
-            }
-        }?;
+            .and_then(|val| val.try_into_operation())
+            .switch()?,
+        ),
+        phone: Box::new(Some(redacted_encrypted_value.clone())),
+        description: Some(Description::from_str_unchecked(REDACTED)),
+        phone_country_code: Some(REDACTED.to_string()),
+        metadata: Box::new(None),
+        connector_customer: Box::new(None),
+        address_id: None,
+        tax_registration_id: Some(redacted_encrypted_value.clone()),
+        document_details: Box::new(None),
+        last_modified_by: initiator
+            .and_then(|initiator| initiator.to_created_by())
+            .map(|last_modified_by| last_modified_by.to_string()),
+    };
 
-        let updated_customer = storage::CustomerUpdate::Update {
-            name: Some(redacted_encrypted_value.clone()),
-            email: Some(
-                types::crypto_operation(
-                    key_manager_state,
-                    type_name!(storage::Customer),
-                    CryptoOperation::Encrypt(REDACTED.to_string().into()),
-                    identifier,
-                    key,
-                )
-                .await
-                .and_then(|val| val.try_into_operation())
-                .switch()?,
-            ),
-            phone: Box::new(Some(redacted_encrypted_value.clone())),
-            description: Some(Description::from_str_unchecked(REDACTED)),
-            phone_country_code: Some(REDACTED.to_string()),
-            metadata: Box::new(None),
-            connector_customer: Box::new(None),
-            address_id: None,
-            tax_registration_id: Some(redacted_encrypted_value.clone()),
-            document_details: Box::new(None),
-            last_modified_by: initiator
-                .and_then(|initiator| initiator.to_created_by())
-                .map(|last_modified_by| last_modified_by.to_string()),
-        };
+    db.delete_customer_match_keys_by_merchant_id_customer_id(
+        provider.get_account().get_id(),
+        &customer.customer_id,
+    )
+    .await
+    .switch()?;
+
+    db.update_customer_by_customer_id_merchant_id(
+        customer.customer_id.clone(),
+        provider.get_account().get_id().to_owned(),
+        customer,
+        updated_customer,
+        provider.get_key_store(),
+        provider.get_account().storage_scheme,
+    )
+    .await
+    .switch()?;
 
-        db.update_customer_by_customer_id_merchant_id(
-            self.clone(),
-            provider.get_account().get_id().to_owned(),
-            customer_orig,
-            updated_customer,
-            provider.get_key_store(),
-            provider.get_account().storage_scheme,
-        )
-        .await
-        .switch()?;
+    Ok(())
+}
 
-        let response = customers::CustomerDeleteResponse {
-            customer_id: self.clone(),
-            customer_deleted: true,
-            address_deleted: true,
-            payment_methods_deleted: true,
-        };
-        metrics::CUSTOMER_REDACTED.add(1, &[]);
-        Ok(services::ApplicationResponse::Json(response))
-    }
+#[cfg(feature = "v1")]
+async fn encrypt_redacted_value(
+    provider: &domain::Provider,
+    key_manager_state: &KeyManagerState,
+) -> errors::CustomResult<Encryptable<Secret<String>>, errors::CustomersErrorResponse> {
+    let key = provider.get_key_store().key.get_inner().peek();
+    let identifier = Identifier::Merchant(provider.get_key_store().merchant_id.clone());
+    types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::Address),
+        CryptoOperation::Encrypt(REDACTED.to_string().into()),
+        identifier,
+        key,
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .switch()
 }
 
 #[instrument(skip(state))]
@@ -1145,6 +1221,9 @@ pub async fn update_customer(
         )
         .await?;
 
+    #[cfg(feature = "v1")]
+    customer_merge::record_customer_match_keys(&state, &provider, &updated_customer).await;
+
     update_customer.request.generate_response(&updated_customer)
 }
 
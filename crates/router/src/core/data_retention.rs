@@ -0,0 +1,861 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use api_models::data_retention as retention_models;
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::{db::queue::QueueInterface, SchedulerAppState};
+
+use crate::{
+    configs::settings::DataRetentionSettings,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{AppState, SessionState},
+    services,
+    types::{
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+    utils::json_files,
+};
+
+const DATA_ARCHIVAL_LOCK_TAG: &str = "DATA_ARCHIVAL";
+const DATA_ARCHIVAL_LOCK_KEY: &str = "DATA_ARCHIVAL_LOCK";
+const DATA_ARCHIVAL_LOCK_VALUE: &str = "LOCKED";
+/// Time after which the lock held by a crashed archival run expires, in seconds
+const DATA_ARCHIVAL_LOCK_TTL: i64 = 900;
+const DEFAULT_BATCH_SIZE: u32 = 1000;
+const MAX_BATCH_SIZE: u32 = 10_000;
+
+/// Archives the records past the retention period of the policies of every tenant, until the
+/// process exits. A single consumer archives the records of a tenant at any time.
+pub async fn start_data_archival(state: AppState) {
+    let settings = state.conf.data_retention.clone();
+    let state = Arc::new(state);
+    let mut interval = tokio::time::interval(Duration::from_secs(settings.interval));
+
+    loop {
+        interval.tick().await;
+
+        for tenant_id in state.get_tenants().await {
+            let session_state = match Arc::clone(&state).get_session_state(&tenant_id, None, || {
+                report!(errors::ApiErrorResponse::InvalidTenant {
+                    tenant_id: tenant_id.get_string_repr().to_string(),
+                })
+            }) {
+                Ok(session_state) => session_state,
+                Err(error) => {
+                    logger::error!(?error, ?tenant_id, "Failed to build data archival state");
+                    continue;
+                }
+            };
+
+            if let Err(error) = archive_tenant_records(&session_state, &settings).await {
+                logger::error!(?error, ?tenant_id, "Data archival run failed");
+            }
+        }
+    }
+}
+
+#[instrument(skip_all, fields(tenant_id = ?state.tenant.tenant_id))]
+async fn archive_tenant_records(
+    state: &SessionState,
+    settings: &DataRetentionSettings,
+) -> RouterResult<()> {
+    let scheduler_db = state.store.get_scheduler_db();
+    let is_lock_acquired = scheduler_db
+        .acquire_pt_lock(
+            DATA_ARCHIVAL_LOCK_TAG,
+            DATA_ARCHIVAL_LOCK_KEY,
+            DATA_ARCHIVAL_LOCK_VALUE,
+            DATA_ARCHIVAL_LOCK_TTL,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the data archival lock")?;
+    if !is_lock_acquired {
+        return Ok(());
+    }
+
+    let result = archive_policies(state, settings).await;
+
+    scheduler_db
+        .release_pt_lock(DATA_ARCHIVAL_LOCK_TAG, DATA_ARCHIVAL_LOCK_KEY)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to release the data archival lock")?;
+
+    result
+}
+
+async fn archive_policies(
+    state: &SessionState,
+    settings: &DataRetentionSettings,
+) -> RouterResult<()> {
+    let policies = state
+        .store
+        .list_data_retention_policies()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the data retention policies")?;
+
+    for policy in policies.into_iter().filter(|policy| policy.is_enabled) {
+        // A failing policy must not hold back the archival of the other tables
+        if let Err(error) = archive_policy_records(state, settings, &policy).await {
+            logger::error!(?error, entity = %policy.entity, "Failed to archive records");
+        }
+    }
+
+    Ok(())
+}
+
+async fn archive_policy_records(
+    state: &SessionState,
+    settings: &DataRetentionSettings,
+    policy: &storage::DataRetentionPolicy,
+) -> RouterResult<()> {
+    let created_before = common_utils::date_time::now()
+        .saturating_sub(time::Duration::days(i64::from(policy.retention_days)));
+    let archived_count = match policy.purge_mode {
+        enums::DataPurgeMode::Delete => {
+            archive_and_delete_records(state, settings, policy, created_before).await?
+        }
+        enums::DataPurgeMode::DetachPartition => {
+            archive_and_detach_partitions(state, settings, policy, created_before).await?
+        }
+    };
+    logger::debug!(entity = %policy.entity, archived_count, "Archived records");
+
+    state
+        .store
+        .update_data_retention_policy_by_entity(
+            policy.entity,
+            storage::DataRetentionPolicyUpdate::ArchivalCompleted {
+                archived_count: policy.archived_count.saturating_add(archived_count),
+                last_archived_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the data retention policy")?;
+
+    Ok(())
+}
+
+/// Archives the oldest records batch by batch, deleting each batch once its file is uploaded and
+/// indexed. A batch whose deletion fails is archived again on the next run, which repoints its
+/// index entries to the new file.
+async fn archive_and_delete_records(
+    state: &SessionState,
+    settings: &DataRetentionSettings,
+    policy: &storage::DataRetentionPolicy,
+    created_before: time::PrimitiveDateTime,
+) -> RouterResult<i64> {
+    let window = storage::ArchivalWindow {
+        created_from: None,
+        created_before,
+    };
+    let batch_size = i64::from(policy.batch_size);
+    let mut archived_count = 0;
+
+    for _ in 0..settings.max_batches_per_run {
+        let records = state
+            .store
+            .find_archivable_records(policy.entity, window, batch_size, 0)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the records to archive")?;
+        if records.is_empty() {
+            break;
+        }
+
+        archive_batch(state, settings, policy, &records).await?;
+        state
+            .store
+            .delete_archivable_records(policy.entity, &records)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the archived records")?;
+
+        let batch_count = i64::try_from(records.len()).unwrap_or(i64::MAX);
+        archived_count += batch_count;
+        if batch_count < batch_size {
+            break;
+        }
+    }
+
+    Ok(archived_count)
+}
+
+/// Archives the partitions whose range ends before the retention period, each as a whole, and
+/// detaches them from the table once all of their records are uploaded and indexed.
+async fn archive_and_detach_partitions(
+    state: &SessionState,
+    settings: &DataRetentionSettings,
+    policy: &storage::DataRetentionPolicy,
+    created_before: time::PrimitiveDateTime,
+) -> RouterResult<i64> {
+    let partitions = state
+        .store
+        .list_table_partitions(policy.entity)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the table partitions")?;
+
+    let batch_size = i64::from(policy.batch_size);
+    let mut archived_count = 0;
+    let mut batch_count = 0;
+
+    for partition in partitions {
+        let Some(range_end) = partition
+            .range_end
+            .filter(|range_end| *range_end <= created_before)
+        else {
+            continue;
+        };
+        // The batch limit is only checked between partitions, as a partition is detached only once
+        // it is archived entirely
+        if batch_count >= settings.max_batches_per_run {
+            break;
+        }
+
+        let window = storage::ArchivalWindow {
+            created_from: partition.range_start,
+            created_before: range_end,
+        };
+        let mut offset = 0;
+        loop {
+            let records = state
+                .store
+                .find_archivable_records(policy.entity, window, batch_size, offset)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the records to archive")?;
+            if records.is_empty() {
+                break;
+            }
+
+            archive_batch(state, settings, policy, &records).await?;
+            batch_count += 1;
+            let records_count = i64::try_from(records.len()).unwrap_or(i64::MAX);
+            offset += records_count;
+            if records_count < batch_size {
+                break;
+            }
+        }
+
+        state
+            .store
+            .detach_table_partition(policy.entity, &partition)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to detach the partition {}", partition.name)
+            })?;
+        logger::info!(entity = %policy.entity, partition = %partition.name, "Detached archived partition");
+        archived_count += offset;
+    }
+
+    Ok(archived_count)
+}
+
+/// Uploads the records as a single file and indexes the records that belong to a merchant.
+async fn archive_batch(
+    state: &SessionState,
+    settings: &DataRetentionSettings,
+    policy: &storage::DataRetentionPolicy,
+    records: &[storage::ArchivableRecord],
+) -> RouterResult<()> {
+    let values = records
+        .iter()
+        .map(|record| record.data.clone())
+        .collect::<Vec<_>>();
+    let file = encode_archive(policy.file_format, &values)?;
+    let file_key = get_file_key(state, settings, policy);
+
+    state
+        .file_storage_client
+        .upload_file(&file_key, file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to upload the archive file {file_key}"))?;
+
+    let index_entries =
+        get_index_entries(policy, records, &file_key, common_utils::date_time::now());
+    if !index_entries.is_empty() {
+        state
+            .store
+            .insert_archived_records(index_entries)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to index the archived records")?;
+    }
+
+    logger::debug!(%file_key, records = records.len(), "Uploaded archive file");
+    Ok(())
+}
+
+/// Returns the index entries of the records that belong to a merchant, the other records are only
+/// retrievable from the archive files
+fn get_index_entries(
+    policy: &storage::DataRetentionPolicy,
+    records: &[storage::ArchivableRecord],
+    file_key: &str,
+    archived_at: time::PrimitiveDateTime,
+) -> Vec<storage::ArchivedRecordNew> {
+    records
+        .iter()
+        .filter_map(|record| {
+            record
+                .merchant_id
+                .clone()
+                .map(|merchant_id| storage::ArchivedRecordNew {
+                    merchant_id,
+                    entity: policy.entity,
+                    record_id: record.record_id.clone(),
+                    payment_id: record.payment_id.clone(),
+                    file_key: file_key.to_string(),
+                    file_format: policy.file_format,
+                    record_created_at: record.created_at,
+                    archived_at,
+                })
+        })
+        .collect()
+}
+
+fn get_file_key(
+    state: &SessionState,
+    settings: &DataRetentionSettings,
+    policy: &storage::DataRetentionPolicy,
+) -> String {
+    let now = common_utils::date_time::now();
+    format!(
+        "{}/{}/{}/{:04}/{:02}/{:02}/{}.{}",
+        settings.path_prefix,
+        state.tenant.tenant_id.get_string_repr(),
+        policy.entity,
+        now.year(),
+        u8::from(now.month()),
+        now.day(),
+        common_utils::generate_time_ordered_id_without_prefix(),
+        get_file_extension(policy.file_format),
+    )
+}
+
+fn get_file_extension(file_format: enums::ArchiveFileFormat) -> &'static str {
+    match file_format {
+        enums::ArchiveFileFormat::Ndjson => "ndjson.gz",
+        enums::ArchiveFileFormat::Parquet => "parquet",
+    }
+}
+
+fn encode_archive(
+    file_format: enums::ArchiveFileFormat,
+    values: &[serde_json::Value],
+) -> RouterResult<Vec<u8>> {
+    match file_format {
+        enums::ArchiveFileFormat::Ndjson => json_files::encode_ndjson_gzip(values)
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        #[cfg(feature = "events_parquet")]
+        enums::ArchiveFileFormat::Parquet => json_files::encode_parquet(values)
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        #[cfg(not(feature = "events_parquet"))]
+        enums::ArchiveFileFormat::Parquet => {
+            Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Parquet archive files require the `events_parquet` feature")
+        }
+    }
+    .attach_printable("Failed to encode the archive file")
+}
+
+fn decode_archive(
+    file_format: enums::ArchiveFileFormat,
+    file: Vec<u8>,
+) -> RouterResult<Vec<serde_json::Value>> {
+    match file_format {
+        enums::ArchiveFileFormat::Ndjson => json_files::decode_ndjson_gzip(&file)
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        #[cfg(feature = "events_parquet")]
+        enums::ArchiveFileFormat::Parquet => json_files::decode_parquet(file)
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        #[cfg(not(feature = "events_parquet"))]
+        enums::ArchiveFileFormat::Parquet => {
+            Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Parquet archive files require the `events_parquet` feature")
+        }
+    }
+    .attach_printable("Failed to decode the archive file")
+}
+
+/// The field holding the identifier of the record, in the layout the records are archived in
+fn get_record_id_field(entity: enums::DataRetentionEntity) -> &'static str {
+    match entity {
+        #[cfg(feature = "v1")]
+        enums::DataRetentionEntity::PaymentIntent => "payment_id",
+        #[cfg(feature = "v1")]
+        enums::DataRetentionEntity::PaymentAttempt => "attempt_id",
+        #[cfg(feature = "v1")]
+        enums::DataRetentionEntity::Refund => "refund_id",
+        #[cfg(feature = "v2")]
+        enums::DataRetentionEntity::PaymentIntent
+        | enums::DataRetentionEntity::PaymentAttempt
+        | enums::DataRetentionEntity::Refund => "id",
+        enums::DataRetentionEntity::Event => "event_id",
+        enums::DataRetentionEntity::ProcessTracker => "id",
+    }
+}
+
+/// Finds the document of the indexed record among the documents of its archive file
+fn find_archived_document<'a>(
+    documents: &'a [serde_json::Value],
+    entry: &storage::ArchivedRecord,
+) -> Option<&'a serde_json::Value> {
+    let id_field = get_record_id_field(entry.entity);
+    documents.iter().find(|document| {
+        document.get(id_field).and_then(serde_json::Value::as_str) == Some(entry.record_id.as_str())
+            && document
+                .get("merchant_id")
+                .and_then(serde_json::Value::as_str)
+                == Some(entry.merchant_id.get_string_repr())
+    })
+}
+
+fn validate_policy_request(
+    entity: enums::DataRetentionEntity,
+    req: &retention_models::DataRetentionPolicyRequest,
+) -> RouterResult<()> {
+    if req.retention_days == 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "retention_days must be greater than 0".to_string(),
+        }));
+    }
+
+    if req
+        .batch_size
+        .is_some_and(|batch_size| batch_size == 0 || batch_size > MAX_BATCH_SIZE)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("batch_size must be between 1 and {MAX_BATCH_SIZE}"),
+        }));
+    }
+
+    // Only the finished tasks and the settled payments and refunds are archived, detaching a
+    // partition would drop the records that are still in use
+    if entity != enums::DataRetentionEntity::Event
+        && req.purge_mode == Some(enums::DataPurgeMode::DetachPartition)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The {entity} records can only be archived with the delete purge mode"
+            ),
+        }));
+    }
+
+    if req.file_format == Some(enums::ArchiveFileFormat::Parquet)
+        && !cfg!(feature = "events_parquet")
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Parquet archive files are not supported by this deployment".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+#[instrument(skip(state))]
+pub async fn upsert_data_retention_policy(
+    state: SessionState,
+    req: retention_models::DataRetentionPolicyUpsertRequest,
+) -> RouterResponse<retention_models::DataRetentionPolicyResponse> {
+    let retention_models::DataRetentionPolicyUpsertRequest {
+        entity,
+        policy: req,
+    } = req;
+    validate_policy_request(entity, &req)?;
+
+    let retention_days = i32::try_from(req.retention_days).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "retention_days is too large".to_string(),
+        },
+    )?;
+    let batch_size = i32::try_from(req.batch_size.unwrap_or(DEFAULT_BATCH_SIZE))
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let file_format = req.file_format.unwrap_or_default();
+    let purge_mode = req.purge_mode.unwrap_or_default();
+    let is_enabled = req.is_enabled.unwrap_or(true);
+
+    let policy = match state
+        .store
+        .find_data_retention_policy_by_entity(entity)
+        .await
+    {
+        Ok(_) => state
+            .store
+            .update_data_retention_policy_by_entity(
+                entity,
+                storage::DataRetentionPolicyUpdate::Update {
+                    retention_days,
+                    file_format,
+                    purge_mode,
+                    batch_size,
+                    is_enabled,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the data retention policy")?,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = common_utils::date_time::now();
+            state
+                .store
+                .insert_data_retention_policy(storage::DataRetentionPolicyNew {
+                    entity,
+                    retention_days,
+                    file_format,
+                    purge_mode,
+                    batch_size,
+                    is_enabled,
+                    archived_count: 0,
+                    last_archived_at: None,
+                    created_at: now,
+                    modified_at: now,
+                })
+                .await
+                .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+                    message: format!("A data retention policy already exists for {entity}"),
+                })?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the data retention policy")?,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        retention_models::DataRetentionPolicyResponse::foreign_from(policy),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_data_retention_policies(
+    state: SessionState,
+) -> RouterResponse<retention_models::DataRetentionPolicyListResponse> {
+    let policies = state
+        .store
+        .list_data_retention_policies()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the data retention policies")?;
+
+    Ok(services::ApplicationResponse::Json(
+        retention_models::DataRetentionPolicyListResponse {
+            policies: policies
+                .into_iter()
+                .map(retention_models::DataRetentionPolicyResponse::foreign_from)
+                .collect(),
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_data_retention_policy(
+    state: SessionState,
+    req: retention_models::DataRetentionEntityPath,
+) -> RouterResponse<retention_models::DataRetentionPolicyDeleteResponse> {
+    let deleted = state
+        .store
+        .delete_data_retention_policy_by_entity(req.entity)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data retention policy not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        retention_models::DataRetentionPolicyDeleteResponse {
+            entity: req.entity,
+            deleted,
+        },
+    ))
+}
+
+/// Retrieves the archived records of a payment from the archive files. This is much slower than
+/// the retrieval of a payment from the database, each file holding the records is downloaded.
+#[instrument(skip(state))]
+pub async fn retrieve_archived_payment(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    req: retention_models::ArchivedPaymentRetrieveRequest,
+) -> RouterResponse<retention_models::ArchivedPaymentResponse> {
+    let index_entries = state
+        .store
+        .find_archived_records_by_merchant_id_payment_id(
+            &merchant_id,
+            req.payment_id.get_string_repr(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the archived records of the payment")?;
+    if index_entries.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::PaymentNotFound));
+    }
+
+    let mut entries_by_file: HashMap<_, Vec<_>> = HashMap::new();
+    for entry in &index_entries {
+        entries_by_file
+            .entry((entry.file_key.as_str(), entry.file_format))
+            .or_default()
+            .push(entry);
+    }
+
+    let mut records = Vec::with_capacity(index_entries.len());
+    for ((file_key, file_format), entries) in entries_by_file {
+        let file = state
+            .file_storage_client
+            .retrieve_file(file_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to retrieve the archive file {file_key}"))?;
+        let documents = decode_archive(file_format, file)?;
+
+        for entry in entries {
+            match find_archived_document(&documents, entry) {
+                Some(document) => records.push(retention_models::ArchivedRecordResponse {
+                    entity: entry.entity,
+                    record_id: entry.record_id.clone(),
+                    created_at: entry.record_created_at,
+                    archived_at: entry.archived_at,
+                    data: document.clone(),
+                }),
+                None => logger::warn!(
+                    %file_key,
+                    record_id = %entry.record_id,
+                    "Archived record missing from its archive file"
+                ),
+            }
+        }
+    }
+    records.sort_by_key(|record| record.created_at);
+
+    Ok(services::ApplicationResponse::Json(
+        retention_models::ArchivedPaymentResponse {
+            payment_id: req.payment_id,
+            records,
+        },
+    ))
+}
+
+impl ForeignFrom<storage::DataRetentionPolicy> for retention_models::DataRetentionPolicyResponse {
+    fn foreign_from(policy: storage::DataRetentionPolicy) -> Self {
+        Self {
+            entity: policy.entity,
+            retention_days: u32::try_from(policy.retention_days).unwrap_or_default(),
+            file_format: policy.file_format,
+            purge_mode: policy.purge_mode,
+            batch_size: u32::try_from(policy.batch_size).unwrap_or_default(),
+            is_enabled: policy.is_enabled,
+            archived_count: policy.archived_count,
+            last_archived_at: policy.last_archived_at,
+            created_at: policy.created_at,
+            modified_at: policy.modified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn policy_request(
+        purge_mode: Option<enums::DataPurgeMode>,
+    ) -> retention_models::DataRetentionPolicyRequest {
+        retention_models::DataRetentionPolicyRequest {
+            retention_days: 365,
+            file_format: None,
+            purge_mode,
+            batch_size: None,
+            is_enabled: None,
+        }
+    }
+
+    fn policy(entity: enums::DataRetentionEntity) -> storage::DataRetentionPolicy {
+        let now = datetime!(2026-10-01 00:00:00);
+        storage::DataRetentionPolicy {
+            entity,
+            retention_days: 365,
+            file_format: enums::ArchiveFileFormat::Ndjson,
+            purge_mode: enums::DataPurgeMode::Delete,
+            batch_size: 1000,
+            is_enabled: true,
+            archived_count: 0,
+            last_archived_at: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    fn merchant_id(merchant_id: &'static str) -> id_type::MerchantId {
+        id_type::MerchantId::try_from(Cow::from(merchant_id)).unwrap()
+    }
+
+    #[test]
+    fn test_validate_policy_request() {
+        assert!(
+            validate_policy_request(enums::DataRetentionEntity::Refund, &policy_request(None))
+                .is_ok()
+        );
+        assert!(validate_policy_request(
+            enums::DataRetentionEntity::Event,
+            &policy_request(Some(enums::DataPurgeMode::DetachPartition))
+        )
+        .is_ok());
+
+        assert!(validate_policy_request(
+            enums::DataRetentionEntity::Refund,
+            &retention_models::DataRetentionPolicyRequest {
+                retention_days: 0,
+                ..policy_request(None)
+            }
+        )
+        .is_err());
+        for batch_size in [0, MAX_BATCH_SIZE + 1] {
+            assert!(validate_policy_request(
+                enums::DataRetentionEntity::Refund,
+                &retention_models::DataRetentionPolicyRequest {
+                    batch_size: Some(batch_size),
+                    ..policy_request(None)
+                }
+            )
+            .is_err());
+        }
+        assert!(validate_policy_request(
+            enums::DataRetentionEntity::Refund,
+            &retention_models::DataRetentionPolicyRequest {
+                batch_size: Some(MAX_BATCH_SIZE),
+                ..policy_request(None)
+            }
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_partitions_are_only_detached_for_events() {
+        let error = validate_policy_request(
+            enums::DataRetentionEntity::ProcessTracker,
+            &policy_request(Some(enums::DataPurgeMode::DetachPartition)),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "The process_tracker records can only be archived with the delete purge mode"
+        ));
+    }
+
+    #[test]
+    fn test_parquet_policy_requires_feature() {
+        let result = validate_policy_request(
+            enums::DataRetentionEntity::Refund,
+            &retention_models::DataRetentionPolicyRequest {
+                file_format: Some(enums::ArchiveFileFormat::Parquet),
+                ..policy_request(None)
+            },
+        );
+
+        assert_eq!(result.is_ok(), cfg!(feature = "events_parquet"));
+    }
+
+    #[test]
+    fn test_ndjson_archive_round_trip() {
+        let values = vec![
+            serde_json::json!({"refund_id": "ref_1", "amount": 100}),
+            serde_json::json!({"refund_id": "ref_2", "amount": null}),
+        ];
+
+        let file = encode_archive(enums::ArchiveFileFormat::Ndjson, &values).unwrap();
+        let decoded = decode_archive(enums::ArchiveFileFormat::Ndjson, file).unwrap();
+
+        assert_eq!(decoded, values);
+        assert_eq!(
+            get_file_extension(enums::ArchiveFileFormat::Ndjson),
+            "ndjson.gz"
+        );
+    }
+
+    #[test]
+    fn test_index_entries_of_merchant_records() {
+        let created_at = datetime!(2025-01-01 00:00:00);
+        let archived_at = datetime!(2026-10-01 00:00:00);
+        let records = [
+            storage::ArchivableRecord {
+                record_id: "ref_1".to_string(),
+                merchant_id: Some(merchant_id("merchant_1")),
+                payment_id: Some("pay_1".to_string()),
+                created_at,
+                data: serde_json::json!({}),
+            },
+            storage::ArchivableRecord {
+                record_id: "pt_1".to_string(),
+                merchant_id: None,
+                payment_id: None,
+                created_at,
+                data: serde_json::json!({}),
+            },
+        ];
+
+        let index_entries = get_index_entries(
+            &policy(enums::DataRetentionEntity::Refund),
+            &records,
+            "archive/public/refund/1.ndjson.gz",
+            archived_at,
+        );
+
+        assert_eq!(
+            index_entries,
+            vec![storage::ArchivedRecordNew {
+                merchant_id: merchant_id("merchant_1"),
+                entity: enums::DataRetentionEntity::Refund,
+                record_id: "ref_1".to_string(),
+                payment_id: Some("pay_1".to_string()),
+                file_key: "archive/public/refund/1.ndjson.gz".to_string(),
+                file_format: enums::ArchiveFileFormat::Ndjson,
+                record_created_at: created_at,
+                archived_at,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_archived_document() {
+        let documents = [
+            serde_json::json!({"event_id": "evt_1", "merchant_id": "merchant_2"}),
+            serde_json::json!({"event_id": "evt_1", "merchant_id": "merchant_1"}),
+            serde_json::json!({"event_id": "evt_2", "merchant_id": "merchant_1"}),
+        ];
+        let entry = storage::ArchivedRecord {
+            merchant_id: merchant_id("merchant_1"),
+            entity: enums::DataRetentionEntity::Event,
+            record_id: "evt_1".to_string(),
+            payment_id: Some("pay_1".to_string()),
+            file_key: "archive/public/event/1.ndjson.gz".to_string(),
+            file_format: enums::ArchiveFileFormat::Ndjson,
+            record_created_at: datetime!(2025-01-01 00:00:00),
+            archived_at: datetime!(2026-10-01 00:00:00),
+        };
+
+        // Records of other merchants may share the identifier
+        assert_eq!(find_archived_document(&documents, &entry), documents.get(1));
+        assert!(find_archived_document(
+            &documents,
+            &storage::ArchivedRecord {
+                record_id: "evt_3".to_string(),
+                ..entry
+            }
+        )
+        .is_none());
+    }
+}
@@ -10,6 +10,10 @@ use router_env::{
     tracing::{self, Instrument},
 };
 use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+pub mod evidence_templates;
+#[cfg(feature = "v1")]
+pub mod rules;
 pub mod transformers;
 
 use common_enums;
@@ -0,0 +1,818 @@
+use std::cmp::Reverse;
+
+use api_models::{disputes as dispute_models, enums::DisputeEvidenceSource, payments};
+use common_utils::ext_traits::ValueExt;
+use error_stack::{report, ResultExt};
+use hyperswitch_masking::ExposeInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils, webhooks,
+    },
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::{ForeignFrom, ForeignTryFrom},
+    },
+    utils::OptionExt,
+};
+
+const DISPUTE_EVIDENCE_REMINDER_TASK: &str = "DISPUTE_EVIDENCE_REMINDER";
+
+#[instrument(skip(state))]
+pub async fn create_dispute_evidence_template(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: dispute_models::DisputeEvidenceTemplateCreateRequest,
+) -> RouterResponse<dispute_models::DisputeEvidenceTemplateResponse> {
+    if req.evidence_sources.is_empty() && req.text_evidence.is_none() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "at least one of evidence_sources or text_evidence must be provided"
+                .to_string(),
+        }));
+    }
+
+    let now = common_utils::date_time::now();
+    let template = storage::DisputeEvidenceTemplateNew {
+        template_id: common_utils::generate_id(consts::ID_LENGTH, "det"),
+        merchant_id: platform.get_processor().get_account().get_id().clone(),
+        profile_id,
+        name: req.name,
+        connector: req.connector,
+        reason_codes: req.reason_codes.into_iter().map(Some).collect(),
+        evidence_sources: serde_json::to_value(req.evidence_sources)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize dispute evidence sources")?,
+        text_evidence: req
+            .text_evidence
+            .map(serde_json::to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize dispute text evidence")?,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let template = state
+        .store
+        .insert_dispute_evidence_template(template)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert dispute evidence template")?;
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeEvidenceTemplateResponse::foreign_try_from(template)?,
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_dispute_evidence_templates(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+) -> RouterResponse<Vec<dispute_models::DisputeEvidenceTemplateResponse>> {
+    let templates = state
+        .store
+        .list_dispute_evidence_templates_by_merchant_id(
+            platform.get_processor().get_account().get_id(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list dispute evidence templates")?;
+
+    let templates = templates
+        .into_iter()
+        .filter(|template| {
+            profile_id.is_none()
+                || template.profile_id.is_none()
+                || template.profile_id == profile_id
+        })
+        .map(dispute_models::DisputeEvidenceTemplateResponse::foreign_try_from)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(templates))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_dispute_evidence_template(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: dispute_models::DisputeEvidenceTemplateId,
+) -> RouterResponse<dispute_models::DisputeEvidenceTemplateResponse> {
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let template = db
+        .find_dispute_evidence_template_by_merchant_id_template_id(merchant_id, &req.template_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("dispute evidence template {} not found", req.template_id),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &template)?;
+
+    let template = db
+        .delete_dispute_evidence_template_by_merchant_id_template_id(
+            merchant_id,
+            &template.template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("dispute evidence template {} not found", req.template_id),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeEvidenceTemplateResponse::foreign_try_from(template)?,
+    ))
+}
+
+/// Assembles the evidence of a dispute from a template, so that it can be reviewed before it is
+/// submitted with the submit evidence API.
+#[instrument(skip(state))]
+pub async fn assemble_dispute_evidence(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: dispute_models::DisputeEvidenceAssembleRequest,
+) -> RouterResponse<dispute_models::DisputeEvidenceAssembleResponse> {
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(merchant_id, &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id.clone(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+
+    let template = match req.template_id {
+        Some(template_id) => {
+            let template = db
+                .find_dispute_evidence_template_by_merchant_id_template_id(
+                    merchant_id,
+                    &template_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: format!("dispute evidence template {template_id} not found"),
+                })?;
+            core_utils::validate_profile_id_from_auth_layer(dispute.profile_id.clone(), &template)?;
+            template
+        }
+        None => find_dispute_evidence_template(&state, &platform, &dispute).await?,
+    };
+
+    let (evidence, evidence_sources) =
+        get_assembled_evidence(&state, &platform, &dispute, &template).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeEvidenceAssembleResponse {
+            dispute_id: dispute.dispute_id,
+            template_id: template.template_id,
+            evidence_sources,
+            evidence,
+            challenge_required_by: dispute.challenge_required_by,
+        },
+    ))
+}
+
+/// Assembles the evidence of a dispute from the most specific template matching the dispute.
+pub(super) async fn get_evidence_from_matching_template(
+    state: &SessionState,
+    platform: &domain::Platform,
+    dispute: &storage::Dispute,
+) -> RouterResult<dispute_models::SubmitEvidenceRequest> {
+    let template = find_dispute_evidence_template(state, platform, dispute).await?;
+    let (evidence, _) = get_assembled_evidence(state, platform, dispute, &template).await?;
+    Ok(evidence)
+}
+
+/// Finds the most specific template for a dispute.
+///
+/// Templates restricted to the reason code of the dispute take precedence over the default
+/// templates, followed by templates restricted to the connector and to the profile of the
+/// dispute. The most recently created template is used among equally specific templates.
+async fn find_dispute_evidence_template(
+    state: &SessionState,
+    platform: &domain::Platform,
+    dispute: &storage::Dispute,
+) -> RouterResult<storage::DisputeEvidenceTemplate> {
+    let templates = state
+        .store
+        .list_dispute_evidence_templates_by_merchant_id(
+            platform.get_processor().get_account().get_id(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list dispute evidence templates")?;
+
+    select_dispute_evidence_template(
+        templates,
+        dispute.profile_id.as_ref(),
+        &dispute.connector,
+        dispute.connector_reason_code.as_deref(),
+    )
+    .ok_or_else(|| {
+        report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "no dispute evidence template found for dispute {}",
+                dispute.dispute_id
+            ),
+        })
+    })
+}
+
+/// Selects the most specific of the templates, which are expected in the descending order of
+/// their creation, that apply to a dispute of the profile, connector and reason code.
+fn select_dispute_evidence_template(
+    templates: Vec<storage::DisputeEvidenceTemplate>,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+    connector: &str,
+    reason_code: Option<&str>,
+) -> Option<storage::DisputeEvidenceTemplate> {
+    let mut templates = templates
+        .into_iter()
+        .filter(|template| {
+            template.profile_id.is_none() || template.profile_id.as_ref() == profile_id
+        })
+        .filter(|template| {
+            template
+                .connector
+                .as_deref()
+                .is_none_or(|template_connector| template_connector == connector)
+        })
+        .filter(|template| {
+            template.reason_codes.is_empty()
+                || reason_code.is_some_and(|reason_code| {
+                    template.reason_codes.iter().any(|template_reason_code| {
+                        template_reason_code.as_deref() == Some(reason_code)
+                    })
+                })
+        })
+        .collect::<Vec<_>>();
+
+    // The sort is stable, so the most recently created template is kept first
+    templates.sort_by_key(|template| {
+        Reverse((
+            !template.reason_codes.is_empty(),
+            template.connector.is_some(),
+            template.profile_id.is_some(),
+        ))
+    });
+
+    templates.into_iter().next()
+}
+
+async fn get_assembled_evidence(
+    state: &SessionState,
+    platform: &domain::Platform,
+    dispute: &storage::Dispute,
+    template: &storage::DisputeEvidenceTemplate,
+) -> RouterResult<(
+    dispute_models::SubmitEvidenceRequest,
+    Vec<DisputeEvidenceSource>,
+)> {
+    let db = &*state.store;
+    let processor = platform.get_processor();
+    let merchant_id = processor.get_account().get_id();
+    let storage_scheme = processor.get_account().storage_scheme;
+
+    let evidence_sources: Vec<DisputeEvidenceSource> = template
+        .evidence_sources
+        .clone()
+        .parse_value("DisputeEvidenceSource")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse dispute evidence sources")?;
+    let text_evidence: dispute_models::DisputeEvidenceTemplateText = template
+        .text_evidence
+        .clone()
+        .map(|text_evidence| text_evidence.parse_value("DisputeEvidenceTemplateText"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse dispute text evidence")?
+        .unwrap_or_default();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_processor_merchant_id(
+            &dispute.payment_id,
+            merchant_id,
+            processor.get_key_store(),
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_processor_merchant_id(
+            &dispute.attempt_id,
+            merchant_id,
+            storage_scheme,
+            processor.get_key_store(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let mut evidence = dispute_models::SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        cancellation_policy_disclosure: text_evidence.cancellation_policy_disclosure,
+        cancellation_rebuttal: text_evidence.cancellation_rebuttal,
+        product_description: text_evidence.product_description,
+        refund_policy_disclosure: text_evidence.refund_policy_disclosure,
+        refund_refusal_explanation: text_evidence.refund_refusal_explanation,
+        uncategorized_text: text_evidence.uncategorized_text,
+        ..Default::default()
+    };
+    let mut found_sources = Vec::new();
+
+    for source in evidence_sources {
+        let is_found = match source {
+            DisputeEvidenceSource::PaymentDetails => {
+                evidence.product_description = evidence
+                    .product_description
+                    .take()
+                    .or(payment_intent.description.clone());
+                evidence.service_date = Some(payment_attempt.created_at.date().to_string());
+                true
+            }
+            DisputeEvidenceSource::CustomerDetails => {
+                let customer = match payment_intent.customer_id.as_ref() {
+                    Some(customer_id) => db
+                        .find_customer_optional_by_customer_id_merchant_id(
+                            customer_id,
+                            merchant_id,
+                            processor.get_key_store(),
+                            storage_scheme,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to find customer for dispute evidence")?,
+                    None => None,
+                };
+                customer.is_some_and(|customer| {
+                    evidence.customer_name = customer.name.map(|name| name.into_inner().expose());
+                    evidence.customer_email_address =
+                        customer.email.map(|email| email.into_inner().expose());
+                    evidence.customer_name.is_some() || evidence.customer_email_address.is_some()
+                })
+            }
+            DisputeEvidenceSource::BillingAddress => {
+                evidence.billing_address = get_formatted_address(
+                    state,
+                    platform,
+                    payment_intent.billing_address_id.as_deref(),
+                )
+                .await?;
+                evidence.billing_address.is_some()
+            }
+            DisputeEvidenceSource::ShippingAddress => {
+                evidence.shipping_address = get_formatted_address(
+                    state,
+                    platform,
+                    payment_intent.shipping_address_id.as_deref(),
+                )
+                .await?;
+                evidence.shipping_address.is_some()
+            }
+            DisputeEvidenceSource::AuthenticationResult => {
+                evidence.access_activity_log =
+                    get_authentication_result(state, platform, &payment_attempt).await?;
+                evidence.customer_purchase_ip = payment_attempt
+                    .browser_info
+                    .clone()
+                    .map(|browser_info| {
+                        browser_info
+                            .parse_value::<crate::types::BrowserInformation>("BrowserInformation")
+                    })
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse browser information")?
+                    .and_then(|browser_info| browser_info.ip_address)
+                    .map(|ip_address| ip_address.to_string());
+                evidence.access_activity_log.is_some() || evidence.customer_purchase_ip.is_some()
+            }
+            DisputeEvidenceSource::RefundHistory => {
+                let refunds = db
+                    .find_refund_by_payment_id_merchant_id(
+                        &dispute.payment_id,
+                        merchant_id,
+                        storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find refunds for dispute evidence")?;
+                let refund_history = refunds
+                    .iter()
+                    .map(|refund| {
+                        format!(
+                            "Refund {} of {} {} created at {} is in {} status",
+                            refund.refund_id,
+                            refund.refund_amount.get_amount_as_i64(),
+                            refund.currency,
+                            refund.created_at,
+                            refund.refund_status
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                evidence.uncategorized_text =
+                    append_refund_history(evidence.uncategorized_text.take(), &refund_history);
+                !refunds.is_empty()
+            }
+            DisputeEvidenceSource::UploadedEvidence => {
+                let uploaded_evidence: api::DisputeEvidence = dispute
+                    .evidence
+                    .clone()
+                    .expose()
+                    .parse_value("DisputeEvidence")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Error while parsing dispute evidence record")?;
+                evidence.cancellation_policy = uploaded_evidence.cancellation_policy;
+                evidence.customer_communication = uploaded_evidence.customer_communication;
+                evidence.customer_signature = uploaded_evidence.customer_signature;
+                evidence.receipt = uploaded_evidence.receipt;
+                evidence.refund_policy = uploaded_evidence.refund_policy;
+                evidence.service_documentation = uploaded_evidence.service_documentation;
+                evidence.shipping_documentation = uploaded_evidence.shipping_documentation;
+                evidence.invoice_showing_distinct_transactions =
+                    uploaded_evidence.invoice_showing_distinct_transactions;
+                evidence.recurring_transaction_agreement =
+                    uploaded_evidence.recurring_transaction_agreement;
+                evidence.uncategorized_file = uploaded_evidence.uncategorized_file;
+                [
+                    &evidence.cancellation_policy,
+                    &evidence.customer_communication,
+                    &evidence.customer_signature,
+                    &evidence.receipt,
+                    &evidence.refund_policy,
+                    &evidence.service_documentation,
+                    &evidence.shipping_documentation,
+                    &evidence.invoice_showing_distinct_transactions,
+                    &evidence.recurring_transaction_agreement,
+                    &evidence.uncategorized_file,
+                ]
+                .iter()
+                .any(|file_id| file_id.is_some())
+            }
+        };
+
+        if is_found {
+            found_sources.push(source);
+        }
+    }
+
+    Ok((evidence, found_sources))
+}
+
+async fn get_formatted_address(
+    state: &SessionState,
+    platform: &domain::Platform,
+    address_id: Option<&str>,
+) -> RouterResult<Option<String>> {
+    let Some(address_id) = address_id else {
+        return Ok(None);
+    };
+
+    let address = state
+        .store
+        .find_address_by_address_id(address_id, platform.get_processor().get_key_store())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find address for dispute evidence")?;
+    Ok(format_address(payments::AddressDetails::from(address)))
+}
+
+/// Formats an address as a single line, skipping the missing parts of the address.
+fn format_address(address: payments::AddressDetails) -> Option<String> {
+    let name = [address.first_name, address.last_name]
+        .into_iter()
+        .flatten()
+        .map(ExposeInterface::expose)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let lines = [
+        Some(name).filter(|name| !name.is_empty()),
+        address.line1.map(ExposeInterface::expose),
+        address.line2.map(ExposeInterface::expose),
+        address.line3.map(ExposeInterface::expose),
+        address.city,
+        address.state.map(ExposeInterface::expose),
+        address.zip.map(ExposeInterface::expose),
+        address.country.map(|country| country.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    (!lines.is_empty()).then(|| lines.join(", "))
+}
+
+/// Appends the history of the refunds of the disputed payment to the text evidence.
+fn append_refund_history(
+    uncategorized_text: Option<String>,
+    refund_history: &[String],
+) -> Option<String> {
+    if refund_history.is_empty() {
+        return uncategorized_text;
+    }
+
+    let refund_history = format!("Refund history:\n{}", refund_history.join("\n"));
+
+    Some(match uncategorized_text {
+        Some(text) => format!("{text}\n\n{refund_history}"),
+        None => refund_history,
+    })
+}
+
+async fn get_authentication_result(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<String>> {
+    let Some(authentication_id) = payment_attempt.authentication_id.as_ref() else {
+        return Ok(payment_attempt
+            .authentication_type
+            .map(|authentication_type| format!("Authentication type: {authentication_type}")));
+    };
+
+    let authentication = state
+        .store
+        .find_authentication_by_merchant_id_authentication_id(
+            platform.get_processor().get_account().get_id(),
+            authentication_id,
+            platform.get_processor().get_key_store(),
+            &state.into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find authentication for dispute evidence")?;
+
+    let details = [
+        Some(format!(
+            "3DS authentication status: {}",
+            authentication.authentication_status
+        )),
+        authentication
+            .trans_status
+            .map(|trans_status| format!("Transaction status: {trans_status}")),
+        authentication.eci.map(|eci| format!("ECI: {eci}")),
+        authentication
+            .ds_trans_id
+            .map(|ds_trans_id| format!("Directory server transaction id: {ds_trans_id}")),
+        authentication
+            .authentication_connector
+            .map(|connector| format!("Authentication provider: {connector}")),
+    ];
+
+    Ok(Some(
+        details.into_iter().flatten().collect::<Vec<_>>().join("\n"),
+    ))
+}
+
+/// Schedules a reminder which is sent as a webhook before the evidence submission deadline of the
+/// dispute.
+pub async fn add_dispute_evidence_reminder_task(
+    db: &dyn StorageInterface,
+    dispute: &storage::Dispute,
+    application_source: common_enums::ApplicationSource,
+) -> common_utils::errors::CustomResult<(), errors::StorageError> {
+    let now = common_utils::date_time::now();
+    let Some(challenge_required_by) = dispute
+        .challenge_required_by
+        .filter(|challenge_required_by| *challenge_required_by > now)
+    else {
+        return Ok(());
+    };
+    let schedule_time = std::cmp::max(
+        challenge_required_by
+            - time::Duration::hours(consts::DISPUTE_EVIDENCE_REMINDER_LEAD_TIME_IN_HOURS),
+        now,
+    );
+
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "dispute_evidence_reminder")),
+    );
+    let runner = common_enums::ProcessTrackerRunner::DisputeEvidenceReminderWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        DISPUTE_EVIDENCE_REMINDER_TASK,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    );
+    let tracking_data = storage::DisputeEvidenceReminderTrackingData {
+        dispute_id: dispute.dispute_id.clone(),
+        merchant_id: dispute.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        DISPUTE_EVIDENCE_REMINDER_TASK,
+        runner,
+        ["DISPUTE", "EVIDENCE_REMINDER"],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+        application_source,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Notifies the merchant that the evidence of an open dispute is due.
+pub async fn trigger_dispute_evidence_reminder(
+    state: &SessionState,
+    platform: &domain::Platform,
+    dispute: storage::Dispute,
+) -> RouterResult<()> {
+    let profile_id = dispute
+        .profile_id
+        .clone()
+        .get_required_value("profile_id")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(platform.get_processor().get_key_store(), &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let dispute_response = dispute_models::DisputeResponse::foreign_from(dispute.clone());
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        platform.get_processor().clone(),
+        business_profile,
+        storage_enums::EventType::DisputeEvidenceDue,
+        storage_enums::EventClass::Disputes,
+        dispute.dispute_id,
+        storage_enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+        Some(dispute.created_at),
+    ))
+    .await
+}
+
+impl ForeignTryFrom<storage::DisputeEvidenceTemplate>
+    for dispute_models::DisputeEvidenceTemplateResponse
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(template: storage::DisputeEvidenceTemplate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            template_id: template.template_id,
+            merchant_id: template.merchant_id,
+            profile_id: template.profile_id,
+            name: template.name,
+            connector: template.connector,
+            reason_codes: template.reason_codes.into_iter().flatten().collect(),
+            evidence_sources: template
+                .evidence_sources
+                .parse_value("DisputeEvidenceSource")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse dispute evidence sources")?,
+            text_evidence: template
+                .text_evidence
+                .map(|text_evidence| text_evidence.parse_value("DisputeEvidenceTemplateText"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse dispute text evidence")?,
+            created_at: template.created_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_utils::id_type::{self, GenerateId};
+
+    use super::*;
+
+    fn template(
+        template_id: &str,
+        profile_id: Option<&id_type::ProfileId>,
+        connector: Option<&str>,
+        reason_codes: &[&str],
+    ) -> storage::DisputeEvidenceTemplate {
+        let now = common_utils::date_time::now();
+        storage::DisputeEvidenceTemplate {
+            template_id: template_id.to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: profile_id.cloned(),
+            name: template_id.to_string(),
+            connector: connector.map(str::to_string),
+            reason_codes: reason_codes
+                .iter()
+                .map(|reason_code| Some(reason_code.to_string()))
+                .collect(),
+            evidence_sources: serde_json::json!([]),
+            text_evidence: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_select_dispute_evidence_template() {
+        let profile_id = id_type::ProfileId::generate();
+        // Listed in the descending order of their creation
+        let templates = vec![
+            template("default_newer", None, None, &[]),
+            template("default_older", None, None, &[]),
+            template("profile", Some(&profile_id), None, &[]),
+            template("connector", None, Some("stripe"), &[]),
+            template("fraud", None, None, &["fraudulent"]),
+            template("fraud_adyen", None, Some("adyen"), &["fraudulent"]),
+        ];
+        let select = |connector, reason_code| {
+            select_dispute_evidence_template(
+                templates.clone(),
+                Some(&profile_id),
+                connector,
+                reason_code,
+            )
+            .map(|template| template.template_id)
+        };
+
+        // Reason codes take precedence over connectors, which take precedence over profiles
+        assert_eq!(
+            select("stripe", Some("fraudulent")).as_deref(),
+            Some("fraud")
+        );
+        assert_eq!(
+            select("adyen", Some("fraudulent")).as_deref(),
+            Some("fraud_adyen")
+        );
+        assert_eq!(select("stripe", None).as_deref(), Some("connector"));
+        assert_eq!(
+            select("checkout", Some("duplicate")).as_deref(),
+            Some("profile")
+        );
+
+        // The most recently created template is used among equally specific templates
+        assert_eq!(
+            select_dispute_evidence_template(templates.clone(), None, "checkout", None)
+                .map(|template| template.template_id)
+                .as_deref(),
+            Some("default_newer")
+        );
+        assert!(select_dispute_evidence_template(
+            vec![template("fraud", None, None, &["fraudulent"])],
+            None,
+            "stripe",
+            Some("duplicate"),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_format_address() {
+        let address = payments::AddressDetails {
+            first_name: Some("John".to_string().into()),
+            last_name: Some("Doe".to_string().into()),
+            line1: Some("1467 Harrison Street".to_string().into()),
+            city: Some("San Fransico".to_string()),
+            zip: Some("94122".to_string().into()),
+            country: Some(common_enums::CountryAlpha2::US),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            format_address(address).as_deref(),
+            Some("John Doe, 1467 Harrison Street, San Fransico, 94122, US")
+        );
+        assert_eq!(format_address(payments::AddressDetails::default()), None);
+    }
+
+    #[test]
+    fn test_append_refund_history() {
+        let refund_history = [
+            "Refund ref_1 of 100 USD created at 2024-01-01 0:00:00.0 is in success status"
+                .to_string(),
+        ];
+
+        assert_eq!(
+            append_refund_history(Some("Delivered on time".to_string()), &refund_history)
+                .as_deref(),
+            Some(
+                "Delivered on time\n\nRefund history:\n\
+                Refund ref_1 of 100 USD created at 2024-01-01 0:00:00.0 is in success status"
+            )
+        );
+        assert_eq!(
+            append_refund_history(None, &refund_history).as_deref(),
+            Some(
+                "Refund history:\n\
+                Refund ref_1 of 100 USD created at 2024-01-01 0:00:00.0 is in success status"
+            )
+        );
+        assert_eq!(
+            append_refund_history(Some("Delivered on time".to_string()), &[]).as_deref(),
+            Some("Delivered on time")
+        );
+    }
+}
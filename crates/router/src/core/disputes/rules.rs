@@ -0,0 +1,685 @@
+use api_models::disputes::{self as dispute_models, DisputeRulesRecord, DisputeRulesRequest};
+use common_utils::ext_traits::{Encode, StringExt};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use euclid::{
+    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    frontend::ast::Program,
+};
+use router_env::{instrument, logger, tracing};
+use rustc_hash::FxHashMap;
+
+use super::evidence_templates;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api::disputes,
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+#[instrument(skip(state))]
+pub async fn upsert_dispute_rules(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: DisputeRulesRequest,
+) -> RouterResponse<DisputeRulesRecord> {
+    let db = state.store.as_ref();
+    validate_profile_access(profile_id.as_ref(), &request.profile_id)?;
+    core_utils::validate_and_get_business_profile(
+        db,
+        platform.get_processor(),
+        Some(&request.profile_id),
+    )
+    .await?;
+
+    euclid::frontend::ast::lowering::lower_program(request.program.clone())
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid Request Data".to_string(),
+        })
+        .attach_printable("The Request has an Invalid Comparison")?;
+
+    let key = request.profile_id.get_dispute_rules_key();
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    match db.find_config_by_key(&key).await {
+        Ok(config) => {
+            let previous_record: DisputeRulesRecord = config
+                .config
+                .parse_struct("DisputeRulesRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the dispute rules record")?;
+
+            let new_record = DisputeRulesRecord {
+                profile_id: request.profile_id,
+                name: request.name.unwrap_or(previous_record.name),
+                program: request.program,
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+
+            let updated_config = configs::ConfigUpdate::Update {
+                config: Some(
+                    new_record
+                        .encode_to_string_of_json()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Unable to serialize config to string")?,
+                ),
+            };
+
+            db.update_config_by_key(&key, updated_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the dispute rules config")?;
+
+            Ok(services::ApplicationResponse::Json(new_record))
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let new_record = DisputeRulesRecord {
+                profile_id: request.profile_id,
+                name: request
+                    .name
+                    .ok_or(errors::ApiErrorResponse::MissingRequiredField { field_name: "name" })?,
+                program: request.program,
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+
+            let new_config = configs::ConfigNew {
+                key,
+                config: new_record
+                    .encode_to_string_of_json()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unable to serialize config to string")?,
+            };
+
+            db.insert_config(new_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the dispute rules config")?;
+
+            Ok(services::ApplicationResponse::Json(new_record))
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the dispute rules config"),
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_dispute_rules(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: common_utils::id_type::ProfileId,
+) -> RouterResponse<DisputeRulesRecord> {
+    validate_profile_access(profile_id.as_ref(), &request)?;
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        platform.get_processor(),
+        Some(&request),
+    )
+    .await?;
+
+    let record = find_dispute_rules(&state, &request).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "dispute rules not found for profile {}",
+                request.get_string_repr()
+            ),
+        },
+    )?;
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_dispute_rules(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: common_utils::id_type::ProfileId,
+) -> RouterResponse<()> {
+    validate_profile_access(profile_id.as_ref(), &request)?;
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        platform.get_processor(),
+        Some(&request),
+    )
+    .await?;
+
+    state
+        .store
+        .delete_config_by_key(&request.get_dispute_rules_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "dispute rules not found for profile {}",
+                request.get_string_repr()
+            ),
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+#[instrument(skip(state))]
+pub async fn list_dispute_automated_actions(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: disputes::DisputeId,
+) -> RouterResponse<Vec<dispute_models::DisputeAutomatedActionResponse>> {
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(merchant_id, &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id,
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+
+    let actions = db
+        .list_dispute_automated_actions_by_merchant_id_dispute_id(merchant_id, &dispute.dispute_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list dispute automated actions")?;
+
+    Ok(services::ApplicationResponse::Json(
+        actions
+            .into_iter()
+            .map(dispute_models::DisputeAutomatedActionResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Evaluates the dispute rules of the profile against a newly opened dispute and takes the decided
+/// action. Every decision is recorded, including the manual reviews, before its action is taken,
+/// and the unique index on the dispute of the recorded decisions makes the rules evaluated only
+/// once per dispute, even when the webhooks of the dispute are delivered concurrently.
+#[instrument(skip_all)]
+pub async fn execute_dispute_rules(
+    state: &SessionState,
+    platform: &domain::Platform,
+    dispute: storage::Dispute,
+) -> RouterResult<Option<storage::DisputeAutomatedAction>> {
+    let db = &*state.store;
+    let Some(profile_id) = dispute.profile_id.clone() else {
+        return Ok(None);
+    };
+    let Some(record) = find_dispute_rules(state, &profile_id).await? else {
+        return Ok(None);
+    };
+
+    let backend_input = get_dispute_rules_backend_input(state, platform, &dispute).await?;
+    let (rule_name, action) = evaluate_dispute_rules(record.program, backend_input)?;
+    let automated_action = match db
+        .insert_dispute_automated_action(storage::DisputeAutomatedActionNew {
+            action_id: common_utils::generate_id(consts::ID_LENGTH, "dra"),
+            dispute_id: dispute.dispute_id.clone(),
+            merchant_id: dispute.merchant_id.clone(),
+            profile_id,
+            rule_name,
+            action,
+            status: get_initial_action_status(action),
+            error_message: None,
+            created_at: common_utils::date_time::now(),
+        })
+        .await
+    {
+        Ok(automated_action) => automated_action,
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::debug!(
+                dispute_id = %dispute.dispute_id,
+                "dispute rules have already been executed for the dispute"
+            );
+            return Ok(None);
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert dispute automated action");
+        }
+    };
+
+    let result = match action {
+        storage_enums::DisputeRuleAction::ManualReview => return Ok(Some(automated_action)),
+        storage_enums::DisputeRuleAction::AcceptDispute => Box::pin(super::accept_dispute(
+            state.clone(),
+            platform.clone(),
+            None,
+            disputes::DisputeId {
+                dispute_id: dispute.dispute_id.clone(),
+            },
+        ))
+        .await
+        .map(|_| ()),
+        storage_enums::DisputeRuleAction::SubmitEvidence => {
+            match evidence_templates::get_evidence_from_matching_template(state, platform, &dispute)
+                .await
+            {
+                Ok(evidence) => Box::pin(super::submit_evidence(
+                    state.clone(),
+                    platform.clone(),
+                    None,
+                    evidence,
+                ))
+                .await
+                .map(|_| ()),
+                Err(error) => Err(error),
+            }
+        }
+    };
+
+    let (status, error_message) = match result {
+        Ok(()) => (storage_enums::DisputeAutomatedActionStatus::Succeeded, None),
+        Err(error) => {
+            logger::error!(?error, %action, "Failed to take automated action on dispute");
+            (
+                storage_enums::DisputeAutomatedActionStatus::Failed,
+                Some(error.current_context().to_string()),
+            )
+        }
+    };
+
+    let automated_action = db
+        .update_dispute_automated_action_by_action_id(
+            &automated_action.action_id,
+            storage::DisputeAutomatedActionUpdate::StatusUpdate {
+                status,
+                error_message,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update dispute automated action")?;
+
+    Ok(Some(automated_action))
+}
+
+/// Returns the name of the rule matching the dispute along with the action it decided, or the
+/// action of the default selection when no rule matches.
+fn evaluate_dispute_rules(
+    program: Program<dispute_models::DisputeRuleOutput>,
+    backend_input: dsl_inputs::BackendInput,
+) -> RouterResult<(Option<String>, storage_enums::DisputeRuleAction)> {
+    let interpreter = backend::VirInterpreterBackend::with_program(program)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error initializing DSL interpreter backend")?;
+    let output = interpreter
+        .execute(backend_input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error executing dispute rules")?;
+    let action = output.get_output().action;
+
+    Ok((output.rule_name, action))
+}
+
+/// A manual review is complete once recorded, the other actions are pending until taken
+fn get_initial_action_status(
+    action: storage_enums::DisputeRuleAction,
+) -> storage_enums::DisputeAutomatedActionStatus {
+    match action {
+        storage_enums::DisputeRuleAction::ManualReview => {
+            storage_enums::DisputeAutomatedActionStatus::Succeeded
+        }
+        storage_enums::DisputeRuleAction::AcceptDispute
+        | storage_enums::DisputeRuleAction::SubmitEvidence => {
+            storage_enums::DisputeAutomatedActionStatus::Pending
+        }
+    }
+}
+
+async fn find_dispute_rules(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<Option<DisputeRulesRecord>> {
+    match state
+        .store
+        .find_config_by_key(&profile_id.get_dispute_rules_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("DisputeRulesRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the dispute rules record")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the dispute rules config"),
+    }
+}
+
+async fn get_dispute_rules_backend_input(
+    state: &SessionState,
+    platform: &domain::Platform,
+    dispute: &storage::Dispute,
+) -> RouterResult<dsl_inputs::BackendInput> {
+    let processor = platform.get_processor();
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_attempt_id_processor_merchant_id(
+            &dispute.attempt_id,
+            processor.get_account().get_id(),
+            processor.get_account().storage_scheme,
+            processor.get_key_store(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let is_three_ds_authenticated = match payment_attempt.authentication_id.as_ref() {
+        Some(authentication_id) => state
+            .store
+            .find_authentication_by_merchant_id_authentication_id(
+                processor.get_account().get_id(),
+                authentication_id,
+                processor.get_key_store(),
+                &state.into(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find authentication of the disputed payment")?
+            .authentication_status
+            .is_success(),
+        None => false,
+    };
+
+    let currency = dispute
+        .dispute_currency
+        .or_else(|| dispute.currency.parse().ok())
+        .or(payment_attempt.currency)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the currency of the dispute")?;
+
+    let metadata = get_dispute_rules_metadata(
+        &dispute.connector,
+        dispute.dispute_stage,
+        dispute.connector_reason_code.clone(),
+        is_three_ds_authenticated,
+    );
+
+    Ok(dsl_inputs::BackendInput {
+        metadata: Some(metadata),
+        payment: dsl_inputs::PaymentInput {
+            amount: dispute.dispute_amount,
+            currency,
+            authentication_type: payment_attempt.authentication_type,
+            card_bin: None,
+            extended_card_bin: None,
+            capture_method: None,
+            business_country: None,
+            billing_country: None,
+            business_label: None,
+            setup_future_usage: None,
+            transaction_initiator: None,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: payment_attempt.payment_method,
+            payment_method_type: payment_attempt.payment_method_type,
+            card_network: None,
+            card_discovery: None,
+        },
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+    })
+}
+
+/// Returns the metadata keys the dispute rules are evaluated against. The reason code is left out
+/// when the connector did not send one, so that no rule on the reason code matches the dispute.
+fn get_dispute_rules_metadata(
+    connector: &str,
+    dispute_stage: storage_enums::DisputeStage,
+    reason_code: Option<String>,
+    is_three_ds_authenticated: bool,
+) -> FxHashMap<String, String> {
+    [
+        ("connector", connector.to_string()),
+        ("dispute_stage", dispute_stage.to_string()),
+        (
+            "three_ds_authenticated",
+            is_three_ds_authenticated.to_string(),
+        ),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value))
+    .chain(reason_code.map(|reason_code| ("reason_code".to_string(), reason_code)))
+    .collect()
+}
+
+fn validate_profile_access(
+    profile_id_auth_layer: Option<&common_utils::id_type::ProfileId>,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<()> {
+    match profile_id_auth_layer {
+        Some(auth_profile_id) if auth_profile_id != profile_id => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Profile id authentication failed. Please use the correct JWT token"
+                    .to_string(),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl ForeignFrom<storage::DisputeAutomatedAction>
+    for dispute_models::DisputeAutomatedActionResponse
+{
+    fn foreign_from(action: storage::DisputeAutomatedAction) -> Self {
+        Self {
+            action_id: action.action_id,
+            dispute_id: action.dispute_id,
+            rule_name: action.rule_name,
+            action: action.action,
+            status: action.status,
+            error_message: action.error_message,
+            created_at: action.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn get_program() -> Program<dispute_models::DisputeRuleOutput> {
+        serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "action": "manual_review" },
+            "rules": [
+                {
+                    "name": "accept_low_value_fraud",
+                    "connectorSelection": { "action": "accept_dispute" },
+                    "statements": [{
+                        "condition": [
+                            {
+                                "lhs": "amount",
+                                "comparison": "less_than",
+                                "value": { "type": "number", "value": 1000 },
+                                "metadata": {}
+                            },
+                            {
+                                "lhs": "metadata",
+                                "comparison": "equal",
+                                "value": {
+                                    "type": "metadata_variant",
+                                    "value": { "key": "reason_code", "value": "10.4" }
+                                },
+                                "metadata": {}
+                            }
+                        ],
+                        "nested": null
+                    }]
+                },
+                {
+                    "name": "fight_authenticated",
+                    "connectorSelection": { "action": "submit_evidence" },
+                    "statements": [{
+                        "condition": [{
+                            "lhs": "metadata",
+                            "comparison": "equal",
+                            "value": {
+                                "type": "metadata_variant",
+                                "value": { "key": "three_ds_authenticated", "value": "true" }
+                            },
+                            "metadata": {}
+                        }],
+                        "nested": null
+                    }]
+                }
+            ],
+            "metadata": {}
+        }))
+        .unwrap()
+    }
+
+    fn get_backend_input(
+        amount: i64,
+        reason_code: Option<&str>,
+        is_three_ds_authenticated: bool,
+    ) -> dsl_inputs::BackendInput {
+        dsl_inputs::BackendInput {
+            metadata: Some(get_dispute_rules_metadata(
+                "stripe",
+                storage_enums::DisputeStage::Dispute,
+                reason_code.map(str::to_string),
+                is_three_ds_authenticated,
+            )),
+            payment: dsl_inputs::PaymentInput {
+                amount: MinorUnit::new(amount),
+                currency: common_enums::Currency::USD,
+                authentication_type: None,
+                card_bin: None,
+                extended_card_bin: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+                transaction_initiator: None,
+            },
+            payment_method: dsl_inputs::PaymentMethodInput {
+                payment_method: None,
+                payment_method_type: None,
+                card_network: None,
+                card_discovery: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            mandate: dsl_inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_matching_rule_decides_the_action() {
+        assert_eq!(
+            evaluate_dispute_rules(get_program(), get_backend_input(500, Some("10.4"), false))
+                .unwrap(),
+            (
+                Some("accept_low_value_fraud".to_string()),
+                storage_enums::DisputeRuleAction::AcceptDispute
+            )
+        );
+        assert_eq!(
+            evaluate_dispute_rules(get_program(), get_backend_input(5000, Some("10.4"), true))
+                .unwrap(),
+            (
+                Some("fight_authenticated".to_string()),
+                storage_enums::DisputeRuleAction::SubmitEvidence
+            )
+        );
+    }
+
+    #[test]
+    fn test_default_action_is_taken_when_no_rule_matches() {
+        for backend_input in [
+            get_backend_input(5000, Some("10.4"), false),
+            // A dispute without a reason code never matches a rule on the reason code
+            get_backend_input(500, None, false),
+        ] {
+            assert_eq!(
+                evaluate_dispute_rules(get_program(), backend_input).unwrap(),
+                (None, storage_enums::DisputeRuleAction::ManualReview)
+            );
+        }
+    }
+
+    #[test]
+    fn test_only_manual_review_is_complete_once_recorded() {
+        assert_eq!(
+            get_initial_action_status(storage_enums::DisputeRuleAction::ManualReview),
+            storage_enums::DisputeAutomatedActionStatus::Succeeded
+        );
+        assert_eq!(
+            get_initial_action_status(storage_enums::DisputeRuleAction::AcceptDispute),
+            storage_enums::DisputeAutomatedActionStatus::Pending
+        );
+        assert_eq!(
+            get_initial_action_status(storage_enums::DisputeRuleAction::SubmitEvidence),
+            storage_enums::DisputeAutomatedActionStatus::Pending
+        );
+    }
+
+    #[test]
+    fn test_dispute_rules_metadata() {
+        let metadata = get_dispute_rules_metadata(
+            "adyen",
+            storage_enums::DisputeStage::PreArbitration,
+            Some("4837".to_string()),
+            true,
+        );
+
+        assert_eq!(metadata.len(), 4);
+        assert_eq!(metadata.get("connector").unwrap(), "adyen");
+        assert_eq!(
+            metadata.get("dispute_stage").unwrap(),
+            &storage_enums::DisputeStage::PreArbitration.to_string()
+        );
+        assert_eq!(metadata.get("three_ds_authenticated").unwrap(), "true");
+        assert_eq!(metadata.get("reason_code").unwrap(), "4837");
+        assert!(!get_dispute_rules_metadata(
+            "adyen",
+            storage_enums::DisputeStage::Dispute,
+            None,
+            false
+        )
+        .contains_key("reason_code"));
+    }
+
+    #[test]
+    fn test_profile_access() {
+        let profile_id = common_utils::id_type::ProfileId::try_from(Cow::from("pro_1")).unwrap();
+        let other_profile_id =
+            common_utils::id_type::ProfileId::try_from(Cow::from("pro_2")).unwrap();
+
+        assert!(validate_profile_access(None, &profile_id).is_ok());
+        assert!(validate_profile_access(Some(&profile_id), &profile_id).is_ok());
+        assert!(validate_profile_access(Some(&other_profile_id), &profile_id).is_err());
+    }
+}
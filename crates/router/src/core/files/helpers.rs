@@ -27,6 +27,17 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("payout_batch") => Some(api::FilePurpose::PayoutBatch),
+        Some("settlement_report") => Some(api::FilePurpose::SettlementReport),
+        _ => None,
+    }
+}
+
+pub fn get_text_file_mime_type(file_name: Option<&str>) -> Option<&'static str> {
+    let extension = file_name?.rsplit_once('.')?.1.to_ascii_lowercase();
+    match extension.as_str() {
+        "csv" => Some("text/csv"),
+        "json" => Some("application/json"),
         _ => None,
     }
 }
@@ -81,6 +92,25 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::PayoutBatch => {
+            let supported_file_types = ["text/csv", "application/json"];
+            if !supported_file_types.contains(&create_file_request.file_type.essence_str()) {
+                return Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV or JSON format".to_string(),
+                }
+                .into());
+            }
+            Ok(())
+        }
+        api::FilePurpose::SettlementReport => {
+            if create_file_request.file_type.essence_str() != "text/csv" {
+                return Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV format".to_string(),
+                }
+                .into());
+            }
+            Ok(())
+        }
     }
 }
 
@@ -399,5 +429,18 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::PayoutBatch | api::FilePurpose::SettlementReport => {
+            state
+                .file_storage_client
+                .upload_file(&file_key, create_file_request.file.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
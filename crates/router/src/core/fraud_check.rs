@@ -44,6 +44,8 @@ use crate::{
     utils::ValueExt,
 };
 pub mod flows;
+#[cfg(feature = "v1")]
+pub mod native;
 pub mod operation;
 pub mod types;
 
@@ -90,15 +92,21 @@ where
     // To construct connector flow specific api
     dyn Connector: services::api::ConnectorIntegration<F, Req, frm_types::FraudCheckResponseData>,
 {
-    let merchant_connector_account = payments::construct_profile_id_and_get_mca(
-        state,
-        platform.get_processor(),
-        payment_data,
-        &frm_data.connector_details.connector_name,
-        None,
-        false,
-    )
-    .await?;
+    let is_native_frm =
+        frm_data.connector_details.connector_name == native::NATIVE_FRM_CONNECTOR_NAME;
+    let merchant_connector_account = if is_native_frm {
+        native::get_native_frm_merchant_connector_account()
+    } else {
+        payments::construct_profile_id_and_get_mca(
+            state,
+            platform.get_processor(),
+            payment_data,
+            &frm_data.connector_details.connector_name,
+            None,
+            false,
+        )
+        .await?
+    };
 
     frm_data
         .payment_attempt
@@ -129,6 +137,10 @@ where
         frm_data.fraud_check.last_step = FraudCheckLastStep::TransactionOrRecordRefund
     }
 
+    if is_native_frm {
+        return native::execute_native_frm_flow(state, frm_data, router_data).await;
+    }
+
     let connector =
         FraudCheckConnectorData::get_connector_by_name(&frm_data.connector_details.connector_name)?;
     let router_data_res = router_data
@@ -197,6 +209,14 @@ where
                 })
                 .attach_printable("Data field not found in frm_routing_algorithm")?;
 
+            if frm_routing_algorithm_struct.data == native::NATIVE_FRM_CONNECTOR_NAME {
+                return native::should_call_native_frm(
+                    state,
+                    payment_data.get_payment_intent().profile_id.as_ref(),
+                )
+                .await;
+            }
+
             let profile_id = payment_data
                 .get_payment_intent()
                 .profile_id
@@ -375,7 +395,15 @@ where
                 }
             }
         }
-        _ => Ok((false, None, None, None)),
+        // Merchants without an FRM connector are checked by the native fraud engine when the
+        // profile has fraud rules
+        None => {
+            native::should_call_native_frm(
+                state,
+                payment_data.get_payment_intent().profile_id.as_ref(),
+            )
+            .await
+        }
     }
 }
 
@@ -614,6 +642,8 @@ where
                 payment_data.set_frm_message(frm_fraud_check.clone());
                 if matches!(frm_fraud_check.frm_status, FraudCheckStatus::Fraud) {
                     frm_info.suggested_action = Some(FrmSuggestion::FrmCancelTransaction);
+                    #[cfg(feature = "v1")]
+                    record_fraud_for_sca_exemptions(state, platform, &frm_data).await;
                 } else if matches!(frm_fraud_check.frm_status, FraudCheckStatus::ManualReview) {
                     frm_info.suggested_action = Some(FrmSuggestion::FrmManualReview);
                 }
@@ -652,6 +682,34 @@ where
     }
 }
 
+/// Payments found to be fraudulent after their authorization count towards the fraud rates of the
+/// sca exemption engine
+#[cfg(feature = "v1")]
+async fn record_fraud_for_sca_exemptions(
+    state: &SessionState,
+    platform: &domain::Platform,
+    frm_data: &FrmData,
+) {
+    match state
+        .store
+        .find_business_profile_by_profile_id(
+            platform.get_processor().get_key_store(),
+            &frm_data.connector_details.profile_id,
+        )
+        .await
+    {
+        Ok(business_profile) => {
+            super::three_ds_decision_rule::exemptions::record_fraudulent_payment(
+                state,
+                &business_profile,
+                &frm_data.payment_attempt,
+            )
+            .await
+        }
+        Err(error) => logger::error!(?error, "Failed to fetch the profile for sca exemptions"),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn call_frm_before_connector_call<F, Req, D>(
     operation: &BoxedOperation<'_, F, Req, D>,
@@ -830,6 +888,11 @@ pub async fn make_fulfillment_api_call(
         )
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    if fraud_check.frm_name == native::NATIVE_FRM_CONNECTOR_NAME {
+        let fulfillment_response = native::get_native_frm_fulfillment_response(&req);
+        update_fraud_check_after_fulfillment(db, fraud_check).await?;
+        return Ok(services::ApplicationResponse::Json(fulfillment_response));
+    }
     let connector_data = FraudCheckConnectorData::get_connector_by_name(&fraud_check.frm_name)?;
     let connector_integration: services::BoxedFrmConnectorIntegrationInterface<
         Fulfillment,
@@ -855,6 +918,25 @@ pub async fn make_fulfillment_api_call(
     )
     .await
     .to_payment_failed_response()?;
+    update_fraud_check_after_fulfillment(db, fraud_check).await?;
+    let fulfillment_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector_data.connector_name.clone().to_string(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+    Ok(services::ApplicationResponse::Json(fulfillment_response))
+}
+
+#[cfg(feature = "v1")]
+async fn update_fraud_check_after_fulfillment(
+    db: &dyn StorageInterface,
+    fraud_check: FraudCheck,
+) -> RouterResult<()> {
     let fraud_check_copy = fraud_check.clone();
     let fraud_check_update = FraudCheckUpdate::ResponseUpdate {
         frm_status: fraud_check.frm_status,
@@ -870,15 +952,5 @@ pub async fn make_fulfillment_api_call(
         .update_fraud_check_response_with_attempt_id(fraud_check_copy, fraud_check_update)
         .await
         .map_err(|error| error.change_context(errors::ApiErrorResponse::PaymentNotFound))?;
-    let fulfillment_response =
-        response
-            .response
-            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
-                code: err.code,
-                message: err.message,
-                connector: connector_data.connector_name.clone().to_string(),
-                status_code: err.status_code,
-                reason: err.reason,
-            })?;
-    Ok(services::ApplicationResponse::Json(fulfillment_response))
+    Ok(())
 }
@@ -0,0 +1,878 @@
+use api_models::{
+    enums as api_enums,
+    fraud_check::{
+        FrmRiskLevelThresholds, FrmRuleDecision, FrmRulesRecord, FrmRulesRequest, FrmSignalsConfig,
+    },
+    payments::AdditionalPaymentData,
+};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    ext_traits::{Encode, StringExt, ValueExt},
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
+use hyperswitch_domain_models::payments::payment_intent;
+use hyperswitch_masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+
+use super::types::{FrmConfigsObject, FrmData};
+use crate::{
+    connector::utils::PaymentsAttemptData,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api::routing::FrmRoutingAlgorithm, domain, fraud_check as frm_types,
+        storage::enums::FraudCheckStatus, ResponseId, RouterData,
+    },
+};
+
+/// Name under which the native fraud engine is recorded in place of an external FRM connector
+pub const NATIVE_FRM_CONNECTOR_NAME: &str = "native_frm";
+
+const IP_COUNTRY_METADATA_KEY: &str = "ip_country";
+
+#[instrument(skip(state))]
+pub async fn upsert_frm_rules(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: FrmRulesRequest,
+) -> RouterResponse<FrmRulesRecord> {
+    let db = state.store.as_ref();
+    validate_profile_access(profile_id.as_ref(), &request.profile_id)?;
+    core_utils::validate_and_get_business_profile(
+        db,
+        platform.get_processor(),
+        Some(&request.profile_id),
+    )
+    .await?;
+
+    euclid::frontend::ast::lowering::lower_program(request.program.clone())
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid Request Data".to_string(),
+        })
+        .attach_printable("The Request has an Invalid Comparison")?;
+    validate_signals_config(&request.signals)?;
+
+    let key = request.profile_id.get_frm_rules_key();
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    match find_frm_rules(&state, &request.profile_id).await? {
+        Some(previous_record) => {
+            let new_record = FrmRulesRecord {
+                profile_id: request.profile_id,
+                name: request.name.unwrap_or(previous_record.name),
+                signals: request.signals,
+                program: request.program,
+                preferred_flow_type: request
+                    .preferred_flow_type
+                    .unwrap_or(previous_record.preferred_flow_type),
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+
+            let updated_config = configs::ConfigUpdate::Update {
+                config: Some(
+                    new_record
+                        .encode_to_string_of_json()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Unable to serialize config to string")?,
+                ),
+            };
+
+            db.update_config_by_key(&key, updated_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the frm rules config")?;
+
+            Ok(services::ApplicationResponse::Json(new_record))
+        }
+        None => {
+            let new_record = FrmRulesRecord {
+                profile_id: request.profile_id,
+                name: request
+                    .name
+                    .ok_or(errors::ApiErrorResponse::MissingRequiredField { field_name: "name" })?,
+                signals: request.signals,
+                program: request.program,
+                preferred_flow_type: request
+                    .preferred_flow_type
+                    .unwrap_or(api_enums::FrmPreferredFlowTypes::Pre),
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+
+            let new_config = configs::ConfigNew {
+                key,
+                config: new_record
+                    .encode_to_string_of_json()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unable to serialize config to string")?,
+            };
+
+            db.insert_config(new_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the frm rules config")?;
+
+            Ok(services::ApplicationResponse::Json(new_record))
+        }
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_frm_rules(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: common_utils::id_type::ProfileId,
+) -> RouterResponse<FrmRulesRecord> {
+    validate_profile_access(profile_id.as_ref(), &request)?;
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        platform.get_processor(),
+        Some(&request),
+    )
+    .await?;
+
+    let record = find_frm_rules(&state, &request).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "frm rules not found for profile {}",
+                request.get_string_repr()
+            ),
+        },
+    )?;
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_frm_rules(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: common_utils::id_type::ProfileId,
+) -> RouterResponse<()> {
+    validate_profile_access(profile_id.as_ref(), &request)?;
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        platform.get_processor(),
+        Some(&request),
+    )
+    .await?;
+
+    state
+        .store
+        .delete_config_by_key(&request.get_frm_rules_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "frm rules not found for profile {}",
+                request.get_string_repr()
+            ),
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+pub async fn find_frm_rules(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<Option<FrmRulesRecord>> {
+    match state
+        .store
+        .find_config_by_key(&profile_id.get_frm_rules_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("FrmRulesRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the frm rules record")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the frm rules config"),
+    }
+}
+
+/// Profiles with fraud rules are checked by the native fraud engine, on every payment method and
+/// gateway
+pub async fn should_call_native_frm(
+    state: &SessionState,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+) -> RouterResult<(
+    bool,
+    Option<FrmRoutingAlgorithm>,
+    Option<common_utils::id_type::ProfileId>,
+    Option<FrmConfigsObject>,
+)> {
+    let Some(profile_id) = profile_id else {
+        return Ok((false, None, None, None));
+    };
+
+    Ok(match find_frm_rules(state, profile_id).await? {
+        Some(record) => (
+            true,
+            Some(FrmRoutingAlgorithm {
+                data: NATIVE_FRM_CONNECTOR_NAME.to_string(),
+                algorithm_type: "single".to_string(),
+            }),
+            Some(profile_id.clone()),
+            Some(FrmConfigsObject {
+                frm_enabled_pm: None,
+                frm_enabled_gateway: None,
+                frm_preferred_flow_type: record.preferred_flow_type,
+            }),
+        ),
+        None => {
+            logger::debug!("No frm rules found for the native fraud engine");
+            (false, None, None, None)
+        }
+    })
+}
+
+/// The native fraud engine needs no credentials, the router data of its flows is built with an
+/// empty connector account
+pub fn get_native_frm_merchant_connector_account() -> helpers::MerchantConnectorAccountType {
+    helpers::MerchantConnectorAccountType::CacheVal(api_models::admin::MerchantConnectorDetails {
+        connector_account_details: serde_json::json!({ "auth_type": "NoKey" }).into(),
+        metadata: None,
+    })
+}
+
+/// Fills in the response of a fraud check flow from the native fraud engine. Payments are scored
+/// once, the decision is reused by the later steps of the same fraud check.
+#[instrument(skip_all)]
+pub async fn execute_native_frm_flow<F, Req>(
+    state: &SessionState,
+    frm_data: &FrmData,
+    mut router_data: RouterData<F, Req, frm_types::FraudCheckResponseData>,
+) -> RouterResult<RouterData<F, Req, frm_types::FraudCheckResponseData>> {
+    let fraud_check = &frm_data.fraud_check;
+    let resource_id = ResponseId::ConnectorTransactionId(fraud_check.frm_id.clone());
+
+    router_data.response = match router_data.response {
+        Ok(frm_types::FraudCheckResponseData::TransactionResponse { .. })
+            if fraud_check.frm_status != FraudCheckStatus::Pending =>
+        {
+            Ok(frm_types::FraudCheckResponseData::TransactionResponse {
+                resource_id,
+                status: fraud_check.frm_status,
+                connector_metadata: fraud_check.metadata.clone(),
+                reason: fraud_check.frm_reason.clone(),
+                score: fraud_check.frm_score,
+            })
+        }
+        Ok(frm_types::FraudCheckResponseData::TransactionResponse { .. }) => {
+            let profile_id = &frm_data.connector_details.profile_id;
+            let record = find_frm_rules(state, profile_id).await?.ok_or(
+                errors::ApiErrorResponse::GenericNotFoundError {
+                    message: format!(
+                        "frm rules not found for profile {}",
+                        profile_id.get_string_repr()
+                    ),
+                },
+            )?;
+            let assessment = assess_payment(state, frm_data, &record.signals).await;
+            let backend_input = get_frm_rules_backend_input(frm_data, &assessment)?;
+            let interpreter = backend::VirInterpreterBackend::with_program(record.program)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error initializing DSL interpreter backend")?;
+            let output = interpreter
+                .execute(backend_input)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error executing frm rules")?;
+            let decision = output.get_output().decision;
+            logger::debug!(
+                score = assessment.score,
+                risk_level = assessment.risk_level,
+                %decision,
+                "native frm decision"
+            );
+
+            Ok(frm_types::FraudCheckResponseData::TransactionResponse {
+                resource_id,
+                status: match decision {
+                    FrmRuleDecision::Accept => FraudCheckStatus::Legit,
+                    FrmRuleDecision::Review => FraudCheckStatus::ManualReview,
+                    FrmRuleDecision::Decline => FraudCheckStatus::Fraud,
+                },
+                connector_metadata: Some(serde_json::json!({
+                    "risk_level": assessment.risk_level,
+                    "signals": assessment.triggered_signals,
+                })),
+                reason: output.rule_name.map(serde_json::Value::String),
+                score: Some(i32::from(assessment.score)),
+            })
+        }
+        Ok(frm_types::FraudCheckResponseData::RecordReturnResponse { .. }) => {
+            Ok(frm_types::FraudCheckResponseData::RecordReturnResponse {
+                resource_id,
+                connector_metadata: None,
+                return_id: frm_data
+                    .refund
+                    .as_ref()
+                    .map(|refund| refund.refund_id.clone()),
+            })
+        }
+        response => response,
+    };
+
+    Ok(router_data)
+}
+
+/// The native fraud engine keeps no shipment records, fulfillments are acknowledged as is
+pub fn get_native_frm_fulfillment_response(
+    request: &super::types::FrmFulfillmentRequest,
+) -> frm_types::FraudCheckResponseData {
+    frm_types::FraudCheckResponseData::FulfillmentResponse {
+        order_id: request.order_id.clone(),
+        shipment_ids: request
+            .fulfillments
+            .iter()
+            .map(|fulfillment| fulfillment.shipment_id.clone())
+            .collect(),
+    }
+}
+
+struct PaymentAssessment {
+    score: u8,
+    risk_level: &'static str,
+    triggered_signals: Vec<&'static str>,
+}
+
+/// Scores the payment with the configured signals. A signal which cannot be evaluated, for
+/// instance when redis is unavailable, is not triggered.
+async fn assess_payment(
+    state: &SessionState,
+    frm_data: &FrmData,
+    signals: &FrmSignalsConfig,
+) -> PaymentAssessment {
+    let card_info = get_additional_card_info(frm_data);
+    let mut weighted_signals = Vec::new();
+
+    if let Some(signal) = &signals.bin_ip_country_mismatch {
+        let ip_country = frm_data.frm_metadata.as_ref().and_then(|frm_metadata| {
+            frm_metadata
+                .peek()
+                .get(IP_COUNTRY_METADATA_KEY)
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_owned)
+        });
+        let card_country = card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_issuing_country_code.as_deref());
+        let is_triggered = is_country_mismatch(card_country, ip_country.as_deref());
+        weighted_signals.push(("bin_ip_country_mismatch", signal.weight, is_triggered));
+    }
+
+    if let Some(signal) = &signals.email_domain {
+        let is_triggered = get_customer_email(frm_data)
+            .is_some_and(|email| is_listed_email_domain(&email, &signal.domains));
+        weighted_signals.push(("email_domain", signal.weight, is_triggered));
+    }
+
+    if let Some(signal) = &signals.velocity {
+        let is_triggered = is_velocity_exceeded(state, frm_data, signal).await;
+        weighted_signals.push(("velocity", signal.weight, is_triggered));
+    }
+
+    if let Some(signal) = &signals.amount_outlier {
+        let is_triggered = is_amount_outlier(state, frm_data, signal).await;
+        weighted_signals.push(("amount_outlier", signal.weight, is_triggered));
+    }
+
+    if let Some(signal) = &signals.blocklist_proximity {
+        let is_triggered = is_near_blocklist(state, frm_data, card_info.as_ref()).await;
+        weighted_signals.push(("blocklist_proximity", signal.weight, is_triggered));
+    }
+
+    score_signals(weighted_signals, &signals.risk_level_thresholds)
+}
+
+/// Adds up the weights of the triggered signals into a score capped at 100, which is then mapped
+/// to a risk level
+fn score_signals(
+    weighted_signals: Vec<(&'static str, u8, bool)>,
+    thresholds: &FrmRiskLevelThresholds,
+) -> PaymentAssessment {
+    let (score, triggered_signals) = weighted_signals.into_iter().fold(
+        (0u8, Vec::new()),
+        |(score, mut triggered_signals), (signal, weight, is_triggered)| {
+            if is_triggered {
+                triggered_signals.push(signal);
+                (score.saturating_add(weight).min(100), triggered_signals)
+            } else {
+                (score, triggered_signals)
+            }
+        },
+    );
+    let risk_level = if score >= thresholds.high {
+        "high"
+    } else if score >= thresholds.medium {
+        "medium"
+    } else {
+        "low"
+    };
+
+    PaymentAssessment {
+        score,
+        risk_level,
+        triggered_signals,
+    }
+}
+
+/// The countries are only compared when both the issuing country of the card and the country of
+/// the IP address are known
+fn is_country_mismatch(card_country: Option<&str>, ip_country: Option<&str>) -> bool {
+    card_country
+        .zip(ip_country)
+        .is_some_and(|(card_country, ip_country)| !card_country.eq_ignore_ascii_case(ip_country))
+}
+
+/// Subdomains of a listed domain are listed as well
+fn is_listed_email_domain(email: &str, domains: &[String]) -> bool {
+    email
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.to_lowercase())
+        .is_some_and(|domain| {
+            domains.iter().any(|listed_domain| {
+                let listed_domain = listed_domain.to_lowercase();
+                domain == listed_domain || domain.ends_with(&format!(".{listed_domain}"))
+            })
+        })
+}
+
+async fn is_velocity_exceeded(
+    state: &SessionState,
+    frm_data: &FrmData,
+    signal: &api_models::fraud_check::FrmVelocitySignal,
+) -> bool {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for frm velocity");
+            return false;
+        }
+    };
+    let browser_info = frm_data.payment_attempt.get_browser_info().ok();
+    let identifiers = [
+        (
+            "customer",
+            frm_data
+                .payment_intent
+                .customer_id
+                .as_ref()
+                .map(|customer_id| customer_id.get_string_repr().to_owned()),
+        ),
+        ("email", get_customer_email(frm_data)),
+        (
+            "ip",
+            browser_info
+                .and_then(|browser_info| browser_info.ip_address)
+                .map(|ip_address| ip_address.to_string()),
+        ),
+        ("card", frm_data.payment_attempt.fingerprint_id.clone()),
+    ];
+
+    let mut is_exceeded = false;
+    for (kind, value) in identifiers {
+        let Some(value) = value else {
+            continue;
+        };
+        // Identifiers are hashed to keep customer data out of redis
+        let Ok(digest) = crypto::Sha256.generate_digest(value.as_bytes()) else {
+            continue;
+        };
+        let key = format!(
+            "frm_velocity_{}_{kind}_{}",
+            frm_data.merchant_account.get_id().get_string_repr(),
+            hex::encode(digest)
+        );
+        let count = match redis_conn
+            .increment_fields_in_hash(&key.as_str().into(), &[("count", 1)])
+            .await
+        {
+            Ok(values) => values.first().copied().unwrap_or_default(),
+            Err(error) => {
+                logger::error!(?error, "Failed to increment frm velocity counter");
+                continue;
+            }
+        };
+        if count == 1 {
+            let _ = redis_conn
+                .set_expiry(&key.as_str().into(), signal.window_in_secs)
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to set frm velocity expiry"));
+        }
+        is_exceeded |= count > usize::try_from(signal.max_attempts).unwrap_or(usize::MAX);
+    }
+    is_exceeded
+}
+
+/// Compares the amount against the running average of the payments of the merchant in the
+/// currency, which includes every payment scored so far
+async fn is_amount_outlier(
+    state: &SessionState,
+    frm_data: &FrmData,
+    signal: &api_models::fraud_check::FrmAmountOutlierSignal,
+) -> bool {
+    let Some(currency) = frm_data.payment_attempt.currency else {
+        return false;
+    };
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for frm amount stats"
+            );
+            return false;
+        }
+    };
+    let amount = frm_data
+        .payment_attempt
+        .net_amount
+        .get_total_amount()
+        .get_amount_as_i64();
+    let key = format!(
+        "frm_amount_stats_{}_{currency}",
+        frm_data.merchant_account.get_id().get_string_repr()
+    );
+
+    match redis_conn
+        .increment_fields_in_hash(&key.as_str().into(), &[("count", 1), ("sum", amount)])
+        .await
+        .as_deref()
+    {
+        Ok(&[count, sum]) => is_above_average_amount(amount, count, sum, signal),
+        Ok(_) => false,
+        Err(error) => {
+            logger::error!(?error, "Failed to update frm amount stats");
+            false
+        }
+    }
+}
+
+/// The running count and sum include the amount being scored, it is compared against the average
+/// of the payments before it
+fn is_above_average_amount(
+    amount: i64,
+    count: usize,
+    sum: usize,
+    signal: &api_models::fraud_check::FrmAmountOutlierSignal,
+) -> bool {
+    let previous_count = u64::try_from(count.saturating_sub(1)).unwrap_or_default();
+    let previous_sum = i64::try_from(sum)
+        .unwrap_or_default()
+        .saturating_sub(amount);
+    previous_count >= u64::from(signal.min_samples)
+        && previous_count > 0
+        && i128::from(amount) * i128::from(previous_count)
+            > i128::from(previous_sum) * i128::from(signal.multiplier)
+}
+
+/// Checks whether the BIN or the extended BIN of the card is blocked, exact card matches are
+/// already declined by the blocklist guard
+async fn is_near_blocklist(
+    state: &SessionState,
+    frm_data: &FrmData,
+    card_info: Option<&api_models::payments::AdditionalCardInfo>,
+) -> bool {
+    let Some(card_info) = card_info else {
+        return false;
+    };
+    let merchant_id = frm_data.merchant_account.get_id();
+    let bins = [
+        card_info.card_isin.as_ref(),
+        card_info.card_extended_bin.as_ref(),
+    ];
+
+    let mut is_near = false;
+    for bin in bins.into_iter().flatten() {
+        match state
+            .store
+            .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+            .await
+        {
+            Ok(_) => is_near = true,
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => {
+                logger::error!(blocklist_db_error=?error, "failed db operations for blocklist");
+            }
+        }
+    }
+    is_near
+}
+
+fn get_additional_card_info(
+    frm_data: &FrmData,
+) -> Option<api_models::payments::AdditionalCardInfo> {
+    frm_data
+        .payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|payment_method_data| match payment_method_data {
+            AdditionalPaymentData::Card(card_info) => Some(*card_info),
+            _ => None,
+        })
+}
+
+fn get_customer_email(frm_data: &FrmData) -> Option<String> {
+    frm_data
+        .payment_intent
+        .customer_details
+        .clone()
+        .and_then(|customer_details| {
+            customer_details
+                .into_inner()
+                .expose()
+                .parse_value::<payment_intent::CustomerData>("CustomerData")
+                .ok()
+        })
+        .and_then(|customer_data| customer_data.email)
+        .or_else(|| {
+            frm_data
+                .address
+                .get_payment_billing()
+                .and_then(|billing| billing.email.clone())
+        })
+        .map(|email| email.peek().to_owned())
+}
+
+fn get_frm_rules_backend_input(
+    frm_data: &FrmData,
+    assessment: &PaymentAssessment,
+) -> RouterResult<dsl_inputs::BackendInput> {
+    let card_info = get_additional_card_info(frm_data);
+    let currency = frm_data
+        .payment_attempt
+        .currency
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the currency of the payment")?;
+
+    let metadata = std::iter::once(("risk_level".to_string(), assessment.risk_level.to_string()))
+        .chain(
+            assessment
+                .triggered_signals
+                .iter()
+                .map(|signal| (signal.to_string(), true.to_string())),
+        )
+        .collect();
+
+    Ok(dsl_inputs::BackendInput {
+        metadata: Some(metadata),
+        payment: dsl_inputs::PaymentInput {
+            amount: frm_data.payment_attempt.net_amount.get_total_amount(),
+            currency,
+            authentication_type: frm_data.payment_attempt.authentication_type,
+            card_bin: card_info
+                .as_ref()
+                .and_then(|card_info| card_info.card_isin.clone()),
+            extended_card_bin: None,
+            capture_method: frm_data.payment_attempt.capture_method,
+            business_country: None,
+            billing_country: frm_data
+                .address
+                .get_payment_billing()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country)
+                .map(common_enums::Country::from_alpha2),
+            business_label: None,
+            setup_future_usage: None,
+            transaction_initiator: None,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: frm_data.payment_attempt.payment_method,
+            payment_method_type: frm_data.payment_attempt.payment_method_type,
+            card_network: card_info
+                .as_ref()
+                .and_then(|card_info| card_info.card_network.clone()),
+            card_discovery: None,
+        },
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: card_info.map(|card_info| dsl_inputs::IssuerDataInput {
+            name: card_info.card_issuer,
+            country: card_info
+                .card_issuing_country_code
+                .and_then(|country_code| country_code.parse().ok())
+                .map(common_enums::Country::from_alpha2),
+        }),
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+    })
+}
+
+fn validate_signals_config(signals: &FrmSignalsConfig) -> RouterResult<()> {
+    let thresholds = &signals.risk_level_thresholds;
+    if thresholds.medium > thresholds.high || thresholds.high > 100 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "risk level thresholds must satisfy medium <= high <= 100".to_string(),
+        })?
+    }
+    if signals
+        .velocity
+        .as_ref()
+        .is_some_and(|velocity| velocity.window_in_secs <= 0)
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "velocity window_in_secs must be positive".to_string(),
+        })?
+    }
+    Ok(())
+}
+
+fn validate_profile_access(
+    profile_id_auth_layer: Option<&common_utils::id_type::ProfileId>,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<()> {
+    match profile_id_auth_layer {
+        Some(auth_profile_id) if auth_profile_id != profile_id => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Profile id authentication failed. Please use the correct JWT token"
+                    .to_string(),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::fraud_check::{FrmAmountOutlierSignal, FrmVelocitySignal};
+
+    use super::*;
+
+    #[test]
+    fn test_triggered_signal_weights_are_added_up() {
+        let assessment = score_signals(
+            vec![
+                ("bin_ip_country_mismatch", 40, true),
+                ("email_domain", 50, false),
+                ("velocity", 30, true),
+            ],
+            &FrmRiskLevelThresholds::default(),
+        );
+
+        assert_eq!(assessment.score, 70);
+        assert_eq!(assessment.risk_level, "high");
+        assert_eq!(
+            assessment.triggered_signals,
+            ["bin_ip_country_mismatch", "velocity"]
+        );
+    }
+
+    #[test]
+    fn test_score_is_capped_at_100() {
+        let assessment = score_signals(
+            vec![("velocity", 80, true), ("amount_outlier", 80, true)],
+            &FrmRiskLevelThresholds::default(),
+        );
+
+        assert_eq!(assessment.score, 100);
+        assert_eq!(assessment.risk_level, "high");
+    }
+
+    #[test]
+    fn test_risk_level_thresholds_are_inclusive() {
+        let thresholds = FrmRiskLevelThresholds {
+            medium: 30,
+            high: 60,
+        };
+        let risk_level =
+            |weight| score_signals(vec![("velocity", weight, true)], &thresholds).risk_level;
+
+        assert_eq!(risk_level(0), "low");
+        assert_eq!(risk_level(29), "low");
+        assert_eq!(risk_level(30), "medium");
+        assert_eq!(risk_level(59), "medium");
+        assert_eq!(risk_level(60), "high");
+        assert_eq!(score_signals(Vec::new(), &thresholds).risk_level, "low");
+    }
+
+    #[test]
+    fn test_country_mismatch_requires_both_countries() {
+        assert!(is_country_mismatch(Some("US"), Some("IN")));
+        assert!(!is_country_mismatch(Some("US"), Some("us")));
+        assert!(!is_country_mismatch(Some("US"), None));
+        assert!(!is_country_mismatch(None, Some("IN")));
+    }
+
+    #[test]
+    fn test_listed_email_domains_match_subdomains() {
+        let domains = ["Disposable.example".to_string()];
+
+        assert!(is_listed_email_domain("john@disposable.example", &domains));
+        assert!(is_listed_email_domain(
+            "john@mail.DISPOSABLE.example",
+            &domains
+        ));
+        assert!(!is_listed_email_domain(
+            "john@notdisposable.example",
+            &domains
+        ));
+        assert!(!is_listed_email_domain("disposable.example", &domains));
+    }
+
+    #[test]
+    fn test_amount_outlier_compares_against_previous_average() {
+        let signal = FrmAmountOutlierSignal {
+            weight: 20,
+            multiplier: 3,
+            min_samples: 3,
+        };
+
+        // Three previous payments averaging 100
+        assert!(is_above_average_amount(400, 4, 700, &signal));
+        assert!(!is_above_average_amount(300, 4, 600, &signal));
+        // Only two previous payments
+        assert!(!is_above_average_amount(400, 3, 600, &signal));
+        // The first payment of the currency
+        assert!(!is_above_average_amount(
+            400,
+            1,
+            400,
+            &FrmAmountOutlierSignal {
+                min_samples: 0,
+                ..signal
+            }
+        ));
+    }
+
+    #[test]
+    fn test_signals_config_validation() {
+        let signals = |medium, high, window_in_secs| FrmSignalsConfig {
+            velocity: Some(FrmVelocitySignal {
+                weight: 20,
+                max_attempts: 5,
+                window_in_secs,
+            }),
+            risk_level_thresholds: FrmRiskLevelThresholds { medium, high },
+            ..Default::default()
+        };
+
+        assert!(validate_signals_config(&signals(30, 60, 3600)).is_ok());
+        assert!(validate_signals_config(&signals(60, 60, 3600)).is_ok());
+        assert!(validate_signals_config(&signals(70, 60, 3600)).is_err());
+        assert!(validate_signals_config(&signals(30, 101, 3600)).is_err());
+        assert!(validate_signals_config(&signals(30, 60, 0)).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+#[cfg(feature = "v1")]
+pub mod journal;
+
+use std::collections::BTreeMap;
+
+use api_models::ledger as ledger_models;
+use common_utils::types::MinorUnit;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{domain, storage, transformers::ForeignFrom},
+};
+
+#[instrument(skip(state))]
+pub async fn create_ledger_fee_schedule(
+    state: SessionState,
+    platform: domain::Platform,
+    req: ledger_models::LedgerFeeScheduleCreateRequest,
+) -> RouterResponse<ledger_models::LedgerFeeScheduleResponse> {
+    if req.percentage_fee_basis_points > 10_000 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "percentage_fee_basis_points must not exceed 10000".to_string(),
+        }));
+    }
+    if req.fixed_fee < MinorUnit::zero() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "fixed_fee must not be negative".to_string(),
+        }));
+    }
+
+    let now = common_utils::date_time::now();
+    let fee_schedule = storage::LedgerFeeScheduleNew {
+        fee_schedule_id: common_utils::generate_id(consts::ID_LENGTH, "lfs"),
+        merchant_id: platform.get_processor().get_account().get_id().clone(),
+        connector: req.connector,
+        payment_method: req.payment_method,
+        currency: req.currency,
+        fixed_fee: req.fixed_fee,
+        percentage_fee_basis_points: i32::from(req.percentage_fee_basis_points),
+        created_at: now,
+        modified_at: now,
+    };
+
+    let fee_schedule = state
+        .store
+        .insert_ledger_fee_schedule(fee_schedule)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert ledger fee schedule")?;
+
+    Ok(services::ApplicationResponse::Json(
+        ledger_models::LedgerFeeScheduleResponse::foreign_from(fee_schedule),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_ledger_fee_schedules(
+    state: SessionState,
+    platform: domain::Platform,
+) -> RouterResponse<Vec<ledger_models::LedgerFeeScheduleResponse>> {
+    let fee_schedules = state
+        .store
+        .list_ledger_fee_schedules_by_merchant_id(platform.get_processor().get_account().get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list ledger fee schedules")?;
+
+    Ok(services::ApplicationResponse::Json(
+        fee_schedules
+            .into_iter()
+            .map(ledger_models::LedgerFeeScheduleResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_ledger_fee_schedule(
+    state: SessionState,
+    platform: domain::Platform,
+    req: ledger_models::LedgerFeeScheduleId,
+) -> RouterResponse<ledger_models::LedgerFeeScheduleResponse> {
+    let fee_schedule = state
+        .store
+        .delete_ledger_fee_schedule_by_merchant_id_fee_schedule_id(
+            platform.get_processor().get_account().get_id(),
+            &req.fee_schedule_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("ledger fee schedule {} not found", req.fee_schedule_id),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        ledger_models::LedgerFeeScheduleResponse::foreign_from(fee_schedule),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn get_ledger_balance(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: ledger_models::LedgerBalanceRequest,
+) -> RouterResponse<ledger_models::LedgerBalanceResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let profile_id = get_profile_id_for_ledger_query(profile_id, req.profile_id)?;
+    let constraints = storage::LedgerPostingConstraints {
+        profile_id: profile_id.clone(),
+        currency: req.currency,
+        time_range: req.time_range,
+        ..Default::default()
+    };
+
+    let balances = state
+        .store
+        .get_ledger_balances(merchant_id, &constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute ledger balances")?;
+
+    let mut balances_by_currency = BTreeMap::<String, ledger_models::LedgerCurrencyBalance>::new();
+    for (account, currency, debit_balance) in balances {
+        // Balances are reported on the normal side of each account
+        let balance = if account.is_debit_normal() {
+            debit_balance
+        } else {
+            -debit_balance
+        };
+        let currency_balance = balances_by_currency
+            .entry(currency.to_string())
+            .or_insert_with(|| ledger_models::LedgerCurrencyBalance {
+                currency,
+                accounts: Vec::new(),
+                net_settlement: MinorUnit::zero(),
+            });
+        if account == common_enums::LedgerAccount::ConnectorReceivable {
+            currency_balance.net_settlement = MinorUnit::new(balance);
+        }
+        currency_balance
+            .accounts
+            .push(ledger_models::LedgerAccountBalance {
+                account,
+                balance: MinorUnit::new(balance),
+            });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        ledger_models::LedgerBalanceResponse {
+            merchant_id: merchant_id.clone(),
+            profile_id,
+            balances: balances_by_currency.into_values().collect(),
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn get_ledger_statement(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: ledger_models::LedgerStatementRequest,
+) -> RouterResponse<ledger_models::LedgerStatementResponse> {
+    let constraints = storage::LedgerPostingConstraints {
+        profile_id: get_profile_id_for_ledger_query(profile_id, req.profile_id)?,
+        currency: req.currency,
+        account: req.account,
+        time_range: req.time_range,
+        limit: Some(i64::from(
+            req.limit.unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into()),
+        )),
+        offset: req.offset.map(i64::from),
+    };
+
+    let postings = state
+        .store
+        .filter_ledger_postings_by_constraints(
+            platform.get_processor().get_account().get_id(),
+            &constraints,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to filter ledger postings")?;
+
+    let data = postings
+        .into_iter()
+        .map(ledger_models::LedgerStatementLine::foreign_from)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        ledger_models::LedgerStatementResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+fn get_profile_id_for_ledger_query(
+    profile_id_from_auth_layer: Option<common_utils::id_type::ProfileId>,
+    requested_profile_id: Option<common_utils::id_type::ProfileId>,
+) -> RouterResult<Option<common_utils::id_type::ProfileId>> {
+    match (profile_id_from_auth_layer, requested_profile_id) {
+        (Some(auth_profile_id), Some(requested_profile_id))
+            if auth_profile_id != requested_profile_id =>
+        {
+            Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: requested_profile_id.get_string_repr().to_owned(),
+            }))
+        }
+        (auth_profile_id, requested_profile_id) => Ok(auth_profile_id.or(requested_profile_id)),
+    }
+}
+
+impl ForeignFrom<storage::LedgerFeeSchedule> for ledger_models::LedgerFeeScheduleResponse {
+    fn foreign_from(fee_schedule: storage::LedgerFeeSchedule) -> Self {
+        Self {
+            fee_schedule_id: fee_schedule.fee_schedule_id,
+            merchant_id: fee_schedule.merchant_id,
+            connector: fee_schedule.connector,
+            payment_method: fee_schedule.payment_method,
+            currency: fee_schedule.currency,
+            fixed_fee: fee_schedule.fixed_fee,
+            percentage_fee_basis_points: fee_schedule.percentage_fee_basis_points,
+            created_at: fee_schedule.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::LedgerPosting> for ledger_models::LedgerStatementLine {
+    fn foreign_from(posting: storage::LedgerPosting) -> Self {
+        Self {
+            posting_id: posting.posting_id,
+            entry_id: posting.entry_id,
+            profile_id: posting.profile_id,
+            connector: posting.connector,
+            account: posting.account,
+            direction: posting.direction,
+            amount: posting.amount,
+            currency: posting.currency,
+            created_at: posting.created_at,
+        }
+    }
+}
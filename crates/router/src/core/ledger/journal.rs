@@ -0,0 +1,651 @@
+//! Derivation of double-entry journal entries from the captures of payments and the state
+//! transitions of refunds, disputes and payouts.
+//!
+//! The storage layer only detects the transitions to be posted and schedules a
+//! [`storage::ProcessTrackerRunner::LedgerPostingWorkflow`] task for each of them, so the journal
+//! entries are posted by the scheduler consumer off the request path.
+//!
+//! Every journal entry is keyed by the merchant, the entry type and the identifier of the object
+//! which transitioned, so replaying a transition does not post it twice.
+
+use std::str::FromStr;
+
+use common_enums::{LedgerAccount, LedgerEntryType, LedgerPostingDirection};
+use common_utils::{id_type, types::MinorUnit};
+use diesel_models::refund as diesel_refund;
+use router_env::{instrument, logger, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerInterface, utils as pt_utils};
+
+use crate::{
+    consts,
+    core::errors::{self, CustomResult},
+    db::StorageInterface,
+    routes::SessionState,
+    types::storage::{self, enums as storage_enums},
+};
+
+const BASIS_POINTS_PER_UNIT: i64 = 10_000;
+
+const LEDGER_POSTING_WORKFLOW_TAG: &str = "LEDGER";
+
+const LEDGER_POSTING_WORKFLOW_TASK: &str = "LEDGER_POSTING";
+
+/// The number of times a ledger posting is retried before the task is marked as failed.
+pub const LEDGER_POSTING_MAX_RETRIES: i32 = 5;
+
+/// The delay in seconds before the first retry of a ledger posting, which grows linearly with
+/// every retry.
+pub const LEDGER_POSTING_RETRY_INTERVAL: i64 = 60;
+
+/// The amount of a transition to be posted to the ledger.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LedgerPostingAmount {
+    /// The amount is known when the transition is detected.
+    Fixed { amount: MinorUnit },
+    /// The amount captured on a payment since its previous capture. The payment intent does not
+    /// hold the connector and payment method the fee schedule is selected by, so they are read
+    /// from the attempt when the capture is posted.
+    PaymentCapture {
+        processor_merchant_id: id_type::MerchantId,
+        attempt_id: String,
+        amount: MinorUnit,
+    },
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct LedgerPostingTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub entry_type: LedgerEntryType,
+    pub reference_id: String,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub currency: storage_enums::Currency,
+    pub amount: LedgerPostingAmount,
+}
+
+struct LedgerTransaction {
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    connector: Option<String>,
+    entry_type: LedgerEntryType,
+    reference_id: String,
+    payment_id: Option<id_type::PaymentId>,
+    currency: storage_enums::Currency,
+    postings: Vec<(LedgerAccount, LedgerPostingDirection, MinorUnit)>,
+}
+
+/// Returns the ledger posting of a payment intent update, if more of the payment was captured.
+/// Every capture is posted for the amount captured since the previous one, so partial and
+/// multiple captures keep the ledger in line with `amount_captured`.
+pub fn get_payment_capture_posting(
+    old_intent: &storage::PaymentIntent,
+    new_intent: &storage::PaymentIntent,
+) -> Option<LedgerPostingTrackingData> {
+    let amount = get_captured_amount_delta(old_intent.amount_captured, new_intent.amount_captured)?;
+    let Some(currency) = new_intent.currency else {
+        logger::warn!(
+            payment_id = ?new_intent.payment_id,
+            "Skipping ledger posting of payment capture without currency"
+        );
+        return None;
+    };
+    let attempt_id = new_intent.active_attempt.get_id();
+
+    Some(LedgerPostingTrackingData {
+        merchant_id: new_intent.merchant_id.clone(),
+        profile_id: new_intent.profile_id.clone(),
+        connector: None,
+        entry_type: LedgerEntryType::PaymentCaptured,
+        reference_id: get_capture_reference_id(
+            &attempt_id,
+            new_intent.amount_captured.unwrap_or_else(MinorUnit::zero),
+        ),
+        payment_id: Some(new_intent.payment_id.clone()),
+        payment_method: None,
+        currency,
+        amount: LedgerPostingAmount::PaymentCapture {
+            processor_merchant_id: new_intent.processor_merchant_id.clone(),
+            attempt_id,
+            amount,
+        },
+    })
+}
+
+/// Returns the amount captured between two states of a payment, if more of it was captured.
+fn get_captured_amount_delta(
+    old_amount_captured: Option<MinorUnit>,
+    new_amount_captured: Option<MinorUnit>,
+) -> Option<MinorUnit> {
+    let captured = |amount_captured: Option<MinorUnit>| {
+        amount_captured.map_or(0, |amount_captured| amount_captured.get_amount_as_i64())
+    };
+    let delta = captured(new_amount_captured).saturating_sub(captured(old_amount_captured));
+
+    (delta > 0).then(|| MinorUnit::new(delta))
+}
+
+/// The total captured so far only grows with every capture of the attempt, so it tells the
+/// captures apart while replaying the same capture maps to the same journal entry.
+fn get_capture_reference_id(attempt_id: &str, amount_captured: MinorUnit) -> String {
+    format!("{attempt_id}_{}", amount_captured.get_amount_as_i64())
+}
+
+/// Returns the ledger posting of a refund update, if the refund succeeded.
+pub fn get_refund_posting(
+    old_refund: &diesel_refund::Refund,
+    new_refund: &diesel_refund::Refund,
+) -> Option<LedgerPostingTrackingData> {
+    if new_refund.refund_status != storage_enums::RefundStatus::Success
+        || old_refund.refund_status == storage_enums::RefundStatus::Success
+    {
+        return None;
+    }
+
+    Some(LedgerPostingTrackingData {
+        merchant_id: new_refund.merchant_id.clone(),
+        profile_id: new_refund.profile_id.clone(),
+        connector: Some(new_refund.connector.clone()),
+        entry_type: LedgerEntryType::RefundSucceeded,
+        reference_id: new_refund.refund_id.clone(),
+        payment_id: Some(new_refund.payment_id.clone()),
+        payment_method: None,
+        currency: new_refund.currency,
+        amount: LedgerPostingAmount::Fixed {
+            amount: new_refund.refund_amount,
+        },
+    })
+}
+
+/// Returns the ledger posting of a dispute update, if the dispute was lost.
+pub fn get_dispute_posting(
+    old_dispute: &storage::Dispute,
+    new_dispute: &storage::Dispute,
+) -> Option<LedgerPostingTrackingData> {
+    if new_dispute.dispute_status != storage_enums::DisputeStatus::DisputeLost
+        || old_dispute.dispute_status == storage_enums::DisputeStatus::DisputeLost
+    {
+        return None;
+    }
+    let Some(currency) = new_dispute
+        .dispute_currency
+        .or_else(|| storage_enums::Currency::from_str(&new_dispute.currency.to_uppercase()).ok())
+    else {
+        logger::warn!(
+            dispute_id = %new_dispute.dispute_id,
+            "Skipping ledger posting of dispute with unknown currency"
+        );
+        return None;
+    };
+
+    Some(LedgerPostingTrackingData {
+        merchant_id: new_dispute.merchant_id.clone(),
+        profile_id: new_dispute.profile_id.clone(),
+        connector: Some(new_dispute.connector.clone()),
+        entry_type: LedgerEntryType::DisputeLost,
+        reference_id: new_dispute.dispute_id.clone(),
+        payment_id: Some(new_dispute.payment_id.clone()),
+        payment_method: None,
+        currency,
+        amount: LedgerPostingAmount::Fixed {
+            amount: new_dispute.dispute_amount,
+        },
+    })
+}
+
+/// Returns the ledger posting of a payout update, if the payout succeeded.
+#[cfg(feature = "payouts")]
+pub fn get_payout_posting(
+    old_payout: &storage::Payouts,
+    new_payout: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+) -> Option<LedgerPostingTrackingData> {
+    if new_payout.status != storage_enums::PayoutStatus::Success
+        || old_payout.status == storage_enums::PayoutStatus::Success
+    {
+        return None;
+    }
+
+    Some(LedgerPostingTrackingData {
+        merchant_id: new_payout.merchant_id.clone(),
+        profile_id: Some(new_payout.profile_id.clone()),
+        connector: payout_attempt.connector.clone(),
+        entry_type: LedgerEntryType::PayoutSucceeded,
+        reference_id: new_payout.payout_id.get_string_repr().to_owned(),
+        payment_id: None,
+        payment_method: None,
+        currency: new_payout.destination_currency,
+        amount: LedgerPostingAmount::Fixed {
+            amount: new_payout.amount,
+        },
+    })
+}
+
+/// Schedules the posting of a transition to the ledger. Scheduling the same transition again is
+/// a no-op.
+#[instrument(skip_all)]
+pub async fn schedule_ledger_posting(
+    db: &dyn ProcessTrackerInterface,
+    application_source: common_enums::ApplicationSource,
+    tracking_data: LedgerPostingTrackingData,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::LedgerPostingWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        LEDGER_POSTING_WORKFLOW_TASK,
+        &format!(
+            "{}_{}",
+            tracking_data.entry_type, tracking_data.reference_id
+        ),
+        &tracking_data.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        LEDGER_POSTING_WORKFLOW_TASK,
+        runner,
+        [LEDGER_POSTING_WORKFLOW_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+        application_source,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::debug!("Ledger posting has already been scheduled");
+            Ok(())
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Posts a scheduled transition to the ledger. Captures are posted for the amount captured on
+/// the payment, along with the processing fee charged by the connector as per the most specific
+/// fee schedule of the merchant.
+#[instrument(skip_all)]
+pub async fn post_ledger_entry(
+    state: &SessionState,
+    tracking_data: LedgerPostingTrackingData,
+) -> CustomResult<(), errors::StorageError> {
+    let db = &*state.store;
+    let LedgerPostingTrackingData {
+        merchant_id,
+        profile_id,
+        connector,
+        entry_type,
+        reference_id,
+        payment_id,
+        payment_method,
+        currency,
+        amount,
+    } = tracking_data;
+
+    let (amount, connector, payment_method) = match amount {
+        LedgerPostingAmount::Fixed { amount } => (amount, connector, payment_method),
+        LedgerPostingAmount::PaymentCapture {
+            processor_merchant_id,
+            attempt_id,
+            amount,
+        } => {
+            let payment_attempt =
+                get_capture_attempt(state, &processor_merchant_id, &attempt_id).await?;
+            (
+                amount,
+                payment_attempt.connector,
+                payment_attempt.payment_method,
+            )
+        }
+    };
+
+    let debit_account = match entry_type {
+        LedgerEntryType::PaymentCaptured => None,
+        LedgerEntryType::RefundSucceeded => Some(LedgerAccount::Refunds),
+        LedgerEntryType::DisputeLost => Some(LedgerAccount::Chargebacks),
+        LedgerEntryType::PayoutSucceeded => Some(LedgerAccount::Payouts),
+    };
+    let postings = match debit_account {
+        Some(debit_account) => vec![
+            (debit_account, LedgerPostingDirection::Debit, amount),
+            (
+                LedgerAccount::ConnectorReceivable,
+                LedgerPostingDirection::Credit,
+                amount,
+            ),
+        ],
+        None => {
+            let fee_schedules = db
+                .list_ledger_fee_schedules_by_merchant_id(&merchant_id)
+                .await?;
+            let fee = find_fee_schedule(
+                &fee_schedules,
+                connector.as_deref(),
+                payment_method,
+                currency,
+            )
+            .map(|fee_schedule| calculate_fee(fee_schedule, amount))
+            .unwrap_or_else(MinorUnit::zero);
+
+            vec![
+                (
+                    LedgerAccount::ConnectorReceivable,
+                    LedgerPostingDirection::Debit,
+                    amount - fee,
+                ),
+                (
+                    LedgerAccount::ProcessingFees,
+                    LedgerPostingDirection::Debit,
+                    fee,
+                ),
+                (LedgerAccount::Sales, LedgerPostingDirection::Credit, amount),
+            ]
+        }
+    };
+
+    record_transaction(
+        db,
+        LedgerTransaction {
+            merchant_id,
+            profile_id,
+            connector,
+            entry_type,
+            reference_id,
+            payment_id,
+            currency,
+            postings,
+        },
+    )
+    .await
+}
+
+/// Returns the attempt a capture was made with.
+async fn get_capture_attempt(
+    state: &SessionState,
+    processor_merchant_id: &id_type::MerchantId,
+    attempt_id: &str,
+) -> CustomResult<storage::PaymentAttempt, errors::StorageError> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            processor_merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(processor_merchant_id, &key_store)
+        .await?;
+
+    db.find_payment_attempt_by_attempt_id_processor_merchant_id(
+        attempt_id,
+        processor_merchant_id,
+        merchant_account.storage_scheme,
+        &key_store,
+    )
+    .await
+}
+
+/// Returns the fee schedule which matches the most attributes of the payment, preferring the
+/// connector over the payment method over the currency.
+fn find_fee_schedule<'a>(
+    fee_schedules: &'a [storage::LedgerFeeSchedule],
+    connector: Option<&str>,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    currency: storage_enums::Currency,
+) -> Option<&'a storage::LedgerFeeSchedule> {
+    fee_schedules
+        .iter()
+        .filter(|fee_schedule| {
+            fee_schedule
+                .connector
+                .as_deref()
+                .is_none_or(|fee_connector| Some(fee_connector) == connector)
+                && fee_schedule
+                    .payment_method
+                    .is_none_or(|fee_payment_method| Some(fee_payment_method) == payment_method)
+                && fee_schedule
+                    .currency
+                    .is_none_or(|fee_currency| fee_currency == currency)
+        })
+        .max_by_key(|fee_schedule| {
+            (
+                fee_schedule.connector.is_some(),
+                fee_schedule.payment_method.is_some(),
+                fee_schedule.currency.is_some(),
+                fee_schedule.created_at,
+            )
+        })
+}
+
+/// Calculates the fee of a captured amount, rounding the percentage fee half up. The fee never
+/// exceeds the captured amount.
+fn calculate_fee(
+    fee_schedule: &storage::LedgerFeeSchedule,
+    captured_amount: MinorUnit,
+) -> MinorUnit {
+    let amount = captured_amount.get_amount_as_i64();
+    let percentage_fee = amount
+        .saturating_mul(i64::from(fee_schedule.percentage_fee_basis_points))
+        .saturating_add(BASIS_POINTS_PER_UNIT / 2)
+        / BASIS_POINTS_PER_UNIT;
+    let fee = fee_schedule
+        .fixed_fee
+        .get_amount_as_i64()
+        .saturating_add(percentage_fee);
+
+    MinorUnit::new(fee.clamp(0, amount.max(0)))
+}
+
+async fn record_transaction(
+    db: &dyn StorageInterface,
+    transaction: LedgerTransaction,
+) -> CustomResult<(), errors::StorageError> {
+    let now = common_utils::date_time::now();
+    let entry_id = common_utils::generate_id(consts::ID_LENGTH, "lje");
+    let postings = transaction
+        .postings
+        .into_iter()
+        .filter(|(_, _, amount)| *amount != MinorUnit::zero())
+        .map(|(account, direction, amount)| storage::LedgerPostingNew {
+            posting_id: common_utils::generate_id(consts::ID_LENGTH, "lp"),
+            entry_id: entry_id.clone(),
+            merchant_id: transaction.merchant_id.clone(),
+            profile_id: transaction.profile_id.clone(),
+            connector: transaction.connector.clone(),
+            account,
+            direction,
+            amount,
+            currency: transaction.currency,
+            created_at: now,
+        })
+        .collect::<Vec<_>>();
+    if postings.is_empty() {
+        return Ok(());
+    }
+
+    let journal_entry = storage::LedgerJournalEntryNew {
+        entry_id,
+        merchant_id: transaction.merchant_id,
+        profile_id: transaction.profile_id,
+        connector: transaction.connector,
+        entry_type: transaction.entry_type,
+        reference_id: transaction.reference_id,
+        payment_id: transaction.payment_id,
+        currency: transaction.currency,
+        created_at: now,
+    };
+
+    match db
+        .insert_ledger_journal_entry_with_postings(journal_entry, postings)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::debug!("Ledger journal entry has already been posted");
+            Ok(())
+        }
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_schedule(
+        connector: Option<&str>,
+        payment_method: Option<storage_enums::PaymentMethod>,
+        currency: Option<storage_enums::Currency>,
+        fixed_fee: i64,
+        percentage_fee_basis_points: i32,
+    ) -> storage::LedgerFeeSchedule {
+        let now = common_utils::date_time::now();
+        storage::LedgerFeeSchedule {
+            fee_schedule_id: common_utils::generate_id(consts::ID_LENGTH, "lfs"),
+            merchant_id: id_type::MerchantId::default(),
+            connector: connector.map(str::to_owned),
+            payment_method,
+            currency,
+            fixed_fee: MinorUnit::new(fixed_fee),
+            percentage_fee_basis_points,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_captured_amount_delta() {
+        // A full capture
+        assert_eq!(
+            get_captured_amount_delta(None, Some(MinorUnit::new(5_000))),
+            Some(MinorUnit::new(5_000))
+        );
+        // A partial capture followed by the capture of the remaining amount
+        assert_eq!(
+            get_captured_amount_delta(None, Some(MinorUnit::new(3_000))),
+            Some(MinorUnit::new(3_000))
+        );
+        assert_eq!(
+            get_captured_amount_delta(Some(MinorUnit::new(3_000)), Some(MinorUnit::new(5_000))),
+            Some(MinorUnit::new(2_000))
+        );
+        // Updates which do not capture more are not posted
+        assert_eq!(
+            get_captured_amount_delta(Some(MinorUnit::new(5_000)), Some(MinorUnit::new(5_000))),
+            None
+        );
+        assert_eq!(
+            get_captured_amount_delta(Some(MinorUnit::new(5_000)), None),
+            None
+        );
+        assert_eq!(get_captured_amount_delta(None, None), None);
+    }
+
+    #[test]
+    fn test_capture_reference_id() {
+        let first_capture = get_capture_reference_id("pay_1_1", MinorUnit::new(1_000));
+        let second_capture = get_capture_reference_id("pay_1_1", MinorUnit::new(2_500));
+
+        assert_eq!(first_capture, "pay_1_1_1000");
+        assert_ne!(first_capture, second_capture);
+        assert_eq!(
+            get_capture_reference_id("pay_1_1", MinorUnit::new(1_000)),
+            first_capture
+        );
+    }
+
+    #[test]
+    fn test_calculate_fee() {
+        // 2.9% + 30
+        let schedule = fee_schedule(None, None, None, 30, 290);
+        assert_eq!(
+            calculate_fee(&schedule, MinorUnit::new(10_000)),
+            MinorUnit::new(320)
+        );
+        // 2.9% of 1050 is 30.45, which is rounded down
+        assert_eq!(
+            calculate_fee(&schedule, MinorUnit::new(1_050)),
+            MinorUnit::new(60)
+        );
+        // 2.9% of 1052 is 30.508, which is rounded up
+        assert_eq!(
+            calculate_fee(&schedule, MinorUnit::new(1_052)),
+            MinorUnit::new(61)
+        );
+        // The fee never exceeds the captured amount
+        assert_eq!(
+            calculate_fee(&schedule, MinorUnit::new(20)),
+            MinorUnit::new(20)
+        );
+        assert_eq!(
+            calculate_fee(&schedule, MinorUnit::zero()),
+            MinorUnit::zero()
+        );
+    }
+
+    #[test]
+    fn test_find_fee_schedule() {
+        let schedules = vec![
+            fee_schedule(None, None, None, 10, 0),
+            fee_schedule(None, None, Some(storage_enums::Currency::USD), 20, 0),
+            fee_schedule(
+                None,
+                Some(storage_enums::PaymentMethod::Card),
+                Some(storage_enums::Currency::USD),
+                30,
+                0,
+            ),
+            fee_schedule(Some("stripe"), None, None, 40, 0),
+            fee_schedule(
+                Some("adyen"),
+                None,
+                Some(storage_enums::Currency::EUR),
+                50,
+                0,
+            ),
+        ];
+        let find = |connector, payment_method, currency| {
+            find_fee_schedule(&schedules, connector, payment_method, currency)
+                .map(|schedule| schedule.fixed_fee)
+        };
+
+        // The connector is preferred over the payment method and currency
+        assert_eq!(
+            find(
+                Some("stripe"),
+                Some(storage_enums::PaymentMethod::Card),
+                storage_enums::Currency::USD
+            ),
+            Some(MinorUnit::new(40))
+        );
+        assert_eq!(
+            find(
+                Some("checkout"),
+                Some(storage_enums::PaymentMethod::Card),
+                storage_enums::Currency::USD
+            ),
+            Some(MinorUnit::new(30))
+        );
+        assert_eq!(
+            find(
+                Some("checkout"),
+                Some(storage_enums::PaymentMethod::Wallet),
+                storage_enums::Currency::USD
+            ),
+            Some(MinorUnit::new(20))
+        );
+        // Schedules of other currencies are not applied
+        assert_eq!(
+            find(Some("adyen"), None, storage_enums::Currency::USD),
+            Some(MinorUnit::new(20))
+        );
+        assert_eq!(
+            find(Some("adyen"), None, storage_enums::Currency::GBP),
+            Some(MinorUnit::new(10))
+        );
+        assert!(find_fee_schedule(&[], None, None, storage_enums::Currency::USD).is_none());
+    }
+}
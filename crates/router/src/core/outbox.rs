@@ -0,0 +1,488 @@
+use std::{sync::Arc, time::Duration};
+
+#[cfg(feature = "v1")]
+use api_models::webhook_events::OutgoingWebhookRequestContent;
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::StringExt;
+use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use hyperswitch_masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use scheduler::{db::queue::QueueInterface, SchedulerAppState};
+
+use crate::{
+    configs::settings::OutboxRelaySettings,
+    core::errors::{self, RouterResult},
+    db::kafka_store::TenantID,
+    routes::{AppState, SessionState},
+    services::kafka::outbox::KafkaOutboxEvent,
+    types::storage::{self, enums},
+};
+#[cfg(feature = "v1")]
+use crate::{core::webhooks as webhooks_core, utils::OptionExt};
+
+const OUTBOX_RELAY_LOCK_TAG: &str = "OUTBOX_RELAY";
+const OUTBOX_RELAY_LOCK_KEY: &str = "OUTBOX_RELAY_LOCK";
+const OUTBOX_RELAY_LOCK_VALUE: &str = "LOCKED";
+/// Time after which the lock held by a crashed relay expires, in seconds. The relay refreshes the
+/// lock before each chunk of entries it publishes, so that the lock does not expire while a large
+/// batch is being published.
+const OUTBOX_RELAY_LOCK_TTL: i64 = 60;
+/// Number of outgoing webhooks of a batch delivered concurrently
+const OUTBOX_WEBHOOK_DELIVERY_CHUNK_SIZE: usize = 10;
+/// Delay before the first retry of an entry that failed to be published, in seconds
+const OUTBOX_RETRY_BASE_DELAY: i64 = 10;
+/// Upper bound of the delay between two publish attempts of an entry, in seconds
+const OUTBOX_RETRY_MAX_DELAY: i64 = 3600;
+/// Interval between two deletions of the published entries past their retention period
+const OUTBOX_CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Publishes the pending outbox entries of every tenant to the events pipeline, and delivers the
+/// outgoing webhooks written to the outbox, until the process exits. A single relay publishes the
+/// entries of a tenant at any time, the relays of the other consumers skip the tenant while its
+/// lock is held.
+pub async fn start_outbox_relay(state: AppState) {
+    let settings = state.conf.outbox_relay.clone();
+    let state = Arc::new(state);
+    let mut interval = tokio::time::interval(Duration::from_millis(settings.poll_interval));
+
+    loop {
+        interval.tick().await;
+
+        for tenant_id in state.get_tenants().await {
+            let Some(session_state) = get_tenant_session_state(&state, &tenant_id) else {
+                continue;
+            };
+
+            if let Err(error) = relay_tenant_entries(&session_state, &settings).await {
+                logger::error!(?error, ?tenant_id, "Outbox relay iteration failed");
+            }
+        }
+    }
+}
+
+/// Deletes the published entries of every tenant past their retention period, until the process
+/// exits. The cleanup runs whether or not the relay is enabled, so that the entries written before
+/// the relay was disabled do not accumulate.
+pub async fn start_outbox_cleanup(state: AppState) {
+    let settings = state.conf.outbox_relay.clone();
+    let state = Arc::new(state);
+    let mut interval = tokio::time::interval(OUTBOX_CLEANUP_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        for tenant_id in state.get_tenants().await {
+            let Some(session_state) = get_tenant_session_state(&state, &tenant_id) else {
+                continue;
+            };
+
+            if let Err(error) = delete_expired_entries(&session_state, &settings).await {
+                logger::error!(?error, ?tenant_id, "Outbox cleanup failed");
+            }
+        }
+    }
+}
+
+fn get_tenant_session_state(
+    state: &Arc<AppState>,
+    tenant_id: &common_utils::id_type::TenantId,
+) -> Option<SessionState> {
+    Arc::clone(state)
+        .get_session_state(tenant_id, None, || {
+            error_stack::report!(errors::ApiErrorResponse::InvalidTenant {
+                tenant_id: tenant_id.get_string_repr().to_string(),
+            })
+        })
+        .inspect_err(|error| {
+            logger::error!(?error, ?tenant_id, "Failed to build outbox state");
+        })
+        .ok()
+}
+
+#[instrument(skip_all, fields(tenant_id = ?state.tenant.tenant_id))]
+async fn relay_tenant_entries(
+    state: &SessionState,
+    settings: &OutboxRelaySettings,
+) -> RouterResult<()> {
+    let scheduler_db = state.store.get_scheduler_db();
+    let is_lock_acquired = scheduler_db
+        .acquire_pt_lock(
+            OUTBOX_RELAY_LOCK_TAG,
+            OUTBOX_RELAY_LOCK_KEY,
+            OUTBOX_RELAY_LOCK_VALUE,
+            OUTBOX_RELAY_LOCK_TTL,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the outbox relay lock")?;
+    if !is_lock_acquired {
+        return Ok(());
+    }
+
+    let result = publish_pending_entries(state, settings).await;
+
+    scheduler_db
+        .release_pt_lock(OUTBOX_RELAY_LOCK_TAG, OUTBOX_RELAY_LOCK_KEY)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to release the outbox relay lock")?;
+
+    result
+}
+
+/// Publishes the oldest pending entries. An entry is marked as published only once the events
+/// pipeline acknowledged it, or its webhook was handed to the delivery with its retries
+/// scheduled, so that an entry is published again if the relay stops in between.
+async fn publish_pending_entries(
+    state: &SessionState,
+    settings: &OutboxRelaySettings,
+) -> RouterResult<()> {
+    let entries = state
+        .store
+        .find_pending_outbox_entries(settings.batch_size)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the pending outbox entries")?;
+    let (webhook_entries, event_entries): (Vec<_>, Vec<_>) = entries
+        .into_iter()
+        .partition(|entry| entry.aggregate_type == enums::OutboxAggregateType::OutgoingWebhook);
+
+    if !event_entries.is_empty() {
+        refresh_relay_lock(state).await?;
+
+        let tenant_id = TenantID(state.tenant.tenant_id.get_string_repr().to_owned());
+        let events = event_entries
+            .iter()
+            .map(|entry| KafkaOutboxEvent::from_storage(entry, tenant_id.clone()))
+            .collect::<Vec<_>>();
+        let publish_results = state
+            .event_handler
+            .publish_events(&events)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to publish the outbox entries")?;
+        for (entry, result) in event_entries.iter().zip(publish_results) {
+            update_entry(state, settings, entry, result).await?;
+        }
+    }
+
+    for chunk in webhook_entries.chunks(OUTBOX_WEBHOOK_DELIVERY_CHUNK_SIZE) {
+        refresh_relay_lock(state).await?;
+
+        let delivery_results = futures::future::join_all(
+            chunk
+                .iter()
+                .map(|entry| deliver_outgoing_webhook(state, entry)),
+        )
+        .await;
+        for (entry, result) in chunk.iter().zip(delivery_results) {
+            update_entry(state, settings, entry, result).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Extends the lock held by the relay by its TTL.
+async fn refresh_relay_lock(state: &SessionState) -> RouterResult<()> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_expiry(&OUTBOX_RELAY_LOCK_KEY.into(), OUTBOX_RELAY_LOCK_TTL)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to refresh the outbox relay lock")
+}
+
+async fn update_entry<E: error_stack::Context>(
+    state: &SessionState,
+    settings: &OutboxRelaySettings,
+    entry: &storage::Outbox,
+    result: error_stack::Result<(), E>,
+) -> RouterResult<()> {
+    let result = result
+        .inspect_err(|error| {
+            logger::warn!(?error, outbox_id = %entry.outbox_id, "Failed to publish outbox entry");
+        })
+        .map_err(|error| format!("{error:?}"));
+    let outbox_update = get_outbox_update(
+        entry,
+        result,
+        settings.max_attempts,
+        common_utils::date_time::now(),
+    );
+
+    state
+        .store
+        .update_outbox_entry_by_outbox_id(&entry.outbox_id, outbox_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to update outbox entry {}", entry.outbox_id))?;
+
+    Ok(())
+}
+
+/// Marks the entry as published, or counts the failed attempt. An entry that failed fewer than
+/// `max_attempts` times stays pending, and is published again once its backoff has elapsed.
+fn get_outbox_update(
+    entry: &storage::Outbox,
+    result: Result<(), String>,
+    max_attempts: i32,
+    now: time::PrimitiveDateTime,
+) -> storage::OutboxUpdate {
+    match result {
+        Ok(()) => storage::OutboxUpdate::Published { published_at: now },
+        Err(last_error) => {
+            let attempts = entry.attempts.saturating_add(1);
+            let (status, next_attempt_at) = if attempts >= max_attempts {
+                (enums::OutboxStatus::Failed, None)
+            } else {
+                (
+                    enums::OutboxStatus::Pending,
+                    Some(get_next_attempt_at(attempts, now)),
+                )
+            };
+            storage::OutboxUpdate::PublishFailed {
+                attempts,
+                last_error,
+                status,
+                next_attempt_at,
+            }
+        }
+    }
+}
+
+/// Time of the next publish attempt of an entry that failed `attempts` times, the delay doubling
+/// with each failed attempt up to [`OUTBOX_RETRY_MAX_DELAY`].
+fn get_next_attempt_at(attempts: i32, now: time::PrimitiveDateTime) -> time::PrimitiveDateTime {
+    let exponent = u32::try_from(attempts.saturating_sub(1)).unwrap_or(0);
+    let delay = 2_i64
+        .checked_pow(exponent)
+        .and_then(|factor| factor.checked_mul(OUTBOX_RETRY_BASE_DELAY))
+        .map_or(OUTBOX_RETRY_MAX_DELAY, |delay| {
+            delay.min(OUTBOX_RETRY_MAX_DELAY)
+        });
+
+    now.saturating_add(time::Duration::seconds(delay))
+}
+
+/// Delivers the outgoing webhook of the event the entry was written for, scheduling its retries
+/// as the webhooks delivered outside of the outbox are.
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(event_id = %entry.aggregate_id))]
+async fn deliver_outgoing_webhook(
+    state: &SessionState,
+    entry: &storage::Outbox,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &entry.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant key store")?;
+    let event = db
+        .find_event_by_merchant_id_event_id(&entry.merchant_id, &entry.aggregate_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the event of the outbox entry")?;
+    let business_profile_id = event
+        .business_profile_id
+        .clone()
+        .get_required_value("business_profile_id")?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(&key_store, &business_profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the business profile of the event")?;
+    let request_content: OutgoingWebhookRequestContent = event
+        .request
+        .as_ref()
+        .get_required_value("request")?
+        .get_inner()
+        .peek()
+        .parse_struct("OutgoingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the outgoing webhook request content")?;
+
+    let process_tracker = webhooks_core::add_outgoing_webhook_retry_task_to_process_tracker(
+        db,
+        &business_profile,
+        &event,
+        state.conf.application_source,
+    )
+    .await
+    .inspect_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to add outgoing webhook retry task to process tracker"
+        );
+    })
+    .ok();
+
+    Box::pin(webhooks_core::trigger_webhook_and_raise_event(
+        state.clone(),
+        business_profile,
+        &key_store,
+        event,
+        request_content,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+        None,
+        process_tracker,
+    ))
+    .await;
+
+    Ok(())
+}
+
+/// The outgoing webhooks of the v2 API are not written to the outbox
+#[cfg(feature = "v2")]
+async fn deliver_outgoing_webhook(
+    _state: &SessionState,
+    _entry: &storage::Outbox,
+) -> RouterResult<()> {
+    Err(errors::ApiErrorResponse::NotImplemented {
+        message: errors::NotImplementedMessage::Reason(
+            "Outgoing webhooks are not relayed from the outbox for the v2 API".to_string(),
+        ),
+    }
+    .into())
+}
+
+async fn delete_expired_entries(
+    state: &SessionState,
+    settings: &OutboxRelaySettings,
+) -> RouterResult<()> {
+    let created_before = get_retention_cutoff(settings, common_utils::date_time::now());
+    let deleted_count = state
+        .store
+        .delete_published_outbox_entries(created_before)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the published outbox entries")?;
+    logger::debug!(deleted_count, "Deleted published outbox entries");
+
+    Ok(())
+}
+
+/// Creation time before which the published entries are past their retention period
+fn get_retention_cutoff(
+    settings: &OutboxRelaySettings,
+    now: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    now.saturating_sub(time::Duration::days(settings.retention_days))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_entry(attempts: i32) -> storage::Outbox {
+        storage::Outbox {
+            outbox_id: "outbox_1".to_string(),
+            dedupe_key: "refund_ref_1_update_digest".to_string(),
+            aggregate_type: enums::OutboxAggregateType::Refund,
+            aggregate_id: "ref_1".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            operation: enums::OutboxOperation::Update,
+            payload: serde_json::json!({ "refund_id": "ref_1" }),
+            status: enums::OutboxStatus::Pending,
+            attempts,
+            last_error: None,
+            created_at: datetime!(2026-10-19 10:00),
+            published_at: None,
+            next_attempt_at: None,
+        }
+    }
+
+    #[test]
+    fn test_published_entry_is_marked_published() {
+        let now = datetime!(2026-10-19 12:00);
+
+        let outbox_update = get_outbox_update(&get_entry(2), Ok(()), 10, now);
+
+        assert!(matches!(
+            outbox_update,
+            storage::OutboxUpdate::Published { published_at } if published_at == now
+        ));
+    }
+
+    #[test]
+    fn test_failed_entry_stays_pending_with_backoff() {
+        let now = datetime!(2026-10-19 12:00);
+
+        let outbox_update = get_outbox_update(
+            &get_entry(2),
+            Err("broker unavailable".to_string()),
+            10,
+            now,
+        );
+
+        assert!(matches!(
+            outbox_update,
+            storage::OutboxUpdate::PublishFailed {
+                attempts: 3,
+                ref last_error,
+                status: enums::OutboxStatus::Pending,
+                next_attempt_at: Some(next_attempt_at),
+            } if last_error == "broker unavailable"
+                && next_attempt_at == datetime!(2026-10-19 12:00:40)
+        ));
+    }
+
+    #[test]
+    fn test_entry_fails_after_max_attempts() {
+        let now = datetime!(2026-10-19 12:00);
+
+        let outbox_update = get_outbox_update(
+            &get_entry(9),
+            Err("broker unavailable".to_string()),
+            10,
+            now,
+        );
+
+        assert!(matches!(
+            outbox_update,
+            storage::OutboxUpdate::PublishFailed {
+                attempts: 10,
+                status: enums::OutboxStatus::Failed,
+                next_attempt_at: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max_delay() {
+        let now = datetime!(2026-10-19 12:00);
+
+        assert_eq!(get_next_attempt_at(1, now), datetime!(2026-10-19 12:00:10));
+        assert_eq!(get_next_attempt_at(2, now), datetime!(2026-10-19 12:00:20));
+        assert_eq!(get_next_attempt_at(5, now), datetime!(2026-10-19 12:02:40));
+        assert_eq!(get_next_attempt_at(10, now), datetime!(2026-10-19 13:00));
+        assert_eq!(
+            get_next_attempt_at(i32::MAX, now),
+            datetime!(2026-10-19 13:00)
+        );
+    }
+
+    #[test]
+    fn test_retention_cutoff() {
+        let settings = OutboxRelaySettings {
+            retention_days: 7,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_retention_cutoff(&settings, datetime!(2026-10-19 12:00)),
+            datetime!(2026-10-12 12:00)
+        );
+    }
+}
@@ -2,6 +2,8 @@ pub mod access_token;
 #[cfg(feature = "v1")]
 pub mod batch_retrieve;
 pub mod cards;
+#[cfg(feature = "v1")]
+pub mod expiry;
 pub mod migration;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
@@ -11,6 +13,8 @@ pub mod transformers;
 pub mod utils;
 mod validator;
 pub mod vault;
+#[cfg(feature = "v1")]
+pub mod webhooks;
 use std::borrow::Cow;
 #[cfg(feature = "v1")]
 use std::collections::HashSet;
@@ -66,7 +66,7 @@ use super::surcharge_decision_configs::{
 use super::tokenize::NetworkTokenizationProcess;
 #[cfg(feature = "v1")]
 use crate::core::payment_methods::{
-    add_payment_method_status_update_task, tokenize,
+    add_payment_method_status_update_task, expiry, tokenize,
     utils::{get_merchant_pm_filter_graph, make_pm_graph, refresh_pm_filters_cache},
 };
 #[cfg(feature = "v1")]
@@ -214,6 +214,15 @@ impl PaymentMethodsController for PmCards<'_> {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to add payment method in db")?;
 
+        let _ = expiry::add_payment_method_expiry_task(self.state, &response)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to schedule the expiry of the payment method"
+                )
+            });
+
         if customer.default_payment_method_id.is_none() && req.payment_method.is_some() {
             let _ = self
                 .set_default_payment_method(
@@ -5010,6 +5019,44 @@ pub async fn get_card_details_from_locker(
         .attach_printable("Get Card Details Failed")
 }
 
+/// Returns the card details held in the decrypted data of a saved card.
+#[cfg(feature = "v1")]
+pub fn get_saved_card_details(
+    payment_method: &domain::PaymentMethod,
+) -> Option<api::CardDetailFromLocker> {
+    payment_method
+        .payment_method_data
+        .clone()
+        .map(|data| data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|data| match data {
+            PaymentMethodsData::Card(card) => Some(api::CardDetailFromLocker::from(card)),
+            _ => None,
+        })
+}
+
+/// Returns the time at which a card stops being valid, which is the end of its expiry month.
+/// Two digit expiry years are taken to be in the current century.
+pub fn get_card_expiry_time(
+    expiry_month: &Secret<String>,
+    expiry_year: &Secret<String>,
+) -> Option<time::PrimitiveDateTime> {
+    let month = expiry_month
+        .peek()
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .and_then(|month| time::Month::try_from(month).ok())?;
+    let year = expiry_year.peek().trim().parse::<i32>().ok()?;
+    let year = if year < 100 { 2000 + year } else { year };
+    let last_day = time::Date::from_calendar_date(year, month, month.length(year)).ok()?;
+
+    Some(
+        time::PrimitiveDateTime::new(last_day, time::Time::MIDNIGHT)
+            .saturating_add(time::Duration::days(1)),
+    )
+}
+
 #[cfg(feature = "v1")]
 pub async fn get_lookup_key_from_locker(
     state: &routes::SessionState,
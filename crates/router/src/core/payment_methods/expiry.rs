@@ -0,0 +1,499 @@
+//! Expiry of the saved cards. Every saved card is tracked by a process tracker task which sends
+//! the pre-expiry notifications and marks the card inactive once it has expired.
+
+use api_models::payment_methods as pm_api;
+use error_stack::ResultExt;
+#[cfg(feature = "email")]
+use hyperswitch_masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::PaymentMethodExpirySettings,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, webhooks as pm_webhooks},
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+#[cfg(feature = "email")]
+use crate::{consts, services::email::types as email_types, utils::user as user_utils};
+
+const PAYMENT_METHOD_EXPIRY_TASK: &str = "PAYMENT_METHOD_EXPIRY";
+const PAYMENT_METHOD_EXPIRY_TAG: &str = "PAYMENT_METHOD_EXPIRY";
+
+/// Returns the time at which a saved card expires, `None` for the other payment methods.
+pub fn get_payment_method_expiry(
+    payment_method: &domain::PaymentMethod,
+) -> Option<PrimitiveDateTime> {
+    cards::get_saved_card_details(payment_method)
+        .and_then(|card| card.expiry_month.zip(card.expiry_year))
+        .and_then(|(expiry_month, expiry_year)| {
+            cards::get_card_expiry_time(&expiry_month, &expiry_year)
+        })
+}
+
+/// Returns the reminder days in the order the reminders are sent, the earliest reminder first.
+pub fn get_expiry_reminder_days(settings: &PaymentMethodExpirySettings) -> Vec<u8> {
+    let mut expiry_reminder_days = settings
+        .expiry_reminder_days
+        .iter()
+        .copied()
+        .filter(|days| *days > 0)
+        .collect::<Vec<_>>();
+    expiry_reminder_days.sort_unstable_by(|a, b| b.cmp(a));
+    expiry_reminder_days.dedup();
+    expiry_reminder_days
+}
+
+/// Finds the step of the expiry task to run next, starting from the given step. Reminders whose
+/// time has passed are skipped, the step after the last reminder expires the card.
+pub fn get_next_expiry_step(
+    card_expiry: PrimitiveDateTime,
+    expiry_reminder_days: &[u8],
+    from_step: usize,
+    now: PrimitiveDateTime,
+) -> (usize, PrimitiveDateTime) {
+    expiry_reminder_days
+        .iter()
+        .enumerate()
+        .skip(from_step)
+        .map(|(step, days)| {
+            (
+                step,
+                card_expiry.saturating_sub(time::Duration::days(i64::from(*days))),
+            )
+        })
+        .find(|(_, schedule_time)| *schedule_time > now)
+        .unwrap_or((expiry_reminder_days.len(), card_expiry))
+}
+
+/// Returns the time at which the card expires if it expires before the given time.
+fn get_card_expiring_before(
+    card: &pm_api::CardDetailFromLocker,
+    expiring_before: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    card.expiry_month
+        .as_ref()
+        .zip(card.expiry_year.as_ref())
+        .and_then(|(expiry_month, expiry_year)| {
+            cards::get_card_expiry_time(expiry_month, expiry_year)
+        })
+        .filter(|expires_at| *expires_at <= expiring_before)
+}
+
+/// Schedules the expiry of a newly saved card. Nothing is scheduled for the other payment
+/// methods or when the expiry sweep is disabled.
+#[instrument(skip_all)]
+pub async fn add_payment_method_expiry_task(
+    state: &SessionState,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<()> {
+    let settings = &state.conf.payment_method_expiry;
+    if !settings.enabled {
+        return Ok(());
+    }
+    let Some(card_expiry) = get_payment_method_expiry(payment_method) else {
+        return Ok(());
+    };
+
+    let expiry_reminder_days = get_expiry_reminder_days(settings);
+    let (step, schedule_time) = get_next_expiry_step(
+        card_expiry,
+        &expiry_reminder_days,
+        0,
+        common_utils::date_time::now(),
+    );
+    let retry_count = i32::try_from(step)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Too many payment method expiry reminders configured")?;
+
+    let tracking_data = storage::PaymentMethodExpiryTrackingData {
+        payment_method_id: payment_method.get_id().clone(),
+        merchant_id: payment_method.merchant_id.clone(),
+        card_expiry,
+        expiry_reminder_days,
+    };
+    let runner = storage::ProcessTrackerRunner::PaymentMethodExpiryWorkflow;
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        pt_utils::get_process_tracker_id(
+            runner,
+            PAYMENT_METHOD_EXPIRY_TASK,
+            payment_method.get_id(),
+            &payment_method.merchant_id,
+        ),
+        PAYMENT_METHOD_EXPIRY_TASK,
+        runner,
+        [PAYMENT_METHOD_EXPIRY_TAG],
+        tracking_data,
+        Some(retry_count),
+        schedule_time,
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct PAYMENT_METHOD_EXPIRY process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting PAYMENT_METHOD_EXPIRY task to process_tracker for payment_method_id: {}",
+                payment_method.get_id()
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Notifies the merchant and the customer of a card expiring in the given number of days, through
+/// the channels enabled in the configuration. A failed notification is only logged, so that it
+/// does not hold up the expiry of the card.
+#[instrument(skip_all)]
+pub async fn send_payment_method_expiry_reminder(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_method: &domain::PaymentMethod,
+    expires_in: u8,
+) {
+    let settings = &state.conf.payment_method_expiry;
+
+    if settings.webhook_notifications {
+        let response = pm_webhooks::get_payment_method_webhook_response(payment_method, None);
+        let _ = pm_webhooks::trigger_payment_method_webhook(
+            state,
+            platform,
+            storage_enums::EventType::PaymentMethodExpiring,
+            response,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to trigger the payment method expiring webhook"
+            )
+        });
+    }
+
+    #[cfg(feature = "email")]
+    if settings.email_notifications {
+        let _ = send_expiry_reminder_email(state, platform, payment_method, expires_in)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to email the card expiry reminder"));
+    }
+
+    #[cfg(not(feature = "email"))]
+    if settings.email_notifications {
+        logger::warn!(
+            expires_in,
+            "Card expiry reminder emails are enabled but the email feature is not compiled in"
+        );
+    }
+}
+
+#[cfg(feature = "email")]
+async fn send_expiry_reminder_email(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_method: &domain::PaymentMethod,
+    expires_in: u8,
+) -> RouterResult<()> {
+    let provider = platform.get_provider();
+    let customer = state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            provider.get_key_store(),
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let Some(email) = customer.email.map(common_utils::pii::Email::from) else {
+        logger::info!("Customer has no email, skipping the card expiry reminder email");
+        return Ok(());
+    };
+    let recipient_email = domain::UserEmail::from_pii_email(email)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the customer email to UserEmail")?;
+
+    let merchant_account = platform.get_processor().get_account();
+    let card = cards::get_saved_card_details(payment_method);
+    let email_contents = email_types::PaymentMethodExpiryReminder {
+        recipient_email,
+        subject: consts::EMAIL_SUBJECT_PAYMENT_METHOD_EXPIRY,
+        customer_name: customer
+            .name
+            .map(|name| name.into_inner().peek().to_owned()),
+        merchant_name: merchant_account
+            .merchant_name
+            .clone()
+            .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+            .unwrap_or_else(|| merchant_account.get_id().get_string_repr().to_owned()),
+        card_network: card
+            .as_ref()
+            .and_then(|card| card.card_network.as_ref())
+            .map(ToString::to_string),
+        last4_digits: card.and_then(|card| card.last4_digits),
+        expires_in,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            user_utils::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send the card expiry reminder email")?;
+
+    Ok(())
+}
+
+/// Marks an expired card inactive and notifies the merchant of it.
+#[instrument(skip_all)]
+pub async fn expire_payment_method(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_method: domain::PaymentMethod,
+) -> RouterResult<()> {
+    let provider = platform.get_provider();
+    let payment_method = state
+        .store
+        .update_payment_method(
+            provider.get_key_store(),
+            payment_method,
+            storage::PaymentMethodUpdate::StatusUpdate {
+                status: Some(storage_enums::PaymentMethodStatus::Inactive),
+                last_modified_by: None,
+            },
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark the expired payment method inactive")?;
+
+    if state.conf.payment_method_expiry.webhook_notifications {
+        let response = pm_webhooks::get_payment_method_webhook_response(&payment_method, None);
+        let _ = pm_webhooks::trigger_payment_method_webhook(
+            state,
+            platform,
+            storage_enums::EventType::PaymentMethodExpired,
+            response,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to trigger the payment method expired webhook"
+            )
+        });
+    }
+
+    Ok(())
+}
+
+/// Lists the active cards of a customer which expire within the requested window, so that an
+/// updated card can be collected before a renewal fails.
+#[instrument(skip_all)]
+pub async fn list_customer_expiring_payment_methods(
+    state: SessionState,
+    platform: domain::Platform,
+    req: pm_api::CustomerExpiringPaymentMethodsRequest,
+) -> RouterResponse<pm_api::CustomerExpiringPaymentMethodsResponse> {
+    let provider = platform.get_provider();
+    let merchant_id = provider.get_account().get_id();
+    let customer = state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &req.customer_id,
+            merchant_id,
+            provider.get_key_store(),
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let within_days = req.within_days.unwrap_or_else(|| {
+        get_expiry_reminder_days(&state.conf.payment_method_expiry)
+            .first()
+            .copied()
+            .map(u16::from)
+            .unwrap_or(30)
+    });
+
+    let payment_methods = state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_status(
+            provider.get_key_store(),
+            &req.customer_id,
+            merchant_id,
+            storage_enums::PaymentMethodStatus::Active,
+            None,
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let now = common_utils::date_time::now();
+    let expiring_before = now.saturating_add(time::Duration::days(i64::from(within_days)));
+    let mut data = payment_methods
+        .iter()
+        .filter_map(|payment_method| {
+            let card = cards::get_saved_card_details(payment_method)?;
+            let expires_at = get_card_expiring_before(&card, expiring_before)?;
+            Some(pm_api::ExpiringPaymentMethod {
+                payment_method_id: payment_method.get_id().clone(),
+                payment_method_type: payment_method.get_payment_method_subtype(),
+                is_default: customer.default_payment_method_id.as_ref()
+                    == Some(payment_method.get_id()),
+                expires_in_days: (expires_at - now).whole_days().max(0),
+                expires_at,
+                card,
+            })
+        })
+        .collect::<Vec<_>>();
+    data.sort_by_key(|payment_method| payment_method.expires_at);
+
+    Ok(services::ApplicationResponse::Json(
+        pm_api::CustomerExpiringPaymentMethodsResponse {
+            customer_id: req.customer_id,
+            within_days,
+            data,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperswitch_masking::Secret;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn saved_card(
+        expiry_month: Option<&str>,
+        expiry_year: Option<&str>,
+    ) -> pm_api::CardDetailFromLocker {
+        pm_api::CardDetailFromLocker {
+            scheme: None,
+            issuer_country: None,
+            issuer_country_code: None,
+            last4_digits: Some("4242".to_string()),
+            card_number: None,
+            expiry_month: expiry_month.map(|month| Secret::new(month.to_string())),
+            expiry_year: expiry_year.map(|year| Secret::new(year.to_string())),
+            card_token: None,
+            card_holder_name: None,
+            card_fingerprint: None,
+            nick_name: None,
+            card_network: None,
+            card_isin: None,
+            card_issuer: None,
+            card_type: None,
+            saved_to_locker: true,
+        }
+    }
+
+    #[test]
+    fn test_expiry_reminder_days() {
+        let settings = PaymentMethodExpirySettings {
+            expiry_reminder_days: vec![7, 30, 0, 30, 14],
+            ..Default::default()
+        };
+
+        // The earliest reminder is sent first, reminders on the day of the expiry are dropped
+        assert_eq!(get_expiry_reminder_days(&settings), vec![30, 14, 7]);
+        assert!(get_expiry_reminder_days(&PaymentMethodExpirySettings {
+            expiry_reminder_days: Vec::new(),
+            ..Default::default()
+        })
+        .is_empty());
+    }
+
+    #[test]
+    fn test_next_expiry_step() {
+        let card_expiry = datetime!(2026-12-01 00:00:00);
+        let expiry_reminder_days = [30, 7];
+
+        assert_eq!(
+            get_next_expiry_step(
+                card_expiry,
+                &expiry_reminder_days,
+                0,
+                datetime!(2026-10-19 00:00:00)
+            ),
+            (0, datetime!(2026-11-01 00:00:00))
+        );
+        assert_eq!(
+            get_next_expiry_step(
+                card_expiry,
+                &expiry_reminder_days,
+                1,
+                datetime!(2026-11-01 00:00:00)
+            ),
+            (1, datetime!(2026-11-24 00:00:00))
+        );
+        assert_eq!(
+            get_next_expiry_step(
+                card_expiry,
+                &expiry_reminder_days,
+                2,
+                datetime!(2026-11-24 00:00:00)
+            ),
+            (2, card_expiry)
+        );
+        assert_eq!(
+            get_next_expiry_step(card_expiry, &[], 0, datetime!(2026-10-19 00:00:00)),
+            (0, card_expiry)
+        );
+    }
+
+    #[test]
+    fn test_next_expiry_step_skips_past_reminders() {
+        let card_expiry = datetime!(2026-12-01 00:00:00);
+
+        // A card saved a week before its expiry only gets the last reminder
+        assert_eq!(
+            get_next_expiry_step(card_expiry, &[30, 7], 0, datetime!(2026-11-20 00:00:00)),
+            (1, datetime!(2026-11-24 00:00:00))
+        );
+        // A card saved after its last reminder is expired directly
+        assert_eq!(
+            get_next_expiry_step(card_expiry, &[30, 7], 0, datetime!(2026-11-28 00:00:00)),
+            (2, card_expiry)
+        );
+    }
+
+    #[test]
+    fn test_card_expiring_before() {
+        let expiring_before = datetime!(2026-12-01 00:00:00);
+
+        assert_eq!(
+            get_card_expiring_before(&saved_card(Some("11"), Some("2026")), expiring_before),
+            Some(datetime!(2026-12-01 00:00:00))
+        );
+        assert_eq!(
+            get_card_expiring_before(&saved_card(Some("10"), Some("26")), expiring_before),
+            Some(datetime!(2026-11-01 00:00:00))
+        );
+        assert!(
+            get_card_expiring_before(&saved_card(Some("12"), Some("2026")), expiring_before)
+                .is_none()
+        );
+        assert!(get_card_expiring_before(&saved_card(Some("11"), None), expiring_before).is_none());
+    }
+}
@@ -0,0 +1,122 @@
+use api_models::{payment_methods as pm_api, webhooks};
+use router_env::{instrument, logger, tracing, Instrument};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    types::{domain, storage::enums as storage_enums},
+};
+
+/// Builds the content of the payment method webhooks from the decrypted payment method.
+pub fn get_payment_method_webhook_response(
+    payment_method: &domain::PaymentMethod,
+    account_updater_result: Option<storage_enums::AccountUpdaterResult>,
+) -> pm_api::PaymentMethodWebhookResponse {
+    let card = cards::get_saved_card_details(payment_method).map(|mut card| {
+        card.scheme.clone_from(&payment_method.scheme);
+        card
+    });
+
+    pm_api::PaymentMethodWebhookResponse {
+        payment_method_id: payment_method.get_id().to_owned(),
+        merchant_id: payment_method.merchant_id.clone(),
+        customer_id: payment_method.customer_id.clone(),
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        status: payment_method.status,
+        card,
+        account_updater_result,
+        last_modified: payment_method.last_modified,
+    }
+}
+
+/// The events of a payment method are identified by the time they are raised as well, as the
+/// idempotent event ID would otherwise suppress every later event of the same type, such as the
+/// second pre-expiry reminder of a card.
+fn get_primary_object_id(response: &pm_api::PaymentMethodWebhookResponse) -> String {
+    format!(
+        "{}_{}",
+        response.payment_method_id,
+        common_utils::date_time::now().assume_utc().unix_timestamp()
+    )
+}
+
+/// Retrieves the payment method again to rebuild the content of a webhook, used when retrying
+/// the delivery of the webhook.
+pub async fn get_payment_method_webhook_response_by_primary_object_id(
+    state: &SessionState,
+    platform: &domain::Platform,
+    primary_object_id: &str,
+) -> RouterResult<pm_api::PaymentMethodWebhookResponse> {
+    let payment_method_id = primary_object_id
+        .rsplit_once('_')
+        .map(|(payment_method_id, _)| payment_method_id)
+        .unwrap_or(primary_object_id);
+    let payment_method = state
+        .store
+        .find_payment_method(
+            platform.get_provider().get_key_store(),
+            payment_method_id,
+            platform.get_provider().get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    Ok(get_payment_method_webhook_response(&payment_method, None))
+}
+
+#[instrument(skip_all)]
+pub async fn trigger_payment_method_webhook(
+    state: &SessionState,
+    platform: &domain::Platform,
+    event_type: storage_enums::EventType,
+    response: pm_api::PaymentMethodWebhookResponse,
+) -> RouterResult<()> {
+    // Payment methods are not scoped to a profile, the webhook is sent with the default profile
+    let Some(profile_id) = platform
+        .get_processor()
+        .get_account()
+        .get_default_profile()
+        .clone()
+    else {
+        logger::info!("Merchant has no default profile, skipping the payment method webhook");
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(platform.get_processor().get_key_store(), &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let cloned_state = state.clone();
+    let processor = platform.get_processor().clone();
+
+    tokio::spawn(
+        async move {
+            let primary_object_id = get_primary_object_id(&response);
+            let primary_object_created_at = Some(response.last_modified);
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                processor,
+                business_profile,
+                event_type,
+                storage_enums::EventClass::PaymentMethods,
+                primary_object_id,
+                storage_enums::EventObjectType::PaymentMethodDetails,
+                webhooks::OutgoingWebhookContent::PaymentMethodDetails(Box::new(response)),
+                primary_object_created_at,
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
@@ -77,6 +77,7 @@ use super::{
 };
 #[cfg(feature = "v1")]
 use crate::core::{
+    customer_merge,
     payments::{OperationSessionGetters, OperationSessionSetters},
     utils as core_utils,
 };
@@ -1077,6 +1078,24 @@ pub fn validate_card_data(
                 },
             )?;
 
+            // The network is identified from the leading digits alone, so that a card number of
+            // an invalid length is rejected for its length rather than for an unknown network
+            let card_number = card.card_number.peek();
+            if let Some(card_network) = card.card_network.clone().or_else(|| {
+                ::cards::get_card_networks_by_iin(card_number)
+                    .into_iter()
+                    .next()
+            }) {
+                ::cards::validate_card_number_for_network(card_number, &card_network)
+                    .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                        message: format!("Invalid card_number length for {card_network}"),
+                    })?;
+                ::cards::validate_card_security_code_for_network(&cvc, &card_network)
+                    .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                        message: format!("Invalid card_cvc length for {card_network}"),
+                    })?;
+            }
+
             validate_card_expiry(&card.card_exp_month, &card.card_exp_year)?;
         }
         Some(api::PaymentMethodData::NetworkToken(network_token)) => {
@@ -1935,8 +1954,8 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
     let db = &*state.store;
     let key_manager_state = &state.into();
     let optional_customer = match customer_id {
-        Some(customer_id) => {
-            let customer_data = db
+        Some(mut customer_id) => {
+            let mut customer_data = db
                 .find_customer_optional_by_customer_id_merchant_id(
                     &customer_id,
                     merchant_id,
@@ -1944,6 +1963,24 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                     storage_scheme,
                 )
                 .await?;
+            // A customer merged into another customer is resolved to the customer it was merged
+            // into, instead of being created again
+            if customer_data.is_none() {
+                if let Some(target_customer_id) =
+                    customer_merge::resolve_merged_customer_id(db, merchant_id, &customer_id)
+                        .await?
+                {
+                    customer_data = db
+                        .find_customer_optional_by_customer_id_merchant_id(
+                            &target_customer_id,
+                            merchant_id,
+                            key_store,
+                            storage_scheme,
+                        )
+                        .await?;
+                    customer_id = target_customer_id;
+                }
+            }
             let key = key_store.key.get_inner().peek();
             let encrypted_data = types::crypto_operation(
                 key_manager_state,
@@ -5437,6 +5474,40 @@ mod test {
     }
 }
 
+/// Returns the most specific BIN range containing the card number, logging the failures of the
+/// lookup since the issuer details of the card are optional.
+async fn find_bin_range_for_card(
+    db: &dyn StorageInterface,
+    card_number: &::cards::CardNumber,
+) -> Option<storage::BinRange> {
+    db.find_bin_range_by_card_number(&card_number.get_card_no())
+        .await
+        .map_err(|error| services::logger::warn!(bin_range_error=?error))
+        .ok()
+        .flatten()
+}
+
+/// Describes the card using the BIN range containing its number, falling back to the card info
+/// of its IIN when no range contains it.
+async fn get_card_info_by_bin_range_or_isin(
+    db: &dyn StorageInterface,
+    bin_range: Option<storage::BinRange>,
+    card_isin: Option<String>,
+) -> Option<storage::CardInfo> {
+    match (bin_range, card_isin) {
+        (Some(bin_range), Some(card_isin)) => Some(bin_range.into_card_info(card_isin)),
+        (_, card_isin) => card_isin
+            .async_and_then(|card_isin| async move {
+                db.get_card_info(&card_isin)
+                    .await
+                    .map_err(|error| services::logger::warn!(card_info_error=?error))
+                    .ok()
+            })
+            .await
+            .flatten(),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn get_additional_payment_data(
     pm_data: &domain::PaymentMethodData,
@@ -5464,19 +5535,24 @@ pub async fn get_additional_payment_data(
                 _ => None,
             };
 
+            let bin_range = find_bin_range_for_card(db, &card_data.card_number).await;
+
             // Added an additional check for card_data.co_badged_card_data.is_some()
             // because is_cobadged_card() only returns true if the card number matches a specific regex.
             // However, this regex does not cover all possible co-badged networks.
             // The co_badged_card_data field is populated based on a co-badged BIN lookup
             // and helps identify co-badged cards that may not match the regex alone.
             // Determine the card network based on cobadge detection and co-badged BIN data
-            let is_cobadged_based_on_regex = card_data
-                .card_number
-                .is_cobadged_card()
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "Card cobadge check failed due to an invalid card network regex",
-                )?;
+            // The card network is resolved from the BIN range containing the card number when
+            // there is one, in place of the regex-based detection
+            let is_cobadged_based_on_regex = bin_range.is_none()
+                && card_data
+                    .card_number
+                    .is_cobadged_card()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Card cobadge check failed due to an invalid card network regex",
+                    )?;
 
             let (card_network, signature_network, is_regulated) = card_data
                 .co_badged_card_data
@@ -5533,40 +5609,33 @@ pub async fn get_additional_payment_data(
                     }),
                 )))
             } else {
-                let card_info = card_isin
-                    .clone()
-                    .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
-                            .await
-                            .map_err(|error| services::logger::warn!(card_info_error=?error))
-                            .ok()
-                    })
-                    .await
-                    .flatten()
-                    .map(|card_info| {
-                        api_models::payments::AdditionalPaymentData::Card(Box::new(
-                            api_models::payments::AdditionalCardInfo {
-                                card_issuer: card_info.card_issuer,
-                                card_network: card_network.clone().or(card_info.card_network),
-                                bank_code: card_info.bank_code,
-                                card_type: card_info.card_type,
-                                card_issuing_country: card_info.card_issuing_country,
-                                card_issuing_country_code: card_info.country_code,
-                                last4: last4.clone(),
-                                card_isin: card_isin.clone(),
-                                card_extended_bin: card_extended_bin.clone(),
-                                card_exp_month: Some(card_data.card_exp_month.clone()),
-                                card_exp_year: Some(card_data.card_exp_year.clone()),
-                                card_holder_name: card_data.card_holder_name.clone(),
-                                // These are filled after calling the processor / connector
-                                payment_checks: None,
-                                authentication_data: None,
-                                auth_code: None,
-                                is_regulated,
-                                signature_network: signature_network.clone(),
-                            },
-                        ))
-                    });
+                let card_info =
+                    get_card_info_by_bin_range_or_isin(db, bin_range, card_isin.clone())
+                        .await
+                        .map(|card_info| {
+                            api_models::payments::AdditionalPaymentData::Card(Box::new(
+                                api_models::payments::AdditionalCardInfo {
+                                    card_issuer: card_info.card_issuer,
+                                    card_network: card_network.clone().or(card_info.card_network),
+                                    bank_code: card_info.bank_code,
+                                    card_type: card_info.card_type,
+                                    card_issuing_country: card_info.card_issuing_country,
+                                    card_issuing_country_code: card_info.country_code,
+                                    last4: last4.clone(),
+                                    card_isin: card_isin.clone(),
+                                    card_extended_bin: card_extended_bin.clone(),
+                                    card_exp_month: Some(card_data.card_exp_month.clone()),
+                                    card_exp_year: Some(card_data.card_exp_year.clone()),
+                                    card_holder_name: card_data.card_holder_name.clone(),
+                                    // These are filled after calling the processor / connector
+                                    payment_checks: None,
+                                    authentication_data: None,
+                                    auth_code: None,
+                                    is_regulated,
+                                    signature_network: signature_network.clone(),
+                                },
+                            ))
+                        });
                 Ok(Some(card_info.unwrap_or_else(|| {
                     api_models::payments::AdditionalPaymentData::Card(Box::new(
                         api_models::payments::AdditionalCardInfo {
@@ -5608,19 +5677,24 @@ pub async fn get_additional_payment_data(
                 _ => None,
             };
 
+            let bin_range = find_bin_range_for_card(db, &card_data.card_number).await;
+
             // Added an additional check for card_data.co_badged_card_data.is_some()
             // because is_cobadged_card() only returns true if the card number matches a specific regex.
             // However, this regex does not cover all possible co-badged networks.
             // The co_badged_card_data field is populated based on a co-badged BIN lookup
             // and helps identify co-badged cards that may not match the regex alone.
             // Determine the card network based on cobadge detection and co-badged BIN data
-            let is_cobadged_based_on_regex = card_data
-                .card_number
-                .is_cobadged_card()
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "Card cobadge check failed due to an invalid card network regex",
-                )?;
+            // The card network is resolved from the BIN range containing the card number when
+            // there is one, in place of the regex-based detection
+            let is_cobadged_based_on_regex = bin_range.is_none()
+                && card_data
+                    .card_number
+                    .is_cobadged_card()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Card cobadge check failed due to an invalid card network regex",
+                    )?;
 
             let (card_network, signature_network, is_regulated) = card_data
                 .co_badged_card_data
@@ -5677,40 +5751,33 @@ pub async fn get_additional_payment_data(
                     }),
                 )))
             } else {
-                let card_info = card_isin
-                    .clone()
-                    .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
-                            .await
-                            .map_err(|error| services::logger::warn!(card_info_error=?error))
-                            .ok()
-                    })
-                    .await
-                    .flatten()
-                    .map(|card_info| {
-                        api_models::payments::AdditionalPaymentData::Card(Box::new(
-                            api_models::payments::AdditionalCardInfo {
-                                card_issuer: card_info.card_issuer,
-                                card_network: card_network.clone().or(card_info.card_network),
-                                bank_code: card_info.bank_code,
-                                card_type: card_info.card_type,
-                                card_issuing_country: card_info.card_issuing_country,
-                                card_issuing_country_code: card_info.country_code,
-                                last4: last4.clone(),
-                                card_isin: card_isin.clone(),
-                                card_extended_bin: card_extended_bin.clone(),
-                                card_exp_month: Some(card_data.card_exp_month.clone()),
-                                card_exp_year: Some(card_data.card_exp_year.clone()),
-                                card_holder_name: card_data.card_holder_name.clone(),
-                                // These are filled after calling the processor / connector
-                                payment_checks: None,
-                                authentication_data: None,
-                                auth_code: None,
-                                is_regulated,
-                                signature_network: signature_network.clone(),
-                            },
-                        ))
-                    });
+                let card_info =
+                    get_card_info_by_bin_range_or_isin(db, bin_range, card_isin.clone())
+                        .await
+                        .map(|card_info| {
+                            api_models::payments::AdditionalPaymentData::Card(Box::new(
+                                api_models::payments::AdditionalCardInfo {
+                                    card_issuer: card_info.card_issuer,
+                                    card_network: card_network.clone().or(card_info.card_network),
+                                    bank_code: card_info.bank_code,
+                                    card_type: card_info.card_type,
+                                    card_issuing_country: card_info.card_issuing_country,
+                                    card_issuing_country_code: card_info.country_code,
+                                    last4: last4.clone(),
+                                    card_isin: card_isin.clone(),
+                                    card_extended_bin: card_extended_bin.clone(),
+                                    card_exp_month: Some(card_data.card_exp_month.clone()),
+                                    card_exp_year: Some(card_data.card_exp_year.clone()),
+                                    card_holder_name: card_data.card_holder_name.clone(),
+                                    // These are filled after calling the processor / connector
+                                    payment_checks: None,
+                                    authentication_data: None,
+                                    auth_code: None,
+                                    is_regulated,
+                                    signature_network: signature_network.clone(),
+                                },
+                            ))
+                        });
                 Ok(Some(card_info.unwrap_or_else(|| {
                     api_models::payments::AdditionalPaymentData::Card(Box::new(
                         api_models::payments::AdditionalCardInfo {
@@ -5984,13 +6051,16 @@ pub async fn get_additional_payment_data(
                 _ => None,
             };
 
-            let card_network = match card_data
-                .card_number
-                .is_cobadged_card()
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "Card cobadge check failed due to an invalid card network regex",
-                )? {
+            let bin_range = find_bin_range_for_card(db, &card_data.card_number).await;
+
+            let card_network = match bin_range.is_none()
+                && card_data
+                    .card_number
+                    .is_cobadged_card()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Card cobadge check failed due to an invalid card network regex",
+                    )? {
                 true => card_data.card_network.clone(),
                 false => None,
             };
@@ -6025,40 +6095,33 @@ pub async fn get_additional_payment_data(
                     }),
                 )))
             } else {
-                let card_info = card_isin
-                    .clone()
-                    .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
-                            .await
-                            .map_err(|error| services::logger::warn!(card_info_error=?error))
-                            .ok()
-                    })
-                    .await
-                    .flatten()
-                    .map(|card_info| {
-                        api_models::payments::AdditionalPaymentData::Card(Box::new(
-                            api_models::payments::AdditionalCardInfo {
-                                card_issuer: card_info.card_issuer,
-                                card_network: card_network.clone().or(card_info.card_network),
-                                bank_code: card_info.bank_code,
-                                card_type: card_info.card_type,
-                                card_issuing_country: card_info.card_issuing_country,
-                                card_issuing_country_code: card_info.country_code,
-                                last4: last4.clone(),
-                                card_isin: card_isin.clone(),
-                                card_extended_bin: card_extended_bin.clone(),
-                                card_exp_month: Some(card_data.card_exp_month.clone()),
-                                card_exp_year: Some(card_data.card_exp_year.clone()),
-                                card_holder_name: card_data.card_holder_name.clone(),
-                                // These are filled after calling the processor / connector
-                                payment_checks: None,
-                                authentication_data: None,
-                                is_regulated: None,
-                                signature_network: None,
-                                auth_code: None,
-                            },
-                        ))
-                    });
+                let card_info =
+                    get_card_info_by_bin_range_or_isin(db, bin_range, card_isin.clone())
+                        .await
+                        .map(|card_info| {
+                            api_models::payments::AdditionalPaymentData::Card(Box::new(
+                                api_models::payments::AdditionalCardInfo {
+                                    card_issuer: card_info.card_issuer,
+                                    card_network: card_network.clone().or(card_info.card_network),
+                                    bank_code: card_info.bank_code,
+                                    card_type: card_info.card_type,
+                                    card_issuing_country: card_info.card_issuing_country,
+                                    card_issuing_country_code: card_info.country_code,
+                                    last4: last4.clone(),
+                                    card_isin: card_isin.clone(),
+                                    card_extended_bin: card_extended_bin.clone(),
+                                    card_exp_month: Some(card_data.card_exp_month.clone()),
+                                    card_exp_year: Some(card_data.card_exp_year.clone()),
+                                    card_holder_name: card_data.card_holder_name.clone(),
+                                    // These are filled after calling the processor / connector
+                                    payment_checks: None,
+                                    authentication_data: None,
+                                    is_regulated: None,
+                                    signature_network: None,
+                                    auth_code: None,
+                                },
+                            ))
+                        });
                 Ok(Some(card_info.unwrap_or_else(|| {
                     api_models::payments::AdditionalPaymentData::Card(Box::new(
                         api_models::payments::AdditionalCardInfo {
@@ -6102,6 +6165,9 @@ pub async fn get_additional_payment_data(
                 _ => None,
             };
 
+            let bin_range =
+                find_bin_range_for_card(db, &card_with_limited_details.card_number).await;
+
             let last4 = Some(card_with_limited_details.card_number.get_last4());
             if card_with_limited_details.card_issuer.is_some()
                 && card_with_limited_details.card_network.is_some()
@@ -6136,45 +6202,40 @@ pub async fn get_additional_payment_data(
                     }),
                 )))
             } else {
-                let card_info = card_isin
-                    .clone()
-                    .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
-                            .await
-                            .map_err(|error| services::logger::warn!(card_info_error=?error))
-                            .ok()
-                    })
-                    .await
-                    .flatten()
-                    .map(|card_info| {
-                        api_models::payments::AdditionalPaymentData::Card(Box::new(
-                            api_models::payments::AdditionalCardInfo {
-                                card_issuer: card_info.card_issuer,
-                                card_network: card_with_limited_details
-                                    .card_network
-                                    .clone()
-                                    .or(card_info.card_network),
-                                bank_code: card_info.bank_code,
-                                card_type: card_info.card_type,
-                                card_issuing_country: card_info.card_issuing_country,
-                                card_issuing_country_code: card_info.country_code,
-                                last4: last4.clone(),
-                                card_isin: card_isin.clone(),
-                                card_extended_bin: card_extended_bin.clone(),
-                                card_exp_month: card_with_limited_details.card_exp_month.clone(),
-                                card_exp_year: card_with_limited_details.card_exp_year.clone(),
-                                card_holder_name: card_with_limited_details
-                                    .card_holder_name
-                                    .clone(),
-                                // These are filled after calling the processor / connector
-                                payment_checks: None,
-                                authentication_data: None,
-                                is_regulated: None,
-                                signature_network: None,
-                                auth_code: None,
-                            },
-                        ))
-                    });
+                let card_info =
+                    get_card_info_by_bin_range_or_isin(db, bin_range, card_isin.clone())
+                        .await
+                        .map(|card_info| {
+                            api_models::payments::AdditionalPaymentData::Card(Box::new(
+                                api_models::payments::AdditionalCardInfo {
+                                    card_issuer: card_info.card_issuer,
+                                    card_network: card_with_limited_details
+                                        .card_network
+                                        .clone()
+                                        .or(card_info.card_network),
+                                    bank_code: card_info.bank_code,
+                                    card_type: card_info.card_type,
+                                    card_issuing_country: card_info.card_issuing_country,
+                                    card_issuing_country_code: card_info.country_code,
+                                    last4: last4.clone(),
+                                    card_isin: card_isin.clone(),
+                                    card_extended_bin: card_extended_bin.clone(),
+                                    card_exp_month: card_with_limited_details
+                                        .card_exp_month
+                                        .clone(),
+                                    card_exp_year: card_with_limited_details.card_exp_year.clone(),
+                                    card_holder_name: card_with_limited_details
+                                        .card_holder_name
+                                        .clone(),
+                                    // These are filled after calling the processor / connector
+                                    payment_checks: None,
+                                    authentication_data: None,
+                                    is_regulated: None,
+                                    signature_network: None,
+                                    auth_code: None,
+                                },
+                            ))
+                        });
                 Ok(Some(card_info.unwrap_or_else(|| {
                     api_models::payments::AdditionalPaymentData::Card(Box::new(
                         api_models::payments::AdditionalCardInfo {
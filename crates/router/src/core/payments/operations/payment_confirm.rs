@@ -1548,10 +1548,23 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
             // Compute card_discovery and set it on payment_attempt BEFORE the 3DS rule evaluation
             payment_data.payment_attempt.card_discovery =
                 payment_data.get_card_discovery_for_card_payment_method();
-            // get three_ds_decision_rule_output using algorithm_id and payment data
-            let decision = three_ds_decision_rule::get_three_ds_decision_rule_output(
+            // get the sca exemption context if the exemption engine is enabled for the profile
+            let exemption_context = three_ds_decision_rule::exemptions::get_exemption_context(
                 state,
-                &business_profile.merchant_id,
+                business_profile,
+                &payment_data.payment_attempt,
+                payment_data.payment_method_data.as_ref(),
+                additional_card_info
+                    .as_ref()
+                    .and_then(|info| info.card_network.as_ref()),
+            )
+            .await
+            .inspect_err(|err| {
+                logger::error!("Error while getting sca exemption context {:?}", err)
+            })
+            .ok()
+            .flatten();
+            let three_ds_decision_rule_request =
                 api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest {
                     routing_id: algorithm_id,
                     payment: api_models::three_ds_decision_rule::PaymentData {
@@ -1606,9 +1619,27 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                             fraud_rate: Some(acquirer.acquirer_fraud_rate),
                         }
                     }),
-                },
-            )
-            .await
+                };
+            // get three_ds_decision_rule_output using algorithm_id and payment data
+            let decision = match exemption_context.as_ref() {
+                Some(exemption_context) => {
+                    three_ds_decision_rule::get_three_ds_decision_rule_output_with_exemptions(
+                        state,
+                        &business_profile.merchant_id,
+                        three_ds_decision_rule_request,
+                        exemption_context,
+                    )
+                    .await
+                }
+                None => {
+                    three_ds_decision_rule::get_three_ds_decision_rule_output(
+                        state,
+                        &business_profile.merchant_id,
+                        three_ds_decision_rule_request,
+                    )
+                    .await
+                }
+            }
             .inspect_err(|err| {
                 logger::error!(
                     "Error while getting three_ds_decision_rule output {:?}",
@@ -2825,6 +2825,45 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             .map(|info| info.status = status)
     });
 
+    // Successful card payments feed the fraud rates of the sca exemption engine
+    if matches!(
+        payment_data.payment_attempt.status,
+        enums::AttemptStatus::Charged | enums::AttemptStatus::Authorized
+    ) && payment_data.payment_attempt.payment_method == Some(enums::PaymentMethod::Card)
+    {
+        let state = state.clone();
+        let key_store = processor.get_key_store().clone();
+        let profile_id = payment_data.payment_intent.profile_id.clone();
+        let payment_attempt = payment_data.payment_attempt.clone();
+        let psd2_sca_exemption_type = payment_data.payment_intent.psd2_sca_exemption_type;
+        tokio::spawn(
+            async move {
+                let Some(profile_id) = profile_id else {
+                    return;
+                };
+                match state
+                    .store
+                    .find_business_profile_by_profile_id(&key_store, &profile_id)
+                    .await
+                {
+                    Ok(business_profile) => {
+                        crate::core::three_ds_decision_rule::exemptions::record_successful_payment(
+                            &state,
+                            &business_profile,
+                            &payment_attempt,
+                            psd2_sca_exemption_type,
+                        )
+                        .await
+                    }
+                    Err(error) => {
+                        logger::error!(?error, "Failed to fetch the profile for sca exemptions")
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+    }
+
     if payment_data.payment_attempt.status == enums::AttemptStatus::Failure {
         let _ = card_testing_guard_utils::increment_blocked_count_in_cache(
             state,
@@ -0,0 +1,1066 @@
+use std::collections::HashSet;
+
+use api_models::{payouts as payout_api, webhooks};
+use common_utils::{
+    ext_traits::ValueExt,
+    id_type::{self, GenerateId},
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_masking::ExposeInterface;
+use router_env::{instrument, logger, tracing, Instrument};
+use scheduler::utils as pt_utils;
+
+use super::{helpers as payout_helpers, payouts_create_core};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+        utils::{self as core_utils, bulk_job},
+        webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const PAYOUT_BATCH_WORKFLOW_TAG: &str = "PAYOUT_BATCH";
+const PAYOUT_BATCH_WORKFLOW_TASK: &str = "PAYOUT_BATCH_EXECUTE";
+
+/// A single row of a payout batch CSV file.
+///
+/// CSV files are flat, so only a single connector can be specified per row and metadata is not
+/// supported. Use a JSON file or the `items` field of the request for the complete item format.
+#[derive(Debug, serde::Deserialize)]
+struct PayoutBatchCsvRecord {
+    payout_id: Option<id_type::PayoutId>,
+    amount: common_utils::types::MinorUnit,
+    currency: api_models::enums::Currency,
+    customer_id: id_type::CustomerId,
+    payout_method_id: String,
+    payout_type: Option<api_models::enums::PayoutType>,
+    connector: Option<api_models::enums::PayoutConnectors>,
+    priority: Option<api_models::enums::PayoutSendPriority>,
+    auto_fulfill: Option<bool>,
+    description: Option<String>,
+}
+
+impl From<PayoutBatchCsvRecord> for payout_api::PayoutBatchItemRequest {
+    fn from(record: PayoutBatchCsvRecord) -> Self {
+        Self {
+            payout_id: record.payout_id,
+            amount: record.amount,
+            currency: record.currency,
+            customer_id: record.customer_id,
+            payout_method_id: record.payout_method_id,
+            payout_type: record.payout_type,
+            connector: record.connector.map(|connector| vec![connector]),
+            priority: record.priority,
+            auto_fulfill: record.auto_fulfill,
+            description: record.description,
+            metadata: None,
+        }
+    }
+}
+
+fn parse_payout_batch_csv(data: &[u8]) -> RouterResult<Vec<payout_api::PayoutBatchItemRequest>> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(data);
+
+    csv_reader
+        .deserialize::<PayoutBatchCsvRecord>()
+        .enumerate()
+        .map(|(line_number, record)| {
+            record
+                .map(payout_api::PayoutBatchItemRequest::from)
+                .map_err(|error| {
+                    report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "Failed to parse line {} of the payout batch file: {error}",
+                            line_number + 1
+                        ),
+                    })
+                })
+        })
+        .collect()
+}
+
+async fn get_payout_batch_items_from_file(
+    state: &SessionState,
+    platform: &domain::Platform,
+    file_id: String,
+) -> RouterResult<Vec<payout_api::PayoutBatchItemRequest>> {
+    let file_info = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(file_id),
+        None,
+        platform,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+
+    let file_data = file_info
+        .file_data
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("File data not found for payout batch file")?;
+
+    match file_info.file_type.as_deref() {
+        Some("text/csv") => parse_payout_batch_csv(&file_data),
+        Some("application/json") => serde_json::from_slice(&file_data).map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Failed to parse the payout batch file: {error}"),
+            })
+        }),
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout batch file must be a CSV or a JSON file".to_string(),
+        })),
+    }
+}
+
+/// Rejects a batch with the list of offending items before it is sent for review. The customer of
+/// each item must belong to the merchant, and the payout method must be one saved for that customer.
+async fn validate_payout_batch_items(
+    state: &SessionState,
+    platform: &domain::Platform,
+    items: &[payout_api::PayoutBatchItemRequest],
+) -> RouterResult<()> {
+    bulk_job::validate_job_item_count("payout batch", items.len(), consts::MAX_PAYOUT_BATCH_ITEMS)?;
+
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let key_store = platform.get_processor().get_key_store();
+    let storage_scheme = platform.get_processor().get_account().storage_scheme;
+    let mut checked_payout_ids = HashSet::new();
+    let mut known_customer_ids = HashSet::new();
+    let mut invalid_items = get_payout_batch_item_errors(items);
+
+    for (index, item) in items.iter().enumerate() {
+        let customer_exists = known_customer_ids.contains(&item.customer_id)
+            || db
+                .find_customer_optional_by_customer_id_merchant_id(
+                    &item.customer_id,
+                    merchant_id,
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the customer of a payout batch item")?
+                .is_some();
+
+        if customer_exists {
+            known_customer_ids.insert(item.customer_id.clone());
+            match db
+                .find_payment_method(key_store, &item.payout_method_id, storage_scheme)
+                .await
+            {
+                Ok(payment_method) => invalid_items.extend(
+                    get_payout_method_error(
+                        &payment_method.merchant_id,
+                        &payment_method.customer_id,
+                        merchant_id,
+                        &item.customer_id,
+                    )
+                    .map(|error| (index, error.to_string())),
+                ),
+                Err(error) if error.current_context().is_db_not_found() => {
+                    invalid_items.push((index, "payout_method_id not found".to_string()));
+                }
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find the payout method of a payout batch item")?,
+            }
+        } else {
+            invalid_items.push((index, "customer_id not found".to_string()));
+        }
+
+        // The duplicates within the batch are reported by `get_payout_batch_item_errors`
+        if let Some(payout_id) = item
+            .payout_id
+            .as_ref()
+            .filter(|payout_id| checked_payout_ids.insert((*payout_id).clone()))
+        {
+            if super::validator::validate_uniqueness_of_payout_id_against_merchant_id(
+                &*state.store,
+                payout_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await?
+            .is_some()
+            {
+                invalid_items.push((index, "payout_id already exists".to_string()));
+            }
+        }
+    }
+
+    invalid_items.sort_by_key(|(index, _)| *index);
+    bulk_job::validate_job_items(
+        "payout batch",
+        invalid_items
+            .into_iter()
+            .map(|(index, error)| format!("{index}: {error}"))
+            .collect(),
+    )
+}
+
+/// Returns the errors of the items which do not depend on the stored records, along with the
+/// index of the offending item.
+fn get_payout_batch_item_errors(
+    items: &[payout_api::PayoutBatchItemRequest],
+) -> Vec<(usize, String)> {
+    let mut seen_payout_ids = HashSet::new();
+    let mut invalid_items = Vec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        if !item.amount.is_greater_than(0) {
+            invalid_items.push((index, "amount must be greater than zero".to_string()));
+        }
+        if let Some(payout_id) = item.payout_id.as_ref() {
+            if !seen_payout_ids.insert(payout_id) {
+                invalid_items.push((index, "duplicate payout_id within the batch".to_string()));
+            }
+        }
+    }
+
+    invalid_items
+}
+
+/// Returns the error of an item whose payout method is not one saved for the customer of the
+/// item. A payout method of another merchant is reported as not found.
+fn get_payout_method_error(
+    payment_method_merchant_id: &id_type::MerchantId,
+    payment_method_customer_id: &id_type::CustomerId,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> Option<&'static str> {
+    if payment_method_merchant_id != merchant_id {
+        Some("payout_method_id not found")
+    } else if payment_method_customer_id != customer_id {
+        Some("payout_method_id does not belong to the customer")
+    } else {
+        None
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_create_core(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    req: payout_api::PayoutBatchCreateRequest,
+) -> RouterResponse<payout_api::PayoutBatchResponse> {
+    let db = &*state.store;
+    let processor = platform.get_processor();
+    let merchant_id = processor.get_account().get_id();
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        None,
+        None,
+        processor,
+        profile_id.as_ref().or(req.profile_id.as_ref()),
+        db,
+        true,
+    )
+    .await?;
+
+    let items = match (req.file_id.clone(), req.items) {
+        (Some(file_id), None) => {
+            get_payout_batch_items_from_file(&state, &platform, file_id).await?
+        }
+        (None, Some(items)) => items,
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "exactly one of file_id or items must be provided".to_string(),
+        }))?,
+    };
+
+    validate_payout_batch_items(&state, &platform, &items).await?;
+
+    let now = common_utils::date_time::now();
+    let payout_batch_id = common_utils::generate_id(consts::ID_LENGTH, "payout_batch");
+    let total_count = i32::try_from(items.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert payout batch item count")?;
+
+    let batch_items = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            // Payout ids are assigned upfront so that re-running an item never creates a second payout
+            let payout_id = item
+                .payout_id
+                .clone()
+                .unwrap_or_else(id_type::PayoutId::generate);
+            let item_index = i32::try_from(index)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to convert payout batch item index")?;
+            let request_data = serde_json::to_value(payout_api::PayoutBatchItemRequest {
+                payout_id: Some(payout_id.clone()),
+                ..item.clone()
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize payout batch item")?;
+
+            Ok(storage::PayoutBatchItemNew {
+                payout_batch_id: payout_batch_id.clone(),
+                item_index,
+                merchant_id: merchant_id.clone(),
+                payout_id,
+                customer_id: item.customer_id,
+                amount: item.amount,
+                currency: item.currency,
+                request_data: request_data.into(),
+                status: storage_enums::PayoutBatchItemStatus::Pending,
+                payout_status: None,
+                error_code: None,
+                error_message: None,
+                created_at: now,
+                modified_at: now,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let payout_batch = db
+        .insert_payout_batch_with_items(
+            storage::PayoutBatchNew {
+                payout_batch_id,
+                merchant_id: merchant_id.clone(),
+                profile_id,
+                status: storage_enums::PayoutBatchStatus::PendingApproval,
+                description: req.description,
+                file_id: req.file_id,
+                total_count,
+                submitted_count: 0,
+                failed_count: 0,
+                created_by: platform
+                    .get_initiator()
+                    .and_then(|initiator| initiator.to_created_by())
+                    .map(|created_by| created_by.to_string()),
+                reviewed_by: None,
+                rejection_reason: None,
+                created_at: now,
+                modified_at: now,
+            },
+            batch_items,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payout_api::PayoutBatchResponse::foreign_from((payout_batch, None)),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_retrieve_core(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    payout_batch_id: String,
+    query: payout_api::PayoutBatchRetrieveQuery,
+) -> RouterResponse<payout_api::PayoutBatchResponse> {
+    let db = &*state.store;
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_payout_batch_id(
+            platform.get_processor().get_account().get_id(),
+            &payout_batch_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("payout batch {payout_batch_id} not found"),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payout_batch)?;
+
+    let items = if query.expand_items {
+        Some(
+            db.list_payout_batch_items_by_payout_batch_id(&payout_batch.payout_batch_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list payout batch items")?,
+        )
+    } else {
+        None
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payout_api::PayoutBatchResponse::foreign_from((payout_batch, items)),
+    ))
+}
+
+/// Approves or rejects a batch which is pending approval.
+///
+/// The review must be performed by a dashboard user other than the one who created the batch.
+#[instrument(skip_all)]
+pub async fn payout_batch_review_core(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    payout_batch_id: String,
+    req: payout_api::PayoutBatchReviewRequest,
+) -> RouterResponse<payout_api::PayoutBatchResponse> {
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_payout_batch_id(merchant_id, &payout_batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("payout batch {payout_batch_id} not found"),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payout_batch)?;
+
+    let reviewed_by = validate_payout_batch_review(
+        &payout_batch,
+        platform
+            .get_initiator()
+            .and_then(|initiator| initiator.to_created_by()),
+    )?;
+    let payout_batch_update = get_payout_batch_review_update(req.action, reviewed_by, req.reason);
+
+    // The status is checked again while updating, so that concurrent reviews cannot both succeed
+    let payout_batch = db
+        .update_payout_batch_by_status(
+            payout_batch,
+            storage_enums::PayoutBatchStatus::PendingApproval,
+            payout_batch_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "payout batch has already been reviewed".to_string(),
+        })?;
+
+    if payout_batch.status == storage_enums::PayoutBatchStatus::Processing {
+        add_payout_batch_execution_task(&state, &payout_batch)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to schedule payout batch execution")?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        payout_api::PayoutBatchResponse::foreign_from((payout_batch, None)),
+    ))
+}
+
+/// Returns the reviewer of the batch, once the batch is found to be pending approval and the
+/// reviewer to be a dashboard user other than the one who created the batch.
+fn validate_payout_batch_review(
+    payout_batch: &storage::PayoutBatch,
+    reviewer: Option<common_utils::types::CreatedBy>,
+) -> RouterResult<String> {
+    let reviewed_by = match reviewer {
+        Some(created_by @ common_utils::types::CreatedBy::Jwt { .. }) => created_by.to_string(),
+        _ => Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "payout batches can only be reviewed by dashboard users".to_string(),
+        }))?,
+    };
+
+    if payout_batch.created_by.as_ref() == Some(&reviewed_by) {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "payout batches cannot be reviewed by the user who created them".to_string(),
+        }));
+    }
+
+    if payout_batch.status != storage_enums::PayoutBatchStatus::PendingApproval {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "payout batch cannot be reviewed as it is in {} status",
+                payout_batch.status
+            ),
+        }));
+    }
+
+    Ok(reviewed_by)
+}
+
+fn get_payout_batch_review_update(
+    action: payout_api::PayoutBatchReviewAction,
+    reviewed_by: String,
+    reason: Option<String>,
+) -> storage::PayoutBatchUpdate {
+    match action {
+        payout_api::PayoutBatchReviewAction::Approve => storage::PayoutBatchUpdate::ReviewUpdate {
+            status: storage_enums::PayoutBatchStatus::Processing,
+            reviewed_by: Some(reviewed_by),
+            rejection_reason: None,
+        },
+        payout_api::PayoutBatchReviewAction::Reject => storage::PayoutBatchUpdate::ReviewUpdate {
+            status: storage_enums::PayoutBatchStatus::Rejected,
+            reviewed_by: Some(reviewed_by),
+            rejection_reason: reason,
+        },
+    }
+}
+
+async fn add_payout_batch_execution_task(
+    state: &SessionState,
+    payout_batch: &storage::PayoutBatch,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        PAYOUT_BATCH_WORKFLOW_TASK,
+        &payout_batch.payout_batch_id,
+        &payout_batch.merchant_id,
+    );
+    let tracking_data = storage::PayoutBatchTrackingData {
+        payout_batch_id: payout_batch.payout_batch_id.clone(),
+        merchant_id: payout_batch.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYOUT_BATCH_WORKFLOW_TASK,
+        runner,
+        [PAYOUT_BATCH_WORKFLOW_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Executes the next set of pending items of an approved batch.
+///
+/// Returns `true` once every item of the batch has been executed and the batch has been moved to
+/// a terminal status.
+#[instrument(skip_all, fields(payout_batch_id = %payout_batch.payout_batch_id))]
+pub async fn execute_payout_batch_items(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    let storage_scheme = platform.get_processor().get_account().storage_scheme;
+
+    let pending_items = db
+        .list_payout_batch_items_by_payout_batch_id_status(
+            &payout_batch.payout_batch_id,
+            storage_enums::PayoutBatchItemStatus::Pending,
+            consts::PAYOUT_BATCH_ITEMS_PER_RUN,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list pending payout batch items")?;
+
+    for item in pending_items {
+        let existing_payout = db
+            .find_optional_payout_by_merchant_id_payout_id(
+                &item.merchant_id,
+                &item.payout_id,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payout for payout batch item")?;
+
+        let item_update = match existing_payout {
+            // The payout was created in a previous run, but the item was not updated
+            Some(payout) => get_payout_batch_item_update(payout.status, None, None),
+            None => execute_payout_batch_item(state, platform, &payout_batch, &item).await?,
+        };
+
+        db.update_payout_batch_item(item, item_update)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payout batch item")?;
+    }
+
+    let item_counts = bulk_job::JobItemCounts::fetch(
+        |status| {
+            db.get_payout_batch_items_count_by_payout_batch_id_status(
+                &payout_batch.payout_batch_id,
+                status,
+            )
+        },
+        storage_enums::PayoutBatchItemStatus::Submitted,
+        storage_enums::PayoutBatchItemStatus::Failed,
+        storage_enums::PayoutBatchItemStatus::Pending,
+    )
+    .await?;
+
+    let (submitted_count, failed_count) = item_counts.get_progress()?;
+    let payout_batch = db
+        .update_payout_batch(
+            payout_batch,
+            storage::PayoutBatchUpdate::ProgressUpdate {
+                submitted_count,
+                failed_count,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch progress")?;
+
+    let Some(outcome) = item_counts.get_outcome() else {
+        return Ok(false);
+    };
+
+    let payout_batch = db
+        .update_payout_batch(
+            payout_batch,
+            storage::PayoutBatchUpdate::StatusUpdate {
+                status: storage_enums::PayoutBatchStatus::foreign_from(outcome),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch status")?;
+
+    trigger_payout_batch_webhook(state, platform, payout_batch).await?;
+    Ok(true)
+}
+
+async fn execute_payout_batch_item(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payout_batch: &storage::PayoutBatch,
+    item: &storage::PayoutBatchItem,
+) -> RouterResult<storage::PayoutBatchItemUpdate> {
+    let item_request: payout_api::PayoutBatchItemRequest = item
+        .request_data
+        .clone()
+        .expose()
+        .parse_value("PayoutBatchItemRequest")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payout batch item")?;
+    let payout_request = item_request
+        .to_payout_create_request(item.payout_id.clone(), payout_batch.profile_id.clone());
+
+    match Box::pin(payouts_create_core(
+        state.clone(),
+        platform.clone(),
+        payout_request,
+    ))
+    .await
+    {
+        Ok(services::ApplicationResponse::Json(payout_response)) => {
+            Ok(get_payout_batch_item_update(
+                payout_response.status,
+                payout_response.error_code,
+                payout_response.error_message,
+            ))
+        }
+        Ok(_) => Ok(get_payout_batch_item_update(
+            storage_enums::PayoutStatus::Pending,
+            None,
+            None,
+        )),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                item_index = item.item_index,
+                "payout batch item failed"
+            );
+            let (error_code, error_message) =
+                bulk_job::get_job_item_error_details(error.current_context());
+            Ok(storage::PayoutBatchItemUpdate {
+                status: storage_enums::PayoutBatchItemStatus::Failed,
+                payout_status: None,
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                modified_at: common_utils::date_time::now(),
+            })
+        }
+    }
+}
+
+fn get_payout_batch_item_update(
+    payout_status: storage_enums::PayoutStatus,
+    error_code: Option<String>,
+    error_message: Option<String>,
+) -> storage::PayoutBatchItemUpdate {
+    let status = if payout_helpers::is_payout_err_state(payout_status) {
+        storage_enums::PayoutBatchItemStatus::Failed
+    } else {
+        storage_enums::PayoutBatchItemStatus::Submitted
+    };
+
+    storage::PayoutBatchItemUpdate {
+        status,
+        payout_status: Some(payout_status),
+        error_code,
+        error_message,
+        modified_at: common_utils::date_time::now(),
+    }
+}
+
+async fn trigger_payout_batch_webhook(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<()> {
+    let Some(event_type) = Option::<storage_enums::EventType>::from(payout_batch.status) else {
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            platform.get_processor().get_key_store(),
+            &payout_batch.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: payout_batch.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let cloned_state = state.clone();
+    let processor = platform.get_processor().clone();
+    let payout_batch_response = payout_api::PayoutBatchResponse::foreign_from((payout_batch, None));
+
+    tokio::spawn(
+        async move {
+            let primary_object_created_at = Some(payout_batch_response.created_at);
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                processor,
+                business_profile,
+                event_type,
+                storage_enums::EventClass::Payouts,
+                payout_batch_response.payout_batch_id.clone(),
+                storage_enums::EventObjectType::PayoutBatchDetails,
+                webhooks::OutgoingWebhookContent::PayoutBatchDetails(Box::new(
+                    payout_batch_response,
+                )),
+                primary_object_created_at,
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
+impl ForeignFrom<storage::PayoutBatchItem> for payout_api::PayoutBatchItemResponse {
+    fn foreign_from(item: storage::PayoutBatchItem) -> Self {
+        Self {
+            item_index: item.item_index,
+            payout_id: item.payout_id,
+            status: item.status,
+            payout_status: item.payout_status,
+            amount: item.amount,
+            currency: item.currency,
+            customer_id: item.customer_id,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        }
+    }
+}
+
+impl ForeignFrom<(storage::PayoutBatch, Option<Vec<storage::PayoutBatchItem>>)>
+    for payout_api::PayoutBatchResponse
+{
+    fn foreign_from(
+        (payout_batch, items): (storage::PayoutBatch, Option<Vec<storage::PayoutBatchItem>>),
+    ) -> Self {
+        Self {
+            payout_batch_id: payout_batch.payout_batch_id,
+            merchant_id: payout_batch.merchant_id,
+            profile_id: payout_batch.profile_id,
+            status: payout_batch.status,
+            description: payout_batch.description,
+            file_id: payout_batch.file_id,
+            total_count: payout_batch.total_count,
+            submitted_count: payout_batch.submitted_count,
+            failed_count: payout_batch.failed_count,
+            created_by: payout_batch.created_by,
+            reviewed_by: payout_batch.reviewed_by,
+            rejection_reason: payout_batch.rejection_reason,
+            created_at: payout_batch.created_at,
+            modified_at: payout_batch.modified_at,
+            items: items.map(|items| {
+                items
+                    .into_iter()
+                    .map(payout_api::PayoutBatchItemResponse::foreign_from)
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl ForeignFrom<bulk_job::JobOutcome> for storage_enums::PayoutBatchStatus {
+    fn foreign_from(outcome: bulk_job::JobOutcome) -> Self {
+        match outcome {
+            bulk_job::JobOutcome::Completed => Self::Completed,
+            bulk_job::JobOutcome::Failed => Self::Failed,
+            bulk_job::JobOutcome::PartiallyCompleted => Self::PartiallyCompleted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::types::{CreatedBy, MinorUnit};
+
+    use super::*;
+
+    fn get_item(payout_id: Option<&str>, amount: i64) -> payout_api::PayoutBatchItemRequest {
+        payout_api::PayoutBatchItemRequest {
+            payout_id: payout_id
+                .map(|payout_id| id_type::PayoutId::try_from(Cow::from(payout_id.to_string())))
+                .transpose()
+                .unwrap(),
+            amount: MinorUnit::new(amount),
+            currency: api_models::enums::Currency::USD,
+            customer_id: id_type::CustomerId::try_from(Cow::from("cus_1")).unwrap(),
+            payout_method_id: "pm_1".to_string(),
+            payout_type: None,
+            connector: None,
+            priority: None,
+            auto_fulfill: None,
+            description: None,
+            metadata: None,
+        }
+    }
+
+    fn get_payout_batch(
+        status: storage_enums::PayoutBatchStatus,
+        created_by: Option<CreatedBy>,
+    ) -> storage::PayoutBatch {
+        let now = common_utils::date_time::now();
+        storage::PayoutBatch {
+            payout_batch_id: "payout_batch_1".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: id_type::ProfileId::try_from(Cow::from("pro_1")).unwrap(),
+            status,
+            description: None,
+            file_id: None,
+            total_count: 3,
+            submitted_count: 2,
+            failed_count: 1,
+            created_by: created_by.map(|created_by| created_by.to_string()),
+            reviewed_by: None,
+            rejection_reason: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    fn get_jwt_user(user_id: &str) -> CreatedBy {
+        CreatedBy::Jwt {
+            user_id: user_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_payout_batch_item_errors() {
+        let items = [
+            get_item(Some("payout_1"), 100),
+            get_item(Some("payout_2"), 0),
+            get_item(None, 100),
+            get_item(Some("payout_1"), -5),
+            get_item(None, 100),
+        ];
+
+        assert_eq!(
+            get_payout_batch_item_errors(&items),
+            vec![
+                (1, "amount must be greater than zero".to_string()),
+                (3, "amount must be greater than zero".to_string()),
+                (3, "duplicate payout_id within the batch".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payout_method_must_belong_to_the_customer_of_the_item() {
+        let merchant_id = id_type::MerchantId::default();
+        let other_merchant_id = id_type::MerchantId::try_from(Cow::from("merchant_2")).unwrap();
+        let customer_id = id_type::CustomerId::try_from(Cow::from("cus_1")).unwrap();
+        let other_customer_id = id_type::CustomerId::try_from(Cow::from("cus_2")).unwrap();
+
+        assert_eq!(
+            get_payout_method_error(&merchant_id, &customer_id, &merchant_id, &customer_id),
+            None
+        );
+        assert_eq!(
+            get_payout_method_error(&merchant_id, &other_customer_id, &merchant_id, &customer_id),
+            Some("payout_method_id does not belong to the customer")
+        );
+        assert_eq!(
+            get_payout_method_error(&other_merchant_id, &customer_id, &merchant_id, &customer_id),
+            Some("payout_method_id not found")
+        );
+    }
+
+    #[test]
+    fn test_payout_batch_is_reviewed_by_another_dashboard_user() {
+        let payout_batch = get_payout_batch(
+            storage_enums::PayoutBatchStatus::PendingApproval,
+            Some(get_jwt_user("user_1")),
+        );
+
+        assert_eq!(
+            validate_payout_batch_review(&payout_batch, Some(get_jwt_user("user_2"))).unwrap(),
+            get_jwt_user("user_2").to_string()
+        );
+    }
+
+    #[test]
+    fn test_payout_batch_cannot_be_reviewed_by_its_creator() {
+        let payout_batch = get_payout_batch(
+            storage_enums::PayoutBatchStatus::PendingApproval,
+            Some(get_jwt_user("user_1")),
+        );
+
+        let error =
+            validate_payout_batch_review(&payout_batch, Some(get_jwt_user("user_1"))).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::AccessForbidden { .. }
+        ));
+    }
+
+    #[test]
+    fn test_payout_batch_cannot_be_reviewed_with_an_api_key() {
+        let payout_batch = get_payout_batch(
+            storage_enums::PayoutBatchStatus::PendingApproval,
+            Some(get_jwt_user("user_1")),
+        );
+        let api_key = CreatedBy::Api {
+            merchant_id: "merchant_1".to_string(),
+        };
+
+        for reviewer in [Some(api_key), None] {
+            let error = validate_payout_batch_review(&payout_batch, reviewer).unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::AccessForbidden { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_only_payout_batch_pending_approval_can_be_reviewed() {
+        for status in [
+            storage_enums::PayoutBatchStatus::Rejected,
+            storage_enums::PayoutBatchStatus::Processing,
+            storage_enums::PayoutBatchStatus::Completed,
+        ] {
+            let payout_batch = get_payout_batch(status, Some(get_jwt_user("user_1")));
+
+            let error = validate_payout_batch_review(&payout_batch, Some(get_jwt_user("user_2")))
+                .unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::PreconditionFailed { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_payout_batch_review_status_transitions() {
+        assert!(matches!(
+            get_payout_batch_review_update(
+                payout_api::PayoutBatchReviewAction::Approve,
+                "user_2".to_string(),
+                Some("ignored".to_string()),
+            ),
+            storage::PayoutBatchUpdate::ReviewUpdate {
+                status: storage_enums::PayoutBatchStatus::Processing,
+                reviewed_by: Some(_),
+                rejection_reason: None,
+            }
+        ));
+        assert!(matches!(
+            get_payout_batch_review_update(
+                payout_api::PayoutBatchReviewAction::Reject,
+                "user_2".to_string(),
+                Some("duplicate batch".to_string()),
+            ),
+            storage::PayoutBatchUpdate::ReviewUpdate {
+                status: storage_enums::PayoutBatchStatus::Rejected,
+                reviewed_by: Some(_),
+                rejection_reason: Some(reason),
+            } if reason == "duplicate batch"
+        ));
+    }
+
+    #[test]
+    fn test_payout_batch_execution_status_transitions() {
+        assert_eq!(
+            storage_enums::PayoutBatchStatus::foreign_from(bulk_job::JobOutcome::Completed),
+            storage_enums::PayoutBatchStatus::Completed
+        );
+        assert_eq!(
+            storage_enums::PayoutBatchStatus::foreign_from(bulk_job::JobOutcome::Failed),
+            storage_enums::PayoutBatchStatus::Failed
+        );
+        assert_eq!(
+            storage_enums::PayoutBatchStatus::foreign_from(
+                bulk_job::JobOutcome::PartiallyCompleted
+            ),
+            storage_enums::PayoutBatchStatus::PartiallyCompleted
+        );
+
+        assert_eq!(
+            get_payout_batch_item_update(storage_enums::PayoutStatus::Success, None, None).status,
+            storage_enums::PayoutBatchItemStatus::Submitted
+        );
+        assert_eq!(
+            get_payout_batch_item_update(storage_enums::PayoutStatus::Pending, None, None).status,
+            storage_enums::PayoutBatchItemStatus::Submitted
+        );
+        assert_eq!(
+            get_payout_batch_item_update(storage_enums::PayoutStatus::Failed, None, None).status,
+            storage_enums::PayoutBatchItemStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_payout_batch_completed_webhook() {
+        for (status, event_type) in [
+            (storage_enums::PayoutBatchStatus::PendingApproval, None),
+            (storage_enums::PayoutBatchStatus::Rejected, None),
+            (storage_enums::PayoutBatchStatus::Processing, None),
+            (
+                storage_enums::PayoutBatchStatus::Completed,
+                Some(storage_enums::EventType::PayoutBatchCompleted),
+            ),
+            (
+                storage_enums::PayoutBatchStatus::PartiallyCompleted,
+                Some(storage_enums::EventType::PayoutBatchCompleted),
+            ),
+            (
+                storage_enums::PayoutBatchStatus::Failed,
+                Some(storage_enums::EventType::PayoutBatchCompleted),
+            ),
+        ] {
+            assert_eq!(Option::<storage_enums::EventType>::from(status), event_type);
+        }
+
+        let payout_batch_response = payout_api::PayoutBatchResponse::foreign_from((
+            get_payout_batch(
+                storage_enums::PayoutBatchStatus::PartiallyCompleted,
+                Some(get_jwt_user("user_1")),
+            ),
+            None,
+        ));
+
+        assert_eq!(payout_batch_response.payout_batch_id, "payout_batch_1");
+        assert_eq!(
+            payout_batch_response.status,
+            storage_enums::PayoutBatchStatus::PartiallyCompleted
+        );
+        assert_eq!(payout_batch_response.submitted_count, 2);
+        assert_eq!(payout_batch_response.failed_count, 1);
+        assert!(payout_batch_response.items.is_none());
+    }
+}
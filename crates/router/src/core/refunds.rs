@@ -1,3 +1,5 @@
+pub mod bulk;
+
 #[cfg(feature = "olap")]
 use std::collections::HashMap;
 
@@ -63,6 +65,45 @@ pub async fn refund_create_core(
     _profile_id: Option<common_utils::id_type::ProfileId>,
     req: refunds::RefundRequest,
 ) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let (payment_intent, payment_attempt, amount) =
+        get_refundable_payment_details(&state, &platform, &req.payment_id, req.amount).await?;
+
+    let creds_identifier = req
+        .merchant_connector_details
+        .as_ref()
+        .map(|mcd| mcd.creds_identifier.to_owned());
+    req.merchant_connector_details
+        .to_owned()
+        .async_map(|mcd| async {
+            helpers::insert_merchant_connector_creds_to_config(db, platform.get_processor(), mcd)
+                .await
+        })
+        .await
+        .transpose()?;
+
+    Box::pin(validate_and_create_refund(
+        &state,
+        &platform,
+        &payment_attempt,
+        &payment_intent,
+        amount,
+        req,
+        creds_identifier,
+    ))
+    .await
+    .map(services::ApplicationResponse::Json)
+}
+
+/// Fetches the payment intent and the last successful attempt of the payment, and validates that
+/// the payment can be refunded with the requested amount
+#[instrument(skip_all)]
+pub async fn get_refundable_payment_details(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_id: &common_utils::id_type::PaymentId,
+    refund_amount: Option<MinorUnit>,
+) -> RouterResult<(storage::PaymentIntent, storage::PaymentAttempt, MinorUnit)> {
     let db = &*state.store;
     let (processor_merchant_id, payment_intent, payment_attempt, amount);
 
@@ -70,7 +111,7 @@ pub async fn refund_create_core(
 
     payment_intent = db
         .find_payment_intent_by_payment_id_processor_merchant_id(
-            &req.payment_id,
+            payment_id,
             processor_merchant_id,
             platform.get_processor().get_key_store(),
             platform.get_processor().get_account().storage_scheme,
@@ -93,7 +134,7 @@ pub async fn refund_create_core(
     )?;
 
     payment_intent
-        .validate_amount_against_intent_state_metadata(req.amount)
+        .validate_amount_against_intent_state_metadata(refund_amount)
         .map_err(|err| {
             err.change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)
                 .attach_printable("refund amount validation against payment intent failed")
@@ -102,8 +143,7 @@ pub async fn refund_create_core(
     payment_intent.prevent_refund_after_post_capture_void()?;
 
     // Amount is not passed in request refer from payment intent.
-    amount = req
-        .amount
+    amount = refund_amount
         .or(payment_intent.amount_captured)
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("amount captured is none in a successful payment")?;
@@ -119,37 +159,14 @@ pub async fn refund_create_core(
 
     payment_attempt = db
         .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_processor_merchant_id(
-            &req.payment_id,
+            payment_id,
             processor_merchant_id,
             platform.get_processor().get_account().storage_scheme,
             platform.get_processor().get_key_store()
         ).await
         .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
-    let creds_identifier = req
-        .merchant_connector_details
-        .as_ref()
-        .map(|mcd| mcd.creds_identifier.to_owned());
-    req.merchant_connector_details
-        .to_owned()
-        .async_map(|mcd| async {
-            helpers::insert_merchant_connector_creds_to_config(db, platform.get_processor(), mcd)
-                .await
-        })
-        .await
-        .transpose()?;
-
-    Box::pin(validate_and_create_refund(
-        &state,
-        &platform,
-        &payment_attempt,
-        &payment_intent,
-        amount,
-        req,
-        creds_identifier,
-    ))
-    .await
-    .map(services::ApplicationResponse::Json)
+    Ok((payment_intent, payment_attempt, amount))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1295,6 +1312,56 @@ pub async fn refund_update_core(
 
 // ********************************************** VALIDATIONS **********************************************
 
+/// Validates that a refund of `refund_amount` can be created for the payment, considering the
+/// refunds already created for it.
+pub async fn validate_refund_against_payment(
+    state: &SessionState,
+    platform: &domain::Platform,
+    connector_transaction_id: &str,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    refund_amount: MinorUnit,
+) -> RouterResult<()> {
+    let all_refunds = state
+        .store
+        .find_refund_by_merchant_id_connector_transaction_id(
+            platform.get_processor().get_account().get_id(),
+            connector_transaction_id,
+            platform.get_processor().get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    //[#249]: Add Connector Based Validation here.
+    validator::validate_payment_order_age(&payment_intent.created_at, state.conf.refund.max_age)
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "created_at".to_string(),
+            expected_format: format!(
+                "created_at not older than {} days",
+                state.conf.refund.max_age
+            ),
+        })?;
+
+    let total_amount_captured = payment_intent
+        .amount_captured
+        .unwrap_or(payment_attempt.get_total_amount());
+
+    validator::validate_refund_amount(
+        total_amount_captured.get_amount_as_i64(),
+        &all_refunds,
+        refund_amount.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
+
+    validator::validate_maximum_refund_against_payment_attempt(
+        &all_refunds,
+        state.conf.refund.max_attempts,
+    )
+    .change_context(errors::ApiErrorResponse::MaximumRefundCount)?;
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 pub async fn validate_and_create_refund(
@@ -1342,43 +1409,17 @@ pub async fn validate_and_create_refund(
             )
         })?;
 
-    let all_refunds = db
-        .find_refund_by_merchant_id_connector_transaction_id(
-            platform.get_processor().get_account().get_id(),
-            &connector_transaction_id,
-            platform.get_processor().get_account().storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
-
     let currency = payment_attempt.currency.get_required_value("currency")?;
 
-    //[#249]: Add Connector Based Validation here.
-    validator::validate_payment_order_age(&payment_intent.created_at, state.conf.refund.max_age)
-        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "created_at".to_string(),
-            expected_format: format!(
-                "created_at not older than {} days",
-                state.conf.refund.max_age
-            ),
-        })?;
-
-    let total_amount_captured = payment_intent
-        .amount_captured
-        .unwrap_or(payment_attempt.get_total_amount());
-
-    validator::validate_refund_amount(
-        total_amount_captured.get_amount_as_i64(),
-        &all_refunds,
-        refund_amount.get_amount_as_i64(),
-    )
-    .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
-
-    validator::validate_maximum_refund_against_payment_attempt(
-        &all_refunds,
-        state.conf.refund.max_attempts,
+    validate_refund_against_payment(
+        state,
+        platform,
+        &connector_transaction_id,
+        payment_attempt,
+        payment_intent,
+        refund_amount,
     )
-    .change_context(errors::ApiErrorResponse::MaximumRefundCount)?;
+    .await?;
 
     let connector = payment_attempt
         .connector
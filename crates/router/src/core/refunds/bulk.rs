@@ -0,0 +1,644 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use api_models::refunds as refund_api;
+use common_utils::{ext_traits::ValueExt, id_type};
+use error_stack::{report, ResultExt};
+use futures::future;
+use hyperswitch_masking::ExposeInterface;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use tokio::sync::{Mutex, Semaphore};
+
+use super::{
+    get_refundable_payment_details, validate_and_create_refund, validate_refund_against_payment,
+};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils::{self as core_utils, bulk_job},
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const BULK_REFUND_WORKFLOW_TAG: &str = "BULK_REFUND";
+const BULK_REFUND_WORKFLOW_TASK: &str = "BULK_REFUND_EXECUTE";
+
+/// Checks the refund of every item against its payment before the job is accepted, so that a job
+/// only fails at execution time because of the connector.
+async fn validate_bulk_refund_items(
+    state: &SessionState,
+    platform: &domain::Platform,
+    profile_id: Option<&id_type::ProfileId>,
+    items: &[refund_api::BulkRefundItemRequest],
+) -> RouterResult<()> {
+    bulk_job::validate_job_item_count("bulk refund", items.len(), consts::MAX_BULK_REFUND_ITEMS)?;
+
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let storage_scheme = platform.get_processor().get_account().storage_scheme;
+    let mut seen_refund_ids = HashSet::new();
+    let mut invalid_items = Vec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        let is_amount_valid = item.amount.is_none_or(|amount| amount.is_greater_than(0));
+        if !is_amount_valid {
+            invalid_items.push(format!("{index}: amount must be greater than zero"));
+        }
+
+        if let Some(refund_id) = item.refund_id.as_ref() {
+            if !seen_refund_ids.insert(refund_id.clone()) {
+                invalid_items.push(format!("{index}: duplicate refund_id within the job"));
+            } else {
+                match db
+                    .find_refund_by_merchant_id_refund_id(merchant_id, refund_id, storage_scheme)
+                    .await
+                {
+                    Ok(_) => invalid_items.push(format!("{index}: refund_id already exists")),
+                    Err(error) if error.current_context().is_db_not_found() => {}
+                    Err(error) => Err(error)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to find refund for bulk refund item")?,
+                }
+            }
+        }
+
+        if is_amount_valid {
+            match validate_bulk_refund_item_payment(state, platform, profile_id, item).await {
+                Ok(()) => {}
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        errors::ApiErrorResponse::InternalServerError
+                    ) =>
+                {
+                    Err(error)?
+                }
+                Err(error) => {
+                    let (_, error_message) =
+                        bulk_job::get_job_item_error_details(error.current_context());
+                    invalid_items.push(format!("{index}: {error_message}"));
+                }
+            }
+        }
+    }
+
+    bulk_job::validate_job_items("bulk refund", invalid_items)
+}
+
+/// Runs the checks performed while creating the refund of an item, without creating it.
+async fn validate_bulk_refund_item_payment(
+    state: &SessionState,
+    platform: &domain::Platform,
+    profile_id: Option<&id_type::ProfileId>,
+    item: &refund_api::BulkRefundItemRequest,
+) -> RouterResult<()> {
+    let (payment_intent, payment_attempt, amount) =
+        get_refundable_payment_details(state, platform, &item.payment_id, item.amount).await?;
+
+    if profile_id.is_some() && profile_id != payment_intent.profile_id.as_ref() {
+        return Err(report!(errors::ApiErrorResponse::PaymentNotFound))
+            .attach_printable("payment does not belong to the profile of the bulk refund");
+    }
+
+    let connector_transaction_id = payment_attempt
+        .connector_transaction_id
+        .clone()
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Missing connector_transaction_id in payment attempt")
+        })?;
+
+    validate_refund_against_payment(
+        state,
+        platform,
+        &connector_transaction_id,
+        &payment_attempt,
+        &payment_intent,
+        amount,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn bulk_refund_create_core(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    req: refund_api::BulkRefundRequest,
+) -> RouterResponse<refund_api::BulkRefundResponse> {
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id();
+
+    validate_bulk_refund_items(&state, &platform, profile_id.as_ref(), &req.items).await?;
+
+    let now = common_utils::date_time::now();
+    let bulk_refund_id = common_utils::generate_id(consts::ID_LENGTH, "bulk_refund");
+    let total_count = i32::try_from(req.items.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert bulk refund item count")?;
+
+    let bulk_refund_items = req
+        .items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            // Refund ids are assigned upfront so that re-running an item never creates a second refund
+            let refund_id = item
+                .refund_id
+                .clone()
+                .unwrap_or_else(|| common_utils::generate_id(consts::ID_LENGTH, "ref"));
+            let item_index = i32::try_from(index)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to convert bulk refund item index")?;
+            let request_data = serde_json::to_value(refund_api::BulkRefundItemRequest {
+                refund_id: Some(refund_id.clone()),
+                ..item.clone()
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize bulk refund item")?;
+
+            Ok(storage::BulkRefundItemNew {
+                bulk_refund_id: bulk_refund_id.clone(),
+                item_index,
+                merchant_id: merchant_id.clone(),
+                payment_id: item.payment_id,
+                refund_id,
+                amount: item.amount,
+                request_data: request_data.into(),
+                connector: None,
+                status: storage_enums::BulkRefundItemStatus::Pending,
+                refund_status: None,
+                error_code: None,
+                error_message: None,
+                created_at: now,
+                modified_at: now,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let bulk_refund = db
+        .insert_bulk_refund(storage::BulkRefundNew {
+            bulk_refund_id,
+            merchant_id: merchant_id.clone(),
+            profile_id,
+            status: storage_enums::BulkRefundStatus::Processing,
+            total_count,
+            submitted_count: 0,
+            failed_count: 0,
+            created_by: platform
+                .get_initiator()
+                .and_then(|initiator| initiator.to_created_by())
+                .map(|created_by| created_by.to_string()),
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert bulk refund")?;
+
+    db.insert_bulk_refund_items(bulk_refund_items)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert bulk refund items")?;
+
+    add_bulk_refund_execution_task(&state, &bulk_refund)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule bulk refund execution")?;
+
+    Ok(services::ApplicationResponse::Json(
+        refund_api::BulkRefundResponse::foreign_from((bulk_refund, total_count)),
+    ))
+}
+
+async fn find_bulk_refund(
+    state: &SessionState,
+    platform: &domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    bulk_refund_id: &str,
+) -> RouterResult<storage::BulkRefund> {
+    let bulk_refund = state
+        .store
+        .find_bulk_refund_by_merchant_id_bulk_refund_id(
+            platform.get_processor().get_account().get_id(),
+            bulk_refund_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("bulk refund {bulk_refund_id} not found"),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &bulk_refund)?;
+
+    Ok(bulk_refund)
+}
+
+#[instrument(skip_all)]
+pub async fn bulk_refund_retrieve_core(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    bulk_refund_id: String,
+) -> RouterResponse<refund_api::BulkRefundResponse> {
+    let bulk_refund = find_bulk_refund(&state, &platform, profile_id, &bulk_refund_id).await?;
+
+    let pending_count = state
+        .store
+        .get_bulk_refund_items_count_by_bulk_refund_id_status(
+            &bulk_refund.bulk_refund_id,
+            storage_enums::BulkRefundItemStatus::Pending,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count pending bulk refund items")?;
+    let pending_count = i32::try_from(pending_count)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(services::ApplicationResponse::Json(
+        refund_api::BulkRefundResponse::foreign_from((bulk_refund, pending_count)),
+    ))
+}
+
+/// Generates a CSV report containing the outcome of every item of the job.
+#[instrument(skip_all)]
+pub async fn bulk_refund_report_core(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<id_type::ProfileId>,
+    bulk_refund_id: String,
+) -> RouterResponse<Vec<u8>> {
+    let bulk_refund = find_bulk_refund(&state, &platform, profile_id, &bulk_refund_id).await?;
+
+    let items = state
+        .store
+        .list_bulk_refund_items_by_bulk_refund_id(&bulk_refund.bulk_refund_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list bulk refund items")?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for item in items {
+        csv_writer
+            .serialize(refund_api::BulkRefundReportRecord::foreign_from(item))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write bulk refund report record")?;
+    }
+    let report = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate bulk refund report")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        report,
+        mime::TEXT_CSV,
+    )))
+}
+
+async fn add_bulk_refund_execution_task(
+    state: &SessionState,
+    bulk_refund: &storage::BulkRefund,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::BulkRefundWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        BULK_REFUND_WORKFLOW_TASK,
+        &bulk_refund.bulk_refund_id,
+        &bulk_refund.merchant_id,
+    );
+    let tracking_data = storage::BulkRefundTrackingData {
+        bulk_refund_id: bulk_refund.bulk_refund_id.clone(),
+        merchant_id: bulk_refund.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        BULK_REFUND_WORKFLOW_TASK,
+        runner,
+        [BULK_REFUND_WORKFLOW_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Limits the number of refunds which are in flight at the same time for every connector.
+///
+/// The limits are configured per connector, so that a single job does not exhaust the rate limits
+/// of a connector.
+struct ConnectorConcurrencyLimiter<'a> {
+    state: &'a SessionState,
+    semaphores: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl<'a> ConnectorConcurrencyLimiter<'a> {
+    fn new(state: &'a SessionState) -> Self {
+        Self {
+            state,
+            semaphores: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn get_semaphore(&self, connector: &str) -> Arc<Semaphore> {
+        self.semaphores
+            .lock()
+            .await
+            .entry(connector.to_string())
+            .or_insert_with(|| {
+                Arc::new(Semaphore::new(
+                    self.state
+                        .conf
+                        .refund
+                        .bulk_refund_concurrency
+                        .get_limit_for_connector(connector),
+                ))
+            })
+            .clone()
+    }
+}
+
+/// Executes the next set of pending items of a job.
+///
+/// Returns `true` once every item of the job has been executed and the job has been moved to a
+/// terminal status.
+#[instrument(skip_all, fields(bulk_refund_id = %bulk_refund.bulk_refund_id))]
+pub async fn execute_bulk_refund_items(
+    state: &SessionState,
+    platform: &domain::Platform,
+    bulk_refund: storage::BulkRefund,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+
+    let pending_items = db
+        .list_bulk_refund_items_by_bulk_refund_id_status(
+            &bulk_refund.bulk_refund_id,
+            storage_enums::BulkRefundItemStatus::Pending,
+            consts::BULK_REFUND_ITEMS_PER_RUN,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list pending bulk refund items")?;
+
+    // Every item runs to completion even if some of them fail, since dropping an item after its
+    // refund was sent to the connector would leave it pending. Failed items stay pending, and are
+    // reconciled with their refund in the next run.
+    let limiter = ConnectorConcurrencyLimiter::new(state);
+    let item_indices = pending_items
+        .iter()
+        .map(|item| item.item_index)
+        .collect::<Vec<_>>();
+    let results = future::join_all(pending_items.into_iter().map(|item| {
+        execute_and_update_bulk_refund_item(state, platform, &bulk_refund, &limiter, item)
+    }))
+    .await;
+    for (item_index, result) in item_indices.into_iter().zip(results) {
+        if let Err(error) = result {
+            logger::error!(?error, item_index, "failed to execute bulk refund item");
+        }
+    }
+
+    let item_counts = bulk_job::JobItemCounts::fetch(
+        |status| {
+            db.get_bulk_refund_items_count_by_bulk_refund_id_status(
+                &bulk_refund.bulk_refund_id,
+                status,
+            )
+        },
+        storage_enums::BulkRefundItemStatus::Submitted,
+        storage_enums::BulkRefundItemStatus::Failed,
+        storage_enums::BulkRefundItemStatus::Pending,
+    )
+    .await?;
+
+    let (submitted_count, failed_count) = item_counts.get_progress()?;
+    let bulk_refund = db
+        .update_bulk_refund(
+            bulk_refund,
+            storage::BulkRefundUpdate::ProgressUpdate {
+                submitted_count,
+                failed_count,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update bulk refund progress")?;
+
+    let Some(outcome) = item_counts.get_outcome() else {
+        return Ok(false);
+    };
+
+    db.update_bulk_refund(
+        bulk_refund,
+        storage::BulkRefundUpdate::StatusUpdate {
+            status: storage_enums::BulkRefundStatus::foreign_from(outcome),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update bulk refund status")?;
+
+    Ok(true)
+}
+
+async fn execute_and_update_bulk_refund_item(
+    state: &SessionState,
+    platform: &domain::Platform,
+    bulk_refund: &storage::BulkRefund,
+    limiter: &ConnectorConcurrencyLimiter<'_>,
+    item: storage::BulkRefundItem,
+) -> RouterResult<()> {
+    let existing_refund = match state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            &item.merchant_id,
+            &item.refund_id,
+            platform.get_processor().get_account().storage_scheme,
+        )
+        .await
+    {
+        Ok(refund) => Some(refund),
+        Err(error) if error.current_context().is_db_not_found() => None,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find refund for bulk refund item")?,
+    };
+
+    let item_update = match existing_refund {
+        // The refund was created in a previous run, but the item was not updated
+        Some(refund) => get_bulk_refund_item_update(
+            refund.refund_status,
+            Some(refund.connector),
+            refund.refund_error_code,
+            refund.refund_error_message,
+        ),
+        None => {
+            match execute_bulk_refund_item(state, platform, bulk_refund, limiter, &item).await {
+                Ok(item_update) => item_update,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        item_index = item.item_index,
+                        "bulk refund item failed"
+                    );
+                    let (error_code, error_message) =
+                        bulk_job::get_job_item_error_details(error.current_context());
+                    storage::BulkRefundItemUpdate {
+                        connector: None,
+                        status: storage_enums::BulkRefundItemStatus::Failed,
+                        refund_status: None,
+                        error_code: Some(error_code),
+                        error_message: Some(error_message),
+                        modified_at: common_utils::date_time::now(),
+                    }
+                }
+            }
+        }
+    };
+
+    state
+        .store
+        .update_bulk_refund_item(item, item_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update bulk refund item")?;
+
+    Ok(())
+}
+
+async fn execute_bulk_refund_item(
+    state: &SessionState,
+    platform: &domain::Platform,
+    bulk_refund: &storage::BulkRefund,
+    limiter: &ConnectorConcurrencyLimiter<'_>,
+    item: &storage::BulkRefundItem,
+) -> RouterResult<storage::BulkRefundItemUpdate> {
+    let item_request: refund_api::BulkRefundItemRequest = item
+        .request_data
+        .clone()
+        .expose()
+        .parse_value("BulkRefundItemRequest")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse bulk refund item")?;
+
+    let (payment_intent, payment_attempt, amount) =
+        get_refundable_payment_details(state, platform, &item.payment_id, item_request.amount)
+            .await?;
+
+    if bulk_refund.profile_id.is_some() && bulk_refund.profile_id != payment_intent.profile_id {
+        return Err(report!(errors::ApiErrorResponse::PaymentNotFound))
+            .attach_printable("payment does not belong to the profile of the bulk refund");
+    }
+
+    let connector = payment_attempt.connector.clone().ok_or_else(|| {
+        report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("No connector populated in payment attempt")
+    })?;
+
+    let semaphore = limiter.get_semaphore(&connector).await;
+    let _permit = semaphore
+        .acquire()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire connector permit for bulk refund item")?;
+
+    let refund_response = Box::pin(validate_and_create_refund(
+        state,
+        platform,
+        &payment_attempt,
+        &payment_intent,
+        amount,
+        item_request.to_refund_request(item.refund_id.clone()),
+        None,
+    ))
+    .await?;
+
+    Ok(get_bulk_refund_item_update(
+        refund_response.status.into(),
+        Some(connector),
+        refund_response.error_code,
+        refund_response.error_message,
+    ))
+}
+
+fn get_bulk_refund_item_update(
+    refund_status: storage_enums::RefundStatus,
+    connector: Option<String>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+) -> storage::BulkRefundItemUpdate {
+    let status = match refund_status {
+        storage_enums::RefundStatus::Failure | storage_enums::RefundStatus::TransactionFailure => {
+            storage_enums::BulkRefundItemStatus::Failed
+        }
+        storage_enums::RefundStatus::Success
+        | storage_enums::RefundStatus::Pending
+        | storage_enums::RefundStatus::ManualReview => {
+            storage_enums::BulkRefundItemStatus::Submitted
+        }
+    };
+
+    storage::BulkRefundItemUpdate {
+        connector,
+        status,
+        refund_status: Some(refund_status),
+        error_code,
+        error_message,
+        modified_at: common_utils::date_time::now(),
+    }
+}
+
+impl ForeignFrom<(storage::BulkRefund, i32)> for refund_api::BulkRefundResponse {
+    fn foreign_from((bulk_refund, pending_count): (storage::BulkRefund, i32)) -> Self {
+        Self {
+            bulk_refund_id: bulk_refund.bulk_refund_id,
+            merchant_id: bulk_refund.merchant_id,
+            status: bulk_refund.status,
+            total_count: bulk_refund.total_count,
+            submitted_count: bulk_refund.submitted_count,
+            failed_count: bulk_refund.failed_count,
+            pending_count,
+            created_by: bulk_refund.created_by,
+            created_at: bulk_refund.created_at,
+            modified_at: bulk_refund.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::BulkRefundItem> for refund_api::BulkRefundReportRecord {
+    fn foreign_from(item: storage::BulkRefundItem) -> Self {
+        Self {
+            item_index: item.item_index,
+            payment_id: item.payment_id,
+            refund_id: item.refund_id,
+            amount: item.amount,
+            status: item.status,
+            refund_status: item.refund_status.map(refund_api::RefundStatus::from),
+            connector: item.connector,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        }
+    }
+}
+
+impl ForeignFrom<bulk_job::JobOutcome> for storage_enums::BulkRefundStatus {
+    fn foreign_from(outcome: bulk_job::JobOutcome) -> Self {
+        match outcome {
+            bulk_job::JobOutcome::Completed => Self::Completed,
+            bulk_job::JobOutcome::Failed => Self::Failed,
+            bulk_job::JobOutcome::PartiallyCompleted => Self::PartiallyCompleted,
+        }
+    }
+}
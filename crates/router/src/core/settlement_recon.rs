@@ -0,0 +1,908 @@
+pub mod parsers;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use api_models::settlement_recon as settlement_models;
+use common_enums::{ReconLineStatus, SettlementTransactionType};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const SETTLEMENT_RECON_WORKFLOW_TAG: &str = "SETTLEMENT_RECON";
+const SETTLEMENT_RECON_WORKFLOW_TASK: &str = "SETTLEMENT_RECON_EXECUTE";
+
+const EXCEPTION_STATUSES: [ReconLineStatus; 3] = [
+    ReconLineStatus::AmountMismatch,
+    ReconLineStatus::MissingInHyperswitch,
+    ReconLineStatus::MissingAtPsp,
+];
+
+#[instrument(skip(state))]
+pub async fn create_settlement_report(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: settlement_models::SettlementReportCreateRequest,
+) -> RouterResponse<settlement_models::SettlementReportResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    api_models::enums::Connector::from_str(&req.connector).change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector",
+        },
+    )?;
+
+    state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(merchant_id, &req.file_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::FileNotFound)?;
+
+    let (period_start, period_end) = match (req.period_start, req.period_end) {
+        (Some(period_start), Some(period_end)) if period_start < period_end => {
+            (Some(period_start), Some(period_end))
+        }
+        (None, None) => (None, None),
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "period_start must precede period_end, and both must be provided"
+                    .to_string(),
+            }));
+        }
+    };
+
+    let now = common_utils::date_time::now();
+    let settlement_report = storage::SettlementReportNew {
+        report_id: common_utils::generate_id(consts::ID_LENGTH, "sr"),
+        merchant_id: merchant_id.clone(),
+        organization_id: platform.get_processor().get_account().get_org_id().clone(),
+        profile_id,
+        connector: req.connector,
+        file_id: req.file_id,
+        format: req.format,
+        status: storage_enums::SettlementReportStatus::Pending,
+        period_start,
+        period_end,
+        total_count: 0,
+        matched_count: 0,
+        amount_mismatch_count: 0,
+        missing_in_hyperswitch_count: 0,
+        missing_at_psp_count: 0,
+        error_message: None,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let settlement_report = state
+        .store
+        .insert_settlement_report(settlement_report)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert settlement report")?;
+
+    add_settlement_recon_task(&state, &settlement_report)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule settlement reconciliation")?;
+
+    Ok(services::ApplicationResponse::Json(
+        settlement_models::SettlementReportResponse::foreign_from(settlement_report),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_settlement_report(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: settlement_models::SettlementReportId,
+) -> RouterResponse<settlement_models::SettlementReportResponse> {
+    let settlement_report =
+        find_settlement_report(&state, &platform, profile_id, &req.report_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        settlement_models::SettlementReportResponse::foreign_from(settlement_report),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_settlement_reports(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    constraints: settlement_models::SettlementReportListConstraints,
+) -> RouterResponse<settlement_models::SettlementReportListResponse> {
+    let settlement_reports = state
+        .store
+        .list_settlement_reports_by_merchant_id(
+            platform.get_processor().get_account().get_id(),
+            i64::from(
+                constraints
+                    .limit
+                    .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into()),
+            ),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list settlement reports")?;
+
+    let data =
+        core_utils::filter_objects_based_on_profile_id_list(profile_id_list, settlement_reports)
+            .into_iter()
+            .map(settlement_models::SettlementReportResponse::foreign_from)
+            .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        settlement_models::SettlementReportListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_settlement_report_lines(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    report_id: String,
+    constraints: settlement_models::SettlementReconLineListConstraints,
+) -> RouterResponse<settlement_models::SettlementReconLineListResponse> {
+    let settlement_report =
+        find_settlement_report(&state, &platform, profile_id.clone(), &report_id).await?;
+
+    list_settlement_recon_lines(
+        &state,
+        &platform,
+        storage::SettlementReconLineConstraints {
+            report_id: Some(settlement_report.report_id),
+            connector: constraints.connector,
+            status: constraints.status.map(|status| vec![status]),
+            is_resolved: constraints.is_resolved,
+            profile_id,
+            limit: Some(i64::from(
+                constraints
+                    .limit
+                    .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into()),
+            )),
+            offset: constraints.offset.map(i64::from),
+        },
+    )
+    .await
+}
+
+/// Lists the exception queue of the merchant, which consists of the unresolved lines which were
+/// not matched, unless requested otherwise.
+#[instrument(skip(state))]
+pub async fn list_settlement_exceptions(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    constraints: settlement_models::SettlementReconLineListConstraints,
+) -> RouterResponse<settlement_models::SettlementReconLineListResponse> {
+    let status = match constraints.status {
+        Some(ReconLineStatus::Matched) => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "matched lines are not exceptions".to_string(),
+            }))
+        }
+        Some(status) => vec![status],
+        None => EXCEPTION_STATUSES.to_vec(),
+    };
+
+    list_settlement_recon_lines(
+        &state,
+        &platform,
+        storage::SettlementReconLineConstraints {
+            report_id: constraints.report_id,
+            connector: constraints.connector,
+            status: Some(status),
+            is_resolved: Some(constraints.is_resolved.unwrap_or(false)),
+            profile_id,
+            limit: Some(i64::from(
+                constraints
+                    .limit
+                    .unwrap_or(consts::DEFAULT_LIST_API_LIMIT.into()),
+            )),
+            offset: constraints.offset.map(i64::from),
+        },
+    )
+    .await
+}
+
+#[instrument(skip(state))]
+pub async fn resolve_settlement_exception(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    line_id: String,
+    req: settlement_models::SettlementExceptionResolveRequest,
+) -> RouterResponse<settlement_models::SettlementReconLineResponse> {
+    let recon_line = state
+        .store
+        .find_settlement_recon_line_by_merchant_id_line_id(
+            platform.get_processor().get_account().get_id(),
+            &line_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("settlement recon line {line_id} not found"),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &recon_line)?;
+
+    if recon_line.status == ReconLineStatus::Matched {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "matched lines do not need to be resolved".to_string(),
+        }));
+    }
+    if recon_line.is_resolved {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "the exception has already been resolved".to_string(),
+        }));
+    }
+
+    let now = common_utils::date_time::now();
+    let recon_line = state
+        .store
+        .update_settlement_recon_line(
+            recon_line,
+            storage::SettlementReconLineUpdate {
+                is_resolved: true,
+                resolution_note: req.resolution_note,
+                resolved_at: Some(now),
+                modified_at: now,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to resolve settlement exception")?;
+
+    Ok(services::ApplicationResponse::Json(
+        settlement_models::SettlementReconLineResponse::foreign_from(recon_line),
+    ))
+}
+
+/// Reconciles the lines of a settlement report against the payment attempts and refunds of the
+/// merchant.
+///
+/// Lines are matched by the connector transaction ID, and then compared by amount and currency.
+/// Captured payments and succeeded refunds of the connector within the capture period covered by
+/// the report which were not settled are recorded as missing at the connector. Failures to read or
+/// parse the report are recorded on the report rather than retried.
+#[instrument(skip_all)]
+pub async fn reconcile_settlement_report(
+    state: &SessionState,
+    platform: &domain::Platform,
+    settlement_report: storage::SettlementReport,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let settlement_report = db
+        .update_settlement_report(
+            settlement_report,
+            storage::SettlementReportUpdate::StatusUpdate {
+                status: storage_enums::SettlementReportStatus::Processing,
+                error_message: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update settlement report status")?;
+
+    let settlement_lines = match get_settlement_lines(state, platform, &settlement_report).await {
+        Ok(settlement_lines) => settlement_lines,
+        Err(error) => {
+            logger::error!(
+                report_id = %settlement_report.report_id,
+                ?error,
+                "Failed to read settlement report"
+            );
+            let error_message = match error.current_context() {
+                errors::ApiErrorResponse::InvalidRequestData { message } => message.clone(),
+                errors::ApiErrorResponse::FileNotAvailable
+                | errors::ApiErrorResponse::FileNotFound => {
+                    "settlement report file not found".to_string()
+                }
+                _ => "Failed to read the settlement report".to_string(),
+            };
+            db.update_settlement_report(
+                settlement_report,
+                storage::SettlementReportUpdate::StatusUpdate {
+                    status: storage_enums::SettlementReportStatus::Failed,
+                    error_message: Some(error_message),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update settlement report status")?;
+            return Ok(());
+        }
+    };
+
+    let settlement_period = get_settlement_period(
+        &settlement_report,
+        &settlement_lines,
+        time::Duration::days(state.conf.settlement_recon.settlement_lag_days),
+    );
+    let recon_lines = match_settlement_lines(
+        state,
+        &settlement_report,
+        settlement_lines,
+        settlement_period.as_ref(),
+    )
+    .await?;
+
+    let count_by_status = |status: ReconLineStatus| {
+        let count = recon_lines
+            .iter()
+            .filter(|recon_line| recon_line.status == status)
+            .count();
+        i32::try_from(count).unwrap_or(i32::MAX)
+    };
+    let settlement_report_update = storage::SettlementReportUpdate::ResultUpdate {
+        period_start: settlement_period
+            .as_ref()
+            .map(|settlement_period| settlement_period.start_time),
+        period_end: settlement_period.and_then(|settlement_period| settlement_period.end_time),
+        total_count: i32::try_from(recon_lines.len()).unwrap_or(i32::MAX),
+        matched_count: count_by_status(ReconLineStatus::Matched),
+        amount_mismatch_count: count_by_status(ReconLineStatus::AmountMismatch),
+        missing_in_hyperswitch_count: count_by_status(ReconLineStatus::MissingInHyperswitch),
+        missing_at_psp_count: count_by_status(ReconLineStatus::MissingAtPsp),
+    };
+
+    db.replace_settlement_recon_lines_by_report_id(&settlement_report.report_id, recon_lines)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert settlement recon lines")?;
+
+    db.update_settlement_report(settlement_report, settlement_report_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update settlement report result")?;
+
+    Ok(())
+}
+
+async fn get_settlement_lines(
+    state: &SessionState,
+    platform: &domain::Platform,
+    settlement_report: &storage::SettlementReport,
+) -> RouterResult<Vec<parsers::SettlementLine>> {
+    let file_info = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(settlement_report.file_id.clone()),
+        None,
+        platform,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+
+    let file_data = file_info
+        .file_data
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("File data not found for settlement report file")?;
+
+    let settlement_lines = match file_info.file_type.as_deref() {
+        Some("text/csv") => parsers::parse_settlement_report(settlement_report.format, &file_data),
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "settlement report file must be a CSV file".to_string(),
+        })),
+    }?;
+
+    if settlement_lines.len() > consts::MAX_SETTLEMENT_REPORT_LINES {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "settlement report must not contain more than {} transactions",
+                consts::MAX_SETTLEMENT_REPORT_LINES
+            ),
+        }));
+    }
+
+    Ok(settlement_lines)
+}
+
+/// Returns the capture period covered by the settlement report. The period is the one declared
+/// with the report, or else the period between the earliest and the latest settlement of its lines
+/// shifted back by the settlement lag of the connector.
+fn get_settlement_period(
+    settlement_report: &storage::SettlementReport,
+    settlement_lines: &[parsers::SettlementLine],
+    settlement_lag: time::Duration,
+) -> Option<common_utils::types::TimeRange> {
+    if let Some(start_time) = settlement_report.period_start {
+        return Some(common_utils::types::TimeRange {
+            start_time,
+            end_time: settlement_report.period_end,
+        });
+    }
+
+    let settled_at = || settlement_lines.iter().filter_map(|line| line.settled_at);
+    settled_at()
+        .min()
+        .zip(settled_at().max())
+        .map(
+            |(first_settled_at, last_settled_at)| common_utils::types::TimeRange {
+                start_time: first_settled_at.saturating_sub(settlement_lag),
+                end_time: Some(last_settled_at.saturating_sub(settlement_lag)),
+            },
+        )
+}
+
+async fn match_settlement_lines(
+    state: &SessionState,
+    settlement_report: &storage::SettlementReport,
+    settlement_lines: Vec<parsers::SettlementLine>,
+    settlement_period: Option<&common_utils::types::TimeRange>,
+) -> RouterResult<Vec<storage::SettlementReconLineNew>> {
+    let db = &*state.store;
+    let merchant_id = &settlement_report.merchant_id;
+    let connector = &settlement_report.connector;
+
+    let mut candidates = HashMap::new();
+    for transaction_type in [
+        SettlementTransactionType::Payment,
+        SettlementTransactionType::Refund,
+    ] {
+        let connector_transaction_ids = settlement_lines
+            .iter()
+            .filter(|line| line.transaction_type == transaction_type)
+            .map(|line| line.connector_transaction_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        for connector_transaction_ids in
+            connector_transaction_ids.chunks(consts::SETTLEMENT_RECON_LOOKUP_BATCH_SIZE)
+        {
+            let found = db
+                .find_settlement_candidates_by_connector_transaction_ids(
+                    merchant_id,
+                    connector,
+                    transaction_type,
+                    connector_transaction_ids.to_vec(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find transactions of settlement report")?;
+            candidates.extend(found.into_iter().filter_map(|candidate| {
+                candidate
+                    .connector_transaction_id
+                    .clone()
+                    .map(|connector_transaction_id| {
+                        ((transaction_type, connector_transaction_id), candidate)
+                    })
+            }));
+        }
+    }
+
+    let now = common_utils::date_time::now();
+    let new_recon_line =
+        |transaction_type: SettlementTransactionType,
+         status: ReconLineStatus,
+         settlement_line: Option<&parsers::SettlementLine>,
+         candidate: Option<&storage::SettlementCandidate>| {
+            storage::SettlementReconLineNew {
+                line_id: common_utils::generate_id(consts::ID_LENGTH, "srl"),
+                report_id: settlement_report.report_id.clone(),
+                merchant_id: merchant_id.clone(),
+                organization_id: settlement_report.organization_id.clone(),
+                profile_id: candidate
+                    .and_then(|candidate| candidate.profile_id.clone())
+                    .or_else(|| settlement_report.profile_id.clone()),
+                connector: connector.clone(),
+                line_number: settlement_line.map(|line| line.line_number),
+                transaction_type,
+                connector_transaction_id: settlement_line
+                    .map(|line| line.connector_transaction_id.clone())
+                    .or_else(|| {
+                        candidate.and_then(|candidate| candidate.connector_transaction_id.clone())
+                    }),
+                status,
+                settled_amount: settlement_line.map(|line| line.amount),
+                settled_currency: settlement_line.map(|line| line.currency),
+                fee: settlement_line.and_then(|line| line.fee),
+                hyperswitch_amount: candidate.map(|candidate| candidate.amount),
+                hyperswitch_currency: candidate.and_then(|candidate| candidate.currency),
+                payment_id: candidate.map(|candidate| candidate.payment_id.clone()),
+                attempt_id: candidate.and_then(|candidate| candidate.attempt_id.clone()),
+                refund_id: candidate.and_then(|candidate| candidate.refund_id.clone()),
+                settled_at: settlement_line.and_then(|line| line.settled_at),
+                is_resolved: false,
+                resolution_note: None,
+                resolved_at: None,
+                created_at: now,
+                modified_at: now,
+            }
+        };
+
+    let mut settled_transactions = HashSet::new();
+    let mut recon_lines = settlement_lines
+        .iter()
+        .map(|settlement_line| {
+            let key = (
+                settlement_line.transaction_type,
+                settlement_line.connector_transaction_id.clone(),
+            );
+            let candidate = candidates.get(&key);
+            let status = get_recon_line_status(settlement_line, candidate);
+            settled_transactions.insert(key);
+            new_recon_line(
+                settlement_line.transaction_type,
+                status,
+                Some(settlement_line),
+                candidate,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(settlement_period) = settlement_period {
+        let unsettled_candidates =
+            db.find_settlement_candidates_by_time_range(merchant_id, connector, settlement_period)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find transactions within the settlement period")?
+                .into_iter()
+                .filter(|candidate| {
+                    settlement_report
+                        .profile_id
+                        .as_ref()
+                        .is_none_or(|profile_id| candidate.profile_id.as_ref() == Some(profile_id))
+                })
+                .filter(|candidate| {
+                    candidate.connector_transaction_id.as_ref().is_none_or(
+                        |connector_transaction_id| {
+                            !settled_transactions.contains(&(
+                                candidate.transaction_type,
+                                connector_transaction_id.clone(),
+                            ))
+                        },
+                    )
+                });
+
+        recon_lines.extend(unsettled_candidates.map(|candidate| {
+            new_recon_line(
+                candidate.transaction_type,
+                ReconLineStatus::MissingAtPsp,
+                None,
+                Some(&candidate),
+            )
+        }));
+    }
+
+    Ok(recon_lines)
+}
+
+/// Returns the status of a settled transaction given the transaction of Hyperswitch it corresponds
+/// to, if any.
+fn get_recon_line_status(
+    settlement_line: &parsers::SettlementLine,
+    candidate: Option<&storage::SettlementCandidate>,
+) -> ReconLineStatus {
+    match candidate {
+        Some(candidate)
+            if candidate.amount == settlement_line.amount
+                && candidate.currency == Some(settlement_line.currency) =>
+        {
+            ReconLineStatus::Matched
+        }
+        Some(_) => ReconLineStatus::AmountMismatch,
+        None => ReconLineStatus::MissingInHyperswitch,
+    }
+}
+
+async fn list_settlement_recon_lines(
+    state: &SessionState,
+    platform: &domain::Platform,
+    constraints: storage::SettlementReconLineConstraints,
+) -> RouterResponse<settlement_models::SettlementReconLineListResponse> {
+    let recon_lines = state
+        .store
+        .filter_settlement_recon_lines_by_constraints(
+            platform.get_processor().get_account().get_id(),
+            &constraints,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to filter settlement recon lines")?;
+
+    let data = recon_lines
+        .into_iter()
+        .map(settlement_models::SettlementReconLineResponse::foreign_from)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        settlement_models::SettlementReconLineListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+async fn find_settlement_report(
+    state: &SessionState,
+    platform: &domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    report_id: &str,
+) -> RouterResult<storage::SettlementReport> {
+    let settlement_report = state
+        .store
+        .find_settlement_report_by_merchant_id_report_id(
+            platform.get_processor().get_account().get_id(),
+            report_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("settlement report {report_id} not found"),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &settlement_report)?;
+
+    Ok(settlement_report)
+}
+
+async fn add_settlement_recon_task(
+    state: &SessionState,
+    settlement_report: &storage::SettlementReport,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::SettlementReconWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        SETTLEMENT_RECON_WORKFLOW_TASK,
+        &settlement_report.report_id,
+        &settlement_report.merchant_id,
+    );
+    let tracking_data = storage::SettlementReportTrackingData {
+        report_id: settlement_report.report_id.clone(),
+        merchant_id: settlement_report.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SETTLEMENT_RECON_WORKFLOW_TASK,
+        runner,
+        [SETTLEMENT_RECON_WORKFLOW_TAG],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+impl ForeignFrom<storage::SettlementReport> for settlement_models::SettlementReportResponse {
+    fn foreign_from(settlement_report: storage::SettlementReport) -> Self {
+        Self {
+            report_id: settlement_report.report_id,
+            merchant_id: settlement_report.merchant_id,
+            profile_id: settlement_report.profile_id,
+            connector: settlement_report.connector,
+            file_id: settlement_report.file_id,
+            format: settlement_report.format,
+            status: settlement_report.status,
+            period_start: settlement_report.period_start,
+            period_end: settlement_report.period_end,
+            total_count: settlement_report.total_count,
+            matched_count: settlement_report.matched_count,
+            amount_mismatch_count: settlement_report.amount_mismatch_count,
+            missing_in_hyperswitch_count: settlement_report.missing_in_hyperswitch_count,
+            missing_at_psp_count: settlement_report.missing_at_psp_count,
+            error_message: settlement_report.error_message,
+            created_at: settlement_report.created_at,
+            modified_at: settlement_report.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::SettlementReconLine> for settlement_models::SettlementReconLineResponse {
+    fn foreign_from(recon_line: storage::SettlementReconLine) -> Self {
+        Self {
+            line_id: recon_line.line_id,
+            report_id: recon_line.report_id,
+            profile_id: recon_line.profile_id,
+            connector: recon_line.connector,
+            line_number: recon_line.line_number,
+            transaction_type: recon_line.transaction_type,
+            connector_transaction_id: recon_line.connector_transaction_id,
+            status: recon_line.status,
+            settled_amount: recon_line.settled_amount,
+            settled_currency: recon_line.settled_currency,
+            fee: recon_line.fee,
+            hyperswitch_amount: recon_line.hyperswitch_amount,
+            hyperswitch_currency: recon_line.hyperswitch_currency,
+            payment_id: recon_line.payment_id,
+            attempt_id: recon_line.attempt_id,
+            refund_id: recon_line.refund_id,
+            settled_at: recon_line.settled_at,
+            is_resolved: recon_line.is_resolved,
+            resolution_note: recon_line.resolution_note,
+            resolved_at: recon_line.resolved_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::{id_type, types::MinorUnit};
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn settlement_line(
+        settled_at: Option<time::PrimitiveDateTime>,
+        amount: i64,
+        currency: storage_enums::Currency,
+    ) -> parsers::SettlementLine {
+        parsers::SettlementLine {
+            line_number: 1,
+            transaction_type: SettlementTransactionType::Payment,
+            connector_transaction_id: "pi_1".to_string(),
+            amount: MinorUnit::new(amount),
+            currency,
+            fee: None,
+            settled_at,
+        }
+    }
+
+    fn settlement_candidate(
+        amount: i64,
+        currency: Option<storage_enums::Currency>,
+    ) -> storage::SettlementCandidate {
+        storage::SettlementCandidate {
+            transaction_type: SettlementTransactionType::Payment,
+            connector_transaction_id: Some("pi_1".to_string()),
+            amount: MinorUnit::new(amount),
+            currency,
+            payment_id: id_type::PaymentId::try_from(Cow::from("pay_1")).unwrap(),
+            attempt_id: Some("pay_1_1".to_string()),
+            refund_id: None,
+            profile_id: None,
+            modified_at: datetime!(2026-10-01 00:00:00),
+        }
+    }
+
+    fn settlement_report(
+        period_start: Option<time::PrimitiveDateTime>,
+        period_end: Option<time::PrimitiveDateTime>,
+    ) -> storage::SettlementReport {
+        let now = datetime!(2026-10-10 00:00:00);
+        storage::SettlementReport {
+            report_id: "sr_1".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            organization_id: id_type::OrganizationId::default(),
+            profile_id: None,
+            connector: "stripe".to_string(),
+            file_id: "file_1".to_string(),
+            format: storage_enums::SettlementReportFormat::Stripe,
+            status: storage_enums::SettlementReportStatus::Processing,
+            period_start,
+            period_end,
+            total_count: 0,
+            matched_count: 0,
+            amount_mismatch_count: 0,
+            missing_in_hyperswitch_count: 0,
+            missing_at_psp_count: 0,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_recon_line_status() {
+        let line = settlement_line(None, 1050, storage_enums::Currency::USD);
+
+        assert_eq!(
+            get_recon_line_status(
+                &line,
+                Some(&settlement_candidate(
+                    1050,
+                    Some(storage_enums::Currency::USD)
+                ))
+            ),
+            ReconLineStatus::Matched
+        );
+        assert_eq!(
+            get_recon_line_status(
+                &line,
+                Some(&settlement_candidate(
+                    1000,
+                    Some(storage_enums::Currency::USD)
+                ))
+            ),
+            ReconLineStatus::AmountMismatch
+        );
+        assert_eq!(
+            get_recon_line_status(
+                &line,
+                Some(&settlement_candidate(
+                    1050,
+                    Some(storage_enums::Currency::EUR)
+                ))
+            ),
+            ReconLineStatus::AmountMismatch
+        );
+        assert_eq!(
+            get_recon_line_status(&line, Some(&settlement_candidate(1050, None))),
+            ReconLineStatus::AmountMismatch
+        );
+        assert_eq!(
+            get_recon_line_status(&line, None),
+            ReconLineStatus::MissingInHyperswitch
+        );
+    }
+
+    #[test]
+    fn test_settlement_period_declared_with_report() {
+        let report = settlement_report(
+            Some(datetime!(2026-09-01 00:00:00)),
+            Some(datetime!(2026-09-30 00:00:00)),
+        );
+        let lines = [settlement_line(
+            Some(datetime!(2026-10-05 00:00:00)),
+            1050,
+            storage_enums::Currency::USD,
+        )];
+
+        let period = get_settlement_period(&report, &lines, time::Duration::days(2)).unwrap();
+
+        assert_eq!(period.start_time, datetime!(2026-09-01 00:00:00));
+        assert_eq!(period.end_time, Some(datetime!(2026-09-30 00:00:00)));
+    }
+
+    #[test]
+    fn test_settlement_period_derived_from_lines() {
+        let report = settlement_report(None, None);
+        let lines = [
+            settlement_line(
+                Some(datetime!(2026-10-05 12:00:00)),
+                1050,
+                storage_enums::Currency::USD,
+            ),
+            settlement_line(None, 1050, storage_enums::Currency::USD),
+            settlement_line(
+                Some(datetime!(2026-10-03 08:00:00)),
+                1050,
+                storage_enums::Currency::USD,
+            ),
+        ];
+
+        let period = get_settlement_period(&report, &lines, time::Duration::days(2)).unwrap();
+
+        // The period is shifted back by the settlement lag of the connector
+        assert_eq!(period.start_time, datetime!(2026-10-01 08:00:00));
+        assert_eq!(period.end_time, Some(datetime!(2026-10-03 12:00:00)));
+    }
+
+    #[test]
+    fn test_settlement_period_without_settlement_times() {
+        let report = settlement_report(None, None);
+        let lines = [settlement_line(None, 1050, storage_enums::Currency::USD)];
+
+        assert!(get_settlement_period(&report, &lines, time::Duration::days(2)).is_none());
+    }
+}
@@ -0,0 +1,408 @@
+//! Parsers of the settlement report files of the connectors.
+//!
+//! Every parser yields the payments and refunds settled by the connector, skipping the lines which
+//! do not correspond to a transaction of Hyperswitch, such as payouts or account level fees.
+
+use std::str::FromStr;
+
+use common_enums::{Currency, SettlementReportFormat, SettlementTransactionType};
+use common_utils::types::{
+    AmountConvertor, MinorUnit, StringMajorUnit, StringMajorUnitForConnector,
+};
+use error_stack::{report, ResultExt};
+use time::PrimitiveDateTime;
+
+use crate::core::errors::{self, RouterResult};
+
+/// A payment or a refund settled by the connector.
+#[derive(Clone, Debug)]
+pub struct SettlementLine {
+    pub line_number: i32,
+    pub transaction_type: SettlementTransactionType,
+    pub connector_transaction_id: String,
+    pub amount: MinorUnit,
+    pub currency: Currency,
+    pub fee: Option<MinorUnit>,
+    pub settled_at: Option<PrimitiveDateTime>,
+}
+
+/// A single row of a settlement report in the generic format, with amounts in the lowest
+/// denomination of the currency.
+#[derive(Debug, serde::Deserialize)]
+struct GenericSettlementRecord {
+    connector_transaction_id: String,
+    transaction_type: SettlementTransactionType,
+    amount: MinorUnit,
+    currency: Currency,
+    fee: Option<MinorUnit>,
+    settled_at: Option<String>,
+}
+
+/// A single row of the itemized payout reconciliation report of Stripe.
+#[derive(Debug, serde::Deserialize)]
+struct StripeSettlementRecord {
+    reporting_category: String,
+    currency: String,
+    gross: StringMajorUnit,
+    fee: Option<StringMajorUnit>,
+    created_utc: Option<String>,
+    payment_intent_id: Option<String>,
+    refund_id: Option<String>,
+    source_id: Option<String>,
+}
+
+/// A single row of the settlement details report of Adyen.
+#[derive(Debug, serde::Deserialize)]
+struct AdyenSettlementRecord {
+    #[serde(rename = "Psp Reference")]
+    psp_reference: String,
+    #[serde(rename = "Modification Reference")]
+    modification_reference: Option<String>,
+    #[serde(rename = "Type")]
+    record_type: String,
+    #[serde(rename = "Creation Date")]
+    creation_date: Option<String>,
+    #[serde(rename = "Gross Currency")]
+    gross_currency: String,
+    #[serde(rename = "Gross Debit (GC)")]
+    gross_debit: Option<StringMajorUnit>,
+    #[serde(rename = "Gross Credit (GC)")]
+    gross_credit: Option<StringMajorUnit>,
+    #[serde(rename = "Net Currency")]
+    net_currency: Option<String>,
+    #[serde(rename = "Commission (NC)")]
+    commission: Option<StringMajorUnit>,
+    #[serde(rename = "Markup (NC)")]
+    markup: Option<StringMajorUnit>,
+    #[serde(rename = "Scheme Fees (NC)")]
+    scheme_fees: Option<StringMajorUnit>,
+    #[serde(rename = "Interchange (NC)")]
+    interchange: Option<StringMajorUnit>,
+}
+
+pub fn parse_settlement_report(
+    format: SettlementReportFormat,
+    data: &[u8],
+) -> RouterResult<Vec<SettlementLine>> {
+    match format {
+        SettlementReportFormat::Generic => {
+            parse_records::<GenericSettlementRecord>(data, |line_number, record| {
+                Ok(Some(SettlementLine {
+                    line_number,
+                    transaction_type: record.transaction_type,
+                    connector_transaction_id: record.connector_transaction_id,
+                    amount: record.amount,
+                    currency: record.currency,
+                    fee: record.fee,
+                    settled_at: record
+                        .settled_at
+                        .as_deref()
+                        .map(parse_iso8601_timestamp)
+                        .transpose()?,
+                }))
+            })
+        }
+        SettlementReportFormat::Stripe => {
+            parse_records::<StripeSettlementRecord>(data, parse_stripe_record)
+        }
+        SettlementReportFormat::Adyen => {
+            parse_records::<AdyenSettlementRecord>(data, parse_adyen_record)
+        }
+    }
+}
+
+fn parse_records<T: serde::de::DeserializeOwned>(
+    data: &[u8],
+    parse_record: impl Fn(i32, T) -> Result<Option<SettlementLine>, String>,
+) -> RouterResult<Vec<SettlementLine>> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(data);
+
+    let mut lines = Vec::new();
+    for (index, record) in csv_reader.deserialize::<T>().enumerate() {
+        let line_number = i32::try_from(index + 1).change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "settlement report contains too many lines".to_string(),
+            },
+        )?;
+        let line = record
+            .map_err(|error| error.to_string())
+            .and_then(|record| parse_record(line_number, record))
+            .map_err(|error| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Failed to parse line {line_number} of the settlement report: {error}"
+                    ),
+                })
+            })?;
+        lines.extend(line);
+    }
+    Ok(lines)
+}
+
+fn parse_stripe_record(
+    line_number: i32,
+    record: StripeSettlementRecord,
+) -> Result<Option<SettlementLine>, String> {
+    let (transaction_type, connector_transaction_id) = match record.reporting_category.as_str() {
+        "charge" => (
+            SettlementTransactionType::Payment,
+            record.payment_intent_id.or(record.source_id),
+        ),
+        "refund" => (
+            SettlementTransactionType::Refund,
+            record.refund_id.or(record.source_id),
+        ),
+        _ => return Ok(None),
+    };
+    let connector_transaction_id =
+        connector_transaction_id.ok_or_else(|| "missing transaction reference".to_string())?;
+    let currency = parse_currency(&record.currency)?;
+
+    Ok(Some(SettlementLine {
+        line_number,
+        transaction_type,
+        connector_transaction_id,
+        amount: convert_to_minor_unit(record.gross, currency)?,
+        currency,
+        fee: record
+            .fee
+            .map(|fee| convert_to_minor_unit(fee, currency))
+            .transpose()?,
+        settled_at: record
+            .created_utc
+            .as_deref()
+            .map(parse_sql_timestamp)
+            .transpose()?,
+    }))
+}
+
+fn parse_adyen_record(
+    line_number: i32,
+    record: AdyenSettlementRecord,
+) -> Result<Option<SettlementLine>, String> {
+    let (transaction_type, connector_transaction_id, gross) = match record.record_type.as_str() {
+        "Settled" => (
+            SettlementTransactionType::Payment,
+            record.psp_reference,
+            record.gross_credit,
+        ),
+        "Refunded" => (
+            SettlementTransactionType::Refund,
+            // Refunds are identified by the psp reference of the refund modification
+            record
+                .modification_reference
+                .unwrap_or(record.psp_reference),
+            record.gross_debit,
+        ),
+        _ => return Ok(None),
+    };
+    let currency = parse_currency(&record.gross_currency)?;
+    let gross = gross.ok_or_else(|| "missing gross amount".to_string())?;
+
+    // Fees are charged in the net currency, so they are only comparable with the settled amount
+    // when the transaction was not converted
+    let fee = match record
+        .net_currency
+        .as_deref()
+        .map(parse_currency)
+        .transpose()?
+    {
+        Some(net_currency) if net_currency == currency => {
+            let fee = [
+                record.commission,
+                record.markup,
+                record.scheme_fees,
+                record.interchange,
+            ]
+            .into_iter()
+            .flatten()
+            .map(|fee| convert_to_minor_unit(fee, currency))
+            .sum::<Result<MinorUnit, _>>()?;
+            Some(fee)
+        }
+        _ => None,
+    };
+
+    Ok(Some(SettlementLine {
+        line_number,
+        transaction_type,
+        connector_transaction_id,
+        amount: convert_to_minor_unit(gross, currency)?,
+        currency,
+        fee,
+        settled_at: record
+            .creation_date
+            .as_deref()
+            .map(parse_sql_timestamp)
+            .transpose()?,
+    }))
+}
+
+fn parse_currency(currency: &str) -> Result<Currency, String> {
+    Currency::from_str(&currency.to_uppercase()).map_err(|_| format!("invalid currency {currency}"))
+}
+
+/// Converts an amount in major units to the lowest denomination of the currency. Debits are
+/// reported as negative amounts by some connectors, so the absolute amount is returned.
+fn convert_to_minor_unit(amount: StringMajorUnit, currency: Currency) -> Result<MinorUnit, String> {
+    StringMajorUnitForConnector
+        .convert_back(amount, currency)
+        .map(|amount| MinorUnit::new(amount.get_amount_as_i64().abs()))
+        .map_err(|error| format!("invalid amount: {error}"))
+}
+
+fn parse_iso8601_timestamp(timestamp: &str) -> Result<PrimitiveDateTime, String> {
+    time::OffsetDateTime::parse(
+        timestamp,
+        &time::format_description::well_known::Iso8601::DEFAULT,
+    )
+    .map(|timestamp| {
+        let timestamp = timestamp.to_offset(time::UtcOffset::UTC);
+        PrimitiveDateTime::new(timestamp.date(), timestamp.time())
+    })
+    .or_else(|_| {
+        PrimitiveDateTime::parse(
+            timestamp,
+            &time::format_description::well_known::Iso8601::DEFAULT,
+        )
+    })
+    .map_err(|error| format!("invalid timestamp {timestamp}: {error}"))
+}
+
+fn parse_sql_timestamp(timestamp: &str) -> Result<PrimitiveDateTime, String> {
+    let format = time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    PrimitiveDateTime::parse(timestamp, format).or_else(|_| parse_iso8601_timestamp(timestamp))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_generic_settlement_report() {
+        let data = b"connector_transaction_id,transaction_type,amount,currency,fee,settled_at
+pi_1,payment,1050,USD,30,2026-10-01T12:00:00Z
+re_1,refund,500,EUR,,2026-10-02T08:30:00+02:00
+";
+
+        let lines = parse_settlement_report(SettlementReportFormat::Generic, data).unwrap();
+
+        assert_eq!(lines.len(), 2);
+        let payment = lines.first().unwrap();
+        assert_eq!(payment.line_number, 1);
+        assert_eq!(payment.transaction_type, SettlementTransactionType::Payment);
+        assert_eq!(payment.connector_transaction_id, "pi_1");
+        assert_eq!(payment.amount, MinorUnit::new(1050));
+        assert_eq!(payment.currency, Currency::USD);
+        assert_eq!(payment.fee, Some(MinorUnit::new(30)));
+        assert_eq!(payment.settled_at, Some(datetime!(2026-10-01 12:00:00)));
+
+        let refund = lines.get(1).unwrap();
+        assert_eq!(refund.line_number, 2);
+        assert_eq!(refund.transaction_type, SettlementTransactionType::Refund);
+        assert_eq!(refund.fee, None);
+        // Timestamps with an offset are converted to UTC
+        assert_eq!(refund.settled_at, Some(datetime!(2026-10-02 06:30:00)));
+    }
+
+    #[test]
+    fn test_parse_stripe_settlement_report() {
+        let data = b"reporting_category,currency,gross,fee,created_utc,payment_intent_id,refund_id,source_id
+charge,usd,10.50,0.60,2026-10-01 12:00:00,pi_1,,ch_1
+charge,usd,5.00,,,,,ch_2
+refund,usd,-2.50,,2026-10-02 08:00:00,,re_1,
+payout,usd,-100.00,,,,,po_1
+";
+
+        let lines = parse_settlement_report(SettlementReportFormat::Stripe, data).unwrap();
+
+        // Payouts are not transactions of Hyperswitch
+        assert_eq!(lines.len(), 3);
+        let payment = lines.first().unwrap();
+        assert_eq!(payment.transaction_type, SettlementTransactionType::Payment);
+        assert_eq!(payment.connector_transaction_id, "pi_1");
+        assert_eq!(payment.amount, MinorUnit::new(1050));
+        assert_eq!(payment.currency, Currency::USD);
+        assert_eq!(payment.fee, Some(MinorUnit::new(60)));
+        assert_eq!(payment.settled_at, Some(datetime!(2026-10-01 12:00:00)));
+
+        // Charges without a payment intent are identified by their source
+        assert_eq!(lines.get(1).unwrap().connector_transaction_id, "ch_2");
+
+        let refund = lines.get(2).unwrap();
+        assert_eq!(refund.line_number, 3);
+        assert_eq!(refund.transaction_type, SettlementTransactionType::Refund);
+        assert_eq!(refund.connector_transaction_id, "re_1");
+        // Debits are reported as absolute amounts
+        assert_eq!(refund.amount, MinorUnit::new(250));
+    }
+
+    #[test]
+    fn test_parse_adyen_settlement_report() {
+        let data = b"Psp Reference,Modification Reference,Type,Creation Date,Gross Currency,Gross Debit (GC),Gross Credit (GC),Net Currency,Commission (NC),Markup (NC),Scheme Fees (NC),Interchange (NC)
+PSP1,,Settled,2026-10-01 12:00:00,EUR,,20.00,EUR,0.10,0.05,0.02,0.03
+PSP2,MOD2,Refunded,2026-10-02 12:00:00,EUR,5.00,,EUR,,,,
+PSP3,,Settled,,USD,,10.00,EUR,0.10,,,
+PSP4,,Fee,,EUR,1.00,,EUR,,,,
+";
+
+        let lines = parse_settlement_report(SettlementReportFormat::Adyen, data).unwrap();
+
+        assert_eq!(lines.len(), 3);
+        let payment = lines.first().unwrap();
+        assert_eq!(payment.transaction_type, SettlementTransactionType::Payment);
+        assert_eq!(payment.connector_transaction_id, "PSP1");
+        assert_eq!(payment.amount, MinorUnit::new(2000));
+        assert_eq!(payment.fee, Some(MinorUnit::new(20)));
+        assert_eq!(payment.settled_at, Some(datetime!(2026-10-01 12:00:00)));
+
+        let refund = lines.get(1).unwrap();
+        assert_eq!(refund.transaction_type, SettlementTransactionType::Refund);
+        assert_eq!(refund.connector_transaction_id, "MOD2");
+        assert_eq!(refund.amount, MinorUnit::new(500));
+        assert_eq!(refund.fee, Some(MinorUnit::new(0)));
+
+        // Fees in another currency than the settled amount are not reported
+        let converted_payment = lines.get(2).unwrap();
+        assert_eq!(converted_payment.currency, Currency::USD);
+        assert_eq!(converted_payment.fee, None);
+    }
+
+    #[test]
+    fn test_parse_settlement_report_with_invalid_line() {
+        let data = b"connector_transaction_id,transaction_type,amount,currency,fee,settled_at
+pi_1,payment,1050,USD,,
+pi_2,payment,1050,XYZ,,
+";
+
+        let error = parse_settlement_report(SettlementReportFormat::Generic, data).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message.starts_with("Failed to parse line 2 ")
+        ));
+    }
+
+    #[test]
+    fn test_parse_stripe_record_without_transaction_reference() {
+        let data = b"reporting_category,currency,gross,fee,created_utc,payment_intent_id,refund_id,source_id
+refund,usd,-2.50,,,,,
+";
+
+        let error = parse_settlement_report(SettlementReportFormat::Stripe, data).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message.ends_with("missing transaction reference")
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use api_models::tenant_registry as tenant_models;
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::{self, tenant_registry::publish_tenant_registry_update},
+    types::{
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+fn validate_tenant_create_request(
+    state: &SessionState,
+    req: &tenant_models::TenantCreateRequest,
+) -> RouterResult<()> {
+    let multitenancy = &state.conf.multitenancy;
+    if !multitenancy.enabled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Multitenancy is not enabled".to_string(),
+        }));
+    }
+
+    if multitenancy.get_tenant(&req.tenant_id).is_some()
+        || req.tenant_id == multitenancy.global_tenant.tenant_id
+    {
+        return Err(report!(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!(
+                "Tenant {} is already configured",
+                req.tenant_id.get_string_repr()
+            ),
+        }));
+    }
+
+    for (field_name, value) in [
+        ("base_url", req.base_url.as_str()),
+        ("schema", req.schema.as_str()),
+        ("redis_key_prefix", req.redis_key_prefix.as_str()),
+        ("clickhouse_database", req.clickhouse_database.as_str()),
+    ] {
+        if value.trim().is_empty() {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("{field_name} must not be empty"),
+            }));
+        }
+    }
+
+    let is_redis_key_prefix_configured = multitenancy
+        .get_tenants()
+        .values()
+        .any(|tenant| tenant.redis_key_prefix == req.redis_key_prefix)
+        || multitenancy.global_tenant.redis_key_prefix == req.redis_key_prefix;
+    if is_redis_key_prefix_configured {
+        return Err(report!(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!(
+                "Redis key prefix {} is already used by another tenant",
+                req.redis_key_prefix
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Evicts the registry entry of the tenant from the caches of the running instances. A failure is
+/// only logged, as the entry expires from the caches regardless.
+async fn publish_update(state: &SessionState, tenant_id: &id_type::TenantId) {
+    if let Err(error) = publish_tenant_registry_update(state.global_store.as_ref(), tenant_id).await
+    {
+        logger::error!(?tenant_id, tenant_registry_publish_error=?error);
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn create_tenant(
+    state: SessionState,
+    req: tenant_models::TenantCreateRequest,
+) -> RouterResponse<tenant_models::TenantResponse> {
+    validate_tenant_create_request(&state, &req)?;
+
+    let now = common_utils::date_time::now();
+    let tenant = storage::TenantRegistryNew {
+        accounts_schema_name: req.accounts_schema.unwrap_or_else(|| req.schema.clone()),
+        tenant_id: req.tenant_id,
+        base_url: req.base_url,
+        schema_name: req.schema,
+        redis_key_prefix: req.redis_key_prefix,
+        clickhouse_database: req.clickhouse_database,
+        control_center_url: req.control_center_url,
+        status: enums::TenantStatus::Active,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let tenant = state
+        .global_store
+        .insert_tenant_registry(tenant)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Tenant with the given tenant_id or redis_key_prefix already exists"
+                .to_string(),
+        })?;
+
+    publish_update(&state, &tenant.tenant_id).await;
+
+    Ok(services::ApplicationResponse::Json(
+        tenant_models::TenantResponse::foreign_from(tenant),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_tenants(
+    state: SessionState,
+) -> RouterResponse<tenant_models::TenantListResponse> {
+    let tenants = state
+        .global_store
+        .list_tenant_registries()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the tenants of the registry")?;
+
+    Ok(services::ApplicationResponse::Json(
+        tenant_models::TenantListResponse {
+            tenants: tenants
+                .into_iter()
+                .map(tenant_models::TenantResponse::foreign_from)
+                .collect(),
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_tenant(
+    state: SessionState,
+    req: tenant_models::TenantIdPath,
+) -> RouterResponse<tenant_models::TenantResponse> {
+    let tenant = state
+        .global_store
+        .find_tenant_registry_by_tenant_id(&req.tenant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Tenant not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        tenant_models::TenantResponse::foreign_from(tenant),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn update_tenant_status(
+    state: SessionState,
+    req: tenant_models::TenantIdPath,
+    status: enums::TenantStatus,
+) -> RouterResponse<tenant_models::TenantResponse> {
+    let tenant = state
+        .global_store
+        .update_tenant_registry_by_tenant_id(
+            &req.tenant_id,
+            storage::TenantRegistryUpdate::StatusUpdate { status },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Tenant not found".to_string(),
+        })?;
+
+    publish_update(&state, &tenant.tenant_id).await;
+
+    Ok(services::ApplicationResponse::Json(
+        tenant_models::TenantResponse::foreign_from(tenant),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_tenant(
+    state: SessionState,
+    req: tenant_models::TenantIdPath,
+) -> RouterResponse<tenant_models::TenantDeleteResponse> {
+    let tenant = state
+        .global_store
+        .delete_tenant_registry_by_tenant_id(&req.tenant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Tenant not found".to_string(),
+        })?;
+
+    publish_update(&state, &tenant.tenant_id).await;
+
+    Ok(services::ApplicationResponse::Json(
+        tenant_models::TenantDeleteResponse {
+            tenant_id: tenant.tenant_id,
+            deleted: true,
+        },
+    ))
+}
+
+impl ForeignFrom<storage::TenantRegistry> for tenant_models::TenantResponse {
+    fn foreign_from(tenant: storage::TenantRegistry) -> Self {
+        Self {
+            tenant_id: tenant.tenant_id,
+            base_url: tenant.base_url,
+            schema: tenant.schema_name,
+            accounts_schema: tenant.accounts_schema_name,
+            redis_key_prefix: tenant.redis_key_prefix,
+            clickhouse_database: tenant.clickhouse_database,
+            control_center_url: tenant.control_center_url,
+            status: tenant.status,
+            created_at: tenant.created_at,
+            modified_at: tenant.modified_at,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod exemptions;
 pub mod utils;
 
 use common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule;
@@ -42,6 +44,38 @@ pub async fn get_three_ds_decision_rule_output(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     request: api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
+) -> errors::RouterResult<common_types::three_ds_decision_rule_engine::ThreeDSDecision> {
+    let decision = execute_three_ds_decision_rule_program(state, merchant_id, &request).await?;
+    // Apply PSD2 validations to the decision
+    let final_decision = utils::apply_psd2_validations_during_execute(decision, &request);
+    Ok(final_decision)
+}
+
+/// Same as `get_three_ds_decision_rule_output`, except that payments which would be challenged
+/// only because of PSD2 are given the optimal exemption by the exemption engine
+#[cfg(feature = "v1")]
+pub async fn get_three_ds_decision_rule_output_with_exemptions(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    request: api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
+    exemption_context: &exemptions::ExemptionContext,
+) -> errors::RouterResult<common_types::three_ds_decision_rule_engine::ThreeDSDecision> {
+    let decision = execute_three_ds_decision_rule_program(state, merchant_id, &request).await?;
+    let final_decision = utils::apply_psd2_validations_during_execute(decision, &request);
+    Ok(exemptions::apply_optimal_exemption(
+        state,
+        exemption_context,
+        decision,
+        final_decision,
+        &request,
+    )
+    .await)
+}
+
+async fn execute_three_ds_decision_rule_program(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    request: &api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
 ) -> errors::RouterResult<common_types::three_ds_decision_rule_engine::ThreeDSDecision> {
     let db = state.store.as_ref();
     // Retrieve the rule from database
@@ -70,10 +104,7 @@ pub async fn get_three_ds_decision_rule_output(
         .execute(backend_input)
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error executing 3DS decision rule")?;
-    // Apply PSD2 validations to the decision
-    let final_decision =
-        utils::apply_psd2_validations_during_execute(result.get_output().get_decision(), &request);
-    Ok(final_decision)
+    Ok(result.get_output().get_decision())
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
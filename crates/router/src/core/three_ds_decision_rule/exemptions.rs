@@ -0,0 +1,900 @@
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::{
+    payments::AdditionalPaymentData,
+    three_ds_decision_rule::{
+        PaymentData, ScaExemptionEngineRecord, ScaExemptionEngineRequest,
+        ThreeDsDecisionRuleExecuteRequest,
+    },
+};
+use common_types::three_ds_decision_rule_engine::ThreeDSDecision;
+use common_utils::{
+    crypto::{self, SignMessage},
+    ext_traits::{Encode, StringExt, ValueExt},
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use super::utils;
+use crate::{
+    core::{
+        blocklist,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::domain,
+    utils::currency,
+};
+
+/// Transactions and fraud older than this are not part of the reference fraud rate
+const REFERENCE_PERIOD_IN_DAYS: i64 = 90;
+/// Daily statistics are grouped in hashes spanning this many days, so that the reference period
+/// is read with a handful of lookups
+const STATS_BUCKET_SPAN_IN_DAYS: i64 = 30;
+const SECONDS_IN_A_DAY: i64 = 86_400;
+const DEFAULT_MIN_TRANSACTIONS_FOR_TRA: u32 = 100;
+/// Upper amount in EUR minor units and maximum reference fraud rate of each TRA band of the PSD2
+/// RTS for remote card payments. Fraud rates are in parts per 100 000, ie. 130 is 0.13%.
+const TRA_EXEMPTION_BANDS: [(i64, i64); 3] = [(10_000, 130), (25_000, 60), (50_000, 10)];
+const FRAUD_RATE_SCALE: i64 = 100_000;
+const LOW_VALUE_EXEMPTION_MAX_AMOUNT: i64 = 3_000;
+/// Low value exemptions of a card are limited to five in a row or 100 EUR in total since its last
+/// strong customer authentication
+const LOW_VALUE_EXEMPTION_MAX_COUNT: usize = 5;
+const LOW_VALUE_EXEMPTION_MAX_CUMULATIVE_AMOUNT: usize = 10_000;
+const LOW_VALUE_COUNTERS_TTL_IN_SECS: i64 = 365 * SECONDS_IN_A_DAY;
+/// Challenged payments remember the card they were made with until they complete, so that the
+/// low value counters of the card are reset once the challenge succeeds
+const PENDING_SCA_TTL_IN_SECS: i64 = SECONDS_IN_A_DAY;
+
+#[instrument(skip(state))]
+pub async fn upsert_sca_exemption_engine(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: ScaExemptionEngineRequest,
+) -> RouterResponse<ScaExemptionEngineRecord> {
+    let db = state.store.as_ref();
+    validate_profile_access(profile_id.as_ref(), &request.profile_id)?;
+    core_utils::validate_and_get_business_profile(
+        db,
+        platform.get_processor(),
+        Some(&request.profile_id),
+    )
+    .await?;
+
+    let key = request.profile_id.get_sca_exemption_engine_key();
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    match find_sca_exemption_engine(&state, &request.profile_id).await? {
+        Some(previous_record) => {
+            let new_record = ScaExemptionEngineRecord {
+                profile_id: request.profile_id,
+                tra_enabled: request.tra_enabled.unwrap_or(previous_record.tra_enabled),
+                low_value_enabled: request
+                    .low_value_enabled
+                    .unwrap_or(previous_record.low_value_enabled),
+                min_transactions_for_tra: request
+                    .min_transactions_for_tra
+                    .unwrap_or(previous_record.min_transactions_for_tra),
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+
+            let updated_config = configs::ConfigUpdate::Update {
+                config: Some(
+                    new_record
+                        .encode_to_string_of_json()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Unable to serialize config to string")?,
+                ),
+            };
+
+            db.update_config_by_key(&key, updated_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the sca exemption engine config")?;
+
+            Ok(services::ApplicationResponse::Json(new_record))
+        }
+        None => {
+            let new_record = ScaExemptionEngineRecord {
+                profile_id: request.profile_id,
+                tra_enabled: request.tra_enabled.unwrap_or(true),
+                low_value_enabled: request.low_value_enabled.unwrap_or(true),
+                min_transactions_for_tra: request
+                    .min_transactions_for_tra
+                    .unwrap_or(DEFAULT_MIN_TRANSACTIONS_FOR_TRA),
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+
+            let new_config = configs::ConfigNew {
+                key,
+                config: new_record
+                    .encode_to_string_of_json()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unable to serialize config to string")?,
+            };
+
+            db.insert_config(new_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the sca exemption engine config")?;
+
+            Ok(services::ApplicationResponse::Json(new_record))
+        }
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_sca_exemption_engine(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: common_utils::id_type::ProfileId,
+) -> RouterResponse<ScaExemptionEngineRecord> {
+    validate_profile_access(profile_id.as_ref(), &request)?;
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        platform.get_processor(),
+        Some(&request),
+    )
+    .await?;
+
+    let record = find_sca_exemption_engine(&state, &request).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "sca exemption engine not configured for profile {}",
+                request.get_string_repr()
+            ),
+        },
+    )?;
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_sca_exemption_engine(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: common_utils::id_type::ProfileId,
+) -> RouterResponse<()> {
+    validate_profile_access(profile_id.as_ref(), &request)?;
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        platform.get_processor(),
+        Some(&request),
+    )
+    .await?;
+
+    state
+        .store
+        .delete_config_by_key(&request.get_sca_exemption_engine_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "sca exemption engine not configured for profile {}",
+                request.get_string_repr()
+            ),
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+pub async fn find_sca_exemption_engine(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<Option<ScaExemptionEngineRecord>> {
+    match state
+        .store
+        .find_config_by_key(&profile_id.get_sca_exemption_engine_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("ScaExemptionEngineRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the sca exemption engine record")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the sca exemption engine config"),
+    }
+}
+
+/// The parties whose fraud rate is tracked, TRA is requested only when the rates of all the
+/// parties of a payment allow it
+#[derive(Debug, Clone)]
+pub enum FraudRateDimension {
+    Acquirer(common_utils::id_type::ProfileAcquirerId),
+    Connector(common_utils::id_type::MerchantConnectorAccountId),
+}
+
+impl FraudRateDimension {
+    fn get_key_segment(&self) -> String {
+        match self {
+            Self::Acquirer(profile_acquirer_id) => {
+                format!("acquirer_{}", profile_acquirer_id.get_string_repr())
+            }
+            Self::Connector(merchant_connector_id) => {
+                format!("connector_{}", merchant_connector_id.get_string_repr())
+            }
+        }
+    }
+}
+
+/// The acquirer is the one configured in the profile for the card network, the connector is known
+/// only once the payment has been routed
+pub fn get_fraud_rate_dimensions(
+    business_profile: &domain::Profile,
+    card_network: Option<&common_enums::CardNetwork>,
+    merchant_connector_id: Option<&common_utils::id_type::MerchantConnectorAccountId>,
+) -> Vec<FraudRateDimension> {
+    let acquirer = card_network.and_then(|card_network| {
+        business_profile
+            .acquirer_config_map
+            .as_ref()
+            .and_then(|acquirer_config_map| {
+                acquirer_config_map
+                    .0
+                    .iter()
+                    .find(|(_, acquirer_config)| &acquirer_config.network == card_network)
+            })
+            .map(|(profile_acquirer_id, _)| {
+                FraudRateDimension::Acquirer(profile_acquirer_id.clone())
+            })
+    });
+    let connector = merchant_connector_id
+        .cloned()
+        .map(FraudRateDimension::Connector);
+
+    acquirer.into_iter().chain(connector).collect()
+}
+
+/// What the exemption engine needs to know about a payment besides its amount
+#[derive(Debug, Clone)]
+pub struct ExemptionContext {
+    pub record: ScaExemptionEngineRecord,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub dimensions: Vec<FraudRateDimension>,
+    /// Keyed hash of the card number, which identifies the low value counters of the card
+    pub card_key: Option<String>,
+}
+
+/// Builds the context of a payment of a profile which has the exemption engine enabled
+pub async fn get_exemption_context(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_attempt: &PaymentAttempt,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+    card_network: Option<&common_enums::CardNetwork>,
+) -> RouterResult<Option<ExemptionContext>> {
+    let Some(record) = find_sca_exemption_engine(state, business_profile.get_id()).await? else {
+        return Ok(None);
+    };
+
+    let card_key = match payment_method_data {
+        Some(domain::PaymentMethodData::Card(card)) => {
+            let secret = blocklist::utils::get_merchant_fingerprint_secret(
+                state,
+                &payment_attempt.merchant_id,
+            )
+            .await?;
+            let digest = crypto::HmacSha512
+                .sign_message(secret.as_bytes(), card.card_number.get_card_no().as_bytes())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to hash the card number for the low value counters")?;
+            Some(hex::encode(digest))
+        }
+        _ => None,
+    };
+
+    Ok(Some(ExemptionContext {
+        record,
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        dimensions: get_fraud_rate_dimensions(
+            business_profile,
+            card_network,
+            payment_attempt.merchant_connector_id.as_ref(),
+        ),
+        card_key,
+    }))
+}
+
+/// Replaces a challenge which the 3DS decision rule did not ask for with the optimal exemption.
+/// Only payments which the rule decided to let through without 3DS, but which PSD2 requires to be
+/// authenticated, are considered.
+pub async fn apply_optimal_exemption(
+    state: &SessionState,
+    context: &ExemptionContext,
+    rule_decision: ThreeDSDecision,
+    final_decision: ThreeDSDecision,
+    request: &ThreeDsDecisionRuleExecuteRequest,
+) -> ThreeDSDecision {
+    let decision =
+        if rule_decision == ThreeDSDecision::NoThreeDs && utils::is_psd2_applicable(request) {
+            get_optimal_exemption(state, context, &request.payment)
+                .await
+                .unwrap_or(final_decision)
+        } else {
+            final_decision
+        };
+
+    if matches!(
+        decision,
+        ThreeDSDecision::ChallengeRequested | ThreeDSDecision::ChallengePreferred
+    ) {
+        mark_pending_sca(state, context).await;
+    }
+    decision
+}
+
+/// TRA is preferred over low value as it leaves the counters of the card untouched
+async fn get_optimal_exemption(
+    state: &SessionState,
+    context: &ExemptionContext,
+    payment: &PaymentData,
+) -> Option<ThreeDSDecision> {
+    let amount_in_eur =
+        get_amount_in_eur(state, payment.amount.get_amount_as_i64(), payment.currency).await?;
+
+    if context.record.tra_enabled && is_tra_eligible(state, context, amount_in_eur).await {
+        return Some(ThreeDSDecision::ThreeDsExemptionRequestedTra);
+    }
+    if context.record.low_value_enabled
+        && reserve_low_value_exemption(state, context, amount_in_eur).await
+    {
+        return Some(ThreeDSDecision::ThreeDsExemptionRequestedLowValue);
+    }
+    None
+}
+
+async fn is_tra_eligible(state: &SessionState, context: &ExemptionContext, amount: i64) -> bool {
+    let Some(max_fraud_rate) = get_tra_max_fraud_rate(amount) else {
+        return false;
+    };
+    if context.dimensions.is_empty() {
+        return false;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for fraud rates");
+            return false;
+        }
+    };
+    let today = get_day(common_utils::date_time::now_unix_timestamp());
+
+    for dimension in &context.dimensions {
+        let stats = get_fraud_rate_stats(&redis_conn, &context.merchant_id, dimension, today).await;
+        logger::debug!(
+            dimension = dimension.get_key_segment(),
+            transaction_count = stats.transaction_count,
+            transaction_amount = stats.transaction_amount,
+            fraud_amount = stats.fraud_amount,
+            "reference fraud rate"
+        );
+        if stats.transaction_count < i64::from(context.record.min_transactions_for_tra)
+            || !stats.is_fraud_rate_within(max_fraud_rate)
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// Counts the exemption against the card up front, and takes it back if the card is over its
+/// limits
+async fn reserve_low_value_exemption(
+    state: &SessionState,
+    context: &ExemptionContext,
+    amount: i64,
+) -> bool {
+    let Some(card_key) = context.card_key.as_ref() else {
+        return false;
+    };
+    if amount > LOW_VALUE_EXEMPTION_MAX_AMOUNT {
+        return false;
+    }
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for low value counters"
+            );
+            return false;
+        }
+    };
+    let key = get_low_value_counters_key(&context.merchant_id, card_key);
+
+    let counters = match redis_conn
+        .increment_fields_in_hash(&key.as_str().into(), &[("count", 1), ("amount", amount)])
+        .await
+    {
+        Ok(counters) => counters,
+        Err(error) => {
+            logger::error!(?error, "Failed to increment low value counters");
+            return false;
+        }
+    };
+    let _ = redis_conn
+        .set_expiry(&key.as_str().into(), LOW_VALUE_COUNTERS_TTL_IN_SECS)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to set low value counters expiry"));
+
+    if is_within_low_value_limits(&counters) {
+        true
+    } else {
+        let _ = redis_conn
+            .increment_fields_in_hash(&key.as_str().into(), &[("count", -1), ("amount", -amount)])
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to release low value counters"));
+        false
+    }
+}
+
+/// The TRA band of the amount, None when the amount is above every band
+fn get_tra_max_fraud_rate(amount: i64) -> Option<i64> {
+    TRA_EXEMPTION_BANDS
+        .iter()
+        .find(|(max_amount, _)| amount <= *max_amount)
+        .map(|(_, max_fraud_rate)| *max_fraud_rate)
+}
+
+/// The counters of the card, including the exemption being reserved
+fn is_within_low_value_limits(counters: &[usize]) -> bool {
+    matches!(
+        counters,
+        &[count, cumulative_amount]
+            if count <= LOW_VALUE_EXEMPTION_MAX_COUNT
+                && cumulative_amount <= LOW_VALUE_EXEMPTION_MAX_CUMULATIVE_AMOUNT
+    )
+}
+
+async fn mark_pending_sca(state: &SessionState, context: &ExemptionContext) {
+    let Some(card_key) = context.card_key.as_ref() else {
+        return;
+    };
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for pending sca");
+            return;
+        }
+    };
+    let _ = redis_conn
+        .set_key_with_expiry(
+            &get_pending_sca_key(&context.merchant_id, &context.payment_id)
+                .as_str()
+                .into(),
+            card_key.clone(),
+            PENDING_SCA_TTL_IN_SECS,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to mark the payment as pending sca"));
+}
+
+/// Adds a successful payment to the fraud rate statistics of its acquirer and connector, and
+/// resets the low value counters of the card when the payment was strongly authenticated
+#[instrument(skip_all)]
+pub async fn record_successful_payment(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_attempt: &PaymentAttempt,
+    psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+) {
+    if let Err(error) = record_fraud_rate_event(
+        state,
+        business_profile,
+        payment_attempt,
+        FraudRateEvent::Transaction,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to record the transaction for fraud rates");
+    }
+
+    if payment_attempt.authentication_type == Some(common_enums::AuthenticationType::ThreeDs)
+        && psd2_sca_exemption_type.is_none()
+    {
+        if let Err(error) = reset_low_value_counters(state, payment_attempt).await {
+            logger::error!(?error, "Failed to reset the low value counters");
+        }
+    }
+}
+
+/// Adds a payment found to be fraudulent to the fraud rate statistics of its acquirer and
+/// connector. Every dispute is counted as fraud, as the reason codes of the connectors are not
+/// normalized, which errs on the side of a higher fraud rate.
+#[instrument(skip_all)]
+pub async fn record_fraudulent_payment(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_attempt: &PaymentAttempt,
+) {
+    if let Err(error) = record_fraud_rate_event(
+        state,
+        business_profile,
+        payment_attempt,
+        FraudRateEvent::Fraud,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to record the fraud for fraud rates");
+    }
+}
+
+#[derive(Debug, Clone, Copy, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum FraudRateEvent {
+    Transaction,
+    Fraud,
+}
+
+#[derive(Debug, Default)]
+struct FraudRateStats {
+    transaction_count: i64,
+    transaction_amount: i64,
+    fraud_amount: i64,
+}
+
+impl FraudRateStats {
+    /// The PSD2 reference fraud rate is the value of fraudulent payments over the value of all
+    /// payments
+    fn is_fraud_rate_within(&self, max_fraud_rate: i64) -> bool {
+        self.transaction_amount > 0
+            && i128::from(self.fraud_amount) * i128::from(FRAUD_RATE_SCALE)
+                <= i128::from(max_fraud_rate) * i128::from(self.transaction_amount)
+    }
+
+    /// Adds up the daily fields of a statistics bucket, skipping the days before the reference
+    /// period
+    fn add_daily_fields(&mut self, fields: HashMap<String, String>, first_day: i64) {
+        for (field, value) in fields {
+            let Some((day, metric)) = field.split_once('_') else {
+                continue;
+            };
+            let (Ok(day), Ok(value)) = (day.parse::<i64>(), value.parse::<i64>()) else {
+                continue;
+            };
+            if day < first_day {
+                continue;
+            }
+            match metric {
+                "count" => self.transaction_count += value,
+                "amount" => self.transaction_amount += value,
+                "fraud_amount" => self.fraud_amount += value,
+                _ => {}
+            }
+        }
+    }
+}
+
+async fn record_fraud_rate_event(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_attempt: &PaymentAttempt,
+    event: FraudRateEvent,
+) -> RouterResult<()> {
+    if payment_attempt.payment_method != Some(common_enums::PaymentMethod::Card)
+        || business_profile
+            .get_three_ds_decision_rule_algorithm_id()
+            .is_none()
+        || find_sca_exemption_engine(state, business_profile.get_id())
+            .await?
+            .is_none()
+    {
+        return Ok(());
+    }
+    let currency = payment_attempt
+        .currency
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("currency is not set in payment attempt")?;
+    let Some(amount) = get_amount_in_eur(
+        state,
+        payment_attempt
+            .net_amount
+            .get_total_amount()
+            .get_amount_as_i64(),
+        currency,
+    )
+    .await
+    else {
+        return Ok(());
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // Payments are counted once per event, as the same outcome can be reported more than once
+    let recorded_key = format!(
+        "sca_fraud_stats_recorded_{event}_{}",
+        payment_attempt.attempt_id
+    );
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &recorded_key.as_str().into(),
+            "true",
+            Some(REFERENCE_PERIOD_IN_DAYS * SECONDS_IN_A_DAY),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+    {
+        redis::SetnxReply::KeySet => {}
+        redis::SetnxReply::KeyNotSet => return Ok(()),
+    }
+
+    let card_network = get_card_network(payment_attempt);
+    let day = get_day(common_utils::date_time::now_unix_timestamp());
+    let fields = match event {
+        FraudRateEvent::Transaction => [
+            (format!("{day}_count"), 1),
+            (format!("{day}_amount"), amount),
+        ],
+        FraudRateEvent::Fraud => [
+            (format!("{day}_fraud_count"), 1),
+            (format!("{day}_fraud_amount"), amount),
+        ],
+    };
+
+    for dimension in get_fraud_rate_dimensions(
+        business_profile,
+        card_network.as_ref(),
+        payment_attempt.merchant_connector_id.as_ref(),
+    ) {
+        let key = get_fraud_rate_stats_key(
+            &payment_attempt.merchant_id,
+            &dimension,
+            day / STATS_BUCKET_SPAN_IN_DAYS,
+        );
+        redis_conn
+            .increment_fields_in_hash(&key.as_str().into(), &fields)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to increment fraud rate stats")?;
+        redis_conn
+            .set_expiry(
+                &key.as_str().into(),
+                (REFERENCE_PERIOD_IN_DAYS + STATS_BUCKET_SPAN_IN_DAYS) * SECONDS_IN_A_DAY,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set fraud rate stats expiry")?;
+    }
+    Ok(())
+}
+
+async fn get_fraud_rate_stats(
+    redis_conn: &redis::RedisConnectionPool,
+    merchant_id: &common_utils::id_type::MerchantId,
+    dimension: &FraudRateDimension,
+    today: i64,
+) -> FraudRateStats {
+    let first_day = today - REFERENCE_PERIOD_IN_DAYS + 1;
+    let mut stats = FraudRateStats::default();
+
+    for bucket in (first_day / STATS_BUCKET_SPAN_IN_DAYS)..=(today / STATS_BUCKET_SPAN_IN_DAYS) {
+        let key = get_fraud_rate_stats_key(merchant_id, dimension, bucket);
+        let fields = match redis_conn
+            .get_hash_fields::<HashMap<String, String>>(&key.as_str().into())
+            .await
+        {
+            Ok(fields) => fields,
+            Err(error) => {
+                logger::error!(?error, "Failed to read fraud rate stats");
+                continue;
+            }
+        };
+
+        stats.add_daily_fields(fields, first_day);
+    }
+    stats
+}
+
+async fn reset_low_value_counters(
+    state: &SessionState,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let pending_sca_key =
+        get_pending_sca_key(&payment_attempt.merchant_id, &payment_attempt.payment_id);
+
+    let card_key = redis_conn
+        .get_key::<Option<String>>(&pending_sca_key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    if let Some(card_key) = card_key {
+        redis_conn
+            .delete_multiple_keys(&[
+                get_low_value_counters_key(&payment_attempt.merchant_id, &card_key).into(),
+                pending_sca_key.into(),
+            ])
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete low value counters")?;
+    }
+    Ok(())
+}
+
+/// Amounts are compared to the PSD2 thresholds in EUR, payments in other currencies are converted
+/// with the forex rates and skipped when the rates are unavailable
+async fn get_amount_in_eur(
+    state: &SessionState,
+    amount: i64,
+    currency: common_enums::Currency,
+) -> Option<i64> {
+    if currency == common_enums::Currency::EUR {
+        return Some(amount);
+    }
+    let conversion = currency::convert_currency(
+        state.clone(),
+        amount,
+        common_enums::Currency::EUR.to_string(),
+        currency.to_string(),
+    )
+    .await
+    .inspect_err(|error| logger::error!(?error, "Failed to convert the amount to EUR"))
+    .ok()?;
+
+    Decimal::from_str(&conversion.converted_amount)
+        .ok()
+        .and_then(|amount| (amount * Decimal::ONE_HUNDRED).round().to_i64())
+}
+
+fn get_card_network(payment_attempt: &PaymentAttempt) -> Option<common_enums::CardNetwork> {
+    payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| additional_payment_data.get_additional_card_info())
+        .and_then(|card_info| card_info.card_network)
+}
+
+fn get_day(timestamp: i64) -> i64 {
+    timestamp / SECONDS_IN_A_DAY
+}
+
+fn get_fraud_rate_stats_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    dimension: &FraudRateDimension,
+    bucket: i64,
+) -> String {
+    format!(
+        "sca_fraud_stats_{}_{}_{bucket}",
+        merchant_id.get_string_repr(),
+        dimension.get_key_segment()
+    )
+}
+
+fn get_low_value_counters_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    card_key: &str,
+) -> String {
+    format!("sca_low_value_{}_{card_key}", merchant_id.get_string_repr())
+}
+
+fn get_pending_sca_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> String {
+    format!(
+        "sca_pending_{}_{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+fn validate_profile_access(
+    profile_id_auth_layer: Option<&common_utils::id_type::ProfileId>,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<()> {
+    match profile_id_auth_layer {
+        Some(auth_profile_id) if auth_profile_id != profile_id => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Profile id authentication failed. Please use the correct JWT token"
+                    .to_string(),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tra_bands_are_selected_by_amount() {
+        assert_eq!(get_tra_max_fraud_rate(0), Some(130));
+        assert_eq!(get_tra_max_fraud_rate(10_000), Some(130));
+        assert_eq!(get_tra_max_fraud_rate(10_001), Some(60));
+        assert_eq!(get_tra_max_fraud_rate(25_000), Some(60));
+        assert_eq!(get_tra_max_fraud_rate(50_000), Some(10));
+        assert_eq!(get_tra_max_fraud_rate(50_001), None);
+    }
+
+    #[test]
+    fn test_fraud_rate_is_compared_by_value() {
+        let stats = |transaction_amount, fraud_amount| FraudRateStats {
+            transaction_count: 1_000,
+            transaction_amount,
+            fraud_amount,
+        };
+
+        // 0.13% of 1 000 000 is 1 300
+        assert!(stats(1_000_000, 1_300).is_fraud_rate_within(130));
+        assert!(!stats(1_000_000, 1_301).is_fraud_rate_within(130));
+        assert!(stats(1_000_000, 0).is_fraud_rate_within(10));
+        // Without any payment volume there is no reference fraud rate
+        assert!(!stats(0, 0).is_fraud_rate_within(130));
+    }
+
+    #[test]
+    fn test_daily_fields_outside_reference_period_are_skipped() {
+        let mut stats = FraudRateStats::default();
+        let fields = [
+            ("100_count", "3"),
+            ("100_amount", "3000"),
+            ("100_fraud_count", "1"),
+            ("100_fraud_amount", "500"),
+            ("101_count", "2"),
+            ("101_amount", "2000"),
+            ("99_count", "10"),
+            ("99_amount", "10000"),
+            ("99_fraud_amount", "10000"),
+            ("invalid", "1"),
+            ("101_count_extra", "1"),
+        ]
+        .into_iter()
+        .map(|(field, value)| (field.to_string(), value.to_string()))
+        .collect();
+
+        stats.add_daily_fields(fields, 100);
+
+        assert_eq!(stats.transaction_count, 5);
+        assert_eq!(stats.transaction_amount, 5_000);
+        assert_eq!(stats.fraud_amount, 500);
+    }
+
+    #[test]
+    fn test_low_value_limits() {
+        assert!(is_within_low_value_limits(&[1, 3_000]));
+        assert!(is_within_low_value_limits(&[
+            LOW_VALUE_EXEMPTION_MAX_COUNT,
+            LOW_VALUE_EXEMPTION_MAX_CUMULATIVE_AMOUNT
+        ]));
+        assert!(!is_within_low_value_limits(&[
+            LOW_VALUE_EXEMPTION_MAX_COUNT + 1,
+            3_000
+        ]));
+        assert!(!is_within_low_value_limits(&[
+            2,
+            LOW_VALUE_EXEMPTION_MAX_CUMULATIVE_AMOUNT + 1
+        ]));
+        assert!(!is_within_low_value_limits(&[1]));
+    }
+}
@@ -9,19 +9,7 @@ pub fn apply_psd2_validations_during_execute(
     decision: ThreeDSDecision,
     request: &api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
 ) -> ThreeDSDecision {
-    let issuer_in_psd2 = request
-        .issuer
-        .as_ref()
-        .and_then(|issuer| issuer.country)
-        .map(|country| PSD2_COUNTRIES.contains(&country))
-        .unwrap_or(false);
-    let acquirer_in_psd2 = request
-        .acquirer
-        .as_ref()
-        .and_then(|acquirer| acquirer.country)
-        .map(|country| PSD2_COUNTRIES.contains(&country))
-        .unwrap_or(false);
-    if issuer_in_psd2 && acquirer_in_psd2 {
+    if is_psd2_applicable(request) {
         // If both issuer and acquirer are in PSD2 region
         match decision {
             // If the decision is to enforce no 3DS, override it to enforce 3DS
@@ -38,6 +26,25 @@ pub fn apply_psd2_validations_during_execute(
     }
 }
 
+// PSD2 applies when both the issuer and the acquirer are in the PSD2 region
+pub fn is_psd2_applicable(
+    request: &api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
+) -> bool {
+    let issuer_in_psd2 = request
+        .issuer
+        .as_ref()
+        .and_then(|issuer| issuer.country)
+        .map(|country| PSD2_COUNTRIES.contains(&country))
+        .unwrap_or(false);
+    let acquirer_in_psd2 = request
+        .acquirer
+        .as_ref()
+        .and_then(|acquirer| acquirer.country)
+        .map(|country| PSD2_COUNTRIES.contains(&country))
+        .unwrap_or(false);
+    issuer_in_psd2 && acquirer_in_psd2
+}
+
 impl ForeignFrom<api_threedsecure::PaymentData> for dsl_inputs::PaymentInput {
     fn foreign_from(request_payment_data: api_threedsecure::PaymentData) -> Self {
         Self {
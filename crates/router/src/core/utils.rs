@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod bulk_job;
 pub mod refunds_transformers;
 pub mod refunds_validator;
 
@@ -2402,12 +2404,44 @@ impl GetProfileId for diesel_models::Dispute {
     }
 }
 
+impl GetProfileId for diesel_models::dispute_evidence_template::DisputeEvidenceTemplate {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        self.profile_id.as_ref()
+    }
+}
+
 impl GetProfileId for diesel_models::Refund {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
         self.profile_id.as_ref()
     }
 }
 
+#[cfg(feature = "v1")]
+impl GetProfileId for diesel_models::bulk_refund::BulkRefund {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        self.profile_id.as_ref()
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl GetProfileId for diesel_models::payout_batch::PayoutBatch {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
+impl GetProfileId for diesel_models::settlement_report::SettlementReport {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        self.profile_id.as_ref()
+    }
+}
+
+impl GetProfileId for diesel_models::settlement_report::SettlementReconLine {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        self.profile_id.as_ref()
+    }
+}
+
 #[cfg(feature = "v1")]
 impl GetProfileId for api_models::routing::RoutingConfigRequest {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
@@ -0,0 +1,151 @@
+//! Helpers shared by the jobs which accept a list of items upfront and execute them in the
+//! background, such as payout batches and bulk refunds.
+
+use std::future::Future;
+
+use common_utils::errors::{CustomResult, ErrorSwitch};
+use error_stack::{report, ResultExt};
+
+use crate::core::errors::{self, RouterResult};
+
+/// Validates the number of items submitted for a job.
+pub fn validate_job_item_count(
+    job_name: &str,
+    item_count: usize,
+    max_items: usize,
+) -> RouterResult<()> {
+    if item_count == 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{job_name} must contain at least one item"),
+        }));
+    }
+
+    if item_count > max_items {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{job_name} cannot contain more than {max_items} items"),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Rejects the job with the list of offending items, if there are any.
+pub fn validate_job_items(job_name: &str, invalid_items: Vec<String>) -> RouterResult<()> {
+    if invalid_items.is_empty() {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("invalid {job_name} items - {}", invalid_items.join(", ")),
+        }))
+    }
+}
+
+/// Returns the error code and message recorded against an item which could not be executed.
+pub fn get_job_item_error_details(error: &errors::ApiErrorResponse) -> (String, String) {
+    let mut api_error: api_models::errors::types::ApiErrorResponse = error.switch();
+    let internal_error = api_error.get_internal_error_mut();
+    (
+        format!(
+            "{}_{:02}",
+            internal_error.sub_code, internal_error.error_identifier
+        ),
+        internal_error.error_message.clone(),
+    )
+}
+
+/// The terminal outcome of a job, once every item has been executed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobOutcome {
+    Completed,
+    Failed,
+    PartiallyCompleted,
+}
+
+/// The number of items of a job in each of the item statuses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JobItemCounts {
+    pub submitted: usize,
+    pub failed: usize,
+    pub pending: usize,
+}
+
+impl JobItemCounts {
+    /// Counts the items of a job using `count_items`, which counts the items in a given status.
+    pub async fn fetch<S, F, Fut>(
+        count_items: F,
+        submitted_status: S,
+        failed_status: S,
+        pending_status: S,
+    ) -> RouterResult<Self>
+    where
+        F: Fn(S) -> Fut,
+        Fut: Future<Output = CustomResult<usize, errors::StorageError>>,
+    {
+        Ok(Self {
+            submitted: count_items(submitted_status)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to count submitted job items")?,
+            failed: count_items(failed_status)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to count failed job items")?,
+            pending: count_items(pending_status)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to count pending job items")?,
+        })
+    }
+
+    /// Returns the submitted and failed counts, in the form stored on the job.
+    pub fn get_progress(&self) -> RouterResult<(i32, i32)> {
+        Ok((
+            i32::try_from(self.submitted)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            i32::try_from(self.failed)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        ))
+    }
+
+    /// Returns the outcome of the job, or `None` while some of its items are still pending.
+    pub fn get_outcome(&self) -> Option<JobOutcome> {
+        if self.pending > 0 {
+            return None;
+        }
+
+        Some(match (self.submitted, self.failed) {
+            (_, 0) => JobOutcome::Completed,
+            (0, _) => JobOutcome::Failed,
+            _ => JobOutcome::PartiallyCompleted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_job_outcome() {
+        let counts = |submitted, failed, pending| JobItemCounts {
+            submitted,
+            failed,
+            pending,
+        };
+
+        assert_eq!(counts(3, 1, 1).get_outcome(), None);
+        assert_eq!(counts(3, 0, 0).get_outcome(), Some(JobOutcome::Completed));
+        assert_eq!(counts(0, 2, 0).get_outcome(), Some(JobOutcome::Failed));
+        assert_eq!(
+            counts(3, 1, 0).get_outcome(),
+            Some(JobOutcome::PartiallyCompleted)
+        );
+    }
+
+    #[test]
+    fn test_validate_job_item_count() {
+        assert!(validate_job_item_count("bulk refund", 0, 10).is_err());
+        assert!(validate_job_item_count("bulk refund", 10, 10).is_ok());
+        assert!(validate_job_item_count("bulk refund", 11, 10).is_err());
+    }
+}
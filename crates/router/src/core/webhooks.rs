@@ -19,6 +19,7 @@ pub mod webhook_events;
 pub(crate) use self::{
     incoming::{incoming_webhooks_wrapper, network_token_incoming_webhooks_wrapper},
     outgoing::{
+        add_outgoing_webhook_retry_task_to_process_tracker,
         create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
         trigger_webhook_and_raise_event,
     },
@@ -32,19 +32,23 @@ use hyperswitch_interfaces::webhooks::{
     IncomingWebhookFlowError, IncomingWebhookRequestDetails, WebhookContext, WebhookResourceData,
 };
 use hyperswitch_masking::{ExposeInterface, PeekInterface};
-use router_env::{instrument, tracing, RequestId};
+use router_env::{
+    instrument,
+    tracing::{self, Instrument},
+    RequestId,
+};
 use unified_connector_service_client::payments as payments_grpc;
 
 use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics, payment_methods,
         payment_methods::cards,
         payments::{self, tokenization, PaymentIntentStateMetadataExt},
-        refunds, relay,
+        refunds, relay, three_ds_decision_rule,
         unified_authentication_service::{
             types::UNIFIED_AUTHENTICATION_SERVICE, utils as uas_utils,
         },
@@ -2338,11 +2342,24 @@ pub async fn get_or_update_dispute_object(
                     .and_then(|initiator| initiator.to_created_by())
                     .map(|created_by| created_by.to_string()),
             };
-            state
+            let dispute = state
                 .store
                 .insert_dispute(new_dispute.clone())
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+            if dispute.dispute_status == enums::DisputeStatus::DisputeOpened {
+                disputes::evidence_templates::add_dispute_evidence_reminder_task(
+                    db,
+                    &dispute,
+                    state.conf.application_source,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to schedule dispute evidence reminder")
+                })
+                .ok();
+            }
+            Ok(dispute)
         }
         Some(dispute) => {
             logger::info!("Dispute Already exists, Updating the dispute details");
@@ -2869,6 +2886,45 @@ async fn disputes_incoming_webhook_flow(
             });
         }
 
+        // New disputes count towards the fraud rates of the sca exemption engine
+        if option_dispute.is_none() {
+            tokio::spawn({
+                let state = state.clone();
+                let business_profile = business_profile.clone();
+                let payment_attempt = payment_attempt.clone();
+
+                async move {
+                    three_ds_decision_rule::exemptions::record_fraudulent_payment(
+                        &state,
+                        &business_profile,
+                        &payment_attempt,
+                    )
+                    .await
+                }
+            });
+        }
+
+        // The rules are evaluated once, when the dispute is opened
+        if option_dispute.is_none()
+            && dispute_object.dispute_status == common_enums::DisputeStatus::DisputeOpened
+        {
+            tokio::spawn({
+                let state = state.clone();
+                let platform = platform.clone();
+                let dispute_object = dispute_object.clone();
+
+                async move {
+                    if let Err(err) =
+                        disputes::rules::execute_dispute_rules(&state, &platform, dispute_object)
+                            .await
+                    {
+                        logger::error!(?err, "Failed to execute dispute rules");
+                    }
+                }
+                .in_current_span()
+            });
+        }
+
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.into();
 
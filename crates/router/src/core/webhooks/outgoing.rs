@@ -169,10 +169,19 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         return Ok(());
     }
 
-    let event_insert_result = state
-        .store
-        .insert_event(new_event, processor.get_key_store())
-        .await;
+    // With the outbox enabled, the outbox relay delivers the webhook once the event is committed
+    let is_outbox_enabled = state.conf.outbox_relay.enabled;
+    let event_insert_result = if is_outbox_enabled {
+        state
+            .store
+            .insert_event_with_outbox(new_event, processor.get_key_store())
+            .await
+    } else {
+        state
+            .store
+            .insert_event(new_event, processor.get_key_store())
+            .await
+    };
 
     let event = match event_insert_result {
         Ok(event) => Ok(event),
@@ -192,6 +201,10 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     )
     .await?;
 
+    if is_outbox_enabled {
+        return Ok(());
+    }
+
     let process_tracker = add_outgoing_webhook_retry_task_to_process_tracker(
         &*state.store,
         &business_profile,
@@ -1001,6 +1014,12 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            #[cfg(feature = "payouts")]
+            webhooks::OutgoingWebhookContent::PayoutBatchDetails(payout_batch_response) => {
+                Self::PayoutBatch {
+                    payout_batch_id: payout_batch_response.payout_batch_id.clone(),
+                }
+            }
             webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
                 Self::Subscription {
                     subscription_id: subscription.id.clone(),
@@ -1008,6 +1027,16 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
                     payment_id: subscription.get_optional_payment_id(),
                 }
             }
+            webhooks::OutgoingWebhookContent::CustomerDetails(customer_merge) => Self::Customer {
+                customer_id: customer_merge.customer_id.clone(),
+                merge_id: customer_merge.merge_id.clone(),
+            },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod {
+                    customer_id: payment_method.customer_id.clone(),
+                    payment_method_id: payment_method.payment_method_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1026,6 +1055,12 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             payout_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PayoutBatch { payout_batch_id } => {
+            OutgoingWebhookEventContent::PayoutBatch {
+                payout_batch_id,
+                content: serde_json::Value::Null,
+            }
+        }
         diesel_models::EventMetadata::Refund {
             payment_id,
             refund_id,
@@ -1062,5 +1097,21 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             payment_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Customer {
+            customer_id,
+            merge_id,
+        } => OutgoingWebhookEventContent::Customer {
+            customer_id,
+            merge_id,
+            content: serde_json::Value::Null,
+        },
+        diesel_models::EventMetadata::PaymentMethod {
+            customer_id,
+            payment_method_id,
+        } => OutgoingWebhookEventContent::PaymentMethod {
+            customer_id,
+            payment_method_id,
+            content: serde_json::Value::Null,
+        },
     })
 }
@@ -630,6 +630,10 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 payout_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::PayoutBatch { payout_batch_id } => Self::PayoutBatch {
+                payout_batch_id,
+                content: serde_json::Value::Null,
+            },
             diesel_models::EventMetadata::Refund {
                 payment_id,
                 refund_id,
@@ -666,6 +670,22 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 payment_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::Customer {
+                customer_id,
+                merge_id,
+            } => Self::Customer {
+                customer_id,
+                merge_id,
+                content: serde_json::Value::Null,
+            },
+            diesel_models::EventMetadata::PaymentMethod {
+                customer_id,
+                payment_method_id,
+            } => Self::PaymentMethod {
+                customer_id,
+                payment_method_id,
+                content: serde_json::Value::Null,
+            },
         }
     }
 }
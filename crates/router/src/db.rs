@@ -1,3 +1,4 @@
+pub mod account_updater;
 pub mod address;
 pub mod api_keys;
 pub mod authentication;
@@ -5,14 +6,20 @@ pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
+pub mod bulk_refund;
 pub mod business_profile;
 pub mod callback_mapper;
 pub mod capture;
 pub mod card_issuer;
 pub mod configs;
+pub mod customer_data_request;
+pub mod customer_merge;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
+pub mod dispute_automated_action;
+pub mod dispute_evidence_template;
 pub mod dynamic_routing_stats;
 pub mod ephemeral_key;
 pub mod events;
@@ -23,19 +30,25 @@ pub mod gsm;
 pub mod health_check;
 pub mod hyperswitch_ai_interaction;
 pub mod kafka_store;
+pub mod ledger;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
+pub mod outbox;
 pub mod payment_link;
 pub mod payment_method_session;
+pub mod payout_batch;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_report;
+pub mod storage_scheme_migration;
+pub mod tenant_registry;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -54,6 +67,7 @@ use hyperswitch_domain_models::payouts::{
     payout_attempt::PayoutAttemptInterface, payouts::PayoutsInterface,
 };
 use hyperswitch_domain_models::{
+    bin_range::BinRangeInterface,
     card_issuer::CardIssuersInterface,
     cards_info::CardsInfoInterface,
     master_key::MasterKeyInterface,
@@ -84,7 +98,7 @@ pub use crate::{
     },
 };
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum StorageImpl {
     Postgresql,
     PostgresqlTest,
@@ -96,21 +110,29 @@ pub trait StorageInterface:
     Send
     + Sync
     + dyn_clone::DynClone
+    + account_updater::AccountUpdaterInterface
     + address::AddressInterface
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface<Error = StorageError>
     + capture::CaptureInterface
     + customers::CustomerInterface<Error = StorageError>
+    + customer_data_request::CustomerDataRequestInterface
+    + customer_merge::CustomerMergeInterface
     + dashboard_metadata::DashboardMetadataInterface
+    + data_retention::DataRetentionInterface
     + dispute::DisputeInterface
+    + dispute_automated_action::DisputeAutomatedActionInterface
+    + dispute_evidence_template::DisputeEvidenceTemplateInterface
     + ephemeral_key::EphemeralKeyInterface
     + ephemeral_key::ClientSecretInterface
     + events::EventInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
+    + ledger::LedgerInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
+    + outbox::OutboxInterface
     + merchant_account::MerchantAccountInterface<Error = StorageError>
     + merchant_connector_account::ConnectorAccessToken
     + merchant_connector_account::MerchantConnectorAccountInterface<Error = StorageError>
@@ -124,9 +146,12 @@ pub trait StorageInterface:
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface<Error = StorageError>
     + PayoutsInterface<Error = StorageError>
+    + payout_batch::PayoutBatchInterface
     + refund::RefundInterface
+    + bulk_refund::BulkRefundInterface
     + reverse_lookup::ReverseLookupInterface
     + CardsInfoInterface<Error = StorageError>
+    + BinRangeInterface<Error = StorageError>
     + merchant_key_store::MerchantKeyStoreInterface<Error = StorageError>
     + MasterKeyInterface
     + payment_link::PaymentLinkInterface
@@ -134,6 +159,8 @@ pub trait StorageInterface:
     + RequestIdStore
     + business_profile::ProfileInterface<Error = StorageError>
     + routing_algorithm::RoutingAlgorithmInterface
+    + settlement_report::SettlementReportInterface
+    + storage_scheme_migration::StorageSchemeMigrationInterface
     + gsm::GsmInterface
     + unified_translations::UnifiedTranslationsInterface
     + authorization::AuthorizationInterface
@@ -150,6 +177,7 @@ pub trait StorageInterface:
     + callback_mapper::CallbackMapperInterface
     + storage_impl::subscription::SubscriptionInterface<Error = StorageError>
     + storage_impl::invoice::InvoiceInterface<Error = StorageError>
+    + hyperswitch_domain_models::billing_catalog::BillingCatalogInterface<Error = StorageError>
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -169,6 +197,7 @@ pub trait GlobalStorageInterface:
     + user_role::UserRoleInterface
     + user_key_store::UserKeyStoreInterface
     + role::RoleInterface
+    + tenant_registry::TenantRegistryInterface
     + RedisConnInterface
     + 'static
 {
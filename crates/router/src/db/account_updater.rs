@@ -0,0 +1,779 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait AccountUpdaterInterface {
+    async fn insert_account_updater_batch(
+        &self,
+        batch: storage::AccountUpdaterBatchNew,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError>;
+
+    async fn find_account_updater_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError>;
+
+    async fn list_account_updater_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterBatch>, errors::StorageError>;
+
+    async fn list_account_updater_batches_by_merchant_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: enums::AccountUpdaterBatchStatus,
+    ) -> CustomResult<Vec<storage::AccountUpdaterBatch>, errors::StorageError>;
+
+    async fn update_account_updater_batch(
+        &self,
+        this: storage::AccountUpdaterBatch,
+        batch_update: storage::AccountUpdaterBatchUpdate,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError>;
+
+    async fn insert_account_updater_cards(
+        &self,
+        cards: Vec<storage::AccountUpdaterCardNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_account_updater_cards_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCard>, errors::StorageError>;
+
+    async fn find_account_updater_cards_submitted_since(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+        submitted_since: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCard>, errors::StorageError>;
+
+    async fn update_account_updater_card_result(
+        &self,
+        this: storage::AccountUpdaterCard,
+        result: enums::AccountUpdaterResult,
+    ) -> CustomResult<storage::AccountUpdaterCard, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_declined_account_updater_candidates(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        failed_since: time::PrimitiveDateTime,
+        error_codes: &[String],
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCandidate>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_active_card_payment_method_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_account_updater_batch(
+        &self,
+        batch: storage::AccountUpdaterBatchNew,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_account_updater_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_account_updater_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterBatch::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_account_updater_batches_by_merchant_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: enums::AccountUpdaterBatchStatus,
+    ) -> CustomResult<Vec<storage::AccountUpdaterBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterBatch::list_by_merchant_id_status(&conn, merchant_id, status)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_account_updater_batch(
+        &self,
+        this: storage::AccountUpdaterBatch,
+        batch_update: storage::AccountUpdaterBatchUpdate,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, batch_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_account_updater_cards(
+        &self,
+        cards: Vec<storage::AccountUpdaterCardNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AccountUpdaterCardNew::batch_insert(cards, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_account_updater_cards_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCard>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterCard::list_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_account_updater_cards_submitted_since(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+        submitted_since: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCard>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterCard::find_by_payment_method_ids_submitted_since(
+            &conn,
+            merchant_id,
+            payment_method_ids,
+            submitted_since,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_account_updater_card_result(
+        &self,
+        this: storage::AccountUpdaterCard,
+        result: enums::AccountUpdaterResult,
+    ) -> CustomResult<storage::AccountUpdaterCard, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_result(&conn, result)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_declined_account_updater_candidates(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        failed_since: time::PrimitiveDateTime,
+        error_codes: &[String],
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCandidate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterCandidate::find_declined(
+            &conn,
+            merchant_id,
+            failed_since,
+            error_codes,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_active_card_payment_method_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterCandidate::find_active_cards(
+            &conn,
+            merchant_id,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterInterface for MockDb {
+    async fn insert_account_updater_batch(
+        &self,
+        batch: storage::AccountUpdaterBatchNew,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        let mut batches = self.account_updater_batches.lock().await;
+        if batches
+            .iter()
+            .any(|existing| existing.batch_id == batch.batch_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "account_updater_batch",
+                key: Some(batch.batch_id.clone()),
+            })?
+        }
+        let batch = storage::AccountUpdaterBatch {
+            batch_id: batch.batch_id,
+            merchant_id: batch.merchant_id,
+            connector: batch.connector,
+            status: batch.status,
+            card_count: batch.card_count,
+            updated_count: batch.updated_count,
+            closed_count: batch.closed_count,
+            unchanged_count: batch.unchanged_count,
+            created_at: batch.created_at,
+            modified_at: batch.modified_at,
+            completed_at: batch.completed_at,
+        };
+        batches.push(batch.clone());
+        Ok(batch)
+    }
+
+    async fn find_account_updater_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        self.account_updater_batches
+            .lock()
+            .await
+            .iter()
+            .find(|batch| &batch.merchant_id == merchant_id && batch.batch_id == batch_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No account updater batch found for batch_id = {batch_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_account_updater_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterBatch>, errors::StorageError> {
+        let mut batches: Vec<_> = self
+            .account_updater_batches
+            .lock()
+            .await
+            .iter()
+            .filter(|batch| &batch.merchant_id == merchant_id)
+            .cloned()
+            .collect();
+        batches.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(batches
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(0))
+            .collect())
+    }
+
+    async fn list_account_updater_batches_by_merchant_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: enums::AccountUpdaterBatchStatus,
+    ) -> CustomResult<Vec<storage::AccountUpdaterBatch>, errors::StorageError> {
+        let mut batches: Vec<_> = self
+            .account_updater_batches
+            .lock()
+            .await
+            .iter()
+            .filter(|batch| &batch.merchant_id == merchant_id && batch.status == status)
+            .cloned()
+            .collect();
+        batches.sort_by_key(|batch| batch.created_at);
+        Ok(batches)
+    }
+
+    async fn update_account_updater_batch(
+        &self,
+        this: storage::AccountUpdaterBatch,
+        batch_update: storage::AccountUpdaterBatchUpdate,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        let mut batches = self.account_updater_batches.lock().await;
+        let batch = batches
+            .iter_mut()
+            .find(|batch| batch.merchant_id == this.merchant_id && batch.batch_id == this.batch_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No account updater batch found for batch_id = {}",
+                this.batch_id
+            )))?;
+        *batch = storage::AccountUpdaterBatchUpdateInternal::from(batch_update)
+            .apply_changeset(batch.clone());
+        Ok(batch.clone())
+    }
+
+    async fn insert_account_updater_cards(
+        &self,
+        cards: Vec<storage::AccountUpdaterCardNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut account_updater_cards = self.account_updater_cards.lock().await;
+        account_updater_cards.extend(cards.into_iter().map(|card| storage::AccountUpdaterCard {
+            batch_id: card.batch_id,
+            payment_method_id: card.payment_method_id,
+            merchant_id: card.merchant_id,
+            customer_id: card.customer_id,
+            reason: card.reason,
+            result: card.result,
+            created_at: card.created_at,
+            modified_at: card.modified_at,
+        }));
+        Ok(())
+    }
+
+    async fn list_account_updater_cards_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCard>, errors::StorageError> {
+        let mut cards: Vec<_> = self
+            .account_updater_cards
+            .lock()
+            .await
+            .iter()
+            .filter(|card| &card.merchant_id == merchant_id && card.batch_id == batch_id)
+            .cloned()
+            .collect();
+        cards.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        Ok(cards)
+    }
+
+    async fn find_account_updater_cards_submitted_since(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+        submitted_since: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCard>, errors::StorageError> {
+        let mut cards: Vec<_> = self
+            .account_updater_cards
+            .lock()
+            .await
+            .iter()
+            .filter(|card| {
+                &card.merchant_id == merchant_id
+                    && payment_method_ids.contains(&card.payment_method_id)
+                    && card.created_at >= submitted_since
+            })
+            .cloned()
+            .collect();
+        cards.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(cards)
+    }
+
+    async fn update_account_updater_card_result(
+        &self,
+        this: storage::AccountUpdaterCard,
+        result: enums::AccountUpdaterResult,
+    ) -> CustomResult<storage::AccountUpdaterCard, errors::StorageError> {
+        let mut cards = self.account_updater_cards.lock().await;
+        let card = cards
+            .iter_mut()
+            .find(|card| {
+                card.batch_id == this.batch_id && card.payment_method_id == this.payment_method_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No account updater card found for payment_method_id = {}",
+                this.payment_method_id
+            )))?;
+        card.result = Some(result);
+        card.modified_at = common_utils::date_time::now();
+        Ok(card.clone())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_declined_account_updater_candidates(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        failed_since: time::PrimitiveDateTime,
+        error_codes: &[String],
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCandidate>, errors::StorageError> {
+        let is_matching_error_code =
+            |code: &Option<String>| code.as_ref().is_some_and(|code| error_codes.contains(code));
+        let mut payment_method_ids: Vec<String> = self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|attempt| {
+                &attempt.merchant_id == merchant_id
+                    && attempt.status == enums::AttemptStatus::Failure
+                    && attempt.modified_at >= failed_since
+                    && (attempt.mandate_id.is_some() || attempt.is_stored_credential == Some(true))
+                    && (error_codes.is_empty()
+                        || is_matching_error_code(&attempt.error_code)
+                        || is_matching_error_code(&attempt.issuer_error_code))
+            })
+            .filter_map(|attempt| attempt.payment_method_id.clone())
+            .collect();
+        payment_method_ids.sort();
+        payment_method_ids.dedup();
+        Ok(payment_method_ids
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or(0))
+            .map(|payment_method_id| storage::AccountUpdaterCandidate {
+                payment_method_id,
+                reason: enums::AccountUpdaterReason::Declined,
+            })
+            .collect())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_active_card_payment_method_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        let mut payment_method_ids: Vec<String> = self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_method| {
+                &payment_method.merchant_id == merchant_id
+                    && payment_method.status == enums::PaymentMethodStatus::Active
+                    && payment_method.payment_method == Some(enums::PaymentMethod::Card)
+                    && starting_after.as_ref().is_none_or(|starting_after| {
+                        &payment_method.payment_method_id > starting_after
+                    })
+            })
+            .map(|payment_method| payment_method.payment_method_id.clone())
+            .collect();
+        payment_method_ids.sort();
+        Ok(payment_method_ids
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or(0))
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_account_updater_batch(
+        &self,
+        batch: storage::AccountUpdaterBatchNew,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        self.diesel_store.insert_account_updater_batch(batch).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_account_updater_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        self.diesel_store
+            .find_account_updater_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_account_updater_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_account_updater_batches_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_account_updater_batches_by_merchant_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: enums::AccountUpdaterBatchStatus,
+    ) -> CustomResult<Vec<storage::AccountUpdaterBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_account_updater_batches_by_merchant_id_status(merchant_id, status)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_account_updater_batch(
+        &self,
+        this: storage::AccountUpdaterBatch,
+        batch_update: storage::AccountUpdaterBatchUpdate,
+    ) -> CustomResult<storage::AccountUpdaterBatch, errors::StorageError> {
+        self.diesel_store
+            .update_account_updater_batch(this, batch_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_account_updater_cards(
+        &self,
+        cards: Vec<storage::AccountUpdaterCardNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store.insert_account_updater_cards(cards).await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_account_updater_cards_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCard>, errors::StorageError> {
+        self.diesel_store
+            .list_account_updater_cards_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_account_updater_cards_submitted_since(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+        submitted_since: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCard>, errors::StorageError> {
+        self.diesel_store
+            .find_account_updater_cards_submitted_since(
+                merchant_id,
+                payment_method_ids,
+                submitted_since,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_account_updater_card_result(
+        &self,
+        this: storage::AccountUpdaterCard,
+        result: enums::AccountUpdaterResult,
+    ) -> CustomResult<storage::AccountUpdaterCard, errors::StorageError> {
+        self.diesel_store
+            .update_account_updater_card_result(this, result)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_declined_account_updater_candidates(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        failed_since: time::PrimitiveDateTime,
+        error_codes: &[String],
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterCandidate>, errors::StorageError> {
+        self.diesel_store
+            .find_declined_account_updater_candidates(merchant_id, failed_since, error_codes, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_active_card_payment_method_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        self.diesel_store
+            .find_active_card_payment_method_ids(merchant_id, starting_after, limit)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::types::keymanager::KeyManagerState;
+    use time::macros::datetime;
+
+    use super::*;
+
+    async fn get_mock_db() -> MockDb {
+        MockDb::new(
+            &redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            },
+            KeyManagerState::mock(),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn get_card(
+        batch_id: &str,
+        payment_method_id: &str,
+        created_at: time::PrimitiveDateTime,
+    ) -> storage::AccountUpdaterCardNew {
+        storage::AccountUpdaterCardNew {
+            batch_id: batch_id.to_string(),
+            payment_method_id: payment_method_id.to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            customer_id: id_type::CustomerId::try_from(Cow::from("cus_1")).unwrap(),
+            reason: enums::AccountUpdaterReason::Expiring,
+            result: None,
+            created_at,
+            modified_at: created_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_results_are_applied() {
+        let db = get_mock_db().await;
+        let merchant_id = id_type::MerchantId::default();
+        let created_at = datetime!(2026-10-01 00:00:00);
+        let batch = db
+            .insert_account_updater_batch(storage::AccountUpdaterBatchNew {
+                batch_id: "aub_1".to_string(),
+                merchant_id: merchant_id.clone(),
+                connector: enums::AccountUpdaterConnector::File,
+                status: enums::AccountUpdaterBatchStatus::Submitted,
+                card_count: 2,
+                updated_count: 0,
+                closed_count: 0,
+                unchanged_count: 0,
+                created_at,
+                modified_at: created_at,
+                completed_at: None,
+            })
+            .await
+            .unwrap();
+        db.insert_account_updater_cards(vec![
+            get_card("aub_1", "pm_2", created_at),
+            get_card("aub_1", "pm_1", created_at),
+        ])
+        .await
+        .unwrap();
+
+        let submitted = db
+            .list_account_updater_batches_by_merchant_id_status(
+                &merchant_id,
+                enums::AccountUpdaterBatchStatus::Submitted,
+            )
+            .await
+            .unwrap();
+        assert_eq!(submitted, vec![batch.clone()]);
+
+        let cards = db
+            .list_account_updater_cards_by_merchant_id_batch_id(&merchant_id, "aub_1")
+            .await
+            .unwrap();
+        assert_eq!(
+            cards
+                .iter()
+                .map(|card| card.payment_method_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["pm_1", "pm_2"]
+        );
+        let card = db
+            .update_account_updater_card_result(
+                cards.into_iter().next().unwrap(),
+                enums::AccountUpdaterResult::NewExpiry,
+            )
+            .await
+            .unwrap();
+        assert_eq!(card.result, Some(enums::AccountUpdaterResult::NewExpiry));
+
+        let batch = db
+            .update_account_updater_batch(
+                batch,
+                storage::AccountUpdaterBatchUpdate::ResultsApplied {
+                    updated_count: 1,
+                    closed_count: 0,
+                    unchanged_count: 1,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(batch.status, enums::AccountUpdaterBatchStatus::Completed);
+        assert_eq!(batch.updated_count, 1);
+        assert_eq!(batch.unchanged_count, 1);
+        assert!(batch.completed_at.is_some());
+        assert!(db
+            .list_account_updater_batches_by_merchant_id_status(
+                &merchant_id,
+                enums::AccountUpdaterBatchStatus::Submitted,
+            )
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cards_submitted_since() {
+        let db = get_mock_db().await;
+        db.insert_account_updater_cards(vec![
+            get_card("aub_1", "pm_1", datetime!(2026-08-01 00:00:00)),
+            get_card("aub_2", "pm_1", datetime!(2026-10-01 00:00:00)),
+            get_card("aub_2", "pm_2", datetime!(2026-10-01 00:00:00)),
+            get_card("aub_2", "pm_3", datetime!(2026-10-01 00:00:00)),
+        ])
+        .await
+        .unwrap();
+
+        let cards = db
+            .find_account_updater_cards_submitted_since(
+                &id_type::MerchantId::default(),
+                vec!["pm_1".to_string(), "pm_2".to_string()],
+                datetime!(2026-09-01 00:00:00),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cards
+                .iter()
+                .map(|card| (card.batch_id.as_str(), card.payment_method_id.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("aub_2", "pm_1"), ("aub_2", "pm_2")]
+        );
+    }
+}
@@ -221,7 +221,10 @@ impl ApiKeyInterface for MockDb {
         // don't allow duplicate key_ids, a those would be a unique constraint violation in the
         // real db as it is used as the primary key
         if locked_api_keys.iter().any(|k| k.key_id == api_key.key_id) {
-            Err(errors::StorageError::MockDbError)?;
+            Err(errors::StorageError::DuplicateValue {
+                entity: "key_id",
+                key: Some(api_key.key_id.get_string_repr().to_owned()),
+            })?;
         }
         let stored_key = storage::ApiKey {
             key_id: api_key.key_id,
@@ -250,7 +253,10 @@ impl ApiKeyInterface for MockDb {
         let key_to_update = locked_api_keys
             .iter_mut()
             .find(|k| k.merchant_id == merchant_id && k.key_id == key_id)
-            .ok_or(errors::StorageError::MockDbError)?;
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "ApiKey of {} not found",
+                key_id.get_string_repr()
+            )))?;
 
         match api_key {
             storage::ApiKeyUpdate::Update {
@@ -0,0 +1,441 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait BulkRefundInterface {
+    async fn insert_bulk_refund(
+        &self,
+        bulk_refund: storage::BulkRefundNew,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError>;
+
+    async fn find_bulk_refund_by_merchant_id_bulk_refund_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        bulk_refund_id: &str,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError>;
+
+    async fn update_bulk_refund(
+        &self,
+        this: storage::BulkRefund,
+        bulk_refund_update: storage::BulkRefundUpdate,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError>;
+
+    async fn insert_bulk_refund_items(
+        &self,
+        items: Vec<storage::BulkRefundItemNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_bulk_refund_items_by_bulk_refund_id(
+        &self,
+        bulk_refund_id: &str,
+    ) -> CustomResult<Vec<storage::BulkRefundItem>, errors::StorageError>;
+
+    async fn list_bulk_refund_items_by_bulk_refund_id_status(
+        &self,
+        bulk_refund_id: &str,
+        status: enums::BulkRefundItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::BulkRefundItem>, errors::StorageError>;
+
+    async fn get_bulk_refund_items_count_by_bulk_refund_id_status(
+        &self,
+        bulk_refund_id: &str,
+        status: enums::BulkRefundItemStatus,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn update_bulk_refund_item(
+        &self,
+        this: storage::BulkRefundItem,
+        item_update: storage::BulkRefundItemUpdate,
+    ) -> CustomResult<storage::BulkRefundItem, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl BulkRefundInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_bulk_refund(
+        &self,
+        bulk_refund: storage::BulkRefundNew,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        bulk_refund
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_bulk_refund_by_merchant_id_bulk_refund_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        bulk_refund_id: &str,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BulkRefund::find_by_merchant_id_bulk_refund_id(&conn, merchant_id, bulk_refund_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_bulk_refund(
+        &self,
+        this: storage::BulkRefund,
+        bulk_refund_update: storage::BulkRefundUpdate,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, bulk_refund_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_bulk_refund_items(
+        &self,
+        items: Vec<storage::BulkRefundItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BulkRefundItemNew::batch_insert(items, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_bulk_refund_items_by_bulk_refund_id(
+        &self,
+        bulk_refund_id: &str,
+    ) -> CustomResult<Vec<storage::BulkRefundItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BulkRefundItem::list_by_bulk_refund_id(&conn, bulk_refund_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_bulk_refund_items_by_bulk_refund_id_status(
+        &self,
+        bulk_refund_id: &str,
+        status: enums::BulkRefundItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::BulkRefundItem>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BulkRefundItem::list_by_bulk_refund_id_status(&conn, bulk_refund_id, status, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_bulk_refund_items_count_by_bulk_refund_id_status(
+        &self,
+        bulk_refund_id: &str,
+        status: enums::BulkRefundItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BulkRefundItem::get_count_by_bulk_refund_id_status(&conn, bulk_refund_id, status)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_bulk_refund_item(
+        &self,
+        this: storage::BulkRefundItem,
+        item_update: storage::BulkRefundItemUpdate,
+    ) -> CustomResult<storage::BulkRefundItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, item_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl BulkRefundInterface for MockDb {
+    async fn insert_bulk_refund(
+        &self,
+        bulk_refund: storage::BulkRefundNew,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        let mut bulk_refunds = self.bulk_refunds.lock().await;
+        if bulk_refunds
+            .iter()
+            .any(|existing| existing.bulk_refund_id == bulk_refund.bulk_refund_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "bulk_refund_id",
+                key: Some(bulk_refund.bulk_refund_id.clone()),
+            })?
+        }
+        let bulk_refund = storage::BulkRefund {
+            bulk_refund_id: bulk_refund.bulk_refund_id,
+            merchant_id: bulk_refund.merchant_id,
+            profile_id: bulk_refund.profile_id,
+            status: bulk_refund.status,
+            total_count: bulk_refund.total_count,
+            submitted_count: bulk_refund.submitted_count,
+            failed_count: bulk_refund.failed_count,
+            created_by: bulk_refund.created_by,
+            created_at: bulk_refund.created_at,
+            modified_at: bulk_refund.modified_at,
+        };
+        bulk_refunds.push(bulk_refund.clone());
+        Ok(bulk_refund)
+    }
+
+    async fn find_bulk_refund_by_merchant_id_bulk_refund_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        bulk_refund_id: &str,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        self.bulk_refunds
+            .lock()
+            .await
+            .iter()
+            .find(|bulk_refund| {
+                bulk_refund.merchant_id == *merchant_id
+                    && bulk_refund.bulk_refund_id == bulk_refund_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No bulk refund found for bulk_refund_id = {bulk_refund_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_bulk_refund(
+        &self,
+        this: storage::BulkRefund,
+        bulk_refund_update: storage::BulkRefundUpdate,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        let mut bulk_refunds = self.bulk_refunds.lock().await;
+        let bulk_refund = bulk_refunds
+            .iter_mut()
+            .find(|bulk_refund| {
+                bulk_refund.merchant_id == this.merchant_id
+                    && bulk_refund.bulk_refund_id == this.bulk_refund_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No bulk refund found for bulk_refund_id = {}",
+                this.bulk_refund_id
+            )))?;
+        let diesel_models::bulk_refund::BulkRefundUpdateInternal {
+            status,
+            submitted_count,
+            failed_count,
+            modified_at,
+        } = bulk_refund_update.into();
+        *bulk_refund = storage::BulkRefund {
+            status: status.unwrap_or(bulk_refund.status),
+            submitted_count: submitted_count.unwrap_or(bulk_refund.submitted_count),
+            failed_count: failed_count.unwrap_or(bulk_refund.failed_count),
+            modified_at,
+            ..bulk_refund.clone()
+        };
+        Ok(bulk_refund.clone())
+    }
+
+    async fn insert_bulk_refund_items(
+        &self,
+        items: Vec<storage::BulkRefundItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut bulk_refund_items = self.bulk_refund_items.lock().await;
+        for item in items {
+            if bulk_refund_items.iter().any(|existing| {
+                existing.bulk_refund_id == item.bulk_refund_id
+                    && existing.item_index == item.item_index
+            }) {
+                Err(errors::StorageError::DuplicateValue {
+                    entity: "bulk_refund_item",
+                    key: Some(format!("{}_{}", item.bulk_refund_id, item.item_index)),
+                })?
+            }
+            bulk_refund_items.push(storage::BulkRefundItem {
+                bulk_refund_id: item.bulk_refund_id,
+                item_index: item.item_index,
+                merchant_id: item.merchant_id,
+                payment_id: item.payment_id,
+                refund_id: item.refund_id,
+                amount: item.amount,
+                request_data: item.request_data,
+                connector: item.connector,
+                status: item.status,
+                refund_status: item.refund_status,
+                error_code: item.error_code,
+                error_message: item.error_message,
+                created_at: item.created_at,
+                modified_at: item.modified_at,
+            });
+        }
+        Ok(())
+    }
+
+    async fn list_bulk_refund_items_by_bulk_refund_id(
+        &self,
+        bulk_refund_id: &str,
+    ) -> CustomResult<Vec<storage::BulkRefundItem>, errors::StorageError> {
+        let mut items: Vec<_> = self
+            .bulk_refund_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.bulk_refund_id == bulk_refund_id)
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| item.item_index);
+        Ok(items)
+    }
+
+    async fn list_bulk_refund_items_by_bulk_refund_id_status(
+        &self,
+        bulk_refund_id: &str,
+        status: enums::BulkRefundItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::BulkRefundItem>, errors::StorageError> {
+        let mut items: Vec<_> = self
+            .bulk_refund_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.bulk_refund_id == bulk_refund_id && item.status == status)
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| item.item_index);
+        items.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(items)
+    }
+
+    async fn get_bulk_refund_items_count_by_bulk_refund_id_status(
+        &self,
+        bulk_refund_id: &str,
+        status: enums::BulkRefundItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Ok(self
+            .bulk_refund_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.bulk_refund_id == bulk_refund_id && item.status == status)
+            .count())
+    }
+
+    async fn update_bulk_refund_item(
+        &self,
+        this: storage::BulkRefundItem,
+        item_update: storage::BulkRefundItemUpdate,
+    ) -> CustomResult<storage::BulkRefundItem, errors::StorageError> {
+        let mut bulk_refund_items = self.bulk_refund_items.lock().await;
+        let item = bulk_refund_items
+            .iter_mut()
+            .find(|item| {
+                item.bulk_refund_id == this.bulk_refund_id && item.item_index == this.item_index
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No bulk refund item found for bulk_refund_id = {} and item_index = {}",
+                this.bulk_refund_id, this.item_index
+            )))?;
+        *item = storage::BulkRefundItem {
+            connector: item_update.connector.or(item.connector.take()),
+            status: item_update.status,
+            refund_status: item_update.refund_status.or(item.refund_status),
+            error_code: item_update.error_code.or(item.error_code.take()),
+            error_message: item_update.error_message.or(item.error_message.take()),
+            modified_at: item_update.modified_at,
+            ..item.clone()
+        };
+        Ok(item.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl BulkRefundInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_bulk_refund(
+        &self,
+        bulk_refund: storage::BulkRefundNew,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        self.diesel_store.insert_bulk_refund(bulk_refund).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_bulk_refund_by_merchant_id_bulk_refund_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        bulk_refund_id: &str,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        self.diesel_store
+            .find_bulk_refund_by_merchant_id_bulk_refund_id(merchant_id, bulk_refund_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_bulk_refund(
+        &self,
+        this: storage::BulkRefund,
+        bulk_refund_update: storage::BulkRefundUpdate,
+    ) -> CustomResult<storage::BulkRefund, errors::StorageError> {
+        self.diesel_store
+            .update_bulk_refund(this, bulk_refund_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_bulk_refund_items(
+        &self,
+        items: Vec<storage::BulkRefundItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store.insert_bulk_refund_items(items).await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_bulk_refund_items_by_bulk_refund_id(
+        &self,
+        bulk_refund_id: &str,
+    ) -> CustomResult<Vec<storage::BulkRefundItem>, errors::StorageError> {
+        self.diesel_store
+            .list_bulk_refund_items_by_bulk_refund_id(bulk_refund_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_bulk_refund_items_by_bulk_refund_id_status(
+        &self,
+        bulk_refund_id: &str,
+        status: enums::BulkRefundItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::BulkRefundItem>, errors::StorageError> {
+        self.diesel_store
+            .list_bulk_refund_items_by_bulk_refund_id_status(bulk_refund_id, status, limit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_bulk_refund_items_count_by_bulk_refund_id_status(
+        &self,
+        bulk_refund_id: &str,
+        status: enums::BulkRefundItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .get_bulk_refund_items_count_by_bulk_refund_id_status(bulk_refund_id, status)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_bulk_refund_item(
+        &self,
+        this: storage::BulkRefundItem,
+        item_update: storage::BulkRefundItemUpdate,
+    ) -> CustomResult<storage::BulkRefundItem, errors::StorageError> {
+        self.diesel_store
+            .update_bulk_refund_item(this, item_update)
+            .await
+    }
+}
@@ -57,16 +57,39 @@ impl CallbackMapperInterface for MockDb {
     #[instrument(skip_all)]
     async fn insert_call_back_mapper(
         &self,
-        _call_back_mapper: domain::CallbackMapper,
+        call_back_mapper: domain::CallbackMapper,
     ) -> CustomResult<domain::CallbackMapper, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut call_back_mappers = self.callback_mappers.lock().await;
+        let call_back_mapper = call_back_mapper.to_storage_model();
+        if call_back_mappers.iter().any(|mapper| {
+            mapper.id == call_back_mapper.id && mapper.type_ == call_back_mapper.type_
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "callback_mapper",
+                key: Some(call_back_mapper.id.clone()),
+            })?
+        }
+        call_back_mappers.push(call_back_mapper.clone());
+        Ok(domain::CallbackMapper::from_storage_model(call_back_mapper))
     }
 
     #[instrument(skip_all)]
     async fn find_call_back_mapper_by_id(
         &self,
-        _id: &str,
+        id: &str,
     ) -> CustomResult<domain::CallbackMapper, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.callback_mappers
+            .lock()
+            .await
+            .iter()
+            .find(|mapper| mapper.id == id)
+            .cloned()
+            .map(domain::CallbackMapper::from_storage_model)
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No callback mapper found for id = {id}"
+                ))
+                .into(),
+            )
     }
 }
@@ -0,0 +1,241 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerDataRequestInterface {
+    async fn insert_customer_data_request(
+        &self,
+        customer_data_request: storage::CustomerDataRequestNew,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError>;
+
+    async fn find_customer_data_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError>;
+
+    async fn list_customer_data_requests_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerDataRequest>, errors::StorageError>;
+
+    async fn update_customer_data_request(
+        &self,
+        this: storage::CustomerDataRequest,
+        customer_data_request_update: storage::CustomerDataRequestUpdate,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerDataRequestInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_customer_data_request(
+        &self,
+        customer_data_request: storage::CustomerDataRequestNew,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_data_request
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_customer_data_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerDataRequest::find_by_merchant_id_request_id(&conn, merchant_id, request_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_data_requests_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerDataRequest>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerDataRequest::list_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_customer_data_request(
+        &self,
+        this: storage::CustomerDataRequest,
+        customer_data_request_update: storage::CustomerDataRequestUpdate,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, customer_data_request_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerDataRequestInterface for MockDb {
+    async fn insert_customer_data_request(
+        &self,
+        customer_data_request: storage::CustomerDataRequestNew,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        let mut customer_data_requests = self.customer_data_requests.lock().await;
+        if customer_data_requests
+            .iter()
+            .any(|request| request.request_id == customer_data_request.request_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "request_id",
+                key: Some(customer_data_request.request_id.clone()),
+            })?
+        }
+        let customer_data_request = storage::CustomerDataRequest {
+            request_id: customer_data_request.request_id,
+            merchant_id: customer_data_request.merchant_id,
+            customer_id: customer_data_request.customer_id,
+            request_type: customer_data_request.request_type,
+            status: customer_data_request.status,
+            file_id: customer_data_request.file_id,
+            requested_by: customer_data_request.requested_by,
+            error_message: customer_data_request.error_message,
+            created_at: customer_data_request.created_at,
+            modified_at: customer_data_request.modified_at,
+            completed_at: customer_data_request.completed_at,
+        };
+        customer_data_requests.push(customer_data_request.clone());
+        Ok(customer_data_request)
+    }
+
+    async fn find_customer_data_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        self.customer_data_requests
+            .lock()
+            .await
+            .iter()
+            .find(|request| request.merchant_id == *merchant_id && request.request_id == request_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No customer data request found for request_id = {request_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_customer_data_requests_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerDataRequest>, errors::StorageError> {
+        let mut requests: Vec<_> = self
+            .customer_data_requests
+            .lock()
+            .await
+            .iter()
+            .filter(|request| {
+                request.merchant_id == *merchant_id && request.customer_id == *customer_id
+            })
+            .cloned()
+            .collect();
+        requests.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(requests)
+    }
+
+    async fn update_customer_data_request(
+        &self,
+        this: storage::CustomerDataRequest,
+        customer_data_request_update: storage::CustomerDataRequestUpdate,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        let mut customer_data_requests = self.customer_data_requests.lock().await;
+        let request = customer_data_requests
+            .iter_mut()
+            .find(|request| request.request_id == this.request_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer data request found for request_id = {}",
+                this.request_id
+            )))?;
+        let diesel_models::customer_data_request::CustomerDataRequestUpdateInternal {
+            status,
+            file_id,
+            error_message,
+            modified_at,
+            completed_at,
+        } = customer_data_request_update.into();
+        *request = storage::CustomerDataRequest {
+            status: status.unwrap_or(request.status),
+            file_id: file_id.or(request.file_id.take()),
+            error_message: error_message.or(request.error_message.take()),
+            modified_at,
+            completed_at: completed_at.or(request.completed_at),
+            ..request.clone()
+        };
+        Ok(request.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerDataRequestInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_customer_data_request(
+        &self,
+        customer_data_request: storage::CustomerDataRequestNew,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        self.diesel_store
+            .insert_customer_data_request(customer_data_request)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_customer_data_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        self.diesel_store
+            .find_customer_data_request_by_merchant_id_request_id(merchant_id, request_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_data_requests_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerDataRequest>, errors::StorageError> {
+        self.diesel_store
+            .list_customer_data_requests_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_customer_data_request(
+        &self,
+        this: storage::CustomerDataRequest,
+        customer_data_request_update: storage::CustomerDataRequestUpdate,
+    ) -> CustomResult<storage::CustomerDataRequest, errors::StorageError> {
+        self.diesel_store
+            .update_customer_data_request(this, customer_data_request_update)
+            .await
+    }
+}
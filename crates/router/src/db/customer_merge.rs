@@ -0,0 +1,558 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerMergeInterface {
+    async fn find_customer_redirect_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerRedirect>, errors::StorageError>;
+
+    async fn list_customer_redirects_by_merchant_id_merge_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merge_id: &str,
+    ) -> CustomResult<Vec<storage::CustomerRedirect>, errors::StorageError>;
+
+    /// Moves the records of the source customers to the target customer, deletes the source
+    /// customers and leaves redirects to the target customer in their place, in one transaction.
+    async fn merge_customers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        target_customer_id: &common_utils::id_type::CustomerId,
+        redirects: Vec<storage::CustomerRedirectNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn replace_customer_match_keys(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        match_keys: Vec<storage::CustomerMatchKeyNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn delete_customer_match_keys_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Returns the match keys shared by more than one customer, for at most `limit` distinct keys.
+    async fn find_duplicated_customer_match_keys(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::CustomerMatchKey>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerMergeInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_customer_redirect_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerRedirect>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerRedirect::find_optional_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_redirects_by_merchant_id_merge_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merge_id: &str,
+    ) -> CustomResult<Vec<storage::CustomerRedirect>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerRedirect::list_by_merchant_id_merge_id(&conn, merchant_id, merge_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn merge_customers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        target_customer_id: &common_utils::id_type::CustomerId,
+        redirects: Vec<storage::CustomerRedirectNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let merchant_id = merchant_id.to_owned();
+        let target_customer_id = target_customer_id.to_owned();
+        let source_customer_ids: Vec<_> = redirects
+            .iter()
+            .map(|redirect| redirect.customer_id.clone())
+            .collect();
+        conn.transaction_async(|conn| async move {
+            storage::CustomerRedirect::reassign_customer_records(
+                &conn,
+                &merchant_id,
+                source_customer_ids.clone(),
+                &target_customer_id,
+            )
+            .await
+            .map_err(errors::StorageError::from)?;
+            storage::CustomerRedirect::update_target_by_merchant_id_target_customer_ids(
+                &conn,
+                &merchant_id,
+                source_customer_ids.clone(),
+                &target_customer_id,
+            )
+            .await
+            .map_err(errors::StorageError::from)?;
+            storage::CustomerRedirectNew::batch_insert(redirects, &conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            storage::CustomerMatchKey::delete_by_merchant_id_customer_ids(
+                &conn,
+                &merchant_id,
+                source_customer_ids,
+            )
+            .await
+            .map_err(errors::StorageError::from)?;
+            Ok::<_, errors::StorageError>(())
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn replace_customer_match_keys(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        match_keys: Vec<storage::CustomerMatchKeyNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let merchant_id = merchant_id.to_owned();
+        let customer_id = customer_id.to_owned();
+        conn.transaction_async(|conn| async move {
+            storage::CustomerMatchKey::delete_by_merchant_id_customer_ids(
+                &conn,
+                &merchant_id,
+                vec![customer_id],
+            )
+            .await
+            .map_err(errors::StorageError::from)?;
+            if !match_keys.is_empty() {
+                storage::CustomerMatchKeyNew::batch_insert(match_keys, &conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+            }
+            Ok::<_, errors::StorageError>(())
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_customer_match_keys_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::CustomerMatchKey::delete_by_merchant_id_customer_ids(
+            &conn,
+            merchant_id,
+            vec![customer_id.to_owned()],
+        )
+        .await
+        .map(|_| ())
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_duplicated_customer_match_keys(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::CustomerMatchKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let key_hashes =
+            storage::CustomerMatchKey::find_duplicated_key_hashes(&conn, merchant_id, limit)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?
+                .into_iter()
+                .map(|(_, key_hash)| key_hash)
+                .collect::<Vec<_>>();
+        if key_hashes.is_empty() {
+            return Ok(Vec::new());
+        }
+        storage::CustomerMatchKey::list_by_merchant_id_key_hashes(&conn, merchant_id, key_hashes)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerMergeInterface for MockDb {
+    async fn find_customer_redirect_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerRedirect>, errors::StorageError> {
+        Ok(self
+            .customer_redirects
+            .lock()
+            .await
+            .iter()
+            .find(|redirect| {
+                redirect.merchant_id == *merchant_id && redirect.customer_id == *customer_id
+            })
+            .cloned())
+    }
+
+    async fn list_customer_redirects_by_merchant_id_merge_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merge_id: &str,
+    ) -> CustomResult<Vec<storage::CustomerRedirect>, errors::StorageError> {
+        let mut redirects: Vec<_> = self
+            .customer_redirects
+            .lock()
+            .await
+            .iter()
+            .filter(|redirect| {
+                redirect.merchant_id == *merchant_id && redirect.merge_id == merge_id
+            })
+            .cloned()
+            .collect();
+        redirects.sort_by(|a, b| {
+            a.customer_id
+                .get_string_repr()
+                .cmp(b.customer_id.get_string_repr())
+        });
+        Ok(redirects)
+    }
+
+    async fn merge_customers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        target_customer_id: &common_utils::id_type::CustomerId,
+        redirects: Vec<storage::CustomerRedirectNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let source_customer_ids: Vec<_> = redirects
+            .iter()
+            .map(|redirect| redirect.customer_id.clone())
+            .collect();
+        let mut customer_redirects = self.customer_redirects.lock().await;
+        if redirects.iter().any(|new_redirect| {
+            customer_redirects.iter().any(|redirect| {
+                redirect.merchant_id == new_redirect.merchant_id
+                    && redirect.customer_id == new_redirect.customer_id
+            })
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "customer_redirect",
+                key: None,
+            })?
+        }
+
+        #[cfg(feature = "v1")]
+        reassign_customer_records(self, merchant_id, &source_customer_ids, target_customer_id)
+            .await;
+
+        customer_redirects
+            .iter_mut()
+            .filter(|redirect| {
+                redirect.merchant_id == *merchant_id
+                    && source_customer_ids.contains(&redirect.target_customer_id)
+            })
+            .for_each(|redirect| redirect.target_customer_id = target_customer_id.clone());
+        customer_redirects.extend(redirects.into_iter().map(|redirect| {
+            storage::CustomerRedirect {
+                merchant_id: redirect.merchant_id,
+                customer_id: redirect.customer_id,
+                target_customer_id: redirect.target_customer_id,
+                merge_id: redirect.merge_id,
+                merged_by: redirect.merged_by,
+                created_at: redirect.created_at,
+            }
+        }));
+        self.customer_match_keys.lock().await.retain(|match_key| {
+            match_key.merchant_id != *merchant_id
+                || !source_customer_ids.contains(&match_key.customer_id)
+        });
+        Ok(())
+    }
+
+    async fn replace_customer_match_keys(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        match_keys: Vec<storage::CustomerMatchKeyNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut customer_match_keys = self.customer_match_keys.lock().await;
+        customer_match_keys.retain(|match_key| {
+            match_key.merchant_id != *merchant_id || match_key.customer_id != *customer_id
+        });
+        customer_match_keys.extend(match_keys.into_iter().map(|match_key| {
+            storage::CustomerMatchKey {
+                merchant_id: match_key.merchant_id,
+                customer_id: match_key.customer_id,
+                key_type: match_key.key_type,
+                key_hash: match_key.key_hash,
+                created_at: match_key.created_at,
+            }
+        }));
+        Ok(())
+    }
+
+    async fn delete_customer_match_keys_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.customer_match_keys.lock().await.retain(|match_key| {
+            match_key.merchant_id != *merchant_id || match_key.customer_id != *customer_id
+        });
+        Ok(())
+    }
+
+    async fn find_duplicated_customer_match_keys(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::CustomerMatchKey>, errors::StorageError> {
+        let customer_match_keys = self.customer_match_keys.lock().await;
+        let merchant_match_keys: Vec<_> = customer_match_keys
+            .iter()
+            .filter(|match_key| match_key.merchant_id == *merchant_id)
+            .collect();
+
+        let mut duplicated_keys: Vec<_> = merchant_match_keys
+            .iter()
+            .filter(|match_key| {
+                merchant_match_keys
+                    .iter()
+                    .filter(|other| {
+                        other.key_type == match_key.key_type && other.key_hash == match_key.key_hash
+                    })
+                    .count()
+                    > 1
+            })
+            .map(|match_key| (match_key.key_type, match_key.key_hash.clone()))
+            .collect();
+        duplicated_keys.sort_by(|a, b| a.1.cmp(&b.1));
+        duplicated_keys.dedup();
+        duplicated_keys.truncate(usize::try_from(limit).unwrap_or(0));
+        let key_hashes: Vec<_> = duplicated_keys
+            .into_iter()
+            .map(|(_, key_hash)| key_hash)
+            .collect();
+
+        let mut match_keys: Vec<_> = merchant_match_keys
+            .into_iter()
+            .filter(|match_key| key_hashes.contains(&match_key.key_hash))
+            .cloned()
+            .collect();
+        match_keys.sort_by_key(|match_key| match_key.created_at);
+        Ok(match_keys)
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerMergeInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_customer_redirect_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerRedirect>, errors::StorageError> {
+        self.diesel_store
+            .find_customer_redirect_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_redirects_by_merchant_id_merge_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merge_id: &str,
+    ) -> CustomResult<Vec<storage::CustomerRedirect>, errors::StorageError> {
+        self.diesel_store
+            .list_customer_redirects_by_merchant_id_merge_id(merchant_id, merge_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn merge_customers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        target_customer_id: &common_utils::id_type::CustomerId,
+        redirects: Vec<storage::CustomerRedirectNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .merge_customers(merchant_id, target_customer_id, redirects)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn replace_customer_match_keys(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        match_keys: Vec<storage::CustomerMatchKeyNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .replace_customer_match_keys(merchant_id, customer_id, match_keys)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_customer_match_keys_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .delete_customer_match_keys_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_duplicated_customer_match_keys(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::CustomerMatchKey>, errors::StorageError> {
+        self.diesel_store
+            .find_duplicated_customer_match_keys(merchant_id, limit)
+            .await
+    }
+}
+
+/// Moves the payouts, payment methods, mandates, addresses and payments of the source customers
+/// to the target customer and deletes the source customers, like the merge transaction of the
+/// database.
+#[cfg(feature = "v1")]
+async fn reassign_customer_records(
+    db: &MockDb,
+    merchant_id: &common_utils::id_type::MerchantId,
+    source_customer_ids: &[common_utils::id_type::CustomerId],
+    target_customer_id: &common_utils::id_type::CustomerId,
+) {
+    #[cfg(feature = "payouts")]
+    reassign_payouts(db, merchant_id, source_customer_ids, target_customer_id).await;
+
+    db.payment_methods
+        .lock()
+        .await
+        .iter_mut()
+        .filter(|payment_method| {
+            payment_method.merchant_id == *merchant_id
+                && source_customer_ids.contains(&payment_method.customer_id)
+        })
+        .for_each(|payment_method| payment_method.customer_id = target_customer_id.clone());
+    db.mandates
+        .lock()
+        .await
+        .iter_mut()
+        .filter(|mandate| {
+            mandate.merchant_id == *merchant_id
+                && source_customer_ids.contains(&mandate.customer_id)
+        })
+        .for_each(|mandate| mandate.customer_id = target_customer_id.clone());
+    db.addresses
+        .lock()
+        .await
+        .iter_mut()
+        .filter(|address| {
+            address.merchant_id == *merchant_id
+                && address
+                    .customer_id
+                    .as_ref()
+                    .is_some_and(|customer_id| source_customer_ids.contains(customer_id))
+        })
+        .for_each(|address| address.customer_id = Some(target_customer_id.clone()));
+    db.payment_intents
+        .lock()
+        .await
+        .iter_mut()
+        .filter(|payment_intent| {
+            payment_intent.merchant_id == *merchant_id
+                && payment_intent
+                    .customer_id
+                    .as_ref()
+                    .is_some_and(|customer_id| source_customer_ids.contains(customer_id))
+        })
+        .for_each(|payment_intent| payment_intent.customer_id = Some(target_customer_id.clone()));
+    db.customers.lock().await.retain(|customer| {
+        customer.merchant_id != *merchant_id || !source_customer_ids.contains(&customer.customer_id)
+    });
+}
+
+/// Moves the payouts of the source customers and the payouts made to their payment methods, along
+/// with the attempts of the payouts, to the target customer.
+#[cfg(all(feature = "v1", feature = "payouts"))]
+async fn reassign_payouts(
+    db: &MockDb,
+    merchant_id: &common_utils::id_type::MerchantId,
+    source_customer_ids: &[common_utils::id_type::CustomerId],
+    target_customer_id: &common_utils::id_type::CustomerId,
+) {
+    let payment_method_ids: Vec<_> = db
+        .payment_methods
+        .lock()
+        .await
+        .iter()
+        .filter(|payment_method| {
+            payment_method.merchant_id == *merchant_id
+                && source_customer_ids.contains(&payment_method.customer_id)
+        })
+        .map(|payment_method| payment_method.payment_method_id.clone())
+        .collect();
+    let payout_ids: Vec<_> = db
+        .payouts
+        .lock()
+        .await
+        .iter_mut()
+        .filter(|payout| {
+            payout.merchant_id == *merchant_id
+                && (payout
+                    .customer_id
+                    .as_ref()
+                    .is_some_and(|customer_id| source_customer_ids.contains(customer_id))
+                    || payout
+                        .payout_method_id
+                        .as_ref()
+                        .is_some_and(|payout_method_id| {
+                            payment_method_ids.contains(payout_method_id)
+                        }))
+        })
+        .map(|payout| {
+            payout.customer_id = Some(target_customer_id.clone());
+            payout.payout_id.clone()
+        })
+        .collect();
+    db.payout_attempt
+        .lock()
+        .await
+        .iter_mut()
+        .filter(|payout_attempt| {
+            payout_attempt.merchant_id == *merchant_id
+                && (payout_attempt
+                    .customer_id
+                    .as_ref()
+                    .is_some_and(|customer_id| source_customer_ids.contains(customer_id))
+                    || payout_ids.contains(&payout_attempt.payout_id))
+        })
+        .for_each(|payout_attempt| payout_attempt.customer_id = Some(target_customer_id.clone()));
+}
@@ -233,7 +233,10 @@ impl DashboardMetadataInterface for MockDb {
                     && metadata.org_id == org_id
                     && metadata.data_key == data_key
             })
-            .ok_or(errors::StorageError::MockDbError)?;
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No dashboard_metadata available for user_id = {user_id:?},\
+                merchant_id = {merchant_id:?}, org_id = {org_id:?} and data_key = {data_key:?}",
+            )))?;
 
         match dashboard_metadata_update {
             storage::DashboardMetadataUpdate::UpdateData {
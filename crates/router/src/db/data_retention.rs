@@ -0,0 +1,898 @@
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::behaviour::Conversion;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait DataRetentionInterface {
+    async fn insert_data_retention_policy(
+        &self,
+        policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError>;
+
+    async fn find_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError>;
+
+    async fn list_data_retention_policies(
+        &self,
+    ) -> CustomResult<Vec<storage::DataRetentionPolicy>, errors::StorageError>;
+
+    async fn update_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+        policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError>;
+
+    async fn delete_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn find_archivable_records(
+        &self,
+        entity: enums::DataRetentionEntity,
+        window: storage::ArchivalWindow,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ArchivableRecord>, errors::StorageError>;
+
+    async fn delete_archivable_records(
+        &self,
+        entity: enums::DataRetentionEntity,
+        records: &[storage::ArchivableRecord],
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn insert_archived_records(
+        &self,
+        records: Vec<storage::ArchivedRecordNew>,
+    ) -> CustomResult<Vec<storage::ArchivedRecord>, errors::StorageError>;
+
+    async fn find_archived_records_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ArchivedRecord>, errors::StorageError>;
+
+    async fn list_table_partitions(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<Vec<storage::TablePartition>, errors::StorageError>;
+
+    async fn detach_table_partition(
+        &self,
+        entity: enums::DataRetentionEntity,
+        partition: &storage::TablePartition,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_data_retention_policy(
+        &self,
+        policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        policy
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionPolicy::find_by_entity(&conn, entity)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_data_retention_policies(
+        &self,
+    ) -> CustomResult<Vec<storage::DataRetentionPolicy>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionPolicy::list(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+        policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DataRetentionPolicy::update_by_entity(&conn, entity, policy_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DataRetentionPolicy::delete_by_entity(&conn, entity)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_archivable_records(
+        &self,
+        entity: enums::DataRetentionEntity,
+        window: storage::ArchivalWindow,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ArchivableRecord>, errors::StorageError> {
+        // Read from the primary, the records read are deleted right after being archived
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ArchivableRecord::find_by_entity(&conn, entity, window, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_archivable_records(
+        &self,
+        entity: enums::DataRetentionEntity,
+        records: &[storage::ArchivableRecord],
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ArchivableRecord::delete_by_entity(&conn, entity, records)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_archived_records(
+        &self,
+        records: Vec<storage::ArchivedRecordNew>,
+    ) -> CustomResult<Vec<storage::ArchivedRecord>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ArchivedRecordNew::batch_upsert(&conn, records)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_archived_records_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ArchivedRecord>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ArchivedRecord::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_table_partitions(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<Vec<storage::TablePartition>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::TablePartition::list_by_entity(&conn, entity)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn detach_table_partition(
+        &self,
+        entity: enums::DataRetentionEntity,
+        partition: &storage::TablePartition,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        partition
+            .detach(&conn, entity)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for MockDb {
+    async fn insert_data_retention_policy(
+        &self,
+        policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let mut policies = self.data_retention_policies.lock().await;
+        if policies
+            .iter()
+            .any(|existing| existing.entity == policy.entity)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "data_retention_policy",
+                key: Some(policy.entity.to_string()),
+            })?
+        }
+        let policy = storage::DataRetentionPolicy {
+            entity: policy.entity,
+            retention_days: policy.retention_days,
+            file_format: policy.file_format,
+            purge_mode: policy.purge_mode,
+            batch_size: policy.batch_size,
+            is_enabled: policy.is_enabled,
+            archived_count: policy.archived_count,
+            last_archived_at: policy.last_archived_at,
+            created_at: policy.created_at,
+            modified_at: policy.modified_at,
+        };
+        policies.push(policy.clone());
+        Ok(policy)
+    }
+
+    async fn find_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.data_retention_policies
+            .lock()
+            .await
+            .iter()
+            .find(|policy| policy.entity == entity)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No data retention policy found for entity = {entity}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_data_retention_policies(
+        &self,
+    ) -> CustomResult<Vec<storage::DataRetentionPolicy>, errors::StorageError> {
+        let mut policies = self.data_retention_policies.lock().await.clone();
+        policies.sort_by_key(|policy| policy.entity.to_string());
+        Ok(policies)
+    }
+
+    async fn update_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+        policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let mut policies = self.data_retention_policies.lock().await;
+        let policy = policies
+            .iter_mut()
+            .find(|policy| policy.entity == entity)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No data retention policy found for entity = {entity}"
+            )))?;
+        *policy = policy_update.apply_changeset(policy.clone());
+        Ok(policy.clone())
+    }
+
+    async fn delete_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut policies = self.data_retention_policies.lock().await;
+        let initial_count = policies.len();
+        policies.retain(|policy| policy.entity != entity);
+        if policies.len() == initial_count {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No data retention policy found for entity = {entity}"
+            )))?
+        }
+        Ok(true)
+    }
+
+    async fn find_archivable_records(
+        &self,
+        entity: enums::DataRetentionEntity,
+        window: storage::ArchivalWindow,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ArchivableRecord>, errors::StorageError> {
+        let in_window = |created_at: time::PrimitiveDateTime| {
+            created_at < window.created_before
+                && window
+                    .created_from
+                    .is_none_or(|created_from| created_at >= created_from)
+        };
+
+        let mut records = match entity {
+            enums::DataRetentionEntity::PaymentIntent => {
+                let payment_intents: Vec<_> = self
+                    .payment_intents
+                    .lock()
+                    .await
+                    .iter()
+                    .filter(|payment_intent| {
+                        payment_intent.status.is_in_terminal_state()
+                            && in_window(payment_intent.created_at)
+                    })
+                    .cloned()
+                    .collect();
+                let mut records = Vec::with_capacity(payment_intents.len());
+                for payment_intent in payment_intents {
+                    let payment_intent = payment_intent
+                        .convert()
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?;
+                    records.push(
+                        storage::ArchivableRecord::try_from(&payment_intent)
+                            .map_err(|error| report!(errors::StorageError::from(error)))?,
+                    );
+                }
+                records
+            }
+            enums::DataRetentionEntity::PaymentAttempt => {
+                let payment_attempts: Vec<_> = self
+                    .payment_attempts
+                    .lock()
+                    .await
+                    .iter()
+                    .filter(|payment_attempt| {
+                        payment_attempt.status.is_terminal_status()
+                            && in_window(payment_attempt.created_at)
+                    })
+                    .cloned()
+                    .collect();
+                let mut records = Vec::with_capacity(payment_attempts.len());
+                for payment_attempt in payment_attempts {
+                    let payment_attempt = payment_attempt
+                        .convert()
+                        .await
+                        .change_context(errors::StorageError::EncryptionError)?;
+                    records.push(
+                        storage::ArchivableRecord::try_from(&payment_attempt)
+                            .map_err(|error| report!(errors::StorageError::from(error)))?,
+                    );
+                }
+                records
+            }
+            enums::DataRetentionEntity::Refund => self
+                .refunds
+                .lock()
+                .await
+                .iter()
+                .filter(|refund| {
+                    matches!(
+                        refund.refund_status,
+                        enums::RefundStatus::Success
+                            | enums::RefundStatus::Failure
+                            | enums::RefundStatus::TransactionFailure
+                    ) && in_window(refund.created_at)
+                })
+                .map(storage::ArchivableRecord::try_from)
+                .collect::<Result<_, _>>()
+                .map_err(|error| report!(errors::StorageError::from(error)))?,
+            enums::DataRetentionEntity::Event => self
+                .events
+                .lock()
+                .await
+                .iter()
+                .filter(|event| in_window(event.created_at))
+                .map(storage::ArchivableRecord::try_from)
+                .collect::<Result<_, _>>()
+                .map_err(|error| report!(errors::StorageError::from(error)))?,
+            enums::DataRetentionEntity::ProcessTracker => self
+                .processes
+                .lock()
+                .await
+                .iter()
+                .filter(|process| {
+                    process.status == enums::ProcessTrackerStatus::Finish
+                        && in_window(process.created_at)
+                })
+                .map(storage::ArchivableRecord::try_from)
+                .collect::<Result<_, _>>()
+                .map_err(|error| report!(errors::StorageError::from(error)))?,
+        };
+
+        records.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.record_id.cmp(&b.record_id))
+        });
+        Ok(records
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(0))
+            .collect())
+    }
+
+    async fn delete_archivable_records(
+        &self,
+        entity: enums::DataRetentionEntity,
+        records: &[storage::ArchivableRecord],
+    ) -> CustomResult<usize, errors::StorageError> {
+        let is_archived = |record_id: &str, merchant_id: Option<&id_type::MerchantId>| {
+            records.iter().any(|record| {
+                record.record_id == record_id
+                    && merchant_id
+                        .is_none_or(|merchant_id| record.merchant_id.as_ref() == Some(merchant_id))
+            })
+        };
+
+        match entity {
+            enums::DataRetentionEntity::PaymentIntent => {
+                let mut payment_intents = self.payment_intents.lock().await;
+                let initial_count = payment_intents.len();
+                payment_intents.retain(|payment_intent| {
+                    #[cfg(feature = "v1")]
+                    let record_id = payment_intent.payment_id.get_string_repr();
+                    #[cfg(feature = "v2")]
+                    let record_id = payment_intent.id.get_string_repr();
+                    !is_archived(record_id, Some(&payment_intent.merchant_id))
+                });
+                Ok(initial_count - payment_intents.len())
+            }
+            enums::DataRetentionEntity::PaymentAttempt => {
+                let mut payment_attempts = self.payment_attempts.lock().await;
+                let initial_count = payment_attempts.len();
+                payment_attempts.retain(|payment_attempt| {
+                    #[cfg(feature = "v1")]
+                    let record_id = payment_attempt.attempt_id.as_str();
+                    #[cfg(feature = "v2")]
+                    let record_id = payment_attempt.id.get_string_repr();
+                    !is_archived(record_id, Some(&payment_attempt.merchant_id))
+                });
+                Ok(initial_count - payment_attempts.len())
+            }
+            enums::DataRetentionEntity::Refund => {
+                let mut refunds = self.refunds.lock().await;
+                let initial_count = refunds.len();
+                refunds.retain(|refund| {
+                    #[cfg(feature = "v1")]
+                    let record_id = refund.refund_id.as_str();
+                    #[cfg(feature = "v2")]
+                    let record_id = refund.id.get_string_repr();
+                    !is_archived(record_id, Some(&refund.merchant_id))
+                });
+                Ok(initial_count - refunds.len())
+            }
+            enums::DataRetentionEntity::Event => {
+                let mut events = self.events.lock().await;
+                let initial_count = events.len();
+                events.retain(|event| !is_archived(&event.event_id, None));
+                Ok(initial_count - events.len())
+            }
+            enums::DataRetentionEntity::ProcessTracker => {
+                let mut processes = self.processes.lock().await;
+                let initial_count = processes.len();
+                processes.retain(|process| !is_archived(&process.id, None));
+                Ok(initial_count - processes.len())
+            }
+        }
+    }
+
+    async fn insert_archived_records(
+        &self,
+        records: Vec<storage::ArchivedRecordNew>,
+    ) -> CustomResult<Vec<storage::ArchivedRecord>, errors::StorageError> {
+        let mut archived_records = self.archived_records.lock().await;
+        let inserted: Vec<_> = records
+            .into_iter()
+            .map(|record| storage::ArchivedRecord {
+                merchant_id: record.merchant_id,
+                entity: record.entity,
+                record_id: record.record_id,
+                payment_id: record.payment_id,
+                file_key: record.file_key,
+                file_format: record.file_format,
+                record_created_at: record.record_created_at,
+                archived_at: record.archived_at,
+            })
+            .collect();
+        archived_records.retain(|existing| {
+            !inserted.iter().any(|record| {
+                record.merchant_id == existing.merchant_id
+                    && record.entity == existing.entity
+                    && record.record_id == existing.record_id
+            })
+        });
+        archived_records.extend(inserted.iter().cloned());
+        Ok(inserted)
+    }
+
+    async fn find_archived_records_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ArchivedRecord>, errors::StorageError> {
+        let mut records: Vec<_> = self
+            .archived_records
+            .lock()
+            .await
+            .iter()
+            .filter(|record| {
+                &record.merchant_id == merchant_id
+                    && record.payment_id.as_deref() == Some(payment_id)
+            })
+            .cloned()
+            .collect();
+        records.sort_by_key(|record| record.record_created_at);
+        Ok(records)
+    }
+
+    async fn list_table_partitions(
+        &self,
+        _entity: enums::DataRetentionEntity,
+    ) -> CustomResult<Vec<storage::TablePartition>, errors::StorageError> {
+        // The in-memory tables are not partitioned
+        Ok(Vec::new())
+    }
+
+    async fn detach_table_partition(
+        &self,
+        _entity: enums::DataRetentionEntity,
+        partition: &storage::TablePartition,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::ValueNotFound(format!(
+            "No partition found with name = {}",
+            partition.name
+        )))?
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_data_retention_policy(
+        &self,
+        policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.diesel_store.insert_data_retention_policy(policy).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.diesel_store
+            .find_data_retention_policy_by_entity(entity)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_data_retention_policies(
+        &self,
+    ) -> CustomResult<Vec<storage::DataRetentionPolicy>, errors::StorageError> {
+        self.diesel_store.list_data_retention_policies().await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+        policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.diesel_store
+            .update_data_retention_policy_by_entity(entity, policy_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_data_retention_policy_by_entity(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_data_retention_policy_by_entity(entity)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_archivable_records(
+        &self,
+        entity: enums::DataRetentionEntity,
+        window: storage::ArchivalWindow,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ArchivableRecord>, errors::StorageError> {
+        self.diesel_store
+            .find_archivable_records(entity, window, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_archivable_records(
+        &self,
+        entity: enums::DataRetentionEntity,
+        records: &[storage::ArchivableRecord],
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_archivable_records(entity, records)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_archived_records(
+        &self,
+        records: Vec<storage::ArchivedRecordNew>,
+    ) -> CustomResult<Vec<storage::ArchivedRecord>, errors::StorageError> {
+        self.diesel_store.insert_archived_records(records).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_archived_records_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ArchivedRecord>, errors::StorageError> {
+        self.diesel_store
+            .find_archived_records_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_table_partitions(
+        &self,
+        entity: enums::DataRetentionEntity,
+    ) -> CustomResult<Vec<storage::TablePartition>, errors::StorageError> {
+        self.diesel_store.list_table_partitions(entity).await
+    }
+
+    #[instrument(skip_all)]
+    async fn detach_table_partition(
+        &self,
+        entity: enums::DataRetentionEntity,
+        partition: &storage::TablePartition,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .detach_table_partition(entity, partition)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use common_utils::types::keymanager::KeyManagerState;
+    use scheduler::db::process_tracker::ProcessTrackerInterface;
+    use time::macros::datetime;
+
+    use super::*;
+
+    async fn get_mock_db() -> MockDb {
+        MockDb::new(
+            &redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            },
+            KeyManagerState::mock(),
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn insert_process(
+        db: &MockDb,
+        id: &str,
+        status: enums::ProcessTrackerStatus,
+        created_at: time::PrimitiveDateTime,
+    ) {
+        let process = db
+            .insert_process(
+                storage::ProcessTrackerNew::new(
+                    id,
+                    "PAYMENTS_SYNC",
+                    storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+                    ["PAYMENT"],
+                    "tracking_data",
+                    None,
+                    created_at,
+                    common_types::consts::API_VERSION,
+                    enums::ApplicationSource::Main,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let mut processes = db.processes.lock().await;
+        if let Some(inserted) = processes
+            .iter_mut()
+            .find(|inserted| inserted.id == process.id)
+        {
+            inserted.status = status;
+            inserted.created_at = created_at;
+        }
+    }
+
+    fn get_archived_record(
+        record_id: &str,
+        file_key: &str,
+        record_created_at: time::PrimitiveDateTime,
+    ) -> storage::ArchivedRecordNew {
+        storage::ArchivedRecordNew {
+            merchant_id: id_type::MerchantId::default(),
+            entity: enums::DataRetentionEntity::Refund,
+            record_id: record_id.to_string(),
+            payment_id: Some("pay_1".to_string()),
+            file_key: file_key.to_string(),
+            file_format: enums::ArchiveFileFormat::Ndjson,
+            record_created_at,
+            archived_at: datetime!(2026-10-01 00:00:00),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_data_retention_policy_lifecycle() {
+        let db = get_mock_db().await;
+        let now = datetime!(2026-10-01 00:00:00);
+        let policy = storage::DataRetentionPolicyNew {
+            entity: enums::DataRetentionEntity::Event,
+            retention_days: 90,
+            file_format: enums::ArchiveFileFormat::Ndjson,
+            purge_mode: enums::DataPurgeMode::Delete,
+            batch_size: 1000,
+            is_enabled: true,
+            archived_count: 0,
+            last_archived_at: None,
+            created_at: now,
+            modified_at: now,
+        };
+
+        db.insert_data_retention_policy(policy.clone())
+            .await
+            .unwrap();
+        assert!(db
+            .insert_data_retention_policy(policy)
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_unique_violation());
+
+        let last_archived_at = datetime!(2026-10-02 00:00:00);
+        let updated = db
+            .update_data_retention_policy_by_entity(
+                enums::DataRetentionEntity::Event,
+                storage::DataRetentionPolicyUpdate::ArchivalCompleted {
+                    archived_count: 42,
+                    last_archived_at,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.archived_count, 42);
+        assert_eq!(updated.last_archived_at, Some(last_archived_at));
+        assert_eq!(updated.retention_days, 90);
+
+        assert!(db
+            .delete_data_retention_policy_by_entity(enums::DataRetentionEntity::Event)
+            .await
+            .unwrap());
+        assert!(db
+            .find_data_retention_policy_by_entity(enums::DataRetentionEntity::Event)
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_only_finished_processes_past_retention_are_archived() {
+        let db = get_mock_db().await;
+        insert_process(
+            &db,
+            "pt_finished_old",
+            enums::ProcessTrackerStatus::Finish,
+            datetime!(2025-01-01 00:00:00),
+        )
+        .await;
+        insert_process(
+            &db,
+            "pt_pending_old",
+            enums::ProcessTrackerStatus::Pending,
+            datetime!(2025-01-01 00:00:00),
+        )
+        .await;
+        insert_process(
+            &db,
+            "pt_finished_recent",
+            enums::ProcessTrackerStatus::Finish,
+            datetime!(2026-10-01 00:00:00),
+        )
+        .await;
+        let window = storage::ArchivalWindow {
+            created_from: None,
+            created_before: datetime!(2026-01-01 00:00:00),
+        };
+
+        let records = db
+            .find_archivable_records(enums::DataRetentionEntity::ProcessTracker, window, 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        let record = records.first().unwrap();
+        assert_eq!(record.record_id, "pt_finished_old");
+        assert!(record.merchant_id.is_none());
+        assert_eq!(
+            record.data.get("id").and_then(serde_json::Value::as_str),
+            Some("pt_finished_old")
+        );
+
+        let deleted = db
+            .delete_archivable_records(enums::DataRetentionEntity::ProcessTracker, &records)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 1);
+        assert!(db
+            .find_archivable_records(enums::DataRetentionEntity::ProcessTracker, window, 10, 0)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(db.processes.lock().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_archived_records_are_reindexed() {
+        let db = get_mock_db().await;
+        db.insert_archived_records(vec![
+            get_archived_record(
+                "ref_2",
+                "archive/1.ndjson.gz",
+                datetime!(2025-01-02 00:00:00),
+            ),
+            get_archived_record(
+                "ref_1",
+                "archive/1.ndjson.gz",
+                datetime!(2025-01-01 00:00:00),
+            ),
+        ])
+        .await
+        .unwrap();
+        // A batch archived again points its records to the new file
+        db.insert_archived_records(vec![get_archived_record(
+            "ref_2",
+            "archive/2.ndjson.gz",
+            datetime!(2025-01-02 00:00:00),
+        )])
+        .await
+        .unwrap();
+
+        let records = db
+            .find_archived_records_by_merchant_id_payment_id(
+                &id_type::MerchantId::default(),
+                "pay_1",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| (record.record_id.as_str(), record.file_key.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("ref_1", "archive/1.ndjson.gz"),
+                ("ref_2", "archive/2.ndjson.gz"),
+            ]
+        );
+        assert!(db
+            .find_archived_records_by_merchant_id_payment_id(
+                &id_type::MerchantId::try_from(Cow::from("merchant_2")).unwrap(),
+                "pay_1"
+            )
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
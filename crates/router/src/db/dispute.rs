@@ -162,7 +162,10 @@ impl DisputeInterface for MockDb {
         &self,
         dispute: storage::DisputeNew,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let evidence = dispute.evidence.ok_or(errors::StorageError::MockDbError)?;
+        // The evidence column defaults to an empty object
+        let evidence = dispute
+            .evidence
+            .unwrap_or_else(|| hyperswitch_masking::Secret::new(serde_json::json!({})));
 
         let mut locked_disputes = self.disputes.lock().await;
 
@@ -170,7 +173,10 @@ impl DisputeInterface for MockDb {
             .iter()
             .any(|d| d.dispute_id == dispute.dispute_id)
         {
-            Err(errors::StorageError::MockDbError)?;
+            Err(errors::StorageError::DuplicateValue {
+                entity: "dispute_id",
+                key: Some(dispute.dispute_id.clone()),
+            })?;
         }
 
         let now = common_utils::date_time::now();
@@ -356,7 +362,10 @@ impl DisputeInterface for MockDb {
         let dispute_to_update = locked_disputes
             .iter_mut()
             .find(|d| d.dispute_id == this.dispute_id)
-            .ok_or(errors::StorageError::MockDbError)?;
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No dispute available for dispute_id = {}",
+                this.dispute_id
+            )))?;
 
         let now = common_utils::date_time::now();
 
@@ -0,0 +1,187 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait DisputeAutomatedActionInterface {
+    async fn insert_dispute_automated_action(
+        &self,
+        action: storage::DisputeAutomatedActionNew,
+    ) -> CustomResult<storage::DisputeAutomatedAction, errors::StorageError>;
+
+    async fn update_dispute_automated_action_by_action_id(
+        &self,
+        action_id: &str,
+        action_update: storage::DisputeAutomatedActionUpdate,
+    ) -> CustomResult<storage::DisputeAutomatedAction, errors::StorageError>;
+
+    async fn list_dispute_automated_actions_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::DisputeAutomatedAction>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DisputeAutomatedActionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_dispute_automated_action(
+        &self,
+        action: storage::DisputeAutomatedActionNew,
+    ) -> CustomResult<storage::DisputeAutomatedAction, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        action
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_dispute_automated_action_by_action_id(
+        &self,
+        action_id: &str,
+        action_update: storage::DisputeAutomatedActionUpdate,
+    ) -> CustomResult<storage::DisputeAutomatedAction, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DisputeAutomatedAction::update_by_action_id(&conn, action_id, action_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_dispute_automated_actions_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::DisputeAutomatedAction>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DisputeAutomatedAction::list_by_merchant_id_dispute_id(
+            &conn,
+            merchant_id,
+            dispute_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DisputeAutomatedActionInterface for MockDb {
+    async fn insert_dispute_automated_action(
+        &self,
+        action: storage::DisputeAutomatedActionNew,
+    ) -> CustomResult<storage::DisputeAutomatedAction, errors::StorageError> {
+        let mut dispute_automated_actions = self.dispute_automated_actions.lock().await;
+        if dispute_automated_actions
+            .iter()
+            .any(|existing| existing.action_id == action.action_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "action_id",
+                key: Some(action.action_id.clone()),
+            })?
+        }
+        if dispute_automated_actions.iter().any(|existing| {
+            existing.merchant_id == action.merchant_id && existing.dispute_id == action.dispute_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "dispute_id",
+                key: Some(action.dispute_id.clone()),
+            })?
+        }
+        let action = storage::DisputeAutomatedAction {
+            action_id: action.action_id,
+            dispute_id: action.dispute_id,
+            merchant_id: action.merchant_id,
+            profile_id: action.profile_id,
+            rule_name: action.rule_name,
+            action: action.action,
+            status: action.status,
+            error_message: action.error_message,
+            created_at: action.created_at,
+        };
+        dispute_automated_actions.push(action.clone());
+        Ok(action)
+    }
+
+    async fn update_dispute_automated_action_by_action_id(
+        &self,
+        action_id: &str,
+        action_update: storage::DisputeAutomatedActionUpdate,
+    ) -> CustomResult<storage::DisputeAutomatedAction, errors::StorageError> {
+        let mut dispute_automated_actions = self.dispute_automated_actions.lock().await;
+        let action = dispute_automated_actions
+            .iter_mut()
+            .find(|action| action.action_id == action_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No dispute automated action found for action_id = {action_id}"
+            )))?;
+        let storage::DisputeAutomatedActionUpdateInternal {
+            status,
+            error_message,
+        } = action_update.into();
+        action.status = status;
+        action.error_message = error_message;
+        Ok(action.clone())
+    }
+
+    async fn list_dispute_automated_actions_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::DisputeAutomatedAction>, errors::StorageError> {
+        let mut actions: Vec<_> = self
+            .dispute_automated_actions
+            .lock()
+            .await
+            .iter()
+            .filter(|action| action.merchant_id == *merchant_id && action.dispute_id == dispute_id)
+            .cloned()
+            .collect();
+        actions.sort_by_key(|action| action.created_at);
+        Ok(actions)
+    }
+}
+
+#[async_trait::async_trait]
+impl DisputeAutomatedActionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_dispute_automated_action(
+        &self,
+        action: storage::DisputeAutomatedActionNew,
+    ) -> CustomResult<storage::DisputeAutomatedAction, errors::StorageError> {
+        self.diesel_store
+            .insert_dispute_automated_action(action)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_dispute_automated_action_by_action_id(
+        &self,
+        action_id: &str,
+        action_update: storage::DisputeAutomatedActionUpdate,
+    ) -> CustomResult<storage::DisputeAutomatedAction, errors::StorageError> {
+        self.diesel_store
+            .update_dispute_automated_action_by_action_id(action_id, action_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_dispute_automated_actions_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::DisputeAutomatedAction>, errors::StorageError> {
+        self.diesel_store
+            .list_dispute_automated_actions_by_merchant_id_dispute_id(merchant_id, dispute_id)
+            .await
+    }
+}
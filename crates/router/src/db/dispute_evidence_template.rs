@@ -0,0 +1,226 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait DisputeEvidenceTemplateInterface {
+    async fn insert_dispute_evidence_template(
+        &self,
+        template: storage::DisputeEvidenceTemplateNew,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError>;
+
+    async fn find_dispute_evidence_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError>;
+
+    async fn list_dispute_evidence_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::DisputeEvidenceTemplate>, errors::StorageError>;
+
+    async fn delete_dispute_evidence_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DisputeEvidenceTemplateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_dispute_evidence_template(
+        &self,
+        template: storage::DisputeEvidenceTemplateNew,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        template
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_dispute_evidence_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DisputeEvidenceTemplate::find_by_merchant_id_template_id(
+            &conn,
+            merchant_id,
+            template_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_dispute_evidence_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::DisputeEvidenceTemplate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DisputeEvidenceTemplate::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_dispute_evidence_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DisputeEvidenceTemplate::delete_by_merchant_id_template_id(
+            &conn,
+            merchant_id,
+            template_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DisputeEvidenceTemplateInterface for MockDb {
+    async fn insert_dispute_evidence_template(
+        &self,
+        template: storage::DisputeEvidenceTemplateNew,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        let mut templates = self.dispute_evidence_templates.lock().await;
+        if templates
+            .iter()
+            .any(|existing| existing.template_id == template.template_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "dispute_evidence_template",
+                key: Some(template.template_id.clone()),
+            })?
+        }
+        let template = storage::DisputeEvidenceTemplate {
+            template_id: template.template_id,
+            merchant_id: template.merchant_id,
+            profile_id: template.profile_id,
+            name: template.name,
+            connector: template.connector,
+            reason_codes: template.reason_codes,
+            evidence_sources: template.evidence_sources,
+            text_evidence: template.text_evidence,
+            created_at: template.created_at,
+            modified_at: template.modified_at,
+        };
+        templates.push(template.clone());
+        Ok(template)
+    }
+
+    async fn find_dispute_evidence_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        self.dispute_evidence_templates
+            .lock()
+            .await
+            .iter()
+            .find(|template| {
+                template.merchant_id == *merchant_id && template.template_id == template_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No dispute evidence template found for template_id = {template_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_dispute_evidence_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::DisputeEvidenceTemplate>, errors::StorageError> {
+        let mut templates = self
+            .dispute_evidence_templates
+            .lock()
+            .await
+            .iter()
+            .filter(|template| template.merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        templates.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+        Ok(templates)
+    }
+
+    async fn delete_dispute_evidence_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        let mut templates = self.dispute_evidence_templates.lock().await;
+        let position = templates
+            .iter()
+            .position(|template| {
+                template.merchant_id == *merchant_id && template.template_id == template_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No dispute evidence template found for template_id = {template_id}"
+            )))?;
+        Ok(templates.remove(position))
+    }
+}
+
+#[async_trait::async_trait]
+impl DisputeEvidenceTemplateInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_dispute_evidence_template(
+        &self,
+        template: storage::DisputeEvidenceTemplateNew,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        self.diesel_store
+            .insert_dispute_evidence_template(template)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_dispute_evidence_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        self.diesel_store
+            .find_dispute_evidence_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_dispute_evidence_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::DisputeEvidenceTemplate>, errors::StorageError> {
+        self.diesel_store
+            .list_dispute_evidence_templates_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_dispute_evidence_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::DisputeEvidenceTemplate, errors::StorageError> {
+        self.diesel_store
+            .delete_dispute_evidence_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
+    }
+}
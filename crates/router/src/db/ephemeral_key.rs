@@ -1,5 +1,7 @@
 #[cfg(feature = "v2")]
 use common_utils::id_type;
+#[cfg(feature = "v2")]
+use hyperswitch_masking::PeekInterface;
 use time::ext::NumericalDuration;
 
 #[cfg(feature = "v2")]
@@ -352,7 +354,26 @@ impl ClientSecretInterface for MockDb {
         new: ClientSecretTypeNew,
         validity: i64,
     ) -> CustomResult<ClientSecretType, errors::StorageError> {
-        todo!()
+        let mut client_secrets = self.client_secrets.lock().await;
+        if client_secrets.iter().any(|client_secret| {
+            client_secret.id == new.id || client_secret.secret.peek() == new.secret.peek()
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "ephemeral key",
+                key: None,
+            })?
+        }
+        let created_at = common_utils::date_time::now();
+        let client_secret = ClientSecretType {
+            id: new.id,
+            created_at,
+            expires: created_at.saturating_add(validity.hours()),
+            merchant_id: new.merchant_id,
+            secret: new.secret,
+            resource_id: new.resource_id,
+        };
+        client_secrets.push(client_secret.clone());
+        Ok(client_secret)
     }
 
     #[cfg(feature = "v2")]
@@ -360,7 +381,16 @@ impl ClientSecretInterface for MockDb {
         &self,
         key: &str,
     ) -> CustomResult<ClientSecretType, errors::StorageError> {
-        todo!()
+        let now = common_utils::date_time::now();
+        self.client_secrets
+            .lock()
+            .await
+            .iter()
+            .find(|client_secret| client_secret.secret.peek() == key && client_secret.expires > now)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound("client secret not found".to_string()).into(),
+            )
     }
 
     #[cfg(feature = "v2")]
@@ -368,6 +398,11 @@ impl ClientSecretInterface for MockDb {
         &self,
         id: &str,
     ) -> CustomResult<ClientSecretType, errors::StorageError> {
-        todo!()
+        let client_secret = self.get_client_secret(id).await?;
+        self.client_secrets
+            .lock()
+            .await
+            .retain(|existing| existing.id != client_secret.id);
+        Ok(client_secret)
     }
 }
@@ -30,6 +30,14 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Event, errors::StorageError>;
 
+    /// Inserts the event of an outgoing webhook along with the outbox entry the outbox relay
+    /// delivers the webhook from
+    async fn insert_event_with_outbox(
+        &self,
+        event: domain::Event,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::Event, errors::StorageError>;
+
     async fn find_event_by_merchant_id_event_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -150,6 +158,30 @@ impl EventInterface for Store {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[instrument(skip_all)]
+    async fn insert_event_with_outbox(
+        &self,
+        event: domain::Event,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::Event, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        event
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert_with_outbox(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     #[instrument(skip_all)]
     async fn find_event_by_merchant_id_event_id(
         &self,
@@ -515,6 +547,59 @@ impl EventInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn insert_event_with_outbox(
+        &self,
+        event: domain::Event,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::Event, errors::StorageError> {
+        let stored_event = Conversion::convert(event)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let storage::OutboxNew {
+            outbox_id,
+            dedupe_key,
+            aggregate_type,
+            aggregate_id,
+            merchant_id,
+            operation,
+            payload,
+            status,
+            attempts,
+            created_at,
+        } = storage::OutboxNew::for_aggregate(
+            &stored_event,
+            storage::enums::OutboxOperation::Insert,
+        )
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        self.events.lock().await.push(stored_event.clone());
+        self.outbox_entries.lock().await.push(storage::Outbox {
+            outbox_id,
+            dedupe_key,
+            aggregate_type,
+            aggregate_id,
+            merchant_id,
+            operation,
+            payload,
+            status,
+            attempts,
+            last_error: None,
+            created_at,
+            published_at: None,
+            next_attempt_at: None,
+        });
+
+        stored_event
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     async fn find_event_by_merchant_id_event_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -893,7 +978,9 @@ impl EventInterface for MockDb {
             .find(|event| {
                 event.merchant_id == Some(merchant_id.to_owned()) && event.event_id == event_id
             })
-            .ok_or(errors::StorageError::MockDbError)?;
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No event available with merchant_id = {merchant_id:?} and event_id  = {event_id}"
+            )))?;
 
         match event {
             domain::EventUpdate::UpdateResponse {
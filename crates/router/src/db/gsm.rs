@@ -158,53 +158,188 @@ impl GsmInterface for Store {
 impl GsmInterface for MockDb {
     async fn add_gsm_rule(
         &self,
-        _rule: hyperswitch_domain_models::gsm::GatewayStatusMap,
+        rule: hyperswitch_domain_models::gsm::GatewayStatusMap,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let rule = diesel_models::gsm::GatewayStatusMappingNew::try_from(rule)
+            .change_context(errors::StorageError::SerializationFailed)
+            .attach_printable("Failed to convert gsm domain models to diesel models")?;
+        let mut gateway_status_maps = self.gateway_status_maps.lock().await;
+        if gateway_status_maps.iter().any(|gsm| {
+            is_gsm_rule_of(
+                gsm,
+                &rule.connector,
+                &rule.flow,
+                &rule.sub_flow,
+                &rule.code,
+                &rule.message,
+            )
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "gsm_rule",
+                key: None,
+            })?
+        }
+        let now = common_utils::date_time::now();
+        let gsm_db_record = storage::GatewayStatusMap {
+            connector: rule.connector,
+            flow: rule.flow,
+            sub_flow: rule.sub_flow,
+            code: rule.code,
+            message: rule.message,
+            status: rule.status,
+            router_error: rule.router_error,
+            decision: rule.decision,
+            created_at: now,
+            last_modified: now,
+            step_up_possible: rule.step_up_possible,
+            unified_code: rule.unified_code,
+            unified_message: rule.unified_message,
+            error_category: rule.error_category,
+            clear_pan_possible: rule.clear_pan_possible,
+            feature_data: rule.feature_data,
+            feature: rule.feature,
+            standardised_code: rule.standardised_code,
+            description: rule.description,
+            user_guidance_message: rule.user_guidance_message,
+        };
+        gateway_status_maps.push(gsm_db_record.clone());
+
+        hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
     async fn find_gsm_decision(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<String, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.gateway_status_maps
+            .lock()
+            .await
+            .iter()
+            .find(|gsm| is_gsm_rule_of(gsm, &connector, &flow, &sub_flow, &code, &message))
+            .map(|gsm| gsm.decision.clone())
+            .ok_or(errors::StorageError::ValueNotFound("No gsm rule found".to_string()).into())
     }
 
     async fn find_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let gsm_db_record = self
+            .gateway_status_maps
+            .lock()
+            .await
+            .iter()
+            .find(|gsm| is_gsm_rule_of(gsm, &connector, &flow, &sub_flow, &code, &message))
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(
+                "No gsm rule found".to_string(),
+            ))?;
+
+        hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
     async fn update_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
-        _data: hyperswitch_domain_models::gsm::GatewayStatusMappingUpdate,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+        data: hyperswitch_domain_models::gsm::GatewayStatusMappingUpdate,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let gsm_update_data = diesel_models::gsm::GatewayStatusMappingUpdate::try_from(data)
+            .change_context(errors::StorageError::SerializationFailed)?;
+        let storage::GatewayStatusMapperUpdateInternal {
+            status,
+            router_error,
+            decision,
+            step_up_possible,
+            unified_code,
+            unified_message,
+            error_category,
+            last_modified,
+            clear_pan_possible,
+            feature_data,
+            feature,
+            standardised_code,
+            description,
+            user_guidance_message,
+            ..
+        } = gsm_update_data.into();
+
+        let mut gateway_status_maps = self.gateway_status_maps.lock().await;
+        let gsm = gateway_status_maps
+            .iter_mut()
+            .find(|gsm| is_gsm_rule_of(gsm, &connector, &flow, &sub_flow, &code, &message))
+            .ok_or(errors::StorageError::ValueNotFound(
+                "No gsm rule found".to_string(),
+            ))?;
+        *gsm = storage::GatewayStatusMap {
+            status: status.unwrap_or(gsm.status.clone()),
+            router_error: router_error.unwrap_or(gsm.router_error.clone()),
+            decision: decision.unwrap_or(gsm.decision.clone()),
+            step_up_possible: step_up_possible.unwrap_or(gsm.step_up_possible),
+            unified_code: unified_code.or(gsm.unified_code.clone()),
+            unified_message: unified_message.or(gsm.unified_message.clone()),
+            error_category: error_category.or(gsm.error_category),
+            last_modified,
+            clear_pan_possible: clear_pan_possible.unwrap_or(gsm.clear_pan_possible),
+            feature_data: feature_data.or(gsm.feature_data.clone()),
+            feature: feature.or(gsm.feature),
+            standardised_code: standardised_code.or(gsm.standardised_code),
+            description: description.or(gsm.description.clone()),
+            user_guidance_message: user_guidance_message.or(gsm.user_guidance_message.clone()),
+            ..gsm.clone()
+        };
+
+        hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm.clone())
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
     async fn delete_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<bool, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut gateway_status_maps = self.gateway_status_maps.lock().await;
+        let position = gateway_status_maps
+            .iter()
+            .position(|gsm| is_gsm_rule_of(gsm, &connector, &flow, &sub_flow, &code, &message))
+            .ok_or(errors::StorageError::ValueNotFound(
+                "No gsm rule found".to_string(),
+            ))?;
+        gateway_status_maps.remove(position);
+        Ok(true)
     }
 }
+
+fn is_gsm_rule_of(
+    gsm: &storage::GatewayStatusMap,
+    connector: &str,
+    flow: &str,
+    sub_flow: &str,
+    code: &str,
+    message: &str,
+) -> bool {
+    gsm.connector == connector
+        && gsm.flow == flow
+        && gsm.sub_flow == sub_flow
+        && gsm.code == code
+        && gsm.message == message
+}
@@ -10,6 +10,7 @@ use common_utils::{
 #[cfg(feature = "v2")]
 use diesel_models::ephemeral_key::{ClientSecretType, ClientSecretTypeNew};
 use diesel_models::{
+    billing_catalog,
     enums::{self, ProcessTrackerStatus},
     ephemeral_key::{EphemeralKey, EphemeralKeyNew},
     refund as diesel_refund,
@@ -24,6 +25,8 @@ use hyperswitch_domain_models::payouts::{
 #[cfg(feature = "v2")]
 use hyperswitch_domain_models::platform::Initiator;
 use hyperswitch_domain_models::{
+    billing_catalog::BillingCatalogInterface,
+    bin_range::BinRangeInterface,
     cards_info::CardsInfoInterface,
     disputes,
     invoice::{Invoice as DomainInvoice, InvoiceInterface, InvoiceUpdate as DomainInvoiceUpdate},
@@ -58,6 +61,8 @@ use super::{
     user_key_store::UserKeyStoreInterface,
     user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload, UserRoleInterface},
 };
+#[cfg(feature = "v1")]
+use crate::core::ledger;
 #[cfg(feature = "payouts")]
 use crate::services::kafka::payout::KafkaPayout;
 use crate::{
@@ -105,6 +110,7 @@ pub struct KafkaStore {
     pub kafka_producer: KafkaProducer,
     pub diesel_store: Store,
     pub tenant_id: TenantID,
+    pub application_source: common_enums::ApplicationSource,
 }
 
 impl KafkaStore {
@@ -113,12 +119,33 @@ impl KafkaStore {
         mut kafka_producer: KafkaProducer,
         tenant_id: TenantID,
         tenant_config: &dyn TenantConfig,
+        application_source: common_enums::ApplicationSource,
     ) -> Self {
         kafka_producer.set_tenancy(tenant_config);
         Self {
             kafka_producer,
             diesel_store: store,
             tenant_id,
+            application_source,
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    async fn schedule_ledger_posting(
+        &self,
+        tracking_data: Option<ledger::journal::LedgerPostingTrackingData>,
+    ) {
+        let Some(tracking_data) = tracking_data else {
+            return;
+        };
+        if let Err(er) = ledger::journal::schedule_ledger_posting(
+            &self.diesel_store,
+            self.application_source,
+            tracking_data,
+        )
+        .await
+        {
+            logger::error!(message="Failed to schedule ledger posting", error_message=?er);
         }
     }
 }
@@ -272,6 +299,29 @@ impl ApiKeyInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl BinRangeInterface for KafkaStore {
+    type Error = errors::StorageError;
+    async fn find_bin_range_by_card_number(
+        &self,
+        card_number: &str,
+    ) -> CustomResult<Option<storage::BinRange>, errors::StorageError> {
+        self.diesel_store
+            .find_bin_range_by_card_number(card_number)
+            .await
+    }
+
+    async fn replace_bin_ranges_by_source(
+        &self,
+        source: &str,
+        bin_ranges: Vec<storage::BinRangeNew>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .replace_bin_ranges_by_source(source, bin_ranges)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl CardsInfoInterface for KafkaStore {
     type Error = errors::StorageError;
@@ -622,6 +672,8 @@ impl DisputeInterface for KafkaStore {
             .diesel_store
             .update_dispute(this.clone(), dispute)
             .await?;
+        #[cfg(feature = "v1")]
+        let ledger_posting = ledger::journal::get_dispute_posting(&this, &dispute_new);
         if let Err(er) = self
             .kafka_producer
             .log_dispute(&dispute_new, Some(this), self.tenant_id.clone())
@@ -629,6 +681,8 @@ impl DisputeInterface for KafkaStore {
         {
             logger::error!(message="Failed to add analytics entry for Dispute {dispute_new:?}", error_message=?er);
         };
+        #[cfg(feature = "v1")]
+        self.schedule_ledger_posting(ledger_posting).await;
 
         Ok(dispute_new)
     }
@@ -723,6 +777,16 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    async fn insert_event_with_outbox(
+        &self,
+        event: domain::Event,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::Event, errors::StorageError> {
+        self.diesel_store
+            .insert_event_with_outbox(event, merchant_key_store)
+            .await
+    }
+
     async fn find_event_by_merchant_id_event_id(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -1860,6 +1924,8 @@ impl PaymentIntentInterface for KafkaStore {
             .diesel_store
             .get_keymanager_state()
             .attach_printable("Missing KeyManagerState")?;
+        #[cfg(feature = "v1")]
+        let ledger_posting = ledger::journal::get_payment_capture_posting(&this, &intent);
         if let Err(er) = self
             .kafka_producer
             .log_payment_intent(
@@ -1872,6 +1938,8 @@ impl PaymentIntentInterface for KafkaStore {
         {
             logger::error!(message="Failed to add analytics entry for Payment Intent {intent:?}", error_message=?er);
         };
+        #[cfg(feature = "v1")]
+        self.schedule_ledger_posting(ledger_posting).await;
 
         Ok(intent)
     }
@@ -2489,6 +2557,13 @@ impl PayoutsInterface for KafkaStore {
         {
             logger::error!(message="Failed to update analytics entry for Payouts {payout:?}\n{payout_attempt:?}", error_message=?err);
         };
+        #[cfg(feature = "v1")]
+        self.schedule_ledger_posting(ledger::journal::get_payout_posting(
+            this,
+            &payout,
+            payout_attempt,
+        ))
+        .await;
         Ok(payout)
     }
 
@@ -2796,6 +2871,8 @@ impl RefundInterface for KafkaStore {
             .diesel_store
             .update_refund(this.clone(), refund, storage_scheme)
             .await?;
+        #[cfg(feature = "v1")]
+        let ledger_posting = ledger::journal::get_refund_posting(&this, &refund);
 
         if let Err(er) = self
             .kafka_producer
@@ -2804,6 +2881,8 @@ impl RefundInterface for KafkaStore {
         {
             logger::error!(message="Failed to insert analytics event for Refund Update {refund?}", error_message=?er);
         }
+        #[cfg(feature = "v1")]
+        self.schedule_ledger_posting(ledger_posting).await;
         Ok(refund)
     }
 
@@ -4519,3 +4598,184 @@ impl SubscriptionInterface for KafkaStore {
             .await
     }
 }
+
+#[async_trait::async_trait]
+impl BillingCatalogInterface for KafkaStore {
+    type Error = errors::StorageError;
+
+    async fn insert_billing_product(
+        &self,
+        product: billing_catalog::BillingProductNew,
+    ) -> CustomResult<billing_catalog::BillingProduct, errors::StorageError> {
+        self.diesel_store.insert_billing_product(product).await
+    }
+
+    async fn find_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+    ) -> CustomResult<billing_catalog::BillingProduct, errors::StorageError> {
+        self.diesel_store
+            .find_billing_product_by_merchant_id_id(merchant_id, product_id)
+            .await
+    }
+
+    async fn list_billing_products_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<billing_catalog::BillingProduct>, errors::StorageError> {
+        self.diesel_store
+            .list_billing_products_by_merchant_id_profile_id(merchant_id, profile_id, limit, offset)
+            .await
+    }
+
+    async fn update_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+        product_update: billing_catalog::BillingProductUpdate,
+    ) -> CustomResult<billing_catalog::BillingProduct, errors::StorageError> {
+        self.diesel_store
+            .update_billing_product_by_merchant_id_id(merchant_id, product_id, product_update)
+            .await
+    }
+
+    async fn insert_billing_plan(
+        &self,
+        plan: billing_catalog::BillingPlanNew,
+    ) -> CustomResult<billing_catalog::BillingPlan, errors::StorageError> {
+        self.diesel_store.insert_billing_plan(plan).await
+    }
+
+    async fn find_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<billing_catalog::BillingPlan, errors::StorageError> {
+        self.diesel_store
+            .find_billing_plan_by_merchant_id_id(merchant_id, plan_id)
+            .await
+    }
+
+    async fn list_billing_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<billing_catalog::BillingPlan>, errors::StorageError> {
+        self.diesel_store
+            .list_billing_plans_by_merchant_id_profile_id(merchant_id, profile_id, limit, offset)
+            .await
+    }
+
+    async fn update_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+        plan_update: billing_catalog::BillingPlanUpdate,
+    ) -> CustomResult<billing_catalog::BillingPlan, errors::StorageError> {
+        self.diesel_store
+            .update_billing_plan_by_merchant_id_id(merchant_id, plan_id, plan_update)
+            .await
+    }
+
+    async fn insert_billing_price(
+        &self,
+        price: billing_catalog::BillingPriceNew,
+    ) -> CustomResult<billing_catalog::BillingPrice, errors::StorageError> {
+        self.diesel_store.insert_billing_price(price).await
+    }
+
+    async fn find_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<billing_catalog::BillingPrice, errors::StorageError> {
+        self.diesel_store
+            .find_billing_price_by_merchant_id_id(merchant_id, price_id)
+            .await
+    }
+
+    async fn list_billing_prices_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<Vec<billing_catalog::BillingPrice>, errors::StorageError> {
+        self.diesel_store
+            .list_billing_prices_by_merchant_id_plan_id(merchant_id, plan_id)
+            .await
+    }
+
+    async fn update_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+        price_update: billing_catalog::BillingPriceUpdate,
+    ) -> CustomResult<billing_catalog::BillingPrice, errors::StorageError> {
+        self.diesel_store
+            .update_billing_price_by_merchant_id_id(merchant_id, price_id, price_update)
+            .await
+    }
+
+    async fn insert_billing_coupon(
+        &self,
+        coupon: billing_catalog::BillingCouponNew,
+    ) -> CustomResult<billing_catalog::BillingCoupon, errors::StorageError> {
+        self.diesel_store.insert_billing_coupon(coupon).await
+    }
+
+    async fn find_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+    ) -> CustomResult<billing_catalog::BillingCoupon, errors::StorageError> {
+        self.diesel_store
+            .find_billing_coupon_by_merchant_id_id(merchant_id, coupon_id)
+            .await
+    }
+
+    async fn find_billing_coupon_by_merchant_id_profile_id_code(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        code: &str,
+    ) -> CustomResult<billing_catalog::BillingCoupon, errors::StorageError> {
+        self.diesel_store
+            .find_billing_coupon_by_merchant_id_profile_id_code(merchant_id, profile_id, code)
+            .await
+    }
+
+    async fn list_billing_coupons_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<billing_catalog::BillingCoupon>, errors::StorageError> {
+        self.diesel_store
+            .list_billing_coupons_by_merchant_id_profile_id(merchant_id, profile_id, limit, offset)
+            .await
+    }
+
+    async fn update_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+        coupon_update: billing_catalog::BillingCouponUpdate,
+    ) -> CustomResult<billing_catalog::BillingCoupon, errors::StorageError> {
+        self.diesel_store
+            .update_billing_coupon_by_merchant_id_id(merchant_id, coupon_id, coupon_update)
+            .await
+    }
+
+    async fn redeem_billing_coupon(
+        &self,
+        coupon: billing_catalog::BillingCoupon,
+    ) -> CustomResult<billing_catalog::BillingCoupon, errors::StorageError> {
+        self.diesel_store.redeem_billing_coupon(coupon).await
+    }
+}
@@ -0,0 +1,432 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, LedgerPostingDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait LedgerInterface {
+    async fn insert_ledger_fee_schedule(
+        &self,
+        fee_schedule: storage::LedgerFeeScheduleNew,
+    ) -> CustomResult<storage::LedgerFeeSchedule, errors::StorageError>;
+
+    async fn list_ledger_fee_schedules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::LedgerFeeSchedule>, errors::StorageError>;
+
+    async fn delete_ledger_fee_schedule_by_merchant_id_fee_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fee_schedule_id: &str,
+    ) -> CustomResult<storage::LedgerFeeSchedule, errors::StorageError>;
+
+    async fn insert_ledger_journal_entry_with_postings(
+        &self,
+        journal_entry: storage::LedgerJournalEntryNew,
+        postings: Vec<storage::LedgerPostingNew>,
+    ) -> CustomResult<storage::LedgerJournalEntry, errors::StorageError>;
+
+    async fn filter_ledger_postings_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::LedgerPostingConstraints,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError>;
+
+    async fn get_ledger_balances(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::LedgerPostingConstraints,
+    ) -> CustomResult<
+        Vec<(common_enums::LedgerAccount, common_enums::Currency, i64)>,
+        errors::StorageError,
+    >;
+}
+
+#[async_trait::async_trait]
+impl LedgerInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_ledger_fee_schedule(
+        &self,
+        fee_schedule: storage::LedgerFeeScheduleNew,
+    ) -> CustomResult<storage::LedgerFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        fee_schedule
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_ledger_fee_schedules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::LedgerFeeSchedule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerFeeSchedule::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_ledger_fee_schedule_by_merchant_id_fee_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fee_schedule_id: &str,
+    ) -> CustomResult<storage::LedgerFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LedgerFeeSchedule::delete_by_merchant_id_fee_schedule_id(
+            &conn,
+            merchant_id,
+            fee_schedule_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_ledger_journal_entry_with_postings(
+        &self,
+        journal_entry: storage::LedgerJournalEntryNew,
+        postings: Vec<storage::LedgerPostingNew>,
+    ) -> CustomResult<storage::LedgerJournalEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| async move {
+            let journal_entry = journal_entry
+                .insert(&conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            storage::LedgerPostingNew::batch_insert(postings, &conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            Ok::<_, errors::StorageError>(journal_entry)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_ledger_postings_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::LedgerPostingConstraints,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::LedgerPosting as LedgerPostingDbExt>::filter_by_constraints(
+            &conn,
+            merchant_id,
+            constraints,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_ledger_balances(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::LedgerPostingConstraints,
+    ) -> CustomResult<
+        Vec<(common_enums::LedgerAccount, common_enums::Currency, i64)>,
+        errors::StorageError,
+    > {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::LedgerPosting as LedgerPostingDbExt>::get_balances_by_account_and_currency(
+            &conn,
+            merchant_id,
+            constraints,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerInterface for MockDb {
+    async fn insert_ledger_fee_schedule(
+        &self,
+        fee_schedule: storage::LedgerFeeScheduleNew,
+    ) -> CustomResult<storage::LedgerFeeSchedule, errors::StorageError> {
+        let mut fee_schedules = self.ledger_fee_schedules.lock().await;
+        if fee_schedules
+            .iter()
+            .any(|existing| existing.fee_schedule_id == fee_schedule.fee_schedule_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "ledger_fee_schedule",
+                key: Some(fee_schedule.fee_schedule_id.clone()),
+            })?
+        }
+        let fee_schedule = storage::LedgerFeeSchedule {
+            fee_schedule_id: fee_schedule.fee_schedule_id,
+            merchant_id: fee_schedule.merchant_id,
+            connector: fee_schedule.connector,
+            payment_method: fee_schedule.payment_method,
+            currency: fee_schedule.currency,
+            fixed_fee: fee_schedule.fixed_fee,
+            percentage_fee_basis_points: fee_schedule.percentage_fee_basis_points,
+            created_at: fee_schedule.created_at,
+            modified_at: fee_schedule.modified_at,
+        };
+        fee_schedules.push(fee_schedule.clone());
+        Ok(fee_schedule)
+    }
+
+    async fn list_ledger_fee_schedules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::LedgerFeeSchedule>, errors::StorageError> {
+        let mut fee_schedules = self
+            .ledger_fee_schedules
+            .lock()
+            .await
+            .iter()
+            .filter(|fee_schedule| fee_schedule.merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        fee_schedules.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+        Ok(fee_schedules)
+    }
+
+    async fn delete_ledger_fee_schedule_by_merchant_id_fee_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fee_schedule_id: &str,
+    ) -> CustomResult<storage::LedgerFeeSchedule, errors::StorageError> {
+        let mut fee_schedules = self.ledger_fee_schedules.lock().await;
+        let position = fee_schedules
+            .iter()
+            .position(|fee_schedule| {
+                fee_schedule.merchant_id == *merchant_id
+                    && fee_schedule.fee_schedule_id == fee_schedule_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No fee schedule found for fee_schedule_id = {fee_schedule_id}"
+            )))?;
+        Ok(fee_schedules.remove(position))
+    }
+
+    async fn insert_ledger_journal_entry_with_postings(
+        &self,
+        journal_entry: storage::LedgerJournalEntryNew,
+        postings: Vec<storage::LedgerPostingNew>,
+    ) -> CustomResult<storage::LedgerJournalEntry, errors::StorageError> {
+        // Both locks are held for the whole insert so that a journal entry and its postings
+        // become visible together, like the database transaction
+        let mut journal_entries = self.ledger_journal_entries.lock().await;
+        let mut ledger_postings = self.ledger_postings.lock().await;
+        if journal_entries
+            .iter()
+            .any(|existing| existing.entry_id == journal_entry.entry_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "ledger_journal_entry",
+                key: Some(journal_entry.entry_id.clone()),
+            })?
+        }
+        if let Some(posting) = postings.iter().find(|posting| {
+            ledger_postings
+                .iter()
+                .any(|existing| existing.posting_id == posting.posting_id)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "ledger_posting",
+                key: Some(posting.posting_id.clone()),
+            })?
+        }
+        let journal_entry = storage::LedgerJournalEntry {
+            entry_id: journal_entry.entry_id,
+            merchant_id: journal_entry.merchant_id,
+            profile_id: journal_entry.profile_id,
+            connector: journal_entry.connector,
+            entry_type: journal_entry.entry_type,
+            reference_id: journal_entry.reference_id,
+            payment_id: journal_entry.payment_id,
+            currency: journal_entry.currency,
+            created_at: journal_entry.created_at,
+        };
+        journal_entries.push(journal_entry.clone());
+        ledger_postings.extend(postings.into_iter().map(|posting| storage::LedgerPosting {
+            posting_id: posting.posting_id,
+            entry_id: posting.entry_id,
+            merchant_id: posting.merchant_id,
+            profile_id: posting.profile_id,
+            connector: posting.connector,
+            account: posting.account,
+            direction: posting.direction,
+            amount: posting.amount,
+            currency: posting.currency,
+            created_at: posting.created_at,
+        }));
+        Ok(journal_entry)
+    }
+
+    async fn filter_ledger_postings_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::LedgerPostingConstraints,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        let mut postings = self
+            .ledger_postings
+            .lock()
+            .await
+            .iter()
+            .filter(|posting| {
+                is_posting_within_constraints(posting, merchant_id, constraints)
+                    && constraints
+                        .account
+                        .is_none_or(|account| posting.account == account)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        postings.sort_by(|this, other| {
+            this.created_at
+                .cmp(&other.created_at)
+                .then_with(|| this.posting_id.cmp(&other.posting_id))
+        });
+
+        Ok(postings
+            .into_iter()
+            .skip(
+                constraints
+                    .offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                constraints
+                    .limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
+    }
+
+    async fn get_ledger_balances(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::LedgerPostingConstraints,
+    ) -> CustomResult<
+        Vec<(common_enums::LedgerAccount, common_enums::Currency, i64)>,
+        errors::StorageError,
+    > {
+        let postings = self.ledger_postings.lock().await;
+        let mut balances: Vec<(common_enums::LedgerAccount, common_enums::Currency, i64)> =
+            Vec::new();
+        for posting in postings
+            .iter()
+            .filter(|posting| is_posting_within_constraints(posting, merchant_id, constraints))
+        {
+            let amount = match posting.direction {
+                common_enums::LedgerPostingDirection::Debit => posting.amount.get_amount_as_i64(),
+                common_enums::LedgerPostingDirection::Credit => -posting.amount.get_amount_as_i64(),
+            };
+            match balances.iter_mut().find(|(account, currency, _)| {
+                *account == posting.account && *currency == posting.currency
+            }) {
+                Some((_, _, balance)) => *balance += amount,
+                None => balances.push((posting.account, posting.currency, amount)),
+            }
+        }
+        Ok(balances)
+    }
+}
+
+fn is_posting_within_constraints(
+    posting: &storage::LedgerPosting,
+    merchant_id: &common_utils::id_type::MerchantId,
+    constraints: &storage::LedgerPostingConstraints,
+) -> bool {
+    posting.merchant_id == *merchant_id
+        && constraints
+            .profile_id
+            .as_ref()
+            .is_none_or(|profile_id| posting.profile_id.as_ref() == Some(profile_id))
+        && constraints
+            .currency
+            .is_none_or(|currency| posting.currency == currency)
+        && constraints.time_range.is_none_or(|time_range| {
+            posting.created_at >= time_range.start_time
+                && time_range
+                    .end_time
+                    .is_none_or(|end_time| posting.created_at <= end_time)
+        })
+}
+
+#[async_trait::async_trait]
+impl LedgerInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_ledger_fee_schedule(
+        &self,
+        fee_schedule: storage::LedgerFeeScheduleNew,
+    ) -> CustomResult<storage::LedgerFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .insert_ledger_fee_schedule(fee_schedule)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_ledger_fee_schedules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::LedgerFeeSchedule>, errors::StorageError> {
+        self.diesel_store
+            .list_ledger_fee_schedules_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_ledger_fee_schedule_by_merchant_id_fee_schedule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fee_schedule_id: &str,
+    ) -> CustomResult<storage::LedgerFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .delete_ledger_fee_schedule_by_merchant_id_fee_schedule_id(merchant_id, fee_schedule_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_ledger_journal_entry_with_postings(
+        &self,
+        journal_entry: storage::LedgerJournalEntryNew,
+        postings: Vec<storage::LedgerPostingNew>,
+    ) -> CustomResult<storage::LedgerJournalEntry, errors::StorageError> {
+        self.diesel_store
+            .insert_ledger_journal_entry_with_postings(journal_entry, postings)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_ledger_postings_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::LedgerPostingConstraints,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        self.diesel_store
+            .filter_ledger_postings_by_constraints(merchant_id, constraints)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_ledger_balances(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::LedgerPostingConstraints,
+    ) -> CustomResult<
+        Vec<(common_enums::LedgerAccount, common_enums::Currency, i64)>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .get_ledger_balances(merchant_id, constraints)
+            .await
+    }
+}
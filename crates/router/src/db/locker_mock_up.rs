@@ -74,7 +74,12 @@ impl LockerMockUpInterface for MockDb {
             .iter()
             .find(|l| l.card_id == card_id)
             .cloned()
-            .ok_or(errors::StorageError::MockDbError.into())
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No locker available for card_id = {card_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn insert_locker_mock_up(
@@ -84,7 +89,10 @@ impl LockerMockUpInterface for MockDb {
         let mut locked_lockers = self.lockers.lock().await;
 
         if locked_lockers.iter().any(|l| l.card_id == new.card_id) {
-            Err(errors::StorageError::MockDbError)?;
+            Err(errors::StorageError::DuplicateValue {
+                entity: "card_id",
+                key: Some(new.card_id.clone()),
+            })?;
         }
 
         let created_locker = storage::LockerMockUp {
@@ -119,7 +127,9 @@ impl LockerMockUpInterface for MockDb {
         let position = locked_lockers
             .iter()
             .position(|l| l.card_id == card_id)
-            .ok_or(errors::StorageError::MockDbError)?;
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No locker available for card_id = {card_id}"
+            )))?;
 
         Ok(locked_lockers.remove(position))
     }
@@ -570,13 +570,19 @@ impl MandateInterface for MockDb {
             .collect());
     }
 
-    // Need to fix this once we move to v2 mandate
     #[cfg(feature = "v2")]
     async fn find_mandate_by_global_customer_id(
         &self,
         id: &id_type::GlobalCustomerId,
     ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
-        todo!()
+        Ok(self
+            .mandates
+            .lock()
+            .await
+            .iter()
+            .filter(|mandate| mandate.customer_id.get_string_repr() == id.get_string_repr())
+            .cloned()
+            .collect())
     }
 
     async fn update_mandate_by_merchant_id_mandate_id(
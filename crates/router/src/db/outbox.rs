@@ -0,0 +1,330 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait OutboxInterface {
+    async fn find_pending_outbox_entries(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Outbox>, errors::StorageError>;
+
+    async fn update_outbox_entry_by_outbox_id(
+        &self,
+        outbox_id: &str,
+        outbox_update: storage::OutboxUpdate,
+    ) -> CustomResult<storage::Outbox, errors::StorageError>;
+
+    async fn delete_published_outbox_entries(
+        &self,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl OutboxInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_pending_outbox_entries(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Outbox>, errors::StorageError> {
+        // Read from the primary, as entries read from a lagging replica would be published again
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Outbox::find_pending(&conn, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_outbox_entry_by_outbox_id(
+        &self,
+        outbox_id: &str,
+        outbox_update: storage::OutboxUpdate,
+    ) -> CustomResult<storage::Outbox, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Outbox::update_by_outbox_id(&conn, outbox_id, outbox_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_published_outbox_entries(
+        &self,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Outbox::delete_published_before(&conn, created_before)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxInterface for MockDb {
+    async fn find_pending_outbox_entries(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Outbox>, errors::StorageError> {
+        let now = common_utils::date_time::now();
+        let mut entries: Vec<_> = self
+            .outbox_entries
+            .lock()
+            .await
+            .iter()
+            .filter(|entry| {
+                entry.status == storage::enums::OutboxStatus::Pending
+                    && entry
+                        .next_attempt_at
+                        .is_none_or(|next_attempt_at| next_attempt_at <= now)
+            })
+            .cloned()
+            .collect();
+        entries.sort_by_key(|entry| entry.created_at);
+        entries.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(entries)
+    }
+
+    async fn update_outbox_entry_by_outbox_id(
+        &self,
+        outbox_id: &str,
+        outbox_update: storage::OutboxUpdate,
+    ) -> CustomResult<storage::Outbox, errors::StorageError> {
+        let mut outbox_entries = self.outbox_entries.lock().await;
+        let entry = outbox_entries
+            .iter_mut()
+            .find(|entry| entry.outbox_id == outbox_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No outbox entry found for outbox_id = {outbox_id}"
+            )))?;
+        let storage::OutboxUpdateInternal {
+            status,
+            attempts,
+            last_error,
+            published_at,
+            next_attempt_at,
+        } = outbox_update.into();
+        *entry = storage::Outbox {
+            status: status.unwrap_or(entry.status),
+            attempts: attempts.unwrap_or(entry.attempts),
+            last_error: last_error.or(entry.last_error.take()),
+            published_at: published_at.or(entry.published_at),
+            next_attempt_at: next_attempt_at.or(entry.next_attempt_at),
+            ..entry.clone()
+        };
+        Ok(entry.clone())
+    }
+
+    async fn delete_published_outbox_entries(
+        &self,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut outbox_entries = self.outbox_entries.lock().await;
+        let initial_count = outbox_entries.len();
+        outbox_entries.retain(|entry| {
+            entry.status != storage::enums::OutboxStatus::Published
+                || entry.created_at >= created_before
+        });
+        Ok(initial_count - outbox_entries.len())
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_pending_outbox_entries(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Outbox>, errors::StorageError> {
+        self.diesel_store.find_pending_outbox_entries(limit).await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_outbox_entry_by_outbox_id(
+        &self,
+        outbox_id: &str,
+        outbox_update: storage::OutboxUpdate,
+    ) -> CustomResult<storage::Outbox, errors::StorageError> {
+        self.diesel_store
+            .update_outbox_entry_by_outbox_id(outbox_id, outbox_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_published_outbox_entries(
+        &self,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_published_outbox_entries(created_before)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::types::keymanager::KeyManagerState;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_entry(
+        outbox_id: &str,
+        status: storage::enums::OutboxStatus,
+        created_at: time::PrimitiveDateTime,
+        next_attempt_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::Outbox {
+        storage::Outbox {
+            outbox_id: outbox_id.to_string(),
+            dedupe_key: format!("refund_ref_1_update_{outbox_id}"),
+            aggregate_type: storage::enums::OutboxAggregateType::Refund,
+            aggregate_id: "ref_1".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            operation: storage::enums::OutboxOperation::Update,
+            payload: serde_json::json!({ "refund_id": "ref_1" }),
+            status,
+            attempts: 0,
+            last_error: None,
+            created_at,
+            published_at: None,
+            next_attempt_at,
+        }
+    }
+
+    async fn get_mock_db() -> MockDb {
+        MockDb::new(
+            &redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            },
+            KeyManagerState::mock(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_find_pending_skips_entries_backing_off() {
+        let db = get_mock_db().await;
+        let now = common_utils::date_time::now();
+        *db.outbox_entries.lock().await = vec![
+            get_entry(
+                "due",
+                storage::enums::OutboxStatus::Pending,
+                datetime!(2026-10-19 10:00),
+                Some(now.saturating_sub(time::Duration::seconds(1))),
+            ),
+            get_entry(
+                "backing_off",
+                storage::enums::OutboxStatus::Pending,
+                datetime!(2026-10-19 09:00),
+                Some(now.saturating_add(time::Duration::hours(1))),
+            ),
+            get_entry(
+                "new",
+                storage::enums::OutboxStatus::Pending,
+                datetime!(2026-10-19 08:00),
+                None,
+            ),
+            get_entry(
+                "failed",
+                storage::enums::OutboxStatus::Failed,
+                datetime!(2026-10-19 07:00),
+                None,
+            ),
+        ];
+
+        let entries = db.find_pending_outbox_entries(10).await.unwrap();
+
+        let outbox_ids: Vec<_> = entries
+            .iter()
+            .map(|entry| entry.outbox_id.as_str())
+            .collect();
+        assert_eq!(outbox_ids, ["new", "due"]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_attempt_is_recorded() {
+        let db = get_mock_db().await;
+        let next_attempt_at = datetime!(2026-10-19 12:00:10);
+        *db.outbox_entries.lock().await = vec![get_entry(
+            "outbox_1",
+            storage::enums::OutboxStatus::Pending,
+            datetime!(2026-10-19 10:00),
+            None,
+        )];
+
+        let entry = db
+            .update_outbox_entry_by_outbox_id(
+                "outbox_1",
+                storage::OutboxUpdate::PublishFailed {
+                    attempts: 1,
+                    last_error: "broker unavailable".to_string(),
+                    status: storage::enums::OutboxStatus::Pending,
+                    next_attempt_at: Some(next_attempt_at),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(entry.attempts, 1);
+        assert_eq!(entry.last_error.as_deref(), Some("broker unavailable"));
+        assert_eq!(entry.status, storage::enums::OutboxStatus::Pending);
+        assert_eq!(entry.next_attempt_at, Some(next_attempt_at));
+    }
+
+    #[tokio::test]
+    async fn test_delete_published_entries_past_retention() {
+        let db = get_mock_db().await;
+        *db.outbox_entries.lock().await = vec![
+            get_entry(
+                "expired",
+                storage::enums::OutboxStatus::Published,
+                datetime!(2026-10-01 10:00),
+                None,
+            ),
+            get_entry(
+                "retained",
+                storage::enums::OutboxStatus::Published,
+                datetime!(2026-10-18 10:00),
+                None,
+            ),
+            get_entry(
+                "pending",
+                storage::enums::OutboxStatus::Pending,
+                datetime!(2026-10-01 10:00),
+                None,
+            ),
+            get_entry(
+                "failed",
+                storage::enums::OutboxStatus::Failed,
+                datetime!(2026-10-01 10:00),
+                None,
+            ),
+        ];
+
+        let deleted_count = db
+            .delete_published_outbox_entries(datetime!(2026-10-12 10:00))
+            .await
+            .unwrap();
+
+        assert_eq!(deleted_count, 1);
+        let outbox_ids: Vec<_> = db
+            .outbox_entries
+            .lock()
+            .await
+            .iter()
+            .map(|entry| entry.outbox_id.clone())
+            .collect();
+        assert_eq!(outbox_ids, ["retained", "pending", "failed"]);
+    }
+}
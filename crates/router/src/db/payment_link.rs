@@ -70,26 +70,94 @@ impl PaymentLinkInterface for Store {
 impl PaymentLinkInterface for MockDb {
     async fn insert_payment_link(
         &self,
-        _payment_link: storage::PaymentLinkNew,
+        payment_link: storage::PaymentLinkNew,
     ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_links = self.payment_link.lock().await;
+        if payment_links
+            .iter()
+            .any(|link| link.payment_link_id == payment_link.payment_link_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payment_link_id",
+                key: Some(payment_link.payment_link_id.clone()),
+            })?
+        }
+        let now = common_utils::date_time::now();
+        let payment_link = storage::PaymentLink {
+            payment_link_id: payment_link.payment_link_id,
+            payment_id: payment_link.payment_id,
+            link_to_pay: payment_link.link_to_pay,
+            merchant_id: payment_link.merchant_id,
+            amount: payment_link.amount,
+            currency: payment_link.currency,
+            created_at: payment_link.created_at.unwrap_or(now),
+            last_modified_at: payment_link.last_modified_at.unwrap_or(now),
+            fulfilment_time: payment_link.fulfilment_time,
+            custom_merchant_name: payment_link.custom_merchant_name,
+            payment_link_config: payment_link.payment_link_config,
+            description: payment_link.description,
+            profile_id: payment_link.profile_id,
+            secure_link: payment_link.secure_link,
+        };
+        payment_links.push(payment_link.clone());
+        Ok(payment_link)
     }
 
     async fn find_payment_link_by_payment_link_id(
         &self,
-        _payment_link_id: &str,
+        payment_link_id: &str,
     ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
-        // TODO: Implement function for `MockDb`x
-        Err(errors::StorageError::MockDbError)?
+        self.payment_link
+            .lock()
+            .await
+            .iter()
+            .find(|link| link.payment_link_id == payment_link_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payment link found for payment_link_id = {payment_link_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn list_payment_link_by_merchant_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
     ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
-        // TODO: Implement function for `MockDb`x
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_links = self
+            .payment_link
+            .lock()
+            .await
+            .iter()
+            .filter(|link| {
+                link.merchant_id == *merchant_id
+                    && payment_link_constraints
+                        .created
+                        .is_none_or(|created| link.created_at == created)
+                    && payment_link_constraints
+                        .created_lt
+                        .is_none_or(|created_lt| link.created_at < created_lt)
+                    && payment_link_constraints
+                        .created_gt
+                        .is_none_or(|created_gt| link.created_at > created_gt)
+                    && payment_link_constraints
+                        .created_lte
+                        .is_none_or(|created_lte| link.created_at <= created_lte)
+                    && payment_link_constraints
+                        .created_gte
+                        .is_none_or(|created_gte| link.created_at >= created_gte)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_links.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+        if let Some(limit) = payment_link_constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+        {
+            payment_links.truncate(limit);
+        }
+        Ok(payment_links)
     }
 }
@@ -1,3 +1,8 @@
+#[cfg(feature = "v2")]
+use error_stack::ResultExt;
+#[cfg(feature = "v2")]
+use hyperswitch_domain_models::behaviour::{Conversion, ReverseConversion};
+
 #[cfg(feature = "v2")]
 use crate::core::errors::{self, CustomResult};
 use crate::db::MockDb;
@@ -169,11 +174,19 @@ mod storage {
 impl PaymentMethodsSessionInterface for MockDb {
     async fn insert_payment_methods_session(
         &self,
-        key_store: &hyperswitch_domain_models::merchant_key_store::MerchantKeyStore,
+        _key_store: &hyperswitch_domain_models::merchant_key_store::MerchantKeyStore,
         payment_methods_session: hyperswitch_domain_models::payment_methods::PaymentMethodSession,
-        validity_in_seconds: i64,
+        _validity_in_seconds: i64,
     ) -> CustomResult<(), errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let db_model = payment_methods_session
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        let mut payment_methods_sessions = self.payment_methods_sessions.lock().await;
+        payment_methods_sessions.retain(|session| session.id != db_model.id);
+        payment_methods_sessions.push(db_model);
+        Ok(())
     }
 
     async fn update_payment_method_session(
@@ -186,7 +199,39 @@ impl PaymentMethodsSessionInterface for MockDb {
         hyperswitch_domain_models::payment_methods::PaymentMethodSession,
         errors::StorageError,
     > {
-        Err(errors::StorageError::MockDbError)?
+        let internal_obj =
+            hyperswitch_domain_models::payment_methods::PaymentMethodsSessionUpdateInternal::from(
+                payment_methods_session,
+            );
+
+        let db_model = current_session
+            .apply_changeset(internal_obj)
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        let mut payment_methods_sessions = self.payment_methods_sessions.lock().await;
+        let session = payment_methods_sessions
+            .iter_mut()
+            .find(|session| session.id == *id)
+            .ok_or(errors::StorageError::ValueNotFound(
+                "No payment methods session found".to_string(),
+            ))?;
+        *session = db_model.clone();
+        drop(payment_methods_sessions);
+
+        db_model
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &key_store.key,
+                common_utils::types::keymanager::Identifier::Merchant(
+                    key_store.merchant_id.clone(),
+                ),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+            .attach_printable("Failed to decrypt payment methods session")
     }
 
     #[cfg(feature = "v2")]
@@ -198,7 +243,31 @@ impl PaymentMethodsSessionInterface for MockDb {
         hyperswitch_domain_models::payment_methods::PaymentMethodSession,
         errors::StorageError,
     > {
-        Err(errors::StorageError::MockDbError)?
+        // Sessions expire along with their redis key in the database backed store
+        let now = common_utils::date_time::now();
+        let db_model = self
+            .payment_methods_sessions
+            .lock()
+            .await
+            .iter()
+            .find(|session| session.id == *id && session.expires_at > now)
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(
+                "No payment methods session found".to_string(),
+            ))?;
+
+        db_model
+            .convert(
+                self.get_keymanager_state()
+                    .attach_printable("Missing KeyManagerState")?,
+                &key_store.key,
+                common_utils::types::keymanager::Identifier::Merchant(
+                    key_store.merchant_id.clone(),
+                ),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+            .attach_printable("Failed to decrypt payment methods session")
     }
 }
 
@@ -0,0 +1,560 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    /// Inserts the batch along with its items, so that a batch is never stored without them.
+    async fn insert_payout_batch_with_items(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_payout_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError>;
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    /// Updates the batch only if it is still in `current_status`, failing with
+    /// [`errors::StorageError::ValueNotFound`] otherwise.
+    async fn update_payout_batch_by_status(
+        &self,
+        this: storage::PayoutBatch,
+        current_status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn list_payout_batch_items_by_payout_batch_id(
+        &self,
+        payout_batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn list_payout_batch_items_by_payout_batch_id_status(
+        &self,
+        payout_batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn get_payout_batch_items_count_by_payout_batch_id_status(
+        &self,
+        payout_batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_with_items(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| async move {
+            let payout_batch = payout_batch
+                .insert(&conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            storage::PayoutBatchItemNew::batch_insert(items, &conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            Ok::<_, errors::StorageError>(payout_batch)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_payout_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_payout_batch_id(
+            &conn,
+            merchant_id,
+            payout_batch_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout_batch_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_status(
+        &self,
+        this: storage::PayoutBatch,
+        current_status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_by_status(&conn, current_status, payout_batch_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_payout_batch_id(
+        &self,
+        payout_batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::list_by_payout_batch_id(&conn, payout_batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_payout_batch_id_status(
+        &self,
+        payout_batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::list_by_payout_batch_id_status(
+            &conn,
+            payout_batch_id,
+            status,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payout_batch_items_count_by_payout_batch_id_status(
+        &self,
+        payout_batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::get_count_by_payout_batch_id_status(
+            &conn,
+            payout_batch_id,
+            status,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, item_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch_with_items(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        let mut payout_batch_items = self.payout_batch_items.lock().await;
+        if payout_batches
+            .iter()
+            .any(|existing| existing.payout_batch_id == payout_batch.payout_batch_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payout_batch",
+                key: Some(payout_batch.payout_batch_id.clone()),
+            })?
+        }
+        if let Some(item) = items.iter().find(|item| {
+            payout_batch_items.iter().any(|existing| {
+                existing.payout_batch_id == item.payout_batch_id
+                    && existing.item_index == item.item_index
+            })
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payout_batch_item",
+                key: Some(format!("{}_{}", item.payout_batch_id, item.item_index)),
+            })?
+        }
+        let payout_batch = storage::PayoutBatch {
+            payout_batch_id: payout_batch.payout_batch_id,
+            merchant_id: payout_batch.merchant_id,
+            profile_id: payout_batch.profile_id,
+            status: payout_batch.status,
+            description: payout_batch.description,
+            file_id: payout_batch.file_id,
+            total_count: payout_batch.total_count,
+            submitted_count: payout_batch.submitted_count,
+            failed_count: payout_batch.failed_count,
+            created_by: payout_batch.created_by,
+            reviewed_by: payout_batch.reviewed_by,
+            rejection_reason: payout_batch.rejection_reason,
+            created_at: payout_batch.created_at,
+            modified_at: payout_batch.modified_at,
+        };
+        payout_batches.push(payout_batch.clone());
+        payout_batch_items.extend(items.into_iter().map(|item| storage::PayoutBatchItem {
+            payout_batch_id: item.payout_batch_id,
+            item_index: item.item_index,
+            merchant_id: item.merchant_id,
+            payout_id: item.payout_id,
+            customer_id: item.customer_id,
+            amount: item.amount,
+            currency: item.currency,
+            request_data: item.request_data,
+            status: item.status,
+            payout_status: item.payout_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+        }));
+        Ok(payout_batch)
+    }
+
+    async fn find_payout_batch_by_merchant_id_payout_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.payout_batches
+            .lock()
+            .await
+            .iter()
+            .find(|payout_batch| {
+                payout_batch.merchant_id == *merchant_id
+                    && payout_batch.payout_batch_id == payout_batch_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout batch found for payout_batch_id = {payout_batch_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        let mut payout_batches = self
+            .payout_batches
+            .lock()
+            .await
+            .iter()
+            .filter(|payout_batch| payout_batch.merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        payout_batches.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+
+        Ok(payout_batches
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        let payout_batch = payout_batches
+            .iter_mut()
+            .find(|payout_batch| payout_batch.payout_batch_id == this.payout_batch_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch found for payout_batch_id = {}",
+                this.payout_batch_id
+            )))?;
+        apply_payout_batch_update(payout_batch, payout_batch_update);
+        Ok(payout_batch.clone())
+    }
+
+    async fn update_payout_batch_by_status(
+        &self,
+        this: storage::PayoutBatch,
+        current_status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        let payout_batch = payout_batches
+            .iter_mut()
+            .find(|payout_batch| {
+                payout_batch.payout_batch_id == this.payout_batch_id
+                    && payout_batch.status == current_status
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch found for payout_batch_id = {} in {current_status} status",
+                this.payout_batch_id
+            )))?;
+        apply_payout_batch_update(payout_batch, payout_batch_update);
+        Ok(payout_batch.clone())
+    }
+
+    async fn list_payout_batch_items_by_payout_batch_id(
+        &self,
+        payout_batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let mut items = self
+            .payout_batch_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.payout_batch_id == payout_batch_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| item.item_index);
+        Ok(items)
+    }
+
+    async fn list_payout_batch_items_by_payout_batch_id_status(
+        &self,
+        payout_batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let mut items = self
+            .payout_batch_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.payout_batch_id == payout_batch_id && item.status == status)
+            .cloned()
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| item.item_index);
+        items.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(items)
+    }
+
+    async fn get_payout_batch_items_count_by_payout_batch_id_status(
+        &self,
+        payout_batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Ok(self
+            .payout_batch_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.payout_batch_id == payout_batch_id && item.status == status)
+            .count())
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let mut payout_batch_items = self.payout_batch_items.lock().await;
+        let item = payout_batch_items
+            .iter_mut()
+            .find(|item| {
+                item.payout_batch_id == this.payout_batch_id && item.item_index == this.item_index
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch item found for payout_batch_id = {}, item_index = {}",
+                this.payout_batch_id, this.item_index
+            )))?;
+        *item = storage::PayoutBatchItem {
+            status: item_update.status,
+            payout_status: item_update.payout_status.or(item.payout_status),
+            error_code: item_update.error_code.or(item.error_code.take()),
+            error_message: item_update.error_message.or(item.error_message.take()),
+            modified_at: item_update.modified_at,
+            ..item.clone()
+        };
+        Ok(item.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_with_items(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_batch_with_items(payout_batch, items)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_payout_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_payout_batch_id(merchant_id, payout_batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batches_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch(this, payout_batch_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_status(
+        &self,
+        this: storage::PayoutBatch,
+        current_status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_by_status(this, current_status, payout_batch_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_payout_batch_id(
+        &self,
+        payout_batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batch_items_by_payout_batch_id(payout_batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_payout_batch_id_status(
+        &self,
+        payout_batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batch_items_by_payout_batch_id_status(payout_batch_id, status, limit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payout_batch_items_count_by_payout_batch_id_status(
+        &self,
+        payout_batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .get_payout_batch_items_count_by_payout_batch_id_status(payout_batch_id, status)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_item(this, item_update)
+            .await
+    }
+}
+
+fn apply_payout_batch_update(
+    payout_batch: &mut storage::PayoutBatch,
+    payout_batch_update: storage::PayoutBatchUpdate,
+) {
+    let diesel_models::payout_batch::PayoutBatchUpdateInternal {
+        status,
+        submitted_count,
+        failed_count,
+        reviewed_by,
+        rejection_reason,
+        modified_at,
+    } = payout_batch_update.into();
+    *payout_batch = storage::PayoutBatch {
+        status: status.unwrap_or(payout_batch.status),
+        submitted_count: submitted_count.unwrap_or(payout_batch.submitted_count),
+        failed_count: failed_count.unwrap_or(payout_batch.failed_count),
+        reviewed_by: reviewed_by.or(payout_batch.reviewed_by.take()),
+        rejection_reason: rejection_reason.or(payout_batch.rejection_reason.take()),
+        modified_at,
+        ..payout_batch.clone()
+    };
+}
@@ -172,9 +172,12 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<diesel_refund::Refund, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            new.insert(&conn)
-                .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+            let result = if self.is_outbox_enabled() {
+                new.insert_with_outbox(&conn).await
+            } else {
+                new.insert(&conn).await
+            };
+            result.map_err(|error| report!(errors::StorageError::from(error)))
         }
 
         #[instrument(skip_all)]
@@ -215,9 +218,12 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<diesel_refund::Refund, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            this.update(&conn, refund)
-                .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+            let result = if self.is_outbox_enabled() {
+                this.update_with_outbox(&conn, refund).await
+            } else {
+                this.update(&conn, refund).await
+            };
+            result.map_err(|error| report!(errors::StorageError::from(error)))
         }
 
         #[cfg(feature = "v2")]
@@ -229,9 +235,12 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<diesel_refund::Refund, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            this.update_with_id(&conn, refund)
-                .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+            let result = if self.is_outbox_enabled() {
+                this.update_with_id_and_outbox(&conn, refund).await
+            } else {
+                this.update_with_id(&conn, refund).await
+            };
+            result.map_err(|error| report!(errors::StorageError::from(error)))
         }
 
         #[cfg(feature = "v1")]
@@ -495,9 +504,12 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = connection::pg_connection_write(self).await?;
-                    new.insert(&conn)
-                        .await
-                        .map_err(|error| report!(errors::StorageError::from(error)))
+                    let result = if self.is_outbox_enabled() {
+                        new.insert_with_outbox(&conn).await
+                    } else {
+                        new.insert(&conn).await
+                    };
+                    result.map_err(|error| report!(errors::StorageError::from(error)))
                 }
                 enums::MerchantStorageScheme::RedisKv => {
                     let merchant_id = new.merchant_id.clone();
@@ -643,9 +655,12 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<diesel_refund::Refund, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            new.insert(&conn)
-                .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+            let result = if self.is_outbox_enabled() {
+                new.insert_with_outbox(&conn).await
+            } else {
+                new.insert(&conn).await
+            };
+            result.map_err(|error| report!(errors::StorageError::from(error)))
         }
 
         #[cfg(feature = "v1")]
@@ -750,9 +765,12 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = connection::pg_connection_write(self).await?;
-                    this.update(&conn, refund)
-                        .await
-                        .map_err(|error| report!(errors::StorageError::from(error)))
+                    let result = if self.is_outbox_enabled() {
+                        this.update_with_outbox(&conn, refund).await
+                    } else {
+                        this.update(&conn, refund).await
+                    };
+                    result.map_err(|error| report!(errors::StorageError::from(error)))
                 }
                 enums::MerchantStorageScheme::RedisKv => {
                     let key_str = key.to_string();
@@ -800,9 +818,12 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<diesel_refund::Refund, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            this.update_with_id(&conn, refund)
-                .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+            let result = if self.is_outbox_enabled() {
+                this.update_with_id_and_outbox(&conn, refund).await
+            } else {
+                this.update_with_id(&conn, refund).await
+            };
+            result.map_err(|error| report!(errors::StorageError::from(error)))
         }
 
         #[cfg(feature = "v1")]
@@ -138,36 +138,97 @@ impl RelayInterface for Store {
 impl RelayInterface for MockDb {
     async fn insert_relay(
         &self,
-        _merchant_key_store: &domain::MerchantKeyStore,
-        _new: hyperswitch_domain_models::relay::Relay,
+        merchant_key_store: &domain::MerchantKeyStore,
+        new: hyperswitch_domain_models::relay::Relay,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let relay = Conversion::convert(new)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let mut relays = self.relays.lock().await;
+        if relays.iter().any(|existing| existing.id == relay.id) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "relay",
+                key: Some(relay.id.get_string_repr().to_owned()),
+            })?
+        }
+        let relay_id = relay.id.clone();
+        relays.push(relay);
+
+        self.get_resource(
+            merchant_key_store,
+            relays,
+            |relay| relay.id == relay_id,
+            "cannot find relay".to_string(),
+        )
+        .await
     }
 
     async fn update_relay(
         &self,
-        _merchant_key_store: &domain::MerchantKeyStore,
-        _current_state: hyperswitch_domain_models::relay::Relay,
-        _relay_update: hyperswitch_domain_models::relay::RelayUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: hyperswitch_domain_models::relay::Relay,
+        relay_update: hyperswitch_domain_models::relay::RelayUpdate,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let relay = Conversion::convert(current_state)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let diesel_models::relay::RelayUpdateInternal {
+            connector_reference_id,
+            status,
+            error_code,
+            error_message,
+            modified_at,
+        } = diesel_models::relay::RelayUpdateInternal::from(relay_update);
+        let relay_id = relay.id.clone();
+        let updated_relay = diesel_models::relay::Relay {
+            connector_reference_id: connector_reference_id.or(relay.connector_reference_id),
+            status: status.unwrap_or(relay.status),
+            error_code: error_code.or(relay.error_code),
+            error_message: error_message.or(relay.error_message),
+            modified_at,
+            ..relay
+        };
+
+        self.update_resource(
+            merchant_key_store,
+            self.relays.lock().await,
+            updated_relay,
+            |relay| relay.id == relay_id,
+            "cannot find relay to update".to_string(),
+        )
+        .await
     }
 
     async fn find_relay_by_id(
         &self,
-        _merchant_key_store: &domain::MerchantKeyStore,
-        _relay_id: &common_utils::id_type::RelayId,
+        merchant_key_store: &domain::MerchantKeyStore,
+        relay_id: &common_utils::id_type::RelayId,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.get_resource(
+            merchant_key_store,
+            self.relays.lock().await,
+            |relay| relay.id == *relay_id,
+            format!("No relay found for id = {}", relay_id.get_string_repr()),
+        )
+        .await
     }
 
     async fn find_relay_by_profile_id_connector_reference_id(
         &self,
-        _merchant_key_store: &domain::MerchantKeyStore,
-        _profile_id: &common_utils::id_type::ProfileId,
-        _connector_reference_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+        profile_id: &common_utils::id_type::ProfileId,
+        connector_reference_id: &str,
     ) -> CustomResult<hyperswitch_domain_models::relay::Relay, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.get_resource(
+            merchant_key_store,
+            self.relays.lock().await,
+            |relay| {
+                relay.profile_id == *profile_id
+                    && relay.connector_reference_id.as_deref() == Some(connector_reference_id)
+            },
+            format!("No relay found for connector_reference_id = {connector_reference_id}"),
+        )
+        .await
     }
 }
 
@@ -0,0 +1,798 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SettlementReportInterface {
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn list_settlement_reports_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReport>, errors::StorageError>;
+
+    async fn update_settlement_report(
+        &self,
+        this: storage::SettlementReport,
+        settlement_report_update: storage::SettlementReportUpdate,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    /// Replaces the recon lines of a settlement report, so that a report can be reconciled again.
+    async fn replace_settlement_recon_lines_by_report_id(
+        &self,
+        report_id: &str,
+        lines: Vec<storage::SettlementReconLineNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn find_settlement_recon_line_by_merchant_id_line_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        line_id: &str,
+    ) -> CustomResult<storage::SettlementReconLine, errors::StorageError>;
+
+    async fn filter_settlement_recon_lines_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::SettlementReconLineConstraints,
+    ) -> CustomResult<Vec<storage::SettlementReconLine>, errors::StorageError>;
+
+    async fn update_settlement_recon_line(
+        &self,
+        this: storage::SettlementReconLine,
+        line_update: storage::SettlementReconLineUpdate,
+    ) -> CustomResult<storage::SettlementReconLine, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_settlement_candidates_by_connector_transaction_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        transaction_type: common_enums::SettlementTransactionType,
+        connector_transaction_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::SettlementCandidate>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_settlement_candidates_by_time_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<storage::SettlementCandidate>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SettlementReportInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        settlement_report
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_settlement_reports_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReport>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReport::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_settlement_report(
+        &self,
+        this: storage::SettlementReport,
+        settlement_report_update: storage::SettlementReportUpdate,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, settlement_report_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn replace_settlement_recon_lines_by_report_id(
+        &self,
+        report_id: &str,
+        lines: Vec<storage::SettlementReconLineNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let report_id = report_id.to_owned();
+        conn.transaction_async(|conn| async move {
+            match storage::SettlementReconLine::delete_by_report_id(&conn, &report_id).await {
+                Ok(_) => {}
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        diesel_models::errors::DatabaseError::NotFound
+                    ) => {}
+                Err(error) => Err(errors::StorageError::from(error))?,
+            }
+            if !lines.is_empty() {
+                storage::SettlementReconLineNew::batch_insert(lines, &conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+            }
+            Ok::<_, errors::StorageError>(())
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_recon_line_by_merchant_id_line_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        line_id: &str,
+    ) -> CustomResult<storage::SettlementReconLine, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReconLine::find_by_merchant_id_line_id(&conn, merchant_id, line_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_settlement_recon_lines_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::SettlementReconLineConstraints,
+    ) -> CustomResult<Vec<storage::SettlementReconLine>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReconLine::filter_by_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_settlement_recon_line(
+        &self,
+        this: storage::SettlementReconLine,
+        line_update: storage::SettlementReconLineUpdate,
+    ) -> CustomResult<storage::SettlementReconLine, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, line_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_settlement_candidates_by_connector_transaction_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        transaction_type: common_enums::SettlementTransactionType,
+        connector_transaction_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::SettlementCandidate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let candidates = match transaction_type {
+            common_enums::SettlementTransactionType::Payment => {
+                storage::SettlementCandidate::find_captured_payment_attempts_by_connector_transaction_ids(
+                    &conn,
+                    merchant_id,
+                    connector,
+                    connector_transaction_ids,
+                )
+                .await
+            }
+            common_enums::SettlementTransactionType::Refund => {
+                storage::SettlementCandidate::find_succeeded_refunds_by_connector_refund_ids(
+                    &conn,
+                    merchant_id,
+                    connector,
+                    connector_transaction_ids,
+                )
+                .await
+            }
+        };
+        candidates.map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_settlement_candidates_by_time_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<storage::SettlementCandidate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let mut candidates =
+            storage::SettlementCandidate::find_captured_payment_attempts_by_time_range(
+                &conn,
+                merchant_id,
+                connector,
+                time_range,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+        candidates.extend(
+            storage::SettlementCandidate::find_succeeded_refunds_by_time_range(
+                &conn,
+                merchant_id,
+                connector,
+                time_range,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?,
+        );
+        Ok(candidates)
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReportInterface for MockDb {
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let mut settlement_reports = self.settlement_reports.lock().await;
+        if settlement_reports
+            .iter()
+            .any(|report| report.report_id == settlement_report.report_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "settlement_report",
+                key: Some(settlement_report.report_id.clone()),
+            })?
+        }
+        let settlement_report = storage::SettlementReport {
+            report_id: settlement_report.report_id,
+            merchant_id: settlement_report.merchant_id,
+            organization_id: settlement_report.organization_id,
+            profile_id: settlement_report.profile_id,
+            connector: settlement_report.connector,
+            file_id: settlement_report.file_id,
+            format: settlement_report.format,
+            status: settlement_report.status,
+            period_start: settlement_report.period_start,
+            period_end: settlement_report.period_end,
+            total_count: settlement_report.total_count,
+            matched_count: settlement_report.matched_count,
+            amount_mismatch_count: settlement_report.amount_mismatch_count,
+            missing_in_hyperswitch_count: settlement_report.missing_in_hyperswitch_count,
+            missing_at_psp_count: settlement_report.missing_at_psp_count,
+            error_message: settlement_report.error_message,
+            created_at: settlement_report.created_at,
+            modified_at: settlement_report.modified_at,
+        };
+        settlement_reports.push(settlement_report.clone());
+        Ok(settlement_report)
+    }
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.settlement_reports
+            .lock()
+            .await
+            .iter()
+            .find(|report| report.merchant_id == *merchant_id && report.report_id == report_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No settlement report found for report_id = {report_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_settlement_reports_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReport>, errors::StorageError> {
+        let mut settlement_reports = self
+            .settlement_reports
+            .lock()
+            .await
+            .iter()
+            .filter(|report| report.merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        settlement_reports.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+
+        Ok(settlement_reports
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    async fn update_settlement_report(
+        &self,
+        this: storage::SettlementReport,
+        settlement_report_update: storage::SettlementReportUpdate,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let mut settlement_reports = self.settlement_reports.lock().await;
+        let report = settlement_reports
+            .iter_mut()
+            .find(|report| {
+                report.merchant_id == this.merchant_id && report.report_id == this.report_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No settlement report found for report_id = {}",
+                this.report_id
+            )))?;
+        let diesel_models::settlement_report::SettlementReportUpdateInternal {
+            status,
+            period_start,
+            period_end,
+            total_count,
+            matched_count,
+            amount_mismatch_count,
+            missing_in_hyperswitch_count,
+            missing_at_psp_count,
+            error_message,
+            modified_at,
+        } = settlement_report_update.into();
+        *report = storage::SettlementReport {
+            status: status.unwrap_or(report.status),
+            period_start: period_start.or(report.period_start),
+            period_end: period_end.or(report.period_end),
+            total_count: total_count.unwrap_or(report.total_count),
+            matched_count: matched_count.unwrap_or(report.matched_count),
+            amount_mismatch_count: amount_mismatch_count.unwrap_or(report.amount_mismatch_count),
+            missing_in_hyperswitch_count: missing_in_hyperswitch_count
+                .unwrap_or(report.missing_in_hyperswitch_count),
+            missing_at_psp_count: missing_at_psp_count.unwrap_or(report.missing_at_psp_count),
+            error_message: error_message.or(report.error_message.take()),
+            modified_at,
+            ..report.clone()
+        };
+        Ok(report.clone())
+    }
+
+    async fn replace_settlement_recon_lines_by_report_id(
+        &self,
+        report_id: &str,
+        lines: Vec<storage::SettlementReconLineNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut recon_lines = self.settlement_recon_lines.lock().await;
+        recon_lines.retain(|line| line.report_id != report_id);
+        if let Some(line) = lines.iter().find(|line| {
+            recon_lines
+                .iter()
+                .any(|existing| existing.line_id == line.line_id)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "settlement_recon_line",
+                key: Some(line.line_id.clone()),
+            })?
+        }
+        recon_lines.extend(lines.into_iter().map(|line| storage::SettlementReconLine {
+            line_id: line.line_id,
+            report_id: line.report_id,
+            merchant_id: line.merchant_id,
+            organization_id: line.organization_id,
+            profile_id: line.profile_id,
+            connector: line.connector,
+            line_number: line.line_number,
+            transaction_type: line.transaction_type,
+            connector_transaction_id: line.connector_transaction_id,
+            status: line.status,
+            settled_amount: line.settled_amount,
+            settled_currency: line.settled_currency,
+            fee: line.fee,
+            hyperswitch_amount: line.hyperswitch_amount,
+            hyperswitch_currency: line.hyperswitch_currency,
+            payment_id: line.payment_id,
+            attempt_id: line.attempt_id,
+            refund_id: line.refund_id,
+            settled_at: line.settled_at,
+            is_resolved: line.is_resolved,
+            resolution_note: line.resolution_note,
+            resolved_at: line.resolved_at,
+            created_at: line.created_at,
+            modified_at: line.modified_at,
+        }));
+        Ok(())
+    }
+
+    async fn find_settlement_recon_line_by_merchant_id_line_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        line_id: &str,
+    ) -> CustomResult<storage::SettlementReconLine, errors::StorageError> {
+        self.settlement_recon_lines
+            .lock()
+            .await
+            .iter()
+            .find(|line| line.merchant_id == *merchant_id && line.line_id == line_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No settlement recon line found for line_id = {line_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn filter_settlement_recon_lines_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::SettlementReconLineConstraints,
+    ) -> CustomResult<Vec<storage::SettlementReconLine>, errors::StorageError> {
+        let mut recon_lines = self
+            .settlement_recon_lines
+            .lock()
+            .await
+            .iter()
+            .filter(|line| {
+                line.merchant_id == *merchant_id
+                    && constraints
+                        .report_id
+                        .as_ref()
+                        .is_none_or(|report_id| line.report_id == *report_id)
+                    && constraints
+                        .connector
+                        .as_ref()
+                        .is_none_or(|connector| line.connector == *connector)
+                    && constraints
+                        .status
+                        .as_ref()
+                        .is_none_or(|status| status.contains(&line.status))
+                    && constraints
+                        .is_resolved
+                        .is_none_or(|is_resolved| line.is_resolved == is_resolved)
+                    && constraints
+                        .profile_id
+                        .as_ref()
+                        .is_none_or(|profile_id| line.profile_id.as_ref() == Some(profile_id))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        recon_lines.sort_by(|this, other| {
+            other
+                .created_at
+                .cmp(&this.created_at)
+                .then_with(|| this.line_id.cmp(&other.line_id))
+        });
+
+        Ok(recon_lines
+            .into_iter()
+            .skip(
+                constraints
+                    .offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                constraints
+                    .limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
+    }
+
+    async fn update_settlement_recon_line(
+        &self,
+        this: storage::SettlementReconLine,
+        line_update: storage::SettlementReconLineUpdate,
+    ) -> CustomResult<storage::SettlementReconLine, errors::StorageError> {
+        let mut recon_lines = self.settlement_recon_lines.lock().await;
+        let line = recon_lines
+            .iter_mut()
+            .find(|line| line.merchant_id == this.merchant_id && line.line_id == this.line_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No settlement recon line found for line_id = {}",
+                this.line_id
+            )))?;
+        *line = storage::SettlementReconLine {
+            is_resolved: line_update.is_resolved,
+            resolution_note: line_update.resolution_note.or(line.resolution_note.take()),
+            resolved_at: line_update.resolved_at.or(line.resolved_at),
+            modified_at: line_update.modified_at,
+            ..line.clone()
+        };
+        Ok(line.clone())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_settlement_candidates_by_connector_transaction_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        transaction_type: common_enums::SettlementTransactionType,
+        connector_transaction_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::SettlementCandidate>, errors::StorageError> {
+        match transaction_type {
+            common_enums::SettlementTransactionType::Payment => Ok(self
+                .payment_attempts
+                .lock()
+                .await
+                .iter()
+                .filter(|attempt| {
+                    is_captured_attempt_of_connector(attempt, merchant_id, connector)
+                        && attempt
+                            .connector_transaction_id
+                            .as_ref()
+                            .is_some_and(|id| connector_transaction_ids.contains(id))
+                })
+                .map(get_payment_attempt_candidate)
+                .collect()),
+            common_enums::SettlementTransactionType::Refund => Ok(self
+                .refunds
+                .lock()
+                .await
+                .iter()
+                .filter(|refund| {
+                    is_succeeded_refund_of_connector(refund, merchant_id, connector)
+                        && refund
+                            .connector_refund_id
+                            .as_ref()
+                            .is_some_and(|id| connector_transaction_ids.contains(id.get_id()))
+                })
+                .map(get_refund_candidate)
+                .collect()),
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_settlement_candidates_by_time_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<storage::SettlementCandidate>, errors::StorageError> {
+        let is_within_time_range = |modified_at: time::PrimitiveDateTime| {
+            modified_at >= time_range.start_time
+                && time_range
+                    .end_time
+                    .is_none_or(|end_time| modified_at <= end_time)
+        };
+        let mut candidates = self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|attempt| {
+                is_captured_attempt_of_connector(attempt, merchant_id, connector)
+                    && is_within_time_range(attempt.modified_at)
+            })
+            .map(get_payment_attempt_candidate)
+            .collect::<Vec<_>>();
+        candidates.extend(
+            self.refunds
+                .lock()
+                .await
+                .iter()
+                .filter(|refund| {
+                    is_succeeded_refund_of_connector(refund, merchant_id, connector)
+                        && is_within_time_range(refund.modified_at)
+                })
+                .map(get_refund_candidate),
+        );
+        Ok(candidates)
+    }
+}
+
+#[cfg(feature = "v1")]
+fn is_captured_attempt_of_connector(
+    attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector: &str,
+) -> bool {
+    attempt.merchant_id == *merchant_id
+        && attempt.connector.as_deref() == Some(connector)
+        && matches!(
+            attempt.status,
+            common_enums::AttemptStatus::Charged | common_enums::AttemptStatus::PartialCharged
+        )
+}
+
+#[cfg(feature = "v1")]
+fn get_payment_attempt_candidate(
+    attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
+) -> storage::SettlementCandidate {
+    storage::SettlementCandidate {
+        transaction_type: common_enums::SettlementTransactionType::Payment,
+        connector_transaction_id: attempt.connector_transaction_id.clone(),
+        amount: attempt
+            .amount_to_capture
+            .unwrap_or(attempt.net_amount.get_total_amount()),
+        currency: attempt.currency,
+        payment_id: attempt.payment_id.clone(),
+        attempt_id: Some(attempt.attempt_id.clone()),
+        refund_id: None,
+        profile_id: Some(attempt.profile_id.clone()),
+        modified_at: attempt.modified_at,
+    }
+}
+
+#[cfg(feature = "v1")]
+fn is_succeeded_refund_of_connector(
+    refund: &diesel_models::refund::Refund,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector: &str,
+) -> bool {
+    refund.merchant_id == *merchant_id
+        && refund.connector == connector
+        && refund.refund_status == common_enums::RefundStatus::Success
+}
+
+#[cfg(feature = "v1")]
+fn get_refund_candidate(refund: &diesel_models::refund::Refund) -> storage::SettlementCandidate {
+    storage::SettlementCandidate {
+        transaction_type: common_enums::SettlementTransactionType::Refund,
+        connector_transaction_id: refund
+            .connector_refund_id
+            .as_ref()
+            .map(|connector_refund_id| connector_refund_id.get_id().clone()),
+        amount: refund.refund_amount,
+        currency: Some(refund.currency),
+        payment_id: refund.payment_id.clone(),
+        attempt_id: None,
+        refund_id: Some(refund.refund_id.clone()),
+        profile_id: refund.profile_id.clone(),
+        modified_at: refund.modified_at,
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReportInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.diesel_store
+            .insert_settlement_report(settlement_report)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_report_by_merchant_id_report_id(merchant_id, report_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_settlement_reports_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReport>, errors::StorageError> {
+        self.diesel_store
+            .list_settlement_reports_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_settlement_report(
+        &self,
+        this: storage::SettlementReport,
+        settlement_report_update: storage::SettlementReportUpdate,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.diesel_store
+            .update_settlement_report(this, settlement_report_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn replace_settlement_recon_lines_by_report_id(
+        &self,
+        report_id: &str,
+        lines: Vec<storage::SettlementReconLineNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .replace_settlement_recon_lines_by_report_id(report_id, lines)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_recon_line_by_merchant_id_line_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        line_id: &str,
+    ) -> CustomResult<storage::SettlementReconLine, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_recon_line_by_merchant_id_line_id(merchant_id, line_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_settlement_recon_lines_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &storage::SettlementReconLineConstraints,
+    ) -> CustomResult<Vec<storage::SettlementReconLine>, errors::StorageError> {
+        self.diesel_store
+            .filter_settlement_recon_lines_by_constraints(merchant_id, constraints)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_settlement_recon_line(
+        &self,
+        this: storage::SettlementReconLine,
+        line_update: storage::SettlementReconLineUpdate,
+    ) -> CustomResult<storage::SettlementReconLine, errors::StorageError> {
+        self.diesel_store
+            .update_settlement_recon_line(this, line_update)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_settlement_candidates_by_connector_transaction_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        transaction_type: common_enums::SettlementTransactionType,
+        connector_transaction_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::SettlementCandidate>, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_candidates_by_connector_transaction_ids(
+                merchant_id,
+                connector,
+                transaction_type,
+                connector_transaction_ids,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_settlement_candidates_by_time_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<storage::SettlementCandidate>, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_candidates_by_time_range(merchant_id, connector, time_range)
+            .await
+    }
+}
@@ -0,0 +1,452 @@
+#[cfg(all(feature = "v1", feature = "kv_store"))]
+use std::collections::HashMap;
+
+#[cfg(feature = "v1")]
+use error_stack::report;
+#[cfg(any(feature = "v1", feature = "kv_store"))]
+use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::behaviour::Conversion;
+#[cfg(feature = "kv_store")]
+use redis_interface::errors::RedisError;
+use router_env::{instrument, tracing};
+#[cfg(all(feature = "v1", feature = "kv_store"))]
+use storage_impl::redis::kv_store::PartitionKey;
+#[cfg(feature = "kv_store")]
+use storage_impl::redis::kv_store::RedisConnInterface;
+use storage_impl::MockDb;
+
+use super::Store;
+#[cfg(feature = "v1")]
+use crate::{connection, types::storage};
+use crate::{
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+};
+
+/// Number of drainer stream entries read at a time while looking for pending entries
+#[cfg(feature = "kv_store")]
+const DRAINER_STREAM_SCAN_COUNT: u64 = 500;
+
+/// Number of entries read from a drainer stream before the rest of it is counted as pending
+#[cfg(feature = "kv_store")]
+const DRAINER_STREAM_SCAN_LIMIT: usize = 50_000;
+
+/// Segments following the merchant id in the partition keys of the merchant specific resources
+#[cfg(feature = "kv_store")]
+const MERCHANT_PARTITION_KEY_SEGMENTS: [&str; 5] = ["pid", "cust", "po", "poa", "mandate"];
+
+/// Whether the partition key is `mid_{merchant_id}_{segment}_{id}` for the merchant, so that the
+/// keys of a merchant whose id starts with the id of another merchant are not matched
+#[cfg(feature = "kv_store")]
+fn is_merchant_partition_key(partition_key: &str, merchant_id: &str) -> bool {
+    partition_key
+        .strip_prefix("mid_")
+        .and_then(|key| key.strip_prefix(merchant_id))
+        .and_then(|key| key.strip_prefix('_'))
+        .is_some_and(|key| {
+            MERCHANT_PARTITION_KEY_SEGMENTS.iter().any(|segment| {
+                key.strip_prefix(segment)
+                    .is_some_and(|id| id.len() > 1 && id.starts_with('_'))
+            })
+        })
+}
+
+#[async_trait::async_trait]
+pub trait StorageSchemeMigrationInterface {
+    /// Counts the entries of the merchant in the drainer streams which are yet to be written to
+    /// the database
+    async fn count_pending_drainer_entries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn list_recently_modified_payment_intents(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError>;
+
+    /// Finds the database copies of the payment, its attempts and its refunds
+    #[cfg(feature = "v1")]
+    async fn find_database_payment_records(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError>;
+
+    /// Finds the redis copies of the payment, its attempts and its refunds
+    #[cfg(feature = "v1")]
+    async fn find_kv_payment_records(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl StorageSchemeMigrationInterface for Store {
+    #[cfg(feature = "kv_store")]
+    #[instrument(skip_all)]
+    async fn count_pending_drainer_entries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        // Entries are tagged with the partition key of the resource, which starts with the
+        // merchant id for the merchant specific resources
+        let merchant_id = merchant_id.get_string_repr();
+        let mut pending_entries = 0;
+
+        for stream_name in self.get_drainer_stream_names() {
+            let mut last_entry_id = "0-0".to_string();
+            let mut scanned_entries = 0;
+            loop {
+                let entries = match redis_conn
+                    .stream_read_entries(
+                        stream_name.as_str(),
+                        last_entry_id.as_str(),
+                        Some(DRAINER_STREAM_SCAN_COUNT),
+                    )
+                    .await
+                {
+                    Ok(response) => response.into_values().flatten().collect::<Vec<_>>(),
+                    Err(error) => match error.current_context() {
+                        RedisError::StreamEmptyOrNotAvailable => break,
+                        _ => Err(error).change_context(errors::StorageError::KVError)?,
+                    },
+                };
+                scanned_entries += entries.len();
+
+                pending_entries += entries
+                    .iter()
+                    .filter(|(_, fields)| {
+                        fields.get("global_id").is_some_and(|global_id| {
+                            is_merchant_partition_key(global_id, merchant_id)
+                        })
+                    })
+                    .count();
+
+                let is_last_page = entries.len()
+                    < usize::try_from(DRAINER_STREAM_SCAN_COUNT).unwrap_or(usize::MAX);
+                match entries.last() {
+                    Some((entry_id, _)) if !is_last_page => last_entry_id = entry_id.clone(),
+                    _ => break,
+                }
+
+                // The entries left unread cannot be ruled out, so they are counted as pending
+                if scanned_entries >= DRAINER_STREAM_SCAN_LIMIT {
+                    let stream_length = redis_conn
+                        .stream_get_length(&stream_name.as_str().into())
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+                    pending_entries += stream_length.saturating_sub(scanned_entries).max(1);
+                    break;
+                }
+            }
+        }
+
+        Ok(pending_entries)
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn list_recently_modified_payment_intents(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::PaymentIntent::list_recently_modified_by_processor_merchant_id(
+            &conn,
+            merchant_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_database_payment_records(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let payment_intent =
+            diesel_models::PaymentIntent::find_optional_by_payment_id_processor_merchant_id(
+                &conn,
+                payment_id,
+                merchant_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+        let payment_attempts =
+            diesel_models::PaymentAttempt::find_by_processor_merchant_id_payment_id(
+                &conn,
+                merchant_id,
+                payment_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+        let refunds = diesel_models::refund::Refund::find_by_payment_id_merchant_id(
+            &conn,
+            payment_id,
+            merchant_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        Ok(storage::PaymentRecords {
+            payment_intent,
+            payment_attempts,
+            refunds,
+        })
+    }
+
+    #[cfg(all(feature = "v1", feature = "kv_store"))]
+    #[instrument(skip_all)]
+    async fn find_kv_payment_records(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError> {
+        let key = PartitionKey::MerchantIdPaymentId {
+            merchant_id,
+            payment_id,
+        }
+        .to_string();
+        let fields = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .get_hash_fields::<HashMap<String, String>>(&key.as_str().into())
+            .await
+            .change_context(errors::StorageError::KVError)?;
+
+        let mut records = storage::PaymentRecords::default();
+        for (field, value) in fields {
+            // The payment hash holds the intent as `pi_{payment_id}`, the attempts as
+            // `pa_{attempt_id}` and the refunds as `pa_{attempt_id}_ref_{refund_id}`
+            if field.starts_with("pi_") {
+                records.payment_intent = Some(
+                    serde_json::from_str(&value)
+                        .change_context(errors::StorageError::DeserializationFailed)?,
+                );
+            } else if field.starts_with("pa_") && field.contains("_ref_") {
+                records.refunds.push(
+                    serde_json::from_str(&value)
+                        .change_context(errors::StorageError::DeserializationFailed)?,
+                );
+            } else if field.starts_with("pa_") {
+                records.payment_attempts.push(
+                    serde_json::from_str(&value)
+                        .change_context(errors::StorageError::DeserializationFailed)?,
+                );
+            }
+        }
+
+        Ok(records)
+    }
+
+    // Nothing is written to redis or to the drainer streams without the kv_store feature
+
+    #[cfg(not(feature = "kv_store"))]
+    async fn count_pending_drainer_entries_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Ok(0)
+    }
+
+    #[cfg(all(feature = "v1", not(feature = "kv_store")))]
+    async fn find_kv_payment_records(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError> {
+        Ok(storage::PaymentRecords::default())
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageSchemeMigrationInterface for MockDb {
+    // The mock store has no drainer and keeps a single copy of every record
+
+    async fn count_pending_drainer_entries_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Ok(0)
+    }
+
+    #[cfg(feature = "v1")]
+    async fn list_recently_modified_payment_intents(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        let mut payment_intents = self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| payment_intent.processor_merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_intents.sort_by(|this, other| other.modified_at.cmp(&this.modified_at));
+        payment_intents.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        let mut recently_modified_payment_intents = Vec::with_capacity(payment_intents.len());
+        for payment_intent in payment_intents {
+            recently_modified_payment_intents.push(
+                Conversion::convert(payment_intent)
+                    .await
+                    .change_context(errors::StorageError::EncryptionError)?,
+            );
+        }
+        Ok(recently_modified_payment_intents)
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_database_payment_records(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError> {
+        let payment_intent = self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .find(|payment_intent| {
+                payment_intent.processor_merchant_id == *merchant_id
+                    && payment_intent.payment_id == *payment_id
+            })
+            .cloned();
+        let payment_intent = match payment_intent {
+            Some(payment_intent) => Some(
+                Conversion::convert(payment_intent)
+                    .await
+                    .change_context(errors::StorageError::EncryptionError)?,
+            ),
+            None => None,
+        };
+
+        let payment_attempts = self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.processor_merchant_id == *merchant_id
+                    && payment_attempt.payment_id == *payment_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut database_payment_attempts = Vec::with_capacity(payment_attempts.len());
+        for payment_attempt in payment_attempts {
+            database_payment_attempts.push(
+                Conversion::convert(payment_attempt)
+                    .await
+                    .change_context(errors::StorageError::EncryptionError)?,
+            );
+        }
+
+        let refunds = self
+            .refunds
+            .lock()
+            .await
+            .iter()
+            .filter(|refund| refund.merchant_id == *merchant_id && refund.payment_id == *payment_id)
+            .cloned()
+            .collect();
+
+        Ok(storage::PaymentRecords {
+            payment_intent,
+            payment_attempts: database_payment_attempts,
+            refunds,
+        })
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_kv_payment_records(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError> {
+        Ok(storage::PaymentRecords::default())
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageSchemeMigrationInterface for KafkaStore {
+    async fn count_pending_drainer_entries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .count_pending_drainer_entries_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn list_recently_modified_payment_intents(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .list_recently_modified_payment_intents(merchant_id, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_database_payment_records(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError> {
+        self.diesel_store
+            .find_database_payment_records(merchant_id, payment_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_kv_payment_records(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentRecords, errors::StorageError> {
+        self.diesel_store
+            .find_kv_payment_records(merchant_id, payment_id)
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "kv_store"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merchant_partition_key_matches_exact_merchant_id() {
+        assert!(is_merchant_partition_key("mid_abc_pid_pay_1", "abc"));
+        assert!(is_merchant_partition_key("mid_abc_cust_cus_1", "abc"));
+        assert!(is_merchant_partition_key("mid_abc_poa_abc_1_1", "abc"));
+        assert!(is_merchant_partition_key(
+            "mid_abc_def_pid_pay_1",
+            "abc_def"
+        ));
+
+        assert!(!is_merchant_partition_key("mid_abc_def_pid_pay_1", "abc"));
+        assert!(!is_merchant_partition_key("mid_abcd_pid_pay_1", "abc"));
+        assert!(!is_merchant_partition_key("mid_abc_pid_", "abc"));
+        assert!(!is_merchant_partition_key(
+            "reverse_lookup_mid_abc_pid_pay_1",
+            "abc"
+        ));
+    }
+}
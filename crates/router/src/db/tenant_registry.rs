@@ -0,0 +1,289 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait TenantRegistryInterface {
+    async fn insert_tenant_registry(
+        &self,
+        tenant: storage::TenantRegistryNew,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError>;
+
+    async fn find_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError>;
+
+    async fn list_tenant_registries(
+        &self,
+    ) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError>;
+
+    async fn list_tenant_registries_by_status(
+        &self,
+        status: enums::TenantStatus,
+    ) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError>;
+
+    async fn update_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+        tenant_update: storage::TenantRegistryUpdate,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError>;
+
+    async fn delete_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl TenantRegistryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_tenant_registry(
+        &self,
+        tenant: storage::TenantRegistryNew,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        tenant
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::TenantRegistry::find_by_tenant_id(&conn, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_tenant_registries(
+        &self,
+    ) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::TenantRegistry::list_all(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_tenant_registries_by_status(
+        &self,
+        status: enums::TenantStatus,
+    ) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::TenantRegistry::list_by_status(&conn, status)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+        tenant_update: storage::TenantRegistryUpdate,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::TenantRegistry::update_by_tenant_id(&conn, tenant_id, tenant_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::TenantRegistry::delete_by_tenant_id(&conn, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl TenantRegistryInterface for MockDb {
+    async fn insert_tenant_registry(
+        &self,
+        tenant: storage::TenantRegistryNew,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        let mut tenant_registries = self.tenant_registries.lock().await;
+        if tenant_registries
+            .iter()
+            .any(|registry| registry.tenant_id == tenant.tenant_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "tenant_id",
+                key: Some(tenant.tenant_id.get_string_repr().to_owned()),
+            })?
+        }
+        let tenant = storage::TenantRegistry {
+            tenant_id: tenant.tenant_id,
+            base_url: tenant.base_url,
+            schema_name: tenant.schema_name,
+            accounts_schema_name: tenant.accounts_schema_name,
+            redis_key_prefix: tenant.redis_key_prefix,
+            clickhouse_database: tenant.clickhouse_database,
+            control_center_url: tenant.control_center_url,
+            status: tenant.status,
+            created_at: tenant.created_at,
+            modified_at: tenant.modified_at,
+        };
+        tenant_registries.push(tenant.clone());
+        Ok(tenant)
+    }
+
+    async fn find_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        self.tenant_registries
+            .lock()
+            .await
+            .iter()
+            .find(|registry| registry.tenant_id == *tenant_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No tenant found for tenant_id = {}",
+                    tenant_id.get_string_repr()
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_tenant_registries(
+        &self,
+    ) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError> {
+        let mut registries = self.tenant_registries.lock().await.clone();
+        registries.sort_by_key(|registry| registry.created_at);
+        Ok(registries)
+    }
+
+    async fn list_tenant_registries_by_status(
+        &self,
+        status: enums::TenantStatus,
+    ) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError> {
+        let mut registries: Vec<_> = self
+            .tenant_registries
+            .lock()
+            .await
+            .iter()
+            .filter(|registry| registry.status == status)
+            .cloned()
+            .collect();
+        registries.sort_by_key(|registry| registry.created_at);
+        Ok(registries)
+    }
+
+    async fn update_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+        tenant_update: storage::TenantRegistryUpdate,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        let mut tenant_registries = self.tenant_registries.lock().await;
+        let registry = tenant_registries
+            .iter_mut()
+            .find(|registry| registry.tenant_id == *tenant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No tenant found for tenant_id = {}",
+                tenant_id.get_string_repr()
+            )))?;
+        let diesel_models::tenant_registry::TenantRegistryUpdateInternal {
+            status,
+            modified_at,
+        } = tenant_update.into();
+        *registry = storage::TenantRegistry {
+            status: status.unwrap_or(registry.status),
+            modified_at,
+            ..registry.clone()
+        };
+        Ok(registry.clone())
+    }
+
+    async fn delete_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        let mut tenant_registries = self.tenant_registries.lock().await;
+        let position = tenant_registries
+            .iter()
+            .position(|registry| registry.tenant_id == *tenant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No tenant found for tenant_id = {}",
+                tenant_id.get_string_repr()
+            )))?;
+        Ok(tenant_registries.remove(position))
+    }
+}
+
+#[async_trait::async_trait]
+impl TenantRegistryInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_tenant_registry(
+        &self,
+        tenant: storage::TenantRegistryNew,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        self.diesel_store.insert_tenant_registry(tenant).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        self.diesel_store
+            .find_tenant_registry_by_tenant_id(tenant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_tenant_registries(
+        &self,
+    ) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError> {
+        self.diesel_store.list_tenant_registries().await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_tenant_registries_by_status(
+        &self,
+        status: enums::TenantStatus,
+    ) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError> {
+        self.diesel_store
+            .list_tenant_registries_by_status(status)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+        tenant_update: storage::TenantRegistryUpdate,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        self.diesel_store
+            .update_tenant_registry_by_tenant_id(tenant_id, tenant_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_tenant_registry_by_tenant_id(
+        &self,
+        tenant_id: &common_utils::id_type::TenantId,
+    ) -> CustomResult<storage::TenantRegistry, errors::StorageError> {
+        self.diesel_store
+            .delete_tenant_registry_by_tenant_id(tenant_id)
+            .await
+    }
+}
@@ -111,36 +111,106 @@ impl UnifiedTranslationsInterface for Store {
 impl UnifiedTranslationsInterface for MockDb {
     async fn add_unfied_translation(
         &self,
-        _translation: storage::UnifiedTranslationsNew,
+        translation: storage::UnifiedTranslationsNew,
     ) -> CustomResult<storage::UnifiedTranslations, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut unified_translations = self.unified_translations.lock().await;
+        if unified_translations.iter().any(|existing| {
+            is_translation_of(
+                existing,
+                &translation.unified_code,
+                &translation.unified_message,
+                &translation.locale,
+            )
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "unified_translation",
+                key: None,
+            })?
+        }
+        let translation = storage::UnifiedTranslations {
+            unified_code: translation.unified_code,
+            unified_message: translation.unified_message,
+            locale: translation.locale,
+            translation: translation.translation,
+            created_at: translation.created_at,
+            last_modified_at: translation.last_modified_at,
+        };
+        unified_translations.push(translation.clone());
+        Ok(translation)
     }
 
     async fn find_translation(
         &self,
-        _unified_code: String,
-        _unified_message: String,
-        _locale: String,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
     ) -> CustomResult<String, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.unified_translations
+            .lock()
+            .await
+            .iter()
+            .find(|translation| {
+                is_translation_of(translation, &unified_code, &unified_message, &locale)
+            })
+            .map(|translation| translation.translation.clone())
+            .ok_or(errors::StorageError::ValueNotFound("No translation found".to_string()).into())
     }
 
     async fn update_translation(
         &self,
-        _unified_code: String,
-        _unified_message: String,
-        _locale: String,
-        _data: storage::UnifiedTranslationsUpdate,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
+        data: storage::UnifiedTranslationsUpdate,
     ) -> CustomResult<storage::UnifiedTranslations, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut unified_translations = self.unified_translations.lock().await;
+        let translation = unified_translations
+            .iter_mut()
+            .find(|translation| {
+                is_translation_of(translation, &unified_code, &unified_message, &locale)
+            })
+            .ok_or(errors::StorageError::ValueNotFound(
+                "No translation found".to_string(),
+            ))?;
+        let storage::UnifiedTranslationsUpdateInternal {
+            translation: updated_translation,
+            last_modified_at,
+        } = data.into();
+        *translation = storage::UnifiedTranslations {
+            translation: updated_translation.unwrap_or(translation.translation.clone()),
+            last_modified_at,
+            ..translation.clone()
+        };
+        Ok(translation.clone())
     }
 
     async fn delete_translation(
         &self,
-        _unified_code: String,
-        _unified_message: String,
-        _locale: String,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
     ) -> CustomResult<bool, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut unified_translations = self.unified_translations.lock().await;
+        let position = unified_translations
+            .iter()
+            .position(|translation| {
+                is_translation_of(translation, &unified_code, &unified_message, &locale)
+            })
+            .ok_or(errors::StorageError::ValueNotFound(
+                "No translation found".to_string(),
+            ))?;
+        unified_translations.remove(position);
+        Ok(true)
     }
 }
+
+fn is_translation_of(
+    translation: &storage::UnifiedTranslations,
+    unified_code: &str,
+    unified_message: &str,
+    locale: &str,
+) -> bool {
+    translation.unified_code == unified_code
+        && translation.unified_message == unified_message
+        && translation.locale == locale
+}
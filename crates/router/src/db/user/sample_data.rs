@@ -16,6 +16,8 @@ use hyperswitch_domain_models::{
 };
 use storage_impl::errors::StorageError;
 
+#[cfg(feature = "v1")]
+use crate::db::{dispute::DisputeInterface, refund::RefundInterface};
 use crate::{connection::pg_connection_write, core::errors::CustomResult, services::Store};
 
 #[async_trait::async_trait]
@@ -253,73 +255,143 @@ impl BatchSampleDataInterface for storage_impl::MockDb {
     async fn insert_payment_intents_batch_for_sample_data(
         &self,
         _state: &KeyManagerState,
-        _batch: Vec<PaymentIntent>,
+        batch: Vec<PaymentIntent>,
         _key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        Err(StorageError::MockDbError)?
+        self.payment_intents
+            .lock()
+            .await
+            .extend(batch.iter().cloned());
+        Ok(batch)
     }
 
     #[cfg(feature = "v1")]
     async fn insert_payment_attempts_batch_for_sample_data(
         &self,
-        _batch: Vec<PaymentAttemptBatchNew>,
-        _state: &KeyManagerState,
-        _key_store: &MerchantKeyStore,
+        batch: Vec<PaymentAttemptBatchNew>,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let payment_attempts = try_join_all(batch.into_iter().map(|payment_attempt| {
+            PaymentAttempt::convert_back(
+                state,
+                payment_attempt.convert_into_payment_attempt(),
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+        }))
+        .await
+        .change_context(StorageError::DecryptionError)?;
+        self.payment_attempts
+            .lock()
+            .await
+            .extend(payment_attempts.iter().cloned());
+        Ok(payment_attempts)
     }
 
     #[cfg(feature = "v1")]
     async fn insert_refunds_batch_for_sample_data(
         &self,
-        _batch: Vec<RefundNew>,
+        batch: Vec<RefundNew>,
     ) -> CustomResult<Vec<Refund>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut refunds = Vec::with_capacity(batch.len());
+        for refund in batch {
+            refunds.push(
+                RefundInterface::insert_refund(
+                    self,
+                    refund,
+                    common_enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await?,
+            );
+        }
+        Ok(refunds)
     }
 
     #[cfg(feature = "v1")]
     async fn insert_disputes_batch_for_sample_data(
         &self,
-        _batch: Vec<DisputeNew>,
+        batch: Vec<DisputeNew>,
     ) -> CustomResult<Vec<Dispute>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut disputes = Vec::with_capacity(batch.len());
+        for dispute in batch {
+            disputes.push(DisputeInterface::insert_dispute(self, dispute).await?);
+        }
+        Ok(disputes)
     }
 
     #[cfg(feature = "v1")]
     async fn delete_payment_intents_for_sample_data(
         &self,
         _state: &KeyManagerState,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
         _key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        Err(StorageError::MockDbError)?
+        remove_sample_data(&mut *self.payment_intents.lock().await, |payment_intent| {
+            payment_intent.merchant_id == *merchant_id
+                && is_sample_data_id(payment_intent.payment_id.get_string_repr())
+        })
     }
 
     #[cfg(feature = "v1")]
     async fn delete_payment_attempts_for_sample_data(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
         _state: &KeyManagerState,
         _key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        Err(StorageError::MockDbError)?
+        remove_sample_data(
+            &mut *self.payment_attempts.lock().await,
+            |payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && is_sample_data_id(payment_attempt.payment_id.get_string_repr())
+            },
+        )
     }
 
     #[cfg(feature = "v1")]
     async fn delete_refunds_for_sample_data(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<Refund>, StorageError> {
-        Err(StorageError::MockDbError)?
+        remove_sample_data(&mut *self.refunds.lock().await, |refund| {
+            refund.merchant_id == *merchant_id
+                && is_sample_data_id(refund.payment_id.get_string_repr())
+        })
     }
 
     #[cfg(feature = "v1")]
     async fn delete_disputes_for_sample_data(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<Dispute>, StorageError> {
-        Err(StorageError::MockDbError)?
+        remove_sample_data(&mut *self.disputes.lock().await, |dispute| {
+            dispute.merchant_id == *merchant_id && is_sample_data_id(&dispute.dispute_id)
+        })
+    }
+}
+
+/// Sample data is identified by the `test_` prefix of its ids, as in the delete queries
+#[cfg(feature = "v1")]
+fn is_sample_data_id(id: &str) -> bool {
+    id.starts_with("test_")
+}
+
+/// Removes the matching records, failing like the delete queries when nothing was deleted
+#[cfg(feature = "v1")]
+fn remove_sample_data<T>(
+    records: &mut Vec<T>,
+    is_sample_data: impl Fn(&T) -> bool,
+) -> CustomResult<Vec<T>, StorageError> {
+    let (removed, retained): (Vec<T>, Vec<T>) = std::mem::take(records)
+        .into_iter()
+        .partition(is_sample_data);
+    *records = retained;
+    if removed.is_empty() {
+        Err(StorageError::ValueNotFound("Value not found".to_string()))
+            .attach_printable("No records deleted")?
     }
+    Ok(removed)
 }
 
 // TODO: This error conversion is re-used from storage_impl and is not DRY when it should be
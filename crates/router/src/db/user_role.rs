@@ -604,10 +604,9 @@ impl UserRoleInterface for MockDb {
         let mut filtered_roles = Vec::new();
 
         for role in user_roles.iter() {
-            let role_org_id = role
-                .org_id
-                .as_ref()
-                .ok_or(report!(errors::StorageError::MockDbError))?;
+            let Some(role_org_id) = role.org_id.as_ref() else {
+                continue;
+            };
 
             let mut filter_condition = role_org_id == payload.org_id;
 
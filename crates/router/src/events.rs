@@ -12,17 +12,20 @@ use time::PrimitiveDateTime;
 
 use crate::{
     db::KafkaProducer,
-    services::kafka::{KafkaMessage, KafkaSettings},
+    services::kafka::{KafkaMessage, KafkaSettings, MQResult},
 };
 
 pub mod api_logs;
 pub mod audit_events;
 pub mod connector_api_logs;
 pub mod event_logger;
+pub mod file_sink;
 pub mod outgoing_webhook_logs;
+pub mod redis_streams;
 pub mod routing_api_logs;
-#[derive(Debug, Serialize, Clone, Copy)]
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, Hash, strum::Display, strum::EnumIter)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentIntent,
     FraudCheck,
@@ -39,15 +42,23 @@ pub enum EventType {
     Authentication,
     RoutingApiLogs,
     RevenueRecovery,
+    Outbox,
+    CustomerErasure,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
 #[serde(tag = "source")]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum EventsConfig {
     Kafka {
         kafka: Box<KafkaSettings>,
     },
+    RedisStreams {
+        redis_streams: Box<redis_streams::RedisStreamsSettings>,
+    },
+    File {
+        file: Box<file_sink::FileSinkSettings>,
+    },
     #[default]
     Logs,
 }
@@ -56,6 +67,10 @@ pub enum EventsConfig {
 #[derive(Debug, Clone)]
 pub enum EventsHandler {
     Kafka(KafkaProducer),
+    /// Publishes the Kafka messages to Redis Streams
+    RedisStreams(KafkaProducer),
+    /// Publishes the Kafka messages to rotating files
+    File(KafkaProducer),
     Logs(event_logger::EventLogger),
 }
 
@@ -79,6 +94,16 @@ impl EventsConfig {
                     .await
                     .change_context(StorageError::InitializationError)?,
             ),
+            Self::RedisStreams { redis_streams } => {
+                EventsHandler::RedisStreams(KafkaProducer::with_redis_streams(
+                    redis_streams::RedisStreamsSink::create(redis_streams)
+                        .await
+                        .change_context(StorageError::InitializationError)?,
+                ))
+            }
+            Self::File { file } => EventsHandler::File(KafkaProducer::with_file_sink(
+                file_sink::FileSink::create(file).await,
+            )),
             Self::Logs => EventsHandler::Logs(event_logger::EventLogger::default()),
         })
     }
@@ -86,6 +111,8 @@ impl EventsConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         match self {
             Self::Kafka { kafka } => kafka.validate(),
+            Self::RedisStreams { redis_streams } => redis_streams.validate(),
+            Self::File { file } => file.validate(),
             Self::Logs => Ok(()),
         }
     }
@@ -94,15 +121,55 @@ impl EventsConfig {
 impl EventsHandler {
     pub fn log_event<T: KafkaMessage>(&self, event: &T) {
         match self {
-            Self::Kafka(kafka) => kafka.log_event(event).unwrap_or_else(|e| {
-                logger::error!("Failed to log event: {:?}", e);
-            }),
+            Self::Kafka(kafka) | Self::RedisStreams(kafka) | Self::File(kafka) => {
+                kafka.log_event(event).unwrap_or_else(|e| {
+                    logger::error!("Failed to log event: {:?}", e);
+                })
+            }
             Self::Logs(logger) => logger.log_event(event),
         };
     }
+
+    /// Publishes the event, returning the failure to the caller instead of logging it. The logs
+    /// handler never fails.
+    pub fn publish_event<T: KafkaMessage>(&self, event: &T) -> MQResult<()> {
+        match self {
+            Self::Kafka(kafka) | Self::RedisStreams(kafka) | Self::File(kafka) => {
+                kafka.log_event(event)
+            }
+            Self::Logs(logger) => {
+                logger.log_event(event);
+                Ok(())
+            }
+        }
+    }
+
+    /// Publishes the events and waits for their acknowledgement, returning the result of each
+    /// event in order. The logs handler never fails.
+    pub async fn publish_events<T: KafkaMessage>(
+        &self,
+        events: &[T],
+    ) -> MQResult<Vec<MQResult<()>>> {
+        match self {
+            Self::Kafka(kafka) | Self::RedisStreams(kafka) | Self::File(kafka) => {
+                kafka.publish_events(events).await
+            }
+            Self::Logs(logger) => Ok(events
+                .iter()
+                .map(|event| {
+                    logger.log_event(event);
+                    Ok(())
+                })
+                .collect()),
+        }
+    }
+
     pub fn add_tenant(&mut self, tenant_config: &dyn TenantConfig) {
-        if let Self::Kafka(kafka_producer) = self {
-            kafka_producer.set_tenancy(tenant_config);
+        match self {
+            Self::Kafka(kafka_producer)
+            | Self::RedisStreams(kafka_producer)
+            | Self::File(kafka_producer) => kafka_producer.set_tenancy(tenant_config),
+            Self::Logs(_) => {}
         }
     }
 }
@@ -120,7 +187,9 @@ impl MessagingInterface for EventsHandler {
         T: Message<Class = Self::MessageClass> + ErasedMaskSerialize,
     {
         match self {
-            Self::Kafka(a) => a.send_message(data, metadata, timestamp),
+            Self::Kafka(a) | Self::RedisStreams(a) | Self::File(a) => {
+                a.send_message(data, metadata, timestamp)
+            }
             Self::Logs(a) => a.send_message(data, metadata, timestamp),
         }
     }
@@ -0,0 +1,521 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError, Weak},
+    time::{Duration, Instant},
+};
+
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt, fp_utils::when};
+use error_stack::ResultExt;
+use events::EventsError;
+use external_services::file_storage::{FileStorageConfig, FileStorageInterface};
+use router_env::{logger, tracing::Instrument};
+use serde::Deserialize;
+use storage_impl::errors::ApplicationError;
+
+use super::EventType;
+
+#[derive(Debug, Default, Deserialize, Clone, Copy, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventFileFormat {
+    /// One JSON document per line
+    #[default]
+    Ndjson,
+    /// Columnar files whose schema is inferred from the events of each file. Requires the
+    /// `events_parquet` feature
+    Parquet,
+}
+
+impl EventFileFormat {
+    fn get_extension(self) -> &'static str {
+        match self {
+            Self::Ndjson => "ndjson",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FileSinkSettings {
+    /// The storage the event files are uploaded to
+    pub file_storage: FileStorageConfig,
+    /// Prefix of the event files. The files are stored under
+    /// `{path_prefix}/{clickhouse_database}/{event_type}/{yyyy}/{mm}/{dd}/`
+    pub path_prefix: String,
+    pub format: EventFileFormat,
+    /// Number of events after which a file is rotated
+    pub max_events_per_file: usize,
+    /// Size of the buffered events, in bytes, after which a file is rotated
+    pub max_bytes_per_file: usize,
+    /// Age of a file, in seconds, after which it is rotated regardless of its size
+    pub rotation_interval: u64,
+}
+
+impl Default for FileSinkSettings {
+    fn default() -> Self {
+        Self {
+            file_storage: FileStorageConfig::default(),
+            path_prefix: "events".to_string(),
+            format: EventFileFormat::default(),
+            max_events_per_file: 10_000,
+            max_bytes_per_file: 64 * 1024 * 1024,
+            rotation_interval: 300,
+        }
+    }
+}
+
+impl FileSinkSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.file_storage
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
+
+        when(self.path_prefix.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Events file path prefix must not be empty".into(),
+            ))
+        })?;
+
+        when(
+            self.max_events_per_file == 0
+                || self.max_bytes_per_file == 0
+                || self.rotation_interval == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Events file rotation limits must be greater than 0".into(),
+                ))
+            },
+        )?;
+
+        when(
+            matches!(self.format, EventFileFormat::Parquet) && !cfg!(feature = "events_parquet"),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Parquet event files require the `events_parquet` feature".into(),
+                ))
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct FileBufferKey {
+    clickhouse_database: Option<String>,
+    event_type: EventType,
+}
+
+#[derive(Debug)]
+struct FileBuffer {
+    records: Vec<Vec<u8>>,
+    size: usize,
+    opened_at: Instant,
+}
+
+type FileBuffers = Mutex<HashMap<FileBufferKey, FileBuffer>>;
+
+/// Buffers events in memory and uploads them as rotating files through the configured
+/// [`FileStorageInterface`]. The events are serialized in the same layout as the Kafka messages,
+/// and a file only ever holds the events of a single type and ClickHouse database.
+#[derive(Clone)]
+pub struct FileSink {
+    file_storage: Arc<dyn FileStorageInterface>,
+    settings: Arc<FileSinkSettings>,
+    buffers: Arc<FileBuffers>,
+}
+
+impl std::fmt::Debug for FileSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileSink")
+            .field("settings", &self.settings)
+            .finish()
+    }
+}
+
+impl FileSink {
+    pub async fn create(conf: &FileSinkSettings) -> Self {
+        let sink = Self {
+            file_storage: conf.file_storage.get_file_storage_client().await,
+            settings: Arc::new(conf.clone()),
+            buffers: Arc::new(Mutex::new(HashMap::new())),
+        };
+
+        tokio::spawn(
+            Self::rotate_periodically(
+                Arc::clone(&sink.file_storage),
+                Arc::clone(&sink.settings),
+                Arc::downgrade(&sink.buffers),
+            )
+            .in_current_span(),
+        );
+
+        sink
+    }
+
+    /// Buffers the event, uploading the file of its type once it reaches the configured limits.
+    pub fn write(
+        &self,
+        event_type: EventType,
+        clickhouse_database: Option<&str>,
+        payload: &[u8],
+    ) -> CustomResult<(), EventsError> {
+        let key = FileBufferKey {
+            clickhouse_database: clickhouse_database.map(ToString::to_string),
+            event_type,
+        };
+
+        let full_buffer = {
+            let mut buffers = self.buffers.lock().unwrap_or_else(PoisonError::into_inner);
+            let buffer = buffers.entry(key.clone()).or_insert_with(|| FileBuffer {
+                records: Vec::new(),
+                size: 0,
+                opened_at: Instant::now(),
+            });
+            buffer.records.push(payload.to_vec());
+            buffer.size += payload.len();
+
+            let is_full = buffer.records.len() >= self.settings.max_events_per_file
+                || buffer.size >= self.settings.max_bytes_per_file;
+            is_full.then(|| buffers.remove(&key)).flatten()
+        };
+
+        if let Some(buffer) = full_buffer {
+            Self::spawn_upload(
+                Arc::clone(&self.file_storage),
+                Arc::clone(&self.settings),
+                key,
+                buffer,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Uploads the events as a file of their own, bypassing the buffers, for the callers that have
+    /// to know whether the events were stored.
+    pub async fn upload(
+        &self,
+        event_type: EventType,
+        clickhouse_database: Option<&str>,
+        payloads: &[Vec<u8>],
+    ) -> CustomResult<(), EventsError> {
+        let key = FileBufferKey {
+            clickhouse_database: clickhouse_database.map(ToString::to_string),
+            event_type,
+        };
+        let file_key = get_file_key(&self.settings, &key);
+        let file = encode_records(self.settings.format, payloads)?;
+
+        self.file_storage
+            .upload_file(&file_key, file)
+            .await
+            .change_context(EventsError::PublishError)
+    }
+
+    async fn rotate_periodically(
+        file_storage: Arc<dyn FileStorageInterface>,
+        settings: Arc<FileSinkSettings>,
+        buffers: Weak<FileBuffers>,
+    ) {
+        let rotation_interval = Duration::from_secs(settings.rotation_interval);
+        let mut interval = tokio::time::interval(rotation_interval);
+        loop {
+            interval.tick().await;
+            // The task stops along with the last clone of the sink
+            let Some(buffers) = buffers.upgrade() else {
+                break;
+            };
+
+            let expired_buffers = {
+                let mut buffers = buffers.lock().unwrap_or_else(PoisonError::into_inner);
+                let expired_keys = buffers
+                    .iter()
+                    .filter(|(_, buffer)| buffer.opened_at.elapsed() >= rotation_interval)
+                    .map(|(key, _)| key.clone())
+                    .collect::<Vec<_>>();
+                expired_keys
+                    .into_iter()
+                    .filter_map(|key| buffers.remove(&key).map(|buffer| (key, buffer)))
+                    .collect::<Vec<_>>()
+            };
+
+            for (key, buffer) in expired_buffers {
+                Self::spawn_upload(
+                    Arc::clone(&file_storage),
+                    Arc::clone(&settings),
+                    key,
+                    buffer,
+                );
+            }
+        }
+    }
+
+    fn spawn_upload(
+        file_storage: Arc<dyn FileStorageInterface>,
+        settings: Arc<FileSinkSettings>,
+        key: FileBufferKey,
+        buffer: FileBuffer,
+    ) {
+        tokio::spawn(
+            async move {
+                let file_key = get_file_key(&settings, &key);
+                let result = match encode_records(settings.format, &buffer.records) {
+                    Ok(file) => file_storage
+                        .upload_file(&file_key, file)
+                        .await
+                        .change_context(EventsError::PublishError),
+                    Err(error) => Err(error),
+                };
+
+                match result {
+                    Ok(()) => logger::debug!(%file_key, events = buffer.records.len(), "Uploaded events file"),
+                    Err(error) => logger::error!(
+                        ?error,
+                        %file_key,
+                        events = buffer.records.len(),
+                        "Failed to upload events file"
+                    ),
+                }
+            }
+            .in_current_span(),
+        );
+    }
+}
+
+fn get_file_key(settings: &FileSinkSettings, key: &FileBufferKey) -> String {
+    let now = common_utils::date_time::now();
+    format!(
+        "{}/{}/{}/{:04}/{:02}/{:02}/{}.{}",
+        settings.path_prefix,
+        key.clickhouse_database.as_deref().unwrap_or("default"),
+        key.event_type,
+        now.year(),
+        u8::from(now.month()),
+        now.day(),
+        common_utils::generate_time_ordered_id_without_prefix(),
+        settings.format.get_extension(),
+    )
+}
+
+fn encode_records(
+    format: EventFileFormat,
+    records: &[Vec<u8>],
+) -> CustomResult<Vec<u8>, EventsError> {
+    match format {
+        EventFileFormat::Ndjson => Ok(records
+            .iter()
+            .flat_map(|record| record.iter().copied().chain(std::iter::once(b'\n')))
+            .collect()),
+        #[cfg(feature = "events_parquet")]
+        EventFileFormat::Parquet => encode_parquet(records),
+        #[cfg(not(feature = "events_parquet"))]
+        EventFileFormat::Parquet => Err(EventsError::SerializationError)
+            .attach_printable("Parquet event files require the `events_parquet` feature"),
+    }
+}
+
+#[cfg(feature = "events_parquet")]
+fn encode_parquet(records: &[Vec<u8>]) -> CustomResult<Vec<u8>, EventsError> {
+    let values = records
+        .iter()
+        .map(|record| serde_json::from_slice::<serde_json::Value>(record))
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(EventsError::SerializationError)?;
+
+    crate::utils::json_files::encode_parquet(&values)
+        .change_context(EventsError::SerializationError)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use external_services::file_storage::FileStorageError;
+
+    use super::*;
+
+    /// Keeps the uploaded files in memory
+    #[derive(Clone, Default)]
+    struct RecordingFileStorage {
+        files: Arc<Mutex<Vec<(String, Vec<u8>)>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl FileStorageInterface for RecordingFileStorage {
+        async fn upload_file(
+            &self,
+            file_key: &str,
+            file: Vec<u8>,
+        ) -> CustomResult<(), FileStorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .push((file_key.to_string(), file));
+            Ok(())
+        }
+
+        async fn delete_file(&self, _file_key: &str) -> CustomResult<(), FileStorageError> {
+            Ok(())
+        }
+
+        async fn retrieve_file(&self, _file_key: &str) -> CustomResult<Vec<u8>, FileStorageError> {
+            Err(FileStorageError::RetrieveFailed.into())
+        }
+    }
+
+    fn file_sink(settings: FileSinkSettings) -> (FileSink, RecordingFileStorage) {
+        let file_storage = RecordingFileStorage::default();
+        let sink = FileSink {
+            file_storage: Arc::new(file_storage.clone()),
+            settings: Arc::new(settings),
+            buffers: Arc::new(Mutex::new(HashMap::new())),
+        };
+        (sink, file_storage)
+    }
+
+    /// Waits for the uploads spawned by the sink to complete
+    async fn get_uploaded_files(
+        file_storage: &RecordingFileStorage,
+        count: usize,
+    ) -> Vec<(String, Vec<u8>)> {
+        for _ in 0..100 {
+            if file_storage.files.lock().unwrap().len() >= count {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        file_storage.files.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn test_validate_settings() {
+        assert!(FileSinkSettings::default().validate().is_ok());
+        assert!(FileSinkSettings {
+            path_prefix: String::new(),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+        assert!(FileSinkSettings {
+            max_events_per_file: 0,
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+        assert!(FileSinkSettings {
+            rotation_interval: 0,
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+        assert_eq!(
+            FileSinkSettings {
+                format: EventFileFormat::Parquet,
+                ..Default::default()
+            }
+            .validate()
+            .is_ok(),
+            cfg!(feature = "events_parquet")
+        );
+    }
+
+    #[test]
+    fn test_encode_ndjson_records() {
+        let file = encode_records(
+            EventFileFormat::Ndjson,
+            &[br#"{"id":1}"#.to_vec(), br#"{"id":2}"#.to_vec()],
+        )
+        .unwrap();
+
+        assert_eq!(file, b"{\"id\":1}\n{\"id\":2}\n");
+    }
+
+    #[test]
+    fn test_file_key() {
+        let settings = FileSinkSettings::default();
+        let now = common_utils::date_time::now();
+        let date = format!(
+            "{:04}/{:02}/{:02}",
+            now.year(),
+            u8::from(now.month()),
+            now.day()
+        );
+
+        let file_key = get_file_key(
+            &settings,
+            &FileBufferKey {
+                clickhouse_database: Some("analytics".to_string()),
+                event_type: EventType::PaymentIntent,
+            },
+        );
+        assert!(file_key.starts_with(&format!("events/analytics/payment_intent/{date}/")));
+        assert!(file_key.ends_with(".ndjson"));
+
+        let file_key = get_file_key(
+            &settings,
+            &FileBufferKey {
+                clickhouse_database: None,
+                event_type: EventType::Refund,
+            },
+        );
+        assert!(file_key.starts_with(&format!("events/default/refund/{date}/")));
+    }
+
+    #[tokio::test]
+    async fn test_write_rotates_file_after_max_events() {
+        let (sink, file_storage) = file_sink(FileSinkSettings {
+            max_events_per_file: 2,
+            ..Default::default()
+        });
+
+        sink.write(EventType::Refund, Some("analytics"), b"{\"id\":1}")
+            .unwrap();
+        sink.write(EventType::Dispute, Some("analytics"), b"{\"id\":2}")
+            .unwrap();
+        assert!(get_uploaded_files(&file_storage, 1).await.is_empty());
+
+        sink.write(EventType::Refund, Some("analytics"), b"{\"id\":3}")
+            .unwrap();
+        let files = get_uploaded_files(&file_storage, 1).await;
+
+        // Only the events of the full buffer are uploaded
+        assert_eq!(files.len(), 1);
+        let (file_key, file) = files.first().unwrap();
+        assert!(file_key.starts_with("events/analytics/refund/"));
+        assert_eq!(file, b"{\"id\":1}\n{\"id\":3}\n");
+        assert_eq!(sink.buffers.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_write_rotates_file_after_max_bytes() {
+        let (sink, file_storage) = file_sink(FileSinkSettings {
+            max_bytes_per_file: 10,
+            ..Default::default()
+        });
+
+        sink.write(EventType::Refund, None, b"{\"id\":1}").unwrap();
+        sink.write(EventType::Refund, None, b"{\"id\":2}").unwrap();
+        let files = get_uploaded_files(&file_storage, 1).await;
+
+        assert_eq!(files.len(), 1);
+        assert!(sink.buffers.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_upload_bypasses_buffers() {
+        let (sink, file_storage) = file_sink(FileSinkSettings::default());
+
+        sink.upload(
+            EventType::Refund,
+            Some("analytics"),
+            &[b"{\"id\":1}".to_vec()],
+        )
+        .await
+        .unwrap();
+
+        let files = file_storage.files.lock().unwrap().clone();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files.first().unwrap().1, b"{\"id\":1}\n");
+        assert!(sink.buffers.lock().unwrap().is_empty());
+    }
+}
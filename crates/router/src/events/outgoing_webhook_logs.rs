@@ -41,6 +41,10 @@ pub enum OutgoingWebhookEventContent {
         payout_id: common_utils::id_type::PayoutId,
         content: Value,
     },
+    PayoutBatch {
+        payout_batch_id: String,
+        content: Value,
+    },
     #[cfg(feature = "v1")]
     Refund {
         payment_id: common_utils::id_type::PaymentId,
@@ -78,6 +82,16 @@ pub enum OutgoingWebhookEventContent {
         payment_id: Option<common_utils::id_type::PaymentId>,
         content: Value,
     },
+    Customer {
+        customer_id: common_utils::id_type::CustomerId,
+        merge_id: String,
+        content: Value,
+    },
+    PaymentMethod {
+        customer_id: common_utils::id_type::CustomerId,
+        payment_method_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -117,6 +131,14 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: hyperswitch_masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            #[cfg(feature = "payouts")]
+            Self::PayoutBatchDetails(payout_batch_payload) => {
+                Some(OutgoingWebhookEventContent::PayoutBatch {
+                    payout_batch_id: payout_batch_payload.payout_batch_id.clone(),
+                    content: hyperswitch_masking::masked_serialize(&payout_batch_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
             Self::SubscriptionDetails(subscription) => {
                 Some(OutgoingWebhookEventContent::Subscription {
                     subscription_id: subscription.id.clone(),
@@ -126,6 +148,20 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                         .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
                 })
             }
+            Self::CustomerDetails(customer_merge) => Some(OutgoingWebhookEventContent::Customer {
+                customer_id: customer_merge.customer_id.clone(),
+                merge_id: customer_merge.merge_id.clone(),
+                content: hyperswitch_masking::masked_serialize(&customer_merge)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
+            Self::PaymentMethodDetails(payment_method) => {
+                Some(OutgoingWebhookEventContent::PaymentMethod {
+                    customer_id: payment_method.customer_id.clone(),
+                    payment_method_id: payment_method.payment_method_id.clone(),
+                    content: hyperswitch_masking::masked_serialize(&payment_method)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
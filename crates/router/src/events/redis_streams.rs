@@ -0,0 +1,354 @@
+use std::{collections::HashSet, sync::Arc};
+
+use common_utils::{
+    errors::CustomResult, ext_traits::ConfigExt, fp_utils::when, types::TenantConfig,
+};
+use error_stack::ResultExt;
+use events::EventsError;
+use redis_interface::{RedisConnectionPool, RedisEntryId, RedisKey, RedisSettings};
+use router_env::{logger, tracing::Instrument};
+use serde::Deserialize;
+use storage_impl::errors::ApplicationError;
+use tokio::sync::Mutex;
+
+use super::EventType;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RedisStreamsSettings {
+    /// The Redis instance holding the event streams
+    pub redis: RedisSettings,
+    /// Prefix of the stream keys. Each event type is appended to its own stream, named
+    /// `{stream_prefix}:{event_type}` and prefixed with the Redis key prefix of the tenant
+    pub stream_prefix: String,
+    /// Approximate number of entries retained in each stream
+    pub max_len: u64,
+    /// Consumer groups created on every stream before its first event is appended, so that
+    /// loaders started later do not miss the events appended in between
+    pub consumer_groups: Vec<String>,
+}
+
+impl Default for RedisStreamsSettings {
+    fn default() -> Self {
+        Self {
+            redis: RedisSettings::default(),
+            stream_prefix: "hyperswitch_events".to_string(),
+            max_len: 1_000_000,
+            consumer_groups: Vec::new(),
+        }
+    }
+}
+
+impl RedisStreamsSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.redis.validate().map_err(|error| {
+            ApplicationError::InvalidConfigurationValueError(format!(
+                "Redis streams events configuration: {error}"
+            ))
+        })?;
+
+        when(self.stream_prefix.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Redis streams prefix must not be empty".into(),
+            ))
+        })?;
+
+        when(self.max_len == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Redis streams max_len must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+/// Appends events to Redis Streams, one stream per event type and tenant. Each entry holds the key
+/// of the event, its type and its payload serialized in the same layout as the Kafka messages.
+#[derive(Clone)]
+pub struct RedisStreamsSink {
+    redis_conn: Arc<RedisConnectionPool>,
+    stream_prefix: String,
+    max_len: u64,
+    consumer_groups: Arc<Vec<String>>,
+    /// Streams whose consumer groups were created by this process, by tenant aware key
+    initialized_streams: Arc<Mutex<HashSet<String>>>,
+}
+
+impl std::fmt::Debug for RedisStreamsSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisStreamsSink")
+            .field("stream_prefix", &self.stream_prefix)
+            .field("max_len", &self.max_len)
+            .finish()
+    }
+}
+
+impl RedisStreamsSink {
+    pub async fn create(conf: &RedisStreamsSettings) -> CustomResult<Self, EventsError> {
+        let redis_conn = RedisConnectionPool::new(&conf.redis)
+            .await
+            .change_context(EventsError::GenericError)
+            .attach_printable("Failed to create Redis connection pool for the event streams")?;
+
+        Ok(Self {
+            redis_conn: Arc::new(redis_conn),
+            stream_prefix: conf.stream_prefix.clone(),
+            max_len: conf.max_len,
+            consumer_groups: Arc::new(conf.consumer_groups.clone()),
+            initialized_streams: Arc::new(Mutex::new(HashSet::new())),
+        })
+    }
+
+    /// Prefixes the streams with the Redis key prefix of the tenant, so that tenants do not share
+    /// streams
+    pub fn set_tenancy(&mut self, tenant_config: &dyn TenantConfig) {
+        self.redis_conn = Arc::new(RedisConnectionPool::clone(
+            &self.redis_conn,
+            tenant_config.get_redis_key_prefix(),
+        ));
+    }
+
+    pub fn get_stream_key(&self, event_type: EventType) -> String {
+        format!("{}:{event_type}", self.stream_prefix)
+    }
+
+    /// Appends the event to the stream of its type, trimming the stream to roughly `max_len`
+    /// entries in the same command.
+    pub async fn append(
+        &self,
+        event_type: EventType,
+        key: String,
+        payload: &[u8],
+        headers: Vec<(String, String)>,
+        timestamp_millis: i64,
+    ) -> CustomResult<(), EventsError> {
+        let fields = get_entry_fields(event_type, key, payload, headers, timestamp_millis)?;
+        self.append_entry(event_type, fields).await
+    }
+
+    /// Appends the event in the background, for the callers that do not wait for the event to be
+    /// stored. Only a malformed event is returned as an error, a failed append is logged.
+    pub fn append_in_background(
+        &self,
+        event_type: EventType,
+        key: String,
+        payload: &[u8],
+        headers: Vec<(String, String)>,
+        timestamp_millis: i64,
+    ) -> CustomResult<(), EventsError> {
+        let fields = get_entry_fields(event_type, key, payload, headers, timestamp_millis)?;
+        let sink = self.clone();
+        tokio::spawn(
+            async move {
+                if let Err(error) = sink.append_entry(event_type, fields).await {
+                    logger::error!(?error, "Failed to append event to Redis stream");
+                }
+            }
+            .in_current_span(),
+        );
+
+        Ok(())
+    }
+
+    async fn append_entry(
+        &self,
+        event_type: EventType,
+        fields: Vec<(String, String)>,
+    ) -> CustomResult<(), EventsError> {
+        let stream = self.get_stream_key(event_type).as_str().into();
+        self.create_consumer_groups(&stream).await;
+
+        self.redis_conn
+            .stream_append_entry_with_cap(
+                &stream,
+                &RedisEntryId::AutoGeneratedID,
+                fields,
+                ("MAXLEN", "~", self.max_len.to_string()),
+            )
+            .await
+            .change_context(EventsError::PublishError)
+            .attach_printable("Failed to append event to Redis stream")
+    }
+
+    /// Creates the consumer groups of the stream, once per stream and process
+    async fn create_consumer_groups(&self, stream: &RedisKey) {
+        if self.consumer_groups.is_empty() {
+            return;
+        }
+
+        let stream_key = stream.tenant_aware_key(&self.redis_conn);
+        let mut initialized_streams = self.initialized_streams.lock().await;
+        if initialized_streams.contains(&stream_key) {
+            return;
+        }
+
+        for group in self.consumer_groups.iter() {
+            // Creating a group which already exists fails with `BUSYGROUP`, which is expected
+            // on every restart
+            if let Err(error) = self
+                .redis_conn
+                .consumer_group_create(stream, group, &RedisEntryId::AfterLastID)
+                .await
+            {
+                logger::debug!(?error, %stream_key, %group, "Consumer group not created");
+            }
+        }
+        initialized_streams.insert(stream_key);
+    }
+}
+
+fn get_entry_fields(
+    event_type: EventType,
+    key: String,
+    payload: &[u8],
+    headers: Vec<(String, String)>,
+    timestamp_millis: i64,
+) -> CustomResult<Vec<(String, String)>, EventsError> {
+    let payload = String::from_utf8(payload.to_vec())
+        .change_context(EventsError::SerializationError)
+        .attach_printable("Event payload is not valid UTF-8")?;
+
+    let mut fields = vec![
+        ("key".to_string(), key),
+        ("event_type".to_string(), event_type.to_string()),
+        ("timestamp".to_string(), timestamp_millis.to_string()),
+        ("payload".to_string(), payload),
+    ];
+    fields.extend(headers);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::id_type;
+    use hyperswitch_interfaces::configs::GlobalTenant;
+
+    use super::*;
+
+    fn settings(stream_prefix: &str, max_len: u64) -> RedisStreamsSettings {
+        RedisStreamsSettings {
+            redis: RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            },
+            stream_prefix: stream_prefix.to_string(),
+            max_len,
+            consumer_groups: vec!["loader".to_string()],
+        }
+    }
+
+    fn tenant(redis_key_prefix: &str) -> GlobalTenant {
+        GlobalTenant {
+            tenant_id: id_type::TenantId::get_default_global_tenant_id(),
+            schema: "public".to_string(),
+            redis_key_prefix: redis_key_prefix.to_string(),
+            clickhouse_database: "default".to_string(),
+        }
+    }
+
+    async fn get_stream_length(sink: &RedisStreamsSink, event_type: EventType) -> usize {
+        sink.redis_conn
+            .stream_get_length(&sink.get_stream_key(event_type).as_str().into())
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validate_settings() {
+        assert!(settings("events", 10).validate().is_ok());
+        assert!(settings("", 10).validate().is_err());
+        assert!(settings("events", 0).validate().is_err());
+    }
+
+    #[test]
+    fn test_entry_fields() {
+        let fields = get_entry_fields(
+            EventType::Refund,
+            "ref_1".to_string(),
+            br#"{"refund_id":"ref_1"}"#,
+            vec![("tenant".to_string(), "public".to_string())],
+            1_700_000_000_000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            fields,
+            vec![
+                ("key".to_string(), "ref_1".to_string()),
+                ("event_type".to_string(), "refund".to_string()),
+                ("timestamp".to_string(), "1700000000000".to_string()),
+                (
+                    "payload".to_string(),
+                    r#"{"refund_id":"ref_1"}"#.to_string()
+                ),
+                ("tenant".to_string(), "public".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entry_fields_of_payload_which_is_not_utf8() {
+        let error = get_entry_fields(
+            EventType::Refund,
+            "ref_1".to_string(),
+            &[0xff, 0xfe],
+            Vec::new(),
+            0,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            EventsError::SerializationError
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_append_trims_stream_to_max_len() {
+        let sink = RedisStreamsSink::create(&settings("test_events_max_len", 2))
+            .await
+            .unwrap();
+
+        for refund_id in ["ref_1", "ref_2", "ref_3"] {
+            sink.append(
+                EventType::Refund,
+                refund_id.to_string(),
+                b"{}",
+                Vec::new(),
+                0,
+            )
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(get_stream_length(&sink, EventType::Refund).await, 2);
+        assert_eq!(get_stream_length(&sink, EventType::Dispute).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_streams_are_prefixed_per_tenant() {
+        let sink = RedisStreamsSink::create(&settings("test_events_tenancy", 10))
+            .await
+            .unwrap();
+        let mut first_tenant_sink = sink.clone();
+        first_tenant_sink.set_tenancy(&tenant("first_tenant"));
+        let mut second_tenant_sink = sink.clone();
+        second_tenant_sink.set_tenancy(&tenant("second_tenant"));
+
+        first_tenant_sink
+            .append(EventType::Refund, "ref_1".to_string(), b"{}", Vec::new(), 0)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            get_stream_length(&first_tenant_sink, EventType::Refund).await,
+            1
+        );
+        assert_eq!(
+            get_stream_length(&second_tenant_sink, EventType::Refund).await,
+            0
+        );
+        assert_eq!(get_stream_length(&sink, EventType::Refund).await, 0);
+    }
+}
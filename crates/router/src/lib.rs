@@ -218,6 +218,11 @@ pub fn mk_app(
                 .service(routes::Disputes::server(state.clone()))
                 .service(routes::Blocklist::server(state.clone()))
                 .service(routes::CardIssuers::server(state.clone()))
+                .service(routes::Ledger::server(state.clone()))
+                .service(routes::SettlementRecon::server(state.clone()))
+                .service(routes::Tenants::server(state.clone()))
+                .service(routes::DataRetention::server(state.clone()))
+                .service(routes::AccountUpdater::server(state.clone()))
                 .service(routes::Subscription::server(state.clone()))
                 .service(routes::Gsm::server(state.clone()))
                 .service(routes::ApplePayCertificatesMigration::server(state.clone()))
@@ -280,15 +285,27 @@ pub fn mk_app(
 /// # Panics
 ///
 ///  Unwrap used because without the value we can't start the server
-#[allow(clippy::expect_used, clippy::unwrap_used)]
 pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> ApplicationResult<Server> {
+    Box::pin(start_server_with_storage(conf, db::StorageImpl::Postgresql)).await
+}
+
+/// Starts the server backed by the storage provided
+///
+/// # Panics
+///
+///  Unwrap used because without the value we can't start the server
+#[allow(clippy::expect_used, clippy::unwrap_used)]
+pub async fn start_server_with_storage(
+    conf: settings::Settings<SecuredSecret>,
+    storage_impl: db::StorageImpl,
+) -> ApplicationResult<Server> {
     logger::debug!(startup_config=?conf);
     let server = conf.server.clone();
     let (tx, rx) = oneshot::channel();
     let api_client = Box::new(services::ProxyClient::new(&conf.proxy).map_err(|error| {
         errors::ApplicationError::ApiClientError(error.current_context().clone())
     })?);
-    let state = Box::pin(AppState::new(conf, tx, api_client)).await;
+    let state = Box::pin(AppState::with_storage(conf, storage_impl, tx, api_client)).await;
     let request_body_limit = server.request_body_limit;
 
     let server_builder =
@@ -1,3 +1,5 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod account_updater;
 pub mod admin;
 pub mod api_keys;
 pub mod app;
@@ -15,6 +17,8 @@ pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod data_retention;
 pub mod disputes;
 #[cfg(feature = "dummy_connector")]
 pub mod dummy_connector;
@@ -26,6 +30,8 @@ pub mod fraud_check;
 pub mod gsm;
 pub mod health;
 pub mod hypersense;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod ledger;
 pub mod lock_utils;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
@@ -56,9 +62,13 @@ pub mod revenue_recovery_data_backfill;
 pub mod revenue_recovery_redis;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod settlement_recon;
 #[cfg(feature = "v1")]
 pub mod subscription;
 pub mod superposition_sdk_config;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod tenant_registry;
 pub mod three_ds_decision_rule;
 pub mod tokenization;
 #[cfg(feature = "olap")]
@@ -86,14 +96,24 @@ pub mod proxy;
 
 pub mod chat;
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::AccountUpdater;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::DataRetention;
 #[cfg(feature = "dummy_connector")]
 pub use self::app::DummyConnector;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::Ledger;
 #[cfg(feature = "v2")]
 pub use self::app::PaymentMethodSession;
 #[cfg(all(feature = "oltp", feature = "v2"))]
 pub use self::app::Proxy;
 #[cfg(all(feature = "olap", feature = "recon", feature = "v1"))]
 pub use self::app::Recon;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::SettlementRecon;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::Tenants;
 pub use self::app::{
     ApiKeys, AppState, ApplePayCertificatesMigration, Authentication, Cache, CardIssuers, Cards,
     Chat, Configs, ConnectorOnboarding, Customers, Disputes, Embedded, EphemeralKey, FeatureMatrix,
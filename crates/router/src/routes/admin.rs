@@ -1092,6 +1092,84 @@ pub async fn merchant_account_kv_status(
     )
     .await
 }
+/// Merchant Account - KV Migrate
+///
+/// Switch the storage scheme of the Merchant Account, and verify in the background that its
+/// pending drainer entries are drained and its redis and postgres copies of recent payments agree
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn merchant_account_kv_migrate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::StorageSchemeMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConfigKeyUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| migrate_merchant_storage_scheme(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - KV Migration Status
+///
+/// Retrieve the progress of the last storage scheme migration of the Merchant Account
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn merchant_account_kv_migration_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::ConfigKeyFetch;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| retrieve_merchant_storage_scheme_migration(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - KV Rollback
+///
+/// Switch the Merchant Account back to the storage scheme it had before its last migration
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn merchant_account_kv_rollback(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::ConfigKeyUpdate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| rollback_merchant_storage_scheme_migration(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
@@ -32,16 +32,22 @@ use hyperswitch_interfaces::{
 };
 use router_env::RequestId;
 use scheduler::SchedulerInterface;
-use storage_impl::{redis::RedisStore, MockDb};
+use storage_impl::{errors::StorageResult, redis::RedisStore};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::account_updater;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::currency;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::data_retention;
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
 #[cfg(all(any(feature = "v1", feature = "v2"), feature = "oltp"))]
 use super::ephemeral_key::*;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::ledger;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::payment_methods;
 #[cfg(feature = "payouts")]
@@ -60,6 +66,10 @@ use super::recovery_webhooks::*;
 use super::refunds;
 #[cfg(feature = "olap")]
 use super::routing;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::settlement_recon;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::tenant_registry;
 #[cfg(all(feature = "oltp", feature = "v2"))]
 use super::tokenization as tokenization_routes;
 #[cfg(all(feature = "olap", any(feature = "v1", feature = "v2")))]
@@ -88,11 +98,11 @@ use crate::errors::RouterResult;
 use crate::routes::authentication;
 #[cfg(feature = "v1")]
 use crate::routes::cards_info::{
-    card_iin_info, create_cards_info, migrate_cards_info, update_cards_info,
+    card_iin_info, create_cards_info, migrate_cards_info, update_cards_info, upload_bin_ranges,
 };
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::feature_matrix;
-#[cfg(all(feature = "frm", feature = "oltp"))]
+#[cfg(all(feature = "frm", any(feature = "olap", feature = "oltp")))]
 use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::profile_acquirer;
@@ -105,7 +115,7 @@ pub use crate::{
         StorageInterface,
     },
     events::EventsHandler,
-    services::{get_cache_store, get_store},
+    services::{get_cache_store, get_mock_store, get_store, tenant_registry::RegisteredTenants},
     types::transformers::ForeignFrom,
 };
 use crate::{
@@ -339,10 +349,13 @@ pub struct AppState {
     pub infra_components: Option<serde_json::Value>,
     pub enhancement: Option<HashMap<String, String>>,
     pub superposition_service: Option<Arc<SuperpositionClient>>,
+    /// Tenants provisioned at runtime through the tenant registry
+    pub registered_tenants: Arc<RegisteredTenants>,
 }
+#[async_trait::async_trait]
 impl scheduler::SchedulerAppState for AppState {
-    fn get_tenants(&self) -> Vec<id_type::TenantId> {
-        self.conf.multitenancy.get_tenant_ids()
+    async fn get_tenants(&self) -> Vec<id_type::TenantId> {
+        self.get_all_tenant_ids().await
     }
 }
 pub trait AppStateInfo {
@@ -546,6 +559,11 @@ impl AppState {
                 infra_components: infra_component_values,
                 enhancement,
                 superposition_service,
+                registered_tenants: Arc::new(RegisteredTenants::new(
+                    storage_impl,
+                    cache_store,
+                    testable,
+                )),
             }
         })
         .await
@@ -562,6 +580,29 @@ impl AppState {
         cache_store: Arc<RedisStore>,
         testable: bool,
     ) -> Box<dyn CommonStorageInterface> {
+        #[allow(clippy::expect_used)]
+        Box::pin(Self::try_get_store_interface(
+            storage_impl,
+            event_handler,
+            conf,
+            tenant,
+            cache_store,
+            testable,
+        ))
+        .await
+        .expect("Failed to create store")
+    }
+
+    /// Creates the store of a tenant, returning an error instead of panicking when the
+    /// connections of the tenant cannot be established, for tenants provisioned at runtime.
+    pub async fn try_get_store_interface(
+        storage_impl: &StorageImpl,
+        event_handler: &EventsHandler,
+        conf: &Settings,
+        tenant: &dyn TenantConfig,
+        cache_store: Arc<RedisStore>,
+        testable: bool,
+    ) -> StorageResult<Box<dyn CommonStorageInterface>> {
         let km_conf = conf.key_manager.get_inner();
         let key_manager_state = KeyManagerState {
             global_tenant_id: conf.multitenancy.global_tenant.tenant_id.clone(),
@@ -578,11 +619,12 @@ impl AppState {
             infra_values: Self::process_env_mappings(conf.infra_values.clone()),
             use_legacy_key_store_decryption: km_conf.use_legacy_key_store_decryption,
         };
-        match storage_impl {
+        Ok(match storage_impl {
             StorageImpl::Postgresql | StorageImpl::PostgresqlTest => match event_handler {
-                EventsHandler::Kafka(kafka_client) => Box::new(
+                EventsHandler::Kafka(kafka_client)
+                | EventsHandler::RedisStreams(kafka_client)
+                | EventsHandler::File(kafka_client) => Box::new(
                     KafkaStore::new(
-                        #[allow(clippy::expect_used)]
                         get_store(
                             &conf.clone(),
                             tenant,
@@ -590,16 +632,15 @@ impl AppState {
                             testable,
                             key_manager_state,
                         )
-                        .await
-                        .expect("Failed to create store"),
+                        .await?,
                         kafka_client.clone(),
                         TenantID(tenant.get_tenant_id().get_string_repr().to_owned()),
                         tenant,
+                        conf.application_source,
                     )
                     .await,
                 ),
                 EventsHandler::Logs(_) => Box::new(
-                    #[allow(clippy::expect_used)]
                     get_store(
                         conf,
                         tenant,
@@ -607,17 +648,11 @@ impl AppState {
                         testable,
                         key_manager_state,
                     )
-                    .await
-                    .expect("Failed to create store"),
+                    .await?,
                 ),
             },
-            #[allow(clippy::expect_used)]
-            StorageImpl::Mock => Box::new(
-                MockDb::new(&conf.redis, key_manager_state)
-                    .await
-                    .expect("Failed to create mock store"),
-            ),
-        }
+            StorageImpl::Mock => Box::new(get_mock_store(conf, tenant, key_manager_state).await?),
+        })
     }
 
     pub async fn new(
@@ -643,21 +678,45 @@ impl AppState {
     where
         F: FnOnce() -> E + Copy,
     {
-        let tenant_conf = self.conf.multitenancy.get_tenant(tenant).ok_or_else(err)?;
+        // Tenants provisioned through the tenant registry are looked up once the tenants of the
+        // static configuration are exhausted
+        let registered_tenant = self
+            .conf
+            .multitenancy
+            .get_tenant(tenant)
+            .is_none()
+            .then(|| self.registered_tenants.get(tenant))
+            .flatten();
+        let tenant_conf = match &registered_tenant {
+            Some(registered_tenant) => &registered_tenant.tenant,
+            None => self.conf.multitenancy.get_tenant(tenant).ok_or_else(err)?,
+        };
         let mut event_handler = self.event_handler.clone();
         event_handler.add_tenant(tenant_conf);
-        let mut store = self.stores.get(tenant).ok_or_else(err)?.clone();
+        let mut store = match &registered_tenant {
+            Some(registered_tenant) => registered_tenant.store.clone(),
+            None => self.stores.get(tenant).ok_or_else(err)?.clone(),
+        };
         let key_manager_state = KeyManagerState::foreign_from((self.as_ref(), tenant_conf.clone()));
         store.set_key_manager_state(key_manager_state);
+        let accounts_store = match &registered_tenant {
+            Some(registered_tenant) => registered_tenant.accounts_store.clone(),
+            None => self.accounts_store.get(tenant).ok_or_else(err)?.clone(),
+        };
+        #[cfg(feature = "olap")]
+        let pool = match &registered_tenant {
+            Some(registered_tenant) => registered_tenant.pool.clone(),
+            None => self.pools.get(tenant).ok_or_else(err)?.clone(),
+        };
         Ok(SessionState {
             store,
             global_store: self.global_store.clone(),
-            accounts_store: self.accounts_store.get(tenant).ok_or_else(err)?.clone(),
+            accounts_store,
             conf: Arc::clone(&self.conf),
             api_client: self.api_client.clone(),
             event_handler,
             #[cfg(feature = "olap")]
-            pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
+            pool,
             file_storage_client: self.file_storage_client.clone(),
             request_id: self.request_id.clone(),
             base_url: tenant_conf.base_url.clone(),
@@ -951,6 +1010,10 @@ impl Payments {
                     web::resource("/v2/profile/filter")
                         .route(web::get().to(payments::get_payment_filters_profile)),
                 )
+                .service(
+                    web::resource("/{payment_id}/archive")
+                        .route(web::get().to(data_retention::retrieve_archived_payment)),
+                )
                 .service(
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments::payments_manual_update)),
@@ -1172,6 +1235,19 @@ impl Routing {
                 })),
             );
 
+        #[cfg(feature = "frm")]
+        {
+            route = route
+                .service(
+                    web::resource("/frm_rules").route(web::post().to(frm_routes::upsert_frm_rules)),
+                )
+                .service(
+                    web::resource("/frm_rules/{profile_id}")
+                        .route(web::get().to(frm_routes::retrieve_frm_rules))
+                        .route(web::delete().to(frm_routes::delete_frm_rules)),
+                );
+        }
+
         #[cfg(feature = "dynamic_routing")]
         {
             route = route
@@ -1338,6 +1414,52 @@ impl Subscription {
                 web::resource("/items").route(web::get().to(subscription::get_subscription_items)),
             )
             .service(web::resource("/list").route(web::get().to(subscription::list_subscriptions)))
+            .service(
+                web::resource("/products")
+                    .route(web::post().to(subscription::create_billing_product))
+                    .route(web::get().to(subscription::list_billing_products)),
+            )
+            .service(
+                web::resource("/products/{product_id}")
+                    .route(web::get().to(subscription::retrieve_billing_product))
+                    .route(web::post().to(subscription::update_billing_product)),
+            )
+            .service(
+                web::resource("/plans")
+                    .route(web::post().to(subscription::create_billing_plan))
+                    .route(web::get().to(subscription::list_billing_plans)),
+            )
+            .service(
+                web::resource("/plans/{plan_id}")
+                    .route(web::get().to(subscription::retrieve_billing_plan))
+                    .route(web::post().to(subscription::update_billing_plan)),
+            )
+            .service(
+                web::resource("/prices")
+                    .route(web::post().to(subscription::create_billing_price))
+                    .route(web::get().to(subscription::list_billing_prices)),
+            )
+            .service(
+                web::resource("/prices/{price_id}")
+                    .route(web::get().to(subscription::retrieve_billing_price))
+                    .route(web::post().to(subscription::update_billing_price)),
+            )
+            .service(
+                web::resource("/coupons")
+                    .route(web::post().to(subscription::create_billing_coupon))
+                    .route(web::get().to(subscription::list_billing_coupons)),
+            )
+            .service(
+                web::resource("/coupons/{coupon_id}")
+                    .route(web::get().to(subscription::retrieve_billing_coupon))
+                    .route(web::post().to(subscription::update_billing_coupon)),
+            )
+            .service(
+                web::resource("/dunning_policy")
+                    .route(web::post().to(subscription::upsert_dunning_policy))
+                    .route(web::get().to(subscription::retrieve_dunning_policy))
+                    .route(web::delete().to(subscription::delete_dunning_policy)),
+            )
             .service(
                 web::resource("/{subscription_id}/confirm").route(web::post().to(
                     |state, req, id, payload| {
@@ -1425,6 +1547,39 @@ impl Customers {
                     web::resource("/{customer_id}/mandates")
                         .route(web::get().to(customers::get_customer_mandates)),
                 )
+                .service(
+                    web::resource("/{customer_id}/data_export")
+                        .route(web::post().to(customers::customers_export_data)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_erasure")
+                        .route(web::post().to(customers::customers_erase_data)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_requests")
+                        .route(web::get().to(customers::customers_list_data_requests)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_requests/{request_id}")
+                        .route(web::get().to(customers::customers_retrieve_data_request)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_requests/{request_id}/archive")
+                        .route(web::get().to(customers::customers_download_data_archive)),
+                )
+                .service(
+                    web::resource("/{customer_id}/merge")
+                        .route(web::post().to(customers::customers_merge)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/expiring").route(
+                        web::get().to(payment_methods::list_customer_expiring_payment_methods_api),
+                    ),
+                )
+                .service(
+                    web::resource("/duplicates")
+                        .route(web::get().to(customers::customers_find_duplicates)),
+                )
                 .service(web::resource("/list").route(web::get().to(customers::customers_list)))
                 .service(
                     web::resource("/list_with_count")
@@ -1493,6 +1648,15 @@ impl Refunds {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
                 .service(web::resource("/sync").route(web::post().to(refunds_retrieve_with_body)))
+                .service(web::resource("/bulk").route(web::post().to(bulk_refunds_create)))
+                .service(
+                    web::resource("/bulk/{bulk_refund_id}")
+                        .route(web::get().to(bulk_refunds_retrieve)),
+                )
+                .service(
+                    web::resource("/bulk/{bulk_refund_id}/report")
+                        .route(web::get().to(bulk_refunds_report)),
+                )
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
@@ -1581,6 +1745,15 @@ impl Payouts {
                 );
         }
         route = route
+            .service(web::resource("/batches").route(web::post().to(payout_batch_create)))
+            .service(
+                web::resource("/batches/{payout_batch_id}")
+                    .route(web::get().to(payout_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batches/{payout_batch_id}/review")
+                    .route(web::post().to(payout_batch_review)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -1986,6 +2159,15 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_toggle_kv))
                     .route(web::get().to(admin::merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/kv/migrate")
+                    .route(web::post().to(admin::merchant_account_kv_migrate))
+                    .route(web::get().to(admin::merchant_account_kv_migration_status)),
+            )
+            .service(
+                web::resource("/{id}/kv/rollback")
+                    .route(web::post().to(admin::merchant_account_kv_rollback)),
+            )
             .service(
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
@@ -2337,10 +2519,33 @@ impl Disputes {
                     .route(web::put().to(disputes::attach_dispute_evidence))
                     .route(web::delete().to(disputes::delete_dispute_evidence)),
             )
+            .service(web::resource("/rules").route(web::post().to(disputes::upsert_dispute_rules)))
+            .service(
+                web::resource("/rules/{profile_id}")
+                    .route(web::get().to(disputes::retrieve_dispute_rules))
+                    .route(web::delete().to(disputes::delete_dispute_rules)),
+            )
+            .service(
+                web::resource("/evidence_templates")
+                    .route(web::post().to(disputes::create_dispute_evidence_template))
+                    .route(web::get().to(disputes::list_dispute_evidence_templates)),
+            )
+            .service(
+                web::resource("/evidence_templates/{template_id}")
+                    .route(web::delete().to(disputes::delete_dispute_evidence_template)),
+            )
             .service(
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/{dispute_id}/automated_actions")
+                    .route(web::get().to(disputes::list_dispute_automated_actions)),
+            )
+            .service(
+                web::resource("/{dispute_id}/evidence/assemble")
+                    .route(web::post().to(disputes::assemble_dispute_evidence)),
+            )
             .service(
                 web::resource("/{dispute_id}").route(web::get().to(disputes::retrieve_dispute)),
             )
@@ -2361,6 +2566,7 @@ impl Cards {
             .service(web::resource("/create").route(web::post().to(create_cards_info)))
             .service(web::resource("/update").route(web::post().to(update_cards_info)))
             .service(web::resource("/update-batch").route(web::post().to(migrate_cards_info)))
+            .service(web::resource("/bin-ranges/upload").route(web::post().to(upload_bin_ranges)))
             .service(web::resource("/{bin}").route(web::get().to(card_iin_info)))
     }
 }
@@ -2659,12 +2865,29 @@ pub struct ThreeDsDecisionRule;
 #[cfg(feature = "oltp")]
 impl ThreeDsDecisionRule {
     pub fn server(state: AppState) -> Scope {
-        web::scope("/three_ds_decision")
+        #[allow(unused_mut)]
+        let mut route = web::scope("/three_ds_decision")
             .app_data(web::Data::new(state))
             .service(
                 web::resource("/execute")
                     .route(web::post().to(three_ds_decision_rule::execute_decision_rule)),
-            )
+            );
+        #[cfg(all(feature = "olap", feature = "v1"))]
+        {
+            route = route
+                .service(
+                    web::resource("/exemption_engine")
+                        .route(web::post().to(three_ds_decision_rule::upsert_sca_exemption_engine)),
+                )
+                .service(
+                    web::resource("/exemption_engine/{profile_id}")
+                        .route(web::get().to(three_ds_decision_rule::retrieve_sca_exemption_engine))
+                        .route(
+                            web::delete().to(three_ds_decision_rule::delete_sca_exemption_engine),
+                        ),
+                );
+        }
+        route
     }
 }
 
@@ -3309,6 +3532,140 @@ impl Authentication {
     }
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct Ledger;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Ledger {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/ledger")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/fee_schedules")
+                    .route(web::post().to(ledger::create_ledger_fee_schedule))
+                    .route(web::get().to(ledger::list_ledger_fee_schedules)),
+            )
+            .service(
+                web::resource("/fee_schedules/{fee_schedule_id}")
+                    .route(web::delete().to(ledger::delete_ledger_fee_schedule)),
+            )
+            .service(web::resource("/balance").route(web::get().to(ledger::get_ledger_balance)))
+            .service(web::resource("/statement").route(web::get().to(ledger::get_ledger_statement)))
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct SettlementRecon;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl SettlementRecon {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/settlement_recon")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/reports")
+                    .route(web::post().to(settlement_recon::create_settlement_report))
+                    .route(web::get().to(settlement_recon::list_settlement_reports)),
+            )
+            .service(
+                web::resource("/reports/{report_id}")
+                    .route(web::get().to(settlement_recon::retrieve_settlement_report)),
+            )
+            .service(
+                web::resource("/reports/{report_id}/lines")
+                    .route(web::get().to(settlement_recon::list_settlement_report_lines)),
+            )
+            .service(
+                web::resource("/exceptions")
+                    .route(web::get().to(settlement_recon::list_settlement_exceptions)),
+            )
+            .service(
+                web::resource("/exceptions/{line_id}/resolve")
+                    .route(web::post().to(settlement_recon::resolve_settlement_exception)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct Tenants;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Tenants {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/tenants")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(tenant_registry::create_tenant))
+                    .route(web::get().to(tenant_registry::list_tenants)),
+            )
+            .service(
+                web::resource("/{tenant_id}")
+                    .route(web::get().to(tenant_registry::retrieve_tenant))
+                    .route(web::delete().to(tenant_registry::delete_tenant)),
+            )
+            .service(
+                web::resource("/{tenant_id}/suspend")
+                    .route(web::post().to(tenant_registry::suspend_tenant)),
+            )
+            .service(
+                web::resource("/{tenant_id}/activate")
+                    .route(web::post().to(tenant_registry::activate_tenant)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct DataRetention;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl DataRetention {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/data_retention/policies")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(data_retention::list_data_retention_policies)),
+            )
+            .service(
+                web::resource("/{entity}")
+                    .route(web::put().to(data_retention::upsert_data_retention_policy))
+                    .route(web::delete().to(data_retention::delete_data_retention_policy)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct AccountUpdater;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl AccountUpdater {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/account_updater")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(account_updater::retrieve_account_updater_enrollment)),
+            )
+            .service(
+                web::resource("/enable")
+                    .route(web::post().to(account_updater::enable_account_updater)),
+            )
+            .service(
+                web::resource("/disable")
+                    .route(web::post().to(account_updater::disable_account_updater)),
+            )
+            .service(
+                web::resource("/batches")
+                    .route(web::get().to(account_updater::list_account_updater_batches)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}")
+                    .route(web::get().to(account_updater::retrieve_account_updater_batch)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct ProfileAcquirer;
 
@@ -127,3 +127,27 @@ pub async fn migrate_cards_info(
     ))
     .await
 }
+
+#[cfg(all(feature = "v1", any(feature = "olap", feature = "oltp")))]
+#[instrument(skip_all, fields(flow = ?Flow::BinRangeUpload))]
+pub async fn upload_bin_ranges(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<cards_info::BinRangeUploadForm>,
+) -> HttpResponse {
+    let flow = Flow::BinRangeUpload;
+    let request = match cards_info::get_bin_range_upload_request(form) {
+        Ok(request) => request,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        request,
+        |state, _, payload, _| cards_info::upload_bin_ranges(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
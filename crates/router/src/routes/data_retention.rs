@@ -0,0 +1,120 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::data_retention as retention_models;
+use common_utils::id_type;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, data_retention},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::storage::enums,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPolicyUpsert))]
+pub async fn upsert_data_retention_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::DataRetentionEntity>,
+    json_payload: web::Json<retention_models::DataRetentionPolicyRequest>,
+) -> HttpResponse {
+    let flow = Flow::DataRetentionPolicyUpsert;
+    let payload = retention_models::DataRetentionPolicyUpsertRequest {
+        entity: path.into_inner(),
+        policy: json_payload.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| data_retention::upsert_data_retention_policy(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPolicyList))]
+pub async fn list_data_retention_policies(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::DataRetentionPolicyList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| data_retention::list_data_retention_policies(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPolicyDelete))]
+pub async fn delete_data_retention_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::DataRetentionEntity>,
+) -> HttpResponse {
+    let flow = Flow::DataRetentionPolicyDelete;
+    let payload = retention_models::DataRetentionEntityPath {
+        entity: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| data_retention::delete_data_retention_policy(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieveArchived, payment_id))]
+pub async fn retrieve_archived_payment(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::PaymentId>,
+) -> HttpResponse {
+    let flow = Flow::PaymentsRetrieveArchived;
+    let payload = retention_models::ArchivedPaymentRetrieveRequest {
+        payment_id: path.into_inner(),
+    };
+    tracing::Span::current().record("payment_id", payload.payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            data_retention::retrieve_archived_payment(
+                state,
+                auth.platform
+                    .get_processor()
+                    .get_account()
+                    .get_id()
+                    .to_owned(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -7,6 +7,8 @@ use crate::{core::api_locking, services::authorization::permissions::Permission}
 pub mod utils;
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::disputes::{evidence_templates, rules};
 use crate::{
     core::disputes,
     services::{api, authentication as auth},
@@ -564,6 +566,372 @@ pub async fn delete_dispute_evidence(
     .await
 }
 
+#[cfg(feature = "v1")]
+/// Disputes - Create Dispute Evidence Template
+///
+/// To create a template describing how the evidence of a dispute is assembled
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence_templates",
+    request_body=DisputeEvidenceTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Dispute evidence template created", body = DisputeEvidenceTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Disputes",
+    operation_id = "Create a Dispute Evidence Template",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeEvidenceTemplateCreate))]
+pub async fn create_dispute_evidence_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::DisputeEvidenceTemplateCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputeEvidenceTemplateCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            evidence_templates::create_dispute_evidence_template(
+                state,
+                auth.platform,
+                profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Disputes - List Dispute Evidence Templates
+#[utoipa::path(
+    get,
+    path = "/disputes/evidence_templates",
+    responses(
+        (status = 200, description = "The dispute evidence templates were retrieved successfully", body = Vec<DisputeEvidenceTemplateResponse>),
+    ),
+    tag = "Disputes",
+    operation_id = "List Dispute Evidence Templates",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeEvidenceTemplateList))]
+pub async fn list_dispute_evidence_templates(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::DisputeEvidenceTemplateList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            evidence_templates::list_dispute_evidence_templates(state, auth.platform, profile_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Disputes - Delete Dispute Evidence Template
+#[utoipa::path(
+    delete,
+    path = "/disputes/evidence_templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the dispute evidence template")
+    ),
+    responses(
+        (status = 200, description = "Dispute evidence template deleted", body = DisputeEvidenceTemplateResponse),
+        (status = 404, description = "Dispute evidence template does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Delete a Dispute Evidence Template",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeEvidenceTemplateDelete))]
+pub async fn delete_dispute_evidence_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputeEvidenceTemplateDelete;
+    let template_id = dispute_models::DisputeEvidenceTemplateId {
+        template_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        template_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            evidence_templates::delete_dispute_evidence_template(
+                state,
+                auth.platform,
+                profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Disputes - Assemble Dispute Evidence
+///
+/// To assemble the evidence of a dispute from the matching evidence template, for review before it is submitted
+#[utoipa::path(
+    post,
+    path = "/disputes/{dispute_id}/evidence/assemble",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    request_body=DisputeEvidenceAssembleRequest,
+    responses(
+        (status = 200, description = "The dispute evidence was assembled successfully", body = DisputeEvidenceAssembleResponse),
+        (status = 404, description = "Dispute or dispute evidence template does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Assemble Dispute Evidence",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeEvidenceAssemble))]
+pub async fn assemble_dispute_evidence(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<dispute_models::DisputeEvidenceAssembleRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputeEvidenceAssemble;
+    let mut payload = json_payload.into_inner();
+    payload.dispute_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            evidence_templates::assemble_dispute_evidence(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeRulesUpsert))]
+pub async fn upsert_dispute_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::DisputeRulesRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputeRulesUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            rules::upsert_dispute_rules(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeRulesRetrieve))]
+pub async fn retrieve_dispute_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+) -> HttpResponse {
+    let flow = Flow::DisputeRulesRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            rules::retrieve_dispute_rules(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeRulesDelete))]
+pub async fn delete_dispute_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+) -> HttpResponse {
+    let flow = Flow::DisputeRulesDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            rules::delete_dispute_rules(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Disputes - List Automated Actions of a Dispute
+///
+/// To list the actions taken automatically on a dispute by the dispute rules of the profile
+#[utoipa::path(
+    get,
+    path = "/disputes/{dispute_id}/automated_actions",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The automated actions were retrieved successfully", body = Vec<DisputeAutomatedActionResponse>),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "List Automated Actions of a Dispute",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeAutomatedActionsList))]
+pub async fn list_dispute_automated_actions(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputeAutomatedActionsList;
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        dispute_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            rules::list_dispute_automated_actions(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::DisputesAggregate))]
 pub async fn get_disputes_aggregate(
     state: web::Data<AppState>,
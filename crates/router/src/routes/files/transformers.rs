@@ -64,10 +64,14 @@ pub async fn get_create_file_request(
         Err(errors::ApiErrorResponse::MissingFile)
             .attach_printable("Missing / Invalid file in the request")?
     }
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
+    // Get file mime type using 'infer', text based files have no magic bytes and are identified
+    // using the file extension instead
+    let mime_type = match infer::get(&file) {
+        Some(kind) => kind.mime_type(),
+        None => helpers::get_text_file_mime_type(file_name.as_deref())
+            .ok_or(errors::ApiErrorResponse::MissingFileContentType)?,
+    };
+    let file_type = mime_type
         .parse::<mime::Mime>()
         .change_context(errors::ApiErrorResponse::MissingFileContentType)
         .attach_printable("File content type error")?;
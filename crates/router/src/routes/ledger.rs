@@ -0,0 +1,173 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::ledger as ledger_models;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, ledger},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::LedgerFeeScheduleCreate))]
+pub async fn create_ledger_fee_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<ledger_models::LedgerFeeScheduleCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::LedgerFeeScheduleCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            ledger::create_ledger_fee_schedule(state, auth.platform, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LedgerFeeScheduleList))]
+pub async fn list_ledger_fee_schedules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::LedgerFeeScheduleList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            ledger::list_ledger_fee_schedules(state, auth.platform)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LedgerFeeScheduleDelete))]
+pub async fn delete_ledger_fee_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::LedgerFeeScheduleDelete;
+    let fee_schedule_id = ledger_models::LedgerFeeScheduleId {
+        fee_schedule_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        fee_schedule_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            ledger::delete_ledger_fee_schedule(state, auth.platform, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LedgerBalanceRetrieve))]
+pub async fn get_ledger_balance(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ledger_models::LedgerBalanceRequest>,
+) -> HttpResponse {
+    let flow = Flow::LedgerBalanceRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            ledger::get_ledger_balance(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LedgerStatementRetrieve))]
+pub async fn get_ledger_statement(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ledger_models::LedgerStatementRequest>,
+) -> HttpResponse {
+    let flow = Flow::LedgerStatementRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            ledger::get_ledger_statement(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -54,6 +54,11 @@ pub enum ApiIdentifier {
     RecoveryRecovery,
     Superposition,
     CardIssuers,
+    Ledger,
+    SettlementRecon,
+    Tenants,
+    DataRetention,
+    AccountUpdater,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -86,6 +91,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CreateDynamicRoutingConfig
             | Flow::UpdateDynamicRoutingConfigs
             | Flow::DecisionManagerUpsertConfig
+            | Flow::FrmRulesUpsert
+            | Flow::FrmRulesRetrieve
+            | Flow::FrmRulesDelete
             | Flow::RoutingEvaluateRule
             | Flow::DecisionEngineRuleMigration
             | Flow::VolumeSplitOnRoutingType
@@ -100,7 +108,26 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GetSubscriptionItemsForSubscription
             | Flow::PauseSubscription
             | Flow::ResumeSubscription
-            | Flow::CancelSubscription => Self::Subscription,
+            | Flow::CancelSubscription
+            | Flow::CreateBillingProduct
+            | Flow::RetrieveBillingProduct
+            | Flow::ListBillingProducts
+            | Flow::UpdateBillingProduct
+            | Flow::CreateBillingPlan
+            | Flow::RetrieveBillingPlan
+            | Flow::ListBillingPlans
+            | Flow::UpdateBillingPlan
+            | Flow::CreateBillingPrice
+            | Flow::RetrieveBillingPrice
+            | Flow::ListBillingPrices
+            | Flow::UpdateBillingPrice
+            | Flow::CreateBillingCoupon
+            | Flow::RetrieveBillingCoupon
+            | Flow::ListBillingCoupons
+            | Flow::UpdateBillingCoupon
+            | Flow::UpsertDunningPolicy
+            | Flow::RetrieveDunningPolicy
+            | Flow::DeleteDunningPolicy => Self::Subscription,
             Flow::RetrieveForexFlow => Self::Forex,
             Flow::AddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
@@ -121,6 +148,13 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersExportData
+            | Flow::CustomersEraseData
+            | Flow::CustomersListDataRequests
+            | Flow::CustomersRetrieveDataRequest
+            | Flow::CustomersDownloadDataArchive
+            | Flow::CustomersMerge
+            | Flow::CustomersFindDuplicates
             | Flow::CustomersList
             | Flow::CustomersListWithConstraints => Self::Customers,
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
@@ -132,6 +166,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodsBatchRetrieve
             | Flow::PaymentMethodsList
             | Flow::CustomerPaymentMethodsList
+            | Flow::CustomerPaymentMethodsExpiringList
             | Flow::GetPaymentMethodTokenData
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
@@ -195,7 +230,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsConfirm
             | Flow::PayoutsManualUpdate
             | Flow::PayoutLinkInitiate
-            | Flow::PayoutsAggregate => Self::Payouts,
+            | Flow::PayoutsAggregate
+            | Flow::PayoutBatchCreate
+            | Flow::PayoutBatchRetrieve
+            | Flow::PayoutBatchReview => Self::Payouts,
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
             | Flow::RefundsRetrieveForceSync
@@ -203,7 +241,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsList
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
-            | Flow::RefundsManualUpdate => Self::Refunds,
+            | Flow::RefundsManualUpdate
+            | Flow::BulkRefundCreate
+            | Flow::BulkRefundRetrieve
+            | Flow::BulkRefundReport => Self::Refunds,
             Flow::Relay | Flow::RelayRetrieve => Self::Relay,
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
@@ -225,11 +266,20 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
-            | Flow::DeleteDisputeEvidence => Self::Disputes,
+            | Flow::DeleteDisputeEvidence
+            | Flow::DisputeEvidenceTemplateCreate
+            | Flow::DisputeEvidenceTemplateList
+            | Flow::DisputeEvidenceTemplateDelete
+            | Flow::DisputeEvidenceAssemble
+            | Flow::DisputeRulesUpsert
+            | Flow::DisputeRulesRetrieve
+            | Flow::DisputeRulesDelete
+            | Flow::DisputeAutomatedActionsList => Self::Disputes,
             Flow::CardsInfo
             | Flow::CardsInfoCreate
             | Flow::CardsInfoUpdate
-            | Flow::CardsInfoMigrate => Self::CardsInfo,
+            | Flow::CardsInfoMigrate
+            | Flow::BinRangeUpload => Self::CardsInfo,
             Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
             Flow::CacheInvalidate => Self::Cache,
             Flow::ProfileCreate
@@ -378,7 +428,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AuthenticationRetrieveEligibilityCheck => Self::Authentication,
             Flow::Proxy => Self::Proxy,
             Flow::ProfileAcquirerCreate | Flow::ProfileAcquirerUpdate => Self::ProfileAcquirer,
-            Flow::ThreeDsDecisionRuleExecute => Self::ThreeDsDecisionRule,
+            Flow::ThreeDsDecisionRuleExecute
+            | Flow::ScaExemptionEngineUpsert
+            | Flow::ScaExemptionEngineRetrieve
+            | Flow::ScaExemptionEngineDelete => Self::ThreeDsDecisionRule,
             Flow::TokenizationCreate
             | Flow::TokenizationRetrieve
             | Flow::TokenizationDelete
@@ -392,6 +445,32 @@ impl From<Flow> for ApiIdentifier {
             Flow::AddCardIssuer | Flow::UpdateCardIssuer | Flow::ListCardIssuers => {
                 Self::CardIssuers
             }
+            Flow::LedgerFeeScheduleCreate
+            | Flow::LedgerFeeScheduleList
+            | Flow::LedgerFeeScheduleDelete
+            | Flow::LedgerBalanceRetrieve
+            | Flow::LedgerStatementRetrieve => Self::Ledger,
+            Flow::SettlementReportCreate
+            | Flow::SettlementReportList
+            | Flow::SettlementReportRetrieve
+            | Flow::SettlementReportLinesList
+            | Flow::SettlementExceptionList
+            | Flow::SettlementExceptionResolve => Self::SettlementRecon,
+            Flow::TenantCreate
+            | Flow::TenantList
+            | Flow::TenantRetrieve
+            | Flow::TenantSuspend
+            | Flow::TenantActivate
+            | Flow::TenantDelete => Self::Tenants,
+            Flow::DataRetentionPolicyUpsert
+            | Flow::DataRetentionPolicyList
+            | Flow::DataRetentionPolicyDelete
+            | Flow::PaymentsRetrieveArchived => Self::DataRetention,
+            Flow::AccountUpdaterEnable
+            | Flow::AccountUpdaterDisable
+            | Flow::AccountUpdaterRetrieve
+            | Flow::AccountUpdaterBatchList
+            | Flow::AccountUpdaterBatchRetrieve => Self::AccountUpdater,
         }
     }
 }
@@ -17,6 +17,8 @@ pub fn spawn_metrics_collector(metrics_collection_interval_in_secs: Option<u16>)
         &cache::SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE,
         &cache::CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE,
         &cache::ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE,
+        &cache::BIN_RANGE_CACHE,
+        &cache::TENANT_REGISTRY_CACHE,
     ];
 
     tokio::spawn(async move {
@@ -1257,6 +1257,49 @@ pub async fn default_payment_method_set_api(
     .await
 }
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsExpiringList))]
+pub async fn list_customer_expiring_payment_methods_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    query_payload: web::Query<api_models::payment_methods::CustomerExpiringPaymentMethodsRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsExpiringList;
+    let payload = api_models::payment_methods::CustomerExpiringPaymentMethodsRequest {
+        customer_id: path.into_inner(),
+        ..query_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::expiry::list_customer_expiring_payment_methods(
+                state,
+                auth.platform,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[cfg(test)]
 mod tests {
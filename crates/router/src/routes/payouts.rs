@@ -105,6 +105,125 @@ pub async fn payouts_retrieve(
     ))
     .await
 }
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payout Batch - Create
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCreate))]
+pub async fn payout_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            batch::payout_batch_create_core(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePayoutWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payout Batch - Retrieve
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchRetrieve))]
+pub async fn payout_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_params: web::Query<payout_types::PayoutBatchRetrieveQuery>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchRetrieve;
+    let payout_batch_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            batch::payout_batch_retrieve_core(
+                state,
+                auth.platform,
+                profile_id,
+                payout_batch_id.clone(),
+                query,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePayoutRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payout Batch - Review
+///
+/// Approval of a batch is only allowed from the dashboard, by a user other than its creator.
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchReview))]
+pub async fn payout_batch_review(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payout_types::PayoutBatchReviewRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchReview;
+    let payout_batch_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            batch::payout_batch_review_core(
+                state,
+                auth.platform,
+                profile_id,
+                payout_batch_id.clone(),
+                req,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePayoutWrite,
+            allow_connected: false,
+            allow_platform: false,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - Update
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
 pub async fn payouts_update(
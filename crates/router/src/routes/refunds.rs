@@ -363,6 +363,120 @@ pub async fn refunds_update(
     .await
 }
 
+#[cfg(feature = "v1")]
+/// Refunds - Bulk Create
+///
+/// To create refunds against multiple payments asynchronously. The refunds are executed in the background, and the progress of the job can be tracked with the bulk refund retrieve API
+#[instrument(skip_all, fields(flow = ?Flow::BulkRefundCreate))]
+// #[post("/bulk")]
+pub async fn bulk_refunds_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::BulkRefundRequest>,
+) -> HttpResponse {
+    let flow = Flow::BulkRefundCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            bulk::bulk_refund_create_core(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundWrite,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Refunds - Bulk Retrieve
+///
+/// To retrieve the progress of a bulk refund job
+#[instrument(skip_all, fields(flow = ?Flow::BulkRefundRetrieve))]
+// #[get("/bulk/{bulk_refund_id}")]
+pub async fn bulk_refunds_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BulkRefundRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            bulk::bulk_refund_retrieve_core(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Refunds - Bulk Report
+///
+/// To download a CSV report containing the outcome of every refund of a bulk refund job
+#[instrument(skip_all, fields(flow = ?Flow::BulkRefundReport))]
+// #[get("/bulk/{bulk_refund_id}/report")]
+pub async fn bulk_refunds_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BulkRefundReport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile.map(|profile| profile.get_id().clone());
+            bulk::bulk_refund_report_core(state, auth.platform, profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                allow_connected_scope_operation: false,
+                allow_platform_self_operation: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundRead,
+                allow_connected: false,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all, fields(flow = ?Flow::RefundsUpdate))]
 pub async fn refunds_metadata_update(
@@ -0,0 +1,137 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::tenant_registry as tenant_models;
+use common_utils::id_type;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, tenant_registry},
+    services::{api, authentication as auth},
+    types::storage::enums,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantCreate))]
+pub async fn create_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<tenant_models::TenantCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::TenantCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| tenant_registry::create_tenant(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantList))]
+pub async fn list_tenants(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::TenantList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| tenant_registry::list_tenants(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantRetrieve))]
+pub async fn retrieve_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::TenantId>,
+) -> HttpResponse {
+    let flow = Flow::TenantRetrieve;
+    let payload = tenant_models::TenantIdPath {
+        tenant_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| tenant_registry::retrieve_tenant(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantSuspend))]
+pub async fn suspend_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::TenantId>,
+) -> HttpResponse {
+    let flow = Flow::TenantSuspend;
+    let payload = tenant_models::TenantIdPath {
+        tenant_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            tenant_registry::update_tenant_status(state, req, enums::TenantStatus::Suspended)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantActivate))]
+pub async fn activate_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::TenantId>,
+) -> HttpResponse {
+    let flow = Flow::TenantActivate;
+    let payload = tenant_models::TenantIdPath {
+        tenant_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            tenant_registry::update_tenant_status(state, req, enums::TenantStatus::Active)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantDelete))]
+pub async fn delete_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::TenantId>,
+) -> HttpResponse {
+    let flow = Flow::TenantDelete;
+    let payload = tenant_models::TenantIdPath {
+        tenant_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| tenant_registry::delete_tenant(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
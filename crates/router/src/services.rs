@@ -10,6 +10,7 @@ pub mod jwt;
 pub mod kafka;
 pub mod logger;
 pub mod pm_auth;
+pub mod tenant_registry;
 
 pub mod card_testing_guard;
 #[cfg(feature = "olap")]
@@ -57,7 +58,10 @@ pub async fn get_store(
     let master_config = config.master_database.clone().into_inner();
 
     #[cfg(feature = "olap")]
-    let replica_config = config.replica_database.clone().into_inner();
+    let replica_config = config
+        .replica_routing
+        .get_inner()
+        .get_replica_settings(config.replica_database.clone().into_inner());
 
     #[allow(clippy::expect_used)]
     let master_enc_key = hex::decode(config.secrets.get_inner().master_enc_key.clone().expose())
@@ -69,9 +73,9 @@ pub async fn get_store(
     #[cfg(feature = "olap")]
     // this would get abstracted, for all cases
     #[allow(clippy::useless_conversion)]
-    let conf = (master_config.into(), replica_config.into());
+    let conf = (master_config.into(), replica_config);
 
-    let store: RouterStore<StoreType> = if test_transaction {
+    let mut store: RouterStore<StoreType> = if test_transaction {
         RouterStore::test_store(
             conf,
             tenant,
@@ -91,6 +95,7 @@ pub async fn get_store(
         )
         .await?
     };
+    store.set_outbox_enabled(config.outbox_relay.enabled);
 
     #[cfg(feature = "kv_store")]
     let store = KVRouterStore::from_store(
@@ -105,6 +110,29 @@ pub async fn get_store(
     Ok(store)
 }
 
+/// Returns the in-memory store of a tenant, creating it on first use, so that the tenant,
+/// accounts and global storage interfaces of a tenant see the same data, as they would when
+/// backed by the same database
+pub async fn get_mock_store(
+    config: &Settings,
+    tenant: &dyn TenantConfig,
+    key_manager_state: keymanager::KeyManagerState,
+) -> StorageResult<storage_impl::MockDb> {
+    static MOCK_STORES: std::sync::LazyLock<
+        tokio::sync::Mutex<std::collections::HashMap<String, storage_impl::MockDb>>,
+    > = std::sync::LazyLock::new(Default::default);
+
+    let mut mock_stores = MOCK_STORES.lock().await;
+    let tenant_id = tenant.get_tenant_id().get_string_repr().to_owned();
+    if let Some(store) = mock_stores.get(&tenant_id) {
+        return Ok(store.clone());
+    }
+
+    let store = storage_impl::MockDb::new(&config.redis, key_manager_state).await?;
+    mock_stores.insert(tenant_id, store.clone());
+    Ok(store)
+}
+
 #[allow(clippy::expect_used)]
 pub async fn get_cache_store(
     config: &Settings,
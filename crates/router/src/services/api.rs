@@ -215,11 +215,23 @@ where
                 )
             })?;
 
+        state
+            .load_registered_tenant(&request_tenant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError.switch())
+            .attach_printable("Failed to load the tenant from the tenant registry")?;
+
         state
             .conf
             .multitenancy
             .get_tenant(&request_tenant_id)
             .map(|tenant| tenant.tenant_id.clone())
+            .or_else(|| {
+                state
+                    .registered_tenants
+                    .get(&request_tenant_id)
+                    .map(|registered_tenant| registered_tenant.tenant.tenant_id.clone())
+            })
             .ok_or(
                 errors::ApiErrorResponse::InvalidTenant {
                     tenant_id: request_tenant_id.get_string_repr().to_string(),
@@ -420,7 +432,7 @@ where
         tag = ?Tag::BeginRequest, payload = ?payload,
     headers = ?incoming_header_to_log);
 
-    let server_wrap_util_res = server_wrap_util(
+    let server_wrap_util_res = storage_impl::database::store::track_writes(server_wrap_util(
         &flow,
         state.clone(),
         incoming_request_header,
@@ -429,7 +441,7 @@ where
         func,
         api_auth,
         lock_action,
-    )
+    ))
     .await
     .map(|response| {
         logger::info!(api_response =? response);
@@ -81,6 +81,19 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    PaymentMethodExpiryReminder {
+        customer_name: String,
+        merchant_name: String,
+        card_description: String,
+        expires_in: u8,
+    },
+    SubscriptionPaymentFailed {
+        customer_name: String,
+        merchant_name: String,
+        amount: String,
+        payment_link: String,
+        next_retry_date: String,
+    },
     WelcomeToCommunity,
     RoleDeleted {
         user_name: String,
@@ -250,6 +263,32 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::PaymentMethodExpiryReminder {
+                customer_name,
+                merchant_name,
+                card_description,
+                expires_in,
+            } => format!(
+                include_str!("assets/payment_method_expiry_reminder.html"),
+                customer_name = customer_name,
+                merchant_name = merchant_name,
+                card_description = card_description,
+                expires_in = expires_in,
+            ),
+            EmailBody::SubscriptionPaymentFailed {
+                customer_name,
+                merchant_name,
+                amount,
+                payment_link,
+                next_retry_date,
+            } => format!(
+                include_str!("assets/subscription_payment_failed.html"),
+                customer_name = customer_name,
+                merchant_name = merchant_name,
+                amount = amount,
+                payment_link = payment_link,
+                next_retry_date = next_retry_date,
+            ),
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
@@ -707,6 +746,78 @@ impl EmailData for ApiKeyExpiryReminder {
     }
 }
 
+pub struct PaymentMethodExpiryReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub customer_name: Option<String>,
+    pub merchant_name: String,
+    pub card_network: Option<String>,
+    pub last4_digits: Option<String>,
+    pub expires_in: u8,
+}
+
+#[async_trait::async_trait]
+impl EmailData for PaymentMethodExpiryReminder {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let card_description = match (&self.card_network, &self.last4_digits) {
+            (Some(card_network), Some(last4_digits)) => {
+                format!("{card_network} card ending in {last4_digits}")
+            }
+            (None, Some(last4_digits)) => format!("card ending in {last4_digits}"),
+            (Some(card_network), None) => format!("{card_network} card"),
+            (None, None) => "card".to_string(),
+        };
+
+        let body = html::get_html_body(EmailBody::PaymentMethodExpiryReminder {
+            customer_name: self
+                .customer_name
+                .clone()
+                .unwrap_or_else(|| "Customer".to_string()),
+            merchant_name: self.merchant_name.clone(),
+            card_description,
+            expires_in: self.expires_in,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
+
+pub struct SubscriptionPaymentFailed {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub customer_name: Option<String>,
+    pub merchant_name: String,
+    pub amount: String,
+    pub payment_link: String,
+    pub next_retry_date: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for SubscriptionPaymentFailed {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::SubscriptionPaymentFailed {
+            customer_name: self
+                .customer_name
+                .clone()
+                .unwrap_or_else(|| "Customer".to_string()),
+            merchant_name: self.merchant_name.clone(),
+            amount: self.amount.clone(),
+            payment_link: self.payment_link.clone(),
+            next_retry_date: self.next_retry_date.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
+
 pub struct WelcomeToCommunity {
     pub recipient_email: domain::UserEmail,
 }
@@ -7,20 +7,28 @@ use num_traits::ToPrimitive;
 use rdkafka::{
     config::FromClientConfig,
     message::{Header, OwnedHeaders},
-    producer::{BaseRecord, DefaultProducerContext, Producer, ThreadedProducer},
+    producer::{
+        BaseRecord, DefaultProducerContext, FutureProducer, FutureRecord, Producer,
+        ThreadedProducer,
+    },
 };
 use serde_json::Value;
 #[cfg(feature = "payouts")]
 pub mod payout;
 use diesel_models::fraud_check::FraudCheck;
 
-use crate::{events::EventType, services::kafka::fraud_check_event::KafkaFraudCheckEvent};
+use crate::{
+    events::{file_sink::FileSink, redis_streams::RedisStreamsSink, EventType},
+    services::kafka::fraud_check_event::KafkaFraudCheckEvent,
+};
 mod authentication;
 mod authentication_event;
+pub mod customer_erasure;
 mod dispute;
 mod dispute_event;
 mod fraud_check;
 mod fraud_check_event;
+pub mod outbox;
 mod payment_attempt;
 mod payment_attempt_event;
 mod payment_intent;
@@ -163,6 +171,8 @@ pub struct KafkaSettings {
     authentication_analytics_topic: String,
     routing_logs_topic: String,
     revenue_recovery_topic: String,
+    outbox_events_topic: String,
+    customer_erasure_topic: String,
 }
 
 impl KafkaSettings {
@@ -262,7 +272,7 @@ impl KafkaSettings {
 
 #[derive(Clone, Debug)]
 pub struct KafkaProducer {
-    producer: Arc<RdKafkaProducer>,
+    producer: MessageTransport,
     intent_analytics_topic: String,
     fraud_check_analytics_topic: String,
     attempt_analytics_topic: String,
@@ -279,9 +289,38 @@ pub struct KafkaProducer {
     ckh_database_name: Option<String>,
     routing_logs_topic: String,
     revenue_recovery_topic: String,
+    outbox_events_topic: String,
+    customer_erasure_topic: String,
+}
+
+/// Transport the messages of the [`KafkaProducer`] are published through. The Redis Streams and
+/// file sinks receive the same keys and payloads as the Kafka topics, and name their streams and
+/// files after the event types instead of the topics.
+#[derive(Clone, Debug)]
+enum MessageTransport {
+    Kafka(Arc<RdKafkaProducer>),
+    RedisStreams(RedisStreamsSink),
+    File(FileSink),
 }
 
-struct RdKafkaProducer(ThreadedProducer<DefaultProducerContext>);
+struct RdKafkaProducer {
+    producer: ThreadedProducer<DefaultProducerContext>,
+    /// Producer of the messages whose delivery is awaited, created on first use as only the
+    /// outbox relay needs it
+    acked_producer: tokio::sync::OnceCell<FutureProducer>,
+    client_config: rdkafka::ClientConfig,
+}
+
+impl RdKafkaProducer {
+    async fn get_acked_producer(&self) -> MQResult<&FutureProducer> {
+        self.acked_producer
+            .get_or_try_init(|| async {
+                FutureProducer::from_config(&self.client_config)
+                    .change_context(KafkaError::InitializationError)
+            })
+            .await
+    }
+}
 
 impl std::fmt::Debug for RdKafkaProducer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -303,17 +342,41 @@ pub enum KafkaError {
 impl KafkaProducer {
     pub fn set_tenancy(&mut self, tenant_config: &dyn TenantConfig) {
         self.ckh_database_name = Some(tenant_config.get_clickhouse_database().to_string());
+        if let MessageTransport::RedisStreams(sink) = &mut self.producer {
+            sink.set_tenancy(tenant_config);
+        }
     }
 
     pub async fn create(conf: &KafkaSettings) -> MQResult<Self> {
-        Ok(Self {
-            producer: Arc::new(RdKafkaProducer(
-                ThreadedProducer::from_config(
-                    rdkafka::ClientConfig::new().set("bootstrap.servers", conf.brokers.join(",")),
-                )
+        let mut client_config = rdkafka::ClientConfig::new();
+        client_config.set("bootstrap.servers", conf.brokers.join(","));
+        let producer = RdKafkaProducer {
+            producer: ThreadedProducer::from_config(&client_config)
                 .change_context(KafkaError::InitializationError)?,
-            )),
+            acked_producer: tokio::sync::OnceCell::new(),
+            client_config,
+        };
+
+        Ok(Self::with_transport(
+            MessageTransport::Kafka(Arc::new(producer)),
+            conf,
+        ))
+    }
+
+    pub fn with_redis_streams(sink: RedisStreamsSink) -> Self {
+        Self::with_transport(
+            MessageTransport::RedisStreams(sink),
+            &KafkaSettings::default(),
+        )
+    }
 
+    pub fn with_file_sink(sink: FileSink) -> Self {
+        Self::with_transport(MessageTransport::File(sink), &KafkaSettings::default())
+    }
+
+    fn with_transport(producer: MessageTransport, conf: &KafkaSettings) -> Self {
+        Self {
+            producer,
             fraud_check_analytics_topic: conf.fraud_check_analytics_topic.clone(),
             intent_analytics_topic: conf.intent_analytics_topic.clone(),
             attempt_analytics_topic: conf.attempt_analytics_topic.clone(),
@@ -330,31 +393,151 @@ impl KafkaProducer {
             ckh_database_name: None,
             routing_logs_topic: conf.routing_logs_topic.clone(),
             revenue_recovery_topic: conf.revenue_recovery_topic.clone(),
-        })
+            outbox_events_topic: conf.outbox_events_topic.clone(),
+            customer_erasure_topic: conf.customer_erasure_topic.clone(),
+        }
     }
 
     pub fn log_event<T: KafkaMessage>(&self, event: &T) -> MQResult<()> {
         router_env::logger::debug!("Logging Kafka Event {event:?}");
-        let topic = self.get_topic(event.event_type());
-        self.producer
-            .0
-            .send(
-                BaseRecord::to(topic)
-                    .key(&event.key())
-                    .payload(&event.value()?)
-                    .timestamp(event.creation_timestamp().unwrap_or_else(|| {
-                        (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000)
-                            .try_into()
-                            .unwrap_or_else(|_| {
-                                // kafka producer accepts milliseconds
-                                // try converting nanos to millis if that fails convert seconds to millis
-                                OffsetDateTime::now_utc().unix_timestamp() * 1_000
+        let timestamp = get_event_timestamp(event);
+        match &self.producer {
+            MessageTransport::Kafka(producer) => producer
+                .producer
+                .send(
+                    BaseRecord::to(self.get_topic(event.event_type()))
+                        .key(&event.key())
+                        .payload(&event.value()?)
+                        .timestamp(timestamp),
+                )
+                .map_err(|(error, record)| report!(error).attach_printable(format!("{record:?}")))
+                .change_context(KafkaError::GenericError),
+            MessageTransport::RedisStreams(sink) => sink
+                .append_in_background(
+                    event.event_type(),
+                    event.key(),
+                    &event.value()?,
+                    Vec::new(),
+                    timestamp,
+                )
+                .change_context(KafkaError::GenericError),
+            MessageTransport::File(sink) => sink
+                .write(
+                    event.event_type(),
+                    self.ckh_database_name.as_deref(),
+                    &event.value()?,
+                )
+                .change_context(KafkaError::GenericError),
+        }
+    }
+
+    /// Publishes the events and waits for the transport to acknowledge them, returning the result
+    /// of each event in order. Unlike [`Self::log_event`], an event is only reported as published
+    /// once the Kafka brokers acknowledged it, it was appended to its Redis stream or the file
+    /// holding it was uploaded.
+    pub async fn publish_events<T: KafkaMessage>(
+        &self,
+        events: &[T],
+    ) -> MQResult<Vec<MQResult<()>>> {
+        match &self.producer {
+            MessageTransport::Kafka(producer) => {
+                let acked_producer = producer.get_acked_producer().await?;
+                // All the messages are enqueued before the first delivery is awaited, so that the
+                // messages of the batch are delivered together
+                let deliveries = events
+                    .iter()
+                    .map(|event| {
+                        acked_producer
+                            .send_result(
+                                FutureRecord::to(self.get_topic(event.event_type()))
+                                    .key(&event.key())
+                                    .payload(&event.value()?)
+                                    .timestamp(get_event_timestamp(event)),
+                            )
+                            .map_err(|(error, record)| {
+                                report!(error).attach_printable(format!("{record:?}"))
                             })
-                    })),
-            )
-            .map_err(|(error, record)| report!(error).attach_printable(format!("{record:?}")))
-            .change_context(KafkaError::GenericError)
+                            .change_context(KafkaError::GenericError)
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut results = Vec::with_capacity(deliveries.len());
+                for delivery in deliveries {
+                    results.push(match delivery {
+                        Ok(delivery) => match delivery.await {
+                            Ok(Ok(_)) => Ok(()),
+                            Ok(Err((error, _))) => {
+                                Err(report!(error).change_context(KafkaError::GenericError))
+                            }
+                            Err(_) => Err(report!(KafkaError::GenericError))
+                                .attach_printable("The delivery of the message was canceled"),
+                        },
+                        Err(error) => Err(error),
+                    });
+                }
+                Ok(results)
+            }
+            MessageTransport::RedisStreams(sink) => {
+                let mut results = Vec::with_capacity(events.len());
+                for event in events {
+                    let result = match event.value() {
+                        Ok(payload) => sink
+                            .append(
+                                event.event_type(),
+                                event.key(),
+                                &payload,
+                                Vec::new(),
+                                get_event_timestamp(event),
+                            )
+                            .await
+                            .change_context(KafkaError::GenericError),
+                        Err(error) => Err(error),
+                    };
+                    results.push(result);
+                }
+                Ok(results)
+            }
+            MessageTransport::File(sink) => {
+                let mut results = Vec::with_capacity(events.len());
+                let mut payloads = HashMap::<EventType, (Vec<usize>, Vec<Vec<u8>>)>::new();
+                for (index, event) in events.iter().enumerate() {
+                    match event.value() {
+                        Ok(payload) => {
+                            let (indices, event_payloads) =
+                                payloads.entry(event.event_type()).or_default();
+                            indices.push(index);
+                            event_payloads.push(payload);
+                            results.push(Ok(()));
+                        }
+                        Err(error) => results.push(Err(error)),
+                    }
+                }
+
+                // The events of a type are uploaded as a file of their own, so that they are
+                // stored once the upload returns
+                for (event_type, (indices, event_payloads)) in payloads {
+                    if let Err(error) = sink
+                        .upload(
+                            event_type,
+                            self.ckh_database_name.as_deref(),
+                            &event_payloads,
+                        )
+                        .await
+                    {
+                        router_env::logger::error!(?error, %event_type, "Failed to upload events");
+                        for index in indices {
+                            if let Some(result) = results.get_mut(index) {
+                                *result = Err(report!(KafkaError::GenericError))
+                                    .attach_printable("Failed to upload the events file");
+                            }
+                        }
+                    }
+                }
+                Ok(results)
+            }
+        }
     }
+
     pub async fn log_fraud_check(
         &self,
         attempt: &FraudCheck,
@@ -669,14 +852,30 @@ impl KafkaProducer {
             EventType::Authentication => &self.authentication_analytics_topic,
             EventType::RoutingApiLogs => &self.routing_logs_topic,
             EventType::RevenueRecovery => &self.revenue_recovery_topic,
+            EventType::Outbox => &self.outbox_events_topic,
+            EventType::CustomerErasure => &self.customer_erasure_topic,
         }
     }
 }
 
+/// Returns the creation time of the event in milliseconds, as the Kafka producer expects it,
+/// defaulting to the current time
+fn get_event_timestamp<T: KafkaMessage>(event: &T) -> i64 {
+    event.creation_timestamp().unwrap_or_else(|| {
+        (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000)
+            .try_into()
+            .unwrap_or_else(|_| {
+                // kafka producer accepts milliseconds
+                // try converting nanos to millis if that fails convert seconds to millis
+                OffsetDateTime::now_utc().unix_timestamp() * 1_000
+            })
+    })
+}
+
 impl Drop for RdKafkaProducer {
     fn drop(&mut self) {
         // Flush the producer to send any pending messages
-        match self.0.flush(rdkafka::util::Timeout::After(
+        match self.producer.flush(rdkafka::util::Timeout::After(
             std::time::Duration::from_secs(5),
         )) {
             Ok(_) => router_env::logger::info!("Kafka events flush Successful"),
@@ -697,7 +896,7 @@ impl MessagingInterface for KafkaProducer {
     where
         T: Message<Class = Self::MessageClass> + hyperswitch_masking::ErasedMaskSerialize,
     {
-        let topic = self.get_topic(data.get_message_class());
+        let event_type = data.get_message_class();
         let json_data = data
             .masked_serialize()
             .and_then(|mut value| {
@@ -709,36 +908,61 @@ impl MessagingInterface for KafkaProducer {
                 serde_json::to_vec(&value)
             })
             .change_context(EventsError::SerializationError)?;
-        let mut headers = OwnedHeaders::new();
-        for (k, v) in metadata.iter() {
-            headers = headers.insert(Header {
-                key: k.as_str(),
-                value: Some(v),
+        let timestamp = (timestamp.assume_utc().unix_timestamp_nanos() / 1_000_000)
+            .to_i64()
+            .unwrap_or_else(|| {
+                // kafka producer accepts milliseconds
+                // try converting nanos to millis if that fails convert seconds to millis
+                timestamp.assume_utc().unix_timestamp() * 1_000
             });
+        match &self.producer {
+            MessageTransport::Kafka(producer) => {
+                let mut headers = OwnedHeaders::new();
+                for (k, v) in metadata.iter() {
+                    headers = headers.insert(Header {
+                        key: k.as_str(),
+                        value: Some(v),
+                    });
+                }
+                headers = headers.insert(Header {
+                    key: "clickhouse_database",
+                    value: self.ckh_database_name.as_ref(),
+                });
+                producer
+                    .producer
+                    .send(
+                        BaseRecord::to(self.get_topic(event_type))
+                            .key(&data.identifier())
+                            .payload(&json_data)
+                            .headers(headers)
+                            .timestamp(timestamp),
+                    )
+                    .map_err(|(error, record)| {
+                        report!(error).attach_printable(format!("{record:?}"))
+                    })
+                    .change_context(KafkaError::GenericError)
+                    .change_context(EventsError::PublishError)
+            }
+            MessageTransport::RedisStreams(sink) => {
+                let headers = metadata
+                    .into_iter()
+                    .chain(
+                        self.ckh_database_name
+                            .clone()
+                            .map(|db_name| ("clickhouse_database".to_string(), db_name)),
+                    )
+                    .collect();
+                sink.append_in_background(
+                    event_type,
+                    data.identifier(),
+                    &json_data,
+                    headers,
+                    timestamp,
+                )
+            }
+            MessageTransport::File(sink) => {
+                sink.write(event_type, self.ckh_database_name.as_deref(), &json_data)
+            }
         }
-        headers = headers.insert(Header {
-            key: "clickhouse_database",
-            value: self.ckh_database_name.as_ref(),
-        });
-        self.producer
-            .0
-            .send(
-                BaseRecord::to(topic)
-                    .key(&data.identifier())
-                    .payload(&json_data)
-                    .headers(headers)
-                    .timestamp(
-                        (timestamp.assume_utc().unix_timestamp_nanos() / 1_000_000)
-                            .to_i64()
-                            .unwrap_or_else(|| {
-                                // kafka producer accepts milliseconds
-                                // try converting nanos to millis if that fails convert seconds to millis
-                                timestamp.assume_utc().unix_timestamp() * 1_000
-                            }),
-                    ),
-            )
-            .map_err(|(error, record)| report!(error).attach_printable(format!("{record:?}")))
-            .change_context(KafkaError::GenericError)
-            .change_context(EventsError::PublishError)
     }
 }
@@ -0,0 +1,35 @@
+use common_utils::id_type;
+use time::OffsetDateTime;
+
+use crate::db::kafka_store::TenantID;
+
+/// Tombstone of an erased customer. The records of the customer in the events pipeline and in
+/// analytics are keyed by the merchant and customer identifiers, consumers are expected to purge
+/// the records matching the key of the tombstone.
+#[derive(serde::Serialize, Debug)]
+pub struct KafkaCustomerErasureEvent<'a> {
+    pub request_id: &'a str,
+    pub merchant_id: &'a id_type::MerchantId,
+    pub customer_id: &'a id_type::CustomerId,
+    #[serde(with = "time::serde::timestamp::nanoseconds")]
+    pub erased_at: OffsetDateTime,
+    pub tenant_id: TenantID,
+}
+
+impl super::KafkaMessage for KafkaCustomerErasureEvent<'_> {
+    fn key(&self) -> String {
+        format!(
+            "{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.customer_id.get_string_repr()
+        )
+    }
+
+    fn event_type(&self) -> crate::events::EventType {
+        crate::events::EventType::CustomerErasure
+    }
+
+    fn creation_timestamp(&self) -> Option<i64> {
+        Some(self.erased_at.unix_timestamp() * 1_000)
+    }
+}
@@ -0,0 +1,52 @@
+use common_utils::id_type;
+use diesel_models::{enums as storage_enums, outbox::Outbox};
+use time::OffsetDateTime;
+
+use crate::db::kafka_store::TenantID;
+
+/// An outbox entry as published by the outbox relay. Consumers are expected to discard the entries
+/// whose `dedupe_key` they have already processed, as the relay publishes at least once.
+#[derive(serde::Serialize, Debug)]
+pub struct KafkaOutboxEvent<'a> {
+    pub outbox_id: &'a str,
+    pub dedupe_key: &'a str,
+    pub aggregate_type: storage_enums::OutboxAggregateType,
+    pub aggregate_id: &'a str,
+    pub merchant_id: &'a id_type::MerchantId,
+    pub operation: storage_enums::OutboxOperation,
+    pub payload: &'a serde_json::Value,
+    #[serde(with = "time::serde::timestamp::nanoseconds")]
+    pub created_at: OffsetDateTime,
+    pub tenant_id: TenantID,
+}
+
+impl<'a> KafkaOutboxEvent<'a> {
+    pub fn from_storage(outbox: &'a Outbox, tenant_id: TenantID) -> Self {
+        Self {
+            outbox_id: &outbox.outbox_id,
+            dedupe_key: &outbox.dedupe_key,
+            aggregate_type: outbox.aggregate_type,
+            aggregate_id: &outbox.aggregate_id,
+            merchant_id: &outbox.merchant_id,
+            operation: outbox.operation,
+            payload: &outbox.payload,
+            created_at: outbox.created_at.assume_utc(),
+            tenant_id,
+        }
+    }
+}
+
+impl super::KafkaMessage for KafkaOutboxEvent<'_> {
+    /// Entries of the same record share a key, so that they are delivered in order
+    fn key(&self) -> String {
+        format!("{}_{}", self.aggregate_type, self.aggregate_id)
+    }
+
+    fn event_type(&self) -> crate::events::EventType {
+        crate::events::EventType::Outbox
+    }
+
+    fn creation_timestamp(&self) -> Option<i64> {
+        Some(self.created_at.unix_timestamp() * 1_000)
+    }
+}
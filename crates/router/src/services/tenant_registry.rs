@@ -0,0 +1,351 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use common_utils::id_type;
+use hyperswitch_interfaces::configs::TenantUserConfig;
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::{
+    cache::{self, CacheKind, TENANT_REGISTRY_CACHE},
+    RedisStore,
+};
+use time::PrimitiveDateTime;
+
+#[cfg(feature = "olap")]
+use crate::analytics::AnalyticsProvider;
+use crate::{
+    configs::settings::Tenant,
+    core::errors::{self, CustomResult},
+    db::{AccountsStorageInterface, GlobalStorageInterface, StorageImpl, StorageInterface},
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+/// Cache key of the list of the active tenants of the registry
+const ACTIVE_TENANTS_KEY: &str = "tenant_registry_active";
+
+/// Cache key of the registry entry of a tenant
+pub fn get_tenant_registry_key(tenant_id: &id_type::TenantId) -> String {
+    format!("tenant_registry_{}", tenant_id.get_string_repr())
+}
+
+/// Evicts the registry entry of the tenant, along with the list of the active tenants, from the
+/// caches of every router, scheduler and drainer instance.
+pub async fn publish_tenant_registry_update(
+    global_store: &dyn GlobalStorageInterface,
+    tenant_id: &id_type::TenantId,
+) -> CustomResult<usize, errors::StorageError> {
+    cache::redact_from_redis_and_publish(
+        global_store.get_cache_store().as_ref(),
+        [
+            CacheKind::TenantRegistry(get_tenant_registry_key(tenant_id).into()),
+            CacheKind::TenantRegistry(ACTIVE_TENANTS_KEY.into()),
+        ],
+    )
+    .await
+}
+
+/// Builds the configuration of a tenant from its registry entry.
+pub fn get_tenant_config(tenant: &storage::TenantRegistry) -> Tenant {
+    Tenant {
+        tenant_id: tenant.tenant_id.clone(),
+        base_url: tenant.base_url.clone(),
+        schema: tenant.schema_name.clone(),
+        accounts_schema: tenant.accounts_schema_name.clone(),
+        redis_key_prefix: tenant.redis_key_prefix.clone(),
+        clickhouse_database: tenant.clickhouse_database.clone(),
+        user: TenantUserConfig {
+            control_center_url: tenant.control_center_url.clone(),
+        },
+    }
+}
+
+/// Stores of a tenant provisioned through the tenant registry
+#[derive(Clone)]
+pub struct RegisteredTenant {
+    pub tenant: Tenant,
+    pub store: Box<dyn StorageInterface>,
+    pub accounts_store: Box<dyn AccountsStorageInterface>,
+    #[cfg(feature = "olap")]
+    pub pool: AnalyticsProvider,
+    /// Last update of the registry entry the stores were built from
+    modified_at: PrimitiveDateTime,
+}
+
+/// Tenants provisioned through the tenant registry.
+///
+/// The stores of a tenant are built lazily, on the first request or scheduler run for the tenant,
+/// and are rebuilt or dropped once its registry entry is evicted from the cache after an update.
+pub struct RegisteredTenants {
+    storage_impl: StorageImpl,
+    cache_store: Arc<RedisStore>,
+    testable: bool,
+    tenants: RwLock<HashMap<id_type::TenantId, Arc<RegisteredTenant>>>,
+}
+
+impl RegisteredTenants {
+    pub fn new(storage_impl: StorageImpl, cache_store: Arc<RedisStore>, testable: bool) -> Self {
+        Self {
+            storage_impl,
+            cache_store,
+            testable,
+            tenants: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, tenant_id: &id_type::TenantId) -> Option<Arc<RegisteredTenant>> {
+        self.tenants
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(tenant_id)
+            .cloned()
+    }
+
+    fn insert(&self, tenant: RegisteredTenant) {
+        self.tenants
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(tenant.tenant.tenant_id.clone(), Arc::new(tenant));
+    }
+
+    fn remove(&self, tenant_id: &id_type::TenantId) {
+        self.tenants
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(tenant_id);
+    }
+}
+
+/// What to do with the stores of a registered tenant once its registry entry has been read
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TenantReload<'a> {
+    /// The stores were built from the current registry entry
+    Keep,
+    /// The tenant was provisioned or updated since the stores were last built
+    Build(&'a storage::TenantRegistry),
+    /// The tenant was suspended or deleted
+    Remove,
+}
+
+/// Returns whether the stores of a tenant, last built from the registry entry modified at
+/// `loaded_modified_at`, have to be built or dropped for its current registry entry.
+fn get_tenant_reload(
+    tenant: Option<&storage::TenantRegistry>,
+    loaded_modified_at: Option<PrimitiveDateTime>,
+) -> TenantReload<'_> {
+    match tenant {
+        Some(tenant) if tenant.status == enums::TenantStatus::Active => {
+            if loaded_modified_at == Some(tenant.modified_at) {
+                TenantReload::Keep
+            } else {
+                TenantReload::Build(tenant)
+            }
+        }
+        _ => TenantReload::Remove,
+    }
+}
+
+#[instrument(skip_all)]
+async fn find_tenant_registry(
+    global_store: &dyn GlobalStorageInterface,
+    tenant_id: &id_type::TenantId,
+) -> CustomResult<Option<storage::TenantRegistry>, errors::StorageError> {
+    let cache_store = global_store.get_cache_store();
+    cache::get_or_populate_in_memory(
+        cache_store.as_ref(),
+        &get_tenant_registry_key(tenant_id),
+        || async {
+            match global_store
+                .find_tenant_registry_by_tenant_id(tenant_id)
+                .await
+            {
+                Ok(tenant) => Ok(Some(tenant)),
+                Err(error) if error.current_context().is_db_not_found() => Ok(None),
+                Err(error) => Err(error),
+            }
+        },
+        &TENANT_REGISTRY_CACHE,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+async fn list_active_tenant_registries(
+    global_store: &dyn GlobalStorageInterface,
+) -> CustomResult<Vec<storage::TenantRegistry>, errors::StorageError> {
+    let cache_store = global_store.get_cache_store();
+    cache::get_or_populate_in_memory(
+        cache_store.as_ref(),
+        ACTIVE_TENANTS_KEY,
+        || global_store.list_tenant_registries_by_status(enums::TenantStatus::Active),
+        &TENANT_REGISTRY_CACHE,
+    )
+    .await
+}
+
+impl AppState {
+    /// Makes the stores of a tenant provisioned through the tenant registry available to
+    /// [`AppState::get_session_state`], building them when the tenant was provisioned or updated
+    /// since they were last built. The tenants of the static configuration are left untouched.
+    #[instrument(skip_all)]
+    pub async fn load_registered_tenant(
+        &self,
+        tenant_id: &id_type::TenantId,
+    ) -> CustomResult<(), errors::StorageError> {
+        if !self.conf.multitenancy.enabled || self.conf.multitenancy.get_tenant(tenant_id).is_some()
+        {
+            return Ok(());
+        }
+
+        let tenant = find_tenant_registry(self.global_store.as_ref(), tenant_id).await?;
+        let loaded_modified_at = self
+            .registered_tenants
+            .get(tenant_id)
+            .map(|registered| registered.modified_at);
+        match get_tenant_reload(tenant.as_ref(), loaded_modified_at) {
+            TenantReload::Keep => {}
+            TenantReload::Build(tenant) => {
+                let registered_tenant = self.build_registered_tenant(tenant).await?;
+                self.registered_tenants.insert(registered_tenant);
+                logger::info!(tenant_id = ?tenant.tenant_id, "Loaded registered tenant");
+            }
+            TenantReload::Remove => self.registered_tenants.remove(tenant_id),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the tenants of the static configuration along with the active tenants of the
+    /// registry, loading the stores of the latter.
+    #[instrument(skip_all)]
+    pub async fn get_all_tenant_ids(&self) -> Vec<id_type::TenantId> {
+        let mut tenant_ids = self.conf.multitenancy.get_tenant_ids();
+        if !self.conf.multitenancy.enabled {
+            return tenant_ids;
+        }
+
+        let registered_tenants = list_active_tenant_registries(self.global_store.as_ref())
+            .await
+            .map_err(|error| logger::error!(tenant_registry_error=?error))
+            .unwrap_or_default();
+        for tenant in registered_tenants {
+            match self.load_registered_tenant(&tenant.tenant_id).await {
+                Ok(()) if self.registered_tenants.get(&tenant.tenant_id).is_some() => {
+                    tenant_ids.push(tenant.tenant_id)
+                }
+                Ok(()) => {}
+                Err(error) => {
+                    logger::error!(tenant_id = ?tenant.tenant_id, tenant_registry_error=?error)
+                }
+            }
+        }
+
+        tenant_ids
+    }
+
+    async fn build_registered_tenant(
+        &self,
+        tenant: &storage::TenantRegistry,
+    ) -> CustomResult<RegisteredTenant, errors::StorageError> {
+        let tenant_config = get_tenant_config(tenant);
+        let store = Box::pin(Self::try_get_store_interface(
+            &self.registered_tenants.storage_impl,
+            &self.event_handler,
+            &self.conf,
+            &tenant_config,
+            Arc::clone(&self.registered_tenants.cache_store),
+            self.registered_tenants.testable,
+        ))
+        .await?;
+
+        #[cfg(feature = "olap")]
+        let pool =
+            AnalyticsProvider::from_conf(self.conf.analytics.get_inner(), &tenant_config).await;
+
+        Ok(RegisteredTenant {
+            store: store.get_storage_interface(),
+            accounts_store: store.get_accounts_storage_interface(),
+            #[cfg(feature = "olap")]
+            pool,
+            tenant: tenant_config,
+            modified_at: tenant.modified_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn tenant(
+        status: enums::TenantStatus,
+        modified_at: PrimitiveDateTime,
+    ) -> storage::TenantRegistry {
+        storage::TenantRegistry {
+            tenant_id: id_type::TenantId::try_from_string("acme".to_string()).unwrap(),
+            base_url: "https://acme.example.com".to_string(),
+            schema_name: "acme".to_string(),
+            accounts_schema_name: "acme_accounts".to_string(),
+            redis_key_prefix: "acme".to_string(),
+            clickhouse_database: "acme".to_string(),
+            control_center_url: "https://control-center.acme.example.com".to_string(),
+            status,
+            created_at: modified_at,
+            modified_at,
+        }
+    }
+
+    #[test]
+    fn test_get_tenant_reload() {
+        let created_at = common_utils::date_time::now();
+        let updated_at = created_at.saturating_add(time::Duration::minutes(5));
+        let active_tenant = tenant(enums::TenantStatus::Active, updated_at);
+
+        // Provisioned since the last load
+        assert_eq!(
+            get_tenant_reload(Some(&active_tenant), None),
+            TenantReload::Build(&active_tenant)
+        );
+        // Updated since the last load
+        assert_eq!(
+            get_tenant_reload(Some(&active_tenant), Some(created_at)),
+            TenantReload::Build(&active_tenant)
+        );
+        assert_eq!(
+            get_tenant_reload(Some(&active_tenant), Some(updated_at)),
+            TenantReload::Keep
+        );
+        // Suspended or deleted
+        assert_eq!(
+            get_tenant_reload(
+                Some(&tenant(enums::TenantStatus::Suspended, updated_at)),
+                Some(created_at)
+            ),
+            TenantReload::Remove
+        );
+        assert_eq!(
+            get_tenant_reload(None, Some(updated_at)),
+            TenantReload::Remove
+        );
+    }
+
+    #[test]
+    fn test_get_tenant_config() {
+        let registry = tenant(enums::TenantStatus::Active, common_utils::date_time::now());
+        let config = get_tenant_config(&registry);
+
+        assert_eq!(config.tenant_id, registry.tenant_id);
+        assert_eq!(config.base_url, registry.base_url);
+        assert_eq!(config.schema, "acme");
+        assert_eq!(config.accounts_schema, "acme_accounts");
+        assert_eq!(config.redis_key_prefix, "acme");
+        assert_eq!(config.clickhouse_database, "acme");
+        assert_eq!(
+            config.user.control_center_url,
+            "https://control-center.acme.example.com"
+        );
+    }
+}
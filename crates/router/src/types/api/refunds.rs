@@ -1,5 +1,5 @@
 #[cfg(feature = "v1")]
-pub use api_models::refunds::RefundRequest;
+pub use api_models::refunds::{BulkRefundRequest, BulkRefundResponse, RefundRequest};
 pub use api_models::refunds::{
     RefundListRequest, RefundListResponse, RefundResponse, RefundStatus, RefundType,
     RefundUpdateRequest, RefundsRetrieveBody, RefundsRetrieveRequest,
@@ -1,19 +1,27 @@
+pub mod account_updater;
 pub mod address;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
+pub mod bin_range;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
+pub mod bulk_refund;
 pub mod business_profile;
 pub mod callback_mapper;
 pub mod capture;
 pub mod card_issuer;
 pub mod cards_info;
 pub mod configs;
+pub mod customer_data_request;
+pub mod customer_merge;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
+pub mod dispute_automated_action;
+pub mod dispute_evidence_template;
 pub mod dynamic_routing_stats;
 pub mod enums;
 pub mod ephemeral_key;
@@ -25,15 +33,18 @@ pub mod gsm;
 pub mod hyperswitch_ai_interaction;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod outbox;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
 #[cfg(feature = "v2")]
@@ -43,6 +54,9 @@ pub mod revenue_recovery_redis_operation;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_report;
+pub mod storage_scheme_migration;
+pub mod tenant_registry;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -70,13 +84,16 @@ pub use hyperswitch_domain_models::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, card_issuer::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*,
-    dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, hyperswitch_ai_interaction::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    account_updater::*, address::*, api_keys::*, authentication::*, authorization::*, bin_range::*,
+    blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*, bulk_refund::*,
+    business_profile::*, callback_mapper::*, capture::*, card_issuer::*, cards_info::*, configs::*,
+    customer_data_request::*, customer_merge::*, customers::*, dashboard_metadata::*,
+    data_retention::*, dispute::*, dispute_automated_action::*, dispute_evidence_template::*,
+    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
+    generic_link::*, gsm::*, hyperswitch_ai_interaction::*, ledger::*, locker_mock_up::*,
+    mandate::*, merchant_account::*, merchant_connector_account::*, merchant_key_store::*,
+    outbox::*, payment_link::*, payment_method::*, payout_batch::*, process_tracker::*, refund::*,
+    reverse_lookup::*, role::*, routing_algorithm::*, settlement_report::*,
+    storage_scheme_migration::*, tenant_registry::*, unified_translations::*, user::*,
+    user_authentication_method::*, user_role::*,
 };
@@ -0,0 +1,5 @@
+pub use diesel_models::account_updater::{
+    AccountUpdaterBatch, AccountUpdaterBatchNew, AccountUpdaterBatchUpdate,
+    AccountUpdaterBatchUpdateInternal, AccountUpdaterCandidate, AccountUpdaterCard,
+    AccountUpdaterCardNew, AccountUpdaterTrackingData,
+};
@@ -0,0 +1,4 @@
+pub use diesel_models::bulk_refund::{
+    BulkRefund, BulkRefundItem, BulkRefundItemNew, BulkRefundItemUpdate, BulkRefundNew,
+    BulkRefundTrackingData, BulkRefundUpdate,
+};
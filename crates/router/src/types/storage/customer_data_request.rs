@@ -0,0 +1,4 @@
+pub use diesel_models::customer_data_request::{
+    CustomerDataRequest, CustomerDataRequestNew, CustomerDataRequestUpdate,
+    CustomerDataRequestUpdateInternal, CustomerErasureTrackingData,
+};
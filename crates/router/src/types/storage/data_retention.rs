@@ -0,0 +1,5 @@
+pub use diesel_models::data_retention::{
+    ArchivableRecord, ArchivalWindow, ArchivedRecord, ArchivedRecordNew, DataRetentionPolicy,
+    DataRetentionPolicyNew, DataRetentionPolicyUpdate, DataRetentionPolicyUpdateInternal,
+    TablePartition,
+};
@@ -0,0 +1,4 @@
+pub use diesel_models::dispute_automated_action::{
+    DisputeAutomatedAction, DisputeAutomatedActionNew, DisputeAutomatedActionUpdate,
+    DisputeAutomatedActionUpdateInternal,
+};
@@ -0,0 +1,3 @@
+pub use diesel_models::dispute_evidence_template::{
+    DisputeEvidenceReminderTrackingData, DisputeEvidenceTemplate, DisputeEvidenceTemplateNew,
+};
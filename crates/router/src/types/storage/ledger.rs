@@ -0,0 +1,134 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+pub use diesel_models::ledger::{
+    LedgerFeeSchedule, LedgerFeeScheduleNew, LedgerJournalEntry, LedgerJournalEntryNew,
+    LedgerPosting, LedgerPostingNew,
+};
+use diesel_models::{errors, query::generics::db_metrics, schema::ledger_posting::dsl};
+use error_stack::ResultExt;
+
+use crate::{connection::PgPooledConn, logger};
+
+#[derive(Clone, Debug, Default)]
+pub struct LedgerPostingConstraints {
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub currency: Option<common_enums::Currency>,
+    pub account: Option<common_enums::LedgerAccount>,
+    pub time_range: Option<common_utils::types::TimeRange>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[async_trait::async_trait]
+pub trait LedgerPostingDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &LedgerPostingConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn get_balances_by_account_and_currency(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &LedgerPostingConstraints,
+    ) -> CustomResult<
+        Vec<(common_enums::LedgerAccount, common_enums::Currency, i64)>,
+        errors::DatabaseError,
+    >;
+}
+
+#[async_trait::async_trait]
+impl LedgerPostingDbExt for LedgerPosting {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &LedgerPostingConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order((dsl::created_at.asc(), dsl::posting_id.asc()))
+            .into_boxed();
+
+        if let Some(profile_id) = &constraints.profile_id {
+            filter = filter.filter(dsl::profile_id.eq(profile_id.to_owned()));
+        }
+        if let Some(currency) = constraints.currency {
+            filter = filter.filter(dsl::currency.eq(currency));
+        }
+        if let Some(account) = constraints.account {
+            filter = filter.filter(dsl::account.eq(account));
+        }
+        if let Some(time_range) = constraints.time_range {
+            filter = filter.filter(dsl::created_at.ge(time_range.start_time));
+
+            if let Some(end_time) = time_range.end_time {
+                filter = filter.filter(dsl::created_at.le(end_time));
+            }
+        }
+        if let Some(limit) = constraints.limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = constraints.offset {
+            filter = filter.offset(offset);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering ledger postings by predicate")
+    }
+
+    async fn get_balances_by_account_and_currency(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &LedgerPostingConstraints,
+    ) -> CustomResult<
+        Vec<(common_enums::LedgerAccount, common_enums::Currency, i64)>,
+        errors::DatabaseError,
+    > {
+        // Balances are stored debit-positive; callers flip the sign for credit-normal accounts
+        let mut query = <Self as HasTable>::table()
+            .group_by((dsl::account, dsl::currency))
+            .select((
+                dsl::account,
+                dsl::currency,
+                diesel::dsl::sql::<diesel::sql_types::BigInt>(
+                    "CAST(SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END) AS BIGINT)",
+                ),
+            ))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        if let Some(profile_id) = &constraints.profile_id {
+            query = query.filter(dsl::profile_id.eq(profile_id.to_owned()));
+        }
+        if let Some(currency) = constraints.currency {
+            query = query.filter(dsl::currency.eq(currency));
+        }
+        if let Some(time_range) = constraints.time_range {
+            query = query.filter(dsl::created_at.ge(time_range.start_time));
+
+            if let Some(end_time) = time_range.end_time {
+                query = query.filter(dsl::created_at.le(end_time));
+            }
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(common_enums::LedgerAccount, common_enums::Currency, i64)>(
+                conn,
+            ),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error computing ledger balances")
+    }
+}
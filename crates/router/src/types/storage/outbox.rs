@@ -0,0 +1 @@
+pub use diesel_models::outbox::{Outbox, OutboxNew, OutboxUpdate, OutboxUpdateInternal};
@@ -180,3 +180,13 @@ pub struct PaymentMethodStatusTrackingData {
     pub merchant_id: common_utils::id_type::MerchantId,
     pub last_modified_by: Option<String>,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentMethodExpiryTrackingData {
+    pub payment_method_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The expiry the task was scheduled for, the task is rescheduled when the card is updated
+    pub card_expiry: time::PrimitiveDateTime,
+    /// Sorted in descending order, the retry count of the task is the index of the next reminder
+    pub expiry_reminder_days: Vec<u8>,
+}
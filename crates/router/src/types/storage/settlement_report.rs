@@ -0,0 +1,5 @@
+pub use diesel_models::settlement_report::{
+    SettlementCandidate, SettlementReconLine, SettlementReconLineConstraints,
+    SettlementReconLineNew, SettlementReconLineUpdate, SettlementReport, SettlementReportNew,
+    SettlementReportTrackingData, SettlementReportUpdate,
+};
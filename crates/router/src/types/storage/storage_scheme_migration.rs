@@ -0,0 +1,9 @@
+use diesel_models::{refund::Refund, PaymentAttempt, PaymentIntent};
+
+/// Copies of a payment, along with its attempts and refunds, held by one of the storages
+#[derive(Debug, Default)]
+pub struct PaymentRecords {
+    pub payment_intent: Option<PaymentIntent>,
+    pub payment_attempts: Vec<PaymentAttempt>,
+    pub refunds: Vec<Refund>,
+}
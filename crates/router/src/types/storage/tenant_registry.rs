@@ -0,0 +1,3 @@
+pub use diesel_models::tenant_registry::{
+    TenantRegistry, TenantRegistryNew, TenantRegistryUpdate, TenantRegistryUpdateInternal,
+};
@@ -4,6 +4,7 @@ pub mod connector_onboarding;
 pub mod currency;
 pub mod db_utils;
 pub mod ext_traits;
+pub mod json_files;
 #[cfg(feature = "olap")]
 pub mod oidc;
 #[cfg(feature = "kv_store")]
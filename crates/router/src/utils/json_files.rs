@@ -0,0 +1,103 @@
+//! Encoding of JSON documents as files, shared by the events file sink and the data archival.
+
+use std::io::{Read, Write};
+#[cfg(feature = "events_parquet")]
+use std::sync::Arc;
+
+use common_utils::errors::{CustomResult, ParsingError};
+use error_stack::ResultExt;
+
+/// Encodes the documents as gzip compressed NDJSON, one document per line.
+pub fn encode_ndjson_gzip(values: &[serde_json::Value]) -> CustomResult<Vec<u8>, ParsingError> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    for value in values {
+        serde_json::to_writer(&mut encoder, value)
+            .change_context(ParsingError::EncodeError("ndjson"))?;
+        encoder
+            .write_all(b"\n")
+            .change_context(ParsingError::EncodeError("ndjson"))?;
+    }
+    encoder
+        .finish()
+        .change_context(ParsingError::EncodeError("gzip"))
+}
+
+pub fn decode_ndjson_gzip(file: &[u8]) -> CustomResult<Vec<serde_json::Value>, ParsingError> {
+    let mut ndjson = Vec::new();
+    flate2::read::GzDecoder::new(file)
+        .read_to_end(&mut ndjson)
+        .change_context(ParsingError::UnknownError)
+        .attach_printable("Failed to decompress the gzip file")?;
+
+    ndjson
+        .split(|byte| *byte == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(serde_json::from_slice)
+        .collect::<Result<_, _>>()
+        .change_context(ParsingError::StructParseFailure("ndjson"))
+}
+
+/// Encodes the documents as a snappy compressed Parquet file, whose schema is inferred from the
+/// documents.
+#[cfg(feature = "events_parquet")]
+pub fn encode_parquet(values: &[serde_json::Value]) -> CustomResult<Vec<u8>, ParsingError> {
+    let schema = Arc::new(
+        arrow_json::reader::infer_json_schema_from_iterator(values.iter().map(Ok))
+            .change_context(ParsingError::EncodeError("parquet"))
+            .attach_printable("Failed to infer the schema of the documents")?,
+    );
+
+    let mut decoder = arrow_json::ReaderBuilder::new(Arc::clone(&schema))
+        .with_batch_size(values.len().max(1))
+        .build_decoder()
+        .change_context(ParsingError::EncodeError("parquet"))?;
+    decoder
+        .serialize(values)
+        .change_context(ParsingError::EncodeError("parquet"))?;
+
+    let properties = parquet::file::properties::WriterProperties::builder()
+        .set_compression(parquet::basic::Compression::SNAPPY)
+        .build();
+    let mut file = Vec::new();
+    let mut writer = parquet::arrow::ArrowWriter::try_new(&mut file, schema, Some(properties))
+        .change_context(ParsingError::EncodeError("parquet"))?;
+    if let Some(batch) = decoder
+        .flush()
+        .change_context(ParsingError::EncodeError("parquet"))?
+    {
+        writer
+            .write(&batch)
+            .change_context(ParsingError::EncodeError("parquet"))?;
+    }
+    writer
+        .close()
+        .change_context(ParsingError::EncodeError("parquet"))?;
+
+    Ok(file)
+}
+
+#[cfg(feature = "events_parquet")]
+pub fn decode_parquet(file: Vec<u8>) -> CustomResult<Vec<serde_json::Value>, ParsingError> {
+    let reader = parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(
+        bytes::Bytes::from(file),
+    )
+    .and_then(|builder| builder.build())
+    .change_context(ParsingError::StructParseFailure("parquet"))?;
+
+    let mut writer = arrow_json::ArrayWriter::new(Vec::new());
+    for batch in reader {
+        let batch = batch.change_context(ParsingError::StructParseFailure("parquet"))?;
+        writer
+            .write(&batch)
+            .change_context(ParsingError::StructParseFailure("parquet"))?;
+    }
+    writer
+        .finish()
+        .change_context(ParsingError::StructParseFailure("parquet"))?;
+
+    let json = writer.into_inner();
+    if json.is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_slice(&json).change_context(ParsingError::StructParseFailure("parquet"))
+}
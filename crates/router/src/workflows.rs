@@ -2,6 +2,7 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod bulk_refund;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
@@ -16,7 +17,28 @@ pub mod process_dispute;
 
 pub mod dispute_list;
 
+pub mod dispute_evidence_reminder;
+
 pub mod invoice_sync;
 
+pub mod subscription_billing;
+
+pub mod dunning;
+
+pub mod settlement_recon;
+
+pub mod customer_erasure;
+
+pub mod account_updater;
+
+pub mod payment_method_expiry;
+
+pub mod storage_scheme_migration;
+
+pub mod ledger_posting;
+
+#[cfg(feature = "payouts")]
+pub mod payout_batch;
+
 #[cfg(feature = "payouts")]
 pub mod payout_sync;
@@ -0,0 +1,77 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{core::account_updater, types::domain};
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+
+pub struct AccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AccountUpdaterWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::AccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AccountUpdaterTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account,
+            key_store,
+            None,
+        );
+
+        account_updater::run_account_updater(state, &platform, tracking_data.connector).await?;
+
+        // The enrollment stays scheduled until the merchant disables it
+        let interval = i64::try_from(state.conf.account_updater.interval).unwrap_or(i64::MAX);
+        let schedule_time =
+            common_utils::date_time::now().saturating_add(time::Duration::seconds(interval));
+        db.as_scheduler()
+            .reset_process(process, schedule_time)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
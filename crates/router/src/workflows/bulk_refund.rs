@@ -0,0 +1,107 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{core::refunds::bulk, types::domain};
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+
+pub struct BulkRefundWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for BulkRefundWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::BulkRefundTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BulkRefundTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let bulk_refund = db
+            .find_bulk_refund_by_merchant_id_bulk_refund_id(
+                &tracking_data.merchant_id,
+                &tracking_data.bulk_refund_id,
+            )
+            .await?;
+
+        if bulk_refund.status != storage::enums::BulkRefundStatus::Processing {
+            logger::warn!(
+                bulk_refund_id = %bulk_refund.bulk_refund_id,
+                status = %bulk_refund.status,
+                "bulk refund is not in processing status, skipping execution"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account,
+            key_store,
+            None,
+        );
+
+        let is_bulk_refund_executed =
+            bulk::execute_bulk_refund_items(state, &platform, bulk_refund).await?;
+
+        if is_bulk_refund_executed {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+        } else {
+            // Pick up the next set of items in the following run
+            db.as_scheduler()
+                .retry_process(process, common_utils::date_time::now())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
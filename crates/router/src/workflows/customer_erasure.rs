@@ -0,0 +1,111 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{core::customer_data_requests, types::domain};
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+
+pub struct CustomerErasureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CustomerErasureWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::CustomerErasureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerErasureTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let customer_data_request = db
+            .find_customer_data_request_by_merchant_id_request_id(
+                &tracking_data.merchant_id,
+                &tracking_data.request_id,
+            )
+            .await?;
+
+        // Requests in processing status are picked up again if a previous run was interrupted
+        if !matches!(
+            customer_data_request.status,
+            storage::enums::CustomerDataRequestStatus::Pending
+                | storage::enums::CustomerDataRequestStatus::Processing
+        ) {
+            logger::warn!(
+                request_id = %customer_data_request.request_id,
+                status = %customer_data_request.status,
+                "customer erasure has already been executed, skipping execution"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account,
+            key_store,
+            None,
+        );
+
+        let process = customer_data_requests::execute_customer_erasure(
+            state,
+            &platform,
+            customer_data_request,
+            tracking_data,
+            process,
+        )
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
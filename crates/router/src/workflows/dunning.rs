@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+#[cfg(all(feature = "v1", feature = "email"))]
+use common_utils::types::{AmountConvertor, StringMajorUnitForCore};
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+#[cfg(all(feature = "v1", feature = "email"))]
+use error_stack::ResultExt;
+#[cfg(all(feature = "v1", feature = "email"))]
+use hyperswitch_masking::PeekInterface;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(all(feature = "v1", feature = "email"))]
+use crate::{
+    consts,
+    core::errors::{self as core_errors, RouterResult, StorageErrorExt},
+    services::email::types as email_types,
+    types::domain,
+    utils::user as user_utils,
+};
+use crate::{routes::SessionState, types::storage};
+
+const DUNNING_WORKFLOW: &str = "DUNNING";
+
+pub struct DunningWorkflow;
+
+#[async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DunningWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data = process
+            .tracking_data
+            .clone()
+            .parse_value::<subscriptions::storage::dunning::DunningTrackingData>(
+            "DunningTrackingData",
+        )?;
+        let subscription_state = state.clone().into();
+        match process.name.as_deref() {
+            Some(DUNNING_WORKFLOW) => {
+                let reminder = Box::pin(subscriptions::workflows::perform_dunning(
+                    &subscription_state,
+                    process,
+                    tracking_data,
+                ))
+                .await?;
+
+                if let Some(reminder) = reminder {
+                    #[cfg(feature = "email")]
+                    {
+                        let _ =
+                            send_dunning_reminder_email(state, reminder)
+                                .await
+                                .map_err(|error| {
+                                    logger::error!(?error, "Failed to email the dunning reminder")
+                                });
+                    }
+
+                    #[cfg(not(feature = "email"))]
+                    logger::warn!(
+                        customer_id = ?reminder.customer_id,
+                        "Dunning reminders are enabled but the email feature is not compiled in"
+                    );
+                }
+
+                Ok(())
+            }
+            _ => Err(errors::ProcessTrackerError::JobNotFound),
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(?error, "Failed to run dunning step");
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "email"))]
+async fn send_dunning_reminder_email(
+    state: &SessionState,
+    reminder: subscriptions::dunning::DunningReminder,
+) -> RouterResult<()> {
+    let provider = reminder.platform.get_provider();
+    let customer = state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &reminder.customer_id,
+            provider.get_account().get_id(),
+            provider.get_key_store(),
+            provider.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(core_errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let Some(email) = customer.email.map(common_utils::pii::Email::from) else {
+        logger::info!("Customer has no email, skipping the dunning reminder email");
+        return Ok(());
+    };
+    let recipient_email = domain::UserEmail::from_pii_email(email)
+        .change_context(core_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the customer email to UserEmail")?;
+
+    let amount = StringMajorUnitForCore
+        .convert(reminder.amount, reminder.currency)
+        .change_context(core_errors::ApiErrorResponse::AmountConversionFailed {
+            amount_type: "StringMajorUnit",
+        })?;
+    let merchant_account = reminder.platform.get_processor().get_account();
+    let email_contents = email_types::SubscriptionPaymentFailed {
+        recipient_email,
+        subject: consts::EMAIL_SUBJECT_SUBSCRIPTION_PAYMENT_FAILED,
+        customer_name: customer
+            .name
+            .map(|name| name.into_inner().peek().to_owned()),
+        merchant_name: merchant_account
+            .merchant_name
+            .clone()
+            .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+            .unwrap_or_else(|| merchant_account.get_id().get_string_repr().to_owned()),
+        amount: format!("{} {}", reminder.currency, amount.get_amount_as_string()),
+        payment_link: reminder.payment_link,
+        next_retry_date: reminder.next_retry_at.date().to_string(),
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            user_utils::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(core_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send the dunning reminder email")?;
+
+    Ok(())
+}
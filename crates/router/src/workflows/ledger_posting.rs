@@ -0,0 +1,78 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::core::ledger::journal;
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+
+pub struct LedgerPostingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for LedgerPostingWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: journal::LedgerPostingTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("LedgerPostingTrackingData")?;
+
+        match journal::post_ledger_entry(state, tracking_data).await {
+            Ok(()) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            // The database may be unavailable, so the posting is retried before the task is
+            // failed
+            Err(error) if process.retry_count < journal::LEDGER_POSTING_MAX_RETRIES => {
+                logger::warn!(
+                    process_id = %process.id,
+                    ?error,
+                    "Failed to post ledger entry, retrying"
+                );
+                let schedule_time =
+                    common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                        journal::LEDGER_POSTING_RETRY_INTERVAL
+                            .saturating_mul(i64::from(process.retry_count).saturating_add(1)),
+                    ));
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+            }
+            Err(error) => Err(error)?,
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -364,8 +364,10 @@ async fn get_outgoing_webhook_content_and_event_type(
 
     use crate::{
         core::{
+            customer_merge::get_customer_merge_response,
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::webhooks::get_payment_method_webhook_response_by_primary_object_id,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
         },
@@ -498,7 +500,15 @@ async fn get_outgoing_webhook_content_and_event_type(
                     }
                 }
                 .map(Box::new)?;
-            let event_type = Some(EventType::from(dispute_response.dispute_status));
+            let event_type = match dispute_response.dispute_status {
+                // Evidence reminders are sent for disputes which are still open
+                storage::enums::DisputeStatus::DisputeOpened
+                    if tracking_data.event_type == EventType::DisputeEvidenceDue =>
+                {
+                    Some(EventType::DisputeEvidenceDue)
+                }
+                dispute_status => Some(EventType::from(dispute_status)),
+            };
             logger::debug!(current_resource_status=%dispute_response.dispute_status);
 
             Ok((
@@ -538,6 +548,29 @@ async fn get_outgoing_webhook_content_and_event_type(
             ))
         }
         #[cfg(feature = "payouts")]
+        diesel_models::enums::EventClass::Payouts
+            if tracking_data.primary_object_type
+                == diesel_models::enums::EventObjectType::PayoutBatchDetails =>
+        {
+            let payout_batch = state
+                .store
+                .find_payout_batch_by_merchant_id_payout_batch_id(
+                    merchant_account.get_id(),
+                    &tracking_data.primary_object_id,
+                )
+                .await?;
+
+            let event_type: Option<EventType> = payout_batch.status.into();
+            logger::debug!(current_resource_status=%payout_batch.status);
+
+            Ok((
+                OutgoingWebhookContent::PayoutBatchDetails(Box::new(
+                    payout_models::PayoutBatchResponse::foreign_from((payout_batch, None)),
+                )),
+                event_type,
+            ))
+        }
+        #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
             let request = payout_models::PayoutRequest::PayoutActionRequest(
@@ -591,5 +624,31 @@ async fn get_outgoing_webhook_content_and_event_type(
                 Some(EventType::InvoicePaid),
             ))
         }
+        diesel_models::enums::EventClass::Customers => {
+            let merge_response = get_customer_merge_response(
+                &state,
+                merchant_account.get_id(),
+                &tracking_data.primary_object_id,
+            )
+            .await?;
+
+            Ok((
+                OutgoingWebhookContent::CustomerDetails(Box::new(merge_response)),
+                Some(EventType::CustomerMerged),
+            ))
+        }
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method_response = get_payment_method_webhook_response_by_primary_object_id(
+                &state,
+                &platform,
+                &tracking_data.primary_object_id,
+            )
+            .await?;
+
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+                Some(tracking_data.event_type),
+            ))
+        }
     }
 }
@@ -0,0 +1,192 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::{Encode, ValueExt};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{core::payment_methods::expiry, types::domain};
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+
+pub struct PaymentMethodExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentMethodExpiryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::PaymentMethodExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentMethodExpiryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_method = match db
+            .find_payment_method(
+                &key_store,
+                &tracking_data.payment_method_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_method) => payment_method,
+            Err(error) if error.current_context().is_db_not_found() => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::REVOKED)
+                    .await
+                    .map_err(Into::into);
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        // Cards which were deactivated or deleted in the meantime need no notifications
+        let card_expiry = expiry::get_payment_method_expiry(&payment_method);
+        let Some(card_expiry) = card_expiry
+            .filter(|_| payment_method.status == storage::enums::PaymentMethodStatus::Active)
+        else {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        };
+
+        let now = common_utils::date_time::now();
+        let step = usize::try_from(process.retry_count)
+            .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?;
+
+        // The card was updated since the task was scheduled, for instance by the account updater,
+        // the reminders start over for the new expiry
+        if card_expiry != tracking_data.card_expiry {
+            logger::info!(
+                payment_method_id = %tracking_data.payment_method_id,
+                "Card expiry has changed, rescheduling the expiry of the payment method"
+            );
+            let expiry_reminder_days = tracking_data.expiry_reminder_days.clone();
+            let (next_step, schedule_time) =
+                expiry::get_next_expiry_step(card_expiry, &expiry_reminder_days, 0, now);
+            let tracking_data = storage::PaymentMethodExpiryTrackingData {
+                card_expiry,
+                ..tracking_data
+            }
+            .encode_to_value()
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+            return schedule_next_step(
+                state,
+                process,
+                next_step,
+                schedule_time,
+                Some(tracking_data),
+            )
+            .await;
+        }
+
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account,
+            key_store,
+            None,
+        );
+
+        match tracking_data.expiry_reminder_days.get(step) {
+            Some(expires_in) => {
+                expiry::send_payment_method_expiry_reminder(
+                    state,
+                    &platform,
+                    &payment_method,
+                    *expires_in,
+                )
+                .await;
+
+                let (next_step, schedule_time) = expiry::get_next_expiry_step(
+                    card_expiry,
+                    &tracking_data.expiry_reminder_days,
+                    step.saturating_add(1),
+                    now,
+                );
+                schedule_next_step(state, process, next_step, schedule_time, None).await
+            }
+            None => {
+                expiry::expire_payment_method(state, &platform, payment_method).await?;
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into)
+            }
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn schedule_next_step(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    step: usize,
+    schedule_time: time::PrimitiveDateTime,
+    tracking_data: Option<serde_json::Value>,
+) -> Result<(), errors::ProcessTrackerError> {
+    let retry_count =
+        i32::try_from(step).map_err(|_| errors::ProcessTrackerError::TypeConversionError)?;
+
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(retry_count),
+                schedule_time: Some(schedule_time),
+                tracking_data,
+                business_status: None,
+                status: Some(storage::enums::ProcessTrackerStatus::New),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::core::admin;
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+
+pub struct StorageSchemeMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for StorageSchemeMigrationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: admin::StorageSchemeMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("StorageSchemeMigrationTrackingData")?;
+
+        let is_last_attempt = process.retry_count >= admin::STORAGE_SCHEME_DRAIN_CHECK_ATTEMPTS;
+        match admin::verify_storage_scheme_migration(state, &tracking_data, is_last_attempt).await?
+        {
+            // The drainer has not caught up with the merchant yet
+            Some(api_models::admin::StorageSchemeMigrationStatus::InProgress) => {
+                let schedule_time = common_utils::date_time::now().saturating_add(
+                    time::Duration::seconds(admin::STORAGE_SCHEME_DRAIN_CHECK_INTERVAL),
+                );
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+            }
+            Some(_) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            None => {
+                logger::warn!(
+                    migration_id = %tracking_data.migration_id,
+                    "storage scheme migration was superseded, skipping execution"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        business_status::RESOURCE_STATUS_MISMATCH,
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
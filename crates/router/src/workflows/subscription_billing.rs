@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{routes::SessionState, types::storage};
+
+const SUBSCRIPTION_BILLING_WORKFLOW: &str = "SUBSCRIPTION_BILLING";
+
+pub struct SubscriptionBillingWorkflow;
+
+#[async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SubscriptionBillingWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data = process
+            .tracking_data
+            .clone()
+            .parse_value::<subscriptions::storage::subscription_billing::SubscriptionBillingTrackingData>(
+            "SubscriptionBillingTrackingData",
+        )?;
+        let subscription_state = state.clone().into();
+        match process.name.as_deref() {
+            Some(SUBSCRIPTION_BILLING_WORKFLOW) => {
+                Box::pin(subscriptions::workflows::perform_subscription_billing(
+                    &subscription_state,
+                    process,
+                    tracking_data,
+                ))
+                .await
+            }
+            _ => Err(errors::ProcessTrackerError::JobNotFound),
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(?error, "Failed to run subscription billing");
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(())
+    }
+}
@@ -116,6 +116,20 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers export data flow.
+    CustomersExportData,
+    /// Customers erase data flow.
+    CustomersEraseData,
+    /// Customers list data requests flow.
+    CustomersListDataRequests,
+    /// Customers retrieve data request flow.
+    CustomersRetrieveDataRequest,
+    /// Customers download data archive flow.
+    CustomersDownloadDataArchive,
+    /// Customers merge flow.
+    CustomersMerge,
+    /// Customers find duplicates flow.
+    CustomersFindDuplicates,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -142,6 +156,8 @@ pub enum Flow {
     PaymentMethodGetTokenDetails,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
+    /// List the active cards of a customer which are about to expire
+    CustomerPaymentMethodsExpiringList,
     /// Payment methods token data get flow.
     GetPaymentMethodTokenData,
     /// List Customers for a merchant
@@ -233,6 +249,12 @@ pub enum Flow {
     PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Payout batch create flow.
+    PayoutBatchCreate,
+    /// Payout batch retrieve flow.
+    PayoutBatchRetrieve,
+    /// Payout batch review flow.
+    PayoutBatchReview,
     /// Payout link initiate flow
     PayoutLinkInitiate,
     /// Payments Redirect flow
@@ -253,6 +275,12 @@ pub enum Flow {
     RefundsFilters,
     /// Refunds aggregates flow
     RefundsAggregate,
+    /// Bulk refund create flow
+    BulkRefundCreate,
+    /// Bulk refund retrieve flow
+    BulkRefundRetrieve,
+    /// Bulk refund report flow
+    BulkRefundReport,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -305,6 +333,44 @@ pub enum Flow {
     ResumeSubscription,
     /// Cancel Subscription flow
     CancelSubscription,
+    /// Billing product create flow
+    CreateBillingProduct,
+    /// Billing product retrieve flow
+    RetrieveBillingProduct,
+    /// Billing product list flow
+    ListBillingProducts,
+    /// Billing product update flow
+    UpdateBillingProduct,
+    /// Billing plan create flow
+    CreateBillingPlan,
+    /// Billing plan retrieve flow
+    RetrieveBillingPlan,
+    /// Billing plan list flow
+    ListBillingPlans,
+    /// Billing plan update flow
+    UpdateBillingPlan,
+    /// Billing price create flow
+    CreateBillingPrice,
+    /// Billing price retrieve flow
+    RetrieveBillingPrice,
+    /// Billing price list flow
+    ListBillingPrices,
+    /// Billing price update flow
+    UpdateBillingPrice,
+    /// Billing coupon create flow
+    CreateBillingCoupon,
+    /// Billing coupon retrieve flow
+    RetrieveBillingCoupon,
+    /// Billing coupon list flow
+    ListBillingCoupons,
+    /// Billing coupon update flow
+    UpdateBillingCoupon,
+    /// Dunning policy upsert flow
+    UpsertDunningPolicy,
+    /// Dunning policy retrieve flow
+    RetrieveDunningPolicy,
+    /// Dunning policy delete flow
+    DeleteDunningPolicy,
     /// Create dynamic routing
     CreateDynamicRoutingConfig,
     /// Toggle dynamic routing
@@ -317,6 +383,58 @@ pub enum Flow {
     UpdateCardIssuer,
     /// List card issuers from the catalog
     ListCardIssuers,
+    /// Create a ledger fee schedule
+    LedgerFeeScheduleCreate,
+    /// List the ledger fee schedules of a merchant
+    LedgerFeeScheduleList,
+    /// Delete a ledger fee schedule
+    LedgerFeeScheduleDelete,
+    /// Retrieve the ledger balances of a merchant
+    LedgerBalanceRetrieve,
+    /// Retrieve the ledger statement of a merchant
+    LedgerStatementRetrieve,
+    /// Create a settlement report for reconciliation
+    SettlementReportCreate,
+    /// List the settlement reports of a merchant
+    SettlementReportList,
+    /// Retrieve a settlement report
+    SettlementReportRetrieve,
+    /// List the recon lines of a settlement report
+    SettlementReportLinesList,
+    /// List the settlement reconciliation exceptions of a merchant
+    SettlementExceptionList,
+    /// Resolve a settlement reconciliation exception
+    SettlementExceptionResolve,
+    /// Provision a tenant in the tenant registry
+    TenantCreate,
+    /// List the tenants of the tenant registry
+    TenantList,
+    /// Retrieve a tenant of the tenant registry
+    TenantRetrieve,
+    /// Suspend a tenant of the tenant registry
+    TenantSuspend,
+    /// Reactivate a suspended tenant of the tenant registry
+    TenantActivate,
+    /// Delete a tenant from the tenant registry
+    TenantDelete,
+    /// Create or update the data retention policy of a table
+    DataRetentionPolicyUpsert,
+    /// List the data retention policies of the tenant
+    DataRetentionPolicyList,
+    /// Delete the data retention policy of a table
+    DataRetentionPolicyDelete,
+    /// Retrieve the archived records of a payment
+    PaymentsRetrieveArchived,
+    /// Enroll the merchant in the card account updater
+    AccountUpdaterEnable,
+    /// Stop the card account updater of the merchant
+    AccountUpdaterDisable,
+    /// Retrieve the card account updater enrollment of the merchant
+    AccountUpdaterRetrieve,
+    /// List the card account updater batches of the merchant
+    AccountUpdaterBatchList,
+    /// Retrieve a card account updater batch along with its cards
+    AccountUpdaterBatchRetrieve,
     /// Add record to blocklist
     AddToBlocklist,
     /// Delete record from blocklist
@@ -367,6 +485,22 @@ pub enum Flow {
     DisputesAggregate,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Dispute Evidence Template create flow
+    DisputeEvidenceTemplateCreate,
+    /// Dispute Evidence Template list flow
+    DisputeEvidenceTemplateList,
+    /// Dispute Evidence Template delete flow
+    DisputeEvidenceTemplateDelete,
+    /// Dispute Evidence assemble flow
+    DisputeEvidenceAssemble,
+    /// Dispute Rules upsert flow
+    DisputeRulesUpsert,
+    /// Dispute Rules retrieve flow
+    DisputeRulesRetrieve,
+    /// Dispute Rules delete flow
+    DisputeRulesDelete,
+    /// Dispute Automated Actions list flow
+    DisputeAutomatedActionsList,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -427,6 +561,12 @@ pub enum Flow {
     DecisionManagerRetrieveConfig,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
+    /// Upsert the native fraud rules of a profile
+    FrmRulesUpsert,
+    /// Retrieve the native fraud rules of a profile
+    FrmRulesRetrieve,
+    /// Delete the native fraud rules of a profile
+    FrmRulesDelete,
     /// Get connectors feature matrix
     FeatureMatrix,
     /// Change password flow
@@ -673,6 +813,8 @@ pub enum Flow {
     CardsInfoUpdate,
     /// Cards Info migrate flow
     CardsInfoMigrate,
+    /// BIN range upload flow
+    BinRangeUpload,
     ///Total payment method count for merchant
     TotalPaymentMethodCount,
     /// Process Tracker Revenue Recovery Workflow Retrieve
@@ -709,6 +851,12 @@ pub enum Flow {
     ProfileAcquirerUpdate,
     /// ThreeDs Decision Rule Execute flow
     ThreeDsDecisionRuleExecute,
+    /// Upsert the sca exemption engine config of a profile
+    ScaExemptionEngineUpsert,
+    /// Retrieve the sca exemption engine config of a profile
+    ScaExemptionEngineRetrieve,
+    /// Delete the sca exemption engine config of a profile
+    ScaExemptionEngineDelete,
     /// Incoming Network Token Webhook Receive
     IncomingNetworkTokenWebhookReceive,
     /// Decision Engine Decide Gateway Call
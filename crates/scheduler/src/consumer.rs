@@ -85,7 +85,7 @@ where
                 }
                 consumer_operation_counter.fetch_add(1, atomic::Ordering::SeqCst);
                 let start_time = std_time::Instant::now();
-                let tenants = state.get_tenants();
+                let tenants = state.get_tenants().await;
                 for tenant in tenants {
                     let session_state = app_state_to_session_state(state, &tenant)?;
                     pt_utils::consumer_operation_handler(
@@ -213,23 +213,52 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn reinitialize_limbo_processes(
         &self,
-        _ids: Vec<String>,
-        _schedule_time: PrimitiveDateTime,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let limbo_processes = processes.iter_mut().filter(|process| {
+            process.status == storage_enums::ProcessTrackerStatus::ProcessStarted
+                && ids.contains(&process.id)
+        });
+
+        let mut count = 0;
+        for process in limbo_processes {
+            process.status = storage_enums::ProcessTrackerStatus::Processing;
+            process.schedule_time = Some(schedule_time);
+            process.updated_at = common_utils::date_time::now();
+            count += 1;
+        }
+        Ok(count)
     }
 
     async fn find_processes_by_time_status(
         &self,
-        _time_lower_limit: PrimitiveDateTime,
-        _time_upper_limit: PrimitiveDateTime,
-        _status: storage_enums::ProcessTrackerStatus,
-        _limit: Option<i64>,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let processes = self.processes.lock().await;
+        let processes = processes
+            .iter()
+            .filter(|process| {
+                process.schedule_time.is_some_and(|schedule_time| {
+                    (time_lower_limit..=time_upper_limit).contains(&schedule_time)
+                }) && process.status == status
+                    && process.version == common_types::consts::API_VERSION
+            })
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .cloned()
+            .collect();
+
+        Ok(processes)
     }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -258,46 +287,190 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let process_to_update = processes.iter_mut().find(|item| item.id == this.id).ok_or(
+            errors::StorageError::ValueNotFound(format!(
+                "No process tracker entry found for id = {}",
+                this.id
+            )),
+        )?;
+
+        *process_to_update = storage::ProcessTrackerUpdateInternal::from(process)
+            .apply_changeset(process_to_update.clone());
+        Ok(process_to_update.clone())
     }
 
     async fn reset_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::New,
+                retry_count: 0,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn retry_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        metrics::TASK_RETRIED.add(1, &[]);
+        let retry_count = this.retry_count + 1;
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::Pending,
+                retry_count,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn finish_process_with_business_status(
         &self,
-        _this: storage::ProcessTracker,
-        _business_status: &'static str,
+        this: storage::ProcessTracker,
+        business_status: &'static str,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(String::from(business_status)),
+            },
+        )
+        .await
+        .attach_printable("Failed to update business status of process")?;
+        metrics::TASK_FINISHED.add(1, &[]);
+        Ok(())
     }
 
     async fn process_tracker_update_process_status_by_ids(
         &self,
-        _task_ids: Vec<String>,
-        _task_update: storage::ProcessTrackerUpdate,
+        task_ids: Vec<String>,
+        task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let changeset = storage::ProcessTrackerUpdateInternal::from(task_update);
+
+        let mut count = 0;
+        for process in processes
+            .iter_mut()
+            .filter(|process| task_ids.contains(&process.id))
+        {
+            *process = changeset.clone().apply_changeset(process.clone());
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::types::keymanager::KeyManagerState;
+    use redis_interface::RedisSettings;
+
+    use super::*;
+
+    async fn insert_process(db: &MockDb, id: &str) -> storage::ProcessTracker {
+        db.insert_process(
+            storage::ProcessTrackerNew::new(
+                id,
+                "PAYMENTS_SYNC",
+                storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+                ["PAYMENT"],
+                "tracking_data",
+                None,
+                common_utils::date_time::now(),
+                common_types::consts::API_VERSION,
+                storage_enums::ApplicationSource::Main,
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_retry_and_reset_process() {
+        let db = MockDb::new(&RedisSettings::default(), KeyManagerState::mock())
+            .await
+            .unwrap();
+        let process = insert_process(&db, "pt_retry").await;
+        let schedule_time = common_utils::date_time::now();
+
+        db.retry_process(process, schedule_time).await.unwrap();
+        let process = db.find_process_by_id("pt_retry").await.unwrap().unwrap();
+        assert_eq!(process.status, storage_enums::ProcessTrackerStatus::Pending);
+        assert_eq!(process.retry_count, 1);
+        assert_eq!(process.schedule_time, Some(schedule_time));
+
+        db.reset_process(process, schedule_time).await.unwrap();
+        let process = db.find_process_by_id("pt_retry").await.unwrap().unwrap();
+        assert_eq!(process.status, storage_enums::ProcessTrackerStatus::New);
+        assert_eq!(process.retry_count, 0);
+
+        let unknown = storage::ProcessTracker {
+            id: "pt_unknown".into(),
+            ..process
+        };
+        assert!(db
+            .update_process(
+                unknown,
+                storage::ProcessTrackerUpdate::StatusUpdate {
+                    status: storage_enums::ProcessTrackerStatus::Finish,
+                    business_status: None,
+                },
+            )
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reinitialize_limbo_processes() {
+        let db = MockDb::new(&RedisSettings::default(), KeyManagerState::mock())
+            .await
+            .unwrap();
+        insert_process(&db, "pt_started").await;
+        insert_process(&db, "pt_new").await;
+        db.process_tracker_update_process_status_by_ids(
+            vec!["pt_started".into()],
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::ProcessStarted,
+                business_status: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let schedule_time = common_utils::date_time::now();
+        let count = db
+            .reinitialize_limbo_processes(vec!["pt_started".into(), "pt_new".into()], schedule_time)
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let started = db.find_process_by_id("pt_started").await.unwrap().unwrap();
+        assert_eq!(
+            started.status,
+            storage_enums::ProcessTrackerStatus::Processing
+        );
+        assert_eq!(started.schedule_time, Some(schedule_time));
+        let new = db.find_process_by_id("pt_new").await.unwrap().unwrap();
+        assert_eq!(new.status, storage_enums::ProcessTrackerStatus::New);
     }
 }
@@ -1,6 +1,6 @@
 use common_utils::errors::CustomResult;
 use diesel_models::process_tracker as storage;
-use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply};
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisEntryId, SetnxReply};
 use router_env::logger;
 use storage_impl::{mock_db::MockDb, redis::kv_store::RedisConnInterface};
 
@@ -81,41 +81,11 @@ impl QueueInterface for Store {
         lock_val: &str,
         ttl: i64,
     ) -> CustomResult<bool, RedisError> {
-        let conn = self.get_redis_conn()?.clone();
-        let is_lock_acquired = conn
-            .set_key_if_not_exists_with_expiry(&lock_key.into(), lock_val, None)
-            .await;
-        Ok(match is_lock_acquired {
-            Ok(SetnxReply::KeySet) => match conn.set_expiry(&lock_key.into(), ttl).await {
-                Ok(()) => true,
-
-                #[allow(unused_must_use)]
-                Err(error) => {
-                    logger::error!(?error);
-                    conn.delete_key(&lock_key.into()).await;
-                    false
-                }
-            },
-            Ok(SetnxReply::KeyNotSet) => {
-                logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
-                false
-            }
-            Err(error) => {
-                logger::error!(?error, %tag, "Error while locking");
-                false
-            }
-        })
+        Ok(acquire_lock(&self.get_redis_conn()?, tag, lock_key, lock_val, ttl).await)
     }
 
     async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> CustomResult<bool, RedisError> {
-        let is_lock_released = self.get_redis_conn()?.delete_key(&lock_key.into()).await;
-        Ok(match is_lock_released {
-            Ok(_del_reply) => true,
-            Err(error) => {
-                logger::error!(?error, %tag, "Error while releasing lock");
-                false
-            }
-        })
+        Ok(release_lock(&self.get_redis_conn()?, tag, lock_key).await)
     }
 
     async fn stream_append_entry(
@@ -138,53 +108,231 @@ impl QueueInterface for Store {
 impl QueueInterface for MockDb {
     async fn fetch_consumer_tasks(
         &self,
-        _stream_name: &str,
-        _group_name: &str,
-        _consumer_name: &str,
+        stream_name: &str,
+        group_name: &str,
+        consumer_name: &str,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
-        // [#172]: Implement function for `MockDb`
-        Err(ProcessTrackerError::ResourceFetchingFailed {
-            resource_name: "consumer_tasks".to_string(),
-        })?
+        crate::consumer::fetch_consumer_tasks(
+            self,
+            &self
+                .get_redis_conn()
+                .map_err(ProcessTrackerError::ERedisError)?
+                .clone(),
+            stream_name,
+            group_name,
+            consumer_name,
+        )
+        .await
     }
 
     async fn consumer_group_create(
         &self,
-        _stream: &str,
-        _group: &str,
-        _id: &RedisEntryId,
+        stream: &str,
+        group: &str,
+        id: &RedisEntryId,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::ConsumerGroupCreateFailed)?
+        self.get_redis_conn()?
+            .consumer_group_create(&stream.into(), group, id)
+            .await
     }
 
     async fn acquire_pt_lock(
         &self,
-        _tag: &str,
-        _lock_key: &str,
-        _lock_val: &str,
-        _ttl: i64,
+        tag: &str,
+        lock_key: &str,
+        lock_val: &str,
+        ttl: i64,
     ) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+        Ok(acquire_lock(&self.get_redis_conn()?, tag, lock_key, lock_val, ttl).await)
     }
 
-    async fn release_pt_lock(&self, _tag: &str, _lock_key: &str) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+    async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> CustomResult<bool, RedisError> {
+        Ok(release_lock(&self.get_redis_conn()?, tag, lock_key).await)
     }
 
     async fn stream_append_entry(
         &self,
-        _stream: &str,
-        _entry_id: &RedisEntryId,
-        _fields: Vec<(&str, String)>,
+        stream: &str,
+        entry_id: &RedisEntryId,
+        fields: Vec<(&str, String)>,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::StreamAppendFailed)?
+        self.get_redis_conn()?
+            .stream_append_entry(&stream.into(), entry_id, fields)
+            .await
     }
 
-    async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
-        Err(RedisError::RedisConnectionError.into())
+    async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
+        self.get_redis_conn()?.get_key::<Vec<u8>>(&key.into()).await
+    }
+}
+
+async fn acquire_lock(
+    conn: &RedisConnectionPool,
+    tag: &str,
+    lock_key: &str,
+    lock_val: &str,
+    ttl: i64,
+) -> bool {
+    let is_lock_acquired = conn
+        .set_key_if_not_exists_with_expiry(&lock_key.into(), lock_val, None)
+        .await;
+    match is_lock_acquired {
+        Ok(SetnxReply::KeySet) => match conn.set_expiry(&lock_key.into(), ttl).await {
+            Ok(()) => true,
+
+            #[allow(unused_must_use)]
+            Err(error) => {
+                logger::error!(?error);
+                conn.delete_key(&lock_key.into()).await;
+                false
+            }
+        },
+        Ok(SetnxReply::KeyNotSet) => {
+            logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
+            false
+        }
+        Err(error) => {
+            logger::error!(?error, %tag, "Error while locking");
+            false
+        }
+    }
+}
+
+async fn release_lock(conn: &RedisConnectionPool, tag: &str, lock_key: &str) -> bool {
+    let is_lock_released = conn.delete_key(&lock_key.into()).await;
+    match is_lock_released {
+        Ok(_del_reply) => true,
+        Err(error) => {
+            logger::error!(?error, %tag, "Error while releasing lock");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::types::keymanager::KeyManagerState;
+    use diesel_models::enums::{ApplicationSource, ProcessTrackerStatus};
+    use redis_interface::RedisSettings;
+
+    use super::*;
+    use crate::{
+        configs::settings::SchedulerSettings, db::process_tracker::ProcessTrackerInterface,
+        flow::SchedulerFlow, producer, utils,
+    };
+
+    async fn create_mock_db() -> MockDb {
+        let redis = RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        };
+        MockDb::new(&redis, KeyManagerState::mock()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_process_runs_through_producer_and_consumer() {
+        let db = create_mock_db().await;
+        let settings = SchedulerSettings {
+            stream: "TEST_PRODUCER_CONSUMER_STREAM".into(),
+            ..Default::default()
+        };
+        let group = &settings.consumer.consumer_group;
+        db.consumer_group_create(&settings.stream, group, &RedisEntryId::AfterLastID)
+            .await
+            .unwrap();
+
+        let schedule_time =
+            common_utils::date_time::now().saturating_sub(time::Duration::minutes(1));
+        db.insert_process(
+            storage::ProcessTrackerNew::new(
+                "pt_producer_consumer",
+                "PAYMENTS_SYNC",
+                storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+                ["PAYMENT"],
+                "tracking_data",
+                None,
+                schedule_time,
+                common_types::consts::API_VERSION,
+                ApplicationSource::Main,
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let tasks = producer::fetch_producer_tasks(&db, &settings)
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        utils::divide_and_append_tasks(
+            &db,
+            SchedulerFlow::Producer,
+            tasks,
+            &settings,
+            ApplicationSource::Main,
+        )
+        .await
+        .unwrap();
+
+        let process = db
+            .find_process_by_id("pt_producer_consumer")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, ProcessTrackerStatus::Processing);
+        assert!(producer::fetch_producer_tasks(&db, &settings)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let tasks = db
+            .fetch_consumer_tasks(&settings.stream, group, "consumer")
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        let process = db
+            .find_process_by_id("pt_producer_consumer")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, ProcessTrackerStatus::ProcessStarted);
+        assert!(db
+            .fetch_consumer_tasks(&settings.stream, group, "consumer")
+            .await
+            .unwrap()
+            .is_empty());
+
+        db.finish_process_with_business_status(process, "COMPLETED_BY_PT")
+            .await
+            .unwrap();
+        let process = db
+            .find_process_by_id("pt_producer_consumer")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, ProcessTrackerStatus::Finish);
+        assert_eq!(process.business_status, "COMPLETED_BY_PT");
+    }
+
+    #[tokio::test]
+    async fn test_producer_lock_is_exclusive() {
+        let db = create_mock_db().await;
+        let lock_key = "TEST_PRODUCER_LOCKING_KEY";
+
+        assert!(db
+            .acquire_pt_lock("PRODUCER", lock_key, "locked", 60)
+            .await
+            .unwrap());
+        assert!(!db
+            .acquire_pt_lock("PRODUCER", lock_key, "locked", 60)
+            .await
+            .unwrap());
+        assert!(db.release_pt_lock("PRODUCER", lock_key).await.unwrap());
+        assert!(db
+            .acquire_pt_lock("PRODUCER", lock_key, "locked", 60)
+            .await
+            .unwrap());
     }
 }
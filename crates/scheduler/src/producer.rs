@@ -66,7 +66,7 @@ where
         match rx.try_recv() {
             Err(mpsc::error::TryRecvError::Empty) => {
                 interval.tick().await;
-                let tenants = state.get_tenants();
+                let tenants = state.get_tenants().await;
                 for tenant in tenants {
                     let session_state = app_state_to_session_state(state, &tenant)?;
                     match run_producer_flow(&session_state, &scheduler_settings).await {
@@ -52,7 +52,8 @@ impl SchedulerInterface for MockDb {}
 
 #[async_trait::async_trait]
 pub trait SchedulerAppState: Send + Sync + Clone {
-    fn get_tenants(&self) -> Vec<id_type::TenantId>;
+    /// Returns the tenants whose tasks are processed, including the tenants provisioned at runtime.
+    async fn get_tenants(&self) -> Vec<id_type::TenantId>;
 }
 #[async_trait::async_trait]
 pub trait SchedulerSessionState: Send + Sync + Clone {
@@ -91,7 +91,7 @@ impl SmithyGenerator {
                 let mut def = String::new();
 
                 if let Some(doc) = documentation {
-                    def.push_str(&format!("/// {}\n", doc));
+                    def.push_str(&self.documentation_to_string(doc, ""));
                 }
 
                 for smithy_trait in traits {
@@ -102,7 +102,7 @@ impl SmithyGenerator {
 
                 for (member_name, member) in members {
                     if let Some(doc) = &member.documentation {
-                        def.push_str(&format!("    /// {}\n", doc));
+                        def.push_str(&self.documentation_to_string(doc, "    "));
                     }
 
                     for smithy_trait in &member.traits {
@@ -124,7 +124,7 @@ impl SmithyGenerator {
                 let mut def = String::new();
 
                 if let Some(doc) = documentation {
-                    def.push_str(&format!("/// {}\n", doc));
+                    def.push_str(&self.documentation_to_string(doc, ""));
                 }
 
                 for smithy_trait in traits {
@@ -135,7 +135,7 @@ impl SmithyGenerator {
 
                 for (member_name, member) in members {
                     if let Some(doc) = &member.documentation {
-                        def.push_str(&format!("    /// {}\n", doc));
+                        def.push_str(&self.documentation_to_string(doc, "    "));
                     }
 
                     for smithy_trait in &member.traits {
@@ -157,7 +157,7 @@ impl SmithyGenerator {
                 let mut def = String::new();
 
                 if let Some(doc) = documentation {
-                    def.push_str(&format!("/// {}\n", doc));
+                    def.push_str(&self.documentation_to_string(doc, ""));
                 }
 
                 for smithy_trait in traits {
@@ -234,9 +234,148 @@ impl SmithyGenerator {
                 def.push('}');
                 def
             }
+            types::SmithyShape::Map { key, value, traits } => {
+                let mut def = String::new();
+
+                for smithy_trait in traits {
+                    def.push_str(&format!("@{}\n", self.trait_to_string(smithy_trait)));
+                }
+
+                def.push_str(&format!("map {} {{\n", name));
+                def.push_str(&format!("    key: {}\n", resolve_target(&key.target)));
+                def.push_str(&format!("    value: {}\n", resolve_target(&value.target)));
+                def.push('}');
+                def
+            }
+            types::SmithyShape::Timestamp { traits } => {
+                self.generate_simple_shape_definition("timestamp", name, traits)
+            }
+            types::SmithyShape::Document { traits } => {
+                self.generate_simple_shape_definition("document", name, traits)
+            }
+            types::SmithyShape::BigDecimal { traits } => {
+                self.generate_simple_shape_definition("bigDecimal", name, traits)
+            }
+            types::SmithyShape::Blob { traits } => {
+                self.generate_simple_shape_definition("blob", name, traits)
+            }
+            types::SmithyShape::Operation {
+                input,
+                output,
+                errors,
+                documentation,
+                traits,
+            } => {
+                let mut def = String::new();
+
+                if let Some(doc) = documentation {
+                    def.push_str(&self.documentation_to_string(doc, ""));
+                }
+
+                for smithy_trait in traits {
+                    def.push_str(&format!("@{}\n", self.trait_to_string(smithy_trait)));
+                }
+
+                def.push_str(&format!("operation {} {{\n", name));
+                def.push_str(&format!("    input: {}\n", resolve_target(input)));
+                def.push_str(&format!("    output: {}\n", resolve_target(output)));
+                if !errors.is_empty() {
+                    let errors = errors
+                        .iter()
+                        .map(|error| resolve_target(error))
+                        .collect::<Vec<_>>();
+                    def.push_str(&format!("    errors: [{}]\n", errors.join(", ")));
+                }
+                def.push('}');
+                def
+            }
+            types::SmithyShape::Resource {
+                identifiers,
+                operations,
+                documentation,
+                traits,
+            } => {
+                let mut def = String::new();
+
+                if let Some(doc) = documentation {
+                    def.push_str(&self.documentation_to_string(doc, ""));
+                }
+
+                for smithy_trait in traits {
+                    def.push_str(&format!("@{}\n", self.trait_to_string(smithy_trait)));
+                }
+
+                def.push_str(&format!("resource {} {{\n", name));
+                if !identifiers.is_empty() {
+                    def.push_str("    identifiers: {\n");
+                    for (identifier, target) in identifiers {
+                        def.push_str(&format!(
+                            "        {}: {}\n",
+                            identifier,
+                            resolve_target(target)
+                        ));
+                    }
+                    def.push_str("    }\n");
+                }
+                def.push_str(&format!("    operations: [{}]\n", operations.join(", ")));
+                def.push('}');
+                def
+            }
+            types::SmithyShape::Service {
+                version,
+                operations,
+                resources,
+                documentation,
+                traits,
+            } => {
+                let mut def = String::new();
+
+                if let Some(doc) = documentation {
+                    def.push_str(&self.documentation_to_string(doc, ""));
+                }
+
+                for smithy_trait in traits {
+                    def.push_str(&format!("@{}\n", self.trait_to_string(smithy_trait)));
+                }
+
+                def.push_str(&format!("service {} {{\n", name));
+                def.push_str(&format!("    version: \"{}\"\n", version));
+                if !operations.is_empty() {
+                    def.push_str(&format!("    operations: [{}]\n", operations.join(", ")));
+                }
+                if !resources.is_empty() {
+                    def.push_str(&format!("    resources: [{}]\n", resources.join(", ")));
+                }
+                def.push('}');
+                def
+            }
         }
     }
 
+    fn generate_simple_shape_definition(
+        &self,
+        shape_type: &str,
+        name: &str,
+        traits: &[types::SmithyTrait],
+    ) -> String {
+        let mut def = String::new();
+
+        for smithy_trait in traits {
+            def.push_str(&format!("@{}\n", self.trait_to_string(smithy_trait)));
+        }
+
+        def.push_str(&format!("{} {}", shape_type, name));
+        def
+    }
+
+    /// Renders a possibly multi-line documentation as doc comments
+    fn documentation_to_string(&self, documentation: &str, indent: &str) -> String {
+        documentation
+            .lines()
+            .map(|line| format!("{}/// {}", indent, line).trim_end().to_string() + "\n")
+            .collect()
+    }
+
     fn resolve_type(
         &self,
         target: &str,
@@ -296,6 +435,24 @@ impl SmithyGenerator {
             types::SmithyTrait::EnumValue { value } => {
                 format!("enumValue(\"{}\")", value)
             }
+            types::SmithyTrait::TimestampFormat { format } => {
+                format!("timestampFormat(\"{}\")", format)
+            }
+            types::SmithyTrait::UniqueItems => "uniqueItems".to_string(),
+            types::SmithyTrait::Http { method, uri, code } => {
+                format!(
+                    "http(method: \"{}\", uri: \"{}\", code: {})",
+                    method, uri, code
+                )
+            }
+            types::SmithyTrait::HttpPayload => "httpPayload".to_string(),
+            types::SmithyTrait::Readonly => "readonly".to_string(),
+            types::SmithyTrait::Idempotent => "idempotent".to_string(),
+            types::SmithyTrait::Title { title } => format!("title(\"{}\")", title),
+            types::SmithyTrait::HttpApiKeyAuth { name, location } => {
+                format!("httpApiKeyAuth(name: \"{}\", in: \"{}\")", name, location)
+            }
+            types::SmithyTrait::RestJson1 => "aws.protocols#restJson1".to_string(),
         }
     }
 }
@@ -305,3 +462,128 @@ impl Default for SmithyGenerator {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SmithyMember, SmithyShape, SmithyTrait};
+
+    fn member(target: &str) -> Box<SmithyMember> {
+        Box::new(SmithyMember {
+            target: target.to_string(),
+            documentation: None,
+            traits: vec![],
+        })
+    }
+
+    fn generate(name: &str, shape: &SmithyShape, shape_to_namespace: &[(&str, &str)]) -> String {
+        let shape_to_namespace = shape_to_namespace
+            .iter()
+            .map(|(shape, namespace)| (shape.to_string(), namespace.to_string()))
+            .collect();
+        SmithyGenerator::new().generate_shape_definition(
+            name,
+            shape,
+            "com.hyperswitch.payment.models",
+            &shape_to_namespace,
+        )
+    }
+
+    #[test]
+    fn test_map_shape() {
+        let shape = SmithyShape::Map {
+            key: member("smithy.api#String"),
+            value: member("Address"),
+            traits: vec![],
+        };
+
+        assert_eq!(
+            generate(
+                "StringAddressMap",
+                &shape,
+                &[("Address", "com.hyperswitch.types")]
+            ),
+            "map StringAddressMap {\n    \
+                key: smithy.api#String\n    \
+                value: com.hyperswitch.types#Address\n\
+            }"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_and_document_shapes() {
+        let timestamp = SmithyShape::Timestamp {
+            traits: vec![SmithyTrait::TimestampFormat {
+                format: "date-time".to_string(),
+            }],
+        };
+        let document = SmithyShape::Document { traits: vec![] };
+
+        assert_eq!(
+            generate("DateTime", &timestamp, &[]),
+            "@timestampFormat(\"date-time\")\ntimestamp DateTime"
+        );
+        assert_eq!(generate("Metadata", &document, &[]), "document Metadata");
+    }
+
+    #[test]
+    fn test_operation_shape() {
+        let shape = SmithyShape::Operation {
+            input: "PaymentsRetrieveRequest".to_string(),
+            output: "PaymentsResponse".to_string(),
+            errors: vec!["ErrorResponse".to_string()],
+            documentation: Some("Retrieves a payment".to_string()),
+            traits: vec![
+                SmithyTrait::Readonly,
+                SmithyTrait::Http {
+                    method: "GET".to_string(),
+                    uri: "/payments/{payment_id}".to_string(),
+                    code: 200,
+                },
+            ],
+        };
+
+        assert_eq!(
+            generate(
+                "RetrievePayment",
+                &shape,
+                &[("ErrorResponse", "com.hyperswitch.errors")]
+            ),
+            "/// Retrieves a payment\n\
+            @readonly\n\
+            @http(method: \"GET\", uri: \"/payments/{payment_id}\", code: 200)\n\
+            operation RetrievePayment {\n    \
+                input: PaymentsRetrieveRequest\n    \
+                output: PaymentsResponse\n    \
+                errors: [com.hyperswitch.errors#ErrorResponse]\n\
+            }"
+        );
+    }
+
+    #[test]
+    fn test_service_shape() {
+        let shape = SmithyShape::Service {
+            version: "2024-01-01".to_string(),
+            operations: vec!["CreatePayment".to_string()],
+            resources: vec![],
+            documentation: None,
+            traits: vec![
+                SmithyTrait::RestJson1,
+                SmithyTrait::HttpApiKeyAuth {
+                    name: "api-key".to_string(),
+                    location: "header".to_string(),
+                },
+            ],
+        };
+
+        assert_eq!(
+            generate("Hyperswitch", &shape, &[]),
+            "@aws.protocols#restJson1\n\
+            @httpApiKeyAuth(name: \"api-key\", in: \"header\")\n\
+            service Hyperswitch {\n    \
+                version: \"2024-01-01\"\n    \
+                operations: [CreatePayment]\n\
+            }"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 // // crates/smithy-core/lib.rs
 
 pub mod generator;
+pub mod routes;
 pub mod types;
 
 pub use generator::SmithyGenerator;
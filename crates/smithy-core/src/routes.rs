@@ -0,0 +1,573 @@
+// crates/smithy-core/routes.rs
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{punctuated::Punctuated, Attribute, Expr, Lit, Meta, Token};
+
+use crate::types::{self as types, SmithyModel};
+
+/// Namespace of the generated operations, resources and service
+pub const SERVICE_NAMESPACE: &str = "com.hyperswitch.smithy.service";
+/// Name of the generated service shape
+pub const SERVICE_NAME: &str = "Hyperswitch";
+
+const DOCUMENT_SHAPE: &str = "smithy.api#Document";
+const STRING_SHAPE: &str = "smithy.api#String";
+
+/// HTTP binding of an API route, as declared by its `#[utoipa::path]` attribute
+#[derive(Debug, Clone)]
+pub struct RouteDefinition {
+    pub function_name: String,
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+    pub path_params: Vec<String>,
+    pub query_params: Vec<(String, String)>,
+    pub tag: Option<String>,
+    pub documentation: Option<String>,
+}
+
+/// Parses the `#[utoipa::path]` annotated functions of the route files in the given directory,
+/// skipping the functions whose `#[cfg]` attributes are not satisfied by the enabled features.
+pub fn parse_routes(
+    routes_dir: &Path,
+    features: &[&str],
+) -> Result<Vec<RouteDefinition>, Box<dyn std::error::Error>> {
+    let mut route_files = fs::read_dir(routes_dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect::<Vec<_>>();
+    // Sort the files so that the generated model does not depend on the directory listing order
+    route_files.sort();
+
+    let mut routes = Vec::new();
+    for route_file in route_files {
+        let content = fs::read_to_string(&route_file)?;
+        let file = syn::parse_file(&content)
+            .map_err(|error| format!("Failed to parse {}: {}", route_file.display(), error))?;
+
+        for item in file.items {
+            let syn::Item::Fn(function) = item else {
+                continue;
+            };
+
+            let is_enabled = function
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"))
+                .all(|attr| {
+                    attr.parse_args::<Meta>()
+                        .is_ok_and(|meta| is_cfg_enabled(&meta, features))
+                });
+            if !is_enabled {
+                continue;
+            }
+
+            let Some(path_attr) = function.attrs.iter().find(|attr| is_utoipa_path(attr)) else {
+                continue;
+            };
+            let Meta::List(path_attr) = &path_attr.meta else {
+                continue;
+            };
+
+            if let Some(route) = parse_route(
+                function.sig.ident.to_string(),
+                path_attr.tokens.clone(),
+                extract_documentation(&function.attrs),
+            ) {
+                routes.push(route);
+            }
+        }
+    }
+
+    Ok(routes)
+}
+
+/// Builds the Smithy service of the parsed routes. The request and response bodies target the
+/// known shapes of the same name, and fall back to documents for the types without a Smithy model.
+pub fn build_service_model(
+    routes: &[RouteDefinition],
+    known_shapes: &HashSet<String>,
+    version: &str,
+) -> SmithyModel {
+    let mut shapes = HashMap::new();
+    let mut operation_names = HashSet::new();
+    let mut untagged_operations = Vec::new();
+    let mut resource_operations: HashMap<String, Vec<String>> = HashMap::new();
+
+    for route in routes {
+        let base_name = to_pascal_case(&route.function_name);
+        let mut operation_name = base_name.clone();
+        let mut counter = 2;
+        while !operation_names.insert(operation_name.clone()) {
+            operation_name = format!("{}{}", base_name, counter);
+            counter += 1;
+        }
+
+        let input_name = format!("{}Input", operation_name);
+        let output_name = format!("{}Output", operation_name);
+
+        let mut input_members = HashMap::new();
+        for label in &route.path_params {
+            input_members.insert(
+                to_member_name(label),
+                types::SmithyMember {
+                    target: STRING_SHAPE.to_string(),
+                    documentation: None,
+                    traits: vec![types::SmithyTrait::Required, types::SmithyTrait::HttpLabel],
+                },
+            );
+        }
+        for (name, value_type) in &route.query_params {
+            input_members.insert(
+                to_member_name(name),
+                types::SmithyMember {
+                    target: resolve_query_target(value_type),
+                    documentation: None,
+                    traits: vec![types::SmithyTrait::HttpQuery { name: name.clone() }],
+                },
+            );
+        }
+        if let Some(request_body) = &route.request_body {
+            input_members.insert(
+                "body".to_string(),
+                types::SmithyMember {
+                    target: resolve_payload_target(request_body, known_shapes, &mut shapes),
+                    documentation: None,
+                    traits: vec![types::SmithyTrait::HttpPayload],
+                },
+            );
+        }
+
+        let mut output_members = HashMap::new();
+        if let Some(response_body) = &route.response_body {
+            output_members.insert(
+                "body".to_string(),
+                types::SmithyMember {
+                    target: resolve_payload_target(response_body, known_shapes, &mut shapes),
+                    documentation: None,
+                    traits: vec![types::SmithyTrait::HttpPayload],
+                },
+            );
+        }
+
+        shapes.insert(
+            input_name.clone(),
+            types::SmithyShape::Structure {
+                members: input_members,
+                documentation: None,
+                traits: vec![],
+            },
+        );
+        shapes.insert(
+            output_name.clone(),
+            types::SmithyShape::Structure {
+                members: output_members,
+                documentation: None,
+                traits: vec![],
+            },
+        );
+
+        let method = route.method.to_uppercase();
+        let mut traits = vec![types::SmithyTrait::Http {
+            method: method.clone(),
+            uri: route.path.clone(),
+            code: route.status,
+        }];
+        match method.as_str() {
+            "GET" | "HEAD" => traits.push(types::SmithyTrait::Readonly),
+            "PUT" | "DELETE" => traits.push(types::SmithyTrait::Idempotent),
+            _ => {}
+        }
+
+        shapes.insert(
+            operation_name.clone(),
+            types::SmithyShape::Operation {
+                input: input_name,
+                output: output_name,
+                errors: vec![],
+                documentation: route.documentation.clone(),
+                traits,
+            },
+        );
+
+        match &route.tag {
+            Some(tag) => resource_operations
+                .entry(to_resource_name(tag))
+                .or_default()
+                .push(operation_name),
+            None => untagged_operations.push(operation_name),
+        }
+    }
+
+    let mut resources = resource_operations.keys().cloned().collect::<Vec<_>>();
+    resources.sort();
+    for (resource_name, operations) in resource_operations {
+        shapes.insert(
+            resource_name,
+            types::SmithyShape::Resource {
+                identifiers: HashMap::new(),
+                operations,
+                documentation: None,
+                traits: vec![],
+            },
+        );
+    }
+
+    shapes.insert(
+        SERVICE_NAME.to_string(),
+        types::SmithyShape::Service {
+            version: version.to_string(),
+            operations: untagged_operations,
+            resources,
+            documentation: Some("Hyperswitch payments API".to_string()),
+            traits: vec![
+                types::SmithyTrait::RestJson1,
+                types::SmithyTrait::HttpApiKeyAuth {
+                    name: "api-key".to_string(),
+                    location: "header".to_string(),
+                },
+                types::SmithyTrait::Title {
+                    title: "Hyperswitch".to_string(),
+                },
+            ],
+        },
+    );
+
+    SmithyModel {
+        namespace: SERVICE_NAMESPACE.to_string(),
+        shapes,
+    }
+}
+
+fn is_utoipa_path(attr: &Attribute) -> bool {
+    let segments = attr
+        .path()
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    segments == ["utoipa", "path"]
+}
+
+/// Evaluates a `#[cfg]` predicate, only the `feature` options are considered to be set.
+fn is_cfg_enabled(meta: &Meta, features: &[&str]) -> bool {
+    match meta {
+        Meta::NameValue(name_value) if name_value.path.is_ident("feature") => {
+            match &name_value.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(feature) => features.contains(&feature.value().as_str()),
+                    _ => false,
+                },
+                _ => false,
+            }
+        }
+        Meta::List(list) => {
+            let Ok(nested) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            else {
+                return false;
+            };
+
+            if list.path.is_ident("all") {
+                nested.iter().all(|meta| is_cfg_enabled(meta, features))
+            } else if list.path.is_ident("any") {
+                nested.iter().any(|meta| is_cfg_enabled(meta, features))
+            } else if list.path.is_ident("not") {
+                !nested.iter().any(|meta| is_cfg_enabled(meta, features))
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
+
+fn extract_documentation(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(line) => Some(line.value()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+
+    let documentation = lines.join("\n").trim().to_string();
+    (!documentation.is_empty()).then_some(documentation)
+}
+
+fn parse_route(
+    function_name: String,
+    tokens: TokenStream,
+    documentation: Option<String>,
+) -> Option<RouteDefinition> {
+    let mut route = RouteDefinition {
+        function_name,
+        method: String::new(),
+        path: String::new(),
+        status: 200,
+        request_body: None,
+        response_body: None,
+        path_params: Vec::new(),
+        query_params: Vec::new(),
+        tag: None,
+        documentation,
+    };
+
+    for item in split_items(tokens) {
+        let Some(TokenTree::Ident(key)) = item.first() else {
+            continue;
+        };
+        let key = key.to_string();
+
+        match (key.as_str(), item.get(1)) {
+            ("get" | "post" | "put" | "delete" | "patch" | "head", None) => route.method = key,
+            ("path", Some(_)) => route.path = parse_string_value(&item)?,
+            ("tag", Some(_)) => route.tag = parse_string_value(&item),
+            ("request_body", Some(TokenTree::Punct(_))) => {
+                route.request_body = Some(tokens_to_type(item.get(2..).unwrap_or_default()));
+            }
+            ("request_body", Some(TokenTree::Group(group))) => {
+                route.request_body = split_items(group.stream())
+                    .iter()
+                    .find(|item| is_key(item, "content"))
+                    .map(|item| tokens_to_type(item.get(2..).unwrap_or_default()));
+            }
+            ("responses", Some(TokenTree::Group(group))) => {
+                if let Some((status, body)) = parse_success_response(group.stream()) {
+                    route.status = status;
+                    route.response_body = body;
+                }
+            }
+            ("params", Some(TokenTree::Group(group))) => {
+                for param in split_items(group.stream()) {
+                    let Some(TokenTree::Group(param)) = param.first() else {
+                        continue;
+                    };
+                    let param = split_items(param.stream());
+                    let (Some(name_and_type), Some(location)) = (param.first(), param.get(1))
+                    else {
+                        continue;
+                    };
+                    let Some(name) = name_and_type
+                        .first()
+                        .and_then(|name| syn::parse2::<syn::LitStr>(name.clone().into()).ok())
+                        .map(|name| name.value())
+                    else {
+                        continue;
+                    };
+                    let value_type = tokens_to_type(name_and_type.get(2..).unwrap_or_default());
+
+                    if matches!(location.first(), Some(TokenTree::Ident(location)) if location == "Query")
+                    {
+                        route.query_params.push((name, value_type));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if route.method.is_empty() || route.path.is_empty() {
+        return None;
+    }
+
+    // The path parameters are bound to the labels of the URI, whether declared or not
+    route.path_params = extract_path_labels(&route.path);
+
+    Some(route)
+}
+
+/// Returns the status and body of the first successful response
+fn parse_success_response(tokens: TokenStream) -> Option<(u16, Option<String>)> {
+    split_items(tokens).into_iter().find_map(|response| {
+        let Some(TokenTree::Group(response)) = response.first() else {
+            return None;
+        };
+        let fields = split_items(response.stream());
+
+        let status = fields
+            .iter()
+            .find(|field| is_key(field, "status"))
+            .and_then(|field| field.get(2))
+            .and_then(|status| syn::parse2::<syn::LitInt>(status.clone().into()).ok())
+            .and_then(|status| status.base10_parse::<u16>().ok())?;
+        if !(200..300).contains(&status) {
+            return None;
+        }
+
+        let body = fields
+            .iter()
+            .find(|field| is_key(field, "body"))
+            .map(|field| tokens_to_type(field.get(2..).unwrap_or_default()));
+
+        Some((status, body))
+    })
+}
+
+/// Splits the tokens at their top-level commas
+fn split_items(tokens: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut items = Vec::new();
+    let mut current = Vec::new();
+    let mut angle_depth = 0usize;
+
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == '<' => angle_depth += 1,
+            TokenTree::Punct(punct) if punct.as_char() == '>' => {
+                angle_depth = angle_depth.saturating_sub(1)
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ',' && angle_depth == 0 => {
+                if !current.is_empty() {
+                    items.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        current.push(token);
+    }
+
+    if !current.is_empty() {
+        items.push(current);
+    }
+    items
+}
+
+fn is_key(item: &[TokenTree], key: &str) -> bool {
+    matches!(item.first(), Some(TokenTree::Ident(ident)) if ident == key)
+}
+
+fn parse_string_value(item: &[TokenTree]) -> Option<String> {
+    let value = item.get(2)?;
+    syn::parse2::<syn::LitStr>(value.clone().into())
+        .ok()
+        .map(|value| value.value())
+}
+
+fn tokens_to_type(tokens: &[TokenTree]) -> String {
+    tokens
+        .iter()
+        .cloned()
+        .collect::<TokenStream>()
+        .to_string()
+        .replace(' ', "")
+}
+
+fn extract_path_labels(path: &str) -> Vec<String> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split_once('}'))
+        .map(|(label, _)| label.to_string())
+        .collect()
+}
+
+/// Resolves a request or response body to a known shape, a list of a known shape, or a document
+fn resolve_payload_target(
+    body_type: &str,
+    known_shapes: &HashSet<String>,
+    shapes: &mut HashMap<String, types::SmithyShape>,
+) -> String {
+    if let Some(inner_type) = body_type
+        .strip_prefix("Vec<")
+        .and_then(|inner_type| inner_type.strip_suffix('>'))
+    {
+        let inner_target = resolve_payload_target(inner_type, known_shapes, shapes);
+        if inner_target == DOCUMENT_SHAPE {
+            return DOCUMENT_SHAPE.to_string();
+        }
+
+        let list_name = format!("{}List", inner_target);
+        shapes
+            .entry(list_name.clone())
+            .or_insert_with(|| types::SmithyShape::List {
+                member: Box::new(types::SmithyMember {
+                    target: inner_target,
+                    documentation: None,
+                    traits: vec![],
+                }),
+                traits: vec![],
+            });
+        return list_name;
+    }
+
+    let shape_name = body_type.split("::").last().unwrap_or(body_type);
+    if known_shapes.contains(shape_name) {
+        shape_name.to_string()
+    } else {
+        DOCUMENT_SHAPE.to_string()
+    }
+}
+
+/// Resolves a query parameter to a prelude shape, the other types are bound as strings
+fn resolve_query_target(value_type: &str) -> String {
+    let value_type = value_type
+        .strip_prefix("Option<")
+        .and_then(|inner_type| inner_type.strip_suffix('>'))
+        .unwrap_or(value_type);
+
+    match value_type {
+        "i8" | "i16" | "i32" | "u8" | "u16" | "u32" => "smithy.api#Integer".to_string(),
+        "i64" | "u64" | "usize" | "isize" => "smithy.api#Long".to_string(),
+        "f32" => "smithy.api#Float".to_string(),
+        "f64" => "smithy.api#Double".to_string(),
+        "bool" => "smithy.api#Boolean".to_string(),
+        _ => STRING_SHAPE.to_string(),
+    }
+}
+
+fn to_pascal_case(value: &str) -> String {
+    value
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut characters = word.chars();
+            characters
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + characters.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Smithy identifiers cannot start with a digit, as some tags do
+fn to_resource_name(tag: &str) -> String {
+    let name = to_pascal_case(tag);
+    if name.starts_with(|character: char| character.is_ascii_digit()) {
+        format!("Tag{}Resource", name)
+    } else {
+        format!("{}Resource", name)
+    }
+}
+
+fn to_member_name(value: &str) -> String {
+    value
+        .chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() {
+                character
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
@@ -63,6 +63,66 @@ pub enum SmithyShape {
         #[serde(skip_serializing_if = "Vec::is_empty")]
         traits: Vec<SmithyTrait>,
     },
+    #[serde(rename = "map")]
+    Map {
+        key: Box<SmithyMember>,
+        value: Box<SmithyMember>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        traits: Vec<SmithyTrait>,
+    },
+    #[serde(rename = "timestamp")]
+    Timestamp {
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        traits: Vec<SmithyTrait>,
+    },
+    #[serde(rename = "document")]
+    Document {
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        traits: Vec<SmithyTrait>,
+    },
+    #[serde(rename = "bigDecimal")]
+    BigDecimal {
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        traits: Vec<SmithyTrait>,
+    },
+    #[serde(rename = "blob")]
+    Blob {
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        traits: Vec<SmithyTrait>,
+    },
+    #[serde(rename = "operation")]
+    Operation {
+        input: String,
+        output: String,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        errors: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        documentation: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        traits: Vec<SmithyTrait>,
+    },
+    #[serde(rename = "resource")]
+    Resource {
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        identifiers: HashMap<String, String>,
+        operations: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        documentation: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        traits: Vec<SmithyTrait>,
+    },
+    #[serde(rename = "service")]
+    Service {
+        version: String,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        operations: Vec<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        resources: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        documentation: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        traits: Vec<SmithyTrait>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,6 +167,28 @@ pub enum SmithyTrait {
     JsonName { name: String },
     #[serde(rename = "smithy.api#enumValue")]
     EnumValue { value: String },
+    #[serde(rename = "smithy.api#timestampFormat")]
+    TimestampFormat { format: String },
+    #[serde(rename = "smithy.api#uniqueItems")]
+    UniqueItems,
+    #[serde(rename = "smithy.api#http")]
+    Http {
+        method: String,
+        uri: String,
+        code: u16,
+    },
+    #[serde(rename = "smithy.api#httpPayload")]
+    HttpPayload,
+    #[serde(rename = "smithy.api#readonly")]
+    Readonly,
+    #[serde(rename = "smithy.api#idempotent")]
+    Idempotent,
+    #[serde(rename = "smithy.api#title")]
+    Title { title: String },
+    #[serde(rename = "smithy.api#httpApiKeyAuth")]
+    HttpApiKeyAuth { name: String, location: String },
+    #[serde(rename = "aws.protocols#restJson1")]
+    RestJson1,
 }
 
 #[derive(Debug, Clone)]
@@ -145,6 +227,11 @@ pub trait SmithyModelGenerator {
     fn generate_smithy_model() -> SmithyModel;
 }
 
+/// Timestamp shape of the `PrimitiveDateTime` fields, serialized as ISO 8601 strings
+pub const DATE_TIME_SHAPE: &str = "DateTime";
+/// Long shape of the amounts expressed in the lowest denomination of the currency
+pub const MINOR_UNIT_SHAPE: &str = "MinorUnit";
+
 // Helper functions moved from the proc-macro crate to be accessible by it.
 
 pub fn resolve_type_and_generate_shapes(
@@ -162,8 +249,39 @@ pub fn resolve_type_and_generate_shapes(
         "f32" => "smithy.api#Float".to_string(),
         "f64" => "smithy.api#Double".to_string(),
         "bool" => "smithy.api#Boolean".to_string(),
-        "PrimitiveDateTime" | "time::PrimitiveDateTime" => "smithy.api#Timestamp".to_string(),
-        "Amount" | "MinorUnit" => "smithy.api#Long".to_string(),
+        "PrimitiveDateTime" | "time::PrimitiveDateTime" => {
+            // The timestamps are serialized as ISO 8601 strings rather than epoch seconds
+            if !shapes.contains_key(DATE_TIME_SHAPE) {
+                generated_shapes.insert(
+                    DATE_TIME_SHAPE.to_string(),
+                    SmithyShape::Timestamp {
+                        traits: vec![SmithyTrait::TimestampFormat {
+                            format: "date-time".to_string(),
+                        }],
+                    },
+                );
+            }
+            DATE_TIME_SHAPE.to_string()
+        }
+        "MinorUnit" | "common_utils::types::MinorUnit" => {
+            if !shapes.contains_key(MINOR_UNIT_SHAPE) {
+                generated_shapes.insert(
+                    MINOR_UNIT_SHAPE.to_string(),
+                    SmithyShape::Long {
+                        traits: vec![SmithyTrait::Documentation {
+                            documentation: "Amount in the lowest denomination of the currency"
+                                .to_string(),
+                        }],
+                    },
+                );
+            }
+            MINOR_UNIT_SHAPE.to_string()
+        }
+        "Amount" => "smithy.api#Long".to_string(),
+        "StringMajorUnit" => "smithy.api#String".to_string(),
+        "FloatMajorUnit" => "smithy.api#Double".to_string(),
+        "Decimal" | "rust_decimal::Decimal" | "BigDecimal" => "smithy.api#BigDecimal".to_string(),
+        "Vec<u8>" | "Bytes" | "bytes::Bytes" => "smithy.api#Blob".to_string(),
         "serde_json::Value" | "Value" | "Object" => "smithy.api#Document".to_string(),
         "Url" | "url::Url" => "smithy.api#String".to_string(),
 
@@ -182,16 +300,7 @@ pub fn resolve_type_and_generate_shapes(
                 resolve_type_and_generate_shapes(inner_type, shapes)?;
             generated_shapes.extend(new_shapes);
 
-            let list_shape_name = format!(
-                "{}List",
-                inner_smithy_type
-                    .split("::")
-                    .last()
-                    .unwrap_or(&inner_smithy_type)
-                    .split('#')
-                    .next_back()
-                    .unwrap_or(&inner_smithy_type)
-            );
+            let list_shape_name = format!("{}List", get_shape_name_suffix(&inner_smithy_type));
             if !shapes.contains_key(&list_shape_name)
                 && !generated_shapes.contains_key(&list_shape_name)
             {
@@ -208,6 +317,35 @@ pub fn resolve_type_and_generate_shapes(
             list_shape_name
         }
 
+        vt if (vt.starts_with("HashSet<") || vt.starts_with("BTreeSet<")) && vt.ends_with('>') => {
+            let wrapper = if vt.starts_with("HashSet<") {
+                "HashSet"
+            } else {
+                "BTreeSet"
+            };
+            let inner_type = extract_generic_inner_type(vt, wrapper)
+                .map_err(|e| syn::Error::new(value_type_span, e))?;
+            let (inner_smithy_type, new_shapes) =
+                resolve_type_and_generate_shapes(inner_type, shapes)?;
+            generated_shapes.extend(new_shapes);
+
+            let set_shape_name = format!("{}Set", get_shape_name_suffix(&inner_smithy_type));
+            if !shapes.contains_key(&set_shape_name)
+                && !generated_shapes.contains_key(&set_shape_name)
+            {
+                let set_shape = SmithyShape::List {
+                    member: Box::new(SmithyMember {
+                        target: inner_smithy_type,
+                        documentation: None,
+                        traits: vec![],
+                    }),
+                    traits: vec![SmithyTrait::UniqueItems],
+                };
+                generated_shapes.insert(set_shape_name.clone(), set_shape);
+            }
+            set_shape_name
+        }
+
         vt if vt.starts_with("Box<") && vt.ends_with('>') => {
             let inner_type = extract_generic_inner_type(vt, "Box")
                 .map_err(|e| syn::Error::new(value_type_span, e))?;
@@ -224,8 +362,13 @@ pub fn resolve_type_and_generate_shapes(
             resolved_type
         }
 
-        vt if vt.starts_with("HashMap<") && vt.ends_with('>') => {
-            let inner_types = extract_generic_inner_type(vt, "HashMap")
+        vt if (vt.starts_with("HashMap<") || vt.starts_with("BTreeMap<")) && vt.ends_with('>') => {
+            let wrapper = if vt.starts_with("HashMap<") {
+                "HashMap"
+            } else {
+                "BTreeMap"
+            };
+            let inner_types = extract_generic_inner_type(vt, wrapper)
                 .map_err(|e| syn::Error::new(value_type_span, e))?;
             let (key_type, value_type) =
                 parse_map_types(inner_types).map_err(|e| syn::Error::new(value_type_span, e))?;
@@ -234,26 +377,41 @@ pub fn resolve_type_and_generate_shapes(
             let (value_smithy_type, value_shapes) =
                 resolve_type_and_generate_shapes(value_type, shapes)?;
             generated_shapes.extend(value_shapes);
-            format!(
-                "smithy.api#Map<key: {}, value: {}>",
-                key_smithy_type, value_smithy_type
-            )
-        }
 
-        vt if vt.starts_with("BTreeMap<") && vt.ends_with('>') => {
-            let inner_types = extract_generic_inner_type(vt, "BTreeMap")
-                .map_err(|e| syn::Error::new(value_type_span, e))?;
-            let (key_type, value_type) =
-                parse_map_types(inner_types).map_err(|e| syn::Error::new(value_type_span, e))?;
-            let (key_smithy_type, key_shapes) = resolve_type_and_generate_shapes(key_type, shapes)?;
-            generated_shapes.extend(key_shapes);
-            let (value_smithy_type, value_shapes) =
-                resolve_type_and_generate_shapes(value_type, shapes)?;
-            generated_shapes.extend(value_shapes);
-            format!(
-                "smithy.api#Map<key: {}, value: {}>",
-                key_smithy_type, value_smithy_type
-            )
+            // Smithy map keys must target string shapes, numeric keys are serialized as strings
+            // in JSON anyway
+            let key_smithy_type = if key_smithy_type.starts_with("smithy.api#")
+                && key_smithy_type != "smithy.api#String"
+            {
+                "smithy.api#String".to_string()
+            } else {
+                key_smithy_type
+            };
+
+            let map_shape_name = format!(
+                "{}{}Map",
+                get_shape_name_suffix(&key_smithy_type),
+                get_shape_name_suffix(&value_smithy_type)
+            );
+            if !shapes.contains_key(&map_shape_name)
+                && !generated_shapes.contains_key(&map_shape_name)
+            {
+                let map_shape = SmithyShape::Map {
+                    key: Box::new(SmithyMember {
+                        target: key_smithy_type,
+                        documentation: None,
+                        traits: vec![],
+                    }),
+                    value: Box::new(SmithyMember {
+                        target: value_smithy_type,
+                        documentation: None,
+                        traits: vec![],
+                    }),
+                    traits: vec![],
+                };
+                generated_shapes.insert(map_shape_name.clone(), map_shape);
+            }
+            map_shape_name
         }
 
         _ => {
@@ -268,6 +426,12 @@ pub fn resolve_type_and_generate_shapes(
     Ok((target_type, generated_shapes))
 }
 
+/// Returns the unqualified name of a shape, used to name the collections of the shape
+fn get_shape_name_suffix(smithy_type: &str) -> &str {
+    let name = smithy_type.split("::").last().unwrap_or(smithy_type);
+    name.split('#').next_back().unwrap_or(name)
+}
+
 fn extract_generic_inner_type<'a>(full_type: &'a str, wrapper: &str) -> Result<&'a str, String> {
     let expected_start = format!("{}<", wrapper);
 
@@ -339,3 +503,54 @@ fn parse_map_types(inner_types: &str) -> Result<(&str, &str), String> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_map_type() -> Result<(), syn::Error> {
+        let (target, shapes) =
+            resolve_type_and_generate_shapes("HashMap<u32, Vec<String>>", &mut HashMap::new())?;
+
+        assert_eq!(target, "StringStringListMap");
+        assert!(matches!(
+            shapes.get("StringStringListMap"),
+            Some(SmithyShape::Map { key, value, .. })
+                if key.target == "smithy.api#String" && value.target == "StringList"
+        ));
+        assert!(matches!(
+            shapes.get("StringList"),
+            Some(SmithyShape::List { member, .. }) if member.target == "smithy.api#String"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_timestamp_and_document_types() -> Result<(), syn::Error> {
+        let mut shapes = HashMap::new();
+        let (target, generated_shapes) =
+            resolve_type_and_generate_shapes("Option<PrimitiveDateTime>", &mut shapes)?;
+
+        assert_eq!(target, DATE_TIME_SHAPE);
+        assert!(matches!(
+            generated_shapes.get(DATE_TIME_SHAPE),
+            Some(SmithyShape::Timestamp { traits }) if matches!(
+                traits.as_slice(),
+                [SmithyTrait::TimestampFormat { format }] if format == "date-time"
+            )
+        ));
+
+        // The timestamp shape is only generated once per model
+        shapes.extend(generated_shapes);
+        let (_, generated_shapes) =
+            resolve_type_and_generate_shapes("PrimitiveDateTime", &mut shapes)?;
+        assert!(generated_shapes.is_empty());
+
+        let (target, generated_shapes) =
+            resolve_type_and_generate_shapes("Secret<serde_json::Value>", &mut shapes)?;
+        assert_eq!(target, "smithy.api#Document");
+        assert!(generated_shapes.is_empty());
+        Ok(())
+    }
+}
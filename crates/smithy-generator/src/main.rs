@@ -1,9 +1,9 @@
 // crates/smithy-generator/main.rs
 
-use std::path::Path;
+use std::{collections::HashSet, path::Path};
 
 use router_env::logger;
-use smithy_core::SmithyGenerator;
+use smithy_core::{routes, SmithyGenerator};
 
 // Include the auto-generated model registry
 include!(concat!(env!("OUT_DIR"), "/model_registry.rs"));
@@ -25,13 +25,29 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
+    let mut known_shapes = HashSet::new();
     for model in models {
         logger::info!("  Processing namespace: {}", model.namespace);
         let shape_names: Vec<_> = model.shapes.keys().collect();
         logger::info!("    Shapes: {:?}", shape_names);
+        known_shapes.extend(model.shapes.keys().cloned());
         generator.add_model(model);
     }
 
+    // Derive the operations and the service from the HTTP bindings of the OpenAPI routes
+    let (features, version) = if cfg!(feature = "v2") {
+        (["v2"], "v2")
+    } else {
+        (["v1"], "v1")
+    };
+    let api_routes = routes::parse_routes(Path::new("crates/openapi/src/routes"), &features)?;
+    logger::info!("Found {} API routes", api_routes.len());
+    generator.add_model(routes::build_service_model(
+        &api_routes,
+        &known_shapes,
+        version,
+    ));
+
     logger::info!("Generating Smithy IDL files...");
 
     // Generate IDL files
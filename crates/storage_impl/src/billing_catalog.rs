@@ -0,0 +1,901 @@
+use common_utils::id_type;
+use diesel_models::billing_catalog as storage;
+use error_stack::report;
+use hyperswitch_domain_models::billing_catalog::BillingCatalogInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    connection, errors::StorageError, kv_router_store::KVRouterStore, CustomResult, DatabaseStore,
+    MockDb, RouterStore,
+};
+
+#[async_trait::async_trait]
+impl<T: DatabaseStore> BillingCatalogInterface for RouterStore<T> {
+    type Error = StorageError;
+
+    #[instrument(skip_all)]
+    async fn insert_billing_product(
+        &self,
+        product: storage::BillingProductNew,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        product
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingProduct::find_by_merchant_id_id(&conn, merchant_id, product_id)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_billing_products_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingProduct>, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingProduct::list_by_merchant_id_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+        product_update: storage::BillingProductUpdate,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BillingProduct::update_by_merchant_id_id(
+            &conn,
+            merchant_id,
+            product_id,
+            product_update,
+        )
+        .await
+        .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_billing_plan(
+        &self,
+        plan: storage::BillingPlanNew,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        plan.insert(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingPlan::find_by_merchant_id_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_billing_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingPlan>, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingPlan::list_by_merchant_id_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+        plan_update: storage::BillingPlanUpdate,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BillingPlan::update_by_merchant_id_id(&conn, merchant_id, plan_id, plan_update)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_billing_price(
+        &self,
+        price: storage::BillingPriceNew,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        price
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingPrice::find_by_merchant_id_id(&conn, merchant_id, price_id)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_billing_prices_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<Vec<storage::BillingPrice>, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingPrice::list_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+        price_update: storage::BillingPriceUpdate,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BillingPrice::update_by_merchant_id_id(&conn, merchant_id, price_id, price_update)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_billing_coupon(
+        &self,
+        coupon: storage::BillingCouponNew,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        coupon
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingCoupon::find_by_merchant_id_id(&conn, merchant_id, coupon_id)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_coupon_by_merchant_id_profile_id_code(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        code: &str,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingCoupon::find_by_merchant_id_profile_id_code(
+            &conn,
+            merchant_id,
+            profile_id,
+            code,
+        )
+        .await
+        .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_billing_coupons_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingCoupon>, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BillingCoupon::list_by_merchant_id_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+        coupon_update: storage::BillingCouponUpdate,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BillingCoupon::update_by_merchant_id_id(
+            &conn,
+            merchant_id,
+            coupon_id,
+            coupon_update,
+        )
+        .await
+        .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn redeem_billing_coupon(
+        &self,
+        coupon: storage::BillingCoupon,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        coupon
+            .redeem(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: DatabaseStore> BillingCatalogInterface for KVRouterStore<T> {
+    type Error = StorageError;
+
+    #[instrument(skip_all)]
+    async fn insert_billing_product(
+        &self,
+        product: storage::BillingProductNew,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        self.router_store.insert_billing_product(product).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        self.router_store
+            .find_billing_product_by_merchant_id_id(merchant_id, product_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_billing_products_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingProduct>, StorageError> {
+        self.router_store
+            .list_billing_products_by_merchant_id_profile_id(merchant_id, profile_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+        product_update: storage::BillingProductUpdate,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        self.router_store
+            .update_billing_product_by_merchant_id_id(merchant_id, product_id, product_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_billing_plan(
+        &self,
+        plan: storage::BillingPlanNew,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        self.router_store.insert_billing_plan(plan).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        self.router_store
+            .find_billing_plan_by_merchant_id_id(merchant_id, plan_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_billing_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingPlan>, StorageError> {
+        self.router_store
+            .list_billing_plans_by_merchant_id_profile_id(merchant_id, profile_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+        plan_update: storage::BillingPlanUpdate,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        self.router_store
+            .update_billing_plan_by_merchant_id_id(merchant_id, plan_id, plan_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_billing_price(
+        &self,
+        price: storage::BillingPriceNew,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        self.router_store.insert_billing_price(price).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        self.router_store
+            .find_billing_price_by_merchant_id_id(merchant_id, price_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_billing_prices_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<Vec<storage::BillingPrice>, StorageError> {
+        self.router_store
+            .list_billing_prices_by_merchant_id_plan_id(merchant_id, plan_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+        price_update: storage::BillingPriceUpdate,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        self.router_store
+            .update_billing_price_by_merchant_id_id(merchant_id, price_id, price_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_billing_coupon(
+        &self,
+        coupon: storage::BillingCouponNew,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        self.router_store.insert_billing_coupon(coupon).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        self.router_store
+            .find_billing_coupon_by_merchant_id_id(merchant_id, coupon_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_billing_coupon_by_merchant_id_profile_id_code(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        code: &str,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        self.router_store
+            .find_billing_coupon_by_merchant_id_profile_id_code(merchant_id, profile_id, code)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_billing_coupons_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingCoupon>, StorageError> {
+        self.router_store
+            .list_billing_coupons_by_merchant_id_profile_id(merchant_id, profile_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+        coupon_update: storage::BillingCouponUpdate,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        self.router_store
+            .update_billing_coupon_by_merchant_id_id(merchant_id, coupon_id, coupon_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn redeem_billing_coupon(
+        &self,
+        coupon: storage::BillingCoupon,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        self.router_store.redeem_billing_coupon(coupon).await
+    }
+}
+
+fn paginate<R>(resources: Vec<R>, limit: Option<i64>, offset: Option<i64>) -> Vec<R> {
+    resources
+        .into_iter()
+        .skip(
+            offset
+                .and_then(|offset| usize::try_from(offset).ok())
+                .unwrap_or(0),
+        )
+        .take(
+            limit
+                .and_then(|limit| usize::try_from(limit).ok())
+                .unwrap_or(usize::MAX),
+        )
+        .collect()
+}
+
+#[async_trait::async_trait]
+impl BillingCatalogInterface for MockDb {
+    type Error = StorageError;
+
+    async fn insert_billing_product(
+        &self,
+        product: storage::BillingProductNew,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        let mut products = self.billing_products.lock().await;
+        if products.iter().any(|existing| existing.id == product.id) {
+            Err(StorageError::DuplicateValue {
+                entity: "billing_product",
+                key: Some(product.id.clone()),
+            })?
+        }
+        let product = storage::BillingProduct {
+            id: product.id,
+            merchant_id: product.merchant_id,
+            profile_id: product.profile_id,
+            name: product.name,
+            description: product.description,
+            is_active: product.is_active,
+            metadata: product.metadata,
+            created_at: product.created_at,
+            modified_at: product.modified_at,
+        };
+        products.push(product.clone());
+        Ok(product)
+    }
+
+    async fn find_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        self.billing_products
+            .lock()
+            .await
+            .iter()
+            .find(|product| product.merchant_id == *merchant_id && product.id == product_id)
+            .cloned()
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!(
+                    "No billing product found for id = {product_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn list_billing_products_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingProduct>, StorageError> {
+        let mut products: Vec<_> = self
+            .billing_products
+            .lock()
+            .await
+            .iter()
+            .filter(|product| {
+                product.merchant_id == *merchant_id && product.profile_id == *profile_id
+            })
+            .cloned()
+            .collect();
+        products.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+        Ok(paginate(products, limit, offset))
+    }
+
+    async fn update_billing_product_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        product_id: &str,
+        product_update: storage::BillingProductUpdate,
+    ) -> CustomResult<storage::BillingProduct, StorageError> {
+        self.billing_products
+            .lock()
+            .await
+            .iter_mut()
+            .find(|product| product.merchant_id == *merchant_id && product.id == product_id)
+            .map(|product| {
+                *product = product_update.apply_changeset(product.clone());
+                product.clone()
+            })
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!(
+                    "No billing product found for id = {product_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn insert_billing_plan(
+        &self,
+        plan: storage::BillingPlanNew,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        let mut plans = self.billing_plans.lock().await;
+        if plans.iter().any(|existing| existing.id == plan.id) {
+            Err(StorageError::DuplicateValue {
+                entity: "billing_plan",
+                key: Some(plan.id.clone()),
+            })?
+        }
+        let plan = storage::BillingPlan {
+            id: plan.id,
+            merchant_id: plan.merchant_id,
+            profile_id: plan.profile_id,
+            product_id: plan.product_id,
+            name: plan.name,
+            description: plan.description,
+            trial_period_days: plan.trial_period_days,
+            is_active: plan.is_active,
+            metadata: plan.metadata,
+            created_at: plan.created_at,
+            modified_at: plan.modified_at,
+        };
+        plans.push(plan.clone());
+        Ok(plan)
+    }
+
+    async fn find_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        self.billing_plans
+            .lock()
+            .await
+            .iter()
+            .find(|plan| plan.merchant_id == *merchant_id && plan.id == plan_id)
+            .cloned()
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!("No billing plan found for id = {plan_id}"))
+                    .into()
+            })
+    }
+
+    async fn list_billing_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingPlan>, StorageError> {
+        let mut plans: Vec<_> = self
+            .billing_plans
+            .lock()
+            .await
+            .iter()
+            .filter(|plan| plan.merchant_id == *merchant_id && plan.profile_id == *profile_id)
+            .cloned()
+            .collect();
+        plans.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+        Ok(paginate(plans, limit, offset))
+    }
+
+    async fn update_billing_plan_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+        plan_update: storage::BillingPlanUpdate,
+    ) -> CustomResult<storage::BillingPlan, StorageError> {
+        self.billing_plans
+            .lock()
+            .await
+            .iter_mut()
+            .find(|plan| plan.merchant_id == *merchant_id && plan.id == plan_id)
+            .map(|plan| {
+                *plan = plan_update.apply_changeset(plan.clone());
+                plan.clone()
+            })
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!("No billing plan found for id = {plan_id}"))
+                    .into()
+            })
+    }
+
+    async fn insert_billing_price(
+        &self,
+        price: storage::BillingPriceNew,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        let mut prices = self.billing_prices.lock().await;
+        if prices.iter().any(|existing| existing.id == price.id) {
+            Err(StorageError::DuplicateValue {
+                entity: "billing_price",
+                key: Some(price.id.clone()),
+            })?
+        }
+        let price = storage::BillingPrice {
+            id: price.id,
+            merchant_id: price.merchant_id,
+            profile_id: price.profile_id,
+            plan_id: price.plan_id,
+            currency: price.currency,
+            pricing_model: price.pricing_model,
+            unit_amount: price.unit_amount,
+            tiers: price.tiers,
+            billing_interval: price.billing_interval,
+            interval_count: price.interval_count,
+            is_active: price.is_active,
+            metadata: price.metadata,
+            created_at: price.created_at,
+            modified_at: price.modified_at,
+        };
+        prices.push(price.clone());
+        Ok(price)
+    }
+
+    async fn find_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        self.billing_prices
+            .lock()
+            .await
+            .iter()
+            .find(|price| price.merchant_id == *merchant_id && price.id == price_id)
+            .cloned()
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!("No billing price found for id = {price_id}"))
+                    .into()
+            })
+    }
+
+    async fn list_billing_prices_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<Vec<storage::BillingPrice>, StorageError> {
+        let mut prices: Vec<_> = self
+            .billing_prices
+            .lock()
+            .await
+            .iter()
+            .filter(|price| price.merchant_id == *merchant_id && price.plan_id == plan_id)
+            .cloned()
+            .collect();
+        prices.sort_by(|this, other| this.created_at.cmp(&other.created_at));
+        Ok(prices)
+    }
+
+    async fn update_billing_price_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        price_id: &str,
+        price_update: storage::BillingPriceUpdate,
+    ) -> CustomResult<storage::BillingPrice, StorageError> {
+        self.billing_prices
+            .lock()
+            .await
+            .iter_mut()
+            .find(|price| price.merchant_id == *merchant_id && price.id == price_id)
+            .map(|price| {
+                *price = price_update.apply_changeset(price.clone());
+                price.clone()
+            })
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!("No billing price found for id = {price_id}"))
+                    .into()
+            })
+    }
+
+    async fn insert_billing_coupon(
+        &self,
+        coupon: storage::BillingCouponNew,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        let mut coupons = self.billing_coupons.lock().await;
+        if coupons.iter().any(|existing| {
+            existing.id == coupon.id
+                || (existing.merchant_id == coupon.merchant_id
+                    && existing.profile_id == coupon.profile_id
+                    && existing.code == coupon.code)
+        }) {
+            Err(StorageError::DuplicateValue {
+                entity: "billing_coupon",
+                key: Some(coupon.code.clone()),
+            })?
+        }
+        let coupon = storage::BillingCoupon {
+            id: coupon.id,
+            merchant_id: coupon.merchant_id,
+            profile_id: coupon.profile_id,
+            code: coupon.code,
+            name: coupon.name,
+            discount_type: coupon.discount_type,
+            percent_off: coupon.percent_off,
+            amount_off: coupon.amount_off,
+            currency: coupon.currency,
+            duration: coupon.duration,
+            duration_in_cycles: coupon.duration_in_cycles,
+            max_redemptions: coupon.max_redemptions,
+            times_redeemed: coupon.times_redeemed,
+            redeem_by: coupon.redeem_by,
+            is_active: coupon.is_active,
+            metadata: coupon.metadata,
+            created_at: coupon.created_at,
+            modified_at: coupon.modified_at,
+        };
+        coupons.push(coupon.clone());
+        Ok(coupon)
+    }
+
+    async fn find_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        self.billing_coupons
+            .lock()
+            .await
+            .iter()
+            .find(|coupon| coupon.merchant_id == *merchant_id && coupon.id == coupon_id)
+            .cloned()
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!("No billing coupon found for id = {coupon_id}"))
+                    .into()
+            })
+    }
+
+    async fn find_billing_coupon_by_merchant_id_profile_id_code(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        code: &str,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        self.billing_coupons
+            .lock()
+            .await
+            .iter()
+            .find(|coupon| {
+                coupon.merchant_id == *merchant_id
+                    && coupon.profile_id == *profile_id
+                    && coupon.code == code
+            })
+            .cloned()
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!("No billing coupon found for code = {code}"))
+                    .into()
+            })
+    }
+
+    async fn list_billing_coupons_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::BillingCoupon>, StorageError> {
+        let mut coupons: Vec<_> = self
+            .billing_coupons
+            .lock()
+            .await
+            .iter()
+            .filter(|coupon| coupon.merchant_id == *merchant_id && coupon.profile_id == *profile_id)
+            .cloned()
+            .collect();
+        coupons.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+        Ok(paginate(coupons, limit, offset))
+    }
+
+    async fn update_billing_coupon_by_merchant_id_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        coupon_id: &str,
+        coupon_update: storage::BillingCouponUpdate,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        self.billing_coupons
+            .lock()
+            .await
+            .iter_mut()
+            .find(|coupon| coupon.merchant_id == *merchant_id && coupon.id == coupon_id)
+            .map(|coupon| {
+                *coupon = storage::BillingCouponUpdateInternal::from(coupon_update)
+                    .apply_changeset(coupon.clone());
+                coupon.clone()
+            })
+            .ok_or_else(|| {
+                StorageError::ValueNotFound(format!("No billing coupon found for id = {coupon_id}"))
+                    .into()
+            })
+    }
+
+    async fn redeem_billing_coupon(
+        &self,
+        coupon: storage::BillingCoupon,
+    ) -> CustomResult<storage::BillingCoupon, StorageError> {
+        let times_redeemed = coupon.times_redeemed.saturating_add(1);
+        self.billing_coupons
+            .lock()
+            .await
+            .iter_mut()
+            .find(|existing| {
+                existing.merchant_id == coupon.merchant_id
+                    && existing.id == coupon.id
+                    && existing.times_redeemed == coupon.times_redeemed
+            })
+            .map(|existing| {
+                *existing = storage::BillingCouponUpdateInternal::from(
+                    storage::BillingCouponUpdate::Redeemed { times_redeemed },
+                )
+                .apply_changeset(existing.clone());
+                existing.clone()
+            })
+            .ok_or_else(|| {
+                StorageError::ValueNotFound("The coupon was redeemed concurrently".to_string())
+                    .into()
+            })
+    }
+}
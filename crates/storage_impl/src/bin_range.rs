@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use async_bb8_diesel::AsyncConnection;
+use diesel_models::bin_range::{BinRange, BinRangeNew};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::bin_range::{BinRangeIndex, BinRangeInterface};
+use redis_interface::errors::RedisError;
+use router_env::{instrument, tracing};
+
+use crate::{
+    errors::StorageError,
+    kv_router_store::KVRouterStore,
+    redis::{
+        cache::{self, CacheKey, CacheKind, BIN_RANGE_CACHE},
+        kv_store::RedisConnInterface,
+    },
+    utils::{pg_connection_read, pg_connection_write},
+    CustomResult, DatabaseStore, MockDb, RouterStore,
+};
+
+/// Key of the BIN range index in the in-memory cache
+const BIN_RANGE_INDEX_KEY: &str = "bin_range_index";
+
+/// Number of BIN ranges inserted in a single statement while replacing the ranges of a source
+const BIN_RANGE_INSERT_BATCH_SIZE: usize = 1000;
+
+impl<T: DatabaseStore> RouterStore<T> {
+    /// Returns the BIN range index of the tenant, building it from the database when it is not
+    /// cached in memory. The index is evicted from every instance when the ranges are replaced.
+    async fn get_bin_range_index(&self) -> CustomResult<Arc<BinRangeIndex>, StorageError> {
+        let redis = self
+            .get_redis_conn()
+            .change_context(StorageError::RedisError(
+                RedisError::RedisConnectionError.into(),
+            ))
+            .attach_printable("Failed to get redis connection")?;
+        let cache_key = CacheKey {
+            key: BIN_RANGE_INDEX_KEY.to_string(),
+            prefix: redis.key_prefix.clone(),
+        };
+
+        if let Some(index) = BIN_RANGE_CACHE
+            .get_val::<Arc<BinRangeIndex>>(cache_key.clone())
+            .await
+        {
+            return Ok(index);
+        }
+
+        let conn = pg_connection_read(self).await?;
+        let bin_ranges = BinRange::list_all(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))?;
+        let index = Arc::new(BinRangeIndex::new(bin_ranges));
+        BIN_RANGE_CACHE.push(cache_key, index.clone()).await;
+
+        Ok(index)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: DatabaseStore> BinRangeInterface for RouterStore<T> {
+    type Error = StorageError;
+
+    #[instrument(skip_all)]
+    async fn find_bin_range_by_card_number(
+        &self,
+        card_number: &str,
+    ) -> CustomResult<Option<BinRange>, StorageError> {
+        Ok(self.get_bin_range_index().await?.find(card_number).cloned())
+    }
+
+    #[instrument(skip_all)]
+    async fn replace_bin_ranges_by_source(
+        &self,
+        source: &str,
+        bin_ranges: Vec<BinRangeNew>,
+    ) -> CustomResult<usize, StorageError> {
+        let conn = pg_connection_write(self).await?;
+        let source = source.to_owned();
+        let inserted_count = bin_ranges.len();
+
+        conn.transaction_async(|conn| async move {
+            match BinRange::delete_by_source(&conn, &source).await {
+                Ok(_) => {}
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        diesel_models::errors::DatabaseError::NotFound
+                    ) => {}
+                Err(error) => Err(StorageError::from(error))?,
+            }
+
+            let mut bin_ranges = bin_ranges.into_iter().peekable();
+            while bin_ranges.peek().is_some() {
+                let batch = bin_ranges
+                    .by_ref()
+                    .take(BIN_RANGE_INSERT_BATCH_SIZE)
+                    .collect::<Vec<_>>();
+                BinRangeNew::batch_insert(batch, &conn)
+                    .await
+                    .map_err(StorageError::from)?;
+            }
+
+            Ok::<_, StorageError>(())
+        })
+        .await
+        .map_err(|error| report!(error))?;
+
+        cache::redact_from_redis_and_publish(
+            self,
+            [CacheKind::BinRange(BIN_RANGE_INDEX_KEY.into())],
+        )
+        .await?;
+
+        Ok(inserted_count)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: DatabaseStore> BinRangeInterface for KVRouterStore<T> {
+    type Error = StorageError;
+
+    #[instrument(skip_all)]
+    async fn find_bin_range_by_card_number(
+        &self,
+        card_number: &str,
+    ) -> CustomResult<Option<BinRange>, StorageError> {
+        self.router_store
+            .find_bin_range_by_card_number(card_number)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn replace_bin_ranges_by_source(
+        &self,
+        source: &str,
+        bin_ranges: Vec<BinRangeNew>,
+    ) -> CustomResult<usize, StorageError> {
+        self.router_store
+            .replace_bin_ranges_by_source(source, bin_ranges)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl BinRangeInterface for MockDb {
+    type Error = StorageError;
+
+    async fn find_bin_range_by_card_number(
+        &self,
+        card_number: &str,
+    ) -> CustomResult<Option<BinRange>, StorageError> {
+        let bin_ranges = self.bin_ranges.lock().await.clone();
+        Ok(BinRangeIndex::new(bin_ranges).find(card_number).cloned())
+    }
+
+    async fn replace_bin_ranges_by_source(
+        &self,
+        source: &str,
+        bin_ranges: Vec<BinRangeNew>,
+    ) -> CustomResult<usize, StorageError> {
+        let mut stored_bin_ranges = self.bin_ranges.lock().await;
+        stored_bin_ranges.retain(|bin_range| bin_range.source != source);
+        let inserted_count = bin_ranges.len();
+        stored_bin_ranges.extend(bin_ranges.into_iter().map(|bin_range| BinRange {
+            bin_range_id: bin_range.bin_range_id,
+            range_low: bin_range.range_low,
+            range_high: bin_range.range_high,
+            card_issuer: bin_range.card_issuer,
+            card_network: bin_range.card_network,
+            card_type: bin_range.card_type,
+            card_subtype: bin_range.card_subtype,
+            card_issuing_country: bin_range.card_issuing_country,
+            country_code: bin_range.country_code,
+            bank_code: bin_range.bank_code,
+            source: bin_range.source,
+            created_at: bin_range.created_at,
+        }));
+
+        Ok(inserted_count)
+    }
+}
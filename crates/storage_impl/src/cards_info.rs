@@ -87,15 +87,57 @@ impl CardsInfoInterface for MockDb {
             .cloned())
     }
 
-    async fn add_card_info(&self, _data: CardInfo) -> CustomResult<CardInfo, StorageError> {
-        Err(StorageError::MockDbError)?
+    async fn add_card_info(&self, data: CardInfo) -> CustomResult<CardInfo, StorageError> {
+        let mut cards_info = self.cards_info.lock().await;
+        if cards_info.iter().any(|ci| ci.card_iin == data.card_iin) {
+            Err(StorageError::DuplicateValue {
+                entity: "cards_info",
+                key: Some(data.card_iin.clone()),
+            })?
+        }
+        cards_info.push(data.clone());
+        Ok(data)
     }
 
     async fn update_card_info(
         &self,
-        _card_iin: String,
-        _data: UpdateCardInfo,
+        card_iin: String,
+        data: UpdateCardInfo,
     ) -> CustomResult<CardInfo, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut cards_info = self.cards_info.lock().await;
+        let card_info = cards_info
+            .iter_mut()
+            .find(|ci| ci.card_iin == card_iin)
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No card info found for card_iin = {card_iin}"
+            )))?;
+        let UpdateCardInfo {
+            card_issuer,
+            card_network,
+            card_type,
+            card_subtype,
+            card_issuing_country,
+            bank_code_id,
+            bank_code,
+            country_code,
+            last_updated,
+            last_updated_provider,
+        } = data;
+        *card_info = CardInfo {
+            card_iin: card_info.card_iin.clone(),
+            card_issuer: card_issuer.or(card_info.card_issuer.take()),
+            card_network: card_network.or(card_info.card_network),
+            card_type: card_type.or(card_info.card_type.take()),
+            card_subtype: card_subtype.or(card_info.card_subtype.take()),
+            card_issuing_country: card_issuing_country.or(card_info.card_issuing_country.take()),
+            bank_code_id: bank_code_id.or(card_info.bank_code_id.take()),
+            bank_code: bank_code.or(card_info.bank_code.take()),
+            country_code: country_code.or(card_info.country_code.take()),
+            date_created: card_info.date_created,
+            last_updated: last_updated.or(card_info.last_updated),
+            last_updated_provider: last_updated_provider.or(card_info.last_updated_provider.take()),
+        };
+
+        Ok(card_info.clone())
     }
 }
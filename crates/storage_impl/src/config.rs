@@ -1,4 +1,4 @@
-use common_utils::DbConnectionParams;
+use common_utils::{DbConnectionParams, DbTlsSettings};
 use hyperswitch_domain_models::master_key::MasterKeyInterface;
 use hyperswitch_masking::{PeekInterface, Secret};
 
@@ -16,6 +16,7 @@ pub struct Database {
     pub queue_strategy: QueueStrategy,
     pub min_idle: Option<u32>,
     pub max_lifetime: Option<u64>,
+    pub tls: Option<DbTlsSettings>,
 }
 
 impl DbConnectionParams for Database {
@@ -34,6 +35,28 @@ impl DbConnectionParams for Database {
     fn get_dbname(&self) -> &str {
         &self.dbname
     }
+    fn get_tls_settings(&self) -> Option<&DbTlsSettings> {
+        self.tls.as_ref()
+    }
+}
+
+/// A read replica, receiving a share of the reads proportional to its weight
+#[derive(Debug, Clone)]
+pub struct WeightedReplica {
+    pub database: Database,
+    pub weight: u32,
+}
+
+/// The read replicas and how reads are routed across them
+#[derive(Debug, Clone)]
+pub struct ReplicaSettings {
+    pub replicas: Vec<WeightedReplica>,
+    /// Replication lag, in milliseconds, above which a replica stops receiving reads
+    pub max_replication_lag: u64,
+    /// Interval between the replication lag checks of each replica, in seconds
+    pub health_check_interval: u64,
+    /// Whether reads issued after a write within the same request are served by the master
+    pub read_your_writes: bool,
 }
 
 #[derive(Debug, serde::Deserialize, Clone, Copy, Default)]
@@ -66,6 +89,7 @@ impl Default for Database {
             queue_strategy: QueueStrategy::default(),
             min_idle: None,
             max_lifetime: None,
+            tls: None,
         }
     }
 }
@@ -54,6 +54,7 @@ pub async fn pg_connection_write<T: crate::DatabaseStore>(
 > {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
+    crate::database::store::record_write();
 
     pool.get()
         .await
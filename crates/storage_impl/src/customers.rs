@@ -847,12 +847,17 @@ impl domain::CustomerInterface for MockDb {
     #[cfg(feature = "v2")]
     async fn find_optional_by_merchant_id_merchant_reference_id(
         &self,
-        _customer_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &MerchantKeyStore,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<domain::Customer>, StorageError> {
-        todo!()
+        let customers = self.customers.lock().await;
+        self.find_resource(key_store, customers, |customer| {
+            customer.merchant_reference_id.as_ref() == Some(customer_id)
+                && &customer.merchant_id == merchant_id
+        })
+        .await
     }
 
     async fn list_customers_by_merchant_id(
@@ -867,8 +872,8 @@ impl domain::CustomerInterface for MockDb {
             customers
                 .iter()
                 .filter(|customer| customer.merchant_id == *merchant_id)
-                .take(usize::from(constraints.limit))
                 .skip(usize::try_from(constraints.offset.unwrap_or(0)).unwrap_or(0))
+                .take(usize::from(constraints.limit))
                 .map(|customer| async {
                     customer
                         .to_owned()
@@ -899,8 +904,8 @@ impl domain::CustomerInterface for MockDb {
             customers
                 .iter()
                 .filter(|customer| customer.merchant_id == *merchant_id)
-                .take(usize::from(constraints.limit))
                 .skip(usize::try_from(constraints.offset.unwrap_or(0)).unwrap_or(0))
+                .take(usize::from(constraints.limit))
                 .map(|customer| async {
                     customer
                         .to_owned()
@@ -926,39 +931,87 @@ impl domain::CustomerInterface for MockDb {
     #[instrument(skip_all)]
     async fn update_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: id_type::CustomerId,
-        _merchant_id: id_type::MerchantId,
-        _customer: domain::Customer,
-        _customer_update: domain::CustomerUpdate,
-        _key_store: &MerchantKeyStore,
+        customer_id: id_type::CustomerId,
+        merchant_id: id_type::MerchantId,
+        customer: domain::Customer,
+        customer_update: domain::CustomerUpdate,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customer = Conversion::convert(customer)
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let updated_customer =
+            diesel_models::CustomerUpdateInternal::from(customer_update).apply_changeset(customer);
+        let customers = self.customers.lock().await;
+        self.update_resource(
+            key_store,
+            customers,
+            updated_customer,
+            |customer| customer.customer_id == customer_id && customer.merchant_id == merchant_id,
+            format!(
+                "No customer found for customer_id = {}",
+                customer_id.get_string_repr()
+            ),
+        )
+        .await
     }
 
     #[cfg(feature = "v1")]
     async fn find_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &MerchantKeyStore,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customers = self.customers.lock().await;
+        let customer: domain::Customer = self
+            .get_resource(
+                key_store,
+                customers,
+                |customer| {
+                    customer.customer_id == *customer_id && &customer.merchant_id == merchant_id
+                },
+                format!(
+                    "No customer found for customer_id = {}",
+                    customer_id.get_string_repr()
+                ),
+            )
+            .await?;
+        match customer.name {
+            Some(ref name) if name.peek() == pii::REDACTED => Err(StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 
     #[cfg(feature = "v2")]
     async fn find_customer_by_merchant_reference_id_merchant_id(
         &self,
-        _merchant_reference_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &MerchantKeyStore,
+        merchant_reference_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customers = self.customers.lock().await;
+        let customer: domain::Customer = self
+            .get_resource(
+                key_store,
+                customers,
+                |customer| {
+                    customer.merchant_reference_id.as_ref() == Some(merchant_reference_id)
+                        && &customer.merchant_id == merchant_id
+                },
+                format!(
+                    "No customer found for merchant_reference_id = {}",
+                    merchant_reference_id.get_string_repr()
+                ),
+            )
+            .await?;
+        match customer.name {
+            Some(ref name) if name.peek() == pii::REDACTED => Err(StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 
     #[allow(clippy::panic)]
@@ -990,47 +1043,92 @@ impl domain::CustomerInterface for MockDb {
     #[cfg(feature = "v1")]
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
     ) -> CustomResult<bool, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let count_before = customers.len();
+        customers.retain(|customer| {
+            !(customer.customer_id == *customer_id && customer.merchant_id == *merchant_id)
+        });
+
+        if customers.len() == count_before {
+            Err(StorageError::ValueNotFound(format!(
+                "No customer found for customer_id = {}",
+                customer_id.get_string_repr()
+            )))?
+        }
+        Ok(true)
     }
 
     #[cfg(feature = "v2")]
     #[allow(clippy::too_many_arguments)]
     async fn update_customer_by_global_id(
         &self,
-        _id: &id_type::GlobalCustomerId,
-        _customer: domain::Customer,
-        _customer_update: domain::CustomerUpdate,
-        _key_store: &MerchantKeyStore,
+        id: &id_type::GlobalCustomerId,
+        customer: domain::Customer,
+        customer_update: domain::CustomerUpdate,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customer = Conversion::convert(customer)
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let updated_customer =
+            diesel_models::CustomerUpdateInternal::from(customer_update).apply_changeset(customer);
+        let customers = self.customers.lock().await;
+        self.update_resource(
+            key_store,
+            customers,
+            updated_customer,
+            |customer| customer.id == *id,
+            format!("No customer found for id = {}", id.get_string_repr()),
+        )
+        .await
     }
 
     #[cfg(feature = "v2")]
     async fn find_customer_by_global_id(
         &self,
-        _id: &id_type::GlobalCustomerId,
-        _key_store: &MerchantKeyStore,
+        id: &id_type::GlobalCustomerId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customers = self.customers.lock().await;
+        let customer: domain::Customer = self
+            .get_resource(
+                key_store,
+                customers,
+                |customer| customer.id == *id,
+                format!("No customer found for id = {}", id.get_string_repr()),
+            )
+            .await?;
+        match customer.name {
+            Some(ref name) if name.peek() == pii::REDACTED => Err(StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 
     #[cfg(feature = "v2")]
     async fn find_customer_by_global_id_merchant_id(
         &self,
-        _id: &id_type::GlobalCustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &MerchantKeyStore,
+        id: &id_type::GlobalCustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let customers = self.customers.lock().await;
+        let customer: domain::Customer = self
+            .get_resource(
+                key_store,
+                customers,
+                |customer| customer.id == *id && customer.merchant_id == *merchant_id,
+                format!("No customer found for id = {}", id.get_string_repr()),
+            )
+            .await?;
+        match customer.name {
+            Some(ref name) if name.peek() == pii::REDACTED => Err(StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 }
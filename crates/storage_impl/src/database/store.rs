@@ -1,4 +1,13 @@
-use async_bb8_diesel::{AsyncConnection, ConnectionError};
+use std::{
+    cell::Cell,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl, ConnectionError};
 use bb8::CustomizeConnection;
 use common_utils::{
     types::{keymanager, TenantConfig},
@@ -6,9 +15,10 @@ use common_utils::{
 };
 use diesel::PgConnection;
 use error_stack::ResultExt;
+use router_env::logger;
 
 use crate::{
-    config::Database,
+    config::{Database, ReplicaSettings},
     errors::{StorageError, StorageResult},
 };
 
@@ -74,24 +84,98 @@ impl DatabaseStore for Store {
     }
 }
 
+tokio::task_local! {
+    /// Whether the request being served has written to the master
+    static HAS_WRITTEN: Cell<bool>;
+}
+
+/// Tracks the writes of `future`, so that its reads following a write can be served by the
+/// master when read-your-writes is enabled
+pub async fn track_writes<F: Future>(future: F) -> F::Output {
+    HAS_WRITTEN.scope(Cell::new(false), future).await
+}
+
+/// Records a write of the request being served, no-op outside of [`track_writes`]
+pub fn record_write() {
+    let _ = HAS_WRITTEN.try_with(|has_written| has_written.set(true));
+}
+
+fn has_written() -> bool {
+    HAS_WRITTEN.try_with(Cell::get).unwrap_or(false)
+}
+
+#[derive(Debug, Clone)]
+pub struct Replica {
+    pub pool: PgPool,
+    pub accounts_pool: PgPool,
+    pub weight: u32,
+    /// Whether the replication lag is within the configured threshold
+    is_healthy: Arc<AtomicBool>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ReplicaStore {
     pub master_pool: PgPool,
-    pub replica_pool: PgPool,
     pub accounts_master_pool: PgPool,
-    pub accounts_replica_pool: PgPool,
+    pub replicas: Vec<Replica>,
+    pub read_your_writes: bool,
+    next_read: Arc<AtomicU64>,
+}
+
+impl ReplicaStore {
+    /// Picks a healthy replica through weighted round robin, `None` when reads should be served
+    /// by the master instead
+    fn select_replica(&self) -> Option<&Replica> {
+        if self.read_your_writes && has_written() {
+            return None;
+        }
+
+        select_weighted(
+            self.replicas
+                .iter()
+                .filter(|replica| replica.is_healthy.load(Ordering::Relaxed))
+                .map(|replica| (replica, replica.weight)),
+            &self.next_read,
+        )
+    }
+}
+
+/// Weighted round robin, each read advances `next_read` and lands on the candidate whose share of
+/// the total weight covers it. Candidates with no weight are never picked.
+fn select_weighted<T>(
+    mut candidates: impl Iterator<Item = (T, u32)> + Clone,
+    next_read: &AtomicU64,
+) -> Option<T> {
+    let total_weight = candidates
+        .clone()
+        .map(|(_, weight)| u64::from(weight))
+        .sum::<u64>();
+    if total_weight == 0 {
+        return None;
+    }
+
+    let mut position = next_read.fetch_add(1, Ordering::Relaxed) % total_weight;
+    candidates.find_map(|(candidate, weight)| {
+        let weight = u64::from(weight);
+        if position < weight {
+            Some(candidate)
+        } else {
+            position -= weight;
+            None
+        }
+    })
 }
 
 #[async_trait::async_trait]
 impl DatabaseStore for ReplicaStore {
-    type Config = (Database, Database);
+    type Config = (Database, ReplicaSettings);
     async fn new(
-        config: (Database, Database),
+        config: (Database, ReplicaSettings),
         tenant_config: &dyn TenantConfig,
         test_transaction: bool,
         _key_manager_state: Option<keymanager::KeyManagerState>,
     ) -> StorageResult<Self> {
-        let (master_config, replica_config) = config;
+        let (master_config, replica_settings) = config;
         let master_pool =
             diesel_make_pg_pool(&master_config, tenant_config.get_schema(), test_transaction)
                 .await
@@ -103,26 +187,55 @@ impl DatabaseStore for ReplicaStore {
         )
         .await
         .attach_printable("failed to create accounts master pool")?;
-        let replica_pool = diesel_make_pg_pool(
-            &replica_config,
-            tenant_config.get_schema(),
-            test_transaction,
-        )
-        .await
-        .attach_printable("failed to create replica pool")?;
 
-        let accounts_replica_pool = diesel_make_pg_pool(
-            &replica_config,
-            tenant_config.get_accounts_schema(),
-            test_transaction,
-        )
-        .await
-        .attach_printable("failed to create accounts pool")?;
+        let mut replicas = Vec::with_capacity(replica_settings.replicas.len());
+        for replica_config in replica_settings.replicas {
+            let pool = diesel_make_pg_pool(
+                &replica_config.database,
+                tenant_config.get_schema(),
+                test_transaction,
+            )
+            .await
+            .attach_printable_lazy(|| {
+                format!(
+                    "failed to create replica pool for {}",
+                    replica_config.database.host
+                )
+            })?;
+            let accounts_pool = diesel_make_pg_pool(
+                &replica_config.database,
+                tenant_config.get_accounts_schema(),
+                test_transaction,
+            )
+            .await
+            .attach_printable_lazy(|| {
+                format!(
+                    "failed to create accounts replica pool for {}",
+                    replica_config.database.host
+                )
+            })?;
+
+            let replica = Replica {
+                pool,
+                accounts_pool,
+                weight: replica_config.weight,
+                is_healthy: Arc::new(AtomicBool::new(true)),
+            };
+            spawn_replication_lag_check(
+                replica.clone(),
+                replica_config.database.host,
+                replica_settings.max_replication_lag,
+                replica_settings.health_check_interval,
+            );
+            replicas.push(replica);
+        }
+
         Ok(Self {
             master_pool,
-            replica_pool,
             accounts_master_pool,
-            accounts_replica_pool,
+            replicas,
+            read_your_writes: replica_settings.read_your_writes,
+            next_read: Arc::new(AtomicU64::new(0)),
         })
     }
 
@@ -131,7 +244,9 @@ impl DatabaseStore for ReplicaStore {
     }
 
     fn get_replica_pool(&self) -> &PgPool {
-        &self.replica_pool
+        self.select_replica()
+            .map(|replica| &replica.pool)
+            .unwrap_or(&self.master_pool)
     }
 
     fn get_accounts_master_pool(&self) -> &PgPool {
@@ -139,10 +254,80 @@ impl DatabaseStore for ReplicaStore {
     }
 
     fn get_accounts_replica_pool(&self) -> &PgPool {
-        &self.accounts_replica_pool
+        self.select_replica()
+            .map(|replica| &replica.accounts_pool)
+            .unwrap_or(&self.accounts_master_pool)
     }
 }
 
+#[derive(diesel::QueryableByName)]
+struct ReplicationLag {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    lag_ms: i64,
+}
+
+/// A replica having replayed all the WAL it received is not lagging, even if its last replayed
+/// transaction is old, as is the case when the master is idle
+const REPLICATION_LAG_QUERY: &str = "SELECT CASE \
+    WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+    ELSE COALESCE((EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()) * 1000)::BIGINT, 0) \
+    END AS lag_ms";
+
+async fn get_replication_lag(pool: &PgPool) -> StorageResult<u64> {
+    let conn = pool
+        .get()
+        .await
+        .change_context(StorageError::DatabaseConnectionError)?;
+    let lag = diesel::sql_query(REPLICATION_LAG_QUERY)
+        .get_result_async::<ReplicationLag>(&*conn)
+        .await
+        .change_context(StorageError::DatabaseConnectionError)
+        .attach_printable("Failed to fetch the replication lag")?;
+
+    Ok(u64::try_from(lag.lag_ms).unwrap_or_default())
+}
+
+/// Periodically checks the replication lag of the replica, taking it out of the read rotation
+/// while its lag exceeds `max_replication_lag` milliseconds or it cannot be reached
+fn spawn_replication_lag_check(
+    replica: Replica,
+    host: String,
+    max_replication_lag: u64,
+    health_check_interval: u64,
+) {
+    let _task_handle = tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(health_check_interval.max(1)));
+        loop {
+            interval.tick().await;
+            let is_healthy = match get_replication_lag(&replica.pool).await {
+                Ok(lag) => {
+                    if lag > max_replication_lag {
+                        logger::warn!(
+                            %host,
+                            lag,
+                            "Replica is lagging, routing its reads to the master"
+                        );
+                    }
+                    lag <= max_replication_lag
+                }
+                Err(error) => {
+                    logger::error!(
+                        %host,
+                        ?error,
+                        "Failed to check the replication lag of the replica"
+                    );
+                    false
+                }
+            };
+            let was_healthy = replica.is_healthy.swap(is_healthy, Ordering::Relaxed);
+            if is_healthy && !was_healthy {
+                logger::info!(%host, "Replica is back in the read rotation");
+            }
+        }
+    });
+}
+
 pub async fn diesel_make_pg_pool(
     database: &Database,
     schema: &str,
@@ -183,3 +368,52 @@ impl CustomizeConnection<PgPooledConn, ConnectionError> for TestTransaction {
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select_all(weights: &[(char, u32)], next_read: &AtomicU64, reads: usize) -> Vec<char> {
+        (0..reads)
+            .filter_map(|_| select_weighted(weights.iter().copied(), next_read))
+            .collect()
+    }
+
+    #[test]
+    fn test_reads_are_spread_by_weight() {
+        let next_read = AtomicU64::new(0);
+
+        assert_eq!(
+            select_all(&[('a', 1), ('b', 3)], &next_read, 8),
+            ['a', 'b', 'b', 'b', 'a', 'b', 'b', 'b']
+        );
+    }
+
+    #[test]
+    fn test_replicas_without_weight_are_skipped() {
+        let next_read = AtomicU64::new(0);
+
+        assert_eq!(
+            select_all(&[('a', 0), ('b', 1), ('c', 0), ('d', 1)], &next_read, 4),
+            ['b', 'd', 'b', 'd']
+        );
+        assert_eq!(select_all(&[('a', 0)], &next_read, 1), Vec::<char>::new());
+        assert_eq!(select_all(&[], &next_read, 1), Vec::<char>::new());
+    }
+
+    #[tokio::test]
+    async fn test_writes_are_tracked_per_request() {
+        assert!(!track_writes(async { has_written() }).await);
+        assert!(
+            track_writes(async {
+                record_write();
+                has_written()
+            })
+            .await
+        );
+
+        // Outside of a tracked request writes are not recorded
+        record_write();
+        assert!(!has_written());
+    }
+}
@@ -186,43 +186,111 @@ impl InvoiceInterface for MockDb {
     #[instrument(skip_all)]
     async fn insert_invoice_entry(
         &self,
-        _key_store: &MerchantKeyStore,
-        _invoice_new: DomainInvoice,
+        key_store: &MerchantKeyStore,
+        invoice_new: DomainInvoice,
     ) -> CustomResult<DomainInvoice, StorageError> {
-        Err(StorageError::MockDbError)?
+        let invoice = invoice_new
+            .convert()
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let mut invoices = self.invoices.lock().await;
+        if invoices.iter().any(|existing| existing.id == invoice.id) {
+            Err(StorageError::DuplicateValue {
+                entity: "invoice",
+                key: Some(invoice.id.get_string_repr().to_owned()),
+            })?
+        }
+        let invoice_id = invoice.id.clone();
+        invoices.push(invoice);
+
+        self.get_resource(
+            key_store,
+            invoices,
+            |invoice| invoice.id == invoice_id,
+            "Invoice not found".to_string(),
+        )
+        .await
     }
 
     async fn find_invoice_by_invoice_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _invoice_id: String,
+        key_store: &MerchantKeyStore,
+        invoice_id: String,
     ) -> CustomResult<DomainInvoice, StorageError> {
-        Err(StorageError::MockDbError)?
+        let invoices = self.invoices.lock().await;
+        self.get_resource(
+            key_store,
+            invoices,
+            |invoice| invoice.id.get_string_repr() == invoice_id,
+            format!("No invoice found for id = {invoice_id}"),
+        )
+        .await
     }
 
     async fn update_invoice_entry(
         &self,
-        _key_store: &MerchantKeyStore,
-        _invoice_id: String,
-        _data: DomainInvoiceUpdate,
+        key_store: &MerchantKeyStore,
+        invoice_id: String,
+        data: DomainInvoiceUpdate,
     ) -> CustomResult<DomainInvoice, StorageError> {
-        Err(StorageError::MockDbError)?
+        let invoice_update = data
+            .construct_new()
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let invoices = self.invoices.lock().await;
+        let invoice = invoices
+            .iter()
+            .find(|invoice| invoice.id.get_string_repr() == invoice_id)
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No invoice found for id = {invoice_id}"
+            )))?;
+
+        self.update_resource(
+            key_store,
+            invoices,
+            invoice_update.apply_changeset(invoice),
+            |invoice| invoice.id.get_string_repr() == invoice_id,
+            format!("No invoice found for id = {invoice_id}"),
+        )
+        .await
     }
 
     async fn get_latest_invoice_for_subscription(
         &self,
-        _key_store: &MerchantKeyStore,
-        _subscription_id: String,
+        key_store: &MerchantKeyStore,
+        subscription_id: String,
     ) -> CustomResult<DomainInvoice, StorageError> {
-        Err(StorageError::MockDbError)?
+        let invoices = self.invoices.lock().await;
+        let latest_invoice = invoices
+            .iter()
+            .filter(|invoice| invoice.subscription_id.get_string_repr() == subscription_id)
+            .max_by_key(|invoice| invoice.created_at)
+            .map(|invoice| invoice.id.clone())
+            .ok_or(StorageError::ValueNotFound(format!(
+                "Invoice not found for subscription_id: {subscription_id}"
+            )))?;
+
+        self.get_resource(
+            key_store,
+            invoices,
+            |invoice| invoice.id == latest_invoice,
+            format!("Invoice not found for subscription_id: {subscription_id}"),
+        )
+        .await
     }
 
     async fn find_invoice_by_subscription_id_connector_invoice_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _subscription_id: String,
-        _connector_invoice_id: common_utils::id_type::InvoiceId,
+        key_store: &MerchantKeyStore,
+        subscription_id: String,
+        connector_invoice_id: common_utils::id_type::InvoiceId,
     ) -> CustomResult<Option<DomainInvoice>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let invoices = self.invoices.lock().await;
+        self.find_resource(key_store, invoices, |invoice| {
+            invoice.subscription_id.get_string_repr() == subscription_id
+                && invoice.connector_invoice_id.as_ref() == Some(&connector_invoice_id)
+        })
+        .await
     }
 }
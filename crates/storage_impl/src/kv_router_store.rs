@@ -49,6 +49,10 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             .as_ref()
             .ok_or_else(|| errors::StorageError::DecryptionError)
     }
+
+    pub fn is_outbox_enabled(&self) -> bool {
+        self.router_store.is_outbox_enabled()
+    }
 }
 
 pub struct InsertResourceParams<'a> {
@@ -183,6 +187,13 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
     }
 
+    /// Returns the names of the drainer streams of all the partitions
+    pub fn get_drainer_stream_names(&self) -> Vec<String> {
+        (0..self.drainer_num_partitions)
+            .map(|partition| self.get_drainer_stream_name(&format!("shard_{partition}")))
+            .collect()
+    }
+
     pub async fn push_to_drainer_stream<R>(
         &self,
         redis_entry: kv::TypedSql,
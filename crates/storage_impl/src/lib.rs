@@ -10,6 +10,8 @@ use hyperswitch_domain_models::{
 use hyperswitch_masking::StrongSecret;
 use redis::{kv_store::RedisConnInterface, pub_sub::PubSubInterface, RedisStore};
 mod address;
+pub mod billing_catalog;
+pub mod bin_range;
 pub mod business_profile;
 pub mod callback_mapper;
 pub mod card_issuer;
@@ -59,12 +61,23 @@ pub struct RouterStore<T: DatabaseStore> {
     master_encryption_key: StrongSecret<Vec<u8>>,
     pub request_id: Option<String>,
     key_manager_state: Option<KeyManagerState>,
+    outbox_enabled: bool,
 }
 
 impl<T: DatabaseStore> RouterStore<T> {
     pub fn set_key_manager_state(&mut self, state: KeyManagerState) {
         self.key_manager_state = Some(state);
     }
+
+    /// Makes the writes of payment intents, payment attempts and refunds insert their outbox
+    /// entries in the same transaction
+    pub fn set_outbox_enabled(&mut self, outbox_enabled: bool) {
+        self.outbox_enabled = outbox_enabled;
+    }
+
+    pub fn is_outbox_enabled(&self) -> bool {
+        self.outbox_enabled
+    }
     fn get_keymanager_state(&self) -> Result<&KeyManagerState, StorageError> {
         self.key_manager_state
             .as_ref()
@@ -167,6 +180,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             master_encryption_key: encryption_key,
             request_id: None,
             key_manager_state,
+            outbox_enabled: false,
         })
     }
 
@@ -303,6 +317,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             master_encryption_key: encryption_key,
             request_id: None,
             key_manager_state,
+            outbox_enabled: false,
         })
     }
 }
@@ -1015,14 +1015,22 @@ impl MerchantConnectorAccountInterface for MockDb {
     type Error = StorageError;
     async fn update_multiple_merchant_connector_accounts(
         &self,
-        _merchant_connector_accounts: Vec<(
+        merchant_connector_accounts: Vec<(
             domain::MerchantConnectorAccount,
             storage::MerchantConnectorAccountUpdateInternal,
         )>,
     ) -> CustomResult<(), StorageError> {
-        // No need to implement this function for `MockDb` as this function will be removed after the
-        // apple pay certificate migration
-        Err(StorageError::MockDbError)?
+        let mut accounts = self.merchant_connector_accounts.lock().await;
+        for (merchant_connector_account, mca_update) in merchant_connector_accounts {
+            let account = accounts
+                .iter_mut()
+                .find(|account| account.get_id() == merchant_connector_account.get_id())
+                .ok_or(StorageError::ValueNotFound(
+                    "cannot find merchant connector account to update".to_string(),
+                ))?;
+            *account = mca_update.create_merchant_connector_account(account.clone());
+        }
+        Ok(())
     }
     #[cfg(feature = "v1")]
     async fn find_merchant_connector_account_by_merchant_id_connector_label(
@@ -1066,11 +1074,44 @@ impl MerchantConnectorAccountInterface for MockDb {
 
     async fn list_enabled_connector_accounts_by_profile_id(
         &self,
-        _profile_id: &common_utils::id_type::ProfileId,
-        _key_store: &MerchantKeyStore,
-        _connector_type: common_enums::ConnectorType,
+        profile_id: &common_utils::id_type::ProfileId,
+        key_store: &MerchantKeyStore,
+        connector_type: common_enums::ConnectorType,
     ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut accounts = self
+            .merchant_connector_accounts
+            .lock()
+            .await
+            .iter()
+            .filter(|account| {
+                #[cfg(feature = "v1")]
+                let account_profile_id = account.profile_id.as_ref();
+                #[cfg(feature = "v2")]
+                let account_profile_id = Some(&account.profile_id);
+
+                account_profile_id == Some(profile_id)
+                    && account.disabled == Some(false)
+                    && account.connector_type == connector_type
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        accounts.sort_by_key(|account| account.created_at);
+
+        let mut output = Vec::with_capacity(accounts.len());
+        for account in accounts.into_iter() {
+            output.push(
+                account
+                    .convert(
+                        self.get_keymanager_state()
+                            .attach_printable("Missing KeyManagerState")?,
+                        key_store.key.get_inner(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
     }
 
     #[cfg(feature = "v1")]
@@ -266,7 +266,7 @@ impl MerchantKeyStoreInterface for MockDb {
 
         let merchant_key = Conversion::convert(merchant_key_store)
             .await
-            .change_context(StorageError::MockDbError)?;
+            .change_context(StorageError::EncryptionError)?;
         locked_merchant_key_store.push(merchant_key.clone());
         let merchant_id = merchant_key.merchant_id.clone();
         merchant_key
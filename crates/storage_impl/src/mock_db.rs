@@ -38,7 +38,10 @@ pub struct MockDb {
     pub redis: Arc<RedisStore>,
     pub api_keys: Arc<Mutex<Vec<store::ApiKey>>>,
     pub ephemeral_keys: Arc<Mutex<Vec<store::EphemeralKey>>>,
+    #[cfg(feature = "v2")]
+    pub client_secrets: Arc<Mutex<Vec<store::ephemeral_key::ClientSecretType>>>,
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
+    pub bin_ranges: Arc<Mutex<Vec<store::bin_range::BinRange>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
@@ -68,6 +71,49 @@ pub struct MockDb {
     pub hyperswitch_ai_interactions:
         Arc<Mutex<Vec<store::hyperswitch_ai_interaction::HyperswitchAiInteraction>>>,
     pub card_issuers: Arc<Mutex<Vec<store::card_issuer::CardIssuer>>>,
+    pub invoices: Arc<Mutex<Vec<store::invoice::Invoice>>>,
+    pub subscriptions: Arc<Mutex<Vec<store::subscription::Subscription>>>,
+    pub billing_products: Arc<Mutex<Vec<store::billing_catalog::BillingProduct>>>,
+    pub billing_plans: Arc<Mutex<Vec<store::billing_catalog::BillingPlan>>>,
+    pub billing_prices: Arc<Mutex<Vec<store::billing_catalog::BillingPrice>>>,
+    pub billing_coupons: Arc<Mutex<Vec<store::billing_catalog::BillingCoupon>>>,
+    pub callback_mappers: Arc<Mutex<Vec<store::callback_mapper::CallbackMapper>>>,
+    pub dynamic_routing_stats: Arc<Mutex<Vec<store::dynamic_routing_stats::DynamicRoutingStats>>>,
+    pub dispute_evidence_templates:
+        Arc<Mutex<Vec<store::dispute_evidence_template::DisputeEvidenceTemplate>>>,
+    pub ledger_fee_schedules: Arc<Mutex<Vec<store::ledger::LedgerFeeSchedule>>>,
+    pub ledger_journal_entries: Arc<Mutex<Vec<store::ledger::LedgerJournalEntry>>>,
+    pub ledger_postings: Arc<Mutex<Vec<store::ledger::LedgerPosting>>>,
+    pub fraud_checks: Arc<Mutex<Vec<store::fraud_check::FraudCheck>>>,
+    pub payout_batches: Arc<Mutex<Vec<store::payout_batch::PayoutBatch>>>,
+    pub payout_batch_items: Arc<Mutex<Vec<store::payout_batch::PayoutBatchItem>>>,
+    pub file_metadata: Arc<Mutex<Vec<store::file::FileMetadata>>>,
+    pub routing_algorithms: Arc<Mutex<Vec<store::routing_algorithm::RoutingAlgorithm>>>,
+    pub settlement_reports: Arc<Mutex<Vec<store::settlement_report::SettlementReport>>>,
+    pub settlement_recon_lines: Arc<Mutex<Vec<store::settlement_report::SettlementReconLine>>>,
+    pub generic_links: Arc<Mutex<Vec<store::generic_link::GenericLink>>>,
+    pub relays: Arc<Mutex<Vec<store::relay::Relay>>>,
+    pub blocklists: Arc<Mutex<Vec<store::blocklist::Blocklist>>>,
+    pub blocklist_fingerprints: Arc<Mutex<Vec<store::blocklist_fingerprint::BlocklistFingerprint>>>,
+    pub blocklist_lookups: Arc<Mutex<Vec<store::blocklist_lookup::BlocklistLookup>>>,
+    pub bulk_refunds: Arc<Mutex<Vec<store::bulk_refund::BulkRefund>>>,
+    pub bulk_refund_items: Arc<Mutex<Vec<store::bulk_refund::BulkRefundItem>>>,
+    pub customer_data_requests: Arc<Mutex<Vec<store::customer_data_request::CustomerDataRequest>>>,
+    pub customer_redirects: Arc<Mutex<Vec<store::customer_merge::CustomerRedirect>>>,
+    pub customer_match_keys: Arc<Mutex<Vec<store::customer_merge::CustomerMatchKey>>>,
+    pub data_retention_policies: Arc<Mutex<Vec<store::data_retention::DataRetentionPolicy>>>,
+    pub archived_records: Arc<Mutex<Vec<store::data_retention::ArchivedRecord>>>,
+    pub account_updater_batches: Arc<Mutex<Vec<store::account_updater::AccountUpdaterBatch>>>,
+    pub account_updater_cards: Arc<Mutex<Vec<store::account_updater::AccountUpdaterCard>>>,
+    pub dispute_automated_actions:
+        Arc<Mutex<Vec<store::dispute_automated_action::DisputeAutomatedAction>>>,
+    pub gateway_status_maps: Arc<Mutex<Vec<store::gsm::GatewayStatusMap>>>,
+    pub outbox_entries: Arc<Mutex<Vec<store::outbox::Outbox>>>,
+    pub tenant_registries: Arc<Mutex<Vec<store::tenant_registry::TenantRegistry>>>,
+    pub unified_translations: Arc<Mutex<Vec<store::unified_translations::UnifiedTranslations>>>,
+    #[cfg(feature = "v2")]
+    pub payment_methods_sessions:
+        Arc<Mutex<Vec<store::payment_methods_session::PaymentMethodSession>>>,
     pub key_manager_state: Option<KeyManagerState>,
 }
 
@@ -99,7 +145,10 @@ impl MockDb {
             ),
             api_keys: Default::default(),
             ephemeral_keys: Default::default(),
+            #[cfg(feature = "v2")]
+            client_secrets: Default::default(),
             cards_info: Default::default(),
+            bin_ranges: Default::default(),
             events: Default::default(),
             disputes: Default::default(),
             lockers: Default::default(),
@@ -127,6 +176,46 @@ impl MockDb {
             themes: Default::default(),
             hyperswitch_ai_interactions: Default::default(),
             card_issuers: Default::default(),
+            invoices: Default::default(),
+            subscriptions: Default::default(),
+            billing_products: Default::default(),
+            billing_plans: Default::default(),
+            billing_prices: Default::default(),
+            billing_coupons: Default::default(),
+            callback_mappers: Default::default(),
+            dynamic_routing_stats: Default::default(),
+            dispute_evidence_templates: Default::default(),
+            ledger_fee_schedules: Default::default(),
+            ledger_journal_entries: Default::default(),
+            ledger_postings: Default::default(),
+            fraud_checks: Default::default(),
+            payout_batches: Default::default(),
+            payout_batch_items: Default::default(),
+            file_metadata: Default::default(),
+            routing_algorithms: Default::default(),
+            settlement_reports: Default::default(),
+            settlement_recon_lines: Default::default(),
+            generic_links: Default::default(),
+            relays: Default::default(),
+            blocklists: Default::default(),
+            blocklist_fingerprints: Default::default(),
+            blocklist_lookups: Default::default(),
+            bulk_refunds: Default::default(),
+            bulk_refund_items: Default::default(),
+            customer_data_requests: Default::default(),
+            customer_redirects: Default::default(),
+            customer_match_keys: Default::default(),
+            data_retention_policies: Default::default(),
+            archived_records: Default::default(),
+            account_updater_batches: Default::default(),
+            account_updater_cards: Default::default(),
+            dispute_automated_actions: Default::default(),
+            gateway_status_maps: Default::default(),
+            outbox_entries: Default::default(),
+            tenant_registries: Default::default(),
+            unified_translations: Default::default(),
+            #[cfg(feature = "v2")]
+            payment_methods_sessions: Default::default(),
             key_manager_state: Some(key_manager_state),
         })
     }
@@ -187,28 +276,41 @@ impl MockDb {
         D: Sync + ReverseConversion<R> + Clone,
         R: Conversion,
     {
-        let resources: Vec<_> = resources.iter().filter(filter_fn).cloned().collect();
+        let resources = self.find_resources(key_store, resources, filter_fn).await?;
         if resources.is_empty() {
             Err(StorageError::ValueNotFound(error_message).into())
         } else {
-            let pm_futures = resources
-                .into_iter()
-                .map(|pm| async {
-                    pm.convert(
-                        self.get_keymanager_state()
-                            .attach_printable("Missing KeyManagerState")?,
-                        key_store.key.get_inner(),
-                        key_store.merchant_id.clone().into(),
-                    )
-                    .await
-                    .change_context(StorageError::DecryptionError)
-                })
-                .collect::<Vec<_>>();
+            Ok(resources)
+        }
+    }
 
-            let domain_resources = futures::future::try_join_all(pm_futures).await?;
+    /// Returns all the resources matching the filter, which may be none
+    pub async fn find_resources<D, R>(
+        &self,
+        key_store: &MerchantKeyStore,
+        resources: MutexGuard<'_, Vec<D>>,
+        filter_fn: impl Fn(&&D) -> bool,
+    ) -> CustomResult<Vec<R>, StorageError>
+    where
+        D: Sync + ReverseConversion<R> + Clone,
+        R: Conversion,
+    {
+        let resources: Vec<_> = resources.iter().filter(filter_fn).cloned().collect();
+        let pm_futures = resources
+            .into_iter()
+            .map(|pm| async {
+                pm.convert(
+                    self.get_keymanager_state()
+                        .attach_printable("Missing KeyManagerState")?,
+                    key_store.key.get_inner(),
+                    key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(StorageError::DecryptionError)
+            })
+            .collect::<Vec<_>>();
 
-            Ok(domain_resources)
-        }
+        futures::future::try_join_all(pm_futures).await
     }
 
     pub async fn update_resource<D, R>(
@@ -248,6 +350,36 @@ impl MockDb {
     }
 }
 
+/// Whether the value is one of the requested values, when any are requested
+pub fn matches_any<T: PartialEq>(requested: &Option<Vec<T>>, value: Option<&T>) -> bool {
+    requested
+        .as_ref()
+        .is_none_or(|requested| value.is_some_and(|value| requested.contains(value)))
+}
+
+/// Collects the values without duplicates, keeping the order they were first seen in
+pub fn distinct<T: PartialEq>(values: impl Iterator<Item = T>) -> Vec<T> {
+    values.fold(Vec::new(), |mut distinct, value| {
+        if !distinct.contains(&value) {
+            distinct.push(value);
+        }
+        distinct
+    })
+}
+
+/// Applies an `OFFSET` and an optional `LIMIT` to the already ordered items
+pub fn paginate<T>(items: Vec<T>, offset: u32, limit: Option<u32>) -> Vec<T> {
+    items
+        .into_iter()
+        .skip(usize::try_from(offset).unwrap_or(usize::MAX))
+        .take(
+            limit
+                .and_then(|limit| usize::try_from(limit).ok())
+                .unwrap_or(usize::MAX),
+        )
+        .collect()
+}
+
 #[cfg(not(feature = "payouts"))]
 impl PayoutsInterface for MockDb {}
 
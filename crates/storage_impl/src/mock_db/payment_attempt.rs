@@ -3,13 +3,16 @@ use common_utils::errors::CustomResult;
 use common_utils::id_type;
 use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
-#[cfg(feature = "v1")]
-use hyperswitch_domain_models::behaviour::Conversion;
 use hyperswitch_domain_models::{
+    behaviour::Conversion,
     merchant_key_store::MerchantKeyStore,
     payments::payment_attempt::{PaymentAttempt, PaymentAttemptInterface, PaymentAttemptUpdate},
 };
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+use super::distinct;
+#[cfg(feature = "olap")]
+use super::matches_any;
 use super::MockDb;
 use crate::errors::StorageError;
 
@@ -19,142 +22,279 @@ impl PaymentAttemptInterface for MockDb {
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
         &self,
-        _payment_id: &common_utils::id_type::PaymentId,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _attempt_id: &str,
+        payment_id: &common_utils::id_type::PaymentId,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.payment_id == *payment_id
+                && payment_attempt.processor_merchant_id == *processor_merchant_id
+                && payment_attempt.attempt_id == attempt_id
+        })
+        .await
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filters_for_payments(
         &self,
-        _pi: &[hyperswitch_domain_models::payments::PaymentIntent],
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
+        pi: &[hyperswitch_domain_models::payments::PaymentIntent],
+        processor_merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         hyperswitch_domain_models::payments::payment_attempt::PaymentListFilters,
         StorageError,
     > {
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+        let active_attempts = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.processor_merchant_id == *processor_merchant_id
+                    && pi.iter().any(|payment_intent| {
+                        payment_intent.active_attempt.get_id() == payment_attempt.attempt_id
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(
+            hyperswitch_domain_models::payments::payment_attempt::PaymentListFilters {
+                connector: distinct(
+                    active_attempts
+                        .iter()
+                        .filter_map(|payment_attempt| payment_attempt.connector.clone()),
+                ),
+                currency: distinct(
+                    active_attempts
+                        .iter()
+                        .filter_map(|payment_attempt| payment_attempt.currency),
+                ),
+                status: distinct(pi.iter().map(|payment_intent| payment_intent.status)),
+                payment_method: distinct(
+                    active_attempts
+                        .iter()
+                        .filter_map(|payment_attempt| payment_attempt.payment_method),
+                ),
+                payment_method_type: distinct(
+                    active_attempts
+                        .iter()
+                        .filter_map(|payment_attempt| payment_attempt.payment_method_type),
+                ),
+                authentication_type: distinct(
+                    active_attempts
+                        .iter()
+                        .filter_map(|payment_attempt| payment_attempt.authentication_type),
+                ),
+            },
+        )
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_total_count_of_filtered_payment_attempts(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _active_attempt_ids: &[String],
-        _connector: Option<Vec<api_models::enums::Connector>>,
-        _payment_method: Option<Vec<common_enums::PaymentMethod>>,
-        _payment_method_type: Option<Vec<common_enums::PaymentMethodType>>,
-        _authentication_type: Option<Vec<common_enums::AuthenticationType>>,
-        _merchanat_connector_id: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
-        _card_network: Option<Vec<storage_enums::CardNetwork>>,
-        _card_discovery: Option<Vec<storage_enums::CardDiscovery>>,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        active_attempt_ids: &[String],
+        connector: Option<Vec<api_models::enums::Connector>>,
+        payment_method: Option<Vec<common_enums::PaymentMethod>>,
+        payment_method_type: Option<Vec<common_enums::PaymentMethodType>>,
+        authentication_type: Option<Vec<common_enums::AuthenticationType>>,
+        merchant_connector_id: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
+        card_network: Option<Vec<storage_enums::CardNetwork>>,
+        card_discovery: Option<Vec<storage_enums::CardDiscovery>>,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<i64, StorageError> {
-        Err(StorageError::MockDbError)?
+        let connector = connector.map(|connectors| {
+            connectors
+                .iter()
+                .map(|connector| connector.to_string())
+                .collect::<Vec<_>>()
+        });
+        let payment_attempts = self.payment_attempts.lock().await;
+        let count = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.processor_merchant_id == *processor_merchant_id
+                    && active_attempt_ids.contains(&payment_attempt.attempt_id)
+                    && matches_any(&connector, payment_attempt.connector.as_ref())
+                    && matches_any(&payment_method, payment_attempt.payment_method.as_ref())
+                    && matches_any(
+                        &payment_method_type,
+                        payment_attempt.payment_method_type.as_ref(),
+                    )
+                    && matches_any(
+                        &authentication_type,
+                        payment_attempt.authentication_type.as_ref(),
+                    )
+                    && matches_any(
+                        &merchant_connector_id,
+                        payment_attempt.merchant_connector_id.as_ref(),
+                    )
+                    && matches_any(
+                        &card_network,
+                        payment_attempt.extract_card_network().as_ref(),
+                    )
+                    && matches_any(&card_discovery, payment_attempt.card_discovery.as_ref())
+            })
+            .count();
+
+        Ok(i64::try_from(count).unwrap_or(i64::MAX))
     }
 
     #[cfg(all(feature = "v2", feature = "olap"))]
     async fn get_total_count_of_filtered_payment_attempts(
         &self,
-        _merchant_id: &id_type::MerchantId,
-        _active_attempt_ids: &[String],
-        _connector: Option<Vec<api_models::enums::Connector>>,
-        _payment_method_type: Option<Vec<common_enums::PaymentMethod>>,
-        _payment_method_subtype: Option<Vec<common_enums::PaymentMethodType>>,
-        _authentication_type: Option<Vec<common_enums::AuthenticationType>>,
-        _merchant_connector_id: Option<Vec<id_type::MerchantConnectorAccountId>>,
+        merchant_id: &id_type::MerchantId,
+        active_attempt_ids: &[String],
+        connector: Option<Vec<api_models::enums::Connector>>,
+        payment_method_type: Option<Vec<common_enums::PaymentMethod>>,
+        payment_method_subtype: Option<Vec<common_enums::PaymentMethodType>>,
+        authentication_type: Option<Vec<common_enums::AuthenticationType>>,
+        merchant_connector_id: Option<Vec<id_type::MerchantConnectorAccountId>>,
         _card_network: Option<Vec<storage_enums::CardNetwork>>,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<i64, StorageError> {
-        Err(StorageError::MockDbError)?
+        let connector = connector.map(|connectors| {
+            connectors
+                .iter()
+                .map(|connector| connector.to_string())
+                .collect::<Vec<_>>()
+        });
+        let payment_attempts = self.payment_attempts.lock().await;
+        let count = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && active_attempt_ids
+                        .iter()
+                        .any(|attempt_id| attempt_id == payment_attempt.id.get_string_repr())
+                    && matches_any(&connector, payment_attempt.connector.as_ref())
+                    && matches_any(
+                        &payment_method_type,
+                        Some(&payment_attempt.payment_method_type),
+                    )
+                    && matches_any(
+                        &payment_method_subtype,
+                        Some(&payment_attempt.payment_method_subtype),
+                    )
+                    && matches_any(
+                        &authentication_type,
+                        Some(&payment_attempt.authentication_type),
+                    )
+                    && matches_any(
+                        &merchant_connector_id,
+                        payment_attempt.merchant_connector_id.as_ref(),
+                    )
+            })
+            .count();
+
+        Ok(i64::try_from(count).unwrap_or(i64::MAX))
     }
 
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_attempt_id_processor_merchant_id(
         &self,
-        _attempt_id: &str,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.attempt_id == attempt_id
+                && payment_attempt.processor_merchant_id == *processor_merchant_id
+        })
+        .await
     }
 
     #[cfg(feature = "v2")]
     async fn find_payment_attempt_by_id(
         &self,
         _merchant_key_store: &MerchantKeyStore,
-        _attempt_id: &id_type::GlobalAttemptId,
+        attempt_id: &id_type::GlobalAttemptId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| payment_attempt.id == *attempt_id)
+            .await
     }
 
     #[cfg(feature = "v2")]
     async fn find_payment_attempts_by_payment_intent_id(
         &self,
-        _id: &id_type::GlobalPaymentId,
+        id: &id_type::GlobalPaymentId,
         _merchant_key_store: &MerchantKeyStore,
         _storage_scheme: common_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| payment_attempt.payment_id == *id)
+            .cloned()
+            .collect())
     }
 
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_preprocessing_id_processor_merchant_id(
         &self,
-        _preprocessing_id: &str,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
+        preprocessing_id: &str,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.preprocessing_step_id.as_deref() == Some(preprocessing_id)
+                && payment_attempt.processor_merchant_id == *processor_merchant_id
+        })
+        .await
     }
 
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_processor_merchant_id_connector_txn_id(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _connector_txn_id: &str,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        connector_txn_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.processor_merchant_id == *processor_merchant_id
+                && payment_attempt.connector_transaction_id.as_deref() == Some(connector_txn_id)
+        })
+        .await
     }
 
     #[cfg(feature = "v2")]
     async fn find_payment_attempt_by_profile_id_connector_transaction_id(
         &self,
         _merchant_key_store: &MerchantKeyStore,
-        _profile_id: &id_type::ProfileId,
-        _connector_transaction_id: &str,
+        profile_id: &id_type::ProfileId,
+        connector_transaction_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.profile_id == *profile_id
+                && payment_attempt.connector_payment_id.as_deref() == Some(connector_transaction_id)
+        })
+        .await
     }
 
     #[cfg(feature = "v1")]
     async fn find_attempts_by_processor_merchant_id_payment_id(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _payment_id: &common_utils::id_type::PaymentId,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.processor_merchant_id == *processor_merchant_id
+                    && payment_attempt.payment_id == *payment_id
+            })
+            .cloned()
+            .collect())
     }
 
     #[cfg(feature = "v1")]
@@ -260,16 +400,24 @@ impl PaymentAttemptInterface for MockDb {
     async fn insert_payment_attempt(
         &self,
         _merchant_key_store: &MerchantKeyStore,
-        _payment_attempt: PaymentAttempt,
+        payment_attempt: PaymentAttempt,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payment_attempts = self.payment_attempts.lock().await;
+        if payment_attempts
+            .iter()
+            .any(|existing| existing.id == payment_attempt.id)
+        {
+            Err(StorageError::DuplicateValue {
+                entity: "payment_attempt",
+                key: Some(payment_attempt.id.get_string_repr().to_owned()),
+            })?
+        }
+        payment_attempts.push(payment_attempt.clone());
+        Ok(payment_attempt)
     }
 
     #[cfg(feature = "v1")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn update_payment_attempt_with_attempt_id(
         &self,
         this: PaymentAttempt,
@@ -281,8 +429,14 @@ impl PaymentAttemptInterface for MockDb {
 
         let item = payment_attempts
             .iter_mut()
-            .find(|item| item.attempt_id == this.attempt_id)
-            .unwrap();
+            .find(|item| {
+                item.attempt_id == this.attempt_id
+                    && item.processor_merchant_id == this.processor_merchant_id
+            })
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payment attempt found for attempt_id = {}",
+                this.attempt_id
+            )))?;
         let diesel_payment_attempt = this
             .convert()
             .await
@@ -308,31 +462,59 @@ impl PaymentAttemptInterface for MockDb {
     #[cfg(feature = "v2")]
     async fn update_payment_attempt(
         &self,
-        _merchant_key_store: &MerchantKeyStore,
-        _this: PaymentAttempt,
-        _payment_attempt: PaymentAttemptUpdate,
+        merchant_key_store: &MerchantKeyStore,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payment_attempts = self.payment_attempts.lock().await;
+
+        let item = payment_attempts
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payment attempt found for id = {}",
+                this.id.get_string_repr()
+            )))?;
+        let diesel_payment_attempt = this
+            .convert()
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let updated_diesel_payment_attempt =
+            diesel_models::PaymentAttemptUpdateInternal::from(payment_attempt)
+                .apply_changeset(diesel_payment_attempt);
+        *item = PaymentAttempt::convert_back(
+            self.get_keymanager_state()
+                .attach_printable("Missing KeyManagerState")?,
+            updated_diesel_payment_attempt,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)?;
+
+        Ok(item.clone())
     }
 
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_processor_merchant_id(
         &self,
-        _connector_transaction_id: &common_utils::types::ConnectorTransactionId,
-        _payment_id: &common_utils::id_type::PaymentId,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
+        connector_transaction_id: &common_utils::types::ConnectorTransactionId,
+        payment_id: &common_utils::id_type::PaymentId,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.connector_transaction_id.as_ref()
+                == Some(connector_transaction_id.get_id())
+                && payment_attempt.payment_id == *payment_id
+                && payment_attempt.processor_merchant_id == *processor_merchant_id
+        })
+        .await
     }
 
     #[cfg(feature = "v1")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_attempt_last_successful_attempt_by_payment_id_processor_merchant_id(
         &self,
         payment_id: &common_utils::id_type::PaymentId,
@@ -340,22 +522,17 @@ impl PaymentAttemptInterface for MockDb {
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        let payment_attempts = self.payment_attempts.lock().await;
-
-        Ok(payment_attempts
-            .iter()
-            .find(|payment_attempt| {
-                payment_attempt.payment_id == *payment_id
-                    && payment_attempt
-                        .processor_merchant_id
-                        .eq(processor_merchant_id)
-            })
-            .cloned()
-            .unwrap())
+        self.find_latest_payment_attempt(|payment_attempt| {
+            payment_attempt.payment_id == *payment_id
+                && payment_attempt
+                    .processor_merchant_id
+                    .eq(processor_merchant_id)
+                && payment_attempt.status == storage_enums::AttemptStatus::Charged
+        })
+        .await
     }
 
     #[cfg(feature = "v1")]
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_processor_merchant_id(
         &self,
         payment_id: &common_utils::id_type::PaymentId,
@@ -363,40 +540,59 @@ impl PaymentAttemptInterface for MockDb {
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        let payment_attempts = self.payment_attempts.lock().await;
-
-        Ok(payment_attempts
-            .iter()
-            .find(|payment_attempt| {
-                payment_attempt.payment_id == *payment_id
-                    && payment_attempt
-                        .processor_merchant_id
-                        .eq(processor_merchant_id)
-                    && (payment_attempt.status == storage_enums::AttemptStatus::PartialCharged
-                        || payment_attempt.status == storage_enums::AttemptStatus::Charged)
-            })
-            .cloned()
-            .unwrap())
+        self.find_latest_payment_attempt(|payment_attempt| {
+            payment_attempt.payment_id == *payment_id
+                && payment_attempt
+                    .processor_merchant_id
+                    .eq(processor_merchant_id)
+                && (payment_attempt.status == storage_enums::AttemptStatus::PartialCharged
+                    || payment_attempt.status == storage_enums::AttemptStatus::Charged)
+        })
+        .await
     }
 
     #[cfg(feature = "v2")]
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id(
         &self,
         _merchant_key_store: &MerchantKeyStore,
         payment_id: &id_type::GlobalPaymentId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<PaymentAttempt, StorageError> {
+        self.find_latest_payment_attempt(|payment_attempt| {
+            payment_attempt.payment_id == *payment_id
+                && (payment_attempt.status == storage_enums::AttemptStatus::PartialCharged
+                    || payment_attempt.status == storage_enums::AttemptStatus::Charged)
+        })
+        .await
+    }
+}
+
+impl MockDb {
+    async fn find_payment_attempt(
+        &self,
+        filter: impl Fn(&PaymentAttempt) -> bool,
     ) -> CustomResult<PaymentAttempt, StorageError> {
         let payment_attempts = self.payment_attempts.lock().await;
 
-        Ok(payment_attempts
+        payment_attempts
             .iter()
-            .find(|payment_attempt| {
-                payment_attempt.payment_id == *payment_id
-                    && (payment_attempt.status == storage_enums::AttemptStatus::PartialCharged
-                        || payment_attempt.status == storage_enums::AttemptStatus::Charged)
-            })
+            .find(|payment_attempt| filter(payment_attempt))
+            .cloned()
+            .ok_or(StorageError::ValueNotFound("Payment attempt not found".to_string()).into())
+    }
+
+    /// Finds the most recently modified payment attempt matching the filter
+    async fn find_latest_payment_attempt(
+        &self,
+        filter: impl Fn(&PaymentAttempt) -> bool,
+    ) -> CustomResult<PaymentAttempt, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        payment_attempts
+            .iter()
+            .filter(|payment_attempt| filter(payment_attempt))
+            .max_by_key(|payment_attempt| payment_attempt.modified_at)
             .cloned()
-            .unwrap())
+            .ok_or(StorageError::ValueNotFound("Payment attempt not found".to_string()).into())
     }
 }
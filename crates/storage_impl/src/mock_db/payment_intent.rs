@@ -1,18 +1,24 @@
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
-#[cfg(feature = "v1")]
 use error_stack::ResultExt;
-#[cfg(feature = "v1")]
-use hyperswitch_domain_models::behaviour::Conversion;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payments::{
+    payment_attempt::PaymentAttempt, payment_intent::PaymentIntentFetchConstraints,
+};
 use hyperswitch_domain_models::{
+    behaviour::Conversion,
     merchant_key_store::MerchantKeyStore,
     payments::{
         payment_intent::{PaymentIntentInterface, PaymentIntentUpdate},
         PaymentIntent,
     },
 };
+#[cfg(feature = "olap")]
+use time::PrimitiveDateTime;
 
 use super::MockDb;
+#[cfg(feature = "olap")]
+use super::{matches_any, paginate};
 use crate::errors::StorageError;
 
 #[async_trait::async_trait]
@@ -21,93 +27,451 @@ impl PaymentIntentInterface for MockDb {
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn filter_payment_intent_by_constraints(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
-        _key_store: &MerchantKeyStore,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        filters: &PaymentIntentFetchConstraints,
+        key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = match filters {
+            PaymentIntentFetchConstraints::Single { payment_intent_id } => self
+                .payment_intents
+                .lock()
+                .await
+                .iter()
+                .filter(|payment_intent| {
+                    payment_intent.processor_merchant_id == *processor_merchant_id
+                        && payment_intent.get_id() == payment_intent_id
+                })
+                .cloned()
+                .collect::<Vec<_>>(),
+            PaymentIntentFetchConstraints::List(params) => {
+                let (starting_at, ending_at) = self
+                    .get_created_at_bounds(processor_merchant_id, params, key_store, storage_scheme)
+                    .await?;
+                let payment_intents = self
+                    .payment_intents
+                    .lock()
+                    .await
+                    .iter()
+                    .filter(|payment_intent| {
+                        payment_intent.processor_merchant_id == *processor_merchant_id
+                            && params
+                                .customer_id
+                                .as_ref()
+                                .is_none_or(|id| payment_intent.customer_id.as_ref() == Some(id))
+                            && matches_any(&params.profile_id, payment_intent.profile_id.as_ref())
+                            && is_created_within(payment_intent, starting_at, ending_at)
+                            && matches_any(&params.currency, payment_intent.currency.as_ref())
+                            && matches_any(&params.status, Some(&payment_intent.status))
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                paginate(
+                    sort_by_created_at_desc(payment_intents),
+                    params.offset,
+                    params.limit,
+                )
+            }
+        };
+
+        Ok(payment_intents)
     }
 
     #[cfg(all(feature = "v2", feature = "olap"))]
     async fn get_filtered_payment_intents_attempt(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
-        _merchant_key_store: &MerchantKeyStore,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
-    ) -> error_stack::Result<
-        Vec<(
-            PaymentIntent,
-            Option<hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt>,
-        )>,
-        StorageError,
-    > {
-        Err(StorageError::MockDbError)?
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PaymentIntentFetchConstraints,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<(PaymentIntent, Option<PaymentAttempt>)>, StorageError> {
+        let PaymentIntentFetchConstraints::List(params) = constraints;
+        let starting_at = match (params.starting_at, &params.starting_after_id) {
+            (Some(starting_at), _) => Some(starting_at),
+            (None, Some(starting_after_id)) => Some(
+                self.find_payment_intent_by_id(
+                    starting_after_id,
+                    merchant_key_store,
+                    storage_scheme,
+                )
+                .await?
+                .created_at,
+            ),
+            (None, None) => None,
+        };
+        let ending_at = match (params.ending_at, &params.ending_before_id) {
+            (Some(ending_at), _) => Some(ending_at),
+            (None, Some(ending_before_id)) => Some(
+                self.find_payment_intent_by_id(
+                    ending_before_id,
+                    merchant_key_store,
+                    storage_scheme,
+                )
+                .await?
+                .created_at,
+            ),
+            (None, None) => None,
+        };
+        let connector = params.connector.as_ref().map(|connectors| {
+            connectors
+                .iter()
+                .map(|connector| connector.to_string())
+                .collect::<Vec<_>>()
+        });
+        let attempts_requested = connector.is_some()
+            || params.payment_method_type.is_some()
+            || params.payment_method_subtype.is_some()
+            || params.authentication_type.is_some()
+            || params.merchant_connector_id.is_some()
+            || params.card_network.is_some();
+
+        let payment_attempts = self.payment_attempts.lock().await;
+        let mut payment_intents = self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == *merchant_id
+                    && params
+                        .customer_id
+                        .as_ref()
+                        .is_none_or(|id| payment_intent.customer_id.as_ref() == Some(id))
+                    && params
+                        .merchant_order_reference_id
+                        .as_ref()
+                        .is_none_or(|reference_id| {
+                            payment_intent
+                                .merchant_reference_id
+                                .as_ref()
+                                .is_some_and(|id| id.get_string_repr() == reference_id)
+                        })
+                    && params
+                        .profile_id
+                        .as_ref()
+                        .is_none_or(|profile_id| payment_intent.profile_id == *profile_id)
+                    && is_created_within(payment_intent, starting_at, ending_at)
+                    && is_amount_within(
+                        get_payment_intent_amount(payment_intent),
+                        params.amount_filter.as_ref(),
+                    )
+                    && matches_any(
+                        &params.currency,
+                        Some(&payment_intent.amount_details.currency),
+                    )
+                    && matches_any(&params.status, Some(&payment_intent.status))
+                    && params
+                        .payment_id
+                        .as_ref()
+                        .is_none_or(|payment_id| payment_intent.get_id() == payment_id)
+            })
+            .map(|payment_intent| {
+                let payment_attempt = payment_intent
+                    .active_attempt_id
+                    .as_ref()
+                    .and_then(|attempt_id| {
+                        payment_attempts
+                            .iter()
+                            .find(|payment_attempt| payment_attempt.id == *attempt_id)
+                    })
+                    .cloned();
+                (payment_intent.clone(), payment_attempt)
+            })
+            .filter(|(_, payment_attempt)| match payment_attempt {
+                Some(payment_attempt) => {
+                    matches_any(&connector, payment_attempt.connector.as_ref())
+                        && matches_any(
+                            &params.payment_method_type,
+                            Some(&payment_attempt.payment_method_type),
+                        )
+                        && matches_any(
+                            &params.payment_method_subtype,
+                            Some(&payment_attempt.payment_method_subtype),
+                        )
+                        && matches_any(
+                            &params.authentication_type,
+                            Some(&payment_attempt.authentication_type),
+                        )
+                        && matches_any(
+                            &params.merchant_connector_id,
+                            payment_attempt.merchant_connector_id.as_ref(),
+                        )
+                        && matches_any(
+                            &params.card_network,
+                            payment_attempt.extract_card_network().as_ref(),
+                        )
+                }
+                None => !attempts_requested,
+            })
+            .collect::<Vec<_>>();
+        payment_intents
+            .sort_by(|(this, _), (other, _)| compare_payment_intents(this, other, &params.order));
+
+        Ok(paginate(payment_intents, params.offset, params.limit))
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn filter_payment_intents_by_time_range_constraints(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _time_range: &common_utils::types::TimeRange,
-        _key_store: &MerchantKeyStore,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+        key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_filters = (*time_range).into();
+        self.filter_payment_intent_by_constraints(
+            processor_merchant_id,
+            &payment_filters,
+            key_store,
+            storage_scheme,
+        )
+        .await
     }
 
     #[cfg(feature = "olap")]
     async fn get_intent_status_with_count(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
-        _time_range: &common_utils::types::TimeRange,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::IntentStatus, i64)>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+
+        Ok(payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.processor_merchant_id == *processor_merchant_id
+                    && matches_any(&profile_id_list, get_profile_id(payment_intent))
+                    && is_created_within(
+                        payment_intent,
+                        Some(time_range.start_time),
+                        time_range.end_time,
+                    )
+            })
+            .fold(
+                Vec::<(common_enums::IntentStatus, i64)>::new(),
+                |mut status_with_count, payment_intent| {
+                    match status_with_count
+                        .iter_mut()
+                        .find(|(status, _)| *status == payment_intent.status)
+                    {
+                        Some((_, count)) => *count = count.saturating_add(1),
+                        None => status_with_count.push((payment_intent.status, 1)),
+                    }
+                    status_with_count
+                },
+            ))
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filtered_active_attempt_ids_for_total_count(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+        let payment_intents = payment_intents.iter().filter(|payment_intent| {
+            payment_intent.processor_merchant_id == *processor_merchant_id
+                && match constraints {
+                    PaymentIntentFetchConstraints::Single { payment_intent_id } => {
+                        payment_intent.get_id() == payment_intent_id
+                    }
+                    PaymentIntentFetchConstraints::List(params) => {
+                        params
+                            .customer_id
+                            .as_ref()
+                            .is_none_or(|id| payment_intent.customer_id.as_ref() == Some(id))
+                            && params
+                                .merchant_order_reference_id
+                                .as_ref()
+                                .is_none_or(|id| {
+                                    payment_intent.merchant_order_reference_id.as_ref() == Some(id)
+                                })
+                            && matches_any(&params.profile_id, payment_intent.profile_id.as_ref())
+                            && is_created_within(
+                                payment_intent,
+                                params.starting_at,
+                                params.ending_at,
+                            )
+                            && is_amount_within(
+                                get_payment_intent_amount(payment_intent),
+                                params.amount_filter.as_ref(),
+                            )
+                            && matches_any(&params.currency, payment_intent.currency.as_ref())
+                            && matches_any(&params.status, Some(&payment_intent.status))
+                    }
+                }
+        });
+
+        Ok(sort_by_created_at_desc(payment_intents.cloned().collect())
+            .iter()
+            .map(|payment_intent| payment_intent.active_attempt.get_id())
+            .collect())
     }
 
     #[cfg(all(feature = "v2", feature = "olap"))]
     async fn get_filtered_active_attempt_ids_for_total_count(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<Option<String>>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let PaymentIntentFetchConstraints::List(params) = constraints;
+        let payment_intents = self.payment_intents.lock().await;
+        let payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == *merchant_id
+                    && params
+                        .customer_id
+                        .as_ref()
+                        .is_none_or(|id| payment_intent.customer_id.as_ref() == Some(id))
+                    && params
+                        .merchant_order_reference_id
+                        .as_ref()
+                        .is_none_or(|reference_id| {
+                            payment_intent
+                                .merchant_reference_id
+                                .as_ref()
+                                .is_some_and(|id| id.get_string_repr() == reference_id)
+                        })
+                    && params
+                        .profile_id
+                        .as_ref()
+                        .is_none_or(|profile_id| payment_intent.profile_id == *profile_id)
+                    && is_created_within(payment_intent, params.starting_at, params.ending_at)
+                    && is_amount_within(
+                        get_payment_intent_amount(payment_intent),
+                        params.amount_filter.as_ref(),
+                    )
+                    && matches_any(
+                        &params.currency,
+                        Some(&payment_intent.amount_details.currency),
+                    )
+                    && matches_any(&params.status, Some(&payment_intent.status))
+                    && params
+                        .payment_id
+                        .as_ref()
+                        .is_none_or(|payment_id| payment_intent.get_id() == payment_id)
+            })
+            .cloned()
+            .collect();
+
+        Ok(sort_by_created_at_desc(payment_intents)
+            .iter()
+            .map(|payment_intent| {
+                payment_intent
+                    .active_attempt_id
+                    .as_ref()
+                    .map(|attempt_id| attempt_id.get_string_repr().to_owned())
+            })
+            .collect())
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filtered_payment_intents_attempt(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
-        _key_store: &MerchantKeyStore,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
-    ) -> error_stack::Result<
-        Vec<(
-            PaymentIntent,
-            hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
-        )>,
-        StorageError,
-    > {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PaymentIntentFetchConstraints,
+        key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<(PaymentIntent, PaymentAttempt)>, StorageError> {
+        let params = match constraints {
+            PaymentIntentFetchConstraints::Single { .. } => None,
+            PaymentIntentFetchConstraints::List(params) => Some(params),
+        };
+        let (starting_at, ending_at) = match params {
+            Some(params) => {
+                self.get_created_at_bounds(processor_merchant_id, params, key_store, storage_scheme)
+                    .await?
+            }
+            None => (None, None),
+        };
+        let connector = params
+            .and_then(|params| params.connector.as_ref())
+            .map(|connectors| {
+                connectors
+                    .iter()
+                    .map(|connector| connector.to_string())
+                    .collect::<Vec<_>>()
+            });
+
+        let payment_attempts = self.payment_attempts.lock().await;
+        let payment_intents = self.payment_intents.lock().await;
+        let mut payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| payment_intent.processor_merchant_id == *processor_merchant_id)
+            .filter_map(|payment_intent| {
+                payment_attempts
+                    .iter()
+                    .find(|payment_attempt| {
+                        payment_attempt.processor_merchant_id == *processor_merchant_id
+                            && payment_attempt.attempt_id == payment_intent.active_attempt.get_id()
+                    })
+                    .map(|payment_attempt| (payment_intent.clone(), payment_attempt.clone()))
+            })
+            .filter(|(payment_intent, payment_attempt)| match constraints {
+                PaymentIntentFetchConstraints::Single { payment_intent_id } => {
+                    payment_intent.get_id() == payment_intent_id
+                }
+                PaymentIntentFetchConstraints::List(params) => {
+                    params
+                        .customer_id
+                        .as_ref()
+                        .is_none_or(|id| payment_intent.customer_id.as_ref() == Some(id))
+                        && params
+                            .merchant_order_reference_id
+                            .as_ref()
+                            .is_none_or(|id| {
+                                payment_intent.merchant_order_reference_id.as_ref() == Some(id)
+                            })
+                        && matches_any(&params.profile_id, payment_intent.profile_id.as_ref())
+                        && is_created_within(payment_intent, starting_at, ending_at)
+                        && is_amount_within(
+                            get_payment_intent_amount(payment_intent),
+                            params.amount_filter.as_ref(),
+                        )
+                        && matches_any(&params.currency, payment_intent.currency.as_ref())
+                        && matches_any(&connector, payment_attempt.connector.as_ref())
+                        && matches_any(&params.status, Some(&payment_intent.status))
+                        && matches_any(
+                            &params.payment_method,
+                            payment_attempt.payment_method.as_ref(),
+                        )
+                        && matches_any(
+                            &params.payment_method_type,
+                            payment_attempt.payment_method_type.as_ref(),
+                        )
+                        && matches_any(
+                            &params.authentication_type,
+                            payment_attempt.authentication_type.as_ref(),
+                        )
+                        && matches_any(
+                            &params.merchant_connector_id,
+                            payment_attempt.merchant_connector_id.as_ref(),
+                        )
+                        && matches_any(
+                            &params.card_network,
+                            payment_attempt.extract_card_network().as_ref(),
+                        )
+                        && matches_any(
+                            &params.card_discovery,
+                            payment_attempt.card_discovery.as_ref(),
+                        )
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok(match params {
+            Some(params) => {
+                payment_intents.sort_by(|(this, _), (other, _)| {
+                    compare_payment_intents(this, other, &params.order)
+                });
+                paginate(payment_intents, params.offset, params.limit)
+            }
+            None => payment_intents,
+        })
     }
 
     #[allow(clippy::panic)]
@@ -123,8 +487,6 @@ impl PaymentIntentInterface for MockDb {
     }
 
     #[cfg(feature = "v1")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn update_payment_intent(
         &self,
         this: PaymentIntent,
@@ -139,7 +501,10 @@ impl PaymentIntentInterface for MockDb {
                 item.get_id() == this.get_id()
                     && item.processor_merchant_id == this.processor_merchant_id
             })
-            .unwrap();
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payment intent found for payment_id = {}",
+                this.get_id().get_string_repr()
+            )))?;
 
         let diesel_payment_intent_update = diesel_models::PaymentIntentUpdate::from(update);
         let diesel_payment_intent = payment_intent
@@ -162,21 +527,45 @@ impl PaymentIntentInterface for MockDb {
     }
 
     #[cfg(feature = "v2")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn update_payment_intent(
         &self,
-        _this: PaymentIntent,
-        _update: PaymentIntentUpdate,
-        _key_store: &MerchantKeyStore,
+        this: PaymentIntent,
+        update: PaymentIntentUpdate,
+        key_store: &MerchantKeyStore,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentIntent, StorageError> {
-        todo!()
+        let mut payment_intents = self.payment_intents.lock().await;
+        let payment_intent = payment_intents
+            .iter_mut()
+            .find(|item| item.get_id() == this.get_id())
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payment intent found for id = {}",
+                this.get_id().get_string_repr()
+            )))?;
+
+        let diesel_payment_intent_update =
+            diesel_models::PaymentIntentUpdateInternal::try_from(update)
+                .change_context(StorageError::DeserializationFailed)?;
+        let diesel_payment_intent = payment_intent
+            .clone()
+            .convert()
+            .await
+            .change_context(StorageError::EncryptionError)?;
+
+        *payment_intent = PaymentIntent::convert_back(
+            self.get_keymanager_state()
+                .attach_printable("Missing KeyManagerState")?,
+            diesel_payment_intent_update.apply_changeset(diesel_payment_intent),
+            key_store.key.get_inner(),
+            key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)?;
+
+        Ok(payment_intent.clone())
     }
 
     #[cfg(feature = "v1")]
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_intent_by_payment_id_processor_merchant_id(
         &self,
         payment_id: &common_utils::id_type::PaymentId,
@@ -186,7 +575,7 @@ impl PaymentIntentInterface for MockDb {
     ) -> CustomResult<PaymentIntent, StorageError> {
         let payment_intents = self.payment_intents.lock().await;
 
-        Ok(payment_intents
+        payment_intents
             .iter()
             .find(|payment_intent| {
                 payment_intent.get_id() == payment_id
@@ -195,7 +584,13 @@ impl PaymentIntentInterface for MockDb {
                         .eq(processor_merchant_id)
             })
             .cloned()
-            .unwrap())
+            .ok_or(
+                StorageError::ValueNotFound(format!(
+                    "No payment intent found for payment_id = {}",
+                    payment_id.get_string_repr()
+                ))
+                .into(),
+            )
     }
 
     #[cfg(feature = "v2")]
@@ -237,3 +632,120 @@ impl PaymentIntentInterface for MockDb {
         Ok(payment_intent.clone())
     }
 }
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+impl MockDb {
+    /// Resolves the `created_at` window of list constraints, looking up the
+    /// `starting_after_id` and `ending_before_id` cursors when no timestamps are given
+    async fn get_created_at_bounds(
+        &self,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        params: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentListParams,
+        key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<(Option<PrimitiveDateTime>, Option<PrimitiveDateTime>), StorageError> {
+        let starting_at = match (params.starting_at, &params.starting_after_id) {
+            (Some(starting_at), _) => Some(starting_at),
+            (None, Some(starting_after_id)) => Some(
+                self.find_payment_intent_by_payment_id_processor_merchant_id(
+                    starting_after_id,
+                    processor_merchant_id,
+                    key_store,
+                    storage_scheme,
+                )
+                .await?
+                .created_at,
+            ),
+            (None, None) => None,
+        };
+        let ending_at = match (params.ending_at, &params.ending_before_id) {
+            (Some(ending_at), _) => Some(ending_at),
+            (None, Some(ending_before_id)) => Some(
+                self.find_payment_intent_by_payment_id_processor_merchant_id(
+                    ending_before_id,
+                    processor_merchant_id,
+                    key_store,
+                    storage_scheme,
+                )
+                .await?
+                .created_at,
+            ),
+            (None, None) => None,
+        };
+
+        Ok((starting_at, ending_at))
+    }
+}
+
+#[cfg(feature = "olap")]
+fn is_created_within(
+    payment_intent: &PaymentIntent,
+    starting_at: Option<PrimitiveDateTime>,
+    ending_at: Option<PrimitiveDateTime>,
+) -> bool {
+    starting_at.is_none_or(|starting_at| payment_intent.created_at >= starting_at)
+        && ending_at.is_none_or(|ending_at| payment_intent.created_at <= ending_at)
+}
+
+#[cfg(feature = "olap")]
+fn is_amount_within(
+    amount: i64,
+    amount_filter: Option<&api_models::payments::AmountFilter>,
+) -> bool {
+    amount_filter.is_none_or(|amount_filter| {
+        amount_filter
+            .start_amount
+            .is_none_or(|start_amount| amount >= start_amount)
+            && amount_filter
+                .end_amount
+                .is_none_or(|end_amount| amount <= end_amount)
+    })
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_payment_intent_amount(payment_intent: &PaymentIntent) -> i64 {
+    payment_intent.amount.get_amount_as_i64()
+}
+
+#[cfg(all(feature = "v2", feature = "olap"))]
+fn get_payment_intent_amount(payment_intent: &PaymentIntent) -> i64 {
+    payment_intent
+        .amount_details
+        .order_amount
+        .get_amount_as_i64()
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_profile_id(payment_intent: &PaymentIntent) -> Option<&common_utils::id_type::ProfileId> {
+    payment_intent.profile_id.as_ref()
+}
+
+#[cfg(all(feature = "v2", feature = "olap"))]
+fn get_profile_id(payment_intent: &PaymentIntent) -> Option<&common_utils::id_type::ProfileId> {
+    Some(&payment_intent.profile_id)
+}
+
+#[cfg(feature = "olap")]
+fn compare_payment_intents(
+    this: &PaymentIntent,
+    other: &PaymentIntent,
+    order: &api_models::payments::Order,
+) -> std::cmp::Ordering {
+    use api_models::payments::{SortBy, SortOn};
+
+    let ordering = match order.on {
+        SortOn::Amount => get_payment_intent_amount(this).cmp(&get_payment_intent_amount(other)),
+        SortOn::Created => this.created_at.cmp(&other.created_at),
+        SortOn::Modified => this.modified_at.cmp(&other.modified_at),
+    };
+    match order.by {
+        SortBy::Asc => ordering,
+        SortBy::Desc => ordering.reverse(),
+    }
+}
+
+#[cfg(feature = "olap")]
+fn sort_by_created_at_desc(mut payment_intents: Vec<PaymentIntent>) -> Vec<PaymentIntent> {
+    payment_intents.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+    payment_intents
+}
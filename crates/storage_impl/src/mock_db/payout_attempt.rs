@@ -1,77 +1,188 @@
+use std::str::FromStr;
+
+use api_models::enums::PayoutConnectors;
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
 use hyperswitch_domain_models::payouts::{
     payout_attempt::{
         PayoutAttempt, PayoutAttemptInterface, PayoutAttemptNew, PayoutAttemptUpdate,
+        PayoutListFilters,
     },
     payouts::Payouts,
 };
 
-use super::MockDb;
-use crate::errors::StorageError;
+use super::{distinct, MockDb};
+use crate::{errors::StorageError, DataModelExt};
 
 #[async_trait::async_trait]
 impl PayoutAttemptInterface for MockDb {
     type Error = StorageError;
     async fn update_payout_attempt(
         &self,
-        _this: &PayoutAttempt,
-        _payout_attempt_update: PayoutAttemptUpdate,
+        this: &PayoutAttempt,
+        payout_attempt_update: PayoutAttemptUpdate,
         _payouts: &Payouts,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payout_attempts = self.payout_attempt.lock().await;
+        let payout_attempt = payout_attempts
+            .iter_mut()
+            .find(|payout_attempt| {
+                payout_attempt.merchant_id == this.merchant_id
+                    && payout_attempt.payout_attempt_id == this.payout_attempt_id
+            })
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payout attempt found for payout_attempt_id = {}",
+                this.payout_attempt_id
+            )))?;
+        *payout_attempt = payout_attempt_update
+            .to_storage_model()
+            .apply_changeset(payout_attempt.clone());
+
+        Ok(PayoutAttempt::from_storage_model(payout_attempt.clone()))
     }
 
     async fn insert_payout_attempt(
         &self,
-        _payout_attempt: PayoutAttemptNew,
+        new: PayoutAttemptNew,
         _payouts: &Payouts,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payout_attempts = self.payout_attempt.lock().await;
+        if payout_attempts.iter().any(|payout_attempt| {
+            payout_attempt.merchant_id == new.merchant_id
+                && payout_attempt.payout_attempt_id == new.payout_attempt_id
+        }) {
+            Err(StorageError::DuplicateValue {
+                entity: "payout_attempt",
+                key: Some(new.payout_attempt_id.clone()),
+            })?
+        }
+        let created_attempt = PayoutAttempt {
+            payout_attempt_id: new.payout_attempt_id,
+            payout_id: new.payout_id,
+            additional_payout_method_data: new.additional_payout_method_data,
+            customer_id: new.customer_id,
+            merchant_id: new.merchant_id,
+            address_id: new.address_id,
+            connector: new.connector,
+            connector_payout_id: new.connector_payout_id,
+            payout_token: new.payout_token,
+            status: new.status,
+            is_eligible: new.is_eligible,
+            error_message: new.error_message,
+            error_code: new.error_code,
+            business_country: new.business_country,
+            business_label: new.business_label,
+            created_at: new.created_at,
+            last_modified_at: new.last_modified_at,
+            profile_id: new.profile_id,
+            merchant_connector_id: new.merchant_connector_id,
+            routing_info: new.routing_info,
+            unified_code: new.unified_code,
+            unified_message: new.unified_message,
+            merchant_order_reference_id: new.merchant_order_reference_id,
+            payout_connector_metadata: new.payout_connector_metadata,
+            processor_merchant_id: new.processor_merchant_id,
+            created_by: new.created_by,
+        };
+        payout_attempts.push(created_attempt.clone().to_storage_model());
+
+        Ok(created_attempt)
     }
 
     async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_attempt_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payout_attempt(|payout_attempt| {
+            payout_attempt.merchant_id == *merchant_id
+                && payout_attempt.payout_attempt_id == payout_attempt_id
+        })
+        .await
     }
 
     async fn find_payout_attempt_by_merchant_id_connector_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _connector_payout_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_payout_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payout_attempt(|payout_attempt| {
+            payout_attempt.merchant_id == *merchant_id
+                && payout_attempt.connector_payout_id.as_deref() == Some(connector_payout_id)
+        })
+        .await
     }
 
     async fn get_filters_for_payouts(
         &self,
-        _payouts: &[Payouts],
-        _merchant_id: &common_utils::id_type::MerchantId,
+        payouts: &[Payouts],
+        merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
-    ) -> CustomResult<
-        hyperswitch_domain_models::payouts::payout_attempt::PayoutListFilters,
-        StorageError,
-    > {
-        Err(StorageError::MockDbError)?
+    ) -> CustomResult<PayoutListFilters, StorageError> {
+        let active_attempt_ids = payouts
+            .iter()
+            .map(|payout| {
+                format!(
+                    "{}_{}",
+                    payout.payout_id.get_string_repr(),
+                    payout.attempt_count
+                )
+            })
+            .collect::<Vec<_>>();
+        let payout_attempts = self.payout_attempt.lock().await;
+        let payouts = payouts
+            .iter()
+            .filter(|payout| payout.merchant_id == *merchant_id)
+            .collect::<Vec<_>>();
+
+        Ok(PayoutListFilters {
+            connector: distinct(
+                payout_attempts
+                    .iter()
+                    .filter(|payout_attempt| {
+                        payout_attempt.merchant_id == *merchant_id
+                            && active_attempt_ids.contains(&payout_attempt.payout_attempt_id)
+                    })
+                    .filter_map(|payout_attempt| payout_attempt.connector.as_deref())
+                    .filter_map(|connector| PayoutConnectors::from_str(connector).ok()),
+            ),
+            currency: distinct(payouts.iter().map(|payout| payout.destination_currency)),
+            status: distinct(payouts.iter().map(|payout| payout.status)),
+            payout_method: distinct(payouts.iter().filter_map(|payout| payout.payout_type)),
+        })
     }
 
     async fn find_payout_attempt_by_merchant_id_merchant_order_reference_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _merchant_order_reference_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_order_reference_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        Err(StorageError::MockDbError)?
+        self.find_payout_attempt(|payout_attempt| {
+            payout_attempt.merchant_id == *merchant_id
+                && payout_attempt.merchant_order_reference_id.as_deref()
+                    == Some(merchant_order_reference_id)
+        })
+        .await
+    }
+}
+
+impl MockDb {
+    async fn find_payout_attempt(
+        &self,
+        filter: impl Fn(&diesel_models::PayoutAttempt) -> bool,
+    ) -> CustomResult<PayoutAttempt, StorageError> {
+        let payout_attempts = self.payout_attempt.lock().await;
+
+        payout_attempts
+            .iter()
+            .find(|payout_attempt| filter(payout_attempt))
+            .cloned()
+            .map(PayoutAttempt::from_storage_model)
+            .ok_or(StorageError::ValueNotFound("Payout attempt not found".to_string()).into())
     }
 }
@@ -1,72 +1,178 @@
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payouts::PayoutFetchConstraints;
 use hyperswitch_domain_models::payouts::{
     payout_attempt::PayoutAttempt,
     payouts::{Payouts, PayoutsInterface, PayoutsNew, PayoutsUpdate},
 };
 
-use crate::{errors::StorageError, MockDb};
+#[cfg(feature = "olap")]
+use super::{matches_any, paginate};
+use crate::{errors::StorageError, DataModelExt, MockDb};
 
 #[async_trait::async_trait]
 impl PayoutsInterface for MockDb {
     type Error = StorageError;
     async fn find_payout_by_merchant_id_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_id: &common_utils::id_type::PayoutId,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_id: &common_utils::id_type::PayoutId,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_optional_payout_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
+            .await?
+            .ok_or(
+                StorageError::ValueNotFound(format!(
+                    "No payout found for payout_id = {}",
+                    payout_id.get_string_repr()
+                ))
+                .into(),
+            )
     }
 
     async fn update_payout(
         &self,
-        _this: &Payouts,
-        _payout_update: PayoutsUpdate,
+        this: &Payouts,
+        payout_update: PayoutsUpdate,
         _payout_attempt: &PayoutAttempt,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payouts = self.payouts.lock().await;
+        let payout = payouts
+            .iter_mut()
+            .find(|payout| {
+                payout.merchant_id == this.merchant_id && payout.payout_id == this.payout_id
+            })
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payout found for payout_id = {}",
+                this.payout_id.get_string_repr()
+            )))?;
+        *payout = payout_update
+            .to_storage_model()
+            .apply_changeset(payout.clone());
+
+        Ok(Payouts::from_storage_model(payout.clone()))
     }
 
     async fn insert_payout(
         &self,
-        _payout: PayoutsNew,
+        new: PayoutsNew,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payouts = self.payouts.lock().await;
+        if payouts.iter().any(|payout| {
+            payout.merchant_id == new.merchant_id && payout.payout_id == new.payout_id
+        }) {
+            Err(StorageError::DuplicateValue {
+                entity: "payouts",
+                key: Some(new.payout_id.get_string_repr().to_owned()),
+            })?
+        }
+        let created_payout = Payouts {
+            payout_id: new.payout_id,
+            merchant_id: new.merchant_id,
+            customer_id: new.customer_id,
+            address_id: new.address_id,
+            payout_type: new.payout_type,
+            payout_method_id: new.payout_method_id,
+            amount: new.amount,
+            destination_currency: new.destination_currency,
+            source_currency: new.source_currency,
+            description: new.description,
+            recurring: new.recurring,
+            auto_fulfill: new.auto_fulfill,
+            return_url: new.return_url,
+            entity_type: new.entity_type,
+            metadata: new.metadata,
+            created_at: new.created_at,
+            last_modified_at: new.last_modified_at,
+            profile_id: new.profile_id,
+            status: new.status,
+            attempt_count: new.attempt_count,
+            confirm: new.confirm,
+            payout_link_id: new.payout_link_id,
+            client_secret: new.client_secret,
+            priority: new.priority,
+            organization_id: new.organization_id,
+            processor_merchant_id: new.processor_merchant_id,
+            created_by: new.created_by,
+        };
+        payouts.push(created_payout.clone().to_storage_model());
+
+        Ok(created_payout)
     }
 
     async fn find_optional_payout_by_merchant_id_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_id: &common_utils::id_type::PayoutId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_id: &common_utils::id_type::PayoutId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Option<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payouts = self.payouts.lock().await;
+
+        Ok(payouts
+            .iter()
+            .find(|payout| payout.merchant_id == *merchant_id && payout.payout_id == *payout_id)
+            .cloned()
+            .map(Payouts::from_storage_model))
     }
 
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &PayoutFetchConstraints,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let (starting_at, ending_at) = self
+            .get_payout_created_at_bounds(merchant_id, filters, storage_scheme)
+            .await?;
+        let mut payouts = self
+            .payouts
+            .lock()
+            .await
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == *merchant_id
+                    && match filters {
+                        PayoutFetchConstraints::Single { payout_id } => {
+                            payout.payout_id == *payout_id
+                        }
+                        PayoutFetchConstraints::List(params) => {
+                            params
+                                .customer_id
+                                .as_ref()
+                                .is_none_or(|id| payout.customer_id.as_ref() == Some(id))
+                                && params
+                                    .profile_id
+                                    .as_ref()
+                                    .is_none_or(|profile_id| payout.profile_id == *profile_id)
+                                && starting_at
+                                    .is_none_or(|starting_at| payout.created_at >= starting_at)
+                                && ending_at.is_none_or(|ending_at| payout.created_at <= ending_at)
+                                && matches_any(&params.currency, Some(&payout.destination_currency))
+                                && matches_any(&params.status, Some(&payout.status))
+                        }
+                    }
+            })
+            .cloned()
+            .map(Payouts::from_storage_model)
+            .collect::<Vec<_>>();
+        payouts.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+
+        Ok(match filters {
+            PayoutFetchConstraints::Single { .. } => payouts,
+            PayoutFetchConstraints::List(params) => paginate(payouts, params.offset, params.limit),
+        })
     }
 
     #[cfg(feature = "olap")]
     async fn filter_payouts_and_attempts(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &PayoutFetchConstraints,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         Vec<(
             Payouts,
@@ -76,52 +182,301 @@ impl PayoutsInterface for MockDb {
         )>,
         StorageError,
     > {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let (starting_at, ending_at) = self
+            .get_payout_created_at_bounds(merchant_id, filters, storage_scheme)
+            .await?;
+        let connector = match filters {
+            PayoutFetchConstraints::Single { .. } => None,
+            PayoutFetchConstraints::List(params) => params.connector.as_ref().map(|connectors| {
+                connectors
+                    .iter()
+                    .map(|connector| connector.to_string())
+                    .collect::<Vec<_>>()
+            }),
+        };
+
+        let payout_attempts = self.payout_attempt.lock().await;
+        let customers = self.customers.lock().await;
+        let addresses = self.addresses.lock().await;
+        let mut payouts = self
+            .payouts
+            .lock()
+            .await
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == *merchant_id
+                    && match filters {
+                        PayoutFetchConstraints::Single { payout_id } => {
+                            payout.payout_id == *payout_id
+                        }
+                        PayoutFetchConstraints::List(params) => {
+                            params
+                                .customer_id
+                                .as_ref()
+                                .is_none_or(|id| payout.customer_id.as_ref() == Some(id))
+                                && params
+                                    .profile_id
+                                    .as_ref()
+                                    .is_none_or(|profile_id| payout.profile_id == *profile_id)
+                                && starting_at
+                                    .is_none_or(|starting_at| payout.created_at >= starting_at)
+                                && ending_at.is_none_or(|ending_at| payout.created_at <= ending_at)
+                                && matches_any(&params.currency, Some(&payout.destination_currency))
+                                && matches_any(&params.status, Some(&payout.status))
+                                && matches_any(&params.payout_method, payout.payout_type.as_ref())
+                        }
+                    }
+            })
+            .flat_map(|payout| {
+                payout_attempts
+                    .iter()
+                    .filter(|payout_attempt| {
+                        payout_attempt.payout_id == payout.payout_id
+                            && matches_any(&connector, payout_attempt.connector.as_ref())
+                            && match filters {
+                                PayoutFetchConstraints::Single { .. } => true,
+                                PayoutFetchConstraints::List(params) => params
+                                    .merchant_order_reference_id
+                                    .as_ref()
+                                    .is_none_or(|reference_id| {
+                                        payout_attempt.merchant_order_reference_id.as_ref()
+                                            == Some(reference_id)
+                                    }),
+                            }
+                    })
+                    .map(|payout_attempt| {
+                        let customer = payout.customer_id.as_ref().and_then(|customer_id| {
+                            customers
+                                .iter()
+                                .find(|customer| {
+                                    customer.merchant_id == *merchant_id
+                                        && get_customer_id(customer) == Some(customer_id)
+                                })
+                                .cloned()
+                        });
+                        let address = payout.address_id.as_ref().and_then(|address_id| {
+                            addresses
+                                .iter()
+                                .find(|address| address.address_id == *address_id)
+                                .cloned()
+                        });
+                        (
+                            Payouts::from_storage_model(payout.clone()),
+                            PayoutAttempt::from_storage_model(payout_attempt.clone()),
+                            customer,
+                            address,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        payouts.sort_by(|(this, ..), (other, ..)| other.created_at.cmp(&this.created_at));
+
+        Ok(match filters {
+            PayoutFetchConstraints::Single { .. } => payouts,
+            PayoutFetchConstraints::List(params) => paginate(payouts, params.offset, params.limit),
+        })
     }
 
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_time_range_constraints(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _time_range: &common_utils::types::TimeRange,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payout_filters = (*time_range).into();
+        self.filter_payouts_by_constraints(merchant_id, &payout_filters, storage_scheme)
+            .await
     }
 
     #[cfg(feature = "olap")]
     async fn get_total_count_of_filtered_payouts(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _active_payout_ids: &[common_utils::id_type::PayoutId],
-        _connector: Option<Vec<api_models::enums::PayoutConnectors>>,
-        _currency: Option<Vec<storage_enums::Currency>>,
-        _status: Option<Vec<storage_enums::PayoutStatus>>,
-        _payout_method: Option<Vec<storage_enums::PayoutType>>,
+        merchant_id: &common_utils::id_type::MerchantId,
+        active_payout_ids: &[common_utils::id_type::PayoutId],
+        connector: Option<Vec<api_models::enums::PayoutConnectors>>,
+        currency: Option<Vec<storage_enums::Currency>>,
+        status: Option<Vec<storage_enums::PayoutStatus>>,
+        payout_method: Option<Vec<storage_enums::PayoutType>>,
     ) -> CustomResult<i64, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let connector = connector.map(|connectors| {
+            connectors
+                .iter()
+                .map(|connector| connector.to_string())
+                .collect::<Vec<_>>()
+        });
+        let payout_attempts = self.payout_attempt.lock().await;
+        let payouts = self.payouts.lock().await;
+        let count = payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == *merchant_id
+                    && active_payout_ids.contains(&payout.payout_id)
+                    && matches_any(&currency, Some(&payout.destination_currency))
+                    && matches_any(&status, Some(&payout.status))
+                    && matches_any(&payout_method, payout.payout_type.as_ref())
+            })
+            .map(|payout| {
+                payout_attempts
+                    .iter()
+                    .filter(|payout_attempt| {
+                        payout_attempt.payout_id == payout.payout_id
+                            && matches_any(&connector, payout_attempt.connector.as_ref())
+                    })
+                    .count()
+            })
+            .sum::<usize>();
+
+        Ok(i64::try_from(count).unwrap_or(i64::MAX))
     }
 
     #[cfg(feature = "olap")]
     async fn filter_active_payout_ids_by_constraints(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PayoutFetchConstraints,
     ) -> CustomResult<Vec<common_utils::id_type::PayoutId>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payout_attempts = self.payout_attempt.lock().await;
+        let mut payouts = self
+            .payouts
+            .lock()
+            .await
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == *merchant_id
+                    && match constraints {
+                        PayoutFetchConstraints::Single { payout_id } => {
+                            payout.payout_id == *payout_id
+                        }
+                        PayoutFetchConstraints::List(params) => {
+                            params
+                                .customer_id
+                                .as_ref()
+                                .is_none_or(|id| payout.customer_id.as_ref() == Some(id))
+                                && params
+                                    .profile_id
+                                    .as_ref()
+                                    .is_none_or(|profile_id| payout.profile_id == *profile_id)
+                                && params
+                                    .starting_at
+                                    .is_none_or(|starting_at| payout.created_at >= starting_at)
+                                && params
+                                    .ending_at
+                                    .is_none_or(|ending_at| payout.created_at <= ending_at)
+                                && matches_any(&params.currency, Some(&payout.destination_currency))
+                                && matches_any(&params.status, Some(&payout.status))
+                        }
+                    }
+            })
+            .flat_map(|payout| {
+                payout_attempts
+                    .iter()
+                    .filter(|payout_attempt| payout_attempt.payout_id == payout.payout_id)
+                    .map(|_| payout.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        payouts.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+
+        Ok(payouts.into_iter().map(|payout| payout.payout_id).collect())
     }
 
     #[cfg(feature = "olap")]
     async fn get_payout_intent_status_with_count(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
-        _time_range: &common_utils::types::TimeRange,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::PayoutStatus, i64)>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let payouts = self.payouts.lock().await;
+
+        Ok(payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == *merchant_id
+                    && matches_any(&profile_id_list, Some(&payout.profile_id))
+                    && payout.created_at >= time_range.start_time
+                    && time_range
+                        .end_time
+                        .is_none_or(|end_time| payout.created_at <= end_time)
+            })
+            .fold(
+                Vec::<(common_enums::PayoutStatus, i64)>::new(),
+                |mut status_with_count, payout| {
+                    match status_with_count
+                        .iter_mut()
+                        .find(|(status, _)| *status == payout.status)
+                    {
+                        Some((_, count)) => *count = count.saturating_add(1),
+                        None => status_with_count.push((payout.status, 1)),
+                    }
+                    status_with_count
+                },
+            ))
     }
 }
+
+#[cfg(feature = "olap")]
+impl MockDb {
+    /// Resolves the `created_at` window of payout list constraints, looking up the
+    /// `starting_after_id` and `ending_before_id` cursors when no timestamps are given
+    async fn get_payout_created_at_bounds(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &PayoutFetchConstraints,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<
+        (
+            Option<time::PrimitiveDateTime>,
+            Option<time::PrimitiveDateTime>,
+        ),
+        StorageError,
+    > {
+        let PayoutFetchConstraints::List(params) = constraints else {
+            return Ok((None, None));
+        };
+        let starting_at = match (params.starting_at, params.starting_after_id.as_ref()) {
+            (Some(starting_at), _) => Some(starting_at),
+            (None, Some(starting_after_id)) => Some(
+                self.find_payout_by_merchant_id_payout_id(
+                    merchant_id,
+                    starting_after_id,
+                    storage_scheme,
+                )
+                .await?
+                .created_at,
+            ),
+            (None, None) => None,
+        };
+        let ending_at = match (params.ending_at, params.ending_before_id.as_ref()) {
+            (Some(ending_at), _) => Some(ending_at),
+            (None, Some(ending_before_id)) => Some(
+                self.find_payout_by_merchant_id_payout_id(
+                    merchant_id,
+                    ending_before_id,
+                    storage_scheme,
+                )
+                .await?
+                .created_at,
+            ),
+            (None, None) => None,
+        };
+
+        Ok((starting_at, ending_at))
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_customer_id(
+    customer: &diesel_models::Customer,
+) -> Option<&common_utils::id_type::CustomerId> {
+    Some(&customer.customer_id)
+}
+
+#[cfg(all(feature = "v2", feature = "olap"))]
+fn get_customer_id(
+    customer: &diesel_models::Customer,
+) -> Option<&common_utils::id_type::CustomerId> {
+    customer.merchant_reference_id.as_ref()
+}
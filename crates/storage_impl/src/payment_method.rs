@@ -897,15 +897,18 @@ impl PaymentMethodInterface for MockDb {
         .await
     }
 
-    // Need to fix this once we complete v2 payment method
     #[cfg(feature = "v2")]
     async fn find_payment_method_list_by_global_customer_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _id: &id_type::GlobalCustomerId,
+        key_store: &MerchantKeyStore,
+        id: &id_type::GlobalCustomerId,
         _limit: Option<i64>,
     ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
-        todo!()
+        let payment_methods = self.payment_methods.lock().await;
+        let find_pm_by = |pm: &&PaymentMethod| pm.customer_id == *id;
+        let error_message = "cannot find payment method".to_string();
+        self.get_resources(key_store, payment_methods, find_pm_by, error_message)
+            .await
     }
 
     #[cfg(feature = "v1")]
@@ -61,9 +61,12 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         let key_manager_state = self
             .get_keymanager_state()
             .attach_printable("Missing KeyManagerState")?;
-        diesel_payment_attempt
-            .insert(&conn)
-            .await
+        let result = if self.is_outbox_enabled() {
+            diesel_payment_attempt.insert_with_outbox(&conn).await
+        } else {
+            diesel_payment_attempt.insert(&conn).await
+        };
+        result
             .map_err(|er| {
                 let new_err = diesel_error_to_data_error(*er.current_context());
                 er.change_context(new_err)
@@ -90,12 +93,16 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
         let conn = pg_connection_write(self).await?;
-        payment_attempt
+        let diesel_payment_attempt = payment_attempt
             .construct_new()
             .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .insert(&conn)
-            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let result = if self.is_outbox_enabled() {
+            diesel_payment_attempt.insert_with_outbox(&conn).await
+        } else {
+            diesel_payment_attempt.insert(&conn).await
+        };
+        result
             .map_err(|error| {
                 let new_error = diesel_error_to_data_error(*error.current_context());
                 error.change_context(new_error)
@@ -123,11 +130,21 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         let key_manager_state = self
             .get_keymanager_state()
             .attach_printable("Missing KeyManagerState")?;
-        this.convert()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .update_with_attempt_id(&conn, payment_attempt.to_storage_model())
+        let diesel_payment_attempt = this
+            .convert()
             .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let payment_attempt_update = payment_attempt.to_storage_model();
+        let result = if self.is_outbox_enabled() {
+            diesel_payment_attempt
+                .update_with_attempt_id_and_outbox(&conn, payment_attempt_update)
+                .await
+        } else {
+            diesel_payment_attempt
+                .update_with_attempt_id(&conn, payment_attempt_update)
+                .await
+        };
+        result
             .map_err(|er| {
                 let new_err = diesel_error_to_data_error(*er.current_context());
                 er.change_context(new_err)
@@ -156,14 +173,21 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
         let conn = pg_connection_write(self).await?;
 
-        Conversion::convert(this)
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .update_with_attempt_id(
-                &conn,
-                diesel_models::PaymentAttemptUpdateInternal::from(payment_attempt),
-            )
+        let diesel_payment_attempt = Conversion::convert(this)
             .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let payment_attempt_update =
+            diesel_models::PaymentAttemptUpdateInternal::from(payment_attempt);
+        let result = if self.is_outbox_enabled() {
+            diesel_payment_attempt
+                .update_with_attempt_id_and_outbox(&conn, payment_attempt_update)
+                .await
+        } else {
+            diesel_payment_attempt
+                .update_with_attempt_id(&conn, payment_attempt_update)
+                .await
+        };
+        result
             .map_err(|error| {
                 let new_error = diesel_error_to_data_error(*error.current_context());
                 error.change_context(new_error)
@@ -1180,9 +1204,20 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             .apply_changeset(payment_attempt.clone());
 
         let updated_by = updated_payment_attempt.updated_by.to_owned();
-        let updated_payment_attempt_with_id = payment_attempt
-            .clone()
-            .update_with_attempt_id(&conn, payment_attempt_internal.clone());
+        let is_outbox_enabled = self.router_store.is_outbox_enabled();
+        let diesel_payment_attempt = payment_attempt.clone();
+        let diesel_payment_attempt_update = payment_attempt_internal.clone();
+        let updated_payment_attempt_with_id = async {
+            if is_outbox_enabled {
+                diesel_payment_attempt
+                    .update_with_attempt_id_and_outbox(&conn, diesel_payment_attempt_update)
+                    .await
+            } else {
+                diesel_payment_attempt
+                    .update_with_attempt_id(&conn, diesel_payment_attempt_update)
+                    .await
+            }
+        };
 
         Box::pin(self.update_resource(
             merchant_key_store,
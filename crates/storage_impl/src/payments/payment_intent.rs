@@ -731,13 +731,16 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         let diesel_payment_intent = payment_intent
             .construct_new()
             .await
-            .change_context(StorageError::EncryptionError)?
-            .insert(&conn)
-            .await
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(*er.current_context());
-                er.change_context(new_err)
-            })?;
+            .change_context(StorageError::EncryptionError)?;
+        let diesel_payment_intent = if self.is_outbox_enabled() {
+            diesel_payment_intent.insert_with_outbox(&conn).await
+        } else {
+            diesel_payment_intent.insert(&conn).await
+        }
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(*er.current_context());
+            er.change_context(new_err)
+        })?;
 
         PaymentIntent::convert_back(
             self.get_keymanager_state()
@@ -765,13 +768,20 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         let diesel_payment_intent = this
             .convert()
             .await
-            .change_context(StorageError::EncryptionError)?
-            .update(&conn, diesel_payment_intent_update)
-            .await
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(*er.current_context());
-                er.change_context(new_err)
-            })?;
+            .change_context(StorageError::EncryptionError)?;
+        let diesel_payment_intent = if self.is_outbox_enabled() {
+            diesel_payment_intent
+                .update_with_outbox(&conn, diesel_payment_intent_update)
+                .await
+        } else {
+            diesel_payment_intent
+                .update(&conn, diesel_payment_intent_update)
+                .await
+        }
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(*er.current_context());
+            er.change_context(new_err)
+        })?;
 
         PaymentIntent::convert_back(
             self.get_keymanager_state()
@@ -799,13 +809,20 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         let diesel_payment_intent = this
             .convert()
             .await
-            .change_context(StorageError::EncryptionError)?
-            .update(&conn, diesel_payment_intent_update)
-            .await
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(*er.current_context());
-                er.change_context(new_err)
-            })?;
+            .change_context(StorageError::EncryptionError)?;
+        let diesel_payment_intent = if self.is_outbox_enabled() {
+            diesel_payment_intent
+                .update_with_outbox(&conn, diesel_payment_intent_update)
+                .await
+        } else {
+            diesel_payment_intent
+                .update(&conn, diesel_payment_intent_update)
+                .await
+        }
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(*er.current_context());
+            er.change_context(new_err)
+        })?;
 
         PaymentIntent::convert_back(
             self.get_keymanager_state()
@@ -106,6 +106,14 @@ pub static CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE: LazyLock<Cache> = LazyLock::n
     )
 });
 
+/// BIN Range Index Cache
+pub static BIN_RANGE_CACHE: LazyLock<Cache> =
+    LazyLock::new(|| Cache::new("BIN_RANGE_CACHE", CACHE_TTL, CACHE_TTI, None));
+
+/// Tenant Registry Cache
+pub static TENANT_REGISTRY_CACHE: LazyLock<Cache> =
+    LazyLock::new(|| Cache::new("TENANT_REGISTRY_CACHE", CACHE_TTL, CACHE_TTI, None));
+
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
     fn as_any(&self) -> &dyn Any;
@@ -129,6 +137,8 @@ pub enum CacheKind<'a> {
     EliminationBasedDynamicRoutingCache(Cow<'a, str>),
     ContractBasedDynamicRoutingCache(Cow<'a, str>),
     PmFiltersCGraph(Cow<'a, str>),
+    BinRange(Cow<'a, str>),
+    TenantRegistry(Cow<'a, str>),
     All(Cow<'a, str>),
 }
 
@@ -145,6 +155,8 @@ impl CacheKind<'_> {
             | CacheKind::EliminationBasedDynamicRoutingCache(key)
             | CacheKind::ContractBasedDynamicRoutingCache(key)
             | CacheKind::PmFiltersCGraph(key)
+            | CacheKind::BinRange(key)
+            | CacheKind::TenantRegistry(key)
             | CacheKind::All(key) => key,
         }
     }
@@ -5,10 +5,10 @@ use redis_interface::{errors as redis_errors, EventInterface, PubsubInterface, R
 use router_env::{logger, tracing::Instrument};
 
 use crate::redis::cache::{
-    CacheKey, CacheKind, CacheRedact, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE,
+    CacheKey, CacheKind, CacheRedact, ACCOUNTS_CACHE, BIN_RANGE_CACHE, CGRAPH_CACHE, CONFIG_CACHE,
     CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE, DECISION_MANAGER_CACHE,
     ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE, PM_FILTERS_CGRAPH_CACHE, ROUTING_CACHE,
-    SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE, SURCHARGE_CACHE,
+    SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE, SURCHARGE_CACHE, TENANT_REGISTRY_CACHE,
 };
 
 #[async_trait::async_trait]
@@ -28,6 +28,11 @@ pub trait PubSubInterface {
 impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
     #[inline]
     async fn subscribe(&self, channel: &str) -> error_stack::Result<(), redis_errors::RedisError> {
+        // Messages are never delivered by the in-memory keyspace, they are applied on publish
+        if self.is_in_memory() {
+            return Ok(());
+        }
+
         // Spawns a task that will automatically re-subscribe to any channels or channel patterns used by the client.
         self.subscriber.manage_subscriptions();
 
@@ -73,6 +78,12 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
             tenant: self.key_prefix.clone(),
         };
 
+        // Without a Redis server, this process is the only subscriber to the invalidations
+        if self.is_in_memory() {
+            invalidate_in_memory_caches(key).await;
+            return Ok(1);
+        }
+
         self.publisher
             .publish(
                 channel,
@@ -102,168 +113,12 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                         }
                     };
 
-                    let key = match message.kind {
-                        CacheKind::Config(key) => {
-                            CONFIG_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::Accounts(key) => {
-                            ACCOUNTS_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::CGraph(key) => {
-                            CGRAPH_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::PmFiltersCGraph(key) => {
-                            PM_FILTERS_CGRAPH_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::EliminationBasedDynamicRoutingCache(key) => {
-                            ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::ContractBasedDynamicRoutingCache(key) => {
-                            CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::SuccessBasedDynamicRoutingCache(key) => {
-                            SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::Routing(key) => {
-                            ROUTING_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::DecisionManager(key) => {
-                            DECISION_MANAGER_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::Surcharge(key) => {
-                            SURCHARGE_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::All(key) => {
-                            CONFIG_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            ACCOUNTS_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            CGRAPH_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            PM_FILTERS_CGRAPH_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            ROUTING_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            DECISION_MANAGER_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            SURCHARGE_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-
-                            key
-                        }
-                    };
-
                     logger::debug!(
-                        key_prefix=?message.tenant.clone(),
+                        key_prefix=?message.tenant,
                         channel_name=?channel_name,
-                        "Done invalidating {key}"
+                        "Invalidating the in-memory caches"
                     );
+                    invalidate_in_memory_caches(message).await;
                 }
                 _ => {
                     logger::debug!("Received message from unknown channel: {channel_name}");
@@ -273,3 +128,195 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
         Ok(())
     }
 }
+
+/// Removes the entry redacted by a published message from the in-memory caches of this process
+async fn invalidate_in_memory_caches(message: CacheRedact<'_>) {
+    let key = match message.kind {
+        CacheKind::Config(key) => {
+            CONFIG_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::Accounts(key) => {
+            ACCOUNTS_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::CGraph(key) => {
+            CGRAPH_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::PmFiltersCGraph(key) => {
+            PM_FILTERS_CGRAPH_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::EliminationBasedDynamicRoutingCache(key) => {
+            ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::ContractBasedDynamicRoutingCache(key) => {
+            CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::SuccessBasedDynamicRoutingCache(key) => {
+            SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::Routing(key) => {
+            ROUTING_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::DecisionManager(key) => {
+            DECISION_MANAGER_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::Surcharge(key) => {
+            SURCHARGE_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::BinRange(key) => {
+            BIN_RANGE_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::TenantRegistry(key) => {
+            TENANT_REGISTRY_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::All(key) => {
+            CONFIG_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            ACCOUNTS_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            CGRAPH_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            PM_FILTERS_CGRAPH_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            ROUTING_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            DECISION_MANAGER_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            SURCHARGE_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            BIN_RANGE_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            TENANT_REGISTRY_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+
+            key
+        }
+    };
+
+    logger::debug!(key_prefix=?message.tenant, "Done invalidating {key}");
+}
@@ -153,40 +153,118 @@ impl SubscriptionInterface for MockDb {
     #[instrument(skip_all)]
     async fn insert_subscription_entry(
         &self,
-        _key_store: &MerchantKeyStore,
-        _subscription_new: DomainSubscription,
+        key_store: &MerchantKeyStore,
+        subscription_new: DomainSubscription,
     ) -> CustomResult<DomainSubscription, StorageError> {
-        Err(StorageError::MockDbError)?
+        let subscription = subscription_new
+            .convert()
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let mut subscriptions = self.subscriptions.lock().await;
+        if subscriptions.iter().any(|existing| {
+            existing.id == subscription.id && existing.merchant_id == subscription.merchant_id
+        }) {
+            Err(StorageError::DuplicateValue {
+                entity: "subscription",
+                key: Some(subscription.id.get_string_repr().to_owned()),
+            })?
+        }
+        let subscription_id = subscription.id.clone();
+        subscriptions.push(subscription);
+
+        self.get_resource(
+            key_store,
+            subscriptions,
+            |subscription| subscription.id == subscription_id,
+            "Subscription not found".to_string(),
+        )
+        .await
     }
 
     async fn find_by_merchant_id_subscription_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _subscription_id: String,
+        key_store: &MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: String,
     ) -> CustomResult<DomainSubscription, StorageError> {
-        Err(StorageError::MockDbError)?
+        let subscriptions = self.subscriptions.lock().await;
+        self.get_resource(
+            key_store,
+            subscriptions,
+            |subscription| {
+                subscription.merchant_id == *merchant_id
+                    && subscription.id.get_string_repr() == subscription_id
+            },
+            format!("No subscription found for id = {subscription_id}"),
+        )
+        .await
     }
 
     async fn update_subscription_entry(
         &self,
-        _key_store: &MerchantKeyStore,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _subscription_id: String,
-        _data: DomainSubscriptionUpdate,
+        key_store: &MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: String,
+        data: DomainSubscriptionUpdate,
     ) -> CustomResult<DomainSubscription, StorageError> {
-        Err(StorageError::MockDbError)?
+        let subscription_update = data
+            .construct_new()
+            .await
+            .change_context(StorageError::EncryptionError)?;
+        let subscriptions = self.subscriptions.lock().await;
+        let subscription = subscriptions
+            .iter()
+            .find(|subscription| {
+                subscription.merchant_id == *merchant_id
+                    && subscription.id.get_string_repr() == subscription_id
+            })
+            .cloned()
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No subscription found for id = {subscription_id}"
+            )))?;
+
+        self.update_resource(
+            key_store,
+            subscriptions,
+            subscription_update.apply_changeset(subscription),
+            |subscription| {
+                subscription.merchant_id == *merchant_id
+                    && subscription.id.get_string_repr() == subscription_id
+            },
+            format!("No subscription found for id = {subscription_id}"),
+        )
+        .await
     }
 
     #[instrument(skip_all)]
     async fn list_by_merchant_id_profile_id(
         &self,
-        _key_store: &MerchantKeyStore,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _profile_id: &common_utils::id_type::ProfileId,
-        _limit: Option<i64>,
-        _offset: Option<i64>,
+        key_store: &MerchantKeyStore,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
     ) -> CustomResult<Vec<DomainSubscription>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let subscriptions = self.subscriptions.lock().await;
+        let mut subscriptions: Vec<DomainSubscription> = self
+            .find_resources(key_store, subscriptions, |subscription| {
+                subscription.merchant_id == *merchant_id && subscription.profile_id == *profile_id
+            })
+            .await?;
+        subscriptions.sort_by(|this, other| other.created_at.cmp(&this.created_at));
+
+        Ok(subscriptions
+            .into_iter()
+            .skip(
+                offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
     }
 }
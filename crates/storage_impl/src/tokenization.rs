@@ -164,29 +164,78 @@ impl<T: DatabaseStore> TokenizationInterface for KVRouterStore<T> {
 impl TokenizationInterface for MockDb {
     async fn insert_tokenization(
         &self,
-        _tokenization: hyperswitch_domain_models::tokenization::Tokenization,
-        _merchant_key_store: &MerchantKeyStore,
+        tokenization: hyperswitch_domain_models::tokenization::Tokenization,
+        merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<hyperswitch_domain_models::tokenization::Tokenization, errors::StorageError>
     {
-        Err(errors::StorageError::MockDbError)?
+        let tokenization = Conversion::convert(tokenization)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let mut tokenizations = self.tokenizations.lock().await;
+        if tokenizations
+            .iter()
+            .any(|existing| existing.id == tokenization.id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "tokenization",
+                key: Some(tokenization.id.get_string_repr().to_owned()),
+            })?
+        }
+        let id = tokenization.id.clone();
+        tokenizations.push(tokenization);
+
+        self.get_resource(
+            merchant_key_store,
+            tokenizations,
+            |tokenization| tokenization.id == id,
+            "Tokenization not found".to_string(),
+        )
+        .await
     }
+
     async fn get_entity_id_vault_id_by_token_id(
         &self,
-        _token: &common_utils::id_type::GlobalTokenId,
-        _merchant_key_store: &MerchantKeyStore,
+        token: &common_utils::id_type::GlobalTokenId,
+        merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<hyperswitch_domain_models::tokenization::Tokenization, errors::StorageError>
     {
-        Err(errors::StorageError::MockDbError)?
+        let tokenizations = self.tokenizations.lock().await;
+        self.get_resource(
+            merchant_key_store,
+            tokenizations,
+            |tokenization| tokenization.id == *token,
+            format!("No tokenization found for id = {}", token.get_string_repr()),
+        )
+        .await
     }
 
     async fn update_tokenization_record(
         &self,
-        _tokenization_record: hyperswitch_domain_models::tokenization::Tokenization,
-        _tokenization_update: hyperswitch_domain_models::tokenization::TokenizationUpdate,
-        _merchant_key_store: &MerchantKeyStore,
+        tokenization_record: hyperswitch_domain_models::tokenization::Tokenization,
+        tokenization_update: hyperswitch_domain_models::tokenization::TokenizationUpdate,
+        merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<hyperswitch_domain_models::tokenization::Tokenization, errors::StorageError>
     {
-        Err(errors::StorageError::MockDbError)?
+        let tokenization_diesel::TokenizationUpdateInternal { updated_at, flag } =
+            tokenization_diesel::TokenizationUpdateInternal::from(tokenization_update);
+        let tokenization_record = Conversion::convert(tokenization_record)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let id = tokenization_record.id.clone();
+        let updated_tokenization = tokenization_diesel::Tokenization {
+            updated_at,
+            flag: flag.unwrap_or(tokenization_record.flag),
+            ..tokenization_record
+        };
+        let tokenizations = self.tokenizations.lock().await;
+        self.update_resource(
+            merchant_key_store,
+            tokenizations,
+            updated_tokenization,
+            |tokenization| tokenization.id == id,
+            format!("No tokenization found for id = {}", id.get_string_repr()),
+        )
+        .await
     }
 }
 
@@ -41,6 +41,7 @@ pub async fn pg_connection_write<T: DatabaseStore>(
 > {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
+    crate::database::store::record_write();
 
     pool.get()
         .await
@@ -81,6 +82,7 @@ pub async fn pg_accounts_connection_write<T: DatabaseStore>(
 > {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_accounts_master_pool();
+    crate::database::store::record_write();
 
     pool.get()
         .await
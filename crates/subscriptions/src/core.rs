@@ -20,9 +20,12 @@ use crate::{
     state::SubscriptionState as SessionState,
 };
 
+pub mod billing_catalog;
 pub mod billing_processor_handler;
+pub mod dunning;
 pub mod errors;
 pub mod invoice_handler;
+pub mod native_billing;
 pub mod payments_api_client;
 pub mod subscription_handler;
 
@@ -42,6 +45,12 @@ pub async fn create_subscription(
     let _customer = SubscriptionHandler::find_customer(&state, &platform, &request.customer_id)
         .await
         .attach_printable("subscriptions: failed to find customer")?;
+    if native_billing::is_natively_billed(&profile) {
+        return Box::pin(native_billing::create_subscription(
+            state, platform, profile, request,
+        ))
+        .await;
+    }
     let billing_handler = BillingHandler::create(
         &state,
         platform.get_processor().get_account(),
@@ -55,12 +64,13 @@ pub async fn create_subscription(
         .create_subscription_entry(
             subscription_id,
             &request.customer_id,
-            billing_handler.connector_name,
-            billing_handler.merchant_connector_id.clone(),
+            Some(billing_handler.connector_name),
+            Some(billing_handler.merchant_connector_id.clone()),
             request.merchant_reference_id.clone(),
             &profile.clone(),
             request.plan_id.clone(),
             Some(request.item_price_id.clone()),
+            None,
         )
         .await
         .attach_printable("subscriptions: failed to create subscription entry")?;
@@ -69,6 +79,7 @@ pub async fn create_subscription(
         plan_id: request.plan_id.clone(),
         item_price_id: request.item_price_id.clone(),
         coupon_code: None,
+        quantity: request.quantity,
     };
 
     let estimate = billing_handler
@@ -89,12 +100,12 @@ pub async fn create_subscription(
     let invoice = invoice_handler
         .create_invoice_entry(
             &state,
-            billing_handler.merchant_connector_id,
+            Some(billing_handler.merchant_connector_id),
             Some(payment.payment_id.clone()),
             estimate.total,
             estimate.currency,
             connector_enums::InvoiceStatus::InvoiceCreated,
-            billing_handler.connector_name,
+            Some(billing_handler.connector_name),
             None,
             None,
         )
@@ -139,6 +150,10 @@ pub async fn get_subscription_items(
             .await?
     };
 
+    if native_billing::is_natively_billed(&profile) {
+        return native_billing::get_subscription_items(state, platform, profile, query).await;
+    }
+
     let billing_handler = BillingHandler::create(
         &state,
         platform.get_processor().get_account(),
@@ -192,6 +207,13 @@ pub async fn create_and_confirm_subscription(
         .await
         .attach_printable("subscriptions: failed to find customer")?;
 
+    if native_billing::is_natively_billed(&profile) {
+        return Box::pin(native_billing::create_and_confirm_subscription(
+            state, platform, profile, request,
+        ))
+        .await;
+    }
+
     let billing_handler = BillingHandler::create(
         &state,
         platform.get_processor().get_account(),
@@ -204,12 +226,13 @@ pub async fn create_and_confirm_subscription(
         .create_subscription_entry(
             subscription_id.clone(),
             &request.customer_id,
-            billing_handler.connector_name,
-            billing_handler.merchant_connector_id.clone(),
+            Some(billing_handler.connector_name),
+            Some(billing_handler.merchant_connector_id.clone()),
             request.merchant_reference_id.clone(),
             &profile.clone(),
             request.plan_id.clone(),
             Some(request.item_price_id.clone()),
+            None,
         )
         .await
         .attach_printable("subscriptions: failed to create subscription entry")?;
@@ -258,7 +281,7 @@ pub async fn create_and_confirm_subscription(
     let invoice_entry = invoice_handler
         .create_invoice_entry(
             &state,
-            profile.get_billing_processor_id()?,
+            Some(profile.get_billing_processor_id()?),
             Some(payment_response.payment_id.clone()),
             amount,
             currency,
@@ -266,7 +289,7 @@ pub async fn create_and_confirm_subscription(
                 .clone()
                 .and_then(|invoice| invoice.status)
                 .unwrap_or(connector_enums::InvoiceStatus::InvoiceCreated),
-            billing_handler.connector_name,
+            Some(billing_handler.connector_name),
             None,
             invoice_details.clone().map(|invoice| invoice.id),
         )
@@ -277,7 +300,7 @@ pub async fn create_and_confirm_subscription(
             &state,
             &invoice_entry,
             invoice_details.clone().map(|details| details.id),
-            billing_handler.connector_name,
+            Some(billing_handler.connector_name),
         )
         .await?;
 
@@ -330,6 +353,17 @@ pub async fn confirm_subscription(
             .await?
     };
 
+    if native_billing::is_natively_billed(&profile) {
+        return Box::pin(native_billing::confirm_subscription(
+            state,
+            platform,
+            profile,
+            request,
+            subscription_id,
+        ))
+        .await;
+    }
+
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
     let invoice_handler = subscription_entry.get_invoice_handler(profile.clone());
     let invoice = invoice_handler
@@ -416,7 +450,7 @@ pub async fn confirm_subscription(
             &state,
             &invoice_entry,
             invoice_details.map(|invoice| invoice.id),
-            billing_handler.connector_name,
+            Some(billing_handler.connector_name),
         )
         .await?;
 
@@ -474,6 +508,9 @@ pub async fn get_estimate(
     let profile = SubscriptionHandler::find_business_profile(&state, &platform, &profile_id)
         .await
         .attach_printable("subscriptions: failed to find business profile in get_estimate")?;
+    if native_billing::is_natively_billed(&profile) {
+        return native_billing::get_estimate(state, platform, profile, query).await;
+    }
     let billing_handler = BillingHandler::create(
         &state,
         platform.get_processor().get_account(),
@@ -498,6 +535,16 @@ pub async fn pause_subscription(
         .await
         .attach_printable("subscriptions: failed to find business profile in pause_subscription")?;
 
+    if native_billing::is_natively_billed(&_profile) {
+        return Box::pin(native_billing::pause_subscription(
+            state,
+            platform,
+            subscription_id,
+            request,
+        ))
+        .await;
+    }
+
     let handler = SubscriptionHandler::new(&state, &platform);
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
 
@@ -548,6 +595,17 @@ pub async fn resume_subscription(
             "subscriptions: failed to find business profile in resume_subscription",
         )?;
 
+    if native_billing::is_natively_billed(&_profile) {
+        return Box::pin(native_billing::resume_subscription(
+            state,
+            platform,
+            _profile,
+            subscription_id,
+            request,
+        ))
+        .await;
+    }
+
     let handler = SubscriptionHandler::new(&state, &platform);
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
 
@@ -599,6 +657,16 @@ pub async fn cancel_subscription(
             "subscriptions: failed to find business profile in cancel_subscription",
         )?;
 
+    if native_billing::is_natively_billed(&_profile) {
+        return Box::pin(native_billing::cancel_subscription(
+            state,
+            platform,
+            subscription_id,
+            request,
+        ))
+        .await;
+    }
+
     let handler = SubscriptionHandler::new(&state, &platform);
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
 
@@ -648,6 +716,17 @@ pub async fn update_subscription(
         .await
         .attach_printable("subscriptions: failed to find business profile in get_subscription")?;
 
+    if native_billing::is_natively_billed(&profile) {
+        return Box::pin(native_billing::update_subscription(
+            state,
+            platform,
+            profile,
+            subscription_id,
+            request,
+        ))
+        .await;
+    }
+
     let handler = SubscriptionHandler::new(&state, &platform);
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
 
@@ -681,6 +760,7 @@ pub async fn update_subscription(
         plan_id: Some(request.plan_id.clone()),
         item_price_id: request.item_price_id.clone(),
         coupon_code: None,
+        quantity: request.quantity,
     };
 
     let estimate = billing_handler
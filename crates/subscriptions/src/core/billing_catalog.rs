@@ -0,0 +1,668 @@
+//! Products, plans, prices and coupons of the native billing engine
+
+use api_models::billing_catalog as catalog_types;
+use common_utils::{consts, id_type};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    api::ApplicationResponse, billing_catalog as storage, platform::Platform,
+};
+
+use super::{errors, subscription_handler::SubscriptionHandler, RouterResponse};
+use crate::{
+    helpers::{ForeignFrom, StorageErrorExt},
+    state::SubscriptionState as SessionState,
+};
+
+pub const BILLING_CATALOG_MAX_LIST_COUNT: i64 = 100;
+
+fn to_i32(value: u32, field_name: &'static str) -> errors::SubscriptionResult<i32> {
+    i32::try_from(value)
+        .change_context(errors::ApiErrorResponse::InvalidDataValue { field_name })
+        .attach_printable_lazy(|| format!("{field_name} does not fit in an i32"))
+}
+
+fn to_u32(value: i32) -> u32 {
+    u32::try_from(value).unwrap_or_default()
+}
+
+pub(crate) async fn find_product(
+    state: &SessionState,
+    platform: &Platform,
+    profile_id: &id_type::ProfileId,
+    product_id: &str,
+) -> errors::SubscriptionResult<storage::BillingProduct> {
+    let not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Product not found for id: {product_id}"),
+    };
+    state
+        .store
+        .find_billing_product_by_merchant_id_id(
+            platform.get_processor().get_account().get_id(),
+            product_id,
+        )
+        .await
+        .to_not_found_response(not_found())?
+        .filter_by_profile(profile_id)
+        .ok_or_else(|| not_found().into())
+}
+
+pub(crate) async fn find_plan(
+    state: &SessionState,
+    platform: &Platform,
+    profile_id: &id_type::ProfileId,
+    plan_id: &str,
+) -> errors::SubscriptionResult<storage::BillingPlan> {
+    let not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Plan not found for id: {plan_id}"),
+    };
+    state
+        .store
+        .find_billing_plan_by_merchant_id_id(
+            platform.get_processor().get_account().get_id(),
+            plan_id,
+        )
+        .await
+        .to_not_found_response(not_found())?
+        .filter_by_profile(profile_id)
+        .ok_or_else(|| not_found().into())
+}
+
+pub(crate) async fn find_price(
+    state: &SessionState,
+    platform: &Platform,
+    profile_id: &id_type::ProfileId,
+    price_id: &str,
+) -> errors::SubscriptionResult<storage::BillingPrice> {
+    let not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Price not found for id: {price_id}"),
+    };
+    state
+        .store
+        .find_billing_price_by_merchant_id_id(
+            platform.get_processor().get_account().get_id(),
+            price_id,
+        )
+        .await
+        .to_not_found_response(not_found())?
+        .filter_by_profile(profile_id)
+        .ok_or_else(|| not_found().into())
+}
+
+pub(crate) async fn find_coupon(
+    state: &SessionState,
+    platform: &Platform,
+    profile_id: &id_type::ProfileId,
+    coupon_id: &str,
+) -> errors::SubscriptionResult<storage::BillingCoupon> {
+    let not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Coupon not found for id: {coupon_id}"),
+    };
+    state
+        .store
+        .find_billing_coupon_by_merchant_id_id(
+            platform.get_processor().get_account().get_id(),
+            coupon_id,
+        )
+        .await
+        .to_not_found_response(not_found())?
+        .filter_by_profile(profile_id)
+        .ok_or_else(|| not_found().into())
+}
+
+/// Catalog resources are looked up by merchant, a resource of another profile of the merchant is
+/// reported as not found
+trait ProfileScoped: Sized {
+    fn get_profile_id(&self) -> &id_type::ProfileId;
+
+    fn filter_by_profile(self, profile_id: &id_type::ProfileId) -> Option<Self> {
+        (self.get_profile_id() == profile_id).then_some(self)
+    }
+}
+
+impl ProfileScoped for storage::BillingProduct {
+    fn get_profile_id(&self) -> &id_type::ProfileId {
+        &self.profile_id
+    }
+}
+
+impl ProfileScoped for storage::BillingPlan {
+    fn get_profile_id(&self) -> &id_type::ProfileId {
+        &self.profile_id
+    }
+}
+
+impl ProfileScoped for storage::BillingPrice {
+    fn get_profile_id(&self) -> &id_type::ProfileId {
+        &self.profile_id
+    }
+}
+
+impl ProfileScoped for storage::BillingCoupon {
+    fn get_profile_id(&self) -> &id_type::ProfileId {
+        &self.profile_id
+    }
+}
+
+pub async fn create_product(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    request: catalog_types::BillingProductCreateRequest,
+) -> RouterResponse<catalog_types::BillingProductResponse> {
+    SubscriptionHandler::find_business_profile(&state, &platform, &profile_id)
+        .await
+        .attach_printable("billing_catalog: failed to find business profile")?;
+
+    let now = common_utils::date_time::now();
+    let product_new = storage::BillingProductNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "prod"),
+        merchant_id: platform.get_processor().get_account().get_id().clone(),
+        profile_id,
+        name: request.name,
+        description: request.description,
+        is_active: true,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let product = state
+        .store
+        .insert_billing_product(product_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to insert product")?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingProductResponse::foreign_from(product),
+    ))
+}
+
+pub async fn retrieve_product(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    product_id: String,
+) -> RouterResponse<catalog_types::BillingProductResponse> {
+    let product = find_product(&state, &platform, &profile_id, &product_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingProductResponse::foreign_from(product),
+    ))
+}
+
+pub async fn list_products(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    query: catalog_types::BillingCatalogListQuery,
+) -> RouterResponse<Vec<catalog_types::BillingProductResponse>> {
+    let products = state
+        .store
+        .list_billing_products_by_merchant_id_profile_id(
+            platform.get_processor().get_account().get_id(),
+            &profile_id,
+            Some(query.limit.unwrap_or(BILLING_CATALOG_MAX_LIST_COUNT)),
+            Some(query.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to list products")?;
+
+    Ok(ApplicationResponse::Json(
+        products
+            .into_iter()
+            .map(catalog_types::BillingProductResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+pub async fn update_product(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    product_id: String,
+    request: catalog_types::BillingProductUpdateRequest,
+) -> RouterResponse<catalog_types::BillingProductResponse> {
+    let product = find_product(&state, &platform, &profile_id, &product_id).await?;
+
+    let product_update = storage::BillingProductUpdate {
+        name: request.name,
+        description: request.description,
+        is_active: request.is_active,
+        metadata: request.metadata,
+        modified_at: common_utils::date_time::now(),
+    };
+
+    let product = state
+        .store
+        .update_billing_product_by_merchant_id_id(&product.merchant_id, &product.id, product_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to update product")?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingProductResponse::foreign_from(product),
+    ))
+}
+
+pub async fn create_plan(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    request: catalog_types::BillingPlanCreateRequest,
+) -> RouterResponse<catalog_types::BillingPlanResponse> {
+    let product = find_product(&state, &platform, &profile_id, &request.product_id).await?;
+
+    let now = common_utils::date_time::now();
+    let plan_new = storage::BillingPlanNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "plan"),
+        merchant_id: product.merchant_id,
+        profile_id: product.profile_id,
+        product_id: product.id,
+        name: request.name,
+        description: request.description,
+        trial_period_days: request
+            .trial_period_days
+            .map(|days| to_i32(days, "trial_period_days"))
+            .transpose()?,
+        is_active: true,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let plan = state
+        .store
+        .insert_billing_plan(plan_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to insert plan")?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingPlanResponse::foreign_from(plan),
+    ))
+}
+
+pub async fn retrieve_plan(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    plan_id: String,
+) -> RouterResponse<catalog_types::BillingPlanResponse> {
+    let plan = find_plan(&state, &platform, &profile_id, &plan_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingPlanResponse::foreign_from(plan),
+    ))
+}
+
+pub async fn list_plans(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    query: catalog_types::BillingCatalogListQuery,
+) -> RouterResponse<Vec<catalog_types::BillingPlanResponse>> {
+    let plans = state
+        .store
+        .list_billing_plans_by_merchant_id_profile_id(
+            platform.get_processor().get_account().get_id(),
+            &profile_id,
+            Some(query.limit.unwrap_or(BILLING_CATALOG_MAX_LIST_COUNT)),
+            Some(query.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to list plans")?;
+
+    Ok(ApplicationResponse::Json(
+        plans
+            .into_iter()
+            .map(catalog_types::BillingPlanResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+pub async fn update_plan(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    plan_id: String,
+    request: catalog_types::BillingPlanUpdateRequest,
+) -> RouterResponse<catalog_types::BillingPlanResponse> {
+    let plan = find_plan(&state, &platform, &profile_id, &plan_id).await?;
+
+    let plan_update = storage::BillingPlanUpdate {
+        name: request.name,
+        description: request.description,
+        trial_period_days: request
+            .trial_period_days
+            .map(|days| to_i32(days, "trial_period_days"))
+            .transpose()?,
+        is_active: request.is_active,
+        metadata: request.metadata,
+        modified_at: common_utils::date_time::now(),
+    };
+
+    let plan = state
+        .store
+        .update_billing_plan_by_merchant_id_id(&plan.merchant_id, &plan.id, plan_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to update plan")?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingPlanResponse::foreign_from(plan),
+    ))
+}
+
+pub async fn create_price(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    request: catalog_types::BillingPriceCreateRequest,
+) -> RouterResponse<catalog_types::BillingPriceResponse> {
+    request
+        .validate()
+        .map_err(|err| errors::ApiErrorResponse::InvalidRequestData {
+            message: err.to_string(),
+        })?;
+
+    let plan = find_plan(&state, &platform, &profile_id, &request.plan_id).await?;
+
+    let now = common_utils::date_time::now();
+    let price_new = storage::BillingPriceNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "price"),
+        merchant_id: plan.merchant_id,
+        profile_id: plan.profile_id,
+        plan_id: plan.id,
+        currency: request.currency,
+        pricing_model: request.pricing_model,
+        unit_amount: request.unit_amount,
+        tiers: request.tiers,
+        billing_interval: request.billing_interval,
+        interval_count: to_i32(request.interval_count.unwrap_or(1), "interval_count")?,
+        is_active: true,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let price = state
+        .store
+        .insert_billing_price(price_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to insert price")?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingPriceResponse::foreign_from(price),
+    ))
+}
+
+pub async fn retrieve_price(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    price_id: String,
+) -> RouterResponse<catalog_types::BillingPriceResponse> {
+    let price = find_price(&state, &platform, &profile_id, &price_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingPriceResponse::foreign_from(price),
+    ))
+}
+
+pub async fn list_prices(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    query: catalog_types::BillingPriceListQuery,
+) -> RouterResponse<Vec<catalog_types::BillingPriceResponse>> {
+    let plan = find_plan(&state, &platform, &profile_id, &query.plan_id).await?;
+
+    let prices = state
+        .store
+        .list_billing_prices_by_merchant_id_plan_id(&plan.merchant_id, &plan.id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to list prices")?;
+
+    Ok(ApplicationResponse::Json(
+        prices
+            .into_iter()
+            .map(catalog_types::BillingPriceResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+pub async fn update_price(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    price_id: String,
+    request: catalog_types::BillingPriceUpdateRequest,
+) -> RouterResponse<catalog_types::BillingPriceResponse> {
+    let price = find_price(&state, &platform, &profile_id, &price_id).await?;
+
+    let price_update = storage::BillingPriceUpdate {
+        is_active: request.is_active,
+        metadata: request.metadata,
+        modified_at: common_utils::date_time::now(),
+    };
+
+    let price = state
+        .store
+        .update_billing_price_by_merchant_id_id(&price.merchant_id, &price.id, price_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to update price")?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingPriceResponse::foreign_from(price),
+    ))
+}
+
+pub async fn create_coupon(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    request: catalog_types::BillingCouponCreateRequest,
+) -> RouterResponse<catalog_types::BillingCouponResponse> {
+    request
+        .validate()
+        .map_err(|err| errors::ApiErrorResponse::InvalidRequestData {
+            message: err.to_string(),
+        })?;
+
+    SubscriptionHandler::find_business_profile(&state, &platform, &profile_id)
+        .await
+        .attach_printable("billing_catalog: failed to find business profile")?;
+
+    let now = common_utils::date_time::now();
+    let coupon_new = storage::BillingCouponNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "coupon"),
+        merchant_id: platform.get_processor().get_account().get_id().clone(),
+        profile_id,
+        code: request.code.clone(),
+        name: request.name,
+        discount_type: request.discount_type,
+        percent_off: request.percent_off.map(i32::from),
+        amount_off: request.amount_off,
+        currency: request.currency,
+        duration: request.duration,
+        duration_in_cycles: request
+            .duration_in_cycles
+            .map(|cycles| to_i32(cycles, "duration_in_cycles"))
+            .transpose()?,
+        max_redemptions: request
+            .max_redemptions
+            .map(|redemptions| to_i32(redemptions, "max_redemptions"))
+            .transpose()?,
+        times_redeemed: 0,
+        redeem_by: request.redeem_by,
+        is_active: true,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let coupon = state
+        .store
+        .insert_billing_coupon(coupon_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!("A coupon with the code {} already exists", request.code),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingCouponResponse::foreign_from(coupon),
+    ))
+}
+
+pub async fn retrieve_coupon(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    coupon_id: String,
+) -> RouterResponse<catalog_types::BillingCouponResponse> {
+    let coupon = find_coupon(&state, &platform, &profile_id, &coupon_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingCouponResponse::foreign_from(coupon),
+    ))
+}
+
+pub async fn list_coupons(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    query: catalog_types::BillingCatalogListQuery,
+) -> RouterResponse<Vec<catalog_types::BillingCouponResponse>> {
+    let coupons = state
+        .store
+        .list_billing_coupons_by_merchant_id_profile_id(
+            platform.get_processor().get_account().get_id(),
+            &profile_id,
+            Some(query.limit.unwrap_or(BILLING_CATALOG_MAX_LIST_COUNT)),
+            Some(query.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to list coupons")?;
+
+    Ok(ApplicationResponse::Json(
+        coupons
+            .into_iter()
+            .map(catalog_types::BillingCouponResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+pub async fn update_coupon(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    coupon_id: String,
+    request: catalog_types::BillingCouponUpdateRequest,
+) -> RouterResponse<catalog_types::BillingCouponResponse> {
+    let coupon = find_coupon(&state, &platform, &profile_id, &coupon_id).await?;
+
+    let coupon_update = storage::BillingCouponUpdate::Update {
+        name: request.name,
+        max_redemptions: request
+            .max_redemptions
+            .map(|redemptions| to_i32(redemptions, "max_redemptions"))
+            .transpose()?,
+        redeem_by: request.redeem_by,
+        is_active: request.is_active,
+        metadata: request.metadata,
+    };
+
+    let coupon = state
+        .store
+        .update_billing_coupon_by_merchant_id_id(&coupon.merchant_id, &coupon.id, coupon_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing_catalog: unable to update coupon")?;
+
+    Ok(ApplicationResponse::Json(
+        catalog_types::BillingCouponResponse::foreign_from(coupon),
+    ))
+}
+
+impl ForeignFrom<storage::BillingProduct> for catalog_types::BillingProductResponse {
+    fn foreign_from(product: storage::BillingProduct) -> Self {
+        Self {
+            id: product.id,
+            profile_id: product.profile_id,
+            name: product.name,
+            description: product.description,
+            is_active: product.is_active,
+            metadata: product.metadata,
+            created_at: product.created_at,
+            modified_at: product.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::BillingPlan> for catalog_types::BillingPlanResponse {
+    fn foreign_from(plan: storage::BillingPlan) -> Self {
+        Self {
+            id: plan.id,
+            profile_id: plan.profile_id,
+            product_id: plan.product_id,
+            name: plan.name,
+            description: plan.description,
+            trial_period_days: plan.trial_period_days.map(to_u32),
+            is_active: plan.is_active,
+            metadata: plan.metadata,
+            created_at: plan.created_at,
+            modified_at: plan.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::BillingPrice> for catalog_types::BillingPriceResponse {
+    fn foreign_from(price: storage::BillingPrice) -> Self {
+        Self {
+            id: price.id,
+            profile_id: price.profile_id,
+            plan_id: price.plan_id,
+            currency: price.currency,
+            pricing_model: price.pricing_model,
+            unit_amount: price.unit_amount,
+            tiers: price.tiers,
+            billing_interval: price.billing_interval,
+            interval_count: to_u32(price.interval_count),
+            is_active: price.is_active,
+            metadata: price.metadata,
+            created_at: price.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::BillingCoupon> for catalog_types::BillingCouponResponse {
+    fn foreign_from(coupon: storage::BillingCoupon) -> Self {
+        Self {
+            id: coupon.id,
+            profile_id: coupon.profile_id,
+            code: coupon.code,
+            name: coupon.name,
+            discount_type: coupon.discount_type,
+            percent_off: coupon
+                .percent_off
+                .and_then(|percent_off| u8::try_from(percent_off).ok()),
+            amount_off: coupon.amount_off,
+            currency: coupon.currency,
+            duration: coupon.duration,
+            duration_in_cycles: coupon.duration_in_cycles.map(to_u32),
+            max_redemptions: coupon.max_redemptions.map(to_u32),
+            times_redeemed: to_u32(coupon.times_redeemed),
+            redeem_by: coupon.redeem_by,
+            is_active: coupon.is_active,
+            metadata: coupon.metadata,
+            created_at: coupon.created_at,
+        }
+    }
+}
@@ -0,0 +1,678 @@
+//! Dunning recovers the subscription invoices whose payment failed. The dunning policy of a profile
+//! decides when the payment of such an invoice is retried, whether the customer is sent a link to
+//! pay it with another payment method and what happens to the subscription once every retry failed.
+
+use std::str::FromStr;
+
+use api_models::subscription::{
+    self as subscription_types, DunningFinalAction, DunningPolicyRecord, DunningPolicyRequest,
+};
+use common_enums::{connector_enums::InvoiceStatus, Currency, FutureUsage, SubscriptionStatus};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+    types::MinorUnit,
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    api::ApplicationResponse,
+    business_profile::Profile,
+    configs,
+    invoice::{Invoice, InvoiceUpdateRequest},
+    platform::Platform,
+    subscription::{Subscription, SubscriptionUpdate},
+};
+use router_env::logger;
+use scheduler::workflows::storage::business_status;
+use time::PrimitiveDateTime;
+
+use super::{
+    billing_processor_handler::BillingHandler, errors, invoice_handler::InvoiceHandler,
+    native_billing, payments_api_client::PaymentsApiClient,
+    subscription_handler::SubscriptionHandler, RouterResponse,
+};
+use crate::{
+    helpers::StorageErrorExt,
+    state::SubscriptionState as SessionState,
+    types::storage::{dunning::DunningTrackingData, invoice_sync::InvoiceSyncPaymentStatus},
+};
+
+/// Time after which a dunning step waiting on the outcome of a payment runs again
+const DUNNING_RECHECK_INTERVAL: time::Duration = time::Duration::hours(1);
+
+pub async fn upsert_dunning_policy(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+    request: DunningPolicyRequest,
+) -> RouterResponse<DunningPolicyRecord> {
+    SubscriptionHandler::find_business_profile(&state, &platform, &profile_id).await?;
+    request
+        .validate()
+        .map_err(|err| errors::ApiErrorResponse::InvalidRequestData {
+            message: err.to_string(),
+        })?;
+
+    let key = profile_id.get_dunning_policy_key();
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let previous_record = find_dunning_policy(&state, &profile_id).await?;
+    let record = DunningPolicyRecord {
+        profile_id,
+        retry_schedule_days: request.retry_schedule_days,
+        send_reminders: request.send_reminders,
+        final_action: request.final_action,
+        created_at: previous_record
+            .as_ref()
+            .map_or(timestamp, |previous_record| previous_record.created_at),
+        modified_at: timestamp,
+    };
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    if previous_record.is_some() {
+        state
+            .store
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the dunning policy config")?;
+    } else {
+        state
+            .store
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the dunning policy config")?;
+    }
+
+    Ok(ApplicationResponse::Json(record))
+}
+
+pub async fn retrieve_dunning_policy(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<DunningPolicyRecord> {
+    SubscriptionHandler::find_business_profile(&state, &platform, &profile_id).await?;
+
+    let record = find_dunning_policy(&state, &profile_id).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "dunning policy not found for profile {}",
+                profile_id.get_string_repr()
+            ),
+        },
+    )?;
+
+    Ok(ApplicationResponse::Json(record))
+}
+
+pub async fn delete_dunning_policy(
+    state: SessionState,
+    platform: Platform,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    SubscriptionHandler::find_business_profile(&state, &platform, &profile_id).await?;
+
+    state
+        .store
+        .delete_config_by_key(&profile_id.get_dunning_policy_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "dunning policy not found for profile {}",
+                profile_id.get_string_repr()
+            ),
+        })?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn find_dunning_policy(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> errors::SubscriptionResult<Option<DunningPolicyRecord>> {
+    match state
+        .store
+        .find_config_by_key(&profile_id.get_dunning_policy_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("DunningPolicyRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the dunning policy record")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the dunning policy config"),
+    }
+}
+
+/// Only the renewals of a running subscription are dunned, the first invoice is paid by the
+/// customer while confirming the subscription
+pub fn is_dunnable(subscription: &Subscription) -> bool {
+    SubscriptionStatus::from_str(&subscription.status).is_ok_and(|status| {
+        matches!(
+            status,
+            SubscriptionStatus::Active | SubscriptionStatus::Trial | SubscriptionStatus::Unpaid
+        )
+    })
+}
+
+/// When the dunning task runs next
+#[derive(Debug)]
+pub enum DunningNextRun {
+    /// Runs the same step again, the outcome of a payment is still awaited
+    Recheck(PrimitiveDateTime),
+    /// Runs the next step, which retries the payment of the invoice
+    NextStep(PrimitiveDateTime),
+    /// Dunning has ended, the task finishes with the business status
+    Finish(&'static str),
+}
+
+/// Reminder emailed to the customer after a failed payment of a subscription invoice
+#[derive(Debug)]
+pub struct DunningReminder {
+    pub platform: Platform,
+    pub customer_id: id_type::CustomerId,
+    pub amount: MinorUnit,
+    pub currency: Currency,
+    /// Link through which the customer can pay the invoice with another payment method
+    pub payment_link: String,
+    /// Number of retries of the payment made so far
+    pub retries_made: usize,
+    pub next_retry_at: PrimitiveDateTime,
+    pub final_action: DunningFinalAction,
+}
+
+/// Runs a step of dunning for the invoice in `tracking_data`. Step `0` handles the initial payment
+/// failure, every later step retries the payment once and waits for its outcome.
+pub async fn run_dunning_step(
+    state: &SessionState,
+    platform: &Platform,
+    profile: &Profile,
+    subscription: Subscription,
+    tracking_data: &mut DunningTrackingData,
+    step: usize,
+) -> errors::SubscriptionResult<(DunningNextRun, Option<DunningReminder>)> {
+    let now = common_utils::date_time::now();
+    let invoice = state
+        .store
+        .find_invoice_by_invoice_id(
+            platform.get_processor().get_key_store(),
+            tracking_data.invoice_id.get_string_repr().to_string(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("invoices: unable to get invoice from database")?;
+    let invoice_handler = InvoiceHandler::new(
+        subscription.clone(),
+        platform.get_processor().get_account().clone(),
+        profile.clone(),
+        platform.get_processor().get_key_store().clone(),
+    );
+
+    if let Some(business_status) = get_invoice_dunning_finish_status(&invoice.status) {
+        return Ok((DunningNextRun::Finish(business_status), None));
+    }
+
+    if collect_with_payment_link(state, &invoice_handler, &invoice, tracking_data).await? {
+        return Ok((
+            DunningNextRun::Finish(business_status::COMPLETED_BY_PT),
+            None,
+        ));
+    }
+
+    if !is_dunnable(&subscription) {
+        logger::info!(
+            subscription_id = ?subscription.id,
+            status = %subscription.status,
+            "Subscription is no longer dunned"
+        );
+        return Ok((
+            DunningNextRun::Finish(business_status::RESOURCE_STATUS_MISMATCH),
+            None,
+        ));
+    }
+
+    if matches!(
+        invoice.status,
+        InvoiceStatus::PaymentPending | InvoiceStatus::ManualReview
+    ) {
+        return Ok((
+            DunningNextRun::Recheck(now.saturating_add(DUNNING_RECHECK_INTERVAL)),
+            None,
+        ));
+    }
+
+    let Some(policy) = find_dunning_policy(state, &subscription.profile_id).await? else {
+        logger::info!(profile_id = ?subscription.profile_id, "Dunning policy was deleted");
+        return Ok((
+            DunningNextRun::Finish(business_status::RESOURCE_STATUS_MISMATCH),
+            None,
+        ));
+    };
+
+    let retry_failed = std::mem::take(&mut tracking_data.retry_pending);
+    if step > 0 && !retry_failed {
+        if retry_payment(state, &invoice_handler, &invoice).await? {
+            tracking_data.retry_pending = true;
+            return Ok((
+                DunningNextRun::Recheck(now.saturating_add(DUNNING_RECHECK_INTERVAL)),
+                None,
+            ));
+        }
+        logger::info!(invoice_id = ?invoice.id, step, "Dunning retry of the invoice failed");
+    }
+
+    let Some(next_retry_at) = get_next_retry_at(
+        &policy.retry_schedule_days,
+        tracking_data.failed_at,
+        step,
+        now,
+    ) else {
+        apply_final_action(state, platform, profile, &subscription, policy.final_action).await?;
+        return Ok((
+            DunningNextRun::Finish(business_status::COMPLETED_BY_PT),
+            None,
+        ));
+    };
+
+    let reminder = if policy.send_reminders {
+        create_reminder(
+            state,
+            platform,
+            &invoice_handler,
+            &invoice,
+            tracking_data,
+            step,
+            next_retry_at,
+            policy.final_action,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, invoice_id = ?invoice.id, "Failed to create dunning payment link")
+        })
+        .ok()
+    } else {
+        None
+    };
+
+    Ok((DunningNextRun::NextStep(next_retry_at), reminder))
+}
+
+/// Dunning ends once the invoice is paid, or when it is in a state dunning does not apply to. The
+/// returned business status is the one the dunning task finishes with.
+fn get_invoice_dunning_finish_status(status: &InvoiceStatus) -> Option<&'static str> {
+    match status {
+        InvoiceStatus::InvoicePaid | InvoiceStatus::PaymentSucceeded => {
+            Some(business_status::COMPLETED_BY_PT)
+        }
+        InvoiceStatus::InvoiceCreated | InvoiceStatus::Voided => {
+            Some(business_status::RESOURCE_STATUS_MISMATCH)
+        }
+        InvoiceStatus::PaymentPending
+        | InvoiceStatus::PaymentPendingTimeout
+        | InvoiceStatus::ManualReview
+        | InvoiceStatus::PaymentFailed
+        | InvoiceStatus::PaymentCanceled => None,
+    }
+}
+
+/// Retries are scheduled relative to the initial payment failure, a retry whose day has already
+/// passed runs right away. Returns `None` once every retry of the schedule has been made.
+fn get_next_retry_at(
+    retry_schedule_days: &[u16],
+    failed_at: PrimitiveDateTime,
+    step: usize,
+    now: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    retry_schedule_days.get(step).map(|days| {
+        failed_at
+            .saturating_add(time::Duration::days(i64::from(*days)))
+            .max(now)
+    })
+}
+
+/// Retries the payment of the invoice with the saved payment method of the subscription, through
+/// the same off session payment and invoice sync as a renewal. Returns whether the payment is
+/// awaited, a payment which could not be made is reported as `false`.
+async fn retry_payment(
+    state: &SessionState,
+    invoice_handler: &InvoiceHandler,
+    invoice: &Invoice,
+) -> errors::SubscriptionResult<bool> {
+    let Some(payment_method_id) = invoice_handler.subscription.payment_method_id.as_deref() else {
+        logger::info!(invoice_id = ?invoice.id, "Subscription has no saved payment method to retry");
+        return Ok(false);
+    };
+
+    let payment = match invoice_handler
+        .create_mit_payment(
+            state,
+            invoice.amount,
+            get_currency(invoice)?,
+            payment_method_id,
+        )
+        .await
+    {
+        Ok(payment) => payment,
+        Err(error) => {
+            logger::error!(?error, invoice_id = ?invoice.id, "Failed to retry invoice payment");
+            return Ok(false);
+        }
+    };
+    if matches!(
+        InvoiceSyncPaymentStatus::from(payment.status),
+        InvoiceSyncPaymentStatus::PaymentFailed
+    ) {
+        return Ok(false);
+    }
+
+    let invoice = invoice_handler
+        .update_invoice(
+            state,
+            invoice.id.clone(),
+            InvoiceUpdateRequest::update_payment_and_status(
+                payment.payment_method_id,
+                Some(payment.payment_id),
+                InvoiceStatus::PaymentPending,
+                None,
+            ),
+        )
+        .await?;
+    invoice_handler
+        .create_invoice_sync_job(
+            state,
+            &invoice,
+            invoice.connector_invoice_id.clone(),
+            invoice.provider_name,
+        )
+        .await?;
+
+    Ok(true)
+}
+
+/// Settles the invoice with a payment the customer made through one of the payment links sent to
+/// them. The payment method of that payment becomes the one the subscription is charged with.
+async fn collect_with_payment_link(
+    state: &SessionState,
+    invoice_handler: &InvoiceHandler,
+    invoice: &Invoice,
+    tracking_data: &DunningTrackingData,
+) -> errors::SubscriptionResult<bool> {
+    let merchant_id = invoice_handler.merchant_account.get_id().get_string_repr();
+    let profile_id = invoice_handler.profile.get_id().get_string_repr();
+    for payment_id in tracking_data.payment_link_payment_ids.iter().rev() {
+        let payment = PaymentsApiClient::sync_payment(
+            state,
+            payment_id.get_string_repr().to_string(),
+            merchant_id,
+            profile_id,
+        )
+        .await?;
+        if !matches!(
+            InvoiceSyncPaymentStatus::from(payment.status),
+            InvoiceSyncPaymentStatus::PaymentSucceeded
+        ) {
+            continue;
+        }
+
+        state
+            .store
+            .update_subscription_entry(
+                &invoice_handler.merchant_key_store,
+                &invoice_handler.subscription.merchant_id,
+                invoice_handler
+                    .subscription
+                    .id
+                    .get_string_repr()
+                    .to_string(),
+                SubscriptionUpdate::new(None, payment.payment_method_id.clone(), None, None, None),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::SubscriptionError {
+                operation: "Subscription Update".to_string(),
+            })
+            .attach_printable("subscriptions: unable to update payment method of subscription")?;
+
+        // The invoice sync records the payment against the invoice like any other payment of it
+        let invoice = invoice_handler
+            .update_invoice(
+                state,
+                invoice.id.clone(),
+                InvoiceUpdateRequest::update_payment_and_status(
+                    payment.payment_method_id,
+                    Some(payment.payment_id),
+                    InvoiceStatus::PaymentPending,
+                    None,
+                ),
+            )
+            .await?;
+        invoice_handler
+            .create_invoice_sync_job(
+                state,
+                &invoice,
+                invoice.connector_invoice_id.clone(),
+                invoice.provider_name,
+            )
+            .await?;
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+/// Creates the payment link the reminder is sent with, valid until the next retry of the payment
+#[allow(clippy::too_many_arguments)]
+async fn create_reminder(
+    state: &SessionState,
+    platform: &Platform,
+    invoice_handler: &InvoiceHandler,
+    invoice: &Invoice,
+    tracking_data: &mut DunningTrackingData,
+    retries_made: usize,
+    next_retry_at: PrimitiveDateTime,
+    final_action: DunningFinalAction,
+) -> errors::SubscriptionResult<DunningReminder> {
+    let currency = get_currency(invoice)?;
+    let request = subscription_types::CreatePaymentLinkRequestData {
+        amount: invoice.amount,
+        currency,
+        customer_id: Some(invoice.customer_id.clone()),
+        profile_id: Some(invoice.profile_id.clone()),
+        setup_future_usage: Some(FutureUsage::OffSession),
+        payment_link: true,
+        session_expiry: u32::try_from(
+            (next_retry_at - common_utils::date_time::now()).whole_seconds(),
+        )
+        .ok()
+        .filter(|session_expiry| *session_expiry > 0),
+        description: Some(format!(
+            "Payment of invoice {}",
+            invoice.id.get_string_repr()
+        )),
+    };
+    let payment = PaymentsApiClient::create_payment_link(
+        state,
+        request,
+        invoice_handler.merchant_account.get_id().get_string_repr(),
+        invoice_handler.profile.get_id().get_string_repr(),
+    )
+    .await?;
+    let payment_link = payment
+        .payment_link
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("payment link is missing in the payment response")?;
+    tracking_data
+        .payment_link_payment_ids
+        .push(payment.payment_id);
+
+    Ok(DunningReminder {
+        platform: platform.clone(),
+        customer_id: invoice.customer_id.clone(),
+        amount: invoice.amount,
+        currency,
+        payment_link: payment_link.link,
+        retries_made,
+        next_retry_at,
+        final_action,
+    })
+}
+
+/// Applies the final action of the dunning policy once every retry has failed. Subscriptions of a
+/// billing processor are paused or cancelled on the billing processor.
+async fn apply_final_action(
+    state: &SessionState,
+    platform: &Platform,
+    profile: &Profile,
+    subscription: &Subscription,
+    final_action: DunningFinalAction,
+) -> errors::SubscriptionResult<()> {
+    logger::info!(subscription_id = ?subscription.id, ?final_action, "Dunning exhausted all retries");
+    if subscription.is_natively_billed() {
+        let status = match final_action {
+            DunningFinalAction::Pause => SubscriptionStatus::Paused,
+            DunningFinalAction::Cancel => SubscriptionStatus::Cancelled,
+            DunningFinalAction::MarkUnpaid => SubscriptionStatus::Unpaid,
+        };
+        return native_billing::apply_dunning_status(state, platform, subscription, status).await;
+    }
+
+    let status = match final_action {
+        DunningFinalAction::Pause => {
+            let billing_handler = BillingHandler::create(
+                state,
+                platform.get_processor().get_account(),
+                platform.get_processor().get_key_store(),
+                profile.clone(),
+            )
+            .await?;
+            let request = subscription_types::PauseSubscriptionRequest {
+                pause_option: Some(subscription_types::PauseOption::Immediately),
+                pause_at: None,
+            };
+            let pause_response = billing_handler
+                .pause_subscription_on_connector(state, subscription, &request)
+                .await?;
+            SubscriptionStatus::from(pause_response.status)
+        }
+        DunningFinalAction::Cancel => {
+            let billing_handler = BillingHandler::create(
+                state,
+                platform.get_processor().get_account(),
+                platform.get_processor().get_key_store(),
+                profile.clone(),
+            )
+            .await?;
+            let request = subscription_types::CancelSubscriptionRequest {
+                cancel_option: Some(subscription_types::CancelOption::Immediately),
+                cancel_at: None,
+                unbilled_charges_option: None,
+                credit_option_for_current_term_charges: None,
+                account_receivables_handling: None,
+                refundable_credits_handling: None,
+                cancel_reason_code: None,
+            };
+            let cancel_response = billing_handler
+                .cancel_subscription_on_connector(state, subscription, &request)
+                .await?;
+            SubscriptionStatus::from(cancel_response.status)
+        }
+        DunningFinalAction::MarkUnpaid => SubscriptionStatus::Unpaid,
+    };
+
+    state
+        .store
+        .update_subscription_entry(
+            platform.get_processor().get_key_store(),
+            &subscription.merchant_id,
+            subscription.id.get_string_repr().to_string(),
+            SubscriptionUpdate::update_status(status.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::SubscriptionError {
+            operation: "Subscription Update".to_string(),
+        })
+        .attach_printable("subscriptions: unable to update subscription entry in database")?;
+
+    Ok(())
+}
+
+fn get_currency(invoice: &Invoice) -> errors::SubscriptionResult<Currency> {
+    Currency::from_str(&invoice.currency)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("invalid invoice currency {}", invoice.currency))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retries_follow_the_schedule_from_the_failure() {
+        let failed_at = common_utils::date_time::now();
+        let schedule = [1, 3, 7];
+
+        assert_eq!(
+            get_next_retry_at(&schedule, failed_at, 0, failed_at),
+            Some(failed_at.saturating_add(time::Duration::days(1)))
+        );
+        assert_eq!(
+            get_next_retry_at(&schedule, failed_at, 2, failed_at),
+            Some(failed_at.saturating_add(time::Duration::days(7)))
+        );
+        assert_eq!(get_next_retry_at(&schedule, failed_at, 3, failed_at), None);
+        assert_eq!(get_next_retry_at(&[], failed_at, 0, failed_at), None);
+    }
+
+    #[test]
+    fn test_overdue_retries_run_right_away() {
+        let failed_at = common_utils::date_time::now();
+        let now = failed_at.saturating_add(time::Duration::days(4));
+
+        assert_eq!(get_next_retry_at(&[1, 3, 7], failed_at, 1, now), Some(now));
+        assert_eq!(
+            get_next_retry_at(&[1, 3, 7], failed_at, 2, now),
+            Some(failed_at.saturating_add(time::Duration::days(7)))
+        );
+    }
+
+    #[test]
+    fn test_invoice_status_ends_dunning() {
+        assert_eq!(
+            get_invoice_dunning_finish_status(&InvoiceStatus::InvoicePaid),
+            Some(business_status::COMPLETED_BY_PT)
+        );
+        assert_eq!(
+            get_invoice_dunning_finish_status(&InvoiceStatus::PaymentSucceeded),
+            Some(business_status::COMPLETED_BY_PT)
+        );
+        assert_eq!(
+            get_invoice_dunning_finish_status(&InvoiceStatus::Voided),
+            Some(business_status::RESOURCE_STATUS_MISMATCH)
+        );
+        assert_eq!(
+            get_invoice_dunning_finish_status(&InvoiceStatus::InvoiceCreated),
+            Some(business_status::RESOURCE_STATUS_MISMATCH)
+        );
+        for status in [
+            InvoiceStatus::PaymentPending,
+            InvoiceStatus::PaymentPendingTimeout,
+            InvoiceStatus::ManualReview,
+            InvoiceStatus::PaymentFailed,
+            InvoiceStatus::PaymentCanceled,
+        ] {
+            assert_eq!(get_invoice_dunning_finish_status(&status), None);
+        }
+    }
+}
@@ -0,0 +1,1921 @@
+//! The native billing engine bills the subscriptions of profiles without a billing processor from
+//! the products, plans, prices and coupons of the billing catalog. Invoices are raised on the
+//! scheduler at every billing cycle boundary and charged off session with the saved payment
+//! method of the subscription.
+
+use std::str::FromStr;
+
+use api_models::subscription::{self as subscription_types, SubscriptionResponse};
+use common_enums::{
+    connector_enums::InvoiceStatus, BillingInterval, BillingPricingModel, CouponDiscountType,
+    CouponDuration, Currency, SubscriptionStatus,
+};
+use common_utils::{
+    ext_traits::OptionExt,
+    id_type::{self, GenerateId},
+    pii,
+    types::MinorUnit,
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    api::ApplicationResponse,
+    billing_catalog as catalog,
+    business_profile::Profile,
+    invoice::{Invoice, InvoiceUpdateRequest},
+    platform::Platform,
+    router_response_types::subscriptions as subscription_response_types,
+    subscription::{Subscription, SubscriptionBillingDetails, SubscriptionUpdate},
+};
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use super::{
+    billing_catalog, errors,
+    invoice_handler::InvoiceHandler,
+    subscription_handler::{SubscriptionHandler, SubscriptionWithHandler},
+    RouterResponse,
+};
+use crate::{
+    helpers::{ForeignTryFrom, StorageErrorExt},
+    state::SubscriptionState as SessionState,
+    types::storage::invoice_sync::InvoiceSyncPaymentStatus,
+    workflows::{dunning as dunning_workflow, subscription_billing},
+};
+
+/// Stands in for the billing processor name where one is expected, for instance in the
+/// `invoice_sync_pt_mapping_{connector}` config key
+pub const NATIVE_BILLING_PROCESSOR: &str = "native";
+
+const LINE_ITEM_PLAN: &str = "plan";
+const LINE_ITEM_TRIAL: &str = "trial";
+const LINE_ITEM_COUPON: &str = "coupon";
+const LINE_ITEM_CREDIT: &str = "credit";
+const LINE_ITEM_PRORATION: &str = "proration";
+
+/// Profiles without a billing processor bill their subscriptions natively
+pub fn is_natively_billed(profile: &Profile) -> bool {
+    profile.billing_processor_id.is_none()
+}
+
+/// The plan and price a subscription is billed from
+pub struct BillingItem {
+    pub plan: catalog::BillingPlan,
+    pub price: catalog::BillingPrice,
+}
+
+impl BillingItem {
+    pub async fn find(
+        state: &SessionState,
+        platform: &Platform,
+        profile_id: &id_type::ProfileId,
+        plan_id: Option<&str>,
+        price_id: &str,
+    ) -> errors::SubscriptionResult<Self> {
+        let price = billing_catalog::find_price(state, platform, profile_id, price_id).await?;
+        if let Some(plan_id) = plan_id.filter(|plan_id| *plan_id != price.plan_id) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("The price {price_id} does not belong to the plan {plan_id}"),
+            }
+            .into());
+        }
+        let plan = billing_catalog::find_plan(state, platform, profile_id, &price.plan_id).await?;
+
+        Ok(Self { plan, price })
+    }
+
+    async fn find_for_subscription(
+        state: &SessionState,
+        platform: &Platform,
+        subscription: &Subscription,
+    ) -> errors::SubscriptionResult<Self> {
+        let price_id = subscription
+            .item_price_id
+            .as_deref()
+            .get_required_value("item_price_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("natively billed subscription has no price")?;
+
+        Self::find(
+            state,
+            platform,
+            &subscription.profile_id,
+            subscription.plan_id.as_deref(),
+            price_id,
+        )
+        .await
+    }
+
+    /// Archived plans and prices keep billing the subscriptions created before they were archived
+    fn ensure_active(&self) -> errors::SubscriptionResult<()> {
+        if self.plan.is_active && self.price.is_active {
+            Ok(())
+        } else {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The price {} is no longer available for new subscriptions",
+                    self.price.id
+                ),
+            }
+            .into())
+        }
+    }
+
+    fn amount_for_quantity(&self, quantity: u32) -> MinorUnit {
+        price_amount(&self.price, quantity)
+    }
+
+    fn trial_end(&self, now: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        self.plan
+            .trial_period_days
+            .filter(|days| *days > 0)
+            .map(|days| now.saturating_add(time::Duration::days(i64::from(days))))
+    }
+
+    fn period_end(
+        &self,
+        period_start: PrimitiveDateTime,
+    ) -> errors::SubscriptionResult<PrimitiveDateTime> {
+        let interval_count = u32::try_from(self.price.interval_count).unwrap_or(1).max(1);
+        add_interval(period_start, self.price.billing_interval, interval_count)
+    }
+
+    fn has_same_cycle(&self, other: &Self) -> bool {
+        self.price.billing_interval == other.price.billing_interval
+            && self.price.interval_count == other.price.interval_count
+    }
+
+    fn line_item(
+        &self,
+        item_type: &str,
+        quantity: u32,
+        amount: MinorUnit,
+    ) -> NativeInvoiceLineItem {
+        NativeInvoiceLineItem {
+            item_id: self.price.id.clone(),
+            item_type: item_type.to_string(),
+            description: self.plan.name.clone(),
+            unit_amount: self.price.unit_amount,
+            quantity,
+            amount,
+        }
+    }
+}
+
+fn price_amount(price: &catalog::BillingPrice, quantity: u32) -> MinorUnit {
+    let unit_amount = price.unit_amount.unwrap_or(MinorUnit::new(0));
+    match price.pricing_model {
+        BillingPricingModel::Flat => unit_amount,
+        BillingPricingModel::PerUnit => MinorUnit::new(
+            unit_amount
+                .get_amount_as_i64()
+                .saturating_mul(i64::from(quantity)),
+        ),
+        BillingPricingModel::Tiered => price
+            .tiers
+            .as_ref()
+            .map(|tiers| tiers.amount_for_quantity(quantity))
+            .unwrap_or(MinorUnit::new(0)),
+    }
+}
+
+fn add_interval(
+    start: PrimitiveDateTime,
+    interval: BillingInterval,
+    interval_count: u32,
+) -> errors::SubscriptionResult<PrimitiveDateTime> {
+    let end = match interval {
+        BillingInterval::Day => {
+            Some(start.saturating_add(time::Duration::days(i64::from(interval_count))))
+        }
+        BillingInterval::Week => {
+            Some(start.saturating_add(time::Duration::weeks(i64::from(interval_count))))
+        }
+        BillingInterval::Month => add_months(start, interval_count),
+        BillingInterval::Year => add_months(start, interval_count.saturating_mul(12)),
+    };
+
+    end.ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("billing period end is out of range")
+}
+
+/// Monthly periods end on the same day of the month, or on the last day of shorter months
+fn add_months(start: PrimitiveDateTime, months: u32) -> Option<PrimitiveDateTime> {
+    let date = start.date();
+    let month_index = u32::from(u8::from(date.month()))
+        .saturating_sub(1)
+        .saturating_add(months);
+    let year = date
+        .year()
+        .checked_add(i32::try_from(month_index / 12).ok()?)?;
+    let month = time::Month::January.nth_next(u8::try_from(month_index % 12).ok()?);
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+
+    time::Date::from_calendar_date(year, month, day)
+        .ok()
+        .map(|date| date.with_time(start.time()))
+}
+
+fn get_quantity(quantity: Option<u32>) -> errors::SubscriptionResult<u32> {
+    match quantity {
+        Some(0) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "quantity must be greater than 0".to_string(),
+        }
+        .into()),
+        quantity => Ok(quantity.unwrap_or(1)),
+    }
+}
+
+/// The part of `amount` covering the time left in the period at `now`.
+fn prorate(
+    amount: MinorUnit,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+) -> MinorUnit {
+    let period_seconds = (period_end - period_start).whole_seconds().max(1);
+    let remaining_seconds = (period_end - now).whole_seconds().clamp(0, period_seconds);
+    let prorated = i128::from(amount.get_amount_as_i64())
+        .saturating_mul(i128::from(remaining_seconds))
+        .checked_div(i128::from(period_seconds))
+        .unwrap_or(0);
+    MinorUnit::new(i64::try_from(prorated).unwrap_or(i64::MAX))
+}
+
+async fn find_redeemable_coupon(
+    state: &SessionState,
+    platform: &Platform,
+    profile_id: &id_type::ProfileId,
+    coupon_code: Option<&str>,
+    currency: Currency,
+) -> errors::SubscriptionResult<Option<catalog::BillingCoupon>> {
+    let Some(code) = coupon_code else {
+        return Ok(None);
+    };
+
+    let coupon = state
+        .store
+        .find_billing_coupon_by_merchant_id_profile_id_code(
+            platform.get_processor().get_account().get_id(),
+            profile_id,
+            code,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Coupon not found for code: {code}"),
+        })?;
+
+    let now = common_utils::date_time::now();
+    let is_expired = coupon.redeem_by.is_some_and(|redeem_by| redeem_by <= now);
+    let is_exhausted = coupon
+        .max_redemptions
+        .is_some_and(|max_redemptions| coupon.times_redeemed >= max_redemptions);
+    if !coupon.is_active || is_expired || is_exhausted {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("The coupon {code} can no longer be redeemed"),
+        }
+        .into());
+    }
+    if coupon
+        .currency
+        .is_some_and(|coupon_currency| coupon_currency != currency)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("The coupon {code} does not apply to prices in {currency}"),
+        }
+        .into());
+    }
+
+    Ok(Some(coupon))
+}
+
+/// The redemption is counted before the subscription is created, so that concurrent requests
+/// can not redeem a coupon more than `max_redemptions` times
+async fn redeem_coupon(
+    state: &SessionState,
+    coupon: Option<&catalog::BillingCoupon>,
+) -> errors::SubscriptionResult<()> {
+    if let Some(coupon) = coupon {
+        state
+            .store
+            .redeem_billing_coupon(coupon.clone())
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The coupon {} was redeemed concurrently, please retry",
+                    coupon.code
+                ),
+            })?;
+    }
+    Ok(())
+}
+
+fn coupon_discount(coupon: &catalog::BillingCoupon, amount: MinorUnit) -> MinorUnit {
+    let amount = amount.get_amount_as_i64().max(0);
+    let discount = match coupon.discount_type {
+        CouponDiscountType::Percentage => amount
+            .saturating_mul(i64::from(coupon.percent_off.unwrap_or(0)))
+            .checked_div(100)
+            .unwrap_or(0),
+        CouponDiscountType::FixedAmount => coupon
+            .amount_off
+            .map(|amount_off| amount_off.get_amount_as_i64())
+            .unwrap_or(0),
+    };
+    MinorUnit::new(discount.clamp(0, amount))
+}
+
+/// The number of invoices the coupon still applies to, `None` for coupons applying forever
+fn coupon_cycles(coupon: &catalog::BillingCoupon) -> Option<u32> {
+    match coupon.duration {
+        CouponDuration::Once => Some(1),
+        CouponDuration::Repeating => Some(
+            coupon
+                .duration_in_cycles
+                .and_then(|cycles| u32::try_from(cycles).ok())
+                .unwrap_or(1),
+        ),
+        CouponDuration::Forever => None,
+    }
+}
+
+fn consume_coupon_cycle(billing_details: &mut SubscriptionBillingDetails) {
+    billing_details.coupon_cycles_remaining = billing_details
+        .coupon_cycles_remaining
+        .map(|cycles| cycles.saturating_sub(1));
+    if billing_details.coupon_cycles_remaining == Some(0) {
+        billing_details.coupon_id = None;
+        billing_details.coupon_cycles_remaining = None;
+    }
+}
+
+/// An invoice of the native billing engine, stored in the metadata of the invoice
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NativeInvoice {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+    pub currency: Currency,
+    pub line_items: Vec<NativeInvoiceLineItem>,
+    pub sub_total: MinorUnit,
+    pub discount: MinorUnit,
+    pub credits_applied: MinorUnit,
+    pub total: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NativeInvoiceLineItem {
+    pub item_id: String,
+    pub item_type: String,
+    pub description: String,
+    pub unit_amount: Option<MinorUnit>,
+    pub quantity: u32,
+    pub amount: MinorUnit,
+}
+
+impl NativeInvoice {
+    fn new(
+        item: &BillingItem,
+        quantity: u32,
+        coupon: Option<&catalog::BillingCoupon>,
+        credit_balance: MinorUnit,
+        period_start: PrimitiveDateTime,
+        period_end: PrimitiveDateTime,
+    ) -> Self {
+        let sub_total = item.amount_for_quantity(quantity);
+        let mut line_items = vec![item.line_item(LINE_ITEM_PLAN, quantity, sub_total)];
+
+        let discount = coupon
+            .map(|coupon| coupon_discount(coupon, sub_total))
+            .unwrap_or(MinorUnit::new(0));
+        if let Some(coupon) = coupon.filter(|_| discount.is_greater_than(0)) {
+            line_items.push(NativeInvoiceLineItem {
+                item_id: coupon.id.clone(),
+                item_type: LINE_ITEM_COUPON.to_string(),
+                description: coupon.name.clone(),
+                unit_amount: None,
+                quantity: 1,
+                amount: MinorUnit::new(discount.get_amount_as_i64().saturating_neg()),
+            });
+        }
+
+        Self::with_credits(
+            item.price.currency,
+            line_items,
+            sub_total,
+            discount,
+            credit_balance,
+            period_start,
+            period_end,
+        )
+    }
+
+    /// The first invoice of a subscription starting with a trial charges nothing, its payment
+    /// only saves the payment method of the customer
+    fn trial(
+        item: &BillingItem,
+        quantity: u32,
+        period_start: PrimitiveDateTime,
+        trial_end: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            period_start,
+            period_end: trial_end,
+            currency: item.price.currency,
+            line_items: vec![item.line_item(LINE_ITEM_TRIAL, quantity, MinorUnit::new(0))],
+            sub_total: MinorUnit::new(0),
+            discount: MinorUnit::new(0),
+            credits_applied: MinorUnit::new(0),
+            total: MinorUnit::new(0),
+        }
+    }
+
+    fn with_credits(
+        currency: Currency,
+        mut line_items: Vec<NativeInvoiceLineItem>,
+        sub_total: MinorUnit,
+        discount: MinorUnit,
+        credit_balance: MinorUnit,
+        period_start: PrimitiveDateTime,
+        period_end: PrimitiveDateTime,
+    ) -> Self {
+        let amount_due = sub_total
+            .get_amount_as_i64()
+            .saturating_sub(discount.get_amount_as_i64())
+            .max(0);
+        let credits_applied = credit_balance.get_amount_as_i64().clamp(0, amount_due);
+        if credits_applied > 0 {
+            line_items.push(NativeInvoiceLineItem {
+                item_id: LINE_ITEM_CREDIT.to_string(),
+                item_type: LINE_ITEM_CREDIT.to_string(),
+                description: "Credit balance".to_string(),
+                unit_amount: None,
+                quantity: 1,
+                amount: MinorUnit::new(credits_applied.saturating_neg()),
+            });
+        }
+
+        Self {
+            period_start,
+            period_end,
+            currency,
+            line_items,
+            sub_total,
+            discount,
+            credits_applied: MinorUnit::new(credits_applied),
+            total: MinorUnit::new(amount_due.saturating_sub(credits_applied)),
+        }
+    }
+
+    fn metadata(&self) -> errors::SubscriptionResult<pii::SecretSerdeValue> {
+        serde_json::to_value(self)
+            .map(pii::SecretSerdeValue::new)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to serialize native invoice")
+    }
+
+    fn to_estimate(
+        &self,
+        amount_due: MinorUnit,
+        next_billing_at: PrimitiveDateTime,
+    ) -> subscription_response_types::GetSubscriptionEstimateResponse {
+        subscription_response_types::GetSubscriptionEstimateResponse {
+            sub_total: self.sub_total,
+            total: self.total,
+            credits_applied: Some(self.credits_applied),
+            amount_paid: None,
+            amount_due: Some(amount_due),
+            currency: self.currency,
+            next_billing_at: Some(next_billing_at),
+            line_items: self
+                .line_items
+                .iter()
+                .map(
+                    |line_item| subscription_response_types::SubscriptionLineItem {
+                        item_id: line_item.item_id.clone(),
+                        item_type: line_item.item_type.clone(),
+                        description: line_item.description.clone(),
+                        amount: line_item.amount,
+                        currency: self.currency,
+                        unit_amount: line_item.unit_amount,
+                        quantity: i64::from(line_item.quantity),
+                        pricing_model: None,
+                    },
+                )
+                .collect(),
+            customer_id: None,
+        }
+    }
+}
+
+/// The billing details of a new subscription along with its first invoice. Subscriptions
+/// starting with a trial are billed for the first time when the trial ends.
+fn new_billing_details(
+    item: &BillingItem,
+    quantity: u32,
+    coupon: Option<&catalog::BillingCoupon>,
+    now: PrimitiveDateTime,
+) -> errors::SubscriptionResult<(SubscriptionBillingDetails, NativeInvoice)> {
+    let trial_end = item.trial_end(now);
+    let first_invoice = match trial_end {
+        Some(trial_end) => NativeInvoice::trial(item, quantity, now, trial_end),
+        None => NativeInvoice::new(
+            item,
+            quantity,
+            coupon,
+            MinorUnit::new(0),
+            now,
+            item.period_end(now)?,
+        ),
+    };
+
+    let mut billing_details = SubscriptionBillingDetails {
+        quantity,
+        coupon_id: coupon.map(|coupon| coupon.id.clone()),
+        coupon_cycles_remaining: coupon.and_then(coupon_cycles),
+        trial_end,
+        current_period_start: now,
+        current_period_end: first_invoice.period_end,
+        credit_balance: MinorUnit::new(0),
+        pause_at: None,
+        resume_at: None,
+        cancel_at: None,
+    };
+    if trial_end.is_none() && coupon.is_some() {
+        consume_coupon_cycle(&mut billing_details);
+    }
+
+    Ok((billing_details, first_invoice))
+}
+
+fn get_billing_details(
+    subscription: &Subscription,
+) -> errors::SubscriptionResult<SubscriptionBillingDetails> {
+    subscription
+        .billing_details
+        .clone()
+        .get_required_value("billing_details")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("natively billed subscription has no billing details")
+}
+
+fn get_status(subscription: &Subscription) -> errors::SubscriptionResult<SubscriptionStatus> {
+    SubscriptionStatus::from_str(&subscription.status)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("invalid subscription status {}", subscription.status))
+}
+
+fn active_status(
+    billing_details: &SubscriptionBillingDetails,
+    now: PrimitiveDateTime,
+) -> SubscriptionStatus {
+    if billing_details
+        .trial_end
+        .is_some_and(|trial_end| trial_end > now)
+    {
+        SubscriptionStatus::Trial
+    } else {
+        SubscriptionStatus::Active
+    }
+}
+
+/// The status a natively billed subscription moves to once the payment of one of its invoices
+/// settles, `None` when the status is left as it is
+pub fn status_after_payment(
+    subscription: &Subscription,
+    payment_status: &InvoiceSyncPaymentStatus,
+    now: PrimitiveDateTime,
+) -> Option<SubscriptionStatus> {
+    let status = SubscriptionStatus::from_str(&subscription.status).ok()?;
+    let is_first_invoice = matches!(
+        status,
+        SubscriptionStatus::Created | SubscriptionStatus::Pending
+    );
+    match (status, payment_status) {
+        (
+            SubscriptionStatus::Paused
+            | SubscriptionStatus::Cancelled
+            | SubscriptionStatus::Failed
+            | SubscriptionStatus::InActive
+            | SubscriptionStatus::Onetime,
+            _,
+        ) => None,
+        (_, InvoiceSyncPaymentStatus::PaymentSucceeded) => subscription
+            .billing_details
+            .as_ref()
+            .map(|billing_details| active_status(billing_details, now)),
+        (SubscriptionStatus::Created, InvoiceSyncPaymentStatus::PaymentProcessing) => {
+            Some(SubscriptionStatus::Pending)
+        }
+        (_, InvoiceSyncPaymentStatus::PaymentProcessing) => None,
+        (_, InvoiceSyncPaymentStatus::PaymentFailed) if is_first_invoice => {
+            Some(SubscriptionStatus::Failed)
+        }
+        (_, InvoiceSyncPaymentStatus::PaymentFailed) => Some(SubscriptionStatus::Unpaid),
+    }
+}
+
+/// The next time the billing task of the subscription has to run, `None` when there is nothing
+/// left to do until the subscription is resumed
+pub fn next_billing_event(
+    status: SubscriptionStatus,
+    billing_details: &SubscriptionBillingDetails,
+) -> Option<PrimitiveDateTime> {
+    let events = match status {
+        SubscriptionStatus::Paused => [billing_details.resume_at, billing_details.cancel_at, None],
+        SubscriptionStatus::Cancelled
+        | SubscriptionStatus::Failed
+        | SubscriptionStatus::InActive
+        | SubscriptionStatus::Onetime
+        | SubscriptionStatus::Created => [None, None, None],
+        SubscriptionStatus::Active
+        | SubscriptionStatus::Pending
+        | SubscriptionStatus::Trial
+        | SubscriptionStatus::Unpaid => [
+            Some(billing_details.current_period_end),
+            billing_details.pause_at,
+            billing_details.cancel_at,
+        ],
+    };
+    events.into_iter().flatten().min()
+}
+
+async fn schedule_billing(
+    state: &SessionState,
+    subscription: &Subscription,
+) -> errors::SubscriptionResult<()> {
+    let status = get_status(subscription)?;
+    match subscription
+        .billing_details
+        .as_ref()
+        .and_then(|billing_details| next_billing_event(status, billing_details))
+    {
+        Some(schedule_time) => {
+            subscription_billing::schedule_subscription_billing(state, subscription, schedule_time)
+                .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Moves a natively billed subscription to the status dunning ended it in. A pause or cancellation
+/// scheduled for later is superseded by it.
+pub async fn apply_dunning_status(
+    state: &SessionState,
+    platform: &Platform,
+    subscription: &Subscription,
+    status: SubscriptionStatus,
+) -> errors::SubscriptionResult<()> {
+    let mut billing_details = get_billing_details(subscription)?;
+    match status {
+        SubscriptionStatus::Paused => billing_details.pause_at = None,
+        SubscriptionStatus::Cancelled => {
+            billing_details.pause_at = None;
+            billing_details.cancel_at = None;
+        }
+        _ => {}
+    }
+    save_billing_details(state, platform, subscription, status, billing_details).await
+}
+
+/// Stores the invoice and collects it off session with the saved payment method of the
+/// subscription. A failed charge is recorded on the invoice rather than returned as an error.
+async fn raise_invoice(
+    state: &SessionState,
+    invoice_handler: &InvoiceHandler,
+    native_invoice: &NativeInvoice,
+) -> errors::SubscriptionResult<Invoice> {
+    let is_settled = !native_invoice.total.is_greater_than(0);
+    let invoice = invoice_handler
+        .create_invoice_entry(
+            state,
+            None,
+            None,
+            native_invoice.total,
+            native_invoice.currency,
+            if is_settled {
+                InvoiceStatus::InvoicePaid
+            } else {
+                InvoiceStatus::InvoiceCreated
+            },
+            None,
+            Some(native_invoice.metadata()?),
+            None,
+        )
+        .await?;
+    if is_settled {
+        return Ok(invoice);
+    }
+
+    let payment = match invoice_handler.subscription.payment_method_id.as_deref() {
+        Some(payment_method_id) => {
+            invoice_handler
+                .create_mit_payment(
+                    state,
+                    native_invoice.total,
+                    native_invoice.currency,
+                    payment_method_id,
+                )
+                .await
+        }
+        None => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The subscription has no saved payment method".to_string(),
+        }
+        .into()),
+    };
+
+    match payment {
+        Ok(payment) => {
+            let invoice = invoice_handler
+                .update_invoice(
+                    state,
+                    invoice.id,
+                    InvoiceUpdateRequest::update_payment_and_status(
+                        payment.payment_method_id.clone(),
+                        Some(payment.payment_id.clone()),
+                        InvoiceStatus::PaymentPending,
+                        None,
+                    ),
+                )
+                .await?;
+            invoice_handler
+                .create_invoice_sync_job(state, &invoice, None, None)
+                .await?;
+            Ok(invoice)
+        }
+        Err(error) => {
+            logger::error!(?error, invoice_id = ?invoice.id, "Failed to charge native invoice");
+            invoice_handler
+                .update_invoice(
+                    state,
+                    invoice.id,
+                    InvoiceUpdateRequest::update_payment_and_status(
+                        None,
+                        None,
+                        InvoiceStatus::PaymentFailed,
+                        None,
+                    ),
+                )
+                .await
+        }
+    }
+}
+
+/// Raises the invoice of the billing period starting at `period_start` and advances the billing
+/// details to that period
+async fn bill_period(
+    state: &SessionState,
+    platform: &Platform,
+    invoice_handler: &InvoiceHandler,
+    mut billing_details: SubscriptionBillingDetails,
+    period_start: PrimitiveDateTime,
+) -> errors::SubscriptionResult<(SubscriptionBillingDetails, Invoice)> {
+    let subscription = &invoice_handler.subscription;
+    let item = BillingItem::find_for_subscription(state, platform, subscription).await?;
+    let coupon = match billing_details.coupon_id.as_deref() {
+        Some(coupon_id) => Some(
+            billing_catalog::find_coupon(state, platform, &subscription.profile_id, coupon_id)
+                .await?,
+        ),
+        None => None,
+    };
+
+    let native_invoice = NativeInvoice::new(
+        &item,
+        billing_details.quantity,
+        coupon.as_ref(),
+        billing_details.credit_balance,
+        period_start,
+        item.period_end(period_start)?,
+    );
+    let invoice = raise_invoice(state, invoice_handler, &native_invoice).await?;
+
+    billing_details.current_period_start = native_invoice.period_start;
+    billing_details.current_period_end = native_invoice.period_end;
+    billing_details.credit_balance =
+        billing_details.credit_balance - native_invoice.credits_applied;
+    if coupon.is_some() {
+        consume_coupon_cycle(&mut billing_details);
+    }
+
+    Ok((billing_details, invoice))
+}
+
+async fn save_billing_details(
+    state: &SessionState,
+    platform: &Platform,
+    subscription: &Subscription,
+    status: SubscriptionStatus,
+    billing_details: SubscriptionBillingDetails,
+) -> errors::SubscriptionResult<()> {
+    state
+        .store
+        .update_subscription_entry(
+            platform.get_processor().get_key_store(),
+            &subscription.merchant_id,
+            subscription.id.get_string_repr().to_string(),
+            SubscriptionUpdate::update_billing_details(Some(status.to_string()), billing_details),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::SubscriptionError {
+            operation: "Subscription Update".to_string(),
+        })
+        .attach_printable("subscriptions: unable to update billing details of subscription")?;
+    Ok(())
+}
+
+/// Runs the billing cycle of a subscription: applies the scheduled cancellation, pause or resume
+/// which are due and raises the invoice of the next period once the current one has ended.
+/// Returns the time the cycle has to run next.
+pub async fn run_billing_cycle(
+    state: &SessionState,
+    platform: &Platform,
+    profile: &Profile,
+    subscription: Subscription,
+    now: PrimitiveDateTime,
+) -> errors::SubscriptionResult<Option<PrimitiveDateTime>> {
+    let mut billing_details = get_billing_details(&subscription)?;
+    let mut status = get_status(&subscription)?;
+    if next_billing_event(status, &billing_details).is_none() {
+        return Ok(None);
+    }
+
+    let invoice_handler = InvoiceHandler::new(
+        subscription.clone(),
+        platform.get_processor().get_account().clone(),
+        profile.clone(),
+        platform.get_processor().get_key_store().clone(),
+    );
+    if billing_details
+        .cancel_at
+        .is_some_and(|cancel_at| cancel_at <= now)
+    {
+        billing_details.cancel_at = None;
+        save_billing_details(
+            state,
+            platform,
+            &subscription,
+            SubscriptionStatus::Cancelled,
+            billing_details,
+        )
+        .await?;
+        return Ok(None);
+    }
+
+    let mut period_start = billing_details.current_period_end;
+    match status {
+        SubscriptionStatus::Paused => match billing_details.resume_at {
+            Some(resume_at) if resume_at <= now => {
+                billing_details.resume_at = None;
+                status = active_status(&billing_details, now);
+                // A subscription resumed after its period ended starts a new period right away
+                period_start = period_start.max(now);
+            }
+            _ => return Ok(next_billing_event(status, &billing_details)),
+        },
+        _ if billing_details
+            .pause_at
+            .is_some_and(|pause_at| pause_at <= now) =>
+        {
+            billing_details.pause_at = None;
+            let next_billing_at = next_billing_event(SubscriptionStatus::Paused, &billing_details);
+            save_billing_details(
+                state,
+                platform,
+                &subscription,
+                SubscriptionStatus::Paused,
+                billing_details,
+            )
+            .await?;
+            return Ok(next_billing_at);
+        }
+        _ => {}
+    }
+
+    let mut failed_invoice = None;
+    if billing_details.current_period_end <= now {
+        let (advanced_billing_details, invoice) = bill_period(
+            state,
+            platform,
+            &invoice_handler,
+            billing_details,
+            period_start,
+        )
+        .await?;
+        billing_details = advanced_billing_details;
+        if invoice.status == InvoiceStatus::PaymentFailed {
+            status = SubscriptionStatus::Unpaid;
+            failed_invoice = Some(invoice);
+        } else if invoice.status == InvoiceStatus::InvoicePaid {
+            status = active_status(&billing_details, now);
+        }
+    }
+
+    let next_billing_at = next_billing_event(status, &billing_details);
+    save_billing_details(state, platform, &subscription, status, billing_details).await?;
+    if let Some(invoice) = failed_invoice {
+        let _ = dunning_workflow::start_dunning(state, &subscription, &invoice)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to start dunning of the invoice"));
+    }
+
+    Ok(next_billing_at)
+}
+
+pub async fn create_subscription(
+    state: SessionState,
+    platform: Platform,
+    profile: Profile,
+    request: subscription_types::CreateSubscriptionRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let item = BillingItem::find(
+        &state,
+        &platform,
+        profile.get_id(),
+        request.plan_id.as_deref(),
+        &request.item_price_id,
+    )
+    .await?;
+    item.ensure_active()?;
+    let quantity = get_quantity(request.quantity)?;
+    let coupon = find_redeemable_coupon(
+        &state,
+        &platform,
+        profile.get_id(),
+        request.coupon_code.as_deref(),
+        item.price.currency,
+    )
+    .await?;
+    let (billing_details, first_invoice) = new_billing_details(
+        &item,
+        quantity,
+        coupon.as_ref(),
+        common_utils::date_time::now(),
+    )?;
+    redeem_coupon(&state, coupon.as_ref()).await?;
+
+    let subscription_handler = SubscriptionHandler::new(&state, &platform);
+    let mut subscription = subscription_handler
+        .create_subscription_entry(
+            id_type::SubscriptionId::generate(),
+            &request.customer_id,
+            None,
+            None,
+            request.merchant_reference_id.clone(),
+            &profile,
+            Some(item.plan.id.clone()),
+            Some(item.price.id.clone()),
+            Some(billing_details),
+        )
+        .await
+        .attach_printable("subscriptions: failed to create subscription entry")?;
+
+    let invoice_handler = subscription.get_invoice_handler(profile.clone());
+    let payment = invoice_handler
+        .create_payment_with_confirm_false(
+            &state,
+            &request,
+            first_invoice.total,
+            first_invoice.currency,
+        )
+        .await
+        .attach_printable("subscriptions: failed to create payment")?;
+
+    let invoice = invoice_handler
+        .create_invoice_entry(
+            &state,
+            None,
+            Some(payment.payment_id.clone()),
+            first_invoice.total,
+            first_invoice.currency,
+            InvoiceStatus::InvoiceCreated,
+            None,
+            Some(first_invoice.metadata()?),
+            None,
+        )
+        .await
+        .attach_printable("subscriptions: failed to create invoice")?;
+
+    subscription
+        .update_subscription(SubscriptionUpdate::new(
+            None,
+            payment.payment_method_id.clone(),
+            None,
+            None,
+            None,
+        ))
+        .await
+        .attach_printable("subscriptions: failed to update subscription")?;
+
+    let response = SubscriptionWithHandler::to_subscription_response(
+        &subscription.subscription,
+        Some(payment),
+        Some(&invoice),
+    )?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn create_and_confirm_subscription(
+    state: SessionState,
+    platform: Platform,
+    profile: Profile,
+    request: subscription_types::CreateAndConfirmSubscriptionRequest,
+) -> RouterResponse<subscription_types::ConfirmSubscriptionResponse> {
+    let item = BillingItem::find(
+        &state,
+        &platform,
+        profile.get_id(),
+        request.plan_id.as_deref(),
+        &request.item_price_id,
+    )
+    .await?;
+    item.ensure_active()?;
+    let quantity = get_quantity(request.quantity)?;
+    let coupon = find_redeemable_coupon(
+        &state,
+        &platform,
+        profile.get_id(),
+        request.coupon_code.as_deref(),
+        item.price.currency,
+    )
+    .await?;
+    let (billing_details, first_invoice) = new_billing_details(
+        &item,
+        quantity,
+        coupon.as_ref(),
+        common_utils::date_time::now(),
+    )?;
+    redeem_coupon(&state, coupon.as_ref()).await?;
+
+    let subscription_handler = SubscriptionHandler::new(&state, &platform);
+    let mut subscription = subscription_handler
+        .create_subscription_entry(
+            id_type::SubscriptionId::generate(),
+            &request.customer_id,
+            None,
+            None,
+            request.merchant_reference_id.clone(),
+            &profile,
+            Some(item.plan.id.clone()),
+            Some(item.price.id.clone()),
+            Some(billing_details),
+        )
+        .await
+        .attach_printable("subscriptions: failed to create subscription entry")?;
+
+    let invoice_handler = subscription.get_invoice_handler(profile.clone());
+    let payment = invoice_handler
+        .create_and_confirm_payment(
+            &state,
+            &request,
+            first_invoice.total,
+            first_invoice.currency,
+        )
+        .await?;
+
+    let invoice = invoice_handler
+        .create_invoice_entry(
+            &state,
+            None,
+            Some(payment.payment_id.clone()),
+            first_invoice.total,
+            first_invoice.currency,
+            InvoiceStatus::PaymentPending,
+            None,
+            Some(first_invoice.metadata()?),
+            None,
+        )
+        .await?;
+
+    invoice_handler
+        .create_invoice_sync_job(&state, &invoice, None, None)
+        .await?;
+
+    let response = complete_confirmation(&state, &mut subscription, &invoice, &payment).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn confirm_subscription(
+    state: SessionState,
+    platform: Platform,
+    profile: Profile,
+    request: subscription_types::ConfirmSubscriptionRequest,
+    subscription_id: id_type::SubscriptionId,
+) -> RouterResponse<subscription_types::ConfirmSubscriptionResponse> {
+    let handler = SubscriptionHandler::new(&state, &platform);
+    let mut subscription = handler.find_subscription(subscription_id).await?;
+
+    let invoice_handler = subscription.get_invoice_handler(profile);
+    let invoice = invoice_handler
+        .get_latest_invoice(&state)
+        .await
+        .attach_printable("subscriptions: failed to get latest invoice")?;
+    let payment = invoice_handler
+        .confirm_payment(
+            &state,
+            invoice.payment_intent_id.clone().ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "payment_intent_id",
+                },
+            )?,
+            &request,
+        )
+        .await?;
+
+    let invoice = invoice_handler
+        .update_invoice(
+            &state,
+            invoice.id,
+            InvoiceUpdateRequest::update_payment_and_status(
+                payment.payment_method_id.clone(),
+                Some(payment.payment_id.clone()),
+                InvoiceStatus::PaymentPending,
+                None,
+            ),
+        )
+        .await?;
+
+    invoice_handler
+        .create_invoice_sync_job(&state, &invoice, None, None)
+        .await?;
+
+    let response = complete_confirmation(&state, &mut subscription, &invoice, &payment).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Saves the payment method of the first payment and starts billing the subscription, unless the
+/// payment already failed
+async fn complete_confirmation(
+    state: &SessionState,
+    subscription: &mut SubscriptionWithHandler<'_>,
+    invoice: &Invoice,
+    payment: &subscription_types::PaymentResponseData,
+) -> errors::SubscriptionResult<subscription_types::ConfirmSubscriptionResponse> {
+    let status = status_after_payment(
+        &subscription.subscription,
+        &InvoiceSyncPaymentStatus::from(payment.status),
+        common_utils::date_time::now(),
+    );
+
+    subscription
+        .update_subscription(SubscriptionUpdate::new(
+            None,
+            payment.payment_method_id.clone(),
+            status.map(|status| status.to_string()),
+            None,
+            None,
+        ))
+        .await?;
+
+    if !matches!(status, Some(SubscriptionStatus::Failed)) {
+        schedule_billing(state, &subscription.subscription).await?;
+    }
+
+    subscription_types::ConfirmSubscriptionResponse::foreign_try_from((
+        &subscription.subscription,
+        invoice,
+        payment,
+    ))
+}
+
+pub async fn get_estimate(
+    state: SessionState,
+    platform: Platform,
+    profile: Profile,
+    query: subscription_types::EstimateSubscriptionQuery,
+) -> RouterResponse<subscription_types::EstimateSubscriptionResponse> {
+    let item = BillingItem::find(
+        &state,
+        &platform,
+        profile.get_id(),
+        query.plan_id.as_deref(),
+        &query.item_price_id,
+    )
+    .await?;
+    item.ensure_active()?;
+    let quantity = get_quantity(query.quantity)?;
+    let coupon = find_redeemable_coupon(
+        &state,
+        &platform,
+        profile.get_id(),
+        query.coupon_code.as_deref(),
+        item.price.currency,
+    )
+    .await?;
+
+    // The estimate covers a full billing cycle, for plans with a trial the cycle following it
+    let now = common_utils::date_time::now();
+    let trial_end = item.trial_end(now);
+    let period_start = trial_end.unwrap_or(now);
+    let period_end = item.period_end(period_start)?;
+    let invoice = NativeInvoice::new(
+        &item,
+        quantity,
+        coupon.as_ref(),
+        MinorUnit::new(0),
+        period_start,
+        period_end,
+    );
+    let amount_due = if trial_end.is_some() {
+        MinorUnit::new(0)
+    } else {
+        invoice.total
+    };
+
+    let mut response = subscription_types::EstimateSubscriptionResponse::from(
+        invoice.to_estimate(amount_due, trial_end.unwrap_or(period_end)),
+    );
+    response.plan_id = Some(item.plan.id);
+    response.item_price_id = Some(item.price.id);
+    response.coupon_code = query.coupon_code;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn get_subscription_items(
+    state: SessionState,
+    platform: Platform,
+    profile: Profile,
+    query: subscription_types::GetSubscriptionItemsQuery,
+) -> RouterResponse<Vec<subscription_types::GetSubscriptionItemsResponse>> {
+    // The billing catalog has no addons, every plan is subscribed to on its own
+    if matches!(
+        query.item_type,
+        subscription_types::SubscriptionItemType::Addon
+    ) {
+        return Ok(ApplicationResponse::Json(Vec::new()));
+    }
+
+    let plans = state
+        .store
+        .list_billing_plans_by_merchant_id_profile_id(
+            platform.get_processor().get_account().get_id(),
+            profile.get_id(),
+            Some(
+                query
+                    .limit
+                    .map(i64::from)
+                    .unwrap_or(billing_catalog::BILLING_CATALOG_MAX_LIST_COUNT),
+            ),
+            query.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("subscriptions: unable to list billing plans")?;
+
+    let mut response = Vec::new();
+    for plan in plans.into_iter().filter(|plan| plan.is_active) {
+        let prices = state
+            .store
+            .list_billing_prices_by_merchant_id_plan_id(&plan.merchant_id, &plan.id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("subscriptions: unable to list billing prices")?;
+
+        response.push(subscription_types::GetSubscriptionItemsResponse {
+            item_id: plan.id.clone(),
+            name: plan.name.clone(),
+            description: plan.description.clone(),
+            price_id: prices
+                .into_iter()
+                .filter(|price| price.is_active)
+                .map(|price| subscription_types::SubscriptionItemPrices {
+                    price_id: price.id.clone(),
+                    item_id: Some(plan.id.clone()),
+                    amount: price_amount(&price, 1),
+                    currency: price.currency,
+                    interval: match price.billing_interval {
+                        BillingInterval::Day => subscription_types::PeriodUnit::Day,
+                        BillingInterval::Week => subscription_types::PeriodUnit::Week,
+                        BillingInterval::Month => subscription_types::PeriodUnit::Month,
+                        BillingInterval::Year => subscription_types::PeriodUnit::Year,
+                    },
+                    interval_count: i64::from(price.interval_count),
+                    trial_period: plan.trial_period_days.map(i64::from),
+                    trial_period_unit: plan
+                        .trial_period_days
+                        .map(|_| subscription_types::PeriodUnit::Day),
+                })
+                .collect(),
+        });
+    }
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Moves the subscription to another price. The unused part of a paid period is credited and the
+/// remaining part is charged at the new price right away, a change of the billing cycle starts a
+/// new period. Credits exceeding the charge are kept for the next invoices. A period which has not
+/// been paid is neither credited nor charged, the new price applies from the next period.
+///
+/// The change is saved before any invoice is raised, a proration invoice which can not be charged
+/// moves the subscription to unpaid and is retried by dunning.
+pub async fn update_subscription(
+    state: SessionState,
+    platform: Platform,
+    profile: Profile,
+    subscription_id: id_type::SubscriptionId,
+    request: subscription_types::UpdateSubscriptionRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let handler = SubscriptionHandler::new(&state, &platform);
+    let mut subscription_entry = handler.find_subscription(subscription_id).await?;
+    let subscription = subscription_entry.subscription.clone();
+    let mut billing_details = get_billing_details(&subscription)?;
+    let status = get_status(&subscription)?;
+
+    let current_item = BillingItem::find_for_subscription(&state, &platform, &subscription).await?;
+    let new_item = BillingItem::find(
+        &state,
+        &platform,
+        profile.get_id(),
+        Some(&request.plan_id),
+        &request.item_price_id,
+    )
+    .await?;
+    new_item.ensure_active()?;
+    if new_item.price.currency != current_item.price.currency {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The currency of a subscription can not be changed".to_string(),
+        }
+        .into());
+    }
+    let quantity = match request.quantity {
+        Some(quantity) => get_quantity(Some(quantity))?,
+        None => billing_details.quantity,
+    };
+
+    let now = common_utils::date_time::now();
+    let mut first_invoice = None;
+    let mut proration_invoice = None;
+    match status {
+        SubscriptionStatus::Cancelled
+        | SubscriptionStatus::Failed
+        | SubscriptionStatus::InActive
+        | SubscriptionStatus::Onetime => {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("A subscription in {status} status can not be updated"),
+            }
+            .into());
+        }
+        // The first invoice is still open, it is priced again
+        SubscriptionStatus::Created => {
+            let coupon = match billing_details.coupon_id.as_deref() {
+                Some(coupon_id) => Some(
+                    billing_catalog::find_coupon(&state, &platform, profile.get_id(), coupon_id)
+                        .await?,
+                ),
+                None => None,
+            };
+            let (new_billing_details, native_invoice) = new_billing_details(
+                &new_item,
+                quantity,
+                coupon.as_ref(),
+                billing_details.current_period_start,
+            )?;
+            billing_details = new_billing_details;
+            first_invoice = Some(native_invoice);
+        }
+        // Nothing has been paid for the current period, the new price applies from the next one
+        SubscriptionStatus::Trial
+        | SubscriptionStatus::Pending
+        | SubscriptionStatus::Paused
+        | SubscriptionStatus::Unpaid => billing_details.quantity = quantity,
+        SubscriptionStatus::Active => {
+            let unused = prorate(
+                current_item.amount_for_quantity(billing_details.quantity),
+                billing_details.current_period_start,
+                billing_details.current_period_end,
+                now,
+            );
+            let (charge, period_start, period_end) = if new_item.has_same_cycle(&current_item) {
+                (
+                    prorate(
+                        new_item.amount_for_quantity(quantity),
+                        billing_details.current_period_start,
+                        billing_details.current_period_end,
+                        now,
+                    ),
+                    billing_details.current_period_start,
+                    billing_details.current_period_end,
+                )
+            } else {
+                (
+                    new_item.amount_for_quantity(quantity),
+                    now,
+                    new_item.period_end(now)?,
+                )
+            };
+            let net_amount = charge
+                .get_amount_as_i64()
+                .saturating_sub(unused.get_amount_as_i64());
+
+            if net_amount > 0 {
+                let line_items = vec![
+                    NativeInvoiceLineItem {
+                        amount: MinorUnit::new(unused.get_amount_as_i64().saturating_neg()),
+                        ..current_item.line_item(
+                            LINE_ITEM_PRORATION,
+                            billing_details.quantity,
+                            MinorUnit::new(0),
+                        )
+                    },
+                    new_item.line_item(LINE_ITEM_PRORATION, quantity, charge),
+                ];
+                let native_invoice = NativeInvoice::with_credits(
+                    new_item.price.currency,
+                    line_items,
+                    MinorUnit::new(net_amount),
+                    MinorUnit::new(0),
+                    billing_details.credit_balance,
+                    period_start,
+                    period_end,
+                );
+                billing_details.credit_balance =
+                    billing_details.credit_balance - native_invoice.credits_applied;
+                proration_invoice = Some(native_invoice);
+            } else {
+                billing_details.credit_balance =
+                    billing_details.credit_balance + MinorUnit::new(net_amount.saturating_neg());
+            }
+
+            billing_details.quantity = quantity;
+            billing_details.current_period_start = period_start;
+            billing_details.current_period_end = period_end;
+        }
+    }
+
+    subscription_entry
+        .update_subscription(SubscriptionUpdate {
+            billing_details: Some(billing_details.clone()),
+            ..SubscriptionUpdate::new(
+                None,
+                None,
+                None,
+                Some(new_item.plan.id),
+                Some(new_item.price.id),
+            )
+        })
+        .await?;
+
+    let invoice_handler = subscription_entry.get_invoice_handler(profile.clone());
+    let mut invoice = None;
+    if let Some(first_invoice) = first_invoice {
+        let latest_invoice = invoice_handler
+            .get_latest_invoice(&state)
+            .await
+            .attach_printable("subscriptions: failed to get latest invoice")?;
+        let updated_invoice = invoice_handler
+            .update_invoice(
+                &state,
+                latest_invoice.id,
+                InvoiceUpdateRequest::update_amount_and_currency(
+                    first_invoice.total,
+                    first_invoice.currency.to_string(),
+                ),
+            )
+            .await?;
+        invoice_handler
+            .update_payment(
+                &state,
+                first_invoice.total,
+                first_invoice.currency,
+                updated_invoice.payment_intent_id.clone().ok_or(
+                    errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "payment_intent_id",
+                    },
+                )?,
+            )
+            .await?;
+        invoice = Some(updated_invoice);
+    }
+
+    let mut failed_invoice = None;
+    if let Some(proration_invoice) = proration_invoice {
+        let raised_invoice = raise_invoice(&state, &invoice_handler, &proration_invoice).await?;
+        if raised_invoice.status == InvoiceStatus::PaymentFailed {
+            subscription_entry
+                .update_subscription(SubscriptionUpdate::update_billing_details(
+                    Some(SubscriptionStatus::Unpaid.to_string()),
+                    billing_details,
+                ))
+                .await?;
+            failed_invoice = Some(raised_invoice.clone());
+        }
+        invoice = Some(raised_invoice);
+    }
+
+    schedule_billing(&state, &subscription_entry.subscription).await?;
+    if let Some(invoice) = failed_invoice {
+        let _ = dunning_workflow::start_dunning(&state, &subscription_entry.subscription, &invoice)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to start dunning of the invoice"));
+    }
+
+    let response = SubscriptionWithHandler::to_subscription_response(
+        &subscription_entry.subscription,
+        None,
+        invoice.as_ref(),
+    )?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn pause_subscription(
+    state: SessionState,
+    platform: Platform,
+    subscription_id: id_type::SubscriptionId,
+    request: subscription_types::PauseSubscriptionRequest,
+) -> RouterResponse<subscription_types::PauseSubscriptionResponse> {
+    let handler = SubscriptionHandler::new(&state, &platform);
+    let mut subscription_entry = handler.find_subscription(subscription_id).await?;
+    let mut billing_details = get_billing_details(&subscription_entry.subscription)?;
+    let mut status = get_status(&subscription_entry.subscription)?;
+    if !matches!(
+        status,
+        SubscriptionStatus::Active
+            | SubscriptionStatus::Trial
+            | SubscriptionStatus::Pending
+            | SubscriptionStatus::Unpaid
+    ) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("A subscription in {status} status can not be paused"),
+        }
+        .into());
+    }
+
+    let now = common_utils::date_time::now();
+    let pause_at = match request.pause_option {
+        Some(subscription_types::PauseOption::Immediately) => now,
+        Some(subscription_types::PauseOption::EndOfTerm) => billing_details.current_period_end,
+        Some(subscription_types::PauseOption::SpecificDate) => {
+            request
+                .pause_at
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "pause_at",
+                })?
+        }
+        None => request.pause_at.unwrap_or(now),
+    };
+    if pause_at <= now {
+        status = SubscriptionStatus::Paused;
+        billing_details.pause_at = None;
+    } else {
+        billing_details.pause_at = Some(pause_at);
+    }
+
+    subscription_entry
+        .update_subscription(SubscriptionUpdate::update_billing_details(
+            Some(status.to_string()),
+            billing_details,
+        ))
+        .await?;
+    schedule_billing(&state, &subscription_entry.subscription).await?;
+
+    let subscription = &subscription_entry.subscription;
+    Ok(ApplicationResponse::Json(
+        subscription_types::PauseSubscriptionResponse {
+            id: subscription.id.clone(),
+            status,
+            merchant_reference_id: subscription.merchant_reference_id.clone(),
+            profile_id: subscription.profile_id.clone(),
+            merchant_id: subscription.merchant_id.clone(),
+            customer_id: subscription.customer_id.clone(),
+            paused_at: Some(pause_at.max(now)),
+        },
+    ))
+}
+
+pub async fn resume_subscription(
+    state: SessionState,
+    platform: Platform,
+    profile: Profile,
+    subscription_id: id_type::SubscriptionId,
+    request: subscription_types::ResumeSubscriptionRequest,
+) -> RouterResponse<subscription_types::ResumeSubscriptionResponse> {
+    let handler = SubscriptionHandler::new(&state, &platform);
+    let mut subscription_entry = handler.find_subscription(subscription_id).await?;
+    let mut billing_details = get_billing_details(&subscription_entry.subscription)?;
+    let mut status = get_status(&subscription_entry.subscription)?;
+    let mut failed_invoice = None;
+
+    let now = common_utils::date_time::now();
+    if !matches!(status, SubscriptionStatus::Paused) {
+        // Resuming a subscription which is not paused yet withdraws its scheduled pause
+        if billing_details.pause_at.take().is_none() {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("A subscription in {status} status can not be resumed"),
+            }
+            .into());
+        }
+    } else {
+        let resume_at = match request.resume_option {
+            Some(subscription_types::ResumeOption::Immediately) => now,
+            Some(subscription_types::ResumeOption::SpecificDate) => {
+                request
+                    .resume_date
+                    .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "resume_date",
+                    })?
+            }
+            None => request.resume_date.unwrap_or(now),
+        };
+
+        if resume_at > now {
+            billing_details.resume_at = Some(resume_at);
+        } else {
+            billing_details.resume_at = None;
+            status = active_status(&billing_details, now);
+            // A subscription resumed after its period ended is billed for a new period right away
+            if billing_details.current_period_end <= now {
+                let invoice_handler = subscription_entry.get_invoice_handler(profile);
+                let (advanced_billing_details, invoice) =
+                    bill_period(&state, &platform, &invoice_handler, billing_details, now).await?;
+                billing_details = advanced_billing_details;
+                if invoice.status == InvoiceStatus::PaymentFailed {
+                    status = SubscriptionStatus::Unpaid;
+                    failed_invoice = Some(invoice);
+                }
+            }
+        }
+    }
+
+    subscription_entry
+        .update_subscription(SubscriptionUpdate::update_billing_details(
+            Some(status.to_string()),
+            billing_details,
+        ))
+        .await?;
+    schedule_billing(&state, &subscription_entry.subscription).await?;
+    if let Some(invoice) = failed_invoice {
+        let _ = dunning_workflow::start_dunning(&state, &subscription_entry.subscription, &invoice)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to start dunning of the invoice"));
+    }
+
+    let subscription = &subscription_entry.subscription;
+    Ok(ApplicationResponse::Json(
+        subscription_types::ResumeSubscriptionResponse {
+            id: subscription.id.clone(),
+            status,
+            merchant_reference_id: subscription.merchant_reference_id.clone(),
+            profile_id: subscription.profile_id.clone(),
+            merchant_id: subscription.merchant_id.clone(),
+            customer_id: subscription.customer_id.clone(),
+            next_billing_at: subscription
+                .billing_details
+                .as_ref()
+                .and_then(|billing_details| billing_details.resume_at)
+                .or(subscription
+                    .billing_details
+                    .as_ref()
+                    .map(|billing_details| billing_details.current_period_end)),
+        },
+    ))
+}
+
+pub async fn cancel_subscription(
+    state: SessionState,
+    platform: Platform,
+    subscription_id: id_type::SubscriptionId,
+    request: subscription_types::CancelSubscriptionRequest,
+) -> RouterResponse<subscription_types::CancelSubscriptionResponse> {
+    let handler = SubscriptionHandler::new(&state, &platform);
+    let mut subscription_entry = handler.find_subscription(subscription_id).await?;
+    let mut billing_details = get_billing_details(&subscription_entry.subscription)?;
+    let mut status = get_status(&subscription_entry.subscription)?;
+    if matches!(
+        status,
+        SubscriptionStatus::Cancelled | SubscriptionStatus::Failed
+    ) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("A subscription in {status} status can not be cancelled"),
+        }
+        .into());
+    }
+
+    let now = common_utils::date_time::now();
+    let cancel_at = match request.cancel_option {
+        Some(subscription_types::CancelOption::Immediately) => now,
+        Some(subscription_types::CancelOption::EndOfTerm) => billing_details.current_period_end,
+        Some(subscription_types::CancelOption::SpecificDate) => {
+            request
+                .cancel_at
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "cancel_at",
+                })?
+        }
+        None => request.cancel_at.unwrap_or(now),
+    };
+    if cancel_at <= now {
+        status = SubscriptionStatus::Cancelled;
+        billing_details.cancel_at = None;
+    } else {
+        billing_details.cancel_at = Some(cancel_at);
+    }
+
+    subscription_entry
+        .update_subscription(SubscriptionUpdate::update_billing_details(
+            Some(status.to_string()),
+            billing_details,
+        ))
+        .await?;
+    schedule_billing(&state, &subscription_entry.subscription).await?;
+
+    let subscription = &subscription_entry.subscription;
+    Ok(ApplicationResponse::Json(
+        subscription_types::CancelSubscriptionResponse {
+            id: subscription.id.clone(),
+            status,
+            merchant_reference_id: subscription.merchant_reference_id.clone(),
+            profile_id: subscription.profile_id.clone(),
+            merchant_id: subscription.merchant_id.clone(),
+            customer_id: subscription.customer_id.clone(),
+            cancelled_at: Some(cancel_at.max(now)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_types::subscriptions::{PriceTier, PriceTiers};
+
+    use super::*;
+
+    fn price(
+        pricing_model: BillingPricingModel,
+        unit_amount: Option<i64>,
+        tiers: Option<PriceTiers>,
+    ) -> catalog::BillingPrice {
+        let now = common_utils::date_time::now();
+        catalog::BillingPrice {
+            id: "price_test".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test")).unwrap(),
+            plan_id: "plan_test".to_string(),
+            currency: Currency::USD,
+            pricing_model,
+            unit_amount: unit_amount.map(MinorUnit::new),
+            tiers,
+            billing_interval: BillingInterval::Month,
+            interval_count: 1,
+            is_active: true,
+            metadata: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    fn coupon(
+        discount_type: CouponDiscountType,
+        percent_off: Option<i32>,
+        amount_off: Option<i64>,
+        duration: CouponDuration,
+        duration_in_cycles: Option<i32>,
+    ) -> catalog::BillingCoupon {
+        let now = common_utils::date_time::now();
+        catalog::BillingCoupon {
+            id: "coupon_test".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test")).unwrap(),
+            code: "TEST".to_string(),
+            name: "Test".to_string(),
+            discount_type,
+            percent_off,
+            amount_off: amount_off.map(MinorUnit::new),
+            currency: None,
+            duration,
+            duration_in_cycles,
+            max_redemptions: None,
+            times_redeemed: 0,
+            redeem_by: None,
+            is_active: true,
+            metadata: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_prorate() {
+        let period_start = common_utils::date_time::now();
+        let period_end = period_start.saturating_add(time::Duration::days(30));
+        let amount = MinorUnit::new(3000);
+
+        let halfway = period_start.saturating_add(time::Duration::days(15));
+        assert_eq!(
+            prorate(amount, period_start, period_end, halfway),
+            MinorUnit::new(1500)
+        );
+        assert_eq!(
+            prorate(amount, period_start, period_end, period_start),
+            amount
+        );
+        let after_end = period_end.saturating_add(time::Duration::days(1));
+        assert_eq!(
+            prorate(amount, period_start, period_end, after_end),
+            MinorUnit::new(0)
+        );
+        let before_start = period_start.saturating_sub(time::Duration::days(1));
+        assert_eq!(
+            prorate(amount, period_start, period_end, before_start),
+            amount
+        );
+    }
+
+    #[test]
+    fn test_price_amount() {
+        let flat = price(BillingPricingModel::Flat, Some(1000), None);
+        assert_eq!(price_amount(&flat, 5), MinorUnit::new(1000));
+
+        let per_unit = price(BillingPricingModel::PerUnit, Some(1000), None);
+        assert_eq!(price_amount(&per_unit, 5), MinorUnit::new(5000));
+
+        let tiers = PriceTiers(vec![
+            PriceTier {
+                up_to: Some(10),
+                unit_amount: MinorUnit::new(100),
+                flat_amount: Some(MinorUnit::new(500)),
+            },
+            PriceTier {
+                up_to: None,
+                unit_amount: MinorUnit::new(50),
+                flat_amount: None,
+            },
+        ]);
+        let tiered = price(BillingPricingModel::Tiered, None, Some(tiers));
+        assert_eq!(price_amount(&tiered, 4), MinorUnit::new(900));
+        assert_eq!(price_amount(&tiered, 10), MinorUnit::new(1500));
+        assert_eq!(price_amount(&tiered, 15), MinorUnit::new(1750));
+        assert_eq!(price_amount(&tiered, 0), MinorUnit::new(0));
+    }
+
+    #[test]
+    fn test_coupon_discount() {
+        let percentage = coupon(
+            CouponDiscountType::Percentage,
+            Some(25),
+            None,
+            CouponDuration::Once,
+            None,
+        );
+        assert_eq!(
+            coupon_discount(&percentage, MinorUnit::new(1000)),
+            MinorUnit::new(250)
+        );
+
+        let fixed = coupon(
+            CouponDiscountType::FixedAmount,
+            None,
+            Some(1500),
+            CouponDuration::Once,
+            None,
+        );
+        assert_eq!(
+            coupon_discount(&fixed, MinorUnit::new(2000)),
+            MinorUnit::new(1500)
+        );
+        // The discount never exceeds the amount it applies to
+        assert_eq!(
+            coupon_discount(&fixed, MinorUnit::new(1000)),
+            MinorUnit::new(1000)
+        );
+        assert_eq!(
+            coupon_discount(&fixed, MinorUnit::new(-100)),
+            MinorUnit::new(0)
+        );
+    }
+
+    #[test]
+    fn test_coupon_cycles() {
+        let once = coupon(
+            CouponDiscountType::Percentage,
+            Some(10),
+            None,
+            CouponDuration::Once,
+            None,
+        );
+        assert_eq!(coupon_cycles(&once), Some(1));
+
+        let repeating = coupon(
+            CouponDiscountType::Percentage,
+            Some(10),
+            None,
+            CouponDuration::Repeating,
+            Some(3),
+        );
+        assert_eq!(coupon_cycles(&repeating), Some(3));
+
+        let forever = coupon(
+            CouponDiscountType::Percentage,
+            Some(10),
+            None,
+            CouponDuration::Forever,
+            None,
+        );
+        assert_eq!(coupon_cycles(&forever), None);
+    }
+}
@@ -223,6 +223,29 @@ impl PaymentsApiClient {
         .await
     }
 
+    pub async fn create_payment_link(
+        state: &SessionState,
+        request: subscription_types::CreatePaymentLinkRequestData,
+        merchant_id: &str,
+        profile_id: &str,
+    ) -> errors::SubscriptionResult<subscription_types::PaymentResponseData> {
+        let base_url = &state.conf.internal_services.payments_base_url;
+        let url = format!("{}/payments", base_url);
+
+        Self::make_payment_api_call(
+            state,
+            services::Method::Post,
+            url,
+            Some(common_utils::request::RequestContent::Json(Box::new(
+                request,
+            ))),
+            "Create Payment Link",
+            merchant_id,
+            profile_id,
+        )
+        .await
+    }
+
     pub async fn update_payment(
         state: &SessionState,
         request: subscription_types::CreatePaymentsRequestData,
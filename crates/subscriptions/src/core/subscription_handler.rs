@@ -11,7 +11,7 @@ use hyperswitch_domain_models::{
     customer, merchant_connector_account,
     platform::Platform,
     router_response_types::{self, subscriptions as subscription_response_types},
-    subscription::{Subscription, SubscriptionStatus},
+    subscription::{Subscription, SubscriptionBillingDetails, SubscriptionStatus},
 };
 use hyperswitch_masking::Secret;
 
@@ -39,12 +39,13 @@ impl<'a> SubscriptionHandler<'a> {
         &self,
         subscription_id: common_utils::id_type::SubscriptionId,
         customer_id: &common_utils::id_type::CustomerId,
-        billing_processor: connector_enums::Connector,
-        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        billing_processor: Option<connector_enums::Connector>,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
         merchant_reference_id: Option<String>,
         profile: &hyperswitch_domain_models::business_profile::Profile,
         plan_id: Option<String>,
         item_price_id: Option<String>,
+        billing_details: Option<SubscriptionBillingDetails>,
     ) -> errors::SubscriptionResult<SubscriptionWithHandler<'_>> {
         let store = self.state.store.clone();
         let db = store.as_ref();
@@ -52,9 +53,9 @@ impl<'a> SubscriptionHandler<'a> {
         let mut subscription = Subscription {
             id: subscription_id,
             status: SubscriptionStatus::Created.to_string(),
-            billing_processor: Some(billing_processor.to_string()),
+            billing_processor: billing_processor.map(|connector| connector.to_string()),
             payment_method_id: None,
-            merchant_connector_id: Some(merchant_connector_id),
+            merchant_connector_id,
             client_secret: None,
             connector_subscription_id: None,
             merchant_id: self.platform.get_processor().get_account().get_id().clone(),
@@ -66,6 +67,7 @@ impl<'a> SubscriptionHandler<'a> {
             merchant_reference_id,
             plan_id,
             item_price_id,
+            billing_details,
         };
 
         subscription.generate_and_set_client_secret();
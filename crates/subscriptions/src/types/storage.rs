@@ -1 +1,3 @@
+pub mod dunning;
 pub mod invoice_sync;
+pub mod subscription_billing;
@@ -0,0 +1,20 @@
+use common_utils::id_type;
+use time::PrimitiveDateTime;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DunningTrackingData {
+    pub subscription_id: id_type::SubscriptionId,
+    pub invoice_id: id_type::InvoiceId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub customer_id: id_type::CustomerId,
+    /// Time of the payment failure that started dunning, retries are scheduled relative to it
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub failed_at: PrimitiveDateTime,
+    /// Set while the outcome of the retry payment of the current step is awaited
+    #[serde(default)]
+    pub retry_pending: bool,
+    /// Payments behind the payment links sent to the customer, in the order they were sent
+    #[serde(default)]
+    pub payment_link_payment_ids: Vec<id_type::PaymentId>,
+}
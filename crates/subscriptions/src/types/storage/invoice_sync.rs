@@ -9,7 +9,8 @@ pub struct InvoiceSyncTrackingData {
     pub customer_id: id_type::CustomerId,
     // connector_invoice_id is optional because in some cases (Trial/Future), the invoice might not have been created in the connector yet.
     pub connector_invoice_id: Option<id_type::InvoiceId>,
-    pub connector_name: api_enums::Connector, // The connector to which the invoice belongs
+    // connector_name is absent for invoices raised by the native billing engine, which are settled locally.
+    pub connector_name: Option<api_enums::Connector>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -20,7 +21,7 @@ pub struct InvoiceSyncRequest {
     pub profile_id: id_type::ProfileId,
     pub customer_id: id_type::CustomerId,
     pub connector_invoice_id: Option<id_type::InvoiceId>,
-    pub connector_name: api_enums::Connector,
+    pub connector_name: Option<api_enums::Connector>,
 }
 
 impl From<InvoiceSyncRequest> for InvoiceSyncTrackingData {
@@ -46,7 +47,7 @@ impl InvoiceSyncRequest {
         profile_id: id_type::ProfileId,
         customer_id: id_type::CustomerId,
         connector_invoice_id: Option<id_type::InvoiceId>,
-        connector_name: api_enums::Connector,
+        connector_name: Option<api_enums::Connector>,
     ) -> Self {
         Self {
             subscription_id,
@@ -69,7 +70,7 @@ impl InvoiceSyncTrackingData {
         profile_id: id_type::ProfileId,
         customer_id: id_type::CustomerId,
         connector_invoice_id: Option<id_type::InvoiceId>,
-        connector_name: api_enums::Connector,
+        connector_name: Option<api_enums::Connector>,
     ) -> Self {
         Self {
             subscription_id,
@@ -0,0 +1,8 @@
+use common_utils::id_type;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionBillingTrackingData {
+    pub subscription_id: id_type::SubscriptionId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+}
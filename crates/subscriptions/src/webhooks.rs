@@ -114,12 +114,12 @@ pub async fn incoming_webhook_flow(
     let invoice_entry = invoice_handler
         .create_invoice_entry(
             &state,
-            billing_connector_mca_id.clone(),
+            Some(billing_connector_mca_id.clone()),
             Some(payment_id),
             mit_payment_data.amount_due,
             mit_payment_data.currency_code,
             InvoiceStatus::PaymentPending,
-            connector,
+            Some(connector),
             None,
             Some(mit_payment_data.invoice_id.clone()),
         )
@@ -132,7 +132,7 @@ pub async fn incoming_webhook_flow(
             &state,
             &invoice_entry,
             Some(mit_payment_data.invoice_id.clone()),
-            connector,
+            Some(connector),
         )
         .await?;
 
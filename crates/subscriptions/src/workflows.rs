@@ -2,7 +2,11 @@
 //!
 //! This module contains workflow definitions for subscription-related operations
 
+pub mod dunning;
 pub mod invoice_sync;
+pub mod subscription_billing;
 
 // Re-export workflow types for easier access
+pub use dunning::*;
 pub use invoice_sync::*;
+pub use subscription_billing::*;
@@ -0,0 +1,185 @@
+use common_utils::{errors::CustomResult, ext_traits::Encode};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{invoice::Invoice, platform::Platform, subscription::Subscription};
+use router_env::logger;
+use scheduler::{
+    errors,
+    workflows::storage::{
+        business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate,
+    },
+};
+
+use crate::{
+    core::{dunning, errors as router_errors},
+    state::SubscriptionState as SessionState,
+    types::storage,
+};
+
+const DUNNING_WORKFLOW: &str = "DUNNING";
+const DUNNING_WORKFLOW_TAG: &str = "SUBSCRIPTION";
+
+/// An invoice is dunned at most once, by a task keyed on the invoice
+fn get_dunning_process_id(invoice: &Invoice) -> String {
+    format!("{DUNNING_WORKFLOW}_{}", invoice.id.get_string_repr())
+}
+
+/// Starts dunning an invoice of `subscription` whose payment failed, when the profile of the
+/// subscription has a dunning policy. A retry failing while the invoice is dunned is picked up by
+/// the running task.
+pub async fn start_dunning(
+    state: &SessionState,
+    subscription: &Subscription,
+    invoice: &Invoice,
+) -> CustomResult<(), router_errors::ApiErrorResponse> {
+    if !dunning::is_dunnable(subscription)
+        || dunning::find_dunning_policy(state, &subscription.profile_id)
+            .await?
+            .is_none()
+    {
+        return Ok(());
+    }
+
+    let process_id = get_dunning_process_id(invoice);
+    let existing_process = state
+        .store
+        .find_process_by_id(&process_id)
+        .await
+        .change_context(router_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("subscriptions: unable to find dunning process")?;
+    if existing_process.is_some() {
+        return Ok(());
+    }
+
+    let now = common_utils::date_time::now();
+    let tracking_data = storage::dunning::DunningTrackingData {
+        subscription_id: subscription.id.clone(),
+        invoice_id: invoice.id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+        profile_id: subscription.profile_id.clone(),
+        customer_id: subscription.customer_id.clone(),
+        failed_at: now,
+        retry_pending: false,
+        payment_link_payment_ids: Vec::new(),
+    };
+
+    let process_tracker_entry = ProcessTrackerNew::new(
+        process_id,
+        DUNNING_WORKFLOW.to_string(),
+        common_enums::ProcessTrackerRunner::DunningWorkflow,
+        vec![DUNNING_WORKFLOW_TAG.to_string()],
+        tracking_data,
+        Some(0),
+        now,
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .change_context(router_errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("subscriptions: unable to form process_tracker type")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(router_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("subscriptions: unable to insert process_tracker entry in DB")?;
+
+    Ok(())
+}
+
+/// Runs the current step of dunning and schedules the task for the next one. Returns the reminder
+/// to email the customer, if any.
+#[cfg(feature = "v1")]
+pub async fn perform_dunning(
+    state: &SessionState,
+    process: ProcessTracker,
+    mut tracking_data: storage::dunning::DunningTrackingData,
+) -> Result<Option<dunning::DunningReminder>, errors::ProcessTrackerError> {
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .attach_printable("Failed to fetch Merchant key store from DB")?;
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await
+        .attach_printable("Subscriptions: Failed to fetch Merchant Account from DB")?;
+
+    let profile = state
+        .store
+        .find_business_profile_by_profile_id(&key_store, &tracking_data.profile_id)
+        .await
+        .attach_printable("Subscriptions: Failed to fetch Business Profile from DB")?;
+
+    let subscription = state
+        .store
+        .find_by_merchant_id_subscription_id(
+            &key_store,
+            merchant_account.get_id(),
+            tracking_data.subscription_id.get_string_repr().to_string(),
+        )
+        .await
+        .attach_printable("Subscriptions: Failed to fetch subscription from DB")?;
+
+    let platform = Platform::new(
+        merchant_account.clone(),
+        key_store.clone(),
+        merchant_account,
+        key_store,
+        None,
+    );
+
+    let step = usize::try_from(process.retry_count)
+        .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?;
+    let (next_run, reminder) = Box::pin(dunning::run_dunning_step(
+        state,
+        &platform,
+        &profile,
+        subscription,
+        &mut tracking_data,
+        step,
+    ))
+    .await?;
+    logger::info!(invoice_id = ?tracking_data.invoice_id, step, ?next_run, "Ran dunning step");
+
+    let (retry_count, schedule_time) = match next_run {
+        dunning::DunningNextRun::Recheck(schedule_time) => (process.retry_count, schedule_time),
+        dunning::DunningNextRun::NextStep(schedule_time) => {
+            (process.retry_count.saturating_add(1), schedule_time)
+        }
+        dunning::DunningNextRun::Finish(status) => {
+            state
+                .store
+                .as_scheduler()
+                .finish_process_with_business_status(process, status)
+                .await?;
+            return Ok(reminder);
+        }
+    };
+
+    let tracking_data = tracking_data
+        .encode_to_value()
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process,
+            ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(retry_count),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(common_enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?;
+
+    Ok(reminder)
+}
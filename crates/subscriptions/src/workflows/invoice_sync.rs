@@ -17,11 +17,12 @@ use storage_impl::StorageError;
 use crate::{
     core::{
         billing_processor_handler as billing, errors as router_errors, invoice_handler,
-        payments_api_client,
+        native_billing, payments_api_client,
     },
     helpers::ForeignTryFrom,
     state::{SubscriptionState as SessionState, SubscriptionStorageInterface as StorageInterface},
     types::storage,
+    workflows::dunning,
 };
 
 const INVOICE_SYNC_WORKFLOW: &str = "INVOICE_SYNC";
@@ -245,11 +246,66 @@ impl<'a> InvoiceSyncHandler<'a> {
             ))
             .await
             .attach_printable("Failed to record back to billing processor")
+        } else if self.subscription.is_natively_billed() {
+            self.settle_native_invoice(payment_response, invoice_sync_status)
+                .await
+                .attach_printable("Failed to settle native invoice")
         } else {
             Ok(self.invoice.clone())
         }
     }
 
+    /// Invoices raised by the native billing engine are settled locally, moving the subscription
+    /// along with the outcome of the payment
+    pub async fn settle_native_invoice(
+        &self,
+        payment_response: subscription_types::PaymentResponseData,
+        invoice_sync_status: storage::invoice_sync::InvoiceSyncPaymentStatus,
+    ) -> CustomResult<hyperswitch_domain_models::invoice::Invoice, router_errors::ApiErrorResponse>
+    {
+        let invoice_handler = invoice_handler::InvoiceHandler::new(
+            self.subscription.clone(),
+            self.merchant_account.clone(),
+            self.profile.clone(),
+            self.key_store.clone(),
+        );
+
+        let update_request = InvoiceUpdateRequest::update_payment_and_status(
+            payment_response.payment_method_id.clone(),
+            Some(payment_response.payment_id.clone()),
+            common_enums::connector_enums::InvoiceStatus::from(invoice_sync_status.clone()),
+            None,
+        );
+        let invoice = invoice_handler
+            .update_invoice(self.state, self.invoice.id.to_owned(), update_request)
+            .await
+            .attach_printable("Failed to update invoice in DB")?;
+
+        if let Some(status) = native_billing::status_after_payment(
+            &self.subscription,
+            &invoice_sync_status,
+            common_utils::date_time::now(),
+        ) {
+            self.state
+                .store
+                .update_subscription_entry(
+                    &self.key_store,
+                    self.merchant_account.get_id(),
+                    self.subscription.id.get_string_repr().to_string(),
+                    domain::subscription::SubscriptionUpdate::update_status(status.to_string()),
+                )
+                .await
+                .change_context(router_errors::ApiErrorResponse::SubscriptionError {
+                    operation: "Subscription Update".to_string(),
+                })
+                .attach_printable(
+                    "subscriptions: unable to update subscription entry in database",
+                )?;
+        }
+
+        Ok(invoice)
+    }
+
     pub async fn perform_billing_processor_record_back(
         &self,
         payment_response: subscription_types::PaymentResponseData,
@@ -342,8 +398,39 @@ impl<'a> InvoiceSyncHandler<'a> {
                 operation: "Invoice_sync process_tracker task completion".to_string(),
             })
             .attach_printable("Failed to update process tracker status")?;
+
+        if matches!(
+            invoice_sync_status,
+            storage::invoice_sync::InvoiceSyncPaymentStatus::PaymentFailed
+        ) {
+            let _ = self
+                .start_dunning(&invoice)
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to start dunning of the invoice"));
+        }
         Ok(invoice)
     }
+
+    /// Hands a failed invoice over to dunning. The subscription is read again, as settling a native
+    /// invoice moves it along with the payment.
+    async fn start_dunning(
+        &self,
+        invoice: &hyperswitch_domain_models::invoice::Invoice,
+    ) -> CustomResult<(), router_errors::ApiErrorResponse> {
+        let subscription = self
+            .state
+            .store
+            .find_by_merchant_id_subscription_id(
+                &self.key_store,
+                self.merchant_account.get_id(),
+                self.subscription.id.get_string_repr().to_string(),
+            )
+            .await
+            .change_context(router_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Subscriptions: Failed to fetch subscription from DB")?;
+
+        dunning::start_dunning(self.state, &subscription, invoice).await
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -379,7 +466,11 @@ pub async fn perform_subscription_invoice_sync(
             logger::error!(?e, "Error in transitioning workflow state");
             retry_subscription_invoice_sync_task(
                 &*handler.state.store,
-                handler.tracking_data.connector_name.to_string().clone(),
+                handler
+                    .tracking_data
+                    .connector_name
+                    .map(|connector_name| connector_name.to_string())
+                    .unwrap_or_else(|| native_billing::NATIVE_BILLING_PROCESSOR.to_string()),
                 handler.merchant_account.get_id().to_owned(),
                 process,
             )
@@ -0,0 +1,165 @@
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{platform::Platform, subscription::Subscription};
+use router_env::logger;
+use scheduler::{
+    errors,
+    workflows::storage::{business_status, ProcessTracker, ProcessTrackerNew},
+};
+
+use crate::{
+    core::{errors as router_errors, native_billing},
+    state::SubscriptionState as SessionState,
+    types::storage,
+};
+
+const SUBSCRIPTION_BILLING_WORKFLOW: &str = "SUBSCRIPTION_BILLING";
+const SUBSCRIPTION_BILLING_WORKFLOW_TAG: &str = "SUBSCRIPTION";
+
+/// Every subscription has a single billing task, which is rescheduled after each billing event
+fn get_subscription_billing_process_id(subscription: &Subscription) -> String {
+    format!(
+        "{SUBSCRIPTION_BILLING_WORKFLOW}_{}",
+        subscription.id.get_string_repr()
+    )
+}
+
+/// Schedules the billing task of a natively billed subscription at `schedule_time`, replacing the
+/// schedule of an existing task
+pub async fn schedule_subscription_billing(
+    state: &SessionState,
+    subscription: &Subscription,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), router_errors::ApiErrorResponse> {
+    let process_id = get_subscription_billing_process_id(subscription);
+
+    let existing_process = state
+        .store
+        .find_process_by_id(&process_id)
+        .await
+        .change_context(router_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("subscriptions: unable to find subscription billing process")?;
+
+    if let Some(process) = existing_process {
+        return state
+            .store
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(router_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("subscriptions: unable to reschedule subscription billing process");
+    }
+
+    let tracking_data = storage::subscription_billing::SubscriptionBillingTrackingData {
+        subscription_id: subscription.id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+        profile_id: subscription.profile_id.clone(),
+    };
+
+    let process_tracker_entry = ProcessTrackerNew::new(
+        process_id,
+        SUBSCRIPTION_BILLING_WORKFLOW.to_string(),
+        common_enums::ProcessTrackerRunner::SubscriptionBillingWorkflow,
+        vec![SUBSCRIPTION_BILLING_WORKFLOW_TAG.to_string()],
+        tracking_data,
+        Some(0),
+        schedule_time,
+        common_types::consts::API_VERSION,
+        state.conf.application_source,
+    )
+    .change_context(router_errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("subscriptions: unable to form process_tracker type")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(router_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("subscriptions: unable to insert process_tracker entry in DB")?;
+
+    Ok(())
+}
+
+#[cfg(feature = "v1")]
+pub async fn perform_subscription_billing(
+    state: &SessionState,
+    process: ProcessTracker,
+    tracking_data: storage::subscription_billing::SubscriptionBillingTrackingData,
+) -> Result<(), errors::ProcessTrackerError> {
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .attach_printable("Failed to fetch Merchant key store from DB")?;
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await
+        .attach_printable("Subscriptions: Failed to fetch Merchant Account from DB")?;
+
+    let profile = state
+        .store
+        .find_business_profile_by_profile_id(&key_store, &tracking_data.profile_id)
+        .await
+        .attach_printable("Subscriptions: Failed to fetch Business Profile from DB")?;
+
+    let subscription = state
+        .store
+        .find_by_merchant_id_subscription_id(
+            &key_store,
+            merchant_account.get_id(),
+            tracking_data.subscription_id.get_string_repr().to_string(),
+        )
+        .await
+        .attach_printable("Subscriptions: Failed to fetch subscription from DB")?;
+
+    // The profile may have been connected to a billing processor since the task was scheduled
+    if !native_billing::is_natively_billed(&profile) || !subscription.is_natively_billed() {
+        logger::info!(
+            subscription_id = ?tracking_data.subscription_id,
+            "Subscription is no longer billed natively"
+        );
+        return state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::RESOURCE_STATUS_MISMATCH)
+            .await
+            .map_err(Into::into);
+    }
+
+    let platform = Platform::new(
+        merchant_account.clone(),
+        key_store.clone(),
+        merchant_account,
+        key_store,
+        None,
+    );
+
+    let next_billing_at = Box::pin(native_billing::run_billing_cycle(
+        state,
+        &platform,
+        &profile,
+        subscription,
+        common_utils::date_time::now(),
+    ))
+    .await?;
+
+    match next_billing_at {
+        Some(schedule_time) => state
+            .store
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .map_err(Into::into),
+        None => state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await
+            .map_err(Into::into),
+    }
+}
@@ -0,0 +1,45 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_batch (
+    payout_batch_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    description VARCHAR(255),
+    file_id VARCHAR(64),
+    total_count INTEGER NOT NULL,
+    submitted_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    created_by VARCHAR(255),
+    reviewed_by VARCHAR(255),
+    rejection_reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_merchant_id_created_at_index ON payout_batch (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    payout_batch_id VARCHAR(64) NOT NULL,
+    item_index INTEGER NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payout_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    request_data JSONB NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    payout_status "PayoutStatus",
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (payout_batch_id, item_index)
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_item_payout_batch_id_status_index ON payout_batch_item (payout_batch_id, status);
+
+ALTER TYPE "EventType"
+ADD VALUE IF NOT EXISTS 'payout_batch_completed';
+
+ALTER TYPE "EventObjectType"
+ADD VALUE IF NOT EXISTS 'payout_batch_details';
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS bulk_refund_item_bulk_refund_id_status_index;
+
+DROP TABLE IF EXISTS bulk_refund_item;
+
+DROP TABLE IF EXISTS bulk_refund;
@@ -0,0 +1,33 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS bulk_refund (
+    bulk_refund_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    total_count INTEGER NOT NULL,
+    submitted_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    created_by VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS bulk_refund_item (
+    bulk_refund_id VARCHAR(64) NOT NULL,
+    item_index INTEGER NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    refund_id VARCHAR(64) NOT NULL,
+    amount BIGINT,
+    request_data JSONB NOT NULL,
+    connector VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    refund_status "RefundStatus",
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (bulk_refund_id, item_index)
+);
+
+CREATE INDEX IF NOT EXISTS bulk_refund_item_bulk_refund_id_status_index ON bulk_refund_item (bulk_refund_id, status);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS dispute_evidence_template_merchant_id_index;
+
+DROP TABLE IF EXISTS dispute_evidence_template;
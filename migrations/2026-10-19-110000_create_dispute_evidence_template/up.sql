@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS dispute_evidence_template (
+    template_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    name VARCHAR(255) NOT NULL,
+    connector VARCHAR(64),
+    reason_codes TEXT[] NOT NULL DEFAULT '{}',
+    evidence_sources JSONB NOT NULL,
+    text_evidence JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS dispute_evidence_template_merchant_id_index ON dispute_evidence_template (merchant_id);
+
+ALTER TYPE "EventType"
+ADD VALUE IF NOT EXISTS 'dispute_evidence_due';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS dispute_automated_action_merchant_id_dispute_id_index;
+
+DROP TABLE IF EXISTS dispute_automated_action;
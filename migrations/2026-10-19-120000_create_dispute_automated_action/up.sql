@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS dispute_automated_action (
+    action_id VARCHAR(64) PRIMARY KEY,
+    dispute_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    rule_name VARCHAR(255),
+    action VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS dispute_automated_action_merchant_id_dispute_id_index ON dispute_automated_action (merchant_id, dispute_id);
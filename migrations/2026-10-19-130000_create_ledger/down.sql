@@ -0,0 +1,14 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS ledger_posting_entry_id_index;
+
+DROP INDEX IF EXISTS ledger_posting_merchant_id_currency_created_at_index;
+
+DROP TABLE IF EXISTS ledger_posting;
+
+DROP INDEX IF EXISTS ledger_journal_entry_merchant_id_entry_type_reference_id_index;
+
+DROP TABLE IF EXISTS ledger_journal_entry;
+
+DROP INDEX IF EXISTS ledger_fee_schedule_merchant_id_index;
+
+DROP TABLE IF EXISTS ledger_fee_schedule;
@@ -0,0 +1,45 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS ledger_fee_schedule (
+    fee_schedule_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64),
+    payment_method VARCHAR(64),
+    currency "Currency",
+    fixed_fee BIGINT NOT NULL DEFAULT 0,
+    percentage_fee_basis_points INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS ledger_fee_schedule_merchant_id_index ON ledger_fee_schedule (merchant_id);
+
+CREATE TABLE IF NOT EXISTS ledger_journal_entry (
+    entry_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    connector VARCHAR(64),
+    entry_type VARCHAR(32) NOT NULL,
+    reference_id VARCHAR(128) NOT NULL,
+    payment_id VARCHAR(64),
+    currency "Currency" NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS ledger_journal_entry_merchant_id_entry_type_reference_id_index ON ledger_journal_entry (merchant_id, entry_type, reference_id);
+
+CREATE TABLE IF NOT EXISTS ledger_posting (
+    posting_id VARCHAR(64) PRIMARY KEY,
+    entry_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    connector VARCHAR(64),
+    account VARCHAR(32) NOT NULL,
+    direction VARCHAR(16) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS ledger_posting_merchant_id_currency_created_at_index ON ledger_posting (merchant_id, currency, created_at);
+
+CREATE INDEX IF NOT EXISTS ledger_posting_entry_id_index ON ledger_posting (entry_id);
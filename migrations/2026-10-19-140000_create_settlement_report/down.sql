@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS settlement_recon_line_merchant_id_status_is_resolved_index;
+
+DROP INDEX IF EXISTS settlement_recon_line_report_id_index;
+
+DROP TABLE IF EXISTS settlement_recon_line;
+
+DROP INDEX IF EXISTS settlement_report_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS settlement_report;
@@ -0,0 +1,54 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS settlement_report (
+    report_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    organization_id VARCHAR(32) NOT NULL,
+    profile_id VARCHAR(64),
+    connector VARCHAR(64) NOT NULL,
+    file_id VARCHAR(64) NOT NULL,
+    format VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    period_start TIMESTAMP,
+    period_end TIMESTAMP,
+    total_count INTEGER NOT NULL DEFAULT 0,
+    matched_count INTEGER NOT NULL DEFAULT 0,
+    amount_mismatch_count INTEGER NOT NULL DEFAULT 0,
+    missing_in_hyperswitch_count INTEGER NOT NULL DEFAULT 0,
+    missing_at_psp_count INTEGER NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS settlement_report_merchant_id_created_at_index ON settlement_report (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS settlement_recon_line (
+    line_id VARCHAR(64) PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    organization_id VARCHAR(32) NOT NULL,
+    profile_id VARCHAR(64),
+    connector VARCHAR(64) NOT NULL,
+    line_number INTEGER,
+    transaction_type VARCHAR(16) NOT NULL,
+    connector_transaction_id VARCHAR(128),
+    status VARCHAR(32) NOT NULL,
+    settled_amount BIGINT,
+    settled_currency "Currency",
+    fee BIGINT,
+    hyperswitch_amount BIGINT,
+    hyperswitch_currency "Currency",
+    payment_id VARCHAR(64),
+    attempt_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    settled_at TIMESTAMP,
+    is_resolved BOOLEAN NOT NULL DEFAULT FALSE,
+    resolution_note TEXT,
+    resolved_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS settlement_recon_line_report_id_index ON settlement_recon_line (report_id);
+
+CREATE INDEX IF NOT EXISTS settlement_recon_line_merchant_id_status_is_resolved_index ON settlement_recon_line (merchant_id, status, is_resolved);
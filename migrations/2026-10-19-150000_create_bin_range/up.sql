@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS bin_range (
+    bin_range_id VARCHAR(64) PRIMARY KEY,
+    range_low VARCHAR(11) NOT NULL,
+    range_high VARCHAR(11) NOT NULL,
+    card_issuer TEXT,
+    card_network TEXT,
+    card_type TEXT,
+    card_subtype TEXT,
+    card_issuing_country TEXT,
+    country_code VARCHAR(32),
+    bank_code VARCHAR(32),
+    source VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS bin_range_source_index ON bin_range (source);
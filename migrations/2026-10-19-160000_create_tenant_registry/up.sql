@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS tenant_registry (
+    tenant_id VARCHAR(64) PRIMARY KEY,
+    base_url TEXT NOT NULL,
+    schema_name VARCHAR(64) NOT NULL,
+    accounts_schema_name VARCHAR(64) NOT NULL,
+    redis_key_prefix VARCHAR(64) NOT NULL,
+    clickhouse_database VARCHAR(64) NOT NULL,
+    control_center_url TEXT NOT NULL,
+    status VARCHAR(32) NOT NULL DEFAULT 'active',
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS tenant_registry_redis_key_prefix_index ON tenant_registry (redis_key_prefix);
@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS outbox (
+    outbox_id VARCHAR(64) PRIMARY KEY,
+    dedupe_key VARCHAR(255) NOT NULL,
+    aggregate_type VARCHAR(32) NOT NULL,
+    aggregate_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    operation VARCHAR(16) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(32) NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    published_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS outbox_dedupe_key_index ON outbox (dedupe_key);
+
+CREATE INDEX IF NOT EXISTS outbox_status_created_at_index ON outbox (status, created_at);
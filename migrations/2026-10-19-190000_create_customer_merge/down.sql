@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS customer_match_key_merchant_id_key_type_key_hash_index;
+
+DROP TABLE IF EXISTS customer_match_key;
+
+DROP INDEX IF EXISTS customer_redirect_merchant_id_merge_id_index;
+
+DROP INDEX IF EXISTS customer_redirect_merchant_id_target_customer_id_index;
+
+DROP TABLE IF EXISTS customer_redirect;
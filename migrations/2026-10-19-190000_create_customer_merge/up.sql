@@ -0,0 +1,31 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'customer_merged';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'customer_details';
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'customers';
+
+CREATE TABLE IF NOT EXISTS customer_redirect (
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    target_customer_id VARCHAR(64) NOT NULL,
+    merge_id VARCHAR(64) NOT NULL,
+    merged_by VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, customer_id)
+);
+
+CREATE INDEX IF NOT EXISTS customer_redirect_merchant_id_target_customer_id_index ON customer_redirect (merchant_id, target_customer_id);
+
+CREATE INDEX IF NOT EXISTS customer_redirect_merchant_id_merge_id_index ON customer_redirect (merchant_id, merge_id);
+
+CREATE TABLE IF NOT EXISTS customer_match_key (
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    key_type VARCHAR(16) NOT NULL,
+    key_hash VARCHAR(128) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, customer_id, key_type)
+);
+
+CREATE INDEX IF NOT EXISTS customer_match_key_merchant_id_key_type_key_hash_index ON customer_match_key (merchant_id, key_type, key_hash);
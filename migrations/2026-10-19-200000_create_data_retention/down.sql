@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS archived_record;
+
+DROP TABLE IF EXISTS data_retention_policy;
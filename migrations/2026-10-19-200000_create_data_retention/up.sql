@@ -0,0 +1,27 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS data_retention_policy (
+    entity VARCHAR(32) PRIMARY KEY,
+    retention_days INTEGER NOT NULL,
+    file_format VARCHAR(32) NOT NULL DEFAULT 'ndjson',
+    purge_mode VARCHAR(32) NOT NULL DEFAULT 'delete',
+    batch_size INTEGER NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    archived_count BIGINT NOT NULL DEFAULT 0,
+    last_archived_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS archived_record (
+    merchant_id VARCHAR(64) NOT NULL,
+    entity VARCHAR(32) NOT NULL,
+    record_id VARCHAR(255) NOT NULL,
+    payment_id VARCHAR(255),
+    file_key VARCHAR(255) NOT NULL,
+    file_format VARCHAR(32) NOT NULL,
+    record_created_at TIMESTAMP NOT NULL,
+    archived_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, entity, record_id)
+);
+
+CREATE INDEX IF NOT EXISTS archived_record_merchant_id_payment_id_index ON archived_record (merchant_id, payment_id);
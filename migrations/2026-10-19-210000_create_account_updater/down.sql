@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS account_updater_card_merchant_id_payment_method_id_index;
+
+DROP TABLE IF EXISTS account_updater_card;
+
+DROP INDEX IF EXISTS account_updater_batch_merchant_id_created_at_index;
+
+DROP INDEX IF EXISTS account_updater_batch_merchant_id_status_index;
+
+DROP TABLE IF EXISTS account_updater_batch;
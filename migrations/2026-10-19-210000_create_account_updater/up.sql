@@ -0,0 +1,38 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_updated';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_details';
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
+
+CREATE TABLE IF NOT EXISTS account_updater_batch (
+    batch_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    card_count INTEGER NOT NULL DEFAULT 0,
+    updated_count INTEGER NOT NULL DEFAULT 0,
+    closed_count INTEGER NOT NULL DEFAULT 0,
+    unchanged_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS account_updater_batch_merchant_id_status_index ON account_updater_batch (merchant_id, status);
+
+CREATE INDEX IF NOT EXISTS account_updater_batch_merchant_id_created_at_index ON account_updater_batch (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS account_updater_card (
+    batch_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    reason VARCHAR(32) NOT NULL,
+    result VARCHAR(32),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (batch_id, payment_method_id)
+);
+
+CREATE INDEX IF NOT EXISTS account_updater_card_merchant_id_payment_method_id_index ON account_updater_card (merchant_id, payment_method_id, created_at);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_expiring';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_expired';
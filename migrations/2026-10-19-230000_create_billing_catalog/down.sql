@@ -0,0 +1,24 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM invoice WHERE merchant_connector_id IS NULL OR provider_name IS NULL;
+
+ALTER TABLE invoice ALTER COLUMN provider_name SET NOT NULL;
+
+ALTER TABLE invoice ALTER COLUMN merchant_connector_id SET NOT NULL;
+
+ALTER TABLE subscription DROP COLUMN IF EXISTS billing_details;
+
+DROP INDEX IF EXISTS billing_coupon_merchant_id_profile_id_code_index;
+
+DROP TABLE IF EXISTS billing_coupon;
+
+DROP INDEX IF EXISTS billing_price_merchant_id_plan_id_index;
+
+DROP TABLE IF EXISTS billing_price;
+
+DROP INDEX IF EXISTS billing_plan_merchant_id_profile_id_index;
+
+DROP TABLE IF EXISTS billing_plan;
+
+DROP INDEX IF EXISTS billing_product_merchant_id_profile_id_index;
+
+DROP TABLE IF EXISTS billing_product;
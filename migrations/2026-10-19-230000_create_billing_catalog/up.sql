@@ -0,0 +1,80 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS billing_product (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS billing_product_merchant_id_profile_id_index ON billing_product (merchant_id, profile_id, created_at);
+
+CREATE TABLE IF NOT EXISTS billing_plan (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    product_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    trial_period_days INTEGER,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS billing_plan_merchant_id_profile_id_index ON billing_plan (merchant_id, profile_id, created_at);
+
+CREATE TABLE IF NOT EXISTS billing_price (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    plan_id VARCHAR(64) NOT NULL,
+    currency "Currency" NOT NULL,
+    pricing_model VARCHAR(32) NOT NULL,
+    unit_amount BIGINT,
+    tiers JSONB,
+    billing_interval VARCHAR(32) NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS billing_price_merchant_id_plan_id_index ON billing_price (merchant_id, plan_id);
+
+CREATE TABLE IF NOT EXISTS billing_coupon (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    code VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    discount_type VARCHAR(32) NOT NULL,
+    percent_off INTEGER,
+    amount_off BIGINT,
+    currency "Currency",
+    duration VARCHAR(32) NOT NULL,
+    duration_in_cycles INTEGER,
+    max_redemptions INTEGER,
+    times_redeemed INTEGER NOT NULL DEFAULT 0,
+    redeem_by TIMESTAMP,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS billing_coupon_merchant_id_profile_id_code_index ON billing_coupon (merchant_id, profile_id, code);
+
+-- Billing cycle, trial, coupon and scheduled changes of subscriptions billed by Hyperswitch
+ALTER TABLE subscription ADD COLUMN IF NOT EXISTS billing_details JSONB;
+
+-- Invoices generated by Hyperswitch are not tied to a billing connector
+ALTER TABLE invoice ALTER COLUMN merchant_connector_id DROP NOT NULL;
+
+ALTER TABLE invoice ALTER COLUMN provider_name DROP NOT NULL;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+DROP INDEX IF EXISTS dispute_automated_action_merchant_id_dispute_id_index;
+
+CREATE UNIQUE INDEX IF NOT EXISTS dispute_automated_action_merchant_id_dispute_id_index ON dispute_automated_action (merchant_id, dispute_id);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE outbox DROP COLUMN IF EXISTS next_attempt_at;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE outbox ADD COLUMN IF NOT EXISTS next_attempt_at TIMESTAMP;